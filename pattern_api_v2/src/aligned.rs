@@ -0,0 +1,267 @@
+//! Matching at aligned offsets only, for buffers of fixed-size records.
+//!
+//! `Aligned(pattern, stride)` only accepts the matches of `pattern` that
+//! start at a multiple of `stride` elements from the front of the slice.
+//! A match at an unaligned offset can hide an aligned one overlapping it,
+//! so the searcher of `pattern` is moved to the next aligned offset with
+//! `SeekableSearcher::seek_front()` instead of continuing after the match.
+//! Searching backward moves it to one element before the end of the match.
+//!
+//! The rejects are the parts between the aligned matches, so the pieces
+//! of `split()` start at record boundaries whenever the needle is a whole
+//! number of records long.
+
+use ::{Pattern, PatternHaystack, PatternError};
+use ::{Searcher, ReverseSearcher, SeekableSearcher};
+
+/// Only accepts the matches of `self.0` at multiples of `self.1`.
+///
+/// Searching panics if the stride is zero.
+#[derive(Copy, Clone, Debug)]
+pub struct Aligned<P>(pub P, pub usize);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SearchStep<C> {
+    Match(C, C),
+    Reject(C, C),
+    Done,
+}
+
+/// Associated type for `<Aligned<P> as Pattern<H>>::Searcher`.
+#[derive(Clone, Debug)]
+pub struct AlignedSearcher<H: PatternHaystack, S> {
+    searcher: S,
+    stride: usize,
+    front: H::Cursor,
+    back: H::Cursor,
+    // Aligned matches already taken from `searcher`,
+    // after the reject in front of them was returned
+    pending_front: Option<(H::Cursor, H::Cursor)>,
+    pending_back: Option<(H::Cursor, H::Cursor)>,
+}
+
+impl<H: PatternHaystack, S: SeekableSearcher<H>> AlignedSearcher<H, S> {
+    fn new(searcher: S, stride: usize) -> AlignedSearcher<H, S> {
+        assert!(stride > 0, "the stride of an aligned pattern is zero");
+        let hs = searcher.haystack();
+        AlignedSearcher {
+            searcher: searcher,
+            stride: stride,
+            front: H::cursor_at_front(hs),
+            back: H::cursor_at_back(hs),
+            pending_front: None,
+            pending_back: None,
+        }
+    }
+
+    #[inline]
+    fn offset(&self, cursor: H::Cursor) -> usize {
+        H::offset_from_front(self.searcher.haystack(), cursor)
+    }
+
+    fn find_front(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        while let Some((a, b)) = self.searcher.next_match() {
+            let offset = self.offset(a);
+            if offset.is_multiple_of(self.stride) {
+                return Some((a, b));
+            }
+            let next = (offset / self.stride + 1) * self.stride;
+            if !self.searcher.seek_front(next) {
+                break;
+            }
+        }
+        // the searcher stops where the backward search found a match
+        self.pending_back.take()
+    }
+
+    fn next(&mut self) -> SearchStep<H::Cursor> {
+        let found = match self.pending_front.take() {
+            Some(m) => Some(m),
+            None => self.find_front(),
+        };
+        match found {
+            Some((a, b)) if a == self.front => {
+                self.front = b;
+                SearchStep::Match(a, b)
+            }
+            Some((a, b)) => {
+                self.pending_front = Some((a, b));
+                let reject = SearchStep::Reject(self.front, a);
+                self.front = a;
+                reject
+            }
+            None if self.front == self.back => SearchStep::Done,
+            None => {
+                let reject = SearchStep::Reject(self.front, self.back);
+                self.front = self.back;
+                reject
+            }
+        }
+    }
+}
+
+impl<H, S> AlignedSearcher<H, S>
+    where H: PatternHaystack,
+          S: SeekableSearcher<H> + ReverseSearcher<H>
+{
+    fn find_back(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        while let Some((a, b)) = self.searcher.next_match_back() {
+            if self.offset(a).is_multiple_of(self.stride) {
+                return Some((a, b));
+            }
+            let end = self.offset(b);
+            if end == 0 || !self.searcher.seek_back(end - 1) {
+                break;
+            }
+        }
+        self.pending_front.take()
+    }
+
+    fn next_back(&mut self) -> SearchStep<H::Cursor> {
+        let found = match self.pending_back.take() {
+            Some(m) => Some(m),
+            None => self.find_back(),
+        };
+        match found {
+            Some((a, b)) if b == self.back => {
+                self.back = a;
+                SearchStep::Match(a, b)
+            }
+            Some((a, b)) => {
+                self.pending_back = Some((a, b));
+                let reject = SearchStep::Reject(b, self.back);
+                self.back = b;
+                reject
+            }
+            None if self.front == self.back => SearchStep::Done,
+            None => {
+                let reject = SearchStep::Reject(self.front, self.back);
+                self.back = self.front;
+                reject
+            }
+        }
+    }
+}
+
+unsafe impl<H: PatternHaystack, S: SeekableSearcher<H>> Searcher<H> for AlignedSearcher<H, S> {
+    #[inline]
+    fn haystack(&self) -> H::Haystack {
+        self.searcher.haystack()
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(a, b) => return Some((a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some((a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+unsafe impl<H, S> ReverseSearcher<H> for AlignedSearcher<H, S>
+    where H: PatternHaystack,
+          S: SeekableSearcher<H> + ReverseSearcher<H>
+{
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(a, b) => return Some((a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    #[inline]
+    fn next_reject_back(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some((a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+// Only for slices, since the searcher seeks to offsets
+// that are not char boundaries in other haystacks
+macro_rules! impl_aligned_pattern {
+    ($slice:ty) => {
+        /// Searches for the matches of the inner pattern
+        /// that start at a multiple of the stride
+        impl<'a, T, P> Pattern<$slice> for Aligned<P>
+            where P: Pattern<$slice>,
+                  P::Searcher: SeekableSearcher<$slice>
+        {
+            type Searcher = AlignedSearcher<$slice, P::Searcher>;
+
+            #[inline]
+            fn into_searcher(self, haystack: $slice) -> Self::Searcher {
+                AlignedSearcher::new(self.0.into_searcher(haystack), self.1)
+            }
+
+            // The front is aligned, so the inner pattern
+            // can check for a prefix by itself
+            #[inline]
+            fn is_prefix_of(self, haystack: $slice) -> bool {
+                self.0.is_prefix_of(haystack)
+            }
+
+            #[inline]
+            fn is_suffix_of(self, haystack: $slice) -> bool
+                where Self::Searcher: ReverseSearcher<$slice>
+            {
+                let len = haystack.len();
+                let mut searcher = self.into_searcher(haystack);
+                let hs = searcher.haystack();
+                match searcher.next_match_back() {
+                    Some((_, b)) => <$slice>::offset_from_front(hs, b) == len,
+                    None => false,
+                }
+            }
+
+            #[inline]
+            fn validate(&self) -> Result<(), PatternError> {
+                if self.1 == 0 {
+                    return Err(PatternError::ZeroStride);
+                }
+                self.0.validate()
+            }
+
+            #[inline]
+            fn matches_at(self, haystack: $slice, offset: usize) -> Option<usize> {
+                if offset.is_multiple_of(self.1) {
+                    self.0.matches_at(haystack, offset)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn strip_prefix_of(self, haystack: $slice)
+                -> Option<<$slice as PatternHaystack>::MatchType>
+            {
+                self.0.strip_prefix_of(haystack)
+            }
+        }
+    }
+}
+
+impl_aligned_pattern!(&'a [T]);
+impl_aligned_pattern!(&'a mut [T]);
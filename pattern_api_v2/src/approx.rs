@@ -0,0 +1,765 @@
+//! Approximate matching, for typo-tolerant search and sequence data.
+//!
+//! `Mismatches { needle, max_mismatches }` matches the windows of the
+//! haystack that are as long as the needle, and differ from it in at
+//! most `max_mismatches` places (Hamming distance).
+//!
+//! `Edits { needle, max_edits }` matches the parts of the haystack that
+//! can be turned into the needle with at most `max_edits` insertions,
+//! deletions and substitutions (Levenshtein distance). The distances of
+//! all end positions are computed with the bit-parallel algorithm of
+//! Myers, 64 needle elements per machine word. A match is found at the
+//! first end position within the limit, once the distance stops
+//! decreasing. It starts at the leftmost position achieving that
+//! distance, and ends at the rightmost one.
+//!
+//! Both are patterns for `&[T]` and `&str` haystacks, where the elements
+//! of a `&str` are its chars. Matches are leftmost and do not overlap.
+//! `match_indices()` and `rmatch_indices()` of this module yield the
+//! distance of each match along with its offset.
+
+use ::{Pattern, PatternHaystack, Searcher, ReverseSearcher, SeekableSearcher, Span};
+
+use core::cmp;
+use alloc::vec;
+use alloc::vec::Vec;
+
+// A haystack as a sequence of elements,
+// indexed by the offsets between them
+pub trait ApproxHaystack: PatternHaystack + Copy {
+    type Elem;
+
+    fn elem_after(self, pos: usize) -> Option<(Self::Elem, usize)>;
+    fn elem_before(self, pos: usize) -> Option<(Self::Elem, usize)>;
+
+    fn seq_len(self) -> usize;
+
+    unsafe fn cursor_at(self, pos: usize) -> Self::Cursor;
+}
+
+impl<'a, T> ApproxHaystack for &'a [T] {
+    type Elem = &'a T;
+
+    #[inline]
+    fn elem_after(self, pos: usize) -> Option<(&'a T, usize)> {
+        self.get(pos).map(|e| (e, pos + 1))
+    }
+
+    #[inline]
+    fn elem_before(self, pos: usize) -> Option<(&'a T, usize)> {
+        if pos == 0 { None } else { Some((&self[pos - 1], pos - 1)) }
+    }
+
+    #[inline]
+    fn seq_len(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    unsafe fn cursor_at(self, pos: usize) -> *const T {
+        self.as_ptr().add(pos)
+    }
+}
+
+impl<'a> ApproxHaystack for &'a str {
+    type Elem = char;
+
+    #[inline]
+    fn elem_after(self, pos: usize) -> Option<(char, usize)> {
+        self[pos..].chars().next().map(|c| (c, pos + c.len_utf8()))
+    }
+
+    #[inline]
+    fn elem_before(self, pos: usize) -> Option<(char, usize)> {
+        self[..pos].chars().next_back().map(|c| (c, pos - c.len_utf8()))
+    }
+
+    #[inline]
+    fn seq_len(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    unsafe fn cursor_at(self, pos: usize) -> *const u8 {
+        self.as_ptr().add(pos)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Patterns
+///////////////////////////////////////////////////////////////////////////////
+
+/// Matches with a Hamming distance of at most `max_mismatches`.
+#[derive(Copy, Clone, Debug)]
+pub struct Mismatches<N> {
+    pub needle: N,
+    pub max_mismatches: usize,
+}
+
+/// Matches with a Levenshtein distance of at most `max_edits`.
+#[derive(Copy, Clone, Debug)]
+pub struct Edits<N> {
+    pub needle: N,
+    pub max_edits: usize,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Metric {
+    Hamming,
+    Levenshtein,
+}
+
+macro_rules! impl_approx_pattern {
+    ($pattern:ident, $max:ident, $metric:expr) => {
+        /// Searches for the leftmost non-overlapping approximate matches
+        impl<'a, 'b, T: PartialEq> Pattern<&'a [T]> for $pattern<&'b [T]> {
+            type Searcher = ApproxSearcher<&'a [T], &'b T>;
+
+            #[inline]
+            fn into_searcher(self, haystack: &'a [T]) -> Self::Searcher {
+                ApproxSearcher::new(haystack, self.needle.iter().collect(), $metric, self.$max)
+            }
+
+            approx_pattern_methods!(&'a [T]);
+        }
+
+        /// Searches for the leftmost non-overlapping approximate matches,
+        /// comparing chars
+        impl<'a, 'b> Pattern<&'a str> for $pattern<&'b str> {
+            type Searcher = ApproxSearcher<&'a str, char>;
+
+            #[inline]
+            fn into_searcher(self, haystack: &'a str) -> Self::Searcher {
+                ApproxSearcher::new(haystack, self.needle.chars().collect(), $metric, self.$max)
+            }
+
+            approx_pattern_methods!(&'a str);
+        }
+    }
+}
+
+// The default impls look for a reject, which is not there
+// if a single match covers the whole haystack
+macro_rules! approx_pattern_methods {
+    ($slice:ty) => {
+        #[inline]
+        fn is_prefix_of(self, haystack: $slice) -> bool {
+            match self.into_searcher(haystack).next_match() {
+                Some((a, _)) => a == haystack.as_ptr(),
+                None => false,
+            }
+        }
+
+        #[inline]
+        fn is_suffix_of(self, haystack: $slice) -> bool
+            where Self::Searcher: ReverseSearcher<$slice>
+        {
+            match self.into_searcher(haystack).next_match_back() {
+                Some((_, b)) => b == haystack[haystack.len()..].as_ptr(),
+                None => false,
+            }
+        }
+    }
+}
+
+impl_approx_pattern!(Mismatches, max_mismatches, Metric::Hamming);
+impl_approx_pattern!(Edits, max_edits, Metric::Levenshtein);
+
+///////////////////////////////////////////////////////////////////////////////
+// Bit-parallel edit distance
+///////////////////////////////////////////////////////////////////////////////
+
+// The last row of the edit distance matrix of the needle against
+// the haystack, where a match may start anywhere (Myers 1999, in the
+// blocked form of Hyyrö 2003). Each block of 64 rows is encoded as the
+// vertical differences between adjacent rows, in `pv` (+1) and `mv` (-1).
+#[derive(Clone, Debug, Default)]
+struct Myers {
+    len: usize,
+    blocks: usize,
+    // `blocks` words per symbol, with bit `i` set if the needle
+    // has that symbol at row `i`
+    masks: Vec<u64>,
+    pv: Vec<u64>,
+    mv: Vec<u64>,
+    score: usize,
+}
+
+impl Myers {
+    fn new<I: Iterator<Item = usize>>(symbols: I, symbol_count: usize, len: usize) -> Myers {
+        let blocks = len.div_ceil(64);
+        let mut masks = vec![0; symbol_count * blocks];
+        for (i, s) in symbols.enumerate() {
+            masks[s * blocks + i / 64] |= 1 << (i % 64);
+        }
+        Myers {
+            len: len,
+            blocks: blocks,
+            masks: masks,
+            pv: vec![!0; blocks],
+            mv: vec![0; blocks],
+            score: len,
+        }
+    }
+
+    fn reset(&mut self) {
+        for w in &mut self.pv {
+            *w = !0;
+        }
+        for w in &mut self.mv {
+            *w = 0;
+        }
+        self.score = self.len;
+    }
+
+    // Advances by one haystack element, equal to the needle symbol `symbol`
+    fn step(&mut self, symbol: Option<usize>) {
+        // the first row is all zeros, since a match can start anywhere
+        let mut hin = 0i32;
+        for b in 0..self.blocks {
+            let eq = symbol.map_or(0, |s| self.masks[s * self.blocks + b]);
+            let (pv, mv) = (self.pv[b], self.mv[b]);
+            let xv = eq | mv;
+            let eq = eq | (hin < 0) as u64;
+            let xh = ((eq & pv).wrapping_add(pv) ^ pv) | eq;
+            let mut ph = mv | !(xh | pv);
+            let mut mh = pv & xh;
+            // the horizontal difference of the last row of the block
+            let last = if b + 1 == self.blocks { (self.len - 1) % 64 } else { 63 };
+            let hout = if ph >> last & 1 != 0 { 1 } else if mh >> last & 1 != 0 { -1 } else { 0 };
+            ph <<= 1;
+            mh <<= 1;
+            if hin < 0 {
+                mh |= 1;
+            } else if hin > 0 {
+                ph |= 1;
+            }
+            self.pv[b] = mh | !(xv | ph);
+            self.mv[b] = ph & xv;
+            hin = hout;
+        }
+        self.score = (self.score as isize + hin as isize) as usize;
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ApproxSearcher
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum SearchStep {
+    Match(usize, usize),
+    Reject(usize, usize),
+    Done
+}
+
+/// Associated type for `<Mismatches<N> as Pattern<H>>::Searcher`
+/// and `<Edits<N> as Pattern<H>>::Searcher`.
+#[derive(Clone, Debug)]
+pub struct ApproxSearcher<H, S> {
+    haystack: H,
+    needle: Vec<S>,
+    // Indices of the first occurrence of each distinct needle element
+    symbols: Vec<usize>,
+    metric: Metric,
+    max_distance: usize,
+    forward: Myers,
+    backward: Myers,
+    // Scratch column for finding the other end of a match
+    column: Vec<usize>,
+
+    front: usize,
+    back: usize,
+    pending_front: Option<(usize, usize, usize)>,
+    pending_back: Option<(usize, usize, usize)>,
+    // Positions of the empty matches returned from either end
+    empty_front: Option<usize>,
+    empty_back: Option<usize>,
+    distance: usize,
+}
+
+impl<H, S> ApproxSearcher<H, S>
+    where H: ApproxHaystack,
+          S: PartialEq<H::Elem> + PartialEq
+{
+    fn new(haystack: H, needle: Vec<S>, metric: Metric, max_distance: usize) -> Self {
+        let mut symbols: Vec<usize> = Vec::new();
+        let mut row_symbols = Vec::with_capacity(needle.len());
+        for (i, e) in needle.iter().enumerate() {
+            match symbols.iter().position(|&j| needle[j] == *e) {
+                Some(s) => row_symbols.push(s),
+                None => {
+                    row_symbols.push(symbols.len());
+                    symbols.push(i);
+                }
+            }
+        }
+        let (forward, backward) = if metric == Metric::Levenshtein {
+            let n = needle.len();
+            (Myers::new(row_symbols.iter().cloned(), symbols.len(), n),
+             Myers::new(row_symbols.iter().rev().cloned(), symbols.len(), n))
+        } else {
+            (Myers::default(), Myers::default())
+        };
+        ApproxSearcher {
+            haystack: haystack,
+            column: Vec::with_capacity(needle.len() + 1),
+            needle: needle,
+            symbols: symbols,
+            metric: metric,
+            max_distance: max_distance,
+            forward: forward,
+            backward: backward,
+            front: 0,
+            back: haystack.seq_len(),
+            pending_front: None,
+            pending_back: None,
+            empty_front: None,
+            empty_back: None,
+            distance: 0,
+        }
+    }
+
+    /// The distance of the match returned last.
+    #[inline]
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+
+    fn symbol(&self, e: &H::Elem) -> Option<usize> {
+        self.symbols.iter().position(|&i| self.needle[i] == *e)
+    }
+
+    fn empty_match_seen(&self, pos: usize) -> bool {
+        self.empty_front == Some(pos) || self.empty_back == Some(pos)
+    }
+
+    fn find_front(&mut self, skip_empty: bool) -> Option<(usize, usize, usize)> {
+        match self.metric {
+            Metric::Hamming => self.hamming_front(skip_empty),
+            Metric::Levenshtein => self.levenshtein_front(skip_empty),
+        }
+    }
+
+    fn find_back(&mut self, skip_empty: bool) -> Option<(usize, usize, usize)> {
+        match self.metric {
+            Metric::Hamming => self.hamming_back(skip_empty),
+            Metric::Levenshtein => self.levenshtein_back(skip_empty),
+        }
+    }
+
+    fn hamming_front(&self, skip_empty: bool) -> Option<(usize, usize, usize)> {
+        let mut start = self.front;
+        loop {
+            if !(skip_empty && start == self.front && self.needle.is_empty()) {
+                let (mut pos, mut d) = (start, 0);
+                for sym in &self.needle {
+                    match self.haystack.elem_after(pos) {
+                        Some((e, next)) if next <= self.back => {
+                            d += (*sym != e) as usize;
+                            pos = next;
+                            if d > self.max_distance {
+                                break;
+                            }
+                        }
+                        // no later window fits either
+                        _ => return None,
+                    }
+                }
+                if d <= self.max_distance {
+                    return Some((start, pos, d));
+                }
+            }
+            match self.haystack.elem_after(start) {
+                Some((_, next)) if next <= self.back => start = next,
+                _ => return None,
+            }
+        }
+    }
+
+    fn hamming_back(&self, skip_empty: bool) -> Option<(usize, usize, usize)> {
+        let mut end = self.back;
+        loop {
+            if !(skip_empty && end == self.back && self.needle.is_empty()) {
+                let (mut pos, mut d) = (end, 0);
+                for sym in self.needle.iter().rev() {
+                    match self.haystack.elem_before(pos) {
+                        Some((e, prev)) if prev >= self.front => {
+                            d += (*sym != e) as usize;
+                            pos = prev;
+                            if d > self.max_distance {
+                                break;
+                            }
+                        }
+                        _ => return None,
+                    }
+                }
+                if d <= self.max_distance {
+                    return Some((pos, end, d));
+                }
+            }
+            match self.haystack.elem_before(end) {
+                Some((_, prev)) if prev >= self.front => end = prev,
+                _ => return None,
+            }
+        }
+    }
+
+    fn levenshtein_front(&mut self, skip_empty: bool) -> Option<(usize, usize, usize)> {
+        self.forward.reset();
+        let m = self.needle.len();
+        let mut found = if m <= self.max_distance && !skip_empty {
+            Some((self.front, m))
+        } else {
+            None
+        };
+        let mut pos = self.front;
+        while let Some((e, next)) = self.haystack.elem_after(pos) {
+            if next > self.back {
+                break;
+            }
+            let symbol = self.symbol(&e);
+            self.forward.step(symbol);
+            pos = next;
+            let score = self.forward.score;
+            match found {
+                // the match ends once its distance stops decreasing
+                Some((_, best)) if score >= best => break,
+                Some(_) => found = Some((pos, score)),
+                None if score <= self.max_distance => found = Some((pos, score)),
+                None => {}
+            }
+        }
+        let (end, d) = found?;
+        let start = self.levenshtein_start(end, d);
+        Some((start, self.levenshtein_end(start, d), d))
+    }
+
+    fn levenshtein_back(&mut self, skip_empty: bool) -> Option<(usize, usize, usize)> {
+        self.backward.reset();
+        let m = self.needle.len();
+        let mut found = if m <= self.max_distance && !skip_empty {
+            Some((self.back, m))
+        } else {
+            None
+        };
+        let mut pos = self.back;
+        while let Some((e, prev)) = self.haystack.elem_before(pos) {
+            if prev < self.front {
+                break;
+            }
+            let symbol = self.symbol(&e);
+            self.backward.step(symbol);
+            pos = prev;
+            let score = self.backward.score;
+            match found {
+                Some((_, best)) if score >= best => break,
+                Some(_) => found = Some((pos, score)),
+                None if score <= self.max_distance => found = Some((pos, score)),
+                None => {}
+            }
+        }
+        let (start, d) = found?;
+        let end = self.levenshtein_end(start, d);
+        Some((self.levenshtein_start(end, d), end, d))
+    }
+
+    // The leftmost start, not before `front`, from which the haystack
+    // up to `end` has the distance `d` to the needle
+    fn levenshtein_start(&mut self, end: usize, d: usize) -> usize {
+        let m = self.needle.len();
+        // column[i]: distance of the last `i` needle elements to haystack[pos..end]
+        self.column.clear();
+        self.column.extend(0..m + 1);
+        let mut best = end;
+        let (mut pos, mut len) = (end, 0);
+        while let Some((e, prev)) = self.haystack.elem_before(pos) {
+            // longer candidates differ in more than `d` insertions
+            if prev < self.front || len == m + d {
+                break;
+            }
+            len += 1;
+            let mut diag = self.column[0];
+            self.column[0] = len;
+            for i in 1..m + 1 {
+                let sub = diag + (self.needle[m - i] != e) as usize;
+                let v = cmp::min(sub, cmp::min(self.column[i], self.column[i - 1]) + 1);
+                diag = self.column[i];
+                self.column[i] = v;
+            }
+            pos = prev;
+            if self.column[m] == d {
+                best = pos;
+            }
+            // the column minimum never decreases
+            if self.column.iter().all(|&v| v > d) {
+                break;
+            }
+        }
+        best
+    }
+
+    // The rightmost end, not after `back`, up to which the haystack
+    // from `start` has the distance `d` to the needle
+    fn levenshtein_end(&mut self, start: usize, d: usize) -> usize {
+        let m = self.needle.len();
+        self.column.clear();
+        self.column.extend(0..m + 1);
+        let mut best = start;
+        let (mut pos, mut len) = (start, 0);
+        while let Some((e, next)) = self.haystack.elem_after(pos) {
+            if next > self.back || len == m + d {
+                break;
+            }
+            len += 1;
+            let mut diag = self.column[0];
+            self.column[0] = len;
+            for i in 1..m + 1 {
+                let sub = diag + (self.needle[i - 1] != e) as usize;
+                let v = cmp::min(sub, cmp::min(self.column[i], self.column[i - 1]) + 1);
+                diag = self.column[i];
+                self.column[i] = v;
+            }
+            pos = next;
+            if self.column[m] == d {
+                best = pos;
+            }
+            if self.column.iter().all(|&v| v > d) {
+                break;
+            }
+        }
+        best
+    }
+
+    fn next(&mut self) -> SearchStep {
+        let skip_empty = self.empty_match_seen(self.front);
+        if self.front == self.back && skip_empty {
+            return SearchStep::Done;
+        }
+        let found = match self.pending_front.take() {
+            Some((a, b, d)) if b <= self.back && !(a == b && skip_empty) => Some((a, b, d)),
+            _ => self.find_front(skip_empty),
+        };
+        match found {
+            Some((a, b, d)) if a == self.front => {
+                self.distance = d;
+                if a == b {
+                    self.empty_front = Some(a);
+                } else {
+                    self.front = b;
+                }
+                SearchStep::Match(a, b)
+            }
+            Some((a, b, d)) => {
+                self.pending_front = Some((a, b, d));
+                let reject = SearchStep::Reject(self.front, a);
+                self.front = a;
+                reject
+            }
+            None if self.front == self.back => SearchStep::Done,
+            None => {
+                let reject = SearchStep::Reject(self.front, self.back);
+                self.front = self.back;
+                reject
+            }
+        }
+    }
+
+    fn next_back(&mut self) -> SearchStep {
+        let skip_empty = self.empty_match_seen(self.back);
+        if self.front == self.back && skip_empty {
+            return SearchStep::Done;
+        }
+        let found = match self.pending_back.take() {
+            Some((a, b, d)) if a >= self.front && !(a == b && skip_empty) => Some((a, b, d)),
+            _ => self.find_back(skip_empty),
+        };
+        match found {
+            Some((a, b, d)) if b == self.back => {
+                self.distance = d;
+                if a == b {
+                    self.empty_back = Some(b);
+                } else {
+                    self.back = a;
+                }
+                SearchStep::Match(a, b)
+            }
+            Some((a, b, d)) => {
+                self.pending_back = Some((a, b, d));
+                let reject = SearchStep::Reject(b, self.back);
+                self.back = b;
+                reject
+            }
+            None if self.front == self.back => SearchStep::Done,
+            None => {
+                let reject = SearchStep::Reject(self.front, self.back);
+                self.back = self.front;
+                reject
+            }
+        }
+    }
+
+    #[inline]
+    fn cursors(&self, a: usize, b: usize) -> (H::Cursor, H::Cursor) {
+        // the offsets are element boundaries of the haystack
+        unsafe { (self.haystack.cursor_at(a), self.haystack.cursor_at(b)) }
+    }
+}
+
+unsafe impl<H, S> Searcher<H> for ApproxSearcher<H, S>
+    where H: ApproxHaystack,
+          S: PartialEq<H::Elem> + PartialEq
+{
+    #[inline]
+    fn haystack(&self) -> H::Haystack {
+        self.haystack.into_haystack()
+    }
+
+    #[inline]
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<H>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(a, b) => return Some(self.cursors(a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some(self.cursors(a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+unsafe impl<H, S> ReverseSearcher<H> for ApproxSearcher<H, S>
+    where H: ApproxHaystack,
+          S: PartialEq<H::Elem> + PartialEq
+{
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(a, b) => return Some(self.cursors(a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    #[inline]
+    fn next_reject_back(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some(self.cursors(a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+impl<H, S> SeekableSearcher<H> for ApproxSearcher<H, S>
+    where H: ApproxHaystack,
+          S: PartialEq<H::Elem> + PartialEq
+{
+    fn seek_front(&mut self, offset: usize) -> bool {
+        let span = Span { start: offset, end: offset };
+        if offset > self.back || H::span_to_cursors(self.haystack(), span).is_none() {
+            return false;
+        }
+        self.front = offset;
+        self.pending_front = None;
+        self.empty_front = None;
+        true
+    }
+
+    fn seek_back(&mut self, offset: usize) -> bool {
+        let span = Span { start: offset, end: offset };
+        if offset < self.front || H::span_to_cursors(self.haystack(), span).is_none() {
+            return false;
+        }
+        self.back = offset;
+        self.pending_back = None;
+        self.empty_back = None;
+        true
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Iterators reporting distances
+///////////////////////////////////////////////////////////////////////////////
+
+/// Created with the function [`match_indices()`].
+///
+/// Yields the offset, the matched part and the distance of each match.
+#[derive(Clone, Debug)]
+pub struct MatchIndices<H, S>(ApproxSearcher<H, S>);
+
+/// Created with the function [`rmatch_indices()`].
+///
+/// Yields the offset, the matched part and the distance of each match,
+/// starting from the back.
+#[derive(Clone, Debug)]
+pub struct RMatchIndices<H, S>(ApproxSearcher<H, S>);
+
+/// Iterates over the approximate matches of `pattern` in `haystack`,
+/// together with their distance to the needle.
+pub fn match_indices<H, S, P>(haystack: H, pattern: P) -> MatchIndices<H, S>
+    where H: ApproxHaystack,
+          S: PartialEq<H::Elem> + PartialEq,
+          P: Pattern<H, Searcher = ApproxSearcher<H, S>>
+{
+    MatchIndices(pattern.into_searcher(haystack))
+}
+
+/// Iterates over the approximate matches of `pattern` in `haystack`
+/// from the back, together with their distance to the needle.
+pub fn rmatch_indices<H, S, P>(haystack: H, pattern: P) -> RMatchIndices<H, S>
+    where H: ApproxHaystack,
+          S: PartialEq<H::Elem> + PartialEq,
+          P: Pattern<H, Searcher = ApproxSearcher<H, S>>
+{
+    RMatchIndices(pattern.into_searcher(haystack))
+}
+
+impl<H, S> Iterator for MatchIndices<H, S>
+    where H: ApproxHaystack,
+          S: PartialEq<H::Elem> + PartialEq
+{
+    type Item = (usize, H::MatchType, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let hs = self.0.haystack();
+        self.0.next_match().map(|(a, b)| unsafe {
+            (H::offset_from_front(hs, a), H::range_to_self(hs, a, b), self.0.distance())
+        })
+    }
+}
+
+impl<H, S> Iterator for RMatchIndices<H, S>
+    where H: ApproxHaystack,
+          S: PartialEq<H::Elem> + PartialEq
+{
+    type Item = (usize, H::MatchType, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let hs = self.0.haystack();
+        self.0.next_match_back().map(|(a, b)| unsafe {
+            (H::offset_from_front(hs, a), H::range_to_self(hs, a, b), self.0.distance())
+        })
+    }
+}
@@ -0,0 +1,304 @@
+//! Type-erased patterns, for choosing the kind of pattern at runtime.
+//!
+//! `Pattern<H>` names its searcher as an associated type, so a `char`,
+//! a `&str` and a closure can not be stored in the same place.
+//! `BoxPattern` wraps any of them behind a trait object, and is a
+//! `Pattern<H>` itself, so it can be passed to all the functions of
+//! `IteratorConstructors`.
+//!
+//! Which searcher traits a `BoxPattern` supports is tracked by its
+//! last type parameter: `Forward`, `Reverse` or `DoubleEnded`,
+//! depending on the constructor it was created with. A pattern can be
+//! downgraded with `into_forward()` or `into_reverse()`, to store
+//! patterns of different capabilities in the same field.
+
+use ::{Pattern, PatternHaystack, PatternError};
+use ::{Searcher, ReverseSearcher, DoubleEndedSearcher, SeekableSearcher};
+
+use core::marker::PhantomData;
+use alloc::boxed::Box;
+
+///////////////////////////////////////////////////////////////////////////////
+// Object safe traits
+///////////////////////////////////////////////////////////////////////////////
+
+// Object safe counterpart of `Pattern<H>`, consuming a `Box<Self>`
+// instead of `self`.
+pub trait DynPattern<'a, H: PatternHaystack> {
+    fn into_dyn_searcher(self: Box<Self>, haystack: H) -> Box<dyn DynSearcher<H> + 'a>;
+    fn dyn_matches_at(self: Box<Self>, haystack: H, offset: usize) -> Option<usize>;
+    fn dyn_validate(&self) -> Result<(), PatternError>;
+}
+
+// A searcher behind a trait object, which gives access to
+// its `ReverseSearcher` impl if it has one.
+pub trait DynSearcher<H: PatternHaystack>: Searcher<H> {
+    fn as_reverse(&mut self) -> Option<&mut dyn ReverseSearcher<H>>;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Capabilities
+///////////////////////////////////////////////////////////////////////////////
+
+/// The wrapped searcher only implements `Searcher`.
+#[derive(Copy, Clone, Debug)]
+pub struct Forward;
+
+/// The wrapped searcher implements `ReverseSearcher`.
+#[derive(Copy, Clone, Debug)]
+pub struct Reverse;
+
+/// The wrapped searcher implements `DoubleEndedSearcher`.
+#[derive(Copy, Clone, Debug)]
+pub struct DoubleEnded;
+
+struct ForwardOnly<S>(S);
+struct Reversible<S>(S);
+
+unsafe impl<H, S> Searcher<H> for ForwardOnly<S>
+    where H: PatternHaystack,
+          S: Searcher<H>
+{
+    #[inline]
+    fn haystack(&self) -> H::Haystack {
+        self.0.haystack()
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        self.0.next_match()
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        self.0.next_reject()
+    }
+
+    #[inline]
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<H>> {
+        self.0.as_seekable()
+    }
+}
+
+impl<H, S> DynSearcher<H> for ForwardOnly<S>
+    where H: PatternHaystack,
+          S: Searcher<H>
+{
+    #[inline]
+    fn as_reverse(&mut self) -> Option<&mut dyn ReverseSearcher<H>> {
+        None
+    }
+}
+
+unsafe impl<H, S> Searcher<H> for Reversible<S>
+    where H: PatternHaystack,
+          S: ReverseSearcher<H>
+{
+    #[inline]
+    fn haystack(&self) -> H::Haystack {
+        self.0.haystack()
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        self.0.next_match()
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        self.0.next_reject()
+    }
+
+    #[inline]
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<H>> {
+        self.0.as_seekable()
+    }
+}
+
+impl<H, S> DynSearcher<H> for Reversible<S>
+    where H: PatternHaystack,
+          S: ReverseSearcher<H>
+{
+    #[inline]
+    fn as_reverse(&mut self) -> Option<&mut dyn ReverseSearcher<H>> {
+        Some(&mut self.0)
+    }
+}
+
+// Wraps the pattern, and picks the searcher wrapper for its capability
+struct Erased<P, K>(P, PhantomData<K>);
+
+impl<'a, H, P> DynPattern<'a, H> for Erased<P, Forward>
+    where H: PatternHaystack + 'a,
+          P: Pattern<H> + 'a
+{
+    fn into_dyn_searcher(self: Box<Self>, haystack: H) -> Box<dyn DynSearcher<H> + 'a> {
+        Box::new(ForwardOnly(self.0.into_searcher(haystack)))
+    }
+
+    fn dyn_matches_at(self: Box<Self>, haystack: H, offset: usize) -> Option<usize> {
+        self.0.matches_at(haystack, offset)
+    }
+
+    fn dyn_validate(&self) -> Result<(), PatternError> {
+        self.0.validate()
+    }
+}
+
+impl<'a, H, P> DynPattern<'a, H> for Erased<P, Reverse>
+    where H: PatternHaystack + 'a,
+          P: Pattern<H> + 'a,
+          P::Searcher: ReverseSearcher<H>
+{
+    fn into_dyn_searcher(self: Box<Self>, haystack: H) -> Box<dyn DynSearcher<H> + 'a> {
+        Box::new(Reversible(self.0.into_searcher(haystack)))
+    }
+
+    fn dyn_matches_at(self: Box<Self>, haystack: H, offset: usize) -> Option<usize> {
+        self.0.matches_at(haystack, offset)
+    }
+
+    fn dyn_validate(&self) -> Result<(), PatternError> {
+        self.0.validate()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// BoxPattern
+///////////////////////////////////////////////////////////////////////////////
+
+/// A pattern of any type, chosen at runtime.
+pub struct BoxPattern<'a, H: PatternHaystack, K = Forward> {
+    pattern: Box<dyn DynPattern<'a, H> + 'a>,
+    _kind: PhantomData<K>,
+}
+
+impl<'a, H: PatternHaystack + 'a> BoxPattern<'a, H, Forward> {
+    pub fn new<P: Pattern<H> + 'a>(pattern: P) -> Self {
+        BoxPattern {
+            pattern: Box::new(Erased(pattern, PhantomData::<Forward>)),
+            _kind: PhantomData,
+        }
+    }
+}
+
+impl<'a, H: PatternHaystack + 'a> BoxPattern<'a, H, Reverse> {
+    pub fn new_reverse<P: Pattern<H> + 'a>(pattern: P) -> Self
+        where P::Searcher: ReverseSearcher<H>
+    {
+        BoxPattern {
+            pattern: Box::new(Erased(pattern, PhantomData::<Reverse>)),
+            _kind: PhantomData,
+        }
+    }
+}
+
+impl<'a, H: PatternHaystack + 'a> BoxPattern<'a, H, DoubleEnded> {
+    pub fn new_double_ended<P: Pattern<H> + 'a>(pattern: P) -> Self
+        where P::Searcher: DoubleEndedSearcher<H>
+    {
+        BoxPattern {
+            pattern: Box::new(Erased(pattern, PhantomData::<Reverse>)),
+            _kind: PhantomData,
+        }
+    }
+
+    pub fn into_reverse(self) -> BoxPattern<'a, H, Reverse> {
+        BoxPattern { pattern: self.pattern, _kind: PhantomData }
+    }
+}
+
+impl<'a, H: PatternHaystack, K> BoxPattern<'a, H, K> {
+    pub fn into_forward(self) -> BoxPattern<'a, H, Forward> {
+        BoxPattern { pattern: self.pattern, _kind: PhantomData }
+    }
+}
+
+impl<'a, H: PatternHaystack, K> Pattern<H> for BoxPattern<'a, H, K> {
+    type Searcher = BoxSearcher<'a, H, K>;
+
+    #[inline]
+    fn into_searcher(self, haystack: H) -> Self::Searcher {
+        BoxSearcher {
+            searcher: self.pattern.into_dyn_searcher(haystack),
+            _kind: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn matches_at(self, haystack: H, offset: usize) -> Option<usize> {
+        self.pattern.dyn_matches_at(haystack, offset)
+    }
+
+    #[inline]
+    fn validate(&self) -> Result<(), PatternError> {
+        self.pattern.dyn_validate()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// BoxSearcher
+///////////////////////////////////////////////////////////////////////////////
+
+/// Associated type for `<BoxPattern<'a, H, K> as Pattern<H>>::Searcher`.
+pub struct BoxSearcher<'a, H: PatternHaystack, K> {
+    searcher: Box<dyn DynSearcher<H> + 'a>,
+    _kind: PhantomData<K>,
+}
+
+impl<'a, H: PatternHaystack, K> BoxSearcher<'a, H, K> {
+    #[inline]
+    fn reverse(&mut self) -> &mut dyn ReverseSearcher<H> {
+        // Only reachable for the `Reverse` and `DoubleEnded` kinds,
+        // whose constructors wrap the searcher in `Reversible`
+        self.searcher.as_reverse().expect("BoxPattern can not search in reverse")
+    }
+}
+
+unsafe impl<'a, H: PatternHaystack, K> Searcher<H> for BoxSearcher<'a, H, K> {
+    #[inline]
+    fn haystack(&self) -> H::Haystack {
+        self.searcher.haystack()
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        self.searcher.next_match()
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        self.searcher.next_reject()
+    }
+
+    #[inline]
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<H>> {
+        self.searcher.as_seekable()
+    }
+}
+
+unsafe impl<'a, H: PatternHaystack> ReverseSearcher<H> for BoxSearcher<'a, H, Reverse> {
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        self.reverse().next_match_back()
+    }
+
+    #[inline]
+    fn next_reject_back(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        self.reverse().next_reject_back()
+    }
+}
+
+unsafe impl<'a, H: PatternHaystack> ReverseSearcher<H> for BoxSearcher<'a, H, DoubleEnded> {
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        self.reverse().next_match_back()
+    }
+
+    #[inline]
+    fn next_reject_back(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        self.reverse().next_reject_back()
+    }
+}
+
+impl<'a, H: PatternHaystack> DoubleEndedSearcher<H> for BoxSearcher<'a, H, DoubleEnded> {}
@@ -0,0 +1,843 @@
+//! Text search in byte slices that are mostly, but not necessarily
+//! completely, UTF-8.
+//!
+//! `PartialUnicode` wraps a `&[u8]` or `&mut [u8]` so that the text
+//! patterns - `char`, `&str`, `&[char]` and `char` predicates - can be
+//! used on it, with the matches returned as `str`. Bytes that are not
+//! part of a valid UTF-8 sequence are never part of a match. Depending
+//! on the `InvalidUtf8Mode`, they are either skipped, or reported as
+//! rejects, one for each maximal run of invalid bytes.
+
+use utf8;
+
+use core::slice;
+
+/// How a search in a `PartialUnicode` haystack treats bytes that are
+/// not part of a valid UTF-8 sequence.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InvalidUtf8Mode {
+    /// Invalid bytes are neither matches nor rejects, so every range
+    /// returned by a searcher is valid UTF-8.
+    Skip,
+    /// Each maximal run of invalid bytes is returned as a reject of its own,
+    /// so matches and rejects together cover the whole haystack.
+    Reject,
+}
+
+/// Splits a byte range into pieces that are either a single valid
+/// char, or a maximal run of invalid bytes.
+#[derive(Copy, Clone)]
+struct Pieces {
+    start: *const u8,
+    len: usize,
+    front: usize,
+    back: usize,
+}
+
+// Pieces only reads the bytes between `front` and `back`, and the
+// searchers never return a range there. So for a `&mut [u8]` haystack,
+// reading them never overlaps with writes through the returned matches,
+// and can happen from any thread, just as with a `&[u8]`.
+unsafe impl Send for Pieces {}
+unsafe impl Sync for Pieces {}
+
+impl Pieces {
+    #[inline]
+    fn new(start: *const u8, end: *const u8) -> Pieces {
+        let len = end as usize - start as usize;
+        Pieces {
+            start: start,
+            len: len,
+            front: 0,
+            back: len,
+        }
+    }
+
+    /// The same haystack, restricted to the offsets `front..back`,
+    /// which need to be at piece boundaries.
+    #[inline]
+    fn restricted(mut self, front: usize, back: usize) -> Pieces {
+        self.front = front;
+        self.back = back;
+        self
+    }
+
+    /// The bytes between `front` and `back`.
+    #[inline]
+    fn bytes(&self) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(self.start.offset(self.front as isize), self.back - self.front)
+        }
+    }
+
+    /// Returns the offsets of the next piece, and its char if it is valid.
+    #[inline]
+    fn next(&mut self) -> Option<(usize, usize, Option<char>)> {
+        if self.front == self.back {
+            return None;
+        }
+        let a = self.front;
+        let (b, c) = match utf8::char_at(self.bytes(), 0) {
+            Some((c, width)) => (a + width, Some(c)),
+            None => (a + utf8::invalid_run_end(self.bytes(), 0), None),
+        };
+        self.front = b;
+        Some((a, b, c))
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, usize, Option<char>)> {
+        if self.front == self.back {
+            return None;
+        }
+        let b = self.back;
+        let len = b - self.front;
+        let (a, c) = match utf8::char_before(self.bytes(), len) {
+            Some(c) => (b - c.len_utf8(), Some(c)),
+            None => (self.front + utf8::invalid_run_start(self.bytes(), len), None),
+        };
+        self.back = a;
+        Some((a, b, c))
+    }
+
+    /// Like `next()`, but joins consecutive valid chars into one piece.
+    fn next_run(&mut self) -> Option<(usize, usize, bool)> {
+        let (a, mut b, c) = self.next()?;
+        if c.is_some() {
+            let mut rest = *self;
+            while let Some((_, end, Some(_))) = rest.next() {
+                b = end;
+                *self = rest;
+            }
+        }
+        Some((a, b, c.is_some()))
+    }
+
+    fn next_run_back(&mut self) -> Option<(usize, usize, bool)> {
+        let (mut a, b, c) = self.next_back()?;
+        if c.is_some() {
+            let mut rest = *self;
+            while let Some((start, _, Some(_))) = rest.next_back() {
+                a = start;
+                *self = rest;
+            }
+        }
+        Some((a, b, c.is_some()))
+    }
+}
+
+macro_rules! impl_both_mutability {
+    ($module:ident, $slice:ty,
+                    $cursor:ty,
+                    $str_slice:ty,
+                    $bytes_to_str:expr) => {
+        pub mod $module {
+            use core_traits::*;
+            use utf8::{self, CharEq, CharEqPattern};
+            use core::ops::{Range, RangeInclusive};
+            #[cfg(feature = "alloc")]
+            use sets::CharSet;
+            use char_classes::CharClass;
+            use super::{InvalidUtf8Mode, Pieces};
+
+            /// A byte slice that is searched as UTF-8 text.
+            pub struct PartialUnicode<'a> {
+                pub bytes: $slice,
+                pub invalid: InvalidUtf8Mode,
+            }
+
+            impl<'a> PartialUnicode<'a> {
+                /// Wraps `bytes`, skipping invalid UTF-8.
+                #[inline]
+                pub fn new(bytes: $slice) -> Self {
+                    PartialUnicode {
+                        bytes: bytes,
+                        invalid: InvalidUtf8Mode::Skip,
+                    }
+                }
+
+                /// Reports each run of invalid UTF-8 as a reject
+                /// instead of skipping it.
+                #[inline]
+                pub fn rejecting_invalid(self) -> Self {
+                    PartialUnicode {
+                        bytes: self.bytes,
+                        invalid: InvalidUtf8Mode::Reject,
+                    }
+                }
+            }
+
+            impl<'a> PatternHaystack for PartialUnicode<'a> {
+                type Haystack = ($cursor, $cursor);
+                type Cursor = $cursor;
+                type MatchType = $str_slice;
+
+                fn into_haystack(self) -> Self::Haystack {
+                    self.bytes.into_haystack()
+                }
+
+                fn offset_from_front(haystack: Self::Haystack,
+                                     begin: Self::Cursor) -> usize {
+                    <$slice>::offset_from_front(haystack, begin)
+                }
+
+                unsafe fn range_to_self(h: Self::Haystack,
+                                        start: Self::Cursor,
+                                        end: Self::Cursor) -> Self::MatchType {
+                    let s = <$slice>::range_to_self(h, start, end);
+                    $bytes_to_str(s)
+                }
+                fn cursor_at_front(hs: Self::Haystack) -> Self::Cursor {
+                    <$slice>::cursor_at_front(hs)
+                }
+                fn cursor_at_back(hs: Self::Haystack) -> Self::Cursor {
+                    <$slice>::cursor_at_back(hs)
+                }
+                fn match_type_len(mt: &Self::MatchType) -> usize { mt.len() }
+
+                /// Only spans of valid UTF-8 can be turned into a `str`.
+                fn span_to_cursors(hs: Self::Haystack,
+                                   span: Span) -> Option<(Self::Cursor, Self::Cursor)> {
+                    <$slice>::span_to_cursors(hs, span).and_then(|(a, b)| {
+                        let bytes = unsafe {
+                            ::core::slice::from_raw_parts(a as *const u8,
+                                                          b as usize - a as usize)
+                        };
+                        ::core::str::from_utf8(bytes).ok().map(|_| (a, b))
+                    })
+                }
+            }
+
+            /// Lines may contain invalid unicode, so they are returned as
+            /// the wrapped byte slice rather than as `str`.
+            impl<'a> ::lines::LineHaystack for PartialUnicode<'a> {
+                type Line = $slice;
+
+                fn haystack_as_bytes(hs: &Self::Haystack) -> &[u8] {
+                    <$slice as ::lines::LineHaystack>::haystack_as_bytes(hs)
+                }
+
+                unsafe fn line_from_range(hs: Self::Haystack,
+                                          start: usize,
+                                          end: usize) -> $slice {
+                    <$slice as ::lines::LineHaystack>::line_from_range(hs, start, end)
+                }
+            }
+
+            #[inline]
+            fn cursors(hs: ($cursor, $cursor),
+                       (a, b): (usize, usize)) -> ($cursor, $cursor) {
+                unsafe {
+                    (hs.0.offset(a as isize), hs.0.offset(b as isize))
+                }
+            }
+
+            //////////////////////////////////////////////////////////////////
+            // Impl for a CharEq wrapper
+            //////////////////////////////////////////////////////////////////
+
+            #[derive(Clone)]
+            pub struct CharEqSearcher<'a, C: CharEq> {
+                char_eq: C,
+                haystack: ($cursor, $cursor),
+                pieces: Pieces,
+                invalid: InvalidUtf8Mode,
+                _marker: ::core::marker::PhantomData<$slice>,
+            }
+
+            // All reads of the haystack go through `pieces`
+            unsafe impl<'a, C: CharEq + Send> Send for CharEqSearcher<'a, C>
+                where $slice: Send {}
+            unsafe impl<'a, C: CharEq + Sync> Sync for CharEqSearcher<'a, C>
+                where $slice: Sync {}
+
+            impl<'a, C: CharEq> CharEqSearcher<'a, C> {
+                #[inline]
+                fn is_reject(&mut self, c: Option<char>) -> bool {
+                    match c {
+                        Some(c) => !self.char_eq.matches(c),
+                        None => self.invalid == InvalidUtf8Mode::Reject,
+                    }
+                }
+            }
+
+            impl<'a, C: CharEq> Pattern<PartialUnicode<'a>> for CharEqPattern<C> {
+                type Searcher = CharEqSearcher<'a, C>;
+
+                #[inline]
+                fn into_searcher(self, haystack: PartialUnicode<'a>) -> CharEqSearcher<'a, C> {
+                    let invalid = haystack.invalid;
+                    let hs = haystack.into_haystack();
+                    CharEqSearcher {
+                        char_eq: self.0,
+                        haystack: hs,
+                        pieces: Pieces::new(hs.0, hs.1),
+                        invalid: invalid,
+                        _marker: ::core::marker::PhantomData,
+                    }
+                }
+
+                fn matches_at(mut self, haystack: PartialUnicode<'a>, offset: usize) -> Option<usize> {
+                    let hs = haystack.into_haystack();
+                    let bytes = <PartialUnicode as OrdSlice>::haystack_as_slice(&hs);
+                    if offset >= bytes.len() {
+                        return None;
+                    }
+                    match utf8::char_at(bytes, offset) {
+                        Some((c, width)) if self.0.matches(c) => Some(offset + width),
+                        _ => None,
+                    }
+                }
+            }
+
+            unsafe impl<'a, C: CharEq> Searcher<PartialUnicode<'a>> for CharEqSearcher<'a, C> {
+                #[inline]
+                fn haystack(&self) -> ($cursor, $cursor) {
+                    self.haystack
+                }
+
+                #[inline]
+                fn next_match(&mut self) -> Option<($cursor, $cursor)> {
+                    while let Some((a, b, c)) = self.pieces.next() {
+                        if let Some(c) = c {
+                            if self.char_eq.matches(c) {
+                                return Some(cursors(self.haystack, (a, b)));
+                            }
+                        }
+                    }
+                    None
+                }
+
+                #[inline]
+                fn next_reject(&mut self) -> Option<($cursor, $cursor)> {
+                    while let Some((a, b, c)) = self.pieces.next() {
+                        if self.is_reject(c) {
+                            return Some(cursors(self.haystack, (a, b)));
+                        }
+                    }
+                    None
+                }
+            }
+
+            unsafe impl<'a, C: CharEq> ReverseSearcher<PartialUnicode<'a>> for CharEqSearcher<'a, C> {
+                #[inline]
+                fn next_match_back(&mut self) -> Option<($cursor, $cursor)> {
+                    while let Some((a, b, c)) = self.pieces.next_back() {
+                        if let Some(c) = c {
+                            if self.char_eq.matches(c) {
+                                return Some(cursors(self.haystack, (a, b)));
+                            }
+                        }
+                    }
+                    None
+                }
+
+                #[inline]
+                fn next_reject_back(&mut self) -> Option<($cursor, $cursor)> {
+                    while let Some((a, b, c)) = self.pieces.next_back() {
+                        if self.is_reject(c) {
+                            return Some(cursors(self.haystack, (a, b)));
+                        }
+                    }
+                    None
+                }
+            }
+
+            impl<'a, C: CharEq> DoubleEndedSearcher<PartialUnicode<'a>> for CharEqSearcher<'a, C> {}
+
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for char
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<char as Pattern<PartialUnicode<'a>>>::Searcher`.
+            #[derive(Clone)]
+            pub struct CharSearcher<'a>(CharEqSearcher<'a, char>);
+
+            unsafe impl<'a> Searcher<PartialUnicode<'a>> for CharSearcher<'a> {
+                searcher_methods!(forward, s, s.0, $cursor, PartialUnicode<'a>);
+            }
+
+            unsafe impl<'a> ReverseSearcher<PartialUnicode<'a>> for CharSearcher<'a> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a> DoubleEndedSearcher<PartialUnicode<'a>> for CharSearcher<'a> {}
+
+            /// Searches for chars that are equal to a given char
+            impl<'a> Pattern<PartialUnicode<'a>> for char {
+                pattern_methods!(CharSearcher<'a>, CharEqPattern, CharSearcher,
+                                 PartialUnicode<'a>, |s| s);
+            }
+
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for F: FnMut(char) -> bool
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<F as Pattern<PartialUnicode<'a>>>::Searcher`.
+            #[derive(Clone)]
+            pub struct CharPredicateSearcher<'a, F>(CharEqSearcher<'a, F>)
+                where F: FnMut(char) -> bool;
+
+            unsafe impl<'a, F> Searcher<PartialUnicode<'a>> for CharPredicateSearcher<'a, F>
+                where F: FnMut(char) -> bool
+            {
+                searcher_methods!(forward, s, s.0, $cursor, PartialUnicode<'a>);
+            }
+
+            unsafe impl<'a, F> ReverseSearcher<PartialUnicode<'a>> for CharPredicateSearcher<'a, F>
+                where F: FnMut(char) -> bool
+            {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a, F> DoubleEndedSearcher<PartialUnicode<'a>> for CharPredicateSearcher<'a, F>
+                where F: FnMut(char) -> bool {}
+
+            /// Searches for chars that match the given predicate
+            impl<'a, F> Pattern<PartialUnicode<'a>> for F where F: FnMut(char) -> bool {
+                pattern_methods!(CharPredicateSearcher<'a, F>, CharEqPattern, CharPredicateSearcher,
+                                 PartialUnicode<'a>, |s| s);
+            }
+
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for &[char]
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<&[char] as Pattern<PartialUnicode<'a>>>::Searcher`.
+            #[derive(Clone)]
+            pub struct CharSliceSearcher<'a, 'b>(CharEqSearcher<'a, &'b [char]>);
+
+            unsafe impl<'a, 'b> Searcher<PartialUnicode<'a>> for CharSliceSearcher<'a, 'b> {
+                searcher_methods!(forward, s, s.0, $cursor, PartialUnicode<'a>);
+            }
+
+            unsafe impl<'a, 'b> ReverseSearcher<PartialUnicode<'a>> for CharSliceSearcher<'a, 'b> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a, 'b> DoubleEndedSearcher<PartialUnicode<'a>> for CharSliceSearcher<'a, 'b> {}
+
+            /// Searches for chars that are equal to any of the chars in the array
+            impl<'a, 'b> Pattern<PartialUnicode<'a>> for &'b [char] {
+                pattern_methods!(CharSliceSearcher<'a, 'b>, CharEqPattern, CharSliceSearcher,
+                                 PartialUnicode<'a>, |s| s);
+            }
+
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for char ranges
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<RangeInclusive<char> as Pattern<PartialUnicode<'a>>>::Searcher`,
+            /// and for the other char ranges.
+            #[derive(Clone)]
+            pub struct CharRangeSearcher<'a, R: CharEq>(CharEqSearcher<'a, R>);
+
+            unsafe impl<'a, R: CharEq> Searcher<PartialUnicode<'a>> for CharRangeSearcher<'a, R> {
+                searcher_methods!(forward, s, s.0, $cursor, PartialUnicode<'a>);
+            }
+
+            unsafe impl<'a, R: CharEq> ReverseSearcher<PartialUnicode<'a>> for CharRangeSearcher<'a, R> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a, R: CharEq> DoubleEndedSearcher<PartialUnicode<'a>> for CharRangeSearcher<'a, R> {}
+
+            /// Searches for chars inside of the range
+            impl<'a> Pattern<PartialUnicode<'a>> for RangeInclusive<char> {
+                pattern_methods!(CharRangeSearcher<'a, RangeInclusive<char>>, CharEqPattern,
+                                 CharRangeSearcher, PartialUnicode<'a>, |s| s);
+            }
+
+            /// Searches for chars inside of the range
+            impl<'a> Pattern<PartialUnicode<'a>> for Range<char> {
+                pattern_methods!(CharRangeSearcher<'a, Range<char>>, CharEqPattern,
+                                 CharRangeSearcher, PartialUnicode<'a>, |s| s);
+            }
+
+            /// Searches for chars inside of any of the ranges in the array
+            impl<'a, 'b> Pattern<PartialUnicode<'a>> for &'b [RangeInclusive<char>] {
+                pattern_methods!(CharRangeSearcher<'a, &'b [RangeInclusive<char>]>, CharEqPattern,
+                                 CharRangeSearcher, PartialUnicode<'a>, |s| s);
+            }
+
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for &CharSet
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<&CharSet as Pattern<PartialUnicode<'a>>>::Searcher`.
+            #[cfg(feature = "alloc")]
+            #[derive(Clone)]
+            pub struct CharSetSearcher<'a, 'b>(CharEqSearcher<'a, &'b CharSet>);
+
+            #[cfg(feature = "alloc")]
+            unsafe impl<'a, 'b> Searcher<PartialUnicode<'a>> for CharSetSearcher<'a, 'b> {
+                searcher_methods!(forward, s, s.0, $cursor, PartialUnicode<'a>);
+            }
+
+            #[cfg(feature = "alloc")]
+            unsafe impl<'a, 'b> ReverseSearcher<PartialUnicode<'a>> for CharSetSearcher<'a, 'b> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            #[cfg(feature = "alloc")]
+            impl<'a, 'b> DoubleEndedSearcher<PartialUnicode<'a>> for CharSetSearcher<'a, 'b> {}
+
+            /// Searches for chars that are in the set
+            #[cfg(feature = "alloc")]
+            impl<'a, 'b> Pattern<PartialUnicode<'a>> for &'b CharSet {
+                pattern_methods!(CharSetSearcher<'a, 'b>, CharEqPattern,
+                                 CharSetSearcher, PartialUnicode<'a>, |s| s);
+            }
+
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for CharClass
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<CharClass as Pattern<PartialUnicode<'a>>>::Searcher`.
+            #[derive(Clone)]
+            pub struct CharClassSearcher<'a>(CharEqSearcher<'a, CharClass>);
+
+            unsafe impl<'a> Searcher<PartialUnicode<'a>> for CharClassSearcher<'a> {
+                searcher_methods!(forward, s, s.0, $cursor, PartialUnicode<'a>);
+            }
+
+            unsafe impl<'a> ReverseSearcher<PartialUnicode<'a>> for CharClassSearcher<'a> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a> DoubleEndedSearcher<PartialUnicode<'a>> for CharClassSearcher<'a> {}
+
+            /// Searches for chars that are in the class
+            impl<'a> Pattern<PartialUnicode<'a>> for CharClass {
+                pattern_methods!(CharClassSearcher<'a>, CharEqPattern,
+                                 CharClassSearcher, PartialUnicode<'a>, |s| s);
+            }
+
+            ////////////////////////////////////////////////////////////////////
+            // Impl for &str
+            ////////////////////////////////////////////////////////////////////
+
+            use fast_sequence_search::{OrdSlice, OrdSlicePattern, OrdSeqSearcher};
+            use fast_sequence_search::ByteOptimization;
+
+            /// A run of invalid bytes counts as a single element,
+            /// so `""` matches around it, but not inside of it.
+            impl<'a> OrdSlice for PartialUnicode<'a> {
+                type NeedleElement = u8;
+                type FastSkipOptimization = ByteOptimization;
+
+                fn next_valid_pos(hs: &Self::Haystack, pos: usize) -> Option<usize> {
+                    Pieces::new(hs.0, hs.1).restricted(pos, hs.1 as usize - hs.0 as usize)
+                                           .next()
+                                           .map(|(_, b, _)| b)
+                }
+
+                fn next_valid_pos_back(hs: &Self::Haystack, pos: usize) -> Option<usize> {
+                    Pieces::new(hs.0, hs.1).restricted(0, pos)
+                                           .next_back()
+                                           .map(|(a, _, _)| a)
+                }
+
+                fn haystack_as_slice<'t>(hs: &'t Self::Haystack) -> &'t [Self::NeedleElement] {
+                    unsafe {
+                        ::core::slice::from_raw_parts(hs.0, hs.1 as usize - hs.0 as usize)
+                    }
+                }
+
+                fn pos_is_valid(hs: &Self::Haystack, pos: usize) -> bool {
+                    let s = Self::haystack_as_slice(hs);
+                    pos == 0 || pos >= s.len() || !utf8::is_inside_char(s, pos) && (
+                        utf8::char_before(s, pos).is_some() || utf8::char_at(s, pos).is_some())
+                }
+
+                unsafe fn cursor_at_offset(hs: Self::Haystack, offset: usize) -> Self::Cursor {
+                    hs.0.offset(offset as isize)
+                }
+            }
+
+            /// Associated type for `<&str as Pattern<PartialUnicode<'a>>>::Searcher`.
+            ///
+            /// The rejects of the substring search can span both text and
+            /// invalid bytes, so they are split up before being returned.
+            pub struct StrSearcher<'a, 'b> {
+                searcher: OrdSeqSearcher<'b, PartialUnicode<'a>>,
+                invalid: InvalidUtf8Mode,
+                front: Pieces,
+                back: Pieces,
+            }
+
+            impl<'a, 'b> StrSearcher<'a, 'b> {
+                #[inline]
+                fn pieces(&self, (a, b): ($cursor, $cursor)) -> Pieces {
+                    let hs = self.searcher.haystack();
+                    Pieces::new(hs.0, hs.1).restricted(
+                        PartialUnicode::offset_from_front(hs, a),
+                        PartialUnicode::offset_from_front(hs, b))
+                }
+            }
+
+            /// Non-allocating substring search.
+            ///
+            /// Will handle the pattern `""` as returning empty matches at each character
+            /// boundary.
+            impl<'a, 'b> Pattern<PartialUnicode<'a>> for &'b str {
+                type Searcher = StrSearcher<'a, 'b>;
+
+                #[inline]
+                fn into_searcher(self, haystack: PartialUnicode<'a>) -> StrSearcher<'a, 'b> {
+                    let invalid = haystack.invalid;
+                    let searcher = OrdSlicePattern(self.as_bytes()).into_searcher(haystack);
+                    let hs = searcher.haystack();
+                    StrSearcher {
+                        searcher: searcher,
+                        invalid: invalid,
+                        front: Pieces::new(hs.0, hs.0),
+                        back: Pieces::new(hs.0, hs.0),
+                    }
+                }
+
+                #[inline]
+                fn is_contained_in(self, haystack: PartialUnicode<'a>) -> bool {
+                    OrdSlicePattern(self.as_bytes()).is_contained_in(haystack)
+                }
+
+                #[inline]
+                fn is_prefix_of(self, haystack: PartialUnicode<'a>) -> bool {
+                    OrdSlicePattern(self.as_bytes()).is_prefix_of(haystack)
+                }
+
+                #[inline]
+                fn is_suffix_of(self, haystack: PartialUnicode<'a>) -> bool {
+                    OrdSlicePattern(self.as_bytes()).is_suffix_of(haystack)
+                }
+
+                #[inline]
+                fn matches_at(self, haystack: PartialUnicode<'a>, offset: usize) -> Option<usize> {
+                    OrdSlicePattern(self.as_bytes()).matches_at(haystack, offset)
+                }
+            }
+
+            unsafe impl<'a, 'b> Searcher<PartialUnicode<'a>> for StrSearcher<'a, 'b> {
+                #[inline]
+                fn haystack(&self) -> ($cursor, $cursor) {
+                    self.searcher.haystack()
+                }
+
+                #[inline]
+                fn next_match(&mut self) -> Option<($cursor, $cursor)> {
+                    self.searcher.next_match()
+                }
+
+                fn next_reject(&mut self) -> Option<($cursor, $cursor)> {
+                    loop {
+                        while let Some((a, b, valid)) = self.front.next_run() {
+                            if valid || self.invalid == InvalidUtf8Mode::Reject {
+                                return Some(cursors(self.haystack(), (a, b)));
+                            }
+                        }
+                        match self.searcher.next_reject() {
+                            Some(reject) => self.front = self.pieces(reject),
+                            None => return None,
+                        }
+                    }
+                }
+            }
+
+            unsafe impl<'a, 'b> ReverseSearcher<PartialUnicode<'a>> for StrSearcher<'a, 'b> {
+                #[inline]
+                fn next_match_back(&mut self) -> Option<($cursor, $cursor)> {
+                    self.searcher.next_match_back()
+                }
+
+                fn next_reject_back(&mut self) -> Option<($cursor, $cursor)> {
+                    loop {
+                        while let Some((a, b, valid)) = self.back.next_run_back() {
+                            if valid || self.invalid == InvalidUtf8Mode::Reject {
+                                return Some(cursors(self.haystack(), (a, b)));
+                            }
+                        }
+                        match self.searcher.next_reject_back() {
+                            Some(reject) => self.back = self.pieces(reject),
+                            None => return None,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl_both_mutability!(shared, &'a [u8], *const u8, &'a str,
+                      |s| ::core::str::from_utf8_unchecked(s));
+
+impl_both_mutability!(mutable, &'a mut [u8], *mut u8, &'a mut str,
+                      |s| ::core::str::from_utf8_unchecked_mut(s));
+
+unsafe impl<'a> ::MutableHaystack for mutable::PartialUnicode<'a> {}
+
+///////////////////////////////////////////////////////////////////////////////
+// Invalid UTF-8
+///////////////////////////////////////////////////////////////////////////////
+
+use core_traits::*;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// An iterator over the byte ranges of a haystack that are not valid UTF-8,
+/// one for each maximal run of invalid bytes.
+#[derive(Clone)]
+pub struct InvalidUtf8Ranges<'a> {
+    pieces: Pieces,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a> InvalidUtf8Ranges<'a> {
+    #[inline]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        let (start, end) = bytes.into_haystack();
+        InvalidUtf8Ranges {
+            pieces: Pieces::new(start, end),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> Iterator for InvalidUtf8Ranges<'a> {
+    type Item = Range<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Range<usize>> {
+        while let Some((a, b, valid)) = self.pieces.next_run() {
+            if !valid {
+                return Some(a..b);
+            }
+        }
+        None
+    }
+}
+
+impl<'a> DoubleEndedIterator for InvalidUtf8Ranges<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Range<usize>> {
+        while let Some((a, b, valid)) = self.pieces.next_run_back() {
+            if !valid {
+                return Some(a..b);
+            }
+        }
+        None
+    }
+}
+
+/// Matches each maximal run of bytes that are not valid UTF-8.
+///
+/// The text between the runs is rejected, so `split(InvalidUtf8)` yields
+/// the valid parts and `trim_matches(InvalidUtf8)` removes invalid bytes
+/// at both ends.
+#[derive(Copy, Clone, Debug)]
+pub struct InvalidUtf8;
+
+/// Associated type for `<InvalidUtf8 as Pattern<&'a [u8]>>::Searcher`.
+#[derive(Clone)]
+pub struct InvalidUtf8Searcher<'a> {
+    pieces: Pieces,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a> InvalidUtf8Searcher<'a> {
+    #[inline]
+    fn next_run(&mut self, valid: bool) -> Option<(*const u8, *const u8)> {
+        while let Some((a, b, v)) = self.pieces.next_run() {
+            if v == valid {
+                return Some(self.cursors(a, b));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn next_run_back(&mut self, valid: bool) -> Option<(*const u8, *const u8)> {
+        while let Some((a, b, v)) = self.pieces.next_run_back() {
+            if v == valid {
+                return Some(self.cursors(a, b));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn cursors(&self, a: usize, b: usize) -> (*const u8, *const u8) {
+        unsafe {
+            (self.pieces.start.offset(a as isize), self.pieces.start.offset(b as isize))
+        }
+    }
+}
+
+macro_rules! invalid_utf8_impls {
+    ($haystack:ty, $cursor:ty) => {
+        impl<'a> Pattern<$haystack> for InvalidUtf8 {
+            type Searcher = InvalidUtf8Searcher<'a>;
+
+            #[inline]
+            fn into_searcher(self, haystack: $haystack) -> InvalidUtf8Searcher<'a> {
+                let (start, end) = haystack.into_haystack();
+                InvalidUtf8Searcher {
+                    pieces: Pieces::new(start, end),
+                    _marker: PhantomData,
+                }
+            }
+
+            // The runs are maximal, so one can only start
+            // after a valid char
+            fn matches_at(self, haystack: $haystack, offset: usize) -> Option<usize> {
+                let (start, end) = haystack.into_haystack();
+                let pieces = Pieces::new(start, end);
+                let bytes = pieces.bytes();
+                if offset >= bytes.len() || utf8::char_at(bytes, offset).is_some()
+                    || (offset > 0 && utf8::char_before(bytes, offset).is_none())
+                {
+                    return None;
+                }
+                Some(utf8::invalid_run_end(bytes, offset))
+            }
+        }
+
+        unsafe impl<'a> Searcher<$haystack> for InvalidUtf8Searcher<'a> {
+            #[inline]
+            fn haystack(&self) -> ($cursor, $cursor) {
+                let (start, end) = self.cursors(0, self.pieces.len);
+                (start as $cursor, end as $cursor)
+            }
+
+            #[inline]
+            fn next_match(&mut self) -> Option<($cursor, $cursor)> {
+                self.next_run(false).map(|(a, b)| (a as $cursor, b as $cursor))
+            }
+
+            #[inline]
+            fn next_reject(&mut self) -> Option<($cursor, $cursor)> {
+                self.next_run(true).map(|(a, b)| (a as $cursor, b as $cursor))
+            }
+        }
+
+        unsafe impl<'a> ReverseSearcher<$haystack> for InvalidUtf8Searcher<'a> {
+            #[inline]
+            fn next_match_back(&mut self) -> Option<($cursor, $cursor)> {
+                self.next_run_back(false).map(|(a, b)| (a as $cursor, b as $cursor))
+            }
+
+            #[inline]
+            fn next_reject_back(&mut self) -> Option<($cursor, $cursor)> {
+                self.next_run_back(true).map(|(a, b)| (a as $cursor, b as $cursor))
+            }
+        }
+
+        impl<'a> DoubleEndedSearcher<$haystack> for InvalidUtf8Searcher<'a> {}
+    }
+}
+
+invalid_utf8_impls!(&'a [u8], *const u8);
+invalid_utf8_impls!(&'a mut [u8], *mut u8);
+#[cfg(feature = "std")]
+invalid_utf8_impls!(&'a ::std::ffi::OsStr, *const u8);
+#[cfg(all(feature = "std", unix))]
+invalid_utf8_impls!(&'a mut ::std::ffi::OsStr, *mut u8);
@@ -0,0 +1,392 @@
+//! Predefined classes of chars, backed by the Unicode tables.
+//!
+//! Instead of a closure like `|c: char| c.is_alphanumeric() || c == '_'`,
+//! the constants of this module name the Unicode general categories,
+//! some common scripts, the identifier properties `XID_Start` and
+//! `XID_Continue`, and the ASCII classes of `char::is_ascii_*`.
+//! A `CharClass` is a pattern for `&str`, `&OsStr` and `PartialUnicode`
+//! haystacks. Lookups are binary searches in static tables, and the
+//! ASCII classes are searched for without decoding the haystack.
+//!
+//! Classes are combined with `union()` and `difference()`, which build
+//! a `CharSet`, so `XID_CONTINUE.union(CharSet::from(&['-'][..]))`
+//! matches the chars of kebab-case identifiers.
+
+use unicode_tables::{GeneralCategory, Script};
+use unicode_tables::GeneralCategory::*;
+use unicode_tables::{GENERAL_CATEGORY_TABLE, SCRIPT_TABLE};
+use unicode_tables::{XID_START_TABLE, XID_CONTINUE_TABLE};
+use utf8::CharEq;
+#[cfg(feature = "alloc")]
+use sets::CharSet;
+
+use core::cmp::Ordering;
+
+///////////////////////////////////////////////////////////////////////////////
+// CharClass
+///////////////////////////////////////////////////////////////////////////////
+
+/// A predefined class of chars.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CharClass(Class);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Class {
+    // A bit per `GeneralCategory` variant
+    Categories(u32),
+    Script(Script),
+    Table(&'static [(char, char)]),
+    // A bit per ASCII char
+    Ascii(u128),
+}
+
+fn bsearch_range_table<T: Copy>(c: char, table: &[(char, char, T)], default: T) -> T {
+    match table.binary_search_by(|&(lo, hi, _)| {
+        if hi < c { Ordering::Less } else if lo > c { Ordering::Greater } else { Ordering::Equal }
+    }) {
+        Ok(i) => table[i].2,
+        Err(_) => default,
+    }
+}
+
+fn in_table(c: char, table: &[(char, char)]) -> bool {
+    table.binary_search_by(|&(lo, hi)| {
+        if hi < c { Ordering::Less } else if lo > c { Ordering::Greater } else { Ordering::Equal }
+    }).is_ok()
+}
+
+impl CharClass {
+    /// Looks up a general category by its short or long name,
+    /// like `"Nd"` or `"Decimal_Number"`.
+    pub fn general_category(name: &str) -> Option<CharClass> {
+        Some(match name {
+            "L" | "Letter" => LETTER,
+            "LC" | "Cased_Letter" => CASED_LETTER,
+            "Lu" | "Uppercase_Letter" => UPPERCASE_LETTER,
+            "Ll" | "Lowercase_Letter" => LOWERCASE_LETTER,
+            "Lt" | "Titlecase_Letter" => TITLECASE_LETTER,
+            "Lm" | "Modifier_Letter" => MODIFIER_LETTER,
+            "Lo" | "Other_Letter" => OTHER_LETTER,
+            "M" | "Mark" => MARK,
+            "Mn" | "Nonspacing_Mark" => NONSPACING_MARK,
+            "Mc" | "Spacing_Mark" => SPACING_MARK,
+            "Me" | "Enclosing_Mark" => ENCLOSING_MARK,
+            "N" | "Number" => NUMBER,
+            "Nd" | "Decimal_Number" => DECIMAL_NUMBER,
+            "Nl" | "Letter_Number" => LETTER_NUMBER,
+            "No" | "Other_Number" => OTHER_NUMBER,
+            "P" | "Punctuation" => PUNCTUATION,
+            "Pc" | "Connector_Punctuation" => CONNECTOR_PUNCTUATION,
+            "Pd" | "Dash_Punctuation" => DASH_PUNCTUATION,
+            "Ps" | "Open_Punctuation" => OPEN_PUNCTUATION,
+            "Pe" | "Close_Punctuation" => CLOSE_PUNCTUATION,
+            "Pi" | "Initial_Punctuation" => INITIAL_PUNCTUATION,
+            "Pf" | "Final_Punctuation" => FINAL_PUNCTUATION,
+            "Po" | "Other_Punctuation" => OTHER_PUNCTUATION,
+            "S" | "Symbol" => SYMBOL,
+            "Sm" | "Math_Symbol" => MATH_SYMBOL,
+            "Sc" | "Currency_Symbol" => CURRENCY_SYMBOL,
+            "Sk" | "Modifier_Symbol" => MODIFIER_SYMBOL,
+            "So" | "Other_Symbol" => OTHER_SYMBOL,
+            "Z" | "Separator" => SEPARATOR,
+            "Zs" | "Space_Separator" => SPACE_SEPARATOR,
+            "Zl" | "Line_Separator" => LINE_SEPARATOR,
+            "Zp" | "Paragraph_Separator" => PARAGRAPH_SEPARATOR,
+            "C" | "Other" => OTHER,
+            "Cc" | "Control" => CONTROL,
+            "Cf" | "Format" => FORMAT,
+            "Co" | "Private_Use" => PRIVATE_USE,
+            "Cn" | "Unassigned" => UNASSIGNED,
+            _ => return None,
+        })
+    }
+
+    /// Looks up one of the scripts of this module by its name, like `"Latin"`.
+    pub fn script(name: &str) -> Option<CharClass> {
+        Some(match name {
+            "Common" => COMMON,
+            "Inherited" => INHERITED,
+            "Latin" => LATIN,
+            "Greek" => GREEK,
+            "Cyrillic" => CYRILLIC,
+            "Armenian" => ARMENIAN,
+            "Hebrew" => HEBREW,
+            "Arabic" => ARABIC,
+            "Devanagari" => DEVANAGARI,
+            "Bengali" => BENGALI,
+            "Tamil" => TAMIL,
+            "Thai" => THAI,
+            "Georgian" => GEORGIAN,
+            "Hangul" => HANGUL,
+            "Hiragana" => HIRAGANA,
+            "Katakana" => KATAKANA,
+            "Han" => HAN,
+            _ => return None,
+        })
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        match self.0 {
+            Class::Categories(mask) => {
+                let cat = bsearch_range_table(c, GENERAL_CATEGORY_TABLE, GeneralCategory::Cn);
+                mask >> cat as u32 & 1 != 0
+            }
+            Class::Script(script) => bsearch_range_table(c, SCRIPT_TABLE, Script::Any) == script,
+            Class::Table(table) => in_table(c, table),
+            Class::Ascii(bits) => (c as u32) < 128 && bits >> (c as u32) & 1 != 0,
+        }
+    }
+
+    /// Whether the class only contains ASCII chars.
+    #[inline]
+    pub fn is_ascii(&self) -> bool {
+        matches!(self.0, Class::Ascii(_))
+    }
+
+    /// The chars that are in this class or in `other`.
+    #[cfg(feature = "alloc")]
+    pub fn union<S: Into<CharSet>>(self, other: S) -> CharSet {
+        CharSet::from(self).union(other)
+    }
+
+    /// The chars that are in this class but not in `other`.
+    #[cfg(feature = "alloc")]
+    pub fn difference<S: Into<CharSet>>(self, other: S) -> CharSet {
+        CharSet::from(self).difference(other)
+    }
+
+    // Calls `f` with sorted, disjoint ranges covering the class
+    #[cfg(feature = "alloc")]
+    fn for_each_range<F: FnMut(char, char)>(&self, mut f: F) {
+        match self.0 {
+            Class::Categories(mask) => {
+                let unassigned = mask & 1 << GeneralCategory::Cn as u32 != 0;
+                // the chars missing from the table are unassigned
+                let mut next = 0;
+                for &(lo, hi, cat) in GENERAL_CATEGORY_TABLE {
+                    if unassigned && next < lo as u32 {
+                        gap(next, lo as u32 - 1, &mut f);
+                    }
+                    if mask >> cat as u32 & 1 != 0 {
+                        f(lo, hi);
+                    }
+                    next = hi as u32 + 1;
+                }
+                if unassigned {
+                    gap(next, 0x10FFFF, &mut f);
+                }
+            }
+            Class::Script(script) => {
+                for &(lo, hi, s) in SCRIPT_TABLE {
+                    if s == script {
+                        f(lo, hi);
+                    }
+                }
+            }
+            Class::Table(table) => {
+                for &(lo, hi) in table {
+                    f(lo, hi);
+                }
+            }
+            Class::Ascii(bits) => {
+                for b in 0..128u8 {
+                    if bits >> b & 1 != 0 {
+                        f(b as char, b as char);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Calls `f` with the chars between two code points, skipping surrogates
+#[cfg(feature = "alloc")]
+fn gap<F: FnMut(char, char)>(lo: u32, hi: u32, f: &mut F) {
+    let lo = if (0xD800..0xE000).contains(&lo) { 0xE000 } else { lo };
+    let hi = if (0xD800..0xE000).contains(&hi) { 0xD7FF } else { hi };
+    if lo <= hi {
+        // both ends are outside of the surrogate range
+        f(::core::char::from_u32(lo).unwrap(), ::core::char::from_u32(hi).unwrap());
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<CharClass> for CharSet {
+    fn from(class: CharClass) -> CharSet {
+        let mut set = CharSet::new();
+        class.for_each_range(|lo, hi| set.insert_range(lo..=hi));
+        set
+    }
+}
+
+impl CharEq for CharClass {
+    #[inline]
+    fn matches(&mut self, c: char) -> bool { self.contains(c) }
+
+    #[inline]
+    fn only_ascii(&self) -> bool { self.is_ascii() }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// General categories
+///////////////////////////////////////////////////////////////////////////////
+
+const fn categories(cats: &[GeneralCategory]) -> CharClass {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < cats.len() {
+        mask |= 1 << cats[i] as u32;
+        i += 1;
+    }
+    CharClass(Class::Categories(mask))
+}
+
+/// `L`, the letters.
+pub const LETTER: CharClass = categories(&[Lu, Ll, Lt, Lm, Lo]);
+/// `LC`, the uppercase, lowercase and titlecase letters.
+pub const CASED_LETTER: CharClass = categories(&[Lu, Ll, Lt]);
+/// `Lu`
+pub const UPPERCASE_LETTER: CharClass = categories(&[Lu]);
+/// `Ll`
+pub const LOWERCASE_LETTER: CharClass = categories(&[Ll]);
+/// `Lt`
+pub const TITLECASE_LETTER: CharClass = categories(&[Lt]);
+/// `Lm`
+pub const MODIFIER_LETTER: CharClass = categories(&[Lm]);
+/// `Lo`
+pub const OTHER_LETTER: CharClass = categories(&[Lo]);
+
+/// `M`, the combining marks.
+pub const MARK: CharClass = categories(&[Mn, Mc, Me]);
+/// `Mn`
+pub const NONSPACING_MARK: CharClass = categories(&[Mn]);
+/// `Mc`
+pub const SPACING_MARK: CharClass = categories(&[Mc]);
+/// `Me`
+pub const ENCLOSING_MARK: CharClass = categories(&[Me]);
+
+/// `N`, the numeric chars.
+pub const NUMBER: CharClass = categories(&[Nd, Nl, No]);
+/// `Nd`, the digits of all decimal systems.
+pub const DECIMAL_NUMBER: CharClass = categories(&[Nd]);
+/// `Nl`
+pub const LETTER_NUMBER: CharClass = categories(&[Nl]);
+/// `No`
+pub const OTHER_NUMBER: CharClass = categories(&[No]);
+
+/// `P`, the punctuation.
+pub const PUNCTUATION: CharClass = categories(&[Pc, Pd, Ps, Pe, Pi, Pf, Po]);
+/// `Pc`
+pub const CONNECTOR_PUNCTUATION: CharClass = categories(&[Pc]);
+/// `Pd`
+pub const DASH_PUNCTUATION: CharClass = categories(&[Pd]);
+/// `Ps`
+pub const OPEN_PUNCTUATION: CharClass = categories(&[Ps]);
+/// `Pe`
+pub const CLOSE_PUNCTUATION: CharClass = categories(&[Pe]);
+/// `Pi`
+pub const INITIAL_PUNCTUATION: CharClass = categories(&[Pi]);
+/// `Pf`
+pub const FINAL_PUNCTUATION: CharClass = categories(&[Pf]);
+/// `Po`
+pub const OTHER_PUNCTUATION: CharClass = categories(&[Po]);
+
+/// `S`, the symbols.
+pub const SYMBOL: CharClass = categories(&[Sm, Sc, Sk, So]);
+/// `Sm`
+pub const MATH_SYMBOL: CharClass = categories(&[Sm]);
+/// `Sc`
+pub const CURRENCY_SYMBOL: CharClass = categories(&[Sc]);
+/// `Sk`
+pub const MODIFIER_SYMBOL: CharClass = categories(&[Sk]);
+/// `So`
+pub const OTHER_SYMBOL: CharClass = categories(&[So]);
+
+/// `Z`, the separators.
+pub const SEPARATOR: CharClass = categories(&[Zs, Zl, Zp]);
+/// `Zs`, the spaces, but not the tab or line breaks.
+pub const SPACE_SEPARATOR: CharClass = categories(&[Zs]);
+/// `Zl`
+pub const LINE_SEPARATOR: CharClass = categories(&[Zl]);
+/// `Zp`
+pub const PARAGRAPH_SEPARATOR: CharClass = categories(&[Zp]);
+
+/// `C`, the control, format, private use and unassigned chars.
+pub const OTHER: CharClass = categories(&[Cc, Cf, Co, Cn]);
+/// `Cc`
+pub const CONTROL: CharClass = categories(&[Cc]);
+/// `Cf`
+pub const FORMAT: CharClass = categories(&[Cf]);
+/// `Co`
+pub const PRIVATE_USE: CharClass = categories(&[Co]);
+/// `Cn`
+pub const UNASSIGNED: CharClass = categories(&[Cn]);
+
+///////////////////////////////////////////////////////////////////////////////
+// Scripts
+///////////////////////////////////////////////////////////////////////////////
+
+/// The chars used by several scripts, like digits and most punctuation.
+pub const COMMON: CharClass = CharClass(Class::Script(Script::Common));
+/// The combining marks taking the script of the char they follow.
+pub const INHERITED: CharClass = CharClass(Class::Script(Script::Inherited));
+pub const LATIN: CharClass = CharClass(Class::Script(Script::Latin));
+pub const GREEK: CharClass = CharClass(Class::Script(Script::Greek));
+pub const CYRILLIC: CharClass = CharClass(Class::Script(Script::Cyrillic));
+pub const ARMENIAN: CharClass = CharClass(Class::Script(Script::Armenian));
+pub const HEBREW: CharClass = CharClass(Class::Script(Script::Hebrew));
+pub const ARABIC: CharClass = CharClass(Class::Script(Script::Arabic));
+pub const DEVANAGARI: CharClass = CharClass(Class::Script(Script::Devanagari));
+pub const BENGALI: CharClass = CharClass(Class::Script(Script::Bengali));
+pub const TAMIL: CharClass = CharClass(Class::Script(Script::Tamil));
+pub const THAI: CharClass = CharClass(Class::Script(Script::Thai));
+pub const GEORGIAN: CharClass = CharClass(Class::Script(Script::Georgian));
+pub const HANGUL: CharClass = CharClass(Class::Script(Script::Hangul));
+pub const HIRAGANA: CharClass = CharClass(Class::Script(Script::Hiragana));
+pub const KATAKANA: CharClass = CharClass(Class::Script(Script::Katakana));
+pub const HAN: CharClass = CharClass(Class::Script(Script::Han));
+
+///////////////////////////////////////////////////////////////////////////////
+// Identifiers
+///////////////////////////////////////////////////////////////////////////////
+
+/// The chars that can start an identifier (UAX #31).
+pub const XID_START: CharClass = CharClass(Class::Table(XID_START_TABLE));
+/// The chars that can continue an identifier (UAX #31), including `_`.
+pub const XID_CONTINUE: CharClass = CharClass(Class::Table(XID_CONTINUE_TABLE));
+
+///////////////////////////////////////////////////////////////////////////////
+// ASCII
+///////////////////////////////////////////////////////////////////////////////
+
+const fn ascii(lo: char, hi: char) -> u128 {
+    (!0u128 >> (127 - hi as u32)) & (!0u128 << lo as u32)
+}
+
+const DIGITS: u128 = ascii('0', '9');
+const UPPER: u128 = ascii('A', 'Z');
+const LOWER: u128 = ascii('a', 'z');
+
+/// The chars of `char::is_ascii_alphabetic`.
+pub const ASCII_ALPHABETIC: CharClass = CharClass(Class::Ascii(UPPER | LOWER));
+/// The chars of `char::is_ascii_uppercase`.
+pub const ASCII_UPPERCASE: CharClass = CharClass(Class::Ascii(UPPER));
+/// The chars of `char::is_ascii_lowercase`.
+pub const ASCII_LOWERCASE: CharClass = CharClass(Class::Ascii(LOWER));
+/// The chars of `char::is_ascii_alphanumeric`.
+pub const ASCII_ALPHANUMERIC: CharClass = CharClass(Class::Ascii(DIGITS | UPPER | LOWER));
+/// The chars of `char::is_ascii_digit`.
+pub const ASCII_DIGIT: CharClass = CharClass(Class::Ascii(DIGITS));
+/// The chars of `char::is_ascii_hexdigit`.
+pub const ASCII_HEXDIGIT: CharClass =
+    CharClass(Class::Ascii(DIGITS | ascii('A', 'F') | ascii('a', 'f')));
+/// The chars of `char::is_ascii_punctuation`.
+pub const ASCII_PUNCTUATION: CharClass = CharClass(Class::Ascii(
+    ascii('!', '/') | ascii(':', '@') | ascii('[', '`') | ascii('{', '~')));
+/// The chars of `char::is_ascii_graphic`.
+pub const ASCII_GRAPHIC: CharClass = CharClass(Class::Ascii(ascii('!', '~')));
+/// The chars of `char::is_ascii_whitespace`.
+pub const ASCII_WHITESPACE: CharClass = CharClass(Class::Ascii(
+    ascii(' ', ' ') | ascii('\t', '\n') | ascii('\x0C', '\r')));
+/// The chars of `char::is_ascii_control`.
+pub const ASCII_CONTROL: CharClass = CharClass(Class::Ascii(ascii('\0', '\x1F') | ascii('\x7F', '\x7F')));
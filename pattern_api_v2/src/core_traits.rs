@@ -1,3 +1,6 @@
+use core::fmt;
+use core::ops::Range;
+
 pub trait Pattern<H: PatternHaystack>: Sized {
     type Searcher: Searcher<H>;
     fn into_searcher(self, haystack: H) -> Self::Searcher;
@@ -27,12 +30,119 @@ pub trait Pattern<H: PatternHaystack>: Sized {
     fn is_contained_in(self, haystack: H) -> bool {
         self.into_searcher(haystack).next_match().is_some()
     }
+
+    // Checks the needle before a search. Patterns whose `into_searcher()`
+    // panics for some needles override this, so that `TryPattern` can
+    // report the problem instead.
+    fn validate(&self) -> Result<(), PatternError> {
+        Ok(())
+    }
+
+    // Anchored matching: returns the end of the match if the pattern
+    // matches exactly at `offset`.
+    //
+    // The impls for substrings, chars and elements compare directly.
+    // This default seeks the searcher to `offset` and takes one match,
+    // which also finds a match overlapping an earlier one. Searchers that
+    // can not be seeked run a forward search up to `offset` instead, which
+    // misses such overlapping matches.
+    fn matches_at(self, haystack: H, offset: usize) -> Option<usize> {
+        let mut searcher = self.into_searcher(haystack);
+        let hs = searcher.haystack();
+        if let Some(searcher) = searcher.as_seekable() {
+            if !searcher.seek_front(offset) {
+                return None;
+            }
+            return match searcher.next_match() {
+                Some((a, b)) if H::offset_from_front(hs, a) == offset => {
+                    Some(H::offset_from_front(hs, b))
+                }
+                _ => None,
+            };
+        }
+
+        while let Some((a, b)) = searcher.next_match() {
+            let start = H::offset_from_front(hs, a);
+            if start == offset {
+                return Some(H::offset_from_front(hs, b));
+            }
+            if start > offset {
+                break;
+            }
+        }
+        None
+    }
+
+    fn strip_prefix_of(self, haystack: H) -> Option<H::MatchType>
+        where H: InverseMatchesAreValid
+    {
+        let mut searcher = self.into_searcher(haystack);
+        let hs = searcher.haystack();
+
+        // Like `is_prefix_of()`, look for the first reject, and give up if
+        // it is at the front. A match before it can only be empty, so the
+        // searcher is rewound to just the front to look for that one.
+        // Searchers that can not be rewound look for the first match.
+        if let Some(searcher) = searcher.as_seekable() {
+            let back = match searcher.next_reject() {
+                Some((a, _)) if a == H::cursor_at_front(hs) => 0,
+                _ => H::haystack_len(hs),
+            };
+            if !(searcher.seek_front(0) && searcher.seek_back(back)) {
+                return None;
+            }
+        }
+
+        // the first match is only a prefix if it starts at the front,
+        // the rest of the haystack is valid due to InverseMatchesAreValid
+        match searcher.next_match() {
+            Some((a, b)) if a == H::cursor_at_front(hs) => unsafe {
+                Some(H::range_to_self(hs, b, H::cursor_at_back(hs)))
+            },
+            _ => None,
+        }
+    }
+
+    fn strip_suffix_of(self, haystack: H) -> Option<H::MatchType>
+        where Self::Searcher: ReverseSearcher<H>,
+              H: InverseMatchesAreValid
+    {
+        let mut searcher = self.into_searcher(haystack);
+        let hs = searcher.haystack();
+
+        // the same early exit as in `strip_prefix_of()`
+        if searcher.as_seekable().is_some() {
+            let len = H::haystack_len(hs);
+            let front = match searcher.next_reject_back() {
+                Some((_, b)) if b == H::cursor_at_back(hs) => len,
+                _ => 0,
+            };
+            let searcher = searcher.as_seekable().unwrap();
+            if !(searcher.seek_back(len) && searcher.seek_front(front)) {
+                return None;
+            }
+        }
+
+        // the last match is only a suffix if it ends at the back,
+        // the rest of the haystack is valid due to InverseMatchesAreValid
+        match searcher.next_match_back() {
+            Some((a, b)) if b == H::cursor_at_back(hs) => unsafe {
+                Some(H::range_to_self(hs, H::cursor_at_front(hs), a))
+            },
+            _ => None,
+        }
+    }
 }
 
 // Defined associated types and functions
 // for dealing with positions in a slice-like type
 // with pointer-like cursors
 // Logically, Haystack <= Cursor <= Back
+//
+// A Haystack or Cursor value stands for the borrow `Self` holds,
+// with its lifetime erased. So a type that only stores them in place
+// of a `Self` can be Send and Sync whenever `Self` is, as long as it
+// accesses the elements only the way `Self` would allow.
 pub trait PatternHaystack: Sized {
     // For storing the bounds of the haystack.
     // Usually a combination of Memory address in form of a raw pointer or usize
@@ -71,6 +181,116 @@ pub trait PatternHaystack: Sized {
     }
 
     fn match_type_len(mt: &Self::MatchType) -> usize;
+
+    // Turns a span back into cursors, if both of its ends are
+    // positions a searcher could have returned for this haystack.
+    // Haystacks that can not check this never accept a span.
+    fn span_to_cursors(hs: Self::Haystack,
+                       span: Span) -> Option<(Self::Cursor, Self::Cursor)> {
+        let _ = (hs, span);
+        None
+    }
+
+    fn cursors_to_span(hs: Self::Haystack,
+                       start: Self::Cursor,
+                       end: Self::Cursor) -> Span {
+        Span {
+            start: Self::offset_from_front(hs, start),
+            end: Self::offset_from_front(hs, end),
+        }
+    }
+
+    // Slices a span out of the haystack it was recorded for,
+    // returning None if it is out of bounds or not at valid positions
+    fn span_to_self(self, span: Span) -> Option<Self::MatchType> {
+        let hs = self.into_haystack();
+        Self::span_to_cursors(hs, span).map(|(a, b)| unsafe {
+            Self::range_to_self(hs, a, b)
+        })
+    }
+}
+
+// Fallible construction of a searcher, for needles from untrusted
+// sources that must not make the search panic.
+pub trait TryPattern<H: PatternHaystack>: Pattern<H> {
+    fn try_into_searcher(self, haystack: H) -> Result<Self::Searcher, PatternError>;
+}
+
+impl<H: PatternHaystack, P: Pattern<H>> TryPattern<H> for P {
+    #[inline]
+    fn try_into_searcher(self, haystack: H) -> Result<P::Searcher, PatternError> {
+        self.validate()?;
+        Ok(self.into_searcher(haystack))
+    }
+}
+
+/// Why a pattern can not be searched for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PatternError {
+    /// The needle starts or ends with a lone surrogate, which could be
+    /// one half of a surrogate pair in the haystack.
+    LoneSurrogateAtEdge,
+    /// The needle starts or ends inside of a UTF-8 sequence,
+    /// so it could match a part of a char.
+    InvalidUtf8Boundary,
+    /// The pattern does not support empty needles.
+    EmptyNeedle,
+    /// The stride of an aligned pattern is zero.
+    ZeroStride,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            PatternError::LoneSurrogateAtEdge =>
+                "the needle starts or ends with a lone surrogate",
+            PatternError::InvalidUtf8Boundary =>
+                "the needle starts or ends inside of a UTF-8 sequence",
+            PatternError::EmptyNeedle =>
+                "the pattern does not support empty needles",
+            PatternError::ZeroStride =>
+                "the stride of an aligned pattern is zero",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for PatternError {}
+
+/// A range of offsets from the front of a haystack.
+///
+/// Unlike cursors, spans can be stored independently of the haystack,
+/// and turned back into a `MatchType` with `PatternHaystack::span_to_self()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl From<Range<usize>> for Span {
+    #[inline]
+    fn from(r: Range<usize>) -> Span {
+        Span { start: r.start, end: r.end }
+    }
+}
+
+impl From<Span> for Range<usize> {
+    #[inline]
+    fn from(span: Span) -> Range<usize> {
+        span.start..span.end
+    }
 }
 
 pub unsafe trait Searcher<H: PatternHaystack> {
@@ -78,6 +298,12 @@ pub unsafe trait Searcher<H: PatternHaystack> {
 
     fn next_match(&mut self) -> Option<(H::Cursor, H::Cursor)>;
     fn next_reject(&mut self) -> Option<(H::Cursor, H::Cursor)>;
+
+    // Gives access to the `SeekableSearcher` impl of the searcher,
+    // for code that only knows it as a `Searcher`.
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<H>> {
+        None
+    }
 }
 
 pub unsafe trait ReverseSearcher<H: PatternHaystack>: Searcher<H> {
@@ -87,4 +313,26 @@ pub unsafe trait ReverseSearcher<H: PatternHaystack>: Searcher<H> {
 
 pub trait DoubleEndedSearcher<H: PatternHaystack>: ReverseSearcher<H> {}
 
+// Searchers that can resume at an arbitrary offset from the front
+// of the haystack, like an editor doing a find-next from the caret.
+//
+// `seek_front()` moves the position the next forward step starts at,
+// `seek_back()` the position the next backward step ends at, without
+// having to re-slice the haystack and adjust the returned offsets.
+// Both return false and leave the searcher unchanged if the offset is
+// not a valid position, or lies beyond the opposite end of the search.
+pub trait SeekableSearcher<H: PatternHaystack>: Searcher<H> {
+    fn seek_front(&mut self, offset: usize) -> bool;
+    fn seek_back(&mut self, offset: usize) -> bool;
+}
+
 pub unsafe trait InverseMatchesAreValid {}
+
+// Marker for haystacks whose MatchType is a unique borrow, like `&mut str`.
+//
+// Implementing it guarantees that `range_to_self()` returns borrows that
+// do not alias each other as long as the cursor ranges do not overlap.
+// Together with the Searcher contract of never returning overlapping
+// ranges - even if used from both ends - this makes it safe to keep
+// and modify all pieces returned by a pattern iterator at the same time.
+pub unsafe trait MutableHaystack: PatternHaystack {}
@@ -1,7 +1,6 @@
 use super::*;
 
-use std::cmp;
-use std::usize;
+use core::cmp;
 
 pub trait OrdSlice: PatternHaystack {
     type NeedleElement: Ord;
@@ -64,40 +63,85 @@ impl<'b, H: OrdSlice> OrdSlicePattern<'b, H> {
     #[inline]
     pub fn is_prefix_of(self, haystack: H) -> bool {
         let hs = haystack.into_haystack();
-        H::starts_with(&hs, &self.0)
+        H::starts_with(&hs, self.0)
     }
 
     /// Checks whether the pattern matches at the back of the haystack
     #[inline]
     pub fn is_suffix_of(self, haystack: H) -> bool {
         let hs = haystack.into_haystack();
-        H::ends_with(&hs, &self.0)
+        H::ends_with(&hs, self.0)
     }
 
     #[inline]
     pub fn is_contained_in(self, haystack: H) -> bool {
         self.into_searcher(haystack).next_match().is_some()
     }
+
+    /// Returns the end of the match if the pattern matches at `offset`
+    #[inline]
+    pub fn matches_at(self, haystack: H, offset: usize) -> Option<usize> {
+        let hs = haystack.into_haystack();
+        let slice = H::haystack_as_slice(&hs);
+        if offset <= slice.len()
+            && H::pos_is_valid(&hs, offset)
+            && slice[offset..].starts_with(self.0)
+        {
+            Some(offset + self.0.len())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the rest of the haystack if the pattern matches at its front
+    #[inline]
+    pub fn strip_prefix_of(self, haystack: H) -> Option<H::MatchType>
+        where H: InverseMatchesAreValid
+    {
+        let hs = haystack.into_haystack();
+        if H::starts_with(&hs, self.0) {
+            unsafe {
+                let start = H::cursor_at_offset(hs, self.0.len());
+                Some(H::range_to_self(hs, start, H::cursor_at_back(hs)))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns the rest of the haystack if the pattern matches at its back
+    #[inline]
+    pub fn strip_suffix_of(self, haystack: H) -> Option<H::MatchType>
+        where H: InverseMatchesAreValid
+    {
+        let hs = haystack.into_haystack();
+        if H::ends_with(&hs, self.0) {
+            unsafe {
+                let end = H::cursor_at_offset(hs, H::haystack_len(hs) - self.0.len());
+                Some(H::range_to_self(hs, H::cursor_at_front(hs), end))
+            }
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
 pub struct Iter<H: PatternHaystack> {
     haystack: H::Haystack,
-    start: H::Cursor,
-    end: H::Cursor,
-    _marker: ::std::marker::PhantomData<H>,
+    _marker: ::core::marker::PhantomData<H>,
 }
 
+// Never dereferences the haystack, it only hands out ranges of it
+unsafe impl<H: PatternHaystack + Send> Send for Iter<H> {}
+unsafe impl<H: PatternHaystack + Sync> Sync for Iter<H> {}
+
 impl<H: PatternHaystack> Iter<H> {
     #[inline]
     fn new(haystack: H::Haystack) -> Self {
-        let start = H::cursor_at_front(haystack);
-        let end = H::cursor_at_back(haystack);
         Iter {
             haystack: haystack,
-            start: start,
-            end: end,
-            _marker: ::std::marker::PhantomData,
+            _marker: ::core::marker::PhantomData,
         }
     }
 
@@ -136,7 +180,7 @@ struct EmptyN {
 }
 
 impl<'b, H: OrdSlice> OrdSeqSearcher<'b, H> {
-    fn new(haystack: H, needle: &'b [H::NeedleElement]) -> OrdSeqSearcher<H> {
+    fn new(haystack: H, needle: &'b [H::NeedleElement]) -> OrdSeqSearcher<'b, H> {
         let hs = haystack.into_haystack();
 
         OrdSeqSearcher {
@@ -255,6 +299,11 @@ unsafe impl<'b, H: OrdSlice> Searcher<H> for OrdSeqSearcher<'b, H>
         self.iter.haystack
     }
 
+    #[inline]
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<H>> {
+        Some(self)
+    }
+
     #[inline(always)]
     fn next_match(&mut self) -> Option<(H::Cursor, H::Cursor)> {
         (|| match self.searcher {
@@ -356,6 +405,63 @@ unsafe impl<'b, H: OrdSlice> ReverseSearcher<H> for OrdSeqSearcher<'b, H>
 
 }
 
+impl<'b, H: OrdSlice> SeekableSearcher<H> for OrdSeqSearcher<'b, H>
+    where H: PatternHaystack
+{
+    fn seek_front(&mut self, offset: usize) -> bool {
+        let hs = self.iter.haystack;
+        if offset > H::haystack_len(hs) || !H::pos_is_valid(&hs, offset) {
+            return false;
+        }
+        match self.searcher {
+            OrdSeqSearcherImpl::Empty(ref mut searcher) => {
+                if offset > searcher.end {
+                    return false;
+                }
+                searcher.position = offset;
+                searcher.is_match_fw = true;
+            }
+            OrdSeqSearcherImpl::TwoWay(ref mut searcher) => {
+                if offset > searcher.end {
+                    return false;
+                }
+                searcher.position = offset;
+                // the memory only holds for the old position
+                if searcher.memory != usize::MAX {
+                    searcher.memory = 0;
+                }
+            }
+        }
+        true
+    }
+
+    fn seek_back(&mut self, offset: usize) -> bool {
+        let hs = self.iter.haystack;
+        if offset > H::haystack_len(hs) || !H::pos_is_valid(&hs, offset) {
+            return false;
+        }
+        match self.searcher {
+            OrdSeqSearcherImpl::Empty(ref mut searcher) => {
+                if offset < searcher.position {
+                    return false;
+                }
+                searcher.end = offset;
+                searcher.is_match_bw = true;
+            }
+            OrdSeqSearcherImpl::TwoWay(ref mut searcher) => {
+                if offset < searcher.position {
+                    return false;
+                }
+                searcher.end = offset;
+                if searcher.memory_back != usize::MAX {
+                    searcher.memory_back = self.needle.len();
+                }
+            }
+        }
+        true
+    }
+}
+
 pub trait FastSkipOptimization<T> {
     fn new(needle: &[T]) -> Self;
     fn contains(&self, byte: &T) -> bool;
@@ -363,6 +469,7 @@ pub trait FastSkipOptimization<T> {
 
 /// `ByteOptimization` is a 64-bit "fingerprint" where each set bit `j` corresponds
 /// to a (byte & 63) == j present in the needle.
+#[derive(Copy, Clone, Debug)]
 pub struct ByteOptimization(u64);
 impl FastSkipOptimization<u8> for ByteOptimization {
     fn new(needle: &[u8]) -> Self {
@@ -374,6 +481,7 @@ impl FastSkipOptimization<u8> for ByteOptimization {
     }
 }
 
+#[derive(Copy, Clone, Debug)]
 pub struct NoOptimization;
 impl<T> FastSkipOptimization<T> for NoOptimization {
     fn new(_: &[T]) -> Self {
@@ -385,6 +493,55 @@ impl<T> FastSkipOptimization<T> for NoOptimization {
     }
 }
 
+// The elements of `&[T]` haystacks, choosing the skip optimization of
+// the Two-Way search by type. Bytes use `ByteOptimization`, the other
+// impls `NoOptimization`. Element types of other crates implement it
+// the same way to be searchable as slices.
+pub trait SliceElement: Ord + Sized {
+    type FastSkipOptimization: FastSkipOptimization<Self>;
+}
+
+impl SliceElement for u8 {
+    type FastSkipOptimization = ByteOptimization;
+}
+
+macro_rules! impl_slice_element {
+    ($($t:ty),*) => {
+        $(
+            impl SliceElement for $t {
+                type FastSkipOptimization = NoOptimization;
+            }
+        )*
+    }
+}
+
+impl_slice_element!(i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,
+                    char, bool, ());
+
+#[cfg(feature = "alloc")]
+impl_slice_element!(::alloc::string::String);
+
+#[cfg(feature = "alloc")]
+impl<T: Ord> SliceElement for ::alloc::vec::Vec<T> {
+    type FastSkipOptimization = NoOptimization;
+}
+
+impl<'a, T: Ord + ?Sized> SliceElement for &'a T {
+    type FastSkipOptimization = NoOptimization;
+}
+
+impl<T: Ord> SliceElement for Option<T> {
+    type FastSkipOptimization = NoOptimization;
+}
+
+impl<A: Ord, B: Ord> SliceElement for (A, B) {
+    type FastSkipOptimization = NoOptimization;
+}
+
+impl<A: Ord, B: Ord, C: Ord> SliceElement for (A, B, C) {
+    type FastSkipOptimization = NoOptimization;
+}
+
 /// The internal state of the two-way substring search algorithm.
 #[derive(Clone, Debug)]
 struct TwoWaySearcher<T, O> {
@@ -407,7 +564,7 @@ struct TwoWaySearcher<T, O> {
     /// index into needle after which we have already matched
     memory_back: usize,
 
-    _marker: ::std::marker::PhantomData<(T, O)>,
+    _marker: ::core::marker::PhantomData<(T, O)>,
 }
 
 /*
@@ -506,7 +663,7 @@ impl<T: Ord, O: FastSkipOptimization<T>> TwoWaySearcher<T, O> {
         // &v[..period]. If it is, we use "Algorithm CP1". Otherwise we use
         // "Algorithm CP2", which is optimized for when the period of the needle
         // is large.
-        if &needle[..crit_pos] == &needle[period.. period + crit_pos] {
+        if needle[..crit_pos] == needle[period.. period + crit_pos] {
             // short period case -- the period is exact
             // compute a separate critical factorization for the reversed needle
             // x = u' v' where |v'| < period(x).
@@ -532,7 +689,7 @@ impl<T: Ord, O: FastSkipOptimization<T>> TwoWaySearcher<T, O> {
                 memory: 0,
                 memory_back: needle.len(),
 
-                _marker: ::std::marker::PhantomData,
+                _marker: ::core::marker::PhantomData,
             }
         } else {
             // long period case -- we have an approximation to the actual period,
@@ -554,7 +711,7 @@ impl<T: Ord, O: FastSkipOptimization<T>> TwoWaySearcher<T, O> {
                 memory: usize::MAX, // Dummy value to signify that the period is long
                 memory_back: usize::MAX,
 
-                _marker: ::std::marker::PhantomData,
+                _marker: ::core::marker::PhantomData,
             }
         }
     }
@@ -825,8 +982,8 @@ impl<T: Ord, O: FastSkipOptimization<T>> TwoWaySearcher<T, O> {
 trait TwoWayStrategy {
     type Output;
     fn use_early_reject() -> bool;
-    fn rejecting(usize, usize) -> Self::Output;
-    fn matching(usize, usize) -> Self::Output;
+    fn rejecting(a: usize, b: usize) -> Self::Output;
+    fn matching(a: usize, b: usize) -> Self::Output;
 }
 
 /// Skip to match intervals as quickly as possible
@@ -1,6 +1,6 @@
 use super::*;
 
-use std::fmt;
+use core::fmt;
 
 /// This macro generates two public iterator structs
 /// wrapping a private internal one that makes use of the `Pattern` API.
@@ -379,6 +379,73 @@ generate_pattern_iterators! {
     delegate double ended;
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// .match_spans()
+///////////////////////////////////////////////////////////////////////////////
+
+derive_pattern_clone!{
+    clone MatchSpansInternal
+    with |s| MatchSpansInternal(s.0.clone())
+}
+
+struct MatchSpansInternal<H, P>(P::Searcher)
+    where P: Pattern<H>,
+          H: PatternHaystack;
+
+impl<H, P: Pattern<H>> fmt::Debug for MatchSpansInternal<H, P>
+    where P::Searcher: fmt::Debug,
+          P: Pattern<H>,
+          H: PatternHaystack
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MatchSpansInternal")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<H, P> MatchSpansInternal<H, P>
+    where P: Pattern<H>,
+          H: PatternHaystack,
+{
+    #[inline]
+    fn new(h: H, p: P) -> Self {
+        MatchSpansInternal(p.into_searcher(h))
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Span> {
+        let h = self.0.haystack();
+        self.0.next_match().map(|(a, b)| H::cursors_to_span(h, a, b))
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<Span>
+        where P::Searcher: ReverseSearcher<H>
+    {
+        let h = self.0.haystack();
+        self.0.next_match_back().map(|(a, b)| H::cursors_to_span(h, a, b))
+    }
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`match_spans()`].
+        ///
+        /// Yields the span of each match.
+        struct MatchSpans;
+    reverse:
+        /// Created with the method [`rmatch_spans()`].
+        ///
+        /// Yields the span of each match, starting from the back.
+        struct RMatchSpans;
+    stability:
+    internal:
+        MatchSpansInternal() yielding (Span);
+    haystack bounds: ();
+    delegate double ended;
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // .split()
 ///////////////////////////////////////////////////////////////////////////////
@@ -399,6 +466,20 @@ struct SplitInternal<H, P>
     finished: bool,
 }
 
+// The cursors only remember the bounds of the searcher,
+// all access to the haystack goes through it
+unsafe impl<H, P> Send for SplitInternal<H, P>
+    where P: Pattern<H>,
+          P::Searcher: Send,
+          H: PatternHaystack + Send,
+{}
+
+unsafe impl<H, P> Sync for SplitInternal<H, P>
+    where P: Pattern<H>,
+          P::Searcher: Sync,
+          H: PatternHaystack + Sync,
+{}
+
 impl<H, P> fmt::Debug for SplitInternal<H, P>
     where P::Searcher: fmt::Debug,
           P: Pattern<H>,
@@ -438,76 +519,87 @@ impl<H, P> SplitInternal<H, P>
     }
 
     #[inline]
-    fn get_end(&mut self) -> Option<H::MatchType> {
+    fn get_end_range(&mut self) -> Option<(H::Cursor, H::Cursor)> {
         let h = self.matcher.haystack();
         let diff = |start: H::Cursor, end: H::Cursor| {
             H::cursor_diff(h, start, end)
         };
         if !self.finished && (self.allow_trailing_empty || diff(self.start, self.end) > 0) {
             self.finished = true;
-            unsafe {
-                let string = H::range_to_self(self.matcher.haystack(),
-                                              self.start,
-                                              self.end);
-                Some(string)
-            }
+            Some((self.start, self.end))
         } else {
             None
         }
     }
 
     #[inline]
-    fn next(&mut self) -> Option<H::MatchType> {
+    fn next_range(&mut self) -> Option<(H::Cursor, H::Cursor)> {
         if self.finished { return None }
 
         match self.matcher.next_match() {
-            Some((a, b)) => unsafe {
-                let elt = H::range_to_self(self.matcher.haystack(),
-                                           self.start,
-                                           a);
+            Some((a, b)) => {
+                let elt = (self.start, a);
                 self.start = b;
                 Some(elt)
             },
-            None => self.get_end(),
+            None => self.get_end_range(),
         }
     }
 
     #[inline]
-    fn next_back(&mut self) -> Option<H::MatchType>
+    fn next_range_back(&mut self) -> Option<(H::Cursor, H::Cursor)>
         where P::Searcher: ReverseSearcher<H>
     {
         if self.finished { return None }
 
         if !self.allow_trailing_empty {
             self.allow_trailing_empty = true;
-            match self.next_back() {
-                Some(elt) => {
-                    if H::match_type_len(&elt) > 0 {
-                        return Some(elt)
-                    } else if self.finished {
-                        return None
-                    }
-                }
+            let h = self.matcher.haystack();
+            match self.next_range_back() {
+                Some((a, b)) if H::cursor_diff(h, a, b) > 0 => return Some((a, b)),
                 _ => if self.finished { return None }
             }
         }
 
         match self.matcher.next_match_back() {
-            Some((a, b)) => unsafe {
-                let elt = H::range_to_self(self.matcher.haystack(),
-                                           b,
-                                           self.end);
+            Some((a, b)) => {
+                let elt = (b, self.end);
                 self.end = a;
                 Some(elt)
             },
-            None => unsafe {
+            None => {
                 self.finished = true;
-                Some(H::range_to_self(self.matcher.haystack(),
-                                      self.start,
-                                      self.end))
+                Some((self.start, self.end))
             },
         }
     }
+
+    #[inline]
+    fn to_self(&self, range: Option<(H::Cursor, H::Cursor)>) -> Option<H::MatchType> {
+        range.map(|(a, b)| unsafe {
+            H::range_to_self(self.matcher.haystack(), a, b)
+        })
+    }
+
+    #[inline]
+    fn get_end(&mut self) -> Option<H::MatchType> {
+        let range = self.get_end_range();
+        self.to_self(range)
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<H::MatchType> {
+        let range = self.next_range();
+        self.to_self(range)
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<H::MatchType>
+        where P::Searcher: ReverseSearcher<H>
+    {
+        let range = self.next_range_back();
+        self.to_self(range)
+    }
 }
 
 generate_pattern_iterators! {
@@ -529,6 +621,145 @@ generate_pattern_iterators! {
     delegate double ended;
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// .split_indices()
+///////////////////////////////////////////////////////////////////////////////
+
+derive_pattern_clone!{
+    clone SplitIndicesInternal
+    with |s| SplitIndicesInternal(s.0.clone())
+}
+
+struct SplitIndicesInternal<H, P>(SplitInternal<H, P>)
+    where P: Pattern<H>,
+          H: PatternHaystack;
+
+impl<H, P> fmt::Debug for SplitIndicesInternal<H, P>
+    where P::Searcher: fmt::Debug,
+          P: Pattern<H>,
+          H: PatternHaystack,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<H, P> SplitIndicesInternal<H, P>
+    where P: Pattern<H>,
+          H: PatternHaystack,
+{
+    #[inline]
+    fn new(h: H, p: P) -> Self {
+        SplitIndicesInternal(SplitInternal::new(h, p))
+    }
+
+    #[inline]
+    fn with_offset(&self, range: Option<(H::Cursor, H::Cursor)>) -> Option<(usize, H::MatchType)> {
+        let h = self.0.matcher.haystack();
+        range.map(|(a, b)| unsafe {
+            (H::offset_from_front(h, a), H::range_to_self(h, a, b))
+        })
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, H::MatchType)> {
+        let range = self.0.next_range();
+        self.with_offset(range)
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, H::MatchType)>
+        where P::Searcher: ReverseSearcher<H>
+    {
+        let range = self.0.next_range_back();
+        self.with_offset(range)
+    }
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`split_indices()`].
+        ///
+        /// Yields each piece together with its offset from the front.
+        struct SplitIndices;
+    reverse:
+        /// Created with the method [`rsplit_indices()`].
+        ///
+        /// Yields each piece together with its offset from the front,
+        /// starting from the back.
+        struct RSplitIndices;
+    stability:
+    internal:
+        SplitIndicesInternal() yielding ((usize, H::MatchType));
+    haystack bounds: (InverseMatchesAreValid);
+    delegate double ended;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// .split_spans()
+///////////////////////////////////////////////////////////////////////////////
+
+derive_pattern_clone!{
+    clone SplitSpansInternal
+    with |s| SplitSpansInternal(s.0.clone())
+}
+
+struct SplitSpansInternal<H, P>(SplitInternal<H, P>)
+    where P: Pattern<H>,
+          H: PatternHaystack;
+
+impl<H, P> fmt::Debug for SplitSpansInternal<H, P>
+    where P::Searcher: fmt::Debug,
+          P: Pattern<H>,
+          H: PatternHaystack,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<H, P> SplitSpansInternal<H, P>
+    where P: Pattern<H>,
+          H: PatternHaystack,
+{
+    #[inline]
+    fn new(h: H, p: P) -> Self {
+        SplitSpansInternal(SplitInternal::new(h, p))
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Span> {
+        let h = self.0.matcher.haystack();
+        self.0.next_range().map(|(a, b)| H::cursors_to_span(h, a, b))
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<Span>
+        where P::Searcher: ReverseSearcher<H>
+    {
+        let h = self.0.matcher.haystack();
+        self.0.next_range_back().map(|(a, b)| H::cursors_to_span(h, a, b))
+    }
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`split_spans()`].
+        ///
+        /// Yields the span of each piece.
+        struct SplitSpans;
+    reverse:
+        /// Created with the method [`rsplit_spans()`].
+        ///
+        /// Yields the span of each piece, starting from the back.
+        struct RSplitSpans;
+    stability:
+    internal:
+        SplitSpansInternal() yielding (Span);
+    haystack bounds: (InverseMatchesAreValid);
+    delegate double ended;
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // .split_terminator()
 ///////////////////////////////////////////////////////////////////////////////
@@ -677,3 +908,371 @@ generate_pattern_iterators! {
     haystack bounds: (InverseMatchesAreValid);
     delegate single ended;
 }
+
+///////////////////////////////////////////////////////////////////////////////
+// .rejects()
+///////////////////////////////////////////////////////////////////////////////
+
+derive_pattern_clone!{
+    clone RejectsInternal
+    with |s| RejectsInternal(s.0.clone())
+}
+
+// The pieces of a split, without the empty ones. Joining adjacent rejects
+// of the searcher instead would also join them across empty matches.
+struct RejectsInternal<H, P>(SplitInternal<H, P>)
+    where P: Pattern<H>,
+          H: PatternHaystack;
+
+impl<H, P> fmt::Debug for RejectsInternal<H, P>
+    where P::Searcher: fmt::Debug,
+          P: Pattern<H>,
+          H: PatternHaystack,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<H, P> RejectsInternal<H, P>
+    where P: Pattern<H>,
+          H: PatternHaystack,
+{
+    #[inline]
+    fn new(h: H, p: P) -> Self {
+        RejectsInternal(SplitInternal::new(h, p))
+    }
+
+    #[inline]
+    fn haystack(&self) -> H::Haystack {
+        self.0.matcher.haystack()
+    }
+
+    #[inline]
+    fn next_range(&mut self) -> Option<(H::Cursor, H::Cursor)> {
+        while let Some((a, b)) = self.0.next_range() {
+            if a != b {
+                return Some((a, b));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn next_range_back(&mut self) -> Option<(H::Cursor, H::Cursor)>
+        where P::Searcher: ReverseSearcher<H>
+    {
+        while let Some((a, b)) = self.0.next_range_back() {
+            if a != b {
+                return Some((a, b));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<H::MatchType> {
+        let h = self.haystack();
+        self.next_range().map(|(a, b)| unsafe { H::range_to_self(h, a, b) })
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<H::MatchType>
+        where P::Searcher: ReverseSearcher<H>
+    {
+        let h = self.haystack();
+        self.next_range_back().map(|(a, b)| unsafe { H::range_to_self(h, a, b) })
+    }
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`rejects()`].
+        ///
+        /// Yields each non-empty part between the matches.
+        struct Rejects;
+    reverse:
+        /// Created with the method [`rrejects()`].
+        ///
+        /// Yields each non-empty part between the matches,
+        /// starting from the back.
+        struct RRejects;
+    stability:
+    internal:
+        RejectsInternal() yielding (H::MatchType);
+    haystack bounds: (InverseMatchesAreValid);
+    delegate double ended;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// .reject_indices()
+///////////////////////////////////////////////////////////////////////////////
+
+derive_pattern_clone!{
+    clone RejectIndicesInternal
+    with |s| RejectIndicesInternal(s.0.clone())
+}
+
+struct RejectIndicesInternal<H, P>(RejectsInternal<H, P>)
+    where P: Pattern<H>,
+          H: PatternHaystack;
+
+impl<H, P> fmt::Debug for RejectIndicesInternal<H, P>
+    where P::Searcher: fmt::Debug,
+          P: Pattern<H>,
+          H: PatternHaystack,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<H, P> RejectIndicesInternal<H, P>
+    where P: Pattern<H>,
+          H: PatternHaystack,
+{
+    #[inline]
+    fn new(h: H, p: P) -> Self {
+        RejectIndicesInternal(RejectsInternal::new(h, p))
+    }
+
+    #[inline]
+    fn with_offset(&self, range: Option<(H::Cursor, H::Cursor)>) -> Option<(usize, H::MatchType)> {
+        let h = self.0.haystack();
+        range.map(|(a, b)| unsafe {
+            (H::offset_from_front(h, a), H::range_to_self(h, a, b))
+        })
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, H::MatchType)> {
+        let range = self.0.next_range();
+        self.with_offset(range)
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, H::MatchType)>
+        where P::Searcher: ReverseSearcher<H>
+    {
+        let range = self.0.next_range_back();
+        self.with_offset(range)
+    }
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`reject_indices()`].
+        ///
+        /// Yields each non-empty part between the matches,
+        /// together with its offset from the front.
+        struct RejectIndices;
+    reverse:
+        /// Created with the method [`rreject_indices()`].
+        ///
+        /// Yields each non-empty part between the matches,
+        /// together with its offset from the front, starting from the back.
+        struct RRejectIndices;
+    stability:
+    internal:
+        RejectIndicesInternal() yielding ((usize, H::MatchType));
+    haystack bounds: (InverseMatchesAreValid);
+    delegate double ended;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// .tokens()
+///////////////////////////////////////////////////////////////////////////////
+
+/// A part of the haystack, as yielded by [`tokens()`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Token<T> {
+    Match(T),
+    Reject(T),
+}
+
+impl<T> Token<T> {
+    #[inline]
+    pub fn is_match(&self) -> bool {
+        match *self {
+            Token::Match(_) => true,
+            Token::Reject(_) => false,
+        }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> T {
+        match self {
+            Token::Match(t) | Token::Reject(t) => t,
+        }
+    }
+}
+
+derive_pattern_clone!{
+    clone TokensInternal
+    with |s| TokensInternal { matcher: s.matcher.clone(), ..*s }
+}
+
+struct TokensInternal<H, P>
+    where P: Pattern<H>,
+          H: PatternHaystack,
+{
+    start: H::Cursor,
+    end: H::Cursor,
+    matcher: P::Searcher,
+    // A match found behind a reject, yielded next
+    pending: Option<(H::Cursor, H::Cursor)>,
+    pending_back: Option<(H::Cursor, H::Cursor)>,
+}
+
+// Like `SplitInternal`, also for the cursors of the pending matches
+unsafe impl<H, P> Send for TokensInternal<H, P>
+    where P: Pattern<H>,
+          P::Searcher: Send,
+          H: PatternHaystack + Send,
+{}
+
+unsafe impl<H, P> Sync for TokensInternal<H, P>
+    where P: Pattern<H>,
+          P::Searcher: Sync,
+          H: PatternHaystack + Sync,
+{}
+
+impl<H, P> fmt::Debug for TokensInternal<H, P>
+    where P::Searcher: fmt::Debug,
+          P: Pattern<H>,
+          H: PatternHaystack,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TokensInternal")
+            .field("matcher", &self.matcher)
+            .finish()
+    }
+}
+
+impl<H, P> TokensInternal<H, P>
+    where P: Pattern<H>,
+          H: PatternHaystack,
+{
+    #[inline]
+    fn new(h: H, p: P) -> Self {
+        let matcher = p.into_searcher(h);
+        let hs = matcher.haystack();
+        TokensInternal {
+            start: H::cursor_at_front(hs),
+            end: H::cursor_at_back(hs),
+            matcher: matcher,
+            pending: None,
+            pending_back: None,
+        }
+    }
+
+    fn next_range(&mut self) -> Option<Token<(H::Cursor, H::Cursor)>> {
+        if let Some((a, b)) = self.pending.take() {
+            self.start = b;
+            return Some(Token::Match((a, b)));
+        }
+        match self.matcher.next_match() {
+            Some((a, b)) if a == self.start => {
+                self.start = b;
+                Some(Token::Match((a, b)))
+            }
+            Some((a, b)) => {
+                self.pending = Some((a, b));
+                let reject = (self.start, a);
+                self.start = a;
+                Some(Token::Reject(reject))
+            }
+            None => {
+                // the rest up to the match taken by the back is a reject
+                let limit = self.pending_back.map_or(self.end, |(a, _)| a);
+                if self.start != limit {
+                    let reject = (self.start, limit);
+                    self.start = limit;
+                    Some(Token::Reject(reject))
+                } else {
+                    self.pending_back.take().map(|(a, b)| {
+                        self.start = b;
+                        Token::Match((a, b))
+                    })
+                }
+            }
+        }
+    }
+
+    fn next_range_back(&mut self) -> Option<Token<(H::Cursor, H::Cursor)>>
+        where P::Searcher: ReverseSearcher<H>
+    {
+        if let Some((a, b)) = self.pending_back.take() {
+            self.end = a;
+            return Some(Token::Match((a, b)));
+        }
+        match self.matcher.next_match_back() {
+            Some((a, b)) if b == self.end => {
+                self.end = a;
+                Some(Token::Match((a, b)))
+            }
+            Some((a, b)) => {
+                self.pending_back = Some((a, b));
+                let reject = (b, self.end);
+                self.end = b;
+                Some(Token::Reject(reject))
+            }
+            None => {
+                let limit = self.pending.map_or(self.start, |(_, b)| b);
+                if self.end != limit {
+                    let reject = (limit, self.end);
+                    self.end = limit;
+                    Some(Token::Reject(reject))
+                } else {
+                    self.pending.take().map(|(a, b)| {
+                        self.end = a;
+                        Token::Match((a, b))
+                    })
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn to_self(&self, token: Token<(H::Cursor, H::Cursor)>) -> Token<H::MatchType> {
+        let h = self.matcher.haystack();
+        unsafe {
+            match token {
+                Token::Match((a, b)) => Token::Match(H::range_to_self(h, a, b)),
+                Token::Reject((a, b)) => Token::Reject(H::range_to_self(h, a, b)),
+            }
+        }
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Token<H::MatchType>> {
+        self.next_range().map(|token| self.to_self(token))
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<Token<H::MatchType>>
+        where P::Searcher: ReverseSearcher<H>
+    {
+        self.next_range_back().map(|token| self.to_self(token))
+    }
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`tokens()`].
+        ///
+        /// Yields the whole haystack, as matches and the non-empty
+        /// parts between them.
+        struct Tokens;
+    reverse:
+        /// Created with the method [`rtokens()`].
+        ///
+        /// Yields the whole haystack, as matches and the non-empty
+        /// parts between them, starting from the back.
+        struct RTokens;
+    stability:
+    internal:
+        TokensInternal() yielding (Token<H::MatchType>);
+    haystack bounds: (InverseMatchesAreValid);
+    delegate double ended;
+}
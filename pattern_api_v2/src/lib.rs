@@ -1,12 +1,19 @@
-#![feature(str_char)]
-#![feature(specialization)]
-#![feature(associated_type_defaults)]
-#![feature(osstring_simple_functions)]
-#![feature(decode_utf16)]
-#![feature(unicode)]
-#![feature(stmt_expr_attributes)]
-#![feature(inclusive_range_syntax)]
-#![feature(range_contains)]
+// The core traits, the `&str` and `&[T]` impls and the iterators
+// only need `core`. The `OsStr` support requires the default `std`
+// feature, and the APIs producing owned values the `alloc` feature.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// The code base predates these idioms, and sticks to its style
+#![allow(clippy::redundant_field_names, clippy::redundant_static_lifetimes)]
+#![allow(clippy::needless_lifetimes, clippy::ptr_offset_with_cast)]
+#![allow(clippy::missing_safety_doc, clippy::wrong_self_convention)]
+
+#[cfg(feature = "std")]
+extern crate core;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[macro_use]
 mod macros;
@@ -14,16 +21,38 @@ mod macros;
 // TODO: This is mostly stolen from std::str
 mod utf8;
 
+mod unicode_tables;
+
 pub mod fast_sequence_search;
 
 pub mod core_traits;
 
 pub mod string;
 pub mod slice;
+pub mod sets;
+pub mod aligned;
+pub mod char_classes;
+pub mod byte_str;
+#[cfg(feature = "std")]
+mod os_str_bytes;
+#[cfg(feature = "std")]
 pub mod os_string;
+#[cfg(feature = "std")]
+pub mod path;
+pub mod segmentation;
+#[cfg(feature = "alloc")]
+pub mod normalization;
+#[cfg(feature = "alloc")]
+pub mod boxed;
+#[cfg(feature = "alloc")]
+pub mod approx;
+#[cfg(feature = "alloc")]
+pub mod signature;
 
 pub mod iterators;
 
+pub mod lines;
+
 pub mod experimental;
 
 pub mod std_integration;
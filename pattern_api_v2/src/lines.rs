@@ -0,0 +1,300 @@
+//! Line-oriented iteration over text haystacks.
+//!
+//! Unlike `split('\n')`, the iterators here strip a `\r` in front of
+//! a `\n`, and do not produce an empty piece after a final line terminator.
+
+use ::{Pattern, PatternHaystack, Searcher, ReverseSearcher, InverseMatchesAreValid};
+use fast_sequence_search::OrdSlice;
+use slice::Elem;
+
+/// A haystack that can be viewed as UTF-8 (or WTF-8) encoded bytes,
+/// and cut into lines at line terminators.
+pub trait LineHaystack: PatternHaystack {
+    type Line;
+
+    fn haystack_as_bytes(hs: &Self::Haystack) -> &[u8];
+
+    /// `start` and `end` need to be at the front/back of the haystack
+    /// or directly next to a line terminator found in `haystack_as_bytes()`.
+    unsafe fn line_from_range(hs: Self::Haystack,
+                              start: usize,
+                              end: usize) -> Self::Line;
+}
+
+impl<H> LineHaystack for H
+    where H: OrdSlice<NeedleElement=u8> + InverseMatchesAreValid
+{
+    type Line = H::MatchType;
+
+    #[inline]
+    fn haystack_as_bytes(hs: &H::Haystack) -> &[u8] {
+        H::haystack_as_slice(hs)
+    }
+
+    #[inline]
+    unsafe fn line_from_range(hs: H::Haystack, start: usize, end: usize) -> H::MatchType {
+        H::range_to_self(hs, H::cursor_at_offset(hs, start), H::cursor_at_offset(hs, end))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Line terminator search
+///////////////////////////////////////////////////////////////////////////////
+
+/// Finds the first `\n`, with the byte search of the slice patterns.
+fn find_newline(text: &[u8]) -> Option<usize> {
+    let mut searcher = Elem(b'\n').into_searcher(text);
+    let hs = searcher.haystack();
+    searcher.next_match().map(|(a, _)| <&[u8]>::offset_from_front(hs, a))
+}
+
+/// Finds the last `\n`, with the byte search of the slice patterns.
+fn rfind_newline(text: &[u8]) -> Option<usize> {
+    let mut searcher = Elem(b'\n').into_searcher(text);
+    let hs = searcher.haystack();
+    searcher.next_match_back().map(|(a, _)| <&[u8]>::offset_from_front(hs, a))
+}
+
+/// Finds the first line terminator in `text`, returning its range.
+fn next_terminator(text: &[u8], unicode: bool) -> Option<(usize, usize)> {
+    if !unicode {
+        return find_newline(text).map(|i| {
+            if i > 0 && text[i - 1] == b'\r' { (i - 1, i + 1) } else { (i, i + 1) }
+        });
+    }
+
+    let mut i = 0;
+    while i < text.len() {
+        match text[i] {
+            b'\n' | 0x0B | 0x0C => return Some((i, i + 1)),
+            b'\r' => {
+                let len = if text.get(i + 1) == Some(&b'\n') { 2 } else { 1 };
+                return Some((i, i + len));
+            }
+            // U+0085 NEXT LINE
+            0xC2 if text[i + 1..].starts_with(&[0x85]) => return Some((i, i + 2)),
+            // U+2028 LINE SEPARATOR, U+2029 PARAGRAPH SEPARATOR
+            0xE2 if text[i + 1..].starts_with(&[0x80, 0xA8])
+                 || text[i + 1..].starts_with(&[0x80, 0xA9]) => return Some((i, i + 3)),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Finds the last line terminator in `text`, returning its range.
+fn next_terminator_back(text: &[u8], unicode: bool) -> Option<(usize, usize)> {
+    if !unicode {
+        return rfind_newline(text).map(|i| {
+            if i > 0 && text[i - 1] == b'\r' { (i - 1, i + 1) } else { (i, i + 1) }
+        });
+    }
+
+    let mut i = text.len();
+    while i > 0 {
+        i -= 1;
+        match text[i] {
+            b'\n' => {
+                let start = if i > 0 && text[i - 1] == b'\r' { i - 1 } else { i };
+                return Some((start, i + 1));
+            }
+            b'\r' | 0x0B | 0x0C => return Some((i, i + 1)),
+            0x85 if text[..i].ends_with(&[0xC2]) => return Some((i - 1, i + 1)),
+            0xA8 | 0xA9 if text[..i].ends_with(&[0xE2, 0x80]) => return Some((i - 2, i + 1)),
+            _ => {}
+        }
+    }
+    None
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// .lines()
+///////////////////////////////////////////////////////////////////////////////
+
+struct LinesInternal<H: LineHaystack> {
+    haystack: H::Haystack,
+    start: usize,
+    end: usize,
+    unicode: bool,
+    allow_trailing_empty: bool,
+    finished: bool,
+}
+
+// Only reads the bytes after `start` and before `end`,
+// which have not been returned as lines yet
+unsafe impl<H: LineHaystack + Send> Send for LinesInternal<H> {}
+unsafe impl<H: LineHaystack + Sync> Sync for LinesInternal<H> {}
+
+impl<H: LineHaystack> Clone for LinesInternal<H> {
+    fn clone(&self) -> Self {
+        LinesInternal {
+            haystack: self.haystack,
+            start: self.start,
+            end: self.end,
+            unicode: self.unicode,
+            allow_trailing_empty: self.allow_trailing_empty,
+            finished: self.finished,
+        }
+    }
+}
+
+impl<H: LineHaystack> LinesInternal<H> {
+    #[inline]
+    fn new(h: H, unicode: bool) -> Self {
+        let haystack = h.into_haystack();
+        let len = H::haystack_as_bytes(&haystack).len();
+        LinesInternal {
+            haystack: haystack,
+            start: 0,
+            end: len,
+            unicode: unicode,
+            allow_trailing_empty: false,
+            finished: false,
+        }
+    }
+
+    #[inline]
+    fn get_end(&mut self) -> Option<(usize, usize)> {
+        if !self.finished && (self.allow_trailing_empty || self.end > self.start) {
+            self.finished = true;
+            return Some((self.start, self.end));
+        }
+        self.finished = true;
+        None
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.finished {
+            return None;
+        }
+
+        let found = {
+            let bytes = H::haystack_as_bytes(&self.haystack);
+            next_terminator(&bytes[self.start..self.end], self.unicode)
+        };
+        match found {
+            Some((a, b)) => {
+                let line = (self.start, self.start + a);
+                self.start += b;
+                Some(line)
+            }
+            None => self.get_end(),
+        }
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, usize)> {
+        if self.finished {
+            return None;
+        }
+
+        if !self.allow_trailing_empty {
+            self.allow_trailing_empty = true;
+            match self.next_back() {
+                Some((a, b)) if b > a => return Some((a, b)),
+                _ => if self.finished { return None; }
+            }
+        }
+
+        let found = {
+            let bytes = H::haystack_as_bytes(&self.haystack);
+            next_terminator_back(&bytes[self.start..self.end], self.unicode)
+        };
+        match found {
+            Some((a, b)) => {
+                let line = (self.start + b, self.end);
+                self.end = self.start + a;
+                Some(line)
+            }
+            None => {
+                self.finished = true;
+                Some((self.start, self.end))
+            }
+        }
+    }
+
+    #[inline]
+    fn line(&self, (a, b): (usize, usize)) -> H::Line {
+        unsafe {
+            // a and b are always next to a line terminator
+            // or at the ends of the haystack
+            H::line_from_range(self.haystack, a, b)
+        }
+    }
+}
+
+/// Created with the methods [`lines()`] and [`lines_any()`].
+///
+/// [`lines()`]: ../std_integration/trait.IteratorConstructors.html#method.lines
+/// [`lines_any()`]: ../std_integration/trait.IteratorConstructors.html#method.lines_any
+pub struct Lines<H: LineHaystack>(LinesInternal<H>);
+
+impl<H: LineHaystack> Lines<H> {
+    #[inline]
+    pub fn new(h: H, unicode: bool) -> Self {
+        Lines(LinesInternal::new(h, unicode))
+    }
+}
+
+impl<H: LineHaystack> Clone for Lines<H> {
+    fn clone(&self) -> Self {
+        Lines(self.0.clone())
+    }
+}
+
+impl<H: LineHaystack> Iterator for Lines<H> {
+    type Item = H::Line;
+
+    #[inline]
+    fn next(&mut self) -> Option<H::Line> {
+        self.0.next().map(|r| self.0.line(r))
+    }
+}
+
+impl<H: LineHaystack> DoubleEndedIterator for Lines<H> {
+    #[inline]
+    fn next_back(&mut self) -> Option<H::Line> {
+        self.0.next_back().map(|r| self.0.line(r))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// .line_indices()
+///////////////////////////////////////////////////////////////////////////////
+
+/// Created with the methods [`line_indices()`] and [`line_indices_any()`].
+///
+/// [`line_indices()`]: ../std_integration/trait.IteratorConstructors.html#method.line_indices
+/// [`line_indices_any()`]: ../std_integration/trait.IteratorConstructors.html#method.line_indices_any
+pub struct LineIndices<H: LineHaystack>(LinesInternal<H>);
+
+impl<H: LineHaystack> LineIndices<H> {
+    #[inline]
+    pub fn new(h: H, unicode: bool) -> Self {
+        LineIndices(LinesInternal::new(h, unicode))
+    }
+}
+
+impl<H: LineHaystack> Clone for LineIndices<H> {
+    fn clone(&self) -> Self {
+        LineIndices(self.0.clone())
+    }
+}
+
+impl<H: LineHaystack> Iterator for LineIndices<H> {
+    type Item = (usize, H::Line);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, H::Line)> {
+        self.0.next().map(|r| (r.0, self.0.line(r)))
+    }
+}
+
+impl<H: LineHaystack> DoubleEndedIterator for LineIndices<H> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, H::Line)> {
+        self.0.next_back().map(|r| (r.0, self.0.line(r)))
+    }
+}
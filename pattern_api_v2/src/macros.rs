@@ -1,7 +1,34 @@
 
 macro_rules! pattern_methods {
+    // For references to a pattern, which are copied
+    // to let the pattern check the needle
+    (copy $t:ty, $pmap:expr, $smap:expr, $slice:ty) => {
+        pattern_methods!($t, $pmap, $smap, $slice);
+
+        #[inline]
+        fn validate(&self) -> Result<(), ::PatternError> {
+            ::Pattern::<$slice>::validate(&($pmap)(*self))
+        }
+    };
     ($t:ty, $pmap:expr, $smap:expr, $slice:ty) => {
         pattern_methods!($t, $pmap, $smap, $slice, |s| s);
+
+        // Only forwarded if the haystack is not mapped,
+        // since the returned remainder is of the haystack type
+
+        #[inline]
+        fn strip_prefix_of(self, haystack: $slice)
+            -> Option<<$slice as PatternHaystack>::MatchType>
+        {
+            ($pmap)(self).strip_prefix_of(haystack)
+        }
+
+        #[inline]
+        fn strip_suffix_of(self, haystack: $slice)
+            -> Option<<$slice as PatternHaystack>::MatchType>
+        {
+            ($pmap)(self).strip_suffix_of(haystack)
+        }
     };
     ($t:ty, $pmap:expr, $smap:expr, $slice:ty, $slice_map:expr) => {
         type Searcher = $t;
@@ -27,10 +54,26 @@ macro_rules! pattern_methods {
         {
             ($pmap)(self).is_suffix_of($slice_map(haystack))
         }
+
+        #[inline]
+        fn matches_at(self, haystack: $slice, offset: usize) -> Option<usize> {
+            ($pmap)(self).matches_at($slice_map(haystack), offset)
+        }
     }
 }
 
 macro_rules! searcher_methods {
+    // Also forwards `as_seekable()`, for wrappers of
+    // a searcher of the same haystack type
+    (forward, $inner_ident:ident, $inner:expr, $cursor:ty, $haystack:ty) => {
+        searcher_methods!(forward, $inner_ident, $inner, $cursor);
+
+        #[inline]
+        fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<$haystack>> {
+            let $inner_ident = self;
+            $inner.as_seekable()
+        }
+    };
     (forward, $inner_ident:ident, $inner:expr, $cursor:ty) => {
         #[inline]
         fn haystack(&self) -> ($cursor, $cursor) {
@@ -59,22 +102,17 @@ macro_rules! searcher_methods {
             let $inner_ident = self;
             $inner.next_reject_back()
         }
-    }
-}
-
-macro_rules! cfg_match {
-    (_ => $b:block) => {
-        $b
     };
-    ($cfg:meta => $b:block $($t:tt)*) => {
-        (
-            #[cfg($cfg)] $b,
-            #[cfg(not($cfg))] (cfg_match!($($t)*)),
-        ).0
-    };
-    () => {
-        {
-            panic!("Conditional code not supported under this configuration");
+    (seek, $inner_ident:ident, $inner:expr) => {
+        #[inline]
+        fn seek_front(&mut self, offset: usize) -> bool {
+            let $inner_ident = self;
+            $inner.seek_front(offset)
+        }
+        #[inline]
+        fn seek_back(&mut self, offset: usize) -> bool {
+            let $inner_ident = self;
+            $inner.seek_back(offset)
         }
     }
 }
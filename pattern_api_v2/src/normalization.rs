@@ -0,0 +1,541 @@
+//! Normalization-insensitive search.
+//!
+//! `Canonical` wraps a `&str` needle and matches every piece of the
+//! haystack that is canonically equivalent to it, no matter whether
+//! needle and haystack are in NFC, NFD or neither of the two.
+//! So `Canonical("\u{e9}")` finds both a precomposed `é` and
+//! an `e` followed by U+0301 COMBINING ACUTE ACCENT.
+//!
+//! The haystack is never copied. Both sides are compared in NFD one
+//! normalization segment at a time, and matches are reported as ranges
+//! of the original, unnormalized haystack. Matches only start and end
+//! at segment boundaries, that is in front of a character whose
+//! decomposition starts with a starter that can not compose with the
+//! character before it. This means `Canonical("e")` does not match
+//! inside of `é`, no matter how it is encoded.
+
+use ::{Pattern, PatternHaystack, Searcher, ReverseSearcher, SeekableSearcher, Span};
+#[cfg(feature = "std")]
+use os_string::shared::PartialUnicode;
+use unicode_tables::{CANONICAL_COMBINING_CLASS_TABLE, CANONICAL_DECOMPOSITION_TABLE};
+use unicode_tables::COMPOSING_STARTER_PAIRS;
+use utf8::{char_at, char_before, is_inside_char};
+
+use core::cmp::Ordering;
+use core::slice;
+use alloc::vec::Vec;
+
+///////////////////////////////////////////////////////////////////////////////
+// Decomposition
+///////////////////////////////////////////////////////////////////////////////
+
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+fn combining_class(c: char) -> u8 {
+    match CANONICAL_COMBINING_CLASS_TABLE.binary_search_by(|&(lo, hi, _)| {
+        if hi < c { Ordering::Less } else if lo > c { Ordering::Greater } else { Ordering::Equal }
+    }) {
+        Ok(i) => CANONICAL_COMBINING_CLASS_TABLE[i].2,
+        Err(_) => 0,
+    }
+}
+
+fn table_decomposition(c: char) -> Option<&'static [char]> {
+    CANONICAL_DECOMPOSITION_TABLE.binary_search_by(|&(k, _)| k.cmp(&c))
+                                 .ok()
+                                 .map(|i| CANONICAL_DECOMPOSITION_TABLE[i].1)
+}
+
+#[inline]
+fn hangul_index(c: char) -> Option<u32> {
+    let c = c as u32;
+    if (S_BASE..S_BASE + S_COUNT).contains(&c) { Some(c - S_BASE) } else { None }
+}
+
+#[inline]
+fn from_u32(c: u32) -> char {
+    // only used for the hangul jamo computed below
+    ::core::char::from_u32(c).unwrap()
+}
+
+/// Appends the full canonical decomposition of `c`.
+fn decompose(c: char, out: &mut Vec<char>) {
+    if let Some(s) = hangul_index(c) {
+        out.push(from_u32(L_BASE + s / N_COUNT));
+        out.push(from_u32(V_BASE + (s % N_COUNT) / T_COUNT));
+        if s % T_COUNT != 0 {
+            out.push(from_u32(T_BASE + s % T_COUNT));
+        }
+        return;
+    }
+    match table_decomposition(c) {
+        Some(d) => out.extend_from_slice(d),
+        None => out.push(c),
+    }
+}
+
+fn first_decomposed(c: char) -> char {
+    if let Some(s) = hangul_index(c) {
+        return from_u32(L_BASE + s / N_COUNT);
+    }
+    table_decomposition(c).map(|d| d[0]).unwrap_or(c)
+}
+
+fn last_decomposed(c: char) -> char {
+    if let Some(s) = hangul_index(c) {
+        return if s % T_COUNT != 0 {
+            from_u32(T_BASE + s % T_COUNT)
+        } else {
+            from_u32(V_BASE + (s % N_COUNT) / T_COUNT)
+        };
+    }
+    table_decomposition(c).map(|d| d[d.len() - 1]).unwrap_or(c)
+}
+
+/// Sorts each run of non-starters by combining class, keeping the
+/// original order of equal classes.
+fn canonical_order(buf: &mut [char]) {
+    for i in 1..buf.len() {
+        let class = combining_class(buf[i]);
+        if class == 0 {
+            continue;
+        }
+        let mut j = i;
+        while j > 0 && combining_class(buf[j - 1]) > class {
+            buf.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// Whether the starters `a` and `b` compose in NFC.
+fn starters_compose(a: char, b: char) -> bool {
+    let (a_u, b_u) = (a as u32, b as u32);
+    let is_l = |c: u32| (L_BASE..L_BASE + L_COUNT).contains(&c);
+    let is_v = |c: u32| (V_BASE..V_BASE + V_COUNT).contains(&c);
+    let is_t = |c: u32| (T_BASE + 1..T_BASE + T_COUNT).contains(&c);
+
+    (is_l(a_u) && is_v(b_u))
+        || (is_v(a_u) && is_t(b_u))
+        || COMPOSING_STARTER_PAIRS.binary_search(&(a, b)).is_ok()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Segment boundaries in possibly ill-formed UTF-8
+///////////////////////////////////////////////////////////////////////////////
+
+/// Bytes that are not part of a valid char are segments of their own.
+fn is_segment_boundary(bytes: &[u8], pos: usize) -> bool {
+    if pos == 0 || pos >= bytes.len() {
+        return true;
+    }
+    if is_inside_char(bytes, pos) {
+        return false;
+    }
+    match (char_before(bytes, pos), char_at(bytes, pos)) {
+        (Some(before), Some((after, _))) => {
+            let first = first_decomposed(after);
+            combining_class(first) == 0 && !starters_compose(last_decomposed(before), first)
+        }
+        _ => true,
+    }
+}
+
+fn next_segment_boundary(bytes: &[u8], mut pos: usize) -> usize {
+    pos += 1;
+    while !is_segment_boundary(bytes, pos) {
+        pos += 1;
+    }
+    pos
+}
+
+fn prev_segment_boundary(bytes: &[u8], mut pos: usize) -> usize {
+    pos -= 1;
+    while !is_segment_boundary(bytes, pos) {
+        pos -= 1;
+    }
+    pos
+}
+
+/// If the NFD of `bytes[start..e]` equals `needle` for a segment
+/// boundary `e <= limit`, returns `e`.
+fn match_at(bytes: &[u8],
+            needle: &[char],
+            buf: &mut Vec<char>,
+            start: usize,
+            limit: usize) -> Option<usize> {
+    let mut matched = 0;
+    let mut pos = start;
+    loop {
+        if matched == needle.len() {
+            return Some(pos);
+        }
+        if pos == limit {
+            return None;
+        }
+        let end = next_segment_boundary(bytes, pos);
+        buf.clear();
+        let mut i = pos;
+        while i < end {
+            match char_at(bytes, i) {
+                Some((c, w)) => {
+                    decompose(c, buf);
+                    i += w;
+                }
+                None => return None,
+            }
+        }
+        canonical_order(buf);
+
+        if needle.len() - matched < buf.len() || needle[matched..matched + buf.len()] != buf[..] {
+            return None;
+        }
+        matched += buf.len();
+        pos = end;
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Pattern
+///////////////////////////////////////////////////////////////////////////////
+
+/// Searches for strings canonically equivalent to the wrapped needle.
+///
+/// Works on `&str` and on `PartialUnicode` haystacks, where bytes that are
+/// not valid UTF-8 never match.
+///
+/// Will handle the pattern `Canonical("")` as returning empty matches
+/// at each segment boundary.
+#[derive(Copy, Clone, Debug)]
+pub struct Canonical<'b>(pub &'b str);
+
+enum SearchStep {
+    Match(usize, usize),
+    Reject(usize, usize),
+    Done
+}
+
+/// Associated type for `<Canonical<'b> as Pattern<H>>::Searcher`.
+#[derive(Clone)]
+pub struct CanonicalSearcher<'a> {
+    haystack: &'a [u8],
+    // The needle in NFD
+    needle: Vec<char>,
+    buf: Vec<char>,
+    front: usize,
+    back: usize,
+    // Next match found while looking for a reject
+    pending_front: Option<(usize, usize)>,
+    pending_back: Option<(usize, usize)>,
+    // Whether the empty needle still matches at `front`/`back`
+    empty_match_front: bool,
+    empty_match_back: bool,
+}
+
+impl<'a> CanonicalSearcher<'a> {
+    fn new(haystack: &'a [u8], needle: &str) -> Self {
+        let mut nfd = Vec::with_capacity(needle.len());
+        for c in needle.chars() {
+            decompose(c, &mut nfd);
+        }
+        canonical_order(&mut nfd);
+
+        CanonicalSearcher {
+            haystack: haystack,
+            needle: nfd,
+            buf: Vec::new(),
+            front: 0,
+            back: haystack.len(),
+            pending_front: None,
+            pending_back: None,
+            empty_match_front: true,
+            empty_match_back: true,
+        }
+    }
+
+    fn find_front(&mut self) -> Option<(usize, usize)> {
+        let mut start = self.front;
+        while start < self.back {
+            if let Some(end) = match_at(self.haystack, &self.needle, &mut self.buf,
+                                        start, self.back) {
+                return Some((start, end));
+            }
+            start = next_segment_boundary(self.haystack, start);
+        }
+        None
+    }
+
+    // Matches can not nest, so the match with the last start
+    // is also the one with the last end.
+    fn find_back(&mut self) -> Option<(usize, usize)> {
+        let mut start = self.back;
+        while start > self.front {
+            start = prev_segment_boundary(self.haystack, start);
+            if let Some(end) = match_at(self.haystack, &self.needle, &mut self.buf,
+                                        start, self.back) {
+                return Some((start, end));
+            }
+        }
+        None
+    }
+
+    fn next(&mut self) -> SearchStep {
+        if self.needle.is_empty() {
+            if self.empty_match_front {
+                self.empty_match_front = false;
+                if self.front == self.back {
+                    // only report the last empty match once
+                    if !self.empty_match_back {
+                        return SearchStep::Done;
+                    }
+                    self.empty_match_back = false;
+                }
+                return SearchStep::Match(self.front, self.front);
+            }
+            if self.front == self.back {
+                return SearchStep::Done;
+            }
+            let a = self.front;
+            self.front = next_segment_boundary(self.haystack, a);
+            self.empty_match_front = true;
+            return SearchStep::Reject(a, self.front);
+        }
+
+        if self.front == self.back {
+            return SearchStep::Done;
+        }
+        let found = match self.pending_front.take() {
+            Some((a, b)) if b <= self.back => Some((a, b)),
+            _ => self.find_front(),
+        };
+        match found {
+            Some((a, b)) if a == self.front => {
+                self.front = b;
+                SearchStep::Match(a, b)
+            }
+            Some((a, b)) => {
+                self.pending_front = Some((a, b));
+                let reject = SearchStep::Reject(self.front, a);
+                self.front = a;
+                reject
+            }
+            None => {
+                let reject = SearchStep::Reject(self.front, self.back);
+                self.front = self.back;
+                reject
+            }
+        }
+    }
+
+    fn next_back(&mut self) -> SearchStep {
+        if self.needle.is_empty() {
+            if self.empty_match_back {
+                self.empty_match_back = false;
+                if self.front == self.back {
+                    if !self.empty_match_front {
+                        return SearchStep::Done;
+                    }
+                    self.empty_match_front = false;
+                }
+                return SearchStep::Match(self.back, self.back);
+            }
+            if self.front == self.back {
+                return SearchStep::Done;
+            }
+            let b = self.back;
+            self.back = prev_segment_boundary(self.haystack, b);
+            self.empty_match_back = true;
+            return SearchStep::Reject(self.back, b);
+        }
+
+        if self.front == self.back {
+            return SearchStep::Done;
+        }
+        let found = match self.pending_back.take() {
+            Some((a, b)) if a >= self.front => Some((a, b)),
+            _ => self.find_back(),
+        };
+        match found {
+            Some((a, b)) if b == self.back => {
+                self.back = a;
+                SearchStep::Match(a, b)
+            }
+            Some((a, b)) => {
+                self.pending_back = Some((a, b));
+                let reject = SearchStep::Reject(b, self.back);
+                self.back = b;
+                reject
+            }
+            None => {
+                let reject = SearchStep::Reject(self.front, self.back);
+                self.back = self.front;
+                reject
+            }
+        }
+    }
+
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(a, b) => return Some((a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    fn next_reject(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some((a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(a, b) => return Some((a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    fn next_reject_back(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some((a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    // Seeking is only allowed to segment boundaries, where matches
+    // start and end. `valid` tells if the haystack allows the offset.
+    fn seek_front(&mut self, offset: usize, valid: bool) -> bool {
+        if !valid || offset > self.back || !is_segment_boundary(self.haystack, offset) {
+            return false;
+        }
+        self.front = offset;
+        self.pending_front = None;
+        self.empty_match_front = true;
+        true
+    }
+
+    fn seek_back(&mut self, offset: usize, valid: bool) -> bool {
+        if !valid || offset < self.front || offset > self.haystack.len()
+            || !is_segment_boundary(self.haystack, offset)
+        {
+            return false;
+        }
+        self.back = offset;
+        self.pending_back = None;
+        self.empty_match_back = true;
+        true
+    }
+
+    // Cursors of both haystacks are pointers into the bytes
+    #[inline]
+    fn cursors(&self, (a, b): (usize, usize)) -> (*const u8, *const u8) {
+        unsafe {
+            (self.haystack.as_ptr().offset(a as isize),
+             self.haystack.as_ptr().offset(b as isize))
+        }
+    }
+}
+
+macro_rules! canonical_searcher_impls {
+    ($haystack:ty) => {
+        unsafe impl<'a> Searcher<$haystack> for CanonicalSearcher<'a> {
+            #[inline]
+            fn haystack(&self) -> (*const u8, *const u8) {
+                self.cursors((0, self.haystack.len()))
+            }
+
+            #[inline]
+            fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<$haystack>> {
+                Some(self)
+            }
+
+            #[inline]
+            fn next_match(&mut self) -> Option<(*const u8, *const u8)> {
+                CanonicalSearcher::next_match(self).map(|r| self.cursors(r))
+            }
+
+            #[inline]
+            fn next_reject(&mut self) -> Option<(*const u8, *const u8)> {
+                CanonicalSearcher::next_reject(self).map(|r| self.cursors(r))
+            }
+        }
+
+        unsafe impl<'a> ReverseSearcher<$haystack> for CanonicalSearcher<'a> {
+            #[inline]
+            fn next_match_back(&mut self) -> Option<(*const u8, *const u8)> {
+                CanonicalSearcher::next_match_back(self).map(|r| self.cursors(r))
+            }
+
+            #[inline]
+            fn next_reject_back(&mut self) -> Option<(*const u8, *const u8)> {
+                CanonicalSearcher::next_reject_back(self).map(|r| self.cursors(r))
+            }
+        }
+
+        impl<'a> SeekableSearcher<$haystack> for CanonicalSearcher<'a> {
+            #[inline]
+            fn seek_front(&mut self, offset: usize) -> bool {
+                let span = Span { start: offset, end: offset };
+                let hs = self.cursors((0, self.haystack.len()));
+                let valid = <$haystack>::span_to_cursors(hs, span).is_some();
+                CanonicalSearcher::seek_front(self, offset, valid)
+            }
+
+            #[inline]
+            fn seek_back(&mut self, offset: usize) -> bool {
+                let span = Span { start: offset, end: offset };
+                let hs = self.cursors((0, self.haystack.len()));
+                let valid = <$haystack>::span_to_cursors(hs, span).is_some();
+                CanonicalSearcher::seek_back(self, offset, valid)
+            }
+        }
+    }
+}
+
+impl<'a, 'b> Pattern<&'a str> for Canonical<'b> {
+    type Searcher = CanonicalSearcher<'a>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &'a str) -> CanonicalSearcher<'a> {
+        CanonicalSearcher::new(haystack.as_bytes(), self.0)
+    }
+}
+
+canonical_searcher_impls!(&'a str);
+
+#[cfg(feature = "std")]
+impl<'a, 'b> Pattern<PartialUnicode<'a>> for Canonical<'b> {
+    type Searcher = CanonicalSearcher<'a>;
+
+    #[inline]
+    fn into_searcher(self, haystack: PartialUnicode<'a>) -> CanonicalSearcher<'a> {
+        let (start, end) = haystack.into_haystack();
+        let bytes = unsafe {
+            slice::from_raw_parts(start, end as usize - start as usize)
+        };
+        CanonicalSearcher::new(bytes, self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+canonical_searcher_impls!(PartialUnicode<'a>);
@@ -0,0 +1,95 @@
+//! Byte view of `OsStr` and `OsString`.
+//!
+//! On Unix the bytes are arbitrary, and are accessed through the
+//! `OsStrExt`/`OsStringExt` extension traits. Elsewhere they are
+//! the platform independent encoded bytes, which are WTF-8 on Windows.
+//!
+//! There is no std API for mutably viewing the bytes, so
+//! `as_bytes_mut()` and `from_bytes_mut()` cast the pointers instead.
+//! They rely on the private layout of `OsStr`, which on Unix is a
+//! transparent wrapper around `[u8]`. std does not promise this, so they
+//! only exist on Unix, and the `&mut OsStr` haystack with them.
+
+use std::ffi::{OsStr, OsString};
+use std::mem;
+
+#[cfg(unix)]
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+#[cfg(unix)]
+#[inline]
+pub fn as_bytes(s: &OsStr) -> &[u8] {
+    s.as_bytes()
+}
+
+#[cfg(not(unix))]
+#[inline]
+pub fn as_bytes(s: &OsStr) -> &[u8] {
+    s.as_encoded_bytes()
+}
+
+/// The bytes need to be valid for the platform, for example
+/// obtained by slicing `as_bytes()` at valid positions.
+#[cfg(unix)]
+#[inline]
+pub unsafe fn from_bytes(b: &[u8]) -> &OsStr {
+    OsStr::from_bytes(b)
+}
+
+/// The bytes need to be valid for the platform, for example
+/// obtained by slicing `as_bytes()` at valid positions.
+#[cfg(not(unix))]
+#[inline]
+pub unsafe fn from_bytes(b: &[u8]) -> &OsStr {
+    OsStr::from_encoded_bytes_unchecked(b)
+}
+
+/// The bytes need to stay valid for the platform.
+#[cfg(unix)]
+#[inline]
+pub unsafe fn as_bytes_mut(s: &mut OsStr) -> &mut [u8] {
+    &mut *(s as *mut OsStr as *mut [u8])
+}
+
+/// The bytes need to be valid for the platform.
+#[cfg(unix)]
+#[inline]
+pub unsafe fn from_bytes_mut(b: &mut [u8]) -> &mut OsStr {
+    &mut *(b as *mut [u8] as *mut OsStr)
+}
+
+#[cfg(unix)]
+#[inline]
+fn into_vec(s: OsString) -> Vec<u8> {
+    s.into_vec()
+}
+
+#[cfg(not(unix))]
+#[inline]
+fn into_vec(s: OsString) -> Vec<u8> {
+    s.into_encoded_bytes()
+}
+
+#[cfg(unix)]
+#[inline]
+unsafe fn from_vec(v: Vec<u8>) -> OsString {
+    OsString::from_vec(v)
+}
+
+#[cfg(not(unix))]
+#[inline]
+unsafe fn from_vec(v: Vec<u8>) -> OsString {
+    OsString::from_encoded_bytes_unchecked(v)
+}
+
+/// Gives `f` access to the bytes of `s`, without copying them.
+///
+/// The bytes need to be valid for the platform when `f` returns.
+pub unsafe fn with_vec<F, R>(s: &mut OsString, f: F) -> R
+    where F: FnOnce(&mut Vec<u8>) -> R
+{
+    let mut v = into_vec(mem::replace(s, OsString::new()));
+    let r = f(&mut v);
+    *s = from_vec(v);
+    r
+}
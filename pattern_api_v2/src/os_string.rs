@@ -22,11 +22,12 @@ macro_rules! impl_both_mutability {
                     $cursor_elem:ty,
                     $cursors_to_haystack:expr,
                     $haystack_to_cursors:expr,
-                    $str_slice:ty) => {
+                    $str_slice:ty,
+                    $os_str_to_str:expr) => {
         pub mod $module {
             use core_traits::*;
             use std::ffi::OsStr;
-            use std::mem;
+            use os_str_bytes;
 
             #[derive(Copy, Clone)]
             struct Iter<'a> {
@@ -36,6 +37,11 @@ macro_rules! impl_both_mutability {
                 _marker: ::std::marker::PhantomData<$slice>
             }
 
+            // Only reads the elements between `start` and `end`,
+            // which have not been returned yet
+            unsafe impl<'a> Send for Iter<'a> where $slice: Send {}
+            unsafe impl<'a> Sync for Iter<'a> where $slice: Sync {}
+
             impl<'a> Iter<'a> {
                 #[inline]
                 fn new(haystack: $slice) -> Self {
@@ -147,6 +153,26 @@ macro_rules! impl_both_mutability {
                     hs.1
                 }
                 fn match_type_len(mt: &Self::MatchType) -> usize { mt.len() }
+
+                fn span_to_cursors(hs: Self::Haystack,
+                                   span: Span) -> Option<(Self::Cursor, Self::Cursor)> {
+                    let len = hs.1 as usize - hs.0 as usize;
+                    if span.start > span.end || span.end > len {
+                        return None;
+                    }
+                    let bytes = unsafe {
+                        ::std::slice::from_raw_parts(hs.0 as *const u8, len)
+                    };
+                    if super::is_span_boundary(bytes, span.start, super::SurrogateMode::native())
+                    && super::is_span_boundary(bytes, span.end, super::SurrogateMode::native()) {
+                        unsafe {
+                            Some((hs.0.offset(span.start as isize),
+                                  hs.0.offset(span.end as isize)))
+                        }
+                    } else {
+                        None
+                    }
+                }
             }
 
             unsafe impl<'a> InverseMatchesAreValid for $slice {}
@@ -156,6 +182,9 @@ macro_rules! impl_both_mutability {
             //////////////////////////////////////////////////////////////////
 
             use utf8::{self, CharEq, CharEqPattern};
+            use std::ops::{Range, RangeInclusive};
+            use sets::CharSet;
+            use char_classes::CharClass;
 
             #[derive(Clone)]
             pub struct CharEqSearcher<'a, C: CharEq> {
@@ -175,6 +204,18 @@ macro_rules! impl_both_mutability {
                         iter: Iter::new(haystack),
                     }
                 }
+
+                fn matches_at(mut self, haystack: $slice, offset: usize) -> Option<usize> {
+                    let hs = haystack.into_haystack();
+                    let bytes = <$slice as OrdSlice>::haystack_as_slice(&hs);
+                    if offset >= bytes.len() {
+                        return None;
+                    }
+                    match utf8::char_at(bytes, offset) {
+                        Some((c, width)) if self.0.matches(c) => Some(offset + width),
+                        _ => None,
+                    }
+                }
             }
 
             unsafe impl<'a, C: CharEq> Searcher<$slice> for CharEqSearcher<'a, C> {
@@ -183,6 +224,11 @@ macro_rules! impl_both_mutability {
                     self.iter.haystack
                 }
 
+                #[inline]
+                fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<$slice>> {
+                    Some(self)
+                }
+
                 #[inline]
                 fn next_match(&mut self) -> Option<($cursor, $cursor)> {
                     if self.ascii_only {
@@ -308,6 +354,30 @@ macro_rules! impl_both_mutability {
 
             impl<'a, C: CharEq> DoubleEndedSearcher<$slice> for CharEqSearcher<'a, C> {}
 
+            impl<'a, C: CharEq> SeekableSearcher<$slice> for CharEqSearcher<'a, C> {
+                fn seek_front(&mut self, offset: usize) -> bool {
+                    let hs = self.iter.haystack;
+                    if offset > <$slice>::offset_from_front(hs, self.iter.end)
+                        || !<$slice as OrdSlice>::pos_is_valid(&hs, offset) {
+                        return false;
+                    }
+                    self.iter.start = unsafe { hs.0.offset(offset as isize) };
+                    true
+                }
+
+                fn seek_back(&mut self, offset: usize) -> bool {
+                    let hs = self.iter.haystack;
+                    if offset < <$slice>::offset_from_front(hs, self.iter.start)
+                        || offset > <$slice>::haystack_len(hs)
+                        || !<$slice as OrdSlice>::pos_is_valid(&hs, offset)
+                    {
+                        return false;
+                    }
+                    self.iter.end = unsafe { hs.0.offset(offset as isize) };
+                    true
+                }
+            }
+
             /////////////////////////////////////////////////////////////////////////////
             // Impl for char
             /////////////////////////////////////////////////////////////////////////////
@@ -317,13 +387,17 @@ macro_rules! impl_both_mutability {
             pub struct CharSearcher<'a>(CharEqSearcher<'a, char>);
 
             unsafe impl<'a> Searcher<$slice> for CharSearcher<'a> {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a> ReverseSearcher<$slice> for CharSearcher<'a> {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a> SeekableSearcher<$slice> for CharSearcher<'a> {
+                searcher_methods!(seek, s, s.0);
+            }
+
             impl<'a> DoubleEndedSearcher<$slice> for CharSearcher<'a> {}
 
             /// Searches for chars that are equal to a given char
@@ -343,7 +417,7 @@ macro_rules! impl_both_mutability {
             unsafe impl<'a, F> Searcher<$slice> for CharPredicateSearcher<'a, F>
                 where F: FnMut(char) -> bool
             {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a, F> ReverseSearcher<$slice> for CharPredicateSearcher<'a, F>
@@ -352,6 +426,12 @@ macro_rules! impl_both_mutability {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a, F> SeekableSearcher<$slice> for CharPredicateSearcher<'a, F>
+                where F: FnMut(char) -> bool
+            {
+                searcher_methods!(seek, s, s.0);
+            }
+
             impl<'a, F> DoubleEndedSearcher<$slice> for CharPredicateSearcher<'a, F>
                 where F: FnMut(char) -> bool {}
 
@@ -360,6 +440,103 @@ macro_rules! impl_both_mutability {
                 pattern_methods!(CharPredicateSearcher<'a, F>, CharEqPattern, CharPredicateSearcher, $slice);
             }
 
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for char ranges
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<RangeInclusive<char> as Pattern<&'a OsStr>>::Searcher`,
+            /// and for the other char ranges.
+            #[derive(Clone)]
+            pub struct CharRangeSearcher<'a, R: CharEq>(CharEqSearcher<'a, R>);
+
+            unsafe impl<'a, R: CharEq> Searcher<$slice> for CharRangeSearcher<'a, R> {
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
+            }
+
+            unsafe impl<'a, R: CharEq> ReverseSearcher<$slice> for CharRangeSearcher<'a, R> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a, R: CharEq> SeekableSearcher<$slice> for CharRangeSearcher<'a, R> {
+                searcher_methods!(seek, s, s.0);
+            }
+
+            impl<'a, R: CharEq> DoubleEndedSearcher<$slice> for CharRangeSearcher<'a, R> {}
+
+            /// Searches for chars inside of the range
+            impl<'a> Pattern<$slice> for RangeInclusive<char> {
+                pattern_methods!(CharRangeSearcher<'a, RangeInclusive<char>>, CharEqPattern,
+                                 CharRangeSearcher, $slice);
+            }
+
+            /// Searches for chars inside of the range
+            impl<'a> Pattern<$slice> for Range<char> {
+                pattern_methods!(CharRangeSearcher<'a, Range<char>>, CharEqPattern,
+                                 CharRangeSearcher, $slice);
+            }
+
+            /// Searches for chars inside of any of the ranges in the array
+            impl<'a, 'b> Pattern<$slice> for &'b [RangeInclusive<char>] {
+                pattern_methods!(CharRangeSearcher<'a, &'b [RangeInclusive<char>]>, CharEqPattern,
+                                 CharRangeSearcher, $slice);
+            }
+
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for &CharSet
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<&CharSet as Pattern<&'a OsStr>>::Searcher`.
+            #[derive(Clone)]
+            pub struct CharSetSearcher<'a, 'b>(CharEqSearcher<'a, &'b CharSet>);
+
+            unsafe impl<'a, 'b> Searcher<$slice> for CharSetSearcher<'a, 'b> {
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
+            }
+
+            unsafe impl<'a, 'b> ReverseSearcher<$slice> for CharSetSearcher<'a, 'b> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a, 'b> SeekableSearcher<$slice> for CharSetSearcher<'a, 'b> {
+                searcher_methods!(seek, s, s.0);
+            }
+
+            impl<'a, 'b> DoubleEndedSearcher<$slice> for CharSetSearcher<'a, 'b> {}
+
+            /// Searches for chars that are in the set
+            impl<'a, 'b> Pattern<$slice> for &'b CharSet {
+                pattern_methods!(CharSetSearcher<'a, 'b>, CharEqPattern,
+                                 CharSetSearcher, $slice);
+            }
+
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for CharClass
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<CharClass as Pattern<&'a OsStr>>::Searcher`.
+            #[derive(Clone)]
+            pub struct CharClassSearcher<'a>(CharEqSearcher<'a, CharClass>);
+
+            unsafe impl<'a> Searcher<$slice> for CharClassSearcher<'a> {
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
+            }
+
+            unsafe impl<'a> ReverseSearcher<$slice> for CharClassSearcher<'a> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a> SeekableSearcher<$slice> for CharClassSearcher<'a> {
+                searcher_methods!(seek, s, s.0);
+            }
+
+            impl<'a> DoubleEndedSearcher<$slice> for CharClassSearcher<'a> {}
+
+            /// Searches for chars that are in the class
+            impl<'a> Pattern<$slice> for CharClass {
+                pattern_methods!(CharClassSearcher<'a>, CharEqPattern,
+                                 CharClassSearcher, $slice);
+            }
+
             /////////////////////////////////////////////////////////////////////////////
             // Impl for &[char]
             /////////////////////////////////////////////////////////////////////////////
@@ -371,13 +548,17 @@ macro_rules! impl_both_mutability {
             pub struct CharSliceSearcher<'a, 'b>(CharEqSearcher<'a, &'b [char]>);
 
             unsafe impl<'a, 'b> Searcher<$slice> for CharSliceSearcher<'a, 'b> {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a, 'b> ReverseSearcher<$slice> for CharSliceSearcher<'a, 'b> {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a, 'b> SeekableSearcher<$slice> for CharSliceSearcher<'a, 'b> {
+                searcher_methods!(seek, s, s.0);
+            }
+
             impl<'a, 'b> DoubleEndedSearcher<$slice> for CharSliceSearcher<'a, 'b> {}
 
             /// Searches for chars that are equal to any of the chars in the array
@@ -410,7 +591,7 @@ macro_rules! impl_both_mutability {
 
                 fn haystack_as_slice<'t>(hs: &'t Self::Haystack) -> &'t [Self::NeedleElement] {
                     unsafe {
-                        mem::transmute($cursors_to_haystack(hs.0, hs.1))
+                        os_str_bytes::as_bytes(&*$cursors_to_haystack(hs.0, hs.1))
                     }
                 }
 
@@ -431,49 +612,43 @@ macro_rules! impl_both_mutability {
                 pattern_methods!{
                     OsStrSearcher<'a, 'b>,
                     |s: &'b OsStr| {
-                        cfg_match! {
-                            windows => {
-                                // on windows lone surrogate pairs
-                                // at the front/back
-                                // need to be considered a contract violation
-                                // since it is not possible
-                                // to find them on a OsStr if they
-                                // are encoded as part of a normal
-                                // character
-
-                                let (a, _, b) = super::split_loony_surrogates(s);
-
-                                if a.len() > 0 || b.len() > 0 {
-                                    panic!("The Pattern API does not support \
-                                            searching for strings \
-                                            starting or ending with \
-                                            lone surrogate codepoints");
-                                }
-                            }
-                            unix => {
-                                // On this platform the surrogate issue
-                                // does not exist
-                            }
-                        }
-
-                        unsafe {
-                            OrdSlicePattern(
-                                mem::transmute::<&'b OsStr, &'b [u8]>(s))
-                        }
+                        OrdSlicePattern(super::needle_bytes(s, super::SurrogateMode::native()))
                     },
                     OsStrSearcher,
                     $slice
                 }
+
+                fn validate(&self) -> Result<(), PatternError> {
+                    super::check_needle(self, super::SurrogateMode::native())
+                }
+            }
+
+            /// Substring search with the given `SurrogateMode`.
+            impl<'a, 'b> Pattern<$slice> for super::OsStrNeedle<'b> {
+                pattern_methods!{
+                    OsStrSearcher<'a, 'b>,
+                    |n: super::OsStrNeedle<'b>| OrdSlicePattern(super::needle_bytes(n.0, n.1)),
+                    OsStrSearcher,
+                    $slice
+                }
+
+                fn validate(&self) -> Result<(), PatternError> {
+                    super::check_needle(self.0, self.1)
+                }
             }
 
             unsafe impl<'a, 'b> Searcher<$slice> for OsStrSearcher<'a, 'b> {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a, 'b> ReverseSearcher<$slice> for OsStrSearcher<'a, 'b> {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a, 'b> SeekableSearcher<$slice> for OsStrSearcher<'a, 'b> {
+                searcher_methods!(seek, s, s.0);
+            }
+
             ////////////////////////////////////////////////////////////////////
             // Impl for &str
             ////////////////////////////////////////////////////////////////////
@@ -492,13 +667,17 @@ macro_rules! impl_both_mutability {
             }
 
             unsafe impl<'a, 'b> Searcher<$slice> for StrSearcher<'a, 'b> {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a, 'b> ReverseSearcher<$slice> for StrSearcher<'a, 'b> {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a, 'b> SeekableSearcher<$slice> for StrSearcher<'a, 'b> {
+                searcher_methods!(seek, s, s.0);
+            }
+
             ////////////////////////////////////////////////////////////////////
             // Wrapper for returning &str matches
             ////////////////////////////////////////////////////////////////////
@@ -525,9 +704,7 @@ macro_rules! impl_both_mutability {
                                         start: Self::Cursor,
                                         end: Self::Cursor) -> Self::MatchType {
                     let s = <$slice>::range_to_self(h, start, end);
-
-                    // cast &[mut]OsStr to &[mut]str
-                    mem::transmute::<$slice, $str_slice>(s)
+                    $os_str_to_str(s)
                 }
                 fn cursor_at_front(hs: Self::Haystack) -> Self::Cursor {
                     <$slice>::cursor_at_front(hs)
@@ -536,6 +713,34 @@ macro_rules! impl_both_mutability {
                     <$slice>::cursor_at_back(hs)
                 }
                 fn match_type_len(mt: &Self::MatchType) -> usize { mt.len() }
+
+                /// Only spans of valid UTF-8 can be turned into a `str`.
+                fn span_to_cursors(hs: Self::Haystack,
+                                   span: Span) -> Option<(Self::Cursor, Self::Cursor)> {
+                    <$slice>::span_to_cursors(hs, span).and_then(|(a, b)| {
+                        let bytes = unsafe {
+                            ::core::slice::from_raw_parts(a as *const u8,
+                                                          b as usize - a as usize)
+                        };
+                        ::core::str::from_utf8(bytes).ok().map(|_| (a, b))
+                    })
+                }
+            }
+
+            /// Lines may contain invalid unicode, so they are returned as
+            /// the wrapped `OsStr` type rather than as `str`.
+            impl<'a> ::lines::LineHaystack for PartialUnicode<'a> {
+                type Line = $slice;
+
+                fn haystack_as_bytes(hs: &Self::Haystack) -> &[u8] {
+                    <$slice as ::lines::LineHaystack>::haystack_as_bytes(hs)
+                }
+
+                unsafe fn line_from_range(hs: Self::Haystack,
+                                          start: usize,
+                                          end: usize) -> $slice {
+                    <$slice as ::lines::LineHaystack>::line_from_range(hs, start, end)
+                }
             }
 
             ////////////////////////////////////////////////////////////////////
@@ -602,6 +807,83 @@ macro_rules! impl_both_mutability {
             impl<'a, 'b> Pattern<PartialUnicode<'a>> for &'b [char] {
                 pattern_methods!(CharSliceSearcher<'a, 'b>, CharEqPattern, CharSliceSearcher, PartialUnicode<'a>, |s: PartialUnicode<'a>| s.os_str);
             }
+
+            ////////////////////////////////////////////////////////////////////
+            // PartialUnicode impl for char ranges
+            ////////////////////////////////////////////////////////////////////
+
+            unsafe impl<'a, R: CharEq> Searcher<PartialUnicode<'a>> for CharRangeSearcher<'a, R> {
+                searcher_methods!(forward, s, s.0, $cursor);
+            }
+
+            unsafe impl<'a, R: CharEq> ReverseSearcher<PartialUnicode<'a>> for CharRangeSearcher<'a, R> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a, R: CharEq> DoubleEndedSearcher<PartialUnicode<'a>> for CharRangeSearcher<'a, R> {}
+
+            /// Searches for chars inside of the range
+            impl<'a> Pattern<PartialUnicode<'a>> for RangeInclusive<char> {
+                pattern_methods!(CharRangeSearcher<'a, RangeInclusive<char>>, CharEqPattern,
+                                 CharRangeSearcher, PartialUnicode<'a>,
+                                 |s: PartialUnicode<'a>| s.os_str);
+            }
+
+            /// Searches for chars inside of the range
+            impl<'a> Pattern<PartialUnicode<'a>> for Range<char> {
+                pattern_methods!(CharRangeSearcher<'a, Range<char>>, CharEqPattern,
+                                 CharRangeSearcher, PartialUnicode<'a>,
+                                 |s: PartialUnicode<'a>| s.os_str);
+            }
+
+            /// Searches for chars inside of any of the ranges in the array
+            impl<'a, 'b> Pattern<PartialUnicode<'a>> for &'b [RangeInclusive<char>] {
+                pattern_methods!(CharRangeSearcher<'a, &'b [RangeInclusive<char>]>, CharEqPattern,
+                                 CharRangeSearcher, PartialUnicode<'a>,
+                                 |s: PartialUnicode<'a>| s.os_str);
+            }
+
+            ////////////////////////////////////////////////////////////////////
+            // PartialUnicode impl for &CharSet
+            ////////////////////////////////////////////////////////////////////
+
+            unsafe impl<'a, 'b> Searcher<PartialUnicode<'a>> for CharSetSearcher<'a, 'b> {
+                searcher_methods!(forward, s, s.0, $cursor);
+            }
+
+            unsafe impl<'a, 'b> ReverseSearcher<PartialUnicode<'a>> for CharSetSearcher<'a, 'b> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a, 'b> DoubleEndedSearcher<PartialUnicode<'a>> for CharSetSearcher<'a, 'b> {}
+
+            /// Searches for chars that are in the set
+            impl<'a, 'b> Pattern<PartialUnicode<'a>> for &'b CharSet {
+                pattern_methods!(CharSetSearcher<'a, 'b>, CharEqPattern,
+                                 CharSetSearcher, PartialUnicode<'a>,
+                                 |s: PartialUnicode<'a>| s.os_str);
+            }
+
+            ////////////////////////////////////////////////////////////////////
+            // PartialUnicode impl for CharClass
+            ////////////////////////////////////////////////////////////////////
+
+            unsafe impl<'a> Searcher<PartialUnicode<'a>> for CharClassSearcher<'a> {
+                searcher_methods!(forward, s, s.0, $cursor);
+            }
+
+            unsafe impl<'a> ReverseSearcher<PartialUnicode<'a>> for CharClassSearcher<'a> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a> DoubleEndedSearcher<PartialUnicode<'a>> for CharClassSearcher<'a> {}
+
+            /// Searches for chars that are in the class
+            impl<'a> Pattern<PartialUnicode<'a>> for CharClass {
+                pattern_methods!(CharClassSearcher<'a>, CharEqPattern,
+                                 CharClassSearcher, PartialUnicode<'a>,
+                                 |s: PartialUnicode<'a>| s.os_str);
+            }
             ////////////////////////////////////////////////////////////////////
             // PartialUnicode impl for &str
             ////////////////////////////////////////////////////////////////////
@@ -632,32 +914,26 @@ macro_rules! impl_both_mutability {
 impl_both_mutability!(shared, &'a OsStr, *const u8, u8, |start, end| {
     let slice = ::std::slice::from_raw_parts(start,
         end as usize - start as usize);
-
-    // TODO: Solve properly
-    mem::transmute::<&'a [u8], &'a OsStr>(slice)
+    os_str_bytes::from_bytes(slice)
 }, |haystack: &OsStr| {
-    // TODO: Solve properly
-    let haystack = unsafe {
-        mem::transmute::<&OsStr, &[u8]>(haystack)
-    };
+    let haystack = os_str_bytes::as_bytes(haystack);
 
     let begin = haystack.as_ptr();
     let end = unsafe {
         begin.offset(haystack.len() as isize)
     };
     (begin, end)
-}, &'a str);
+}, &'a str, |s| ::std::str::from_utf8_unchecked(os_str_bytes::as_bytes(s)));
 
+// The mutable byte view of `os_str_bytes` is only available on Unix
+#[cfg(unix)]
 impl_both_mutability!(mutable, &'a mut OsStr, *mut u8, u8, |start, end| {
     let slice = ::std::slice::from_raw_parts_mut(start,
         end as usize - start as usize);
-
-    // TODO: Solve properly
-    mem::transmute::<&'a mut [u8], &'a mut OsStr>(slice)
+    os_str_bytes::from_bytes_mut(slice)
 }, |haystack: &mut OsStr| {
-    // TODO: Solve properly
     let haystack = unsafe {
-        mem::transmute::<&mut OsStr, &mut [u8]>(haystack)
+        os_str_bytes::as_bytes_mut(haystack)
     };
 
     let begin = haystack.as_mut_ptr();
@@ -665,47 +941,126 @@ impl_both_mutability!(mutable, &'a mut OsStr, *mut u8, u8, |start, end| {
         begin.offset(haystack.len() as isize)
     };
     (begin, end)
-}, &'a mut str);
+}, &'a mut str, |s| ::std::str::from_utf8_unchecked_mut(os_str_bytes::as_bytes_mut(s)));
 
 use ::Pattern;
 use ::ReverseSearcher;
+use utf8;
+use ::PatternHaystack;
+use ::PatternError;
+use os_str_bytes;
 use std::ffi::OsStr;
 use std::ffi::OsString;
 
+#[cfg(unix)]
+unsafe impl<'a> ::MutableHaystack for &'a mut OsStr {}
+#[cfg(unix)]
+unsafe impl<'a> ::MutableHaystack for mutable::PartialUnicode<'a> {}
+
 impl<'a, 'b> Pattern<&'a OsStr> for &'b String {
-    pattern_methods!(shared::StrSearcher<'a, 'b>, |s: &'b String| &**s, |s| s, &'a OsStr);
+    pattern_methods!(copy shared::StrSearcher<'a, 'b>, |s: &'b String| &**s, |s| s,
+                     &'a OsStr);
 }
 
 impl<'a, 'b, 'c> Pattern<&'a OsStr> for &'c &'b str {
-    pattern_methods!(shared::StrSearcher<'a, 'b>, |&s| s, |s| s, &'a OsStr);
+    pattern_methods!(copy shared::StrSearcher<'a, 'b>, |&s| s, |s| s, &'a OsStr);
 }
 
 impl<'a, 'b> Pattern<&'a OsStr> for &'b OsString {
-    pattern_methods!(shared::OsStrSearcher<'a, 'b>, |s: &'b OsString| &**s, |s| s, &'a OsStr);
+    pattern_methods!(copy shared::OsStrSearcher<'a, 'b>, |s: &'b OsString| &**s, |s| s,
+                     &'a OsStr);
 }
 
 impl<'a, 'b, 'c> Pattern<&'a OsStr> for &'c &'b OsStr {
-    pattern_methods!(shared::OsStrSearcher<'a, 'b>, |&s| s, |s| s, &'a OsStr);
+    pattern_methods!(copy shared::OsStrSearcher<'a, 'b>, |&s| s, |s| s, &'a OsStr);
 }
 
-#[cfg(any(test, not(unix)))]
-fn starts_with_surrogate(v: &[u8]) -> Option<u16> {
-    let mut iter = v.iter().cloned();
-    use utf8::next_code_point;
+////////////////////////////////////////////////////////////////////////////////
+// Surrogate handling
+////////////////////////////////////////////////////////////////////////////////
+
+/// How a search treats the lone surrogates of an `OsStr` needle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SurrogateMode {
+    /// The needle is searched for byte by byte, like on Unix,
+    /// where an `OsStr` is an arbitrary sequence of bytes. Edits of an
+    /// `OsString` on Windows still keep surrogate pairs joined, since its
+    /// bytes have to stay WTF-8.
+    Bytes,
+    /// The bytes are WTF-8, like on Windows, where a surrogate pair is
+    /// always joined into a single four byte sequence. A lone surrogate at
+    /// the front or back of the needle could be one half of such a pair in
+    /// the haystack, which a search can not report, so searching for it
+    /// panics, or fails with `PatternError::LoneSurrogateAtEdge` when
+    /// using `TryPattern`. The same goes for a needle starting or ending
+    /// inside of a UTF-8 sequence, which fails with
+    /// `PatternError::InvalidUtf8Boundary`.
+    Wtf8,
+}
+
+impl SurrogateMode {
+    /// The mode matching the `OsStr` encoding of the target platform.
+    pub fn native() -> SurrogateMode {
+        if cfg!(windows) { SurrogateMode::Wtf8 } else { SurrogateMode::Bytes }
+    }
+}
+
+/// An `OsStr` needle that is searched for with the given `SurrogateMode`
+/// instead of the native one, for example to get the Windows behaviour
+/// on other platforms.
+#[derive(Copy, Clone, Debug)]
+pub struct OsStrNeedle<'b>(pub &'b OsStr, pub SurrogateMode);
+
+/// Checks for the needles `needle_bytes()` panics on.
+///
+/// In `SurrogateMode::Bytes` any needle is fine, since the matches
+/// do not need to be at char boundaries.
+fn check_needle(s: &OsStr, mode: SurrogateMode) -> Result<(), PatternError> {
+    let bytes = os_str_bytes::as_bytes(s);
+    if mode == SurrogateMode::Wtf8 {
+        let (a, _, b) = split_loony_surrogates(bytes);
+        if !a.is_empty() || !b.is_empty() {
+            return Err(PatternError::LoneSurrogateAtEdge);
+        }
+        if utf8::cuts_sequence(bytes) {
+            return Err(PatternError::InvalidUtf8Boundary);
+        }
+    }
+    Ok(())
+}
+
+fn needle_bytes(s: &OsStr, mode: SurrogateMode) -> &[u8] {
+    match check_needle(s, mode) {
+        Err(PatternError::LoneSurrogateAtEdge) => {
+            panic!("The Pattern API does not support \
+                    searching for strings \
+                    starting or ending with \
+                    lone surrogate codepoints");
+        }
+        Err(_) => {
+            panic!("The Pattern API does not support \
+                    searching for strings \
+                    starting or ending inside of \
+                    a UTF-8 sequence");
+        }
+        Ok(()) => os_str_bytes::as_bytes(s),
+    }
+}
 
+fn starts_with_surrogate(v: &[u8]) -> Option<u16> {
     if v.len() >= 3
     && v[0] == 237
     && v[1] >= 160
     && v[1] <= 191
     && v[2] >= 128
     && v[2] <= 191 {
-        next_code_point(|| iter.next()).map(|c| c as u32 as u16)
+        // decoded by hand, since a surrogate is not a valid `char`
+        Some(0xD000 | (v[1] as u16 & 0x3F) << 6 | (v[2] as u16 & 0x3F))
     } else {
         None
     }
 }
 
-#[cfg(any(test, not(unix)))]
 fn ends_with_surrogate(v: &[u8]) -> Option<u16> {
     if v.len() >= 3 {
         starts_with_surrogate(&v[v.len() - 3..])
@@ -714,22 +1069,17 @@ fn ends_with_surrogate(v: &[u8]) -> Option<u16> {
     }
 }
 
-#[cfg(any(test, not(unix)))]
-fn split_loony_surrogates(s: &OsStr) -> (&[u8], &[u8], &[u8]) {
-    let s = unsafe {
-        ::std::mem::transmute::<&OsStr, &[u8]>(s)
-    };
-
+fn split_loony_surrogates(s: &[u8]) -> (&[u8], &[u8], &[u8]) {
     let mut front_len = 0;
     if let Some(s) = starts_with_surrogate(s) {
-        if (0xDC00...0xDFFF).contains(s) {
+        if (0xDC00..=0xDFFF).contains(&s) {
             front_len = 3;
         }
     };
 
     let mut back_len = 0;
     if let Some(s) = ends_with_surrogate(s) {
-        if (0xD800...0xDBFF).contains(s) {
+        if (0xD800..=0xDBFF).contains(&s) {
             back_len = 3;
         }
     };
@@ -740,12 +1090,107 @@ fn split_loony_surrogates(s: &OsStr) -> (&[u8], &[u8], &[u8]) {
     (&s[..a], &s[a..b], &s[b..])
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Editing
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenates the `pieces` of an edited `OsString`.
+///
+/// In `SurrogateMode::Wtf8`, a lead surrogate at the end of one piece and a
+/// trail surrogate at the start of the next one are joined into a single
+/// four byte sequence, like `OsString::push()` does on Windows, so an edit
+/// never leaves a split-up surrogate pair behind.
+pub(crate) fn join_pieces(pieces: &[&[u8]], mode: SurrogateMode) -> Vec<u8> {
+    let mut v = Vec::with_capacity(pieces.iter().map(|p| p.len()).sum());
+    for &piece in pieces {
+        let mut piece = piece;
+        if mode == SurrogateMode::Wtf8 {
+            let (_, _, lead) = split_loony_surrogates(&v);
+            let (trail, _, _) = split_loony_surrogates(piece);
+            if let (Some(lead), Some(trail)) = (starts_with_surrogate(lead),
+                                                starts_with_surrogate(trail)) {
+                let c = 0x10000 + ((lead as u32 - 0xD800) << 10 | (trail as u32 - 0xDC00));
+                let c = char::from_u32(c).unwrap();
+                let len = v.len();
+                v.truncate(len - 3);
+                v.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                piece = &piece[3..];
+            }
+        }
+        v.extend_from_slice(piece);
+    }
+    v
+}
+
+/// Whether `idx` is a position in `s` that an edit can start or end at,
+/// that is not inside of a char, or in `SurrogateMode::Wtf8` inside of
+/// an encoded surrogate.
+pub(crate) fn is_edit_boundary(s: &[u8], idx: usize, mode: SurrogateMode) -> bool {
+    if idx == 0 || idx == s.len() {
+        return true;
+    }
+    idx < s.len()
+        && !utf8::is_inside_char(s, idx)
+        && !(mode == SurrogateMode::Wtf8
+             && (1..3).any(|back| {
+                 back <= idx && starts_with_surrogate(&s[idx - back..]).is_some()
+             }))
+}
+
+/// Whether a span of an `OsStr` can start or end at `idx`.
+///
+/// In `SurrogateMode::Wtf8` the bytes of a slice must stay WTF-8,
+/// so `idx` must not be inside of a char or an encoded surrogate.
+fn is_span_boundary(s: &[u8], idx: usize, mode: SurrogateMode) -> bool {
+    match mode {
+        SurrogateMode::Bytes => idx <= s.len(),
+        SurrogateMode::Wtf8 => is_edit_boundary(s, idx, mode),
+    }
+}
+
+#[test]
+fn test_join_pieces() {
+    fn join(pieces: &[&[u16]], mode: SurrogateMode) -> OsString {
+        use std_integration::OsStringExtension;
+        let pieces: Vec<_> = pieces.iter().map(|p| OsString::from_wide(p)).collect();
+        let bytes: Vec<_> = pieces.iter().map(|p| os_str_bytes::as_bytes(p)).collect();
+        unsafe {
+            os_str_bytes::from_bytes(&join_pieces(&bytes, mode)).to_owned()
+        }
+    }
+    use std_integration::OsStringExtension;
+    let pair = OsString::from_wide(&[0xD83D, 0xDE3A]);
+
+    assert_eq!(join(&[&[0x61, 0xD83D], &[0xDE3A, 0x62]], SurrogateMode::Wtf8),
+               OsString::from_wide(&[0x61, 0xD83D, 0xDE3A, 0x62]));
+    assert_eq!(join(&[&[0xD83D], &[], &[0xDE3A]], SurrogateMode::Wtf8), pair);
+    assert_eq!(join(&[&[0xDE3A], &[0xD83D]], SurrogateMode::Wtf8),
+               OsString::from_wide(&[0xDE3A, 0xD83D]));
+    assert!(join(&[&[0xD83D], &[0xDE3A]], SurrogateMode::Bytes) != pair);
+}
+
+#[test]
+fn test_is_edit_boundary() {
+    use std_integration::OsStringExtension;
+    let s = OsString::from_wide(&[0x61, 0xD83D, 0xE9, 0xD83D, 0xDE3A]);
+    let s = os_str_bytes::as_bytes(&s);
+    let wtf8: Vec<_> = (0..s.len() + 2)
+        .filter(|&i| is_edit_boundary(s, i, SurrogateMode::Wtf8))
+        .collect();
+    assert_eq!(wtf8, [0, 1, 4, 6, 10]);
+    // the encoded surrogates are just invalid bytes
+    let bytes: Vec<_> = (0..s.len() + 2)
+        .filter(|&i| is_edit_boundary(s, i, SurrogateMode::Bytes))
+        .collect();
+    assert_eq!(bytes, [0, 1, 2, 3, 4, 6, 10]);
+}
+
 #[test]
 fn test_split_loony_surrogates() {
     fn check(s: &[u16]) -> (usize, usize, usize) {
         use std_integration::OsStringExtension;
         let s = OsString::from_wide(s);
-        let (x, y, z) = split_loony_surrogates(&s);
+        let (x, y, z) = split_loony_surrogates(os_str_bytes::as_bytes(&s));
         (x.len(), y.len(), z.len())
     }
 
@@ -760,3 +1205,17 @@ fn test_split_loony_surrogates() {
     assert_eq!(check(&[                        0xD800]), (0,0,3));
 }
 
+#[test]
+fn test_is_span_boundary() {
+    use std_integration::OsStringExtension;
+    let s = OsString::from_wide(&[0x61, 0xD83D, 0xE9, 0xD83D, 0xDE3A]);
+    let s = os_str_bytes::as_bytes(&s);
+    let wtf8: Vec<_> = (0..s.len() + 2)
+        .filter(|&i| is_span_boundary(s, i, SurrogateMode::Wtf8))
+        .collect();
+    assert_eq!(wtf8, [0, 1, 4, 6, 10]);
+    let bytes: Vec<_> = (0..s.len() + 2)
+        .filter(|&i| is_span_boundary(s, i, SurrogateMode::Bytes))
+        .collect();
+    assert_eq!(bytes, (0..s.len() + 1).collect::<Vec<_>>());
+}
@@ -0,0 +1,675 @@
+//! Searching paths by whole components.
+//!
+//! `PathComponents` wraps a `&Path` so that patterns only match whole
+//! components, or runs of whole components: `"lib"` matches the second
+//! component of `/usr/lib/x`, but nothing in `/usr/libexec`. Every match
+//! and reject starts at a component, and the separators after it are
+//! part of it, but not of the returned path, so the pieces between matches
+//! can be used as relative paths: splitting `src/lib/mod.rs` by `"lib"`
+//! gives `src` at offset 0 and `mod.rs` at offset 8.
+//!
+//! The supported patterns are `&OsStr`, `&str` and `&Path` needles, which
+//! may contain separators to match several components at once, a `char`,
+//! which matches single char components, and `Glob`, which matches
+//! each component against a shell style wildcard pattern.
+
+use ::{Pattern, PatternHaystack, Searcher, ReverseSearcher, DoubleEndedSearcher, Span};
+use ::SeekableSearcher;
+use ::{InverseMatchesAreValid, PatternError};
+use os_str_bytes;
+use utf8;
+
+use std::ffi::OsStr;
+use std::marker::PhantomData;
+use std::path::{self, Path};
+use std::slice;
+
+/// A path, searched by whole components.
+#[derive(Copy, Clone, Debug)]
+pub struct PathComponents<'a>(pub &'a Path);
+
+#[inline]
+fn is_separator(b: u8) -> bool {
+    b < 128 && path::is_separator(b as char)
+}
+
+fn is_component_start(s: &[u8], i: usize) -> bool {
+    i < s.len() && !is_separator(s[i]) && (i == 0 || is_separator(s[i - 1]))
+}
+
+fn is_component_end(s: &[u8], i: usize) -> bool {
+    i > 0 && !is_separator(s[i - 1]) && (i == s.len() || is_separator(s[i]))
+}
+
+/// The first component start after `i`, or the end of `s`.
+fn next_component_start(s: &[u8], mut i: usize) -> usize {
+    i += 1;
+    while i < s.len() && !is_component_start(s, i) {
+        i += 1;
+    }
+    i
+}
+
+/// The first byte at or after `i` that is not a separator.
+fn skip_separators(s: &[u8], mut i: usize) -> usize {
+    while i < s.len() && is_separator(s[i]) {
+        i += 1;
+    }
+    i
+}
+
+/// The last component end before `i`, or the start of `s`.
+fn prev_component_end(s: &[u8], mut i: usize) -> usize {
+    i -= 1;
+    while i > 0 && !is_component_end(s, i) {
+        i -= 1;
+    }
+    i
+}
+
+impl<'a> PatternHaystack for PathComponents<'a> {
+    type Haystack = (*const u8, *const u8);
+    type Cursor = *const u8;
+    type MatchType = &'a Path;
+
+    fn into_haystack(self) -> Self::Haystack {
+        self.0.as_os_str().into_haystack()
+    }
+
+    fn offset_from_front(haystack: Self::Haystack,
+                         begin: Self::Cursor) -> usize {
+        begin as usize - haystack.0 as usize
+    }
+
+    /// Separators at the end are not part of the returned path,
+    /// but a root at the front is kept.
+    unsafe fn range_to_self(hs: Self::Haystack,
+                            start: Self::Cursor,
+                            mut end: Self::Cursor) -> Self::MatchType {
+        while end != start && end.offset(-1) != hs.0 && is_separator(*end.offset(-1)) {
+            end = end.offset(-1);
+        }
+        let bytes = slice::from_raw_parts(start, end as usize - start as usize);
+        Path::new(os_str_bytes::from_bytes(bytes))
+    }
+    fn cursor_at_front(hs: Self::Haystack) -> Self::Cursor {
+        hs.0
+    }
+    fn cursor_at_back(hs: Self::Haystack) -> Self::Cursor {
+        hs.1
+    }
+    fn match_type_len(mt: &Self::MatchType) -> usize { mt.as_os_str().len() }
+
+    /// Spans start at a component, or at an end of the path,
+    /// and end at a component boundary.
+    fn span_to_cursors(hs: Self::Haystack,
+                       span: Span) -> Option<(Self::Cursor, Self::Cursor)> {
+        let s = unsafe { slice::from_raw_parts(hs.0, hs.1 as usize - hs.0 as usize) };
+        let valid_start = |i| i == 0 || i == s.len() || is_component_start(s, i);
+        let valid_end = |i| valid_start(i) || is_component_end(s, i);
+        if span.start <= span.end && span.end <= s.len()
+            && valid_start(span.start) && valid_end(span.end)
+        {
+            unsafe {
+                Some((hs.0.offset(span.start as isize), hs.0.offset(span.end as isize)))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl<'a> InverseMatchesAreValid for PathComponents<'a> {}
+
+///////////////////////////////////////////////////////////////////////////////
+// Searcher
+///////////////////////////////////////////////////////////////////////////////
+
+/// Finds the first or last run of whole components in `s[front..back]`
+/// that matches.
+trait ComponentMatcher {
+    fn find(&mut self, s: &[u8], front: usize, back: usize) -> Option<(usize, usize)>;
+    fn rfind(&mut self, s: &[u8], front: usize, back: usize) -> Option<(usize, usize)>;
+}
+
+#[derive(Clone)]
+struct PathSearcher<'a, M> {
+    haystack: (*const u8, *const u8),
+    matcher: M,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a Path>,
+}
+
+// The haystack is only read, like through the `&'a Path` it came from
+unsafe impl<'a, M: Send> Send for PathSearcher<'a, M> {}
+unsafe impl<'a, M: Sync> Sync for PathSearcher<'a, M> {}
+
+impl<'a, M: ComponentMatcher> PathSearcher<'a, M> {
+    fn new(haystack: PathComponents<'a>, matcher: M) -> Self {
+        let hs = haystack.into_haystack();
+        PathSearcher {
+            haystack: hs,
+            matcher: matcher,
+            front: 0,
+            back: PathComponents::haystack_len(hs),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn bytes(&self) -> &'a [u8] {
+        let hs = self.haystack;
+        unsafe {
+            slice::from_raw_parts(hs.0, hs.1 as usize - hs.0 as usize)
+        }
+    }
+
+    #[inline]
+    fn cursors(&self, a: usize, b: usize) -> (*const u8, *const u8) {
+        unsafe {
+            (self.haystack.0.offset(a as isize), self.haystack.0.offset(b as isize))
+        }
+    }
+
+    /// The next match, or the reject up to it.
+    ///
+    /// `front` and `back` always stay at the start of a component
+    /// or at an end of the path, so a match takes the separators after it.
+    fn next(&mut self) -> Option<(usize, usize, bool)> {
+        if self.front == self.back {
+            return None;
+        }
+        let front = self.front;
+        match self.matcher.find(self.bytes(), self.front, self.back) {
+            Some((a, b)) if a == front => {
+                self.front = skip_separators(self.bytes(), b);
+                Some((a, self.front, true))
+            }
+            Some((a, _)) => {
+                self.front = a;
+                Some((front, a, false))
+            }
+            None => {
+                self.front = self.back;
+                Some((front, self.back, false))
+            }
+        }
+    }
+
+    fn next_back(&mut self) -> Option<(usize, usize, bool)> {
+        if self.front == self.back {
+            return None;
+        }
+        let back = self.back;
+        match self.matcher.rfind(self.bytes(), self.front, self.back) {
+            Some((a, b)) if skip_separators(self.bytes(), b) == back => {
+                self.back = a;
+                Some((a, back, true))
+            }
+            Some((_, b)) => {
+                self.back = skip_separators(self.bytes(), b);
+                Some((self.back, back, false))
+            }
+            None => {
+                self.back = self.front;
+                Some((self.front, back, false))
+            }
+        }
+    }
+}
+
+unsafe impl<'a, M: ComponentMatcher> Searcher<PathComponents<'a>> for PathSearcher<'a, M> {
+    #[inline]
+    fn haystack(&self) -> (*const u8, *const u8) {
+        self.haystack
+    }
+
+    #[inline]
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<PathComponents<'a>>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(*const u8, *const u8)> {
+        while let Some((a, b, is_match)) = self.next() {
+            if is_match {
+                return Some(self.cursors(a, b));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(*const u8, *const u8)> {
+        while let Some((a, b, is_match)) = self.next() {
+            if !is_match {
+                return Some(self.cursors(a, b));
+            }
+        }
+        None
+    }
+}
+
+unsafe impl<'a, M: ComponentMatcher> ReverseSearcher<PathComponents<'a>> for PathSearcher<'a, M> {
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(*const u8, *const u8)> {
+        while let Some((a, b, is_match)) = self.next_back() {
+            if is_match {
+                return Some(self.cursors(a, b));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn next_reject_back(&mut self) -> Option<(*const u8, *const u8)> {
+        while let Some((a, b, is_match)) = self.next_back() {
+            if !is_match {
+                return Some(self.cursors(a, b));
+            }
+        }
+        None
+    }
+}
+
+// Only the offsets an empty span can be at are valid, so the searcher
+// never starts inside of a component or its separators
+impl<'a, M: ComponentMatcher> SeekableSearcher<PathComponents<'a>> for PathSearcher<'a, M> {
+    fn seek_front(&mut self, offset: usize) -> bool {
+        let span = Span { start: offset, end: offset };
+        if offset > self.back || PathComponents::span_to_cursors(self.haystack, span).is_none() {
+            return false;
+        }
+        self.front = offset;
+        true
+    }
+
+    fn seek_back(&mut self, offset: usize) -> bool {
+        let span = Span { start: offset, end: offset };
+        if offset < self.front || PathComponents::span_to_cursors(self.haystack, span).is_none() {
+            return false;
+        }
+        self.back = offset;
+        true
+    }
+}
+
+impl<'a, M: ComponentMatcher> DoubleEndedSearcher<PathComponents<'a>> for PathSearcher<'a, M> {}
+
+///////////////////////////////////////////////////////////////////////////////
+// Impl for &OsStr, &str and &Path
+///////////////////////////////////////////////////////////////////////////////
+
+/// Finds the needle with the `OsStrSearcher`, and skips occurrences
+/// that do not start and end at component boundaries.
+#[derive(Clone)]
+struct Needle<'b>(&'b OsStr);
+
+fn find_needle(needle: &OsStr, s: &[u8], mut front: usize, back: usize)
+    -> Option<(usize, usize)>
+{
+    while front < back {
+        let haystack = unsafe { os_str_bytes::from_bytes(&s[front..back]) };
+        let mut searcher = needle.into_searcher(haystack);
+        let (a, b) = match searcher.next_match() {
+            Some((a, b)) => (<&OsStr>::offset_from_front(searcher.haystack(), a) + front,
+                             <&OsStr>::offset_from_front(searcher.haystack(), b) + front),
+            None => return None,
+        };
+        if is_component_start(s, a) && is_component_end(s, b) {
+            return Some((a, b));
+        }
+        front = next_component_start(s, a);
+    }
+    None
+}
+
+fn rfind_needle(needle: &OsStr, s: &[u8], front: usize, mut back: usize)
+    -> Option<(usize, usize)>
+{
+    while front < back {
+        let haystack = unsafe { os_str_bytes::from_bytes(&s[front..back]) };
+        let mut searcher = needle.into_searcher(haystack);
+        let (a, b) = match searcher.next_match_back() {
+            Some((a, b)) => (<&OsStr>::offset_from_front(searcher.haystack(), a) + front,
+                             <&OsStr>::offset_from_front(searcher.haystack(), b) + front),
+            None => return None,
+        };
+        if is_component_start(s, a) && is_component_end(s, b) {
+            return Some((a, b));
+        }
+        back = prev_component_end(s, b);
+    }
+    None
+}
+
+impl<'b> ComponentMatcher for Needle<'b> {
+    fn find(&mut self, s: &[u8], front: usize, back: usize) -> Option<(usize, usize)> {
+        find_needle(self.0, s, front, back)
+    }
+
+    fn rfind(&mut self, s: &[u8], front: usize, back: usize) -> Option<(usize, usize)> {
+        rfind_needle(self.0, s, front, back)
+    }
+}
+
+/// Associated type for `<&OsStr as Pattern<PathComponents<'a>>>::Searcher`.
+#[derive(Clone)]
+pub struct NeedleSearcher<'a, 'b>(PathSearcher<'a, Needle<'b>>);
+
+unsafe impl<'a, 'b> Searcher<PathComponents<'a>> for NeedleSearcher<'a, 'b> {
+    searcher_methods!(forward, s, s.0, *const u8, PathComponents<'a>);
+}
+
+unsafe impl<'a, 'b> ReverseSearcher<PathComponents<'a>> for NeedleSearcher<'a, 'b> {
+    searcher_methods!(reverse, s, s.0, *const u8);
+}
+
+impl<'a, 'b> SeekableSearcher<PathComponents<'a>> for NeedleSearcher<'a, 'b> {
+    searcher_methods!(seek, s, s.0);
+}
+
+impl<'a, 'b> DoubleEndedSearcher<PathComponents<'a>> for NeedleSearcher<'a, 'b> {}
+
+/// Searches for a run of whole components.
+impl<'a, 'b> Pattern<PathComponents<'a>> for &'b OsStr {
+    type Searcher = NeedleSearcher<'a, 'b>;
+
+    fn into_searcher(self, haystack: PathComponents<'a>) -> NeedleSearcher<'a, 'b> {
+        NeedleSearcher(PathSearcher::new(haystack, Needle(self)))
+    }
+
+    /// An empty needle never matches, since components are not empty.
+    fn validate(&self) -> Result<(), PatternError> {
+        if self.is_empty() { Err(PatternError::EmptyNeedle) } else { Ok(()) }
+    }
+}
+
+/// Searches for a run of whole components.
+impl<'a, 'b> Pattern<PathComponents<'a>> for &'b str {
+    pattern_methods!(NeedleSearcher<'a, 'b>, |s: &'b str| OsStr::new(s), |s| s,
+                     PathComponents<'a>);
+
+    fn validate(&self) -> Result<(), PatternError> {
+        Pattern::<PathComponents>::validate(&OsStr::new(*self))
+    }
+}
+
+/// Searches for a run of whole components.
+impl<'a, 'b> Pattern<PathComponents<'a>> for &'b Path {
+    pattern_methods!(NeedleSearcher<'a, 'b>, |p: &'b Path| p.as_os_str(), |s| s,
+                     PathComponents<'a>);
+
+    fn validate(&self) -> Result<(), PatternError> {
+        Pattern::<PathComponents>::validate(&self.as_os_str())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Impl for char
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+struct CharNeedle([u8; 4], usize);
+
+impl CharNeedle {
+    fn as_os_str(&self) -> &OsStr {
+        unsafe { os_str_bytes::from_bytes(&self.0[..self.1]) }
+    }
+}
+
+impl ComponentMatcher for CharNeedle {
+    fn find(&mut self, s: &[u8], front: usize, back: usize) -> Option<(usize, usize)> {
+        find_needle(self.as_os_str(), s, front, back)
+    }
+
+    fn rfind(&mut self, s: &[u8], front: usize, back: usize) -> Option<(usize, usize)> {
+        rfind_needle(self.as_os_str(), s, front, back)
+    }
+}
+
+/// Associated type for `<char as Pattern<PathComponents<'a>>>::Searcher`.
+#[derive(Clone)]
+pub struct CharSearcher<'a>(PathSearcher<'a, CharNeedle>);
+
+unsafe impl<'a> Searcher<PathComponents<'a>> for CharSearcher<'a> {
+    searcher_methods!(forward, s, s.0, *const u8, PathComponents<'a>);
+}
+
+unsafe impl<'a> ReverseSearcher<PathComponents<'a>> for CharSearcher<'a> {
+    searcher_methods!(reverse, s, s.0, *const u8);
+}
+
+impl<'a> SeekableSearcher<PathComponents<'a>> for CharSearcher<'a> {
+    searcher_methods!(seek, s, s.0);
+}
+
+impl<'a> DoubleEndedSearcher<PathComponents<'a>> for CharSearcher<'a> {}
+
+/// Searches for components that consist of the given char, like `.`
+impl<'a> Pattern<PathComponents<'a>> for char {
+    type Searcher = CharSearcher<'a>;
+
+    fn into_searcher(self, haystack: PathComponents<'a>) -> CharSearcher<'a> {
+        let mut buf = [0; 4];
+        let len = self.encode_utf8(&mut buf).len();
+        CharSearcher(PathSearcher::new(haystack, CharNeedle(buf, len)))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Impl for Glob
+///////////////////////////////////////////////////////////////////////////////
+
+/// A shell style wildcard pattern that is matched against
+/// each component on its own.
+///
+/// `*` matches any number of chars, `?` a single one, and `[...]` one of
+/// the listed chars or ranges like `a-z`, or any other char if the list
+/// starts with `!` or `^`. Everything else matches itself.
+/// Bytes that are not valid UTF-8 are only matched by `*` and `?`.
+#[derive(Copy, Clone, Debug)]
+pub struct Glob<'b>(pub &'b str);
+
+/// The width of the char at `i`, counting invalid bytes as chars of their own.
+#[inline]
+fn char_width(s: &[u8], i: usize) -> usize {
+    utf8::char_at(s, i).map_or(1, |(_, w)| w)
+}
+
+/// Matches a `[...]` class at the start of `class` against `c`.
+///
+/// Returns whether it matched, and the length of the class, or `None`
+/// if the class is not closed, in which case the `[` is a literal.
+fn match_class(class: &str, c: Option<char>) -> Option<(bool, usize)> {
+    let mut chars = class.char_indices().skip(1).peekable();
+    let negated = match chars.peek() {
+        Some(&(_, '!')) | Some(&(_, '^')) => {
+            chars.next();
+            true
+        }
+        _ => false,
+    };
+    let mut matched = false;
+    let mut first = true;
+    while let Some((i, lo)) = chars.next() {
+        if lo == ']' && !first {
+            return Some((matched != negated && c.is_some(), i + 1));
+        }
+        first = false;
+        let mut hi = lo;
+        if let Some(&(_, '-')) = chars.peek() {
+            let mut rest = chars.clone();
+            rest.next();
+            match rest.next() {
+                Some((_, end)) if end != ']' => {
+                    hi = end;
+                    chars = rest;
+                }
+                _ => {}
+            }
+        }
+        if let Some(c) = c {
+            matched |= lo <= c && c <= hi;
+        }
+    }
+    None
+}
+
+fn glob_matches(glob: &str, s: &[u8]) -> bool {
+    let p = glob.as_bytes();
+    let (mut pi, mut si) = (0, 0);
+    // the position after the last `*`, and where its match ends
+    let mut star = None;
+    while si < s.len() {
+        if pi < p.len() {
+            match p[pi] {
+                b'*' => {
+                    pi += 1;
+                    star = Some((pi, si));
+                    continue;
+                }
+                b'?' => {
+                    pi += 1;
+                    si += char_width(s, si);
+                    continue;
+                }
+                b'[' => {
+                    let c = utf8::char_at(s, si).map(|(c, _)| c);
+                    match match_class(&glob[pi..], c) {
+                        Some((true, len)) => {
+                            pi += len;
+                            si += char_width(s, si);
+                            continue;
+                        }
+                        Some((false, _)) => {}
+                        None if s[si] == b'[' => {
+                            pi += 1;
+                            si += 1;
+                            continue;
+                        }
+                        None => {}
+                    }
+                }
+                b => if b == s[si] {
+                    pi += 1;
+                    si += 1;
+                    continue;
+                },
+            }
+        }
+        // mismatch, let the last `*` match one more char
+        match star {
+            Some((star_pi, star_si)) => {
+                let star_si = star_si + char_width(s, star_si);
+                pi = star_pi;
+                si = star_si;
+                star = Some((star_pi, star_si));
+            }
+            None => return false,
+        }
+    }
+    p[pi..].iter().all(|&b| b == b'*')
+}
+
+impl<'b> Glob<'b> {
+    fn component_end(s: &[u8], mut i: usize) -> usize {
+        while i < s.len() && !is_separator(s[i]) {
+            i += 1;
+        }
+        i
+    }
+}
+
+impl<'b> ComponentMatcher for Glob<'b> {
+    fn find(&mut self, s: &[u8], mut front: usize, back: usize) -> Option<(usize, usize)> {
+        if !is_component_start(s, front) {
+            front = next_component_start(s, front);
+        }
+        while front < back {
+            let end = Glob::component_end(s, front);
+            if end > back {
+                break;
+            }
+            if glob_matches(self.0, &s[front..end]) {
+                return Some((front, end));
+            }
+            front = next_component_start(s, front);
+        }
+        None
+    }
+
+    fn rfind(&mut self, s: &[u8], front: usize, mut back: usize) -> Option<(usize, usize)> {
+        if !is_component_end(s, back) && back > 0 {
+            back = prev_component_end(s, back);
+        }
+        while back > front {
+            let mut start = back;
+            while start > 0 && !is_separator(s[start - 1]) {
+                start -= 1;
+            }
+            if start < front {
+                break;
+            }
+            if glob_matches(self.0, &s[start..back]) {
+                return Some((start, back));
+            }
+            if start == 0 {
+                break;
+            }
+            back = prev_component_end(s, start);
+        }
+        None
+    }
+}
+
+/// Associated type for `<Glob as Pattern<PathComponents<'a>>>::Searcher`.
+#[derive(Clone)]
+pub struct GlobSearcher<'a, 'b>(PathSearcher<'a, Glob<'b>>);
+
+unsafe impl<'a, 'b> Searcher<PathComponents<'a>> for GlobSearcher<'a, 'b> {
+    searcher_methods!(forward, s, s.0, *const u8, PathComponents<'a>);
+}
+
+unsafe impl<'a, 'b> ReverseSearcher<PathComponents<'a>> for GlobSearcher<'a, 'b> {
+    searcher_methods!(reverse, s, s.0, *const u8);
+}
+
+impl<'a, 'b> SeekableSearcher<PathComponents<'a>> for GlobSearcher<'a, 'b> {
+    searcher_methods!(seek, s, s.0);
+}
+
+impl<'a, 'b> DoubleEndedSearcher<PathComponents<'a>> for GlobSearcher<'a, 'b> {}
+
+/// Searches for single components that match the glob.
+impl<'a, 'b> Pattern<PathComponents<'a>> for Glob<'b> {
+    type Searcher = GlobSearcher<'a, 'b>;
+
+    fn into_searcher(self, haystack: PathComponents<'a>) -> GlobSearcher<'a, 'b> {
+        GlobSearcher(PathSearcher::new(haystack, self))
+    }
+
+    fn validate(&self) -> Result<(), PatternError> {
+        if self.0.is_empty() { Err(PatternError::EmptyNeedle) } else { Ok(()) }
+    }
+}
+
+#[test]
+fn test_glob_matches() {
+    assert!(glob_matches("*.rs", b"lib.rs"));
+    assert!(glob_matches("*.rs", b".rs"));
+    assert!(!glob_matches("*.rs", b"lib.rsx"));
+    assert!(glob_matches("l?b*", b"lib.rs"));
+    assert!(glob_matches("caf?", "caf\u{e9}".as_bytes()));
+    assert!(glob_matches("a*b*c", b"aXbYbZc"));
+    assert!(!glob_matches("a*b*c", b"aXbYbZ"));
+    assert!(glob_matches("[a-c]x[!0-9]", b"bxy"));
+    assert!(!glob_matches("[a-c]x[!0-9]", b"bx5"));
+    assert!(glob_matches("[]]", b"]"));
+    assert!(glob_matches("[x", b"[x"));
+    assert!(glob_matches("*", b"\xff"));
+    assert!(!glob_matches("[!a]", b"\xff"));
+    assert!(glob_matches("", b""));
+    assert!(!glob_matches("", b"a"));
+}
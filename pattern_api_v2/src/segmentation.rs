@@ -0,0 +1,1180 @@
+//! Unicode text segmentation (UAX #29).
+//!
+//! `Graphemes` is a view of a `&str` whose cursors only ever
+//! fall on extended grapheme cluster boundaries, so that patterns searched
+//! in it can not cut through combining sequences, emoji ZWJ sequences,
+//! flags or hangul syllables.
+//!
+//! `WordBoundary` is a pattern matching the empty string at each
+//! word boundary, which makes `split(WordBoundary)` return
+//! words and the runs of whitespace and punctuation between them.
+
+use ::{Pattern, PatternHaystack, Searcher, ReverseSearcher, DoubleEndedSearcher, Span};
+use ::SeekableSearcher;
+use ::InverseMatchesAreValid;
+use unicode_tables::{GraphemeCat, IndicConjunctBreak, WordCat};
+use unicode_tables::{GRAPHEME_CAT_TABLE, INDIC_CONJUNCT_BREAK_TABLE};
+use unicode_tables::{EXTENDED_PICTOGRAPHIC_TABLE, WORD_CAT_TABLE};
+
+use core::cmp::Ordering;
+
+///////////////////////////////////////////////////////////////////////////////
+// Property lookup
+///////////////////////////////////////////////////////////////////////////////
+
+fn bsearch_range_table<T: Copy>(c: char, table: &[(char, char, T)], default: T) -> T {
+    match table.binary_search_by(|&(lo, hi, _)| {
+        if hi < c { Ordering::Less } else if lo > c { Ordering::Greater } else { Ordering::Equal }
+    }) {
+        Ok(i) => table[i].2,
+        Err(_) => default,
+    }
+}
+
+fn grapheme_cat(c: char) -> GraphemeCat {
+    bsearch_range_table(c, GRAPHEME_CAT_TABLE, GraphemeCat::Any)
+}
+
+fn indic_conjunct_break(c: char) -> IndicConjunctBreak {
+    bsearch_range_table(c, INDIC_CONJUNCT_BREAK_TABLE, IndicConjunctBreak::None)
+}
+
+fn is_extended_pictographic(c: char) -> bool {
+    EXTENDED_PICTOGRAPHIC_TABLE.binary_search_by(|&(lo, hi)| {
+        if hi < c { Ordering::Less } else if lo > c { Ordering::Greater } else { Ordering::Equal }
+    }).is_ok()
+}
+
+fn word_cat(c: char) -> WordCat {
+    bsearch_range_table(c, WORD_CAT_TABLE, WordCat::Any)
+}
+
+#[inline]
+fn char_before(s: &str, pos: usize) -> Option<char> {
+    s[..pos].chars().next_back()
+}
+
+#[inline]
+fn char_at(s: &str, pos: usize) -> Option<char> {
+    s[pos..].chars().next()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Regional indicator runs
+///////////////////////////////////////////////////////////////////////////////
+
+#[inline]
+fn is_regional_indicator(c: char) -> bool {
+    grapheme_cat(c) == GraphemeCat::RegionalIndicator
+}
+
+// The start of the char before `pos`, if it is a regional indicator
+fn regional_indicator_before(s: &str, pos: usize) -> Option<usize> {
+    char_before(s, pos).and_then(|c| {
+        if is_regional_indicator(c) { Some(pos - c.len_utf8()) } else { None }
+    })
+}
+
+// Like `regional_indicator_before()`, after applying WB4
+fn regional_indicator_unit_before(s: &str, pos: usize) -> Option<usize> {
+    word_char_before(s, pos).and_then(|(start, cat)| {
+        if cat == WordCat::RegionalIndicator { Some(start) } else { None }
+    })
+}
+
+// GB12/GB13 and WB15/WB16 depend on the number of regional indicators
+// before a position, back to the start of their run. Counting them anew
+// at every position of a long run would take quadratic time, so this
+// remembers the last position it was asked about: `count` regional
+// indicators lie between `start`, where their run begins, and `pos`.
+#[derive(Copy, Clone)]
+struct RegionalIndicators {
+    start: usize,
+    pos: usize,
+    count: usize,
+}
+
+impl RegionalIndicators {
+    #[inline]
+    fn new() -> Self {
+        RegionalIndicators { start: 0, pos: 0, count: 0 }
+    }
+
+    // `prev` returns the start of the char or WB4 unit before a position,
+    // if it is a regional indicator. Asking about a position close to the
+    // last one only looks at the chars in between.
+    fn count_before(&mut self, s: &str, pos: usize,
+                    prev: fn(&str, usize) -> Option<usize>) -> usize {
+        if self.start < pos && pos <= self.pos {
+            // The run only has regional indicators
+            // and the chars WB4 ignores in it
+            self.count -= s[pos..self.pos].chars()
+                                          .filter(|&c| is_regional_indicator(c))
+                                          .count();
+        } else {
+            let mut count = 0;
+            let mut start = pos;
+            loop {
+                if start == self.pos && self.start < start {
+                    count += self.count;
+                    start = self.start;
+                    break;
+                }
+                match prev(s, start) {
+                    Some(p) => {
+                        count += 1;
+                        start = p;
+                    }
+                    None => break,
+                }
+            }
+            self.start = start;
+            self.count = count;
+        }
+        self.pos = pos;
+        self.count
+    }
+}
+
+// The boundary rules, for searching from one end of a haystack
+#[derive(Copy, Clone)]
+struct Boundaries {
+    grapheme_ri: RegionalIndicators,
+    word_ri: RegionalIndicators,
+}
+
+impl Boundaries {
+    #[inline]
+    fn new() -> Self {
+        Boundaries {
+            grapheme_ri: RegionalIndicators::new(),
+            word_ri: RegionalIndicators::new(),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Grapheme cluster boundaries
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns whether `pos` is an extended grapheme cluster boundary of `s`.
+///
+/// Positions that are not char boundaries are never grapheme boundaries.
+pub fn is_grapheme_boundary(s: &str, pos: usize) -> bool {
+    Boundaries::new().is_grapheme_boundary(s, pos)
+}
+
+impl Boundaries {
+    fn is_grapheme_boundary(&mut self, s: &str, pos: usize) -> bool {
+        use unicode_tables::GraphemeCat::*;
+
+        if pos == 0 || pos == s.len() {
+            return true; // GB1, GB2
+        }
+        if !s.is_char_boundary(pos) {
+            return false;
+        }
+        let before = char_before(s, pos).unwrap();
+        let after = char_at(s, pos).unwrap();
+
+        match (grapheme_cat(before), grapheme_cat(after)) {
+            (CR, LF) => false,                                           // GB3
+            (CR, _) | (LF, _) | (Control, _) => true,                    // GB4
+            (_, CR) | (_, LF) | (_, Control) => true,                    // GB5
+            (L, L) | (L, V) | (L, LV) | (L, LVT) => false,               // GB6
+            (LV, V) | (LV, T) | (V, V) | (V, T) => false,                // GB7
+            (LVT, T) | (T, T) => false,                                  // GB8
+            (_, Extend) | (_, ZWJ) => false,                             // GB9
+            (_, SpacingMark) => false,                                   // GB9a
+            (Prepend, _) => false,                                       // GB9b
+            _ if is_indic_conjunct(s, pos, after) => false,              // GB9c
+            (ZWJ, _) if is_emoji_zwj_sequence(s, pos, after) => false,   // GB11
+            (RegionalIndicator, RegionalIndicator) => {                  // GB12, GB13
+                self.grapheme_ri.count_before(s, pos, regional_indicator_before)
+                                .is_multiple_of(2)
+            }
+            _ => true,                                                   // GB999
+        }
+    }
+
+    fn next_grapheme_boundary(&mut self, s: &str, pos: usize) -> usize {
+        let mut pos = pos;
+        loop {
+            pos += char_at(s, pos).unwrap().len_utf8();
+            if self.is_grapheme_boundary(s, pos) {
+                return pos;
+            }
+        }
+    }
+
+    fn prev_grapheme_boundary(&mut self, s: &str, pos: usize) -> usize {
+        let mut pos = pos;
+        loop {
+            pos -= char_before(s, pos).unwrap().len_utf8();
+            if self.is_grapheme_boundary(s, pos) {
+                return pos;
+            }
+        }
+    }
+}
+
+// Consonant [Extend Linker]* Linker [Extend Linker]* × Consonant
+fn is_indic_conjunct(s: &str, pos: usize, after: char) -> bool {
+    if indic_conjunct_break(after) != IndicConjunctBreak::Consonant {
+        return false;
+    }
+    let mut seen_linker = false;
+    for c in s[..pos].chars().rev() {
+        match indic_conjunct_break(c) {
+            IndicConjunctBreak::Linker => seen_linker = true,
+            IndicConjunctBreak::Extend => {}
+            IndicConjunctBreak::Consonant => return seen_linker,
+            IndicConjunctBreak::None => return false,
+        }
+    }
+    false
+}
+
+// ExtPict Extend* ZWJ × ExtPict
+fn is_emoji_zwj_sequence(s: &str, pos: usize, after: char) -> bool {
+    if !is_extended_pictographic(after) {
+        return false;
+    }
+    let zwj_len = '\u{200D}'.len_utf8();
+    for c in s[..pos - zwj_len].chars().rev() {
+        if grapheme_cat(c) != GraphemeCat::Extend {
+            return is_extended_pictographic(c);
+        }
+    }
+    false
+}
+
+/// Returns the first grapheme boundary after `pos`, which needs to be
+/// smaller than `s.len()`.
+pub fn next_grapheme_boundary(s: &str, pos: usize) -> usize {
+    Boundaries::new().next_grapheme_boundary(s, pos)
+}
+
+/// Returns the last grapheme boundary before `pos`, which needs to be
+/// greater than `0`.
+pub fn prev_grapheme_boundary(s: &str, pos: usize) -> usize {
+    Boundaries::new().prev_grapheme_boundary(s, pos)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Word boundaries
+///////////////////////////////////////////////////////////////////////////////
+
+#[inline]
+fn is_ignorable(cat: WordCat) -> bool {
+    matches!(cat, WordCat::Extend | WordCat::Format | WordCat::ZWJ)
+}
+
+#[inline]
+fn is_newline(cat: WordCat) -> bool {
+    matches!(cat, WordCat::CR | WordCat::LF | WordCat::Newline)
+}
+
+#[inline]
+fn is_ah_letter(cat: WordCat) -> bool {
+    cat == WordCat::ALetter || cat == WordCat::HebrewLetter
+}
+
+#[inline]
+fn is_mid_num_let_q(cat: WordCat) -> bool {
+    cat == WordCat::MidNumLet || cat == WordCat::SingleQuote
+}
+
+// The char before `pos` after applying WB4, as (start, category)
+fn word_char_before(s: &str, pos: usize) -> Option<(usize, WordCat)> {
+    let mut pos = pos;
+    while let Some(c) = char_before(s, pos) {
+        let start = pos - c.len_utf8();
+        let cat = word_cat(c);
+        if is_ignorable(cat) {
+            // Extend, Format and ZWJ attach to the char before them,
+            // unless it is the start of text or a line break
+            match char_before(s, start) {
+                Some(p) if !is_newline(word_cat(p)) => {
+                    pos = start;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        return Some((start, cat));
+    }
+    None
+}
+
+// The char at `pos` after applying WB4, as (end, category)
+fn word_char_at(s: &str, pos: usize) -> Option<(usize, WordCat)> {
+    char_at(s, pos).map(|c| {
+        let cat = word_cat(c);
+        let mut end = pos + c.len_utf8();
+        if !is_newline(cat) {
+            while let Some(n) = char_at(s, end) {
+                if !is_ignorable(word_cat(n)) {
+                    break;
+                }
+                end += n.len_utf8();
+            }
+        }
+        (end, cat)
+    })
+}
+
+/// Returns whether `pos` is a default word boundary of `s`.
+///
+/// Positions that are not char boundaries are never word boundaries.
+pub fn is_word_boundary(s: &str, pos: usize) -> bool {
+    Boundaries::new().is_word_boundary(s, pos)
+}
+
+impl Boundaries {
+    fn is_word_boundary(&mut self, s: &str, pos: usize) -> bool {
+        use unicode_tables::WordCat::*;
+
+        if pos == 0 || pos == s.len() {
+            return true; // WB1, WB2
+        }
+        if !s.is_char_boundary(pos) {
+            return false;
+        }
+        let before = char_before(s, pos).unwrap();
+        let after = char_at(s, pos).unwrap();
+        let (raw_a, b) = (word_cat(before), word_cat(after));
+
+        if raw_a == CR && b == LF { return false; }                         // WB3
+        if is_newline(raw_a) || is_newline(b) { return true; }              // WB3a, WB3b
+        if raw_a == ZWJ && is_extended_pictographic(after) { return false; } // WB3c
+        if raw_a == WSegSpace && b == WSegSpace { return false; }           // WB3d
+        if is_ignorable(b) { return false; }                                // WB4
+
+        let (a_start, a) = word_char_before(s, pos).unwrap();
+        let before_a = word_char_before(s, a_start).map(|t| t.1).unwrap_or(Any);
+        let after_b = word_char_at(s, pos)
+                          .and_then(|(b_end, _)| word_char_at(s, b_end))
+                          .map(|t| t.1)
+                          .unwrap_or(Any);
+
+        if is_ah_letter(a) && is_ah_letter(b) { return false; }             // WB5
+        if is_ah_letter(a) && (b == MidLetter || is_mid_num_let_q(b))
+            && is_ah_letter(after_b) { return false; }                      // WB6
+        if is_ah_letter(before_a) && (a == MidLetter || is_mid_num_let_q(a))
+            && is_ah_letter(b) { return false; }                            // WB7
+        if a == HebrewLetter && b == SingleQuote { return false; }          // WB7a
+        if a == HebrewLetter && b == DoubleQuote
+            && after_b == HebrewLetter { return false; }                    // WB7b
+        if before_a == HebrewLetter && a == DoubleQuote
+            && b == HebrewLetter { return false; }                          // WB7c
+        if a == Numeric && b == Numeric { return false; }                   // WB8
+        if is_ah_letter(a) && b == Numeric { return false; }                // WB9
+        if a == Numeric && is_ah_letter(b) { return false; }                // WB10
+        if before_a == Numeric && (a == MidNum || is_mid_num_let_q(a))
+            && b == Numeric { return false; }                               // WB11
+        if a == Numeric && (b == MidNum || is_mid_num_let_q(b))
+            && after_b == Numeric { return false; }                         // WB12
+        if a == Katakana && b == Katakana { return false; }                 // WB13
+        if (is_ah_letter(a) || a == Numeric || a == Katakana || a == ExtendNumLet)
+            && b == ExtendNumLet { return false; }                          // WB13a
+        if a == ExtendNumLet
+            && (is_ah_letter(b) || b == Numeric || b == Katakana) { return false; } // WB13b
+        if a == RegionalIndicator && b == RegionalIndicator {               // WB15, WB16
+            return self.word_ri.count_before(s, pos, regional_indicator_unit_before)
+                               .is_multiple_of(2);
+        }
+        true                                                                // WB999
+    }
+
+    // Word boundaries that would split a grapheme cluster are not reported
+    #[inline]
+    fn is_word_and_grapheme_boundary(&mut self, s: &str, pos: usize) -> bool {
+        self.is_word_boundary(s, pos) && self.is_grapheme_boundary(s, pos)
+    }
+
+    fn next_word_boundary(&mut self, s: &str, pos: usize) -> usize {
+        let mut pos = pos;
+        loop {
+            pos += char_at(s, pos).unwrap().len_utf8();
+            if self.is_word_and_grapheme_boundary(s, pos) {
+                return pos;
+            }
+        }
+    }
+
+    fn prev_word_boundary(&mut self, s: &str, pos: usize) -> usize {
+        let mut pos = pos;
+        loop {
+            pos -= char_before(s, pos).unwrap().len_utf8();
+            if self.is_word_and_grapheme_boundary(s, pos) {
+                return pos;
+            }
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Graphemes haystack
+///////////////////////////////////////////////////////////////////////////////
+
+/// A view of a `&str` as a sequence of extended grapheme clusters.
+///
+/// Cursors are byte offsets into the string, and all patterns
+/// only return matches and rejects starting and ending at grapheme
+/// cluster boundaries.
+#[derive(Copy, Clone, Debug)]
+pub struct Graphemes<'a>(pub &'a str);
+
+impl<'a> PatternHaystack for Graphemes<'a> {
+    type Haystack = &'a str;
+    type Cursor = usize;
+    type MatchType = &'a str;
+
+    fn into_haystack(self) -> &'a str {
+        self.0
+    }
+
+    fn offset_from_front(_: &'a str, begin: usize) -> usize {
+        begin
+    }
+
+    unsafe fn range_to_self(hs: &'a str, start: usize, end: usize) -> &'a str {
+        &hs[start..end]
+    }
+    fn cursor_at_front(_: &'a str) -> usize {
+        0
+    }
+    fn cursor_at_back(hs: &'a str) -> usize {
+        hs.len()
+    }
+    fn match_type_len(mt: &&'a str) -> usize { mt.len() }
+
+    fn span_to_cursors(hs: &'a str, span: Span) -> Option<(usize, usize)> {
+        if span.start <= span.end
+            && span.end <= hs.len()
+            && is_grapheme_boundary(hs, span.start)
+            && is_grapheme_boundary(hs, span.end)
+        {
+            Some((span.start, span.end))
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl<'a> InverseMatchesAreValid for Graphemes<'a> {}
+
+#[derive(Copy, Clone)]
+struct Iter<'a> {
+    haystack: &'a str,
+    start: usize,
+    end: usize,
+    boundaries_front: Boundaries,
+    boundaries_back: Boundaries,
+}
+
+impl<'a> Iter<'a> {
+    #[inline]
+    fn new(haystack: &'a str) -> Self {
+        Iter {
+            haystack: haystack,
+            start: 0,
+            end: haystack.len(),
+            boundaries_front: Boundaries::new(),
+            boundaries_back: Boundaries::new(),
+        }
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.start == self.end {
+            return None;
+        }
+        let a = self.start;
+        self.start = self.boundaries_front.next_grapheme_boundary(self.haystack, a);
+        Some((a, self.start))
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, usize)> {
+        if self.start == self.end {
+            return None;
+        }
+        let b = self.end;
+        self.end = self.boundaries_back.prev_grapheme_boundary(self.haystack, b);
+        Some((self.end, b))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Impl for a ClusterEq wrapper
+///////////////////////////////////////////////////////////////////////////////
+
+/// Matches single grapheme clusters.
+pub trait ClusterEq {
+    fn matches(&mut self, cluster: &str) -> bool;
+}
+
+/// Matches clusters consisting of exactly this char.
+impl ClusterEq for char {
+    #[inline]
+    fn matches(&mut self, cluster: &str) -> bool {
+        let mut chars = cluster.chars();
+        chars.next() == Some(*self) && chars.next().is_none()
+    }
+}
+
+/// Matches clusters consisting of exactly one of the chars.
+impl<'b> ClusterEq for &'b [char] {
+    #[inline]
+    fn matches(&mut self, cluster: &str) -> bool {
+        self.iter().any(|&c| { let mut c = c; c.matches(cluster) })
+    }
+}
+
+impl<F> ClusterEq for F where F: FnMut(&str) -> bool {
+    #[inline]
+    fn matches(&mut self, cluster: &str) -> bool { (*self)(cluster) }
+}
+
+pub struct ClusterEqPattern<C: ClusterEq>(pub C);
+
+#[derive(Clone)]
+pub struct ClusterEqSearcher<'a, C: ClusterEq> {
+    cluster_eq: C,
+    iter: Iter<'a>,
+}
+
+impl<'a, C: ClusterEq> Pattern<Graphemes<'a>> for ClusterEqPattern<C> {
+    type Searcher = ClusterEqSearcher<'a, C>;
+
+    #[inline]
+    fn into_searcher(self, haystack: Graphemes<'a>) -> ClusterEqSearcher<'a, C> {
+        ClusterEqSearcher {
+            cluster_eq: self.0,
+            iter: Iter::new(haystack.0),
+        }
+    }
+}
+
+unsafe impl<'a, C: ClusterEq> Searcher<Graphemes<'a>> for ClusterEqSearcher<'a, C> {
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.iter.haystack
+    }
+
+    #[inline]
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<Graphemes<'a>>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        while let Some((a, b)) = self.iter.next() {
+            if self.cluster_eq.matches(&self.iter.haystack[a..b]) {
+                return Some((a, b));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(usize, usize)> {
+        while let Some((a, b)) = self.iter.next() {
+            if !self.cluster_eq.matches(&self.iter.haystack[a..b]) {
+                return Some((a, b));
+            }
+        }
+        None
+    }
+}
+
+unsafe impl<'a, C: ClusterEq> ReverseSearcher<Graphemes<'a>> for ClusterEqSearcher<'a, C> {
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        while let Some((a, b)) = self.iter.next_back() {
+            if self.cluster_eq.matches(&self.iter.haystack[a..b]) {
+                return Some((a, b));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn next_reject_back(&mut self) -> Option<(usize, usize)> {
+        while let Some((a, b)) = self.iter.next_back() {
+            if !self.cluster_eq.matches(&self.iter.haystack[a..b]) {
+                return Some((a, b));
+            }
+        }
+        None
+    }
+}
+
+impl<'a, C: ClusterEq> DoubleEndedSearcher<Graphemes<'a>> for ClusterEqSearcher<'a, C> {}
+
+impl<'a, C: ClusterEq> SeekableSearcher<Graphemes<'a>> for ClusterEqSearcher<'a, C> {
+    fn seek_front(&mut self, offset: usize) -> bool {
+        if offset > self.iter.end || !is_grapheme_boundary(self.iter.haystack, offset) {
+            return false;
+        }
+        self.iter.start = offset;
+        true
+    }
+
+    fn seek_back(&mut self, offset: usize) -> bool {
+        if offset < self.iter.start || offset > self.iter.haystack.len()
+            || !is_grapheme_boundary(self.iter.haystack, offset)
+        {
+            return false;
+        }
+        self.iter.end = offset;
+        true
+    }
+}
+
+/// Searches for clusters that consist of exactly the given char
+impl<'a> Pattern<Graphemes<'a>> for char {
+    pattern_methods!(ClusterEqSearcher<'a, char>, ClusterEqPattern, |s| s, Graphemes<'a>);
+}
+
+/// Searches for clusters that consist of exactly one of the chars
+impl<'a, 'b> Pattern<Graphemes<'a>> for &'b [char] {
+    pattern_methods!(ClusterEqSearcher<'a, &'b [char]>, ClusterEqPattern, |s| s, Graphemes<'a>);
+}
+
+/// Searches for clusters for which the closure returns true
+impl<'a, F> Pattern<Graphemes<'a>> for F where F: FnMut(&str) -> bool {
+    pattern_methods!(ClusterEqSearcher<'a, F>, ClusterEqPattern, |s| s, Graphemes<'a>);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Impl for &str
+///////////////////////////////////////////////////////////////////////////////
+
+enum SearchStep {
+    Match(usize, usize),
+    Reject(usize, usize),
+    Done
+}
+
+/// Associated type for `<&str as Pattern<Graphemes<'a>>>::Searcher`.
+///
+/// Finds candidates with the regular substring search, and skips those
+/// that do not start and end on grapheme cluster boundaries.
+#[derive(Clone)]
+pub struct GraphemeStrSearcher<'a, 'b> {
+    haystack: &'a str,
+    needle: &'b str,
+    front: usize,
+    back: usize,
+    // Next match found while looking for a reject
+    pending_front: Option<(usize, usize)>,
+    pending_back: Option<(usize, usize)>,
+    // Whether the empty needle still matches at `front`/`back`
+    empty_match_front: bool,
+    empty_match_back: bool,
+    boundaries_front: Boundaries,
+    boundaries_back: Boundaries,
+}
+
+impl<'a, 'b> Pattern<Graphemes<'a>> for &'b str {
+    type Searcher = GraphemeStrSearcher<'a, 'b>;
+
+    #[inline]
+    fn into_searcher(self, haystack: Graphemes<'a>) -> GraphemeStrSearcher<'a, 'b> {
+        GraphemeStrSearcher {
+            haystack: haystack.0,
+            needle: self,
+            front: 0,
+            back: haystack.0.len(),
+            pending_front: None,
+            pending_back: None,
+            empty_match_front: true,
+            empty_match_back: true,
+            boundaries_front: Boundaries::new(),
+            boundaries_back: Boundaries::new(),
+        }
+    }
+}
+
+impl<'a, 'b> GraphemeStrSearcher<'a, 'b> {
+    fn is_valid_match(haystack: &str, a: usize, b: usize,
+                      boundaries: &mut Boundaries) -> bool {
+        boundaries.is_grapheme_boundary(haystack, a)
+            && boundaries.is_grapheme_boundary(haystack, b)
+    }
+
+    fn find_front(&mut self) -> Option<(usize, usize)> {
+        let mut from = self.front;
+        while let Some(i) = self.haystack[from..self.back].find(self.needle) {
+            let a = from + i;
+            let b = a + self.needle.len();
+            if Self::is_valid_match(self.haystack, a, b, &mut self.boundaries_front) {
+                return Some((a, b));
+            }
+            from = a + char_at(self.haystack, a).unwrap().len_utf8();
+        }
+        None
+    }
+
+    fn find_back(&mut self) -> Option<(usize, usize)> {
+        let mut to = self.back;
+        while let Some(a) = self.haystack[self.front..to].rfind(self.needle) {
+            let a = self.front + a;
+            let b = a + self.needle.len();
+            if Self::is_valid_match(self.haystack, a, b, &mut self.boundaries_back) {
+                return Some((a, b));
+            }
+            to = b - char_before(self.haystack, b).unwrap().len_utf8();
+        }
+        None
+    }
+
+    fn next(&mut self) -> SearchStep {
+        if self.needle.is_empty() {
+            if self.empty_match_front {
+                self.empty_match_front = false;
+                if self.front == self.back {
+                    // only report the last empty match once
+                    if !self.empty_match_back {
+                        return SearchStep::Done;
+                    }
+                    self.empty_match_back = false;
+                }
+                return SearchStep::Match(self.front, self.front);
+            }
+            if self.front == self.back {
+                return SearchStep::Done;
+            }
+            let a = self.front;
+            self.front = self.boundaries_front.next_grapheme_boundary(self.haystack, a);
+            self.empty_match_front = true;
+            return SearchStep::Reject(a, self.front);
+        }
+
+        if self.front == self.back {
+            return SearchStep::Done;
+        }
+        let found = match self.pending_front.take() {
+            Some((a, b)) if b <= self.back => Some((a, b)),
+            _ => self.find_front(),
+        };
+        match found {
+            Some((a, b)) if a == self.front => {
+                self.front = b;
+                SearchStep::Match(a, b)
+            }
+            Some((a, b)) => {
+                self.pending_front = Some((a, b));
+                let reject = SearchStep::Reject(self.front, a);
+                self.front = a;
+                reject
+            }
+            None => {
+                let reject = SearchStep::Reject(self.front, self.back);
+                self.front = self.back;
+                reject
+            }
+        }
+    }
+
+    fn next_back(&mut self) -> SearchStep {
+        if self.needle.is_empty() {
+            if self.empty_match_back {
+                self.empty_match_back = false;
+                if self.front == self.back {
+                    if !self.empty_match_front {
+                        return SearchStep::Done;
+                    }
+                    self.empty_match_front = false;
+                }
+                return SearchStep::Match(self.back, self.back);
+            }
+            if self.front == self.back {
+                return SearchStep::Done;
+            }
+            let b = self.back;
+            self.back = self.boundaries_back.prev_grapheme_boundary(self.haystack, b);
+            self.empty_match_back = true;
+            return SearchStep::Reject(self.back, b);
+        }
+
+        if self.front == self.back {
+            return SearchStep::Done;
+        }
+        let found = match self.pending_back.take() {
+            Some((a, b)) if a >= self.front => Some((a, b)),
+            _ => self.find_back(),
+        };
+        match found {
+            Some((a, b)) if b == self.back => {
+                self.back = a;
+                SearchStep::Match(a, b)
+            }
+            Some((a, b)) => {
+                self.pending_back = Some((a, b));
+                let reject = SearchStep::Reject(b, self.back);
+                self.back = b;
+                reject
+            }
+            None => {
+                let reject = SearchStep::Reject(self.front, self.back);
+                self.back = self.front;
+                reject
+            }
+        }
+    }
+}
+
+unsafe impl<'a, 'b> Searcher<Graphemes<'a>> for GraphemeStrSearcher<'a, 'b> {
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.haystack
+    }
+
+    #[inline]
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<Graphemes<'a>>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(a, b) => return Some((a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some((a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+unsafe impl<'a, 'b> ReverseSearcher<Graphemes<'a>> for GraphemeStrSearcher<'a, 'b> {
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(a, b) => return Some((a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    #[inline]
+    fn next_reject_back(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some((a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+impl<'a, 'b> SeekableSearcher<Graphemes<'a>> for GraphemeStrSearcher<'a, 'b> {
+    fn seek_front(&mut self, offset: usize) -> bool {
+        if offset > self.back || !is_grapheme_boundary(self.haystack, offset) {
+            return false;
+        }
+        self.front = offset;
+        self.pending_front = None;
+        self.empty_match_front = true;
+        true
+    }
+
+    fn seek_back(&mut self, offset: usize) -> bool {
+        if offset < self.front || offset > self.haystack.len()
+            || !is_grapheme_boundary(self.haystack, offset)
+        {
+            return false;
+        }
+        self.back = offset;
+        self.pending_back = None;
+        self.empty_match_back = true;
+        true
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Impl for WordBoundary
+///////////////////////////////////////////////////////////////////////////////
+
+/// Matches the empty string at each word boundary, as defined by
+/// the default word boundary rules of UAX #29.
+///
+/// Boundaries inside of a grapheme cluster are skipped, so the pieces
+/// between matches never contain broken user-perceived characters.
+#[derive(Copy, Clone, Debug)]
+pub struct WordBoundary;
+
+/// Associated type for `<WordBoundary as Pattern<&'a str>>::Searcher`
+/// and `<WordBoundary as Pattern<Graphemes<'a>>>::Searcher`.
+#[derive(Clone)]
+pub struct WordBoundarySearcher<'a> {
+    haystack: &'a str,
+    front: usize,
+    back: usize,
+    // Whether the boundary at `front`/`back` still needs to be returned
+    match_front: bool,
+    match_back: bool,
+    boundaries_front: Boundaries,
+    boundaries_back: Boundaries,
+}
+
+impl<'a> WordBoundarySearcher<'a> {
+    fn new(haystack: &'a str) -> Self {
+        WordBoundarySearcher {
+            haystack: haystack,
+            front: 0,
+            back: haystack.len(),
+            match_front: true,
+            match_back: true,
+            boundaries_front: Boundaries::new(),
+            boundaries_back: Boundaries::new(),
+        }
+    }
+
+    fn next(&mut self) -> SearchStep {
+        if self.match_front {
+            self.match_front = false;
+            if self.front == self.back {
+                // only report the last boundary once
+                if !self.match_back {
+                    return SearchStep::Done;
+                }
+                self.match_back = false;
+            }
+            return SearchStep::Match(self.front, self.front);
+        }
+        if self.front == self.back {
+            return SearchStep::Done;
+        }
+        let a = self.front;
+        self.front = self.boundaries_front.next_word_boundary(self.haystack, a);
+        self.match_front = true;
+        SearchStep::Reject(a, self.front)
+    }
+
+    fn next_back(&mut self) -> SearchStep {
+        if self.match_back {
+            self.match_back = false;
+            if self.front == self.back {
+                if !self.match_front {
+                    return SearchStep::Done;
+                }
+                self.match_front = false;
+            }
+            return SearchStep::Match(self.back, self.back);
+        }
+        if self.front == self.back {
+            return SearchStep::Done;
+        }
+        let b = self.back;
+        self.back = self.boundaries_back.prev_word_boundary(self.haystack, b);
+        self.match_back = true;
+        SearchStep::Reject(self.back, b)
+    }
+
+    // `is_valid` tells the positions a cursor of the haystack can be at,
+    // which do not need to be word boundaries
+    fn seek_front(&mut self, offset: usize, is_valid: fn(&str, usize) -> bool) -> bool {
+        if offset > self.back || !is_valid(self.haystack, offset) {
+            return false;
+        }
+        self.front = offset;
+        self.match_front = self.boundaries_front
+                               .is_word_and_grapheme_boundary(self.haystack, offset);
+        true
+    }
+
+    fn seek_back(&mut self, offset: usize, is_valid: fn(&str, usize) -> bool) -> bool {
+        if offset < self.front || offset > self.haystack.len() || !is_valid(self.haystack, offset) {
+            return false;
+        }
+        self.back = offset;
+        self.match_back = self.boundaries_back
+                              .is_word_and_grapheme_boundary(self.haystack, offset);
+        true
+    }
+
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(a, b) => return Some((a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    fn next_reject(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some((a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(a, b) => return Some((a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    fn next_reject_back(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some((a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+impl<'a> Pattern<Graphemes<'a>> for WordBoundary {
+    type Searcher = WordBoundarySearcher<'a>;
+
+    #[inline]
+    fn into_searcher(self, haystack: Graphemes<'a>) -> WordBoundarySearcher<'a> {
+        WordBoundarySearcher::new(haystack.0)
+    }
+}
+
+unsafe impl<'a> Searcher<Graphemes<'a>> for WordBoundarySearcher<'a> {
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.haystack
+    }
+
+    #[inline]
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<Graphemes<'a>>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        WordBoundarySearcher::next_match(self)
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(usize, usize)> {
+        WordBoundarySearcher::next_reject(self)
+    }
+}
+
+unsafe impl<'a> ReverseSearcher<Graphemes<'a>> for WordBoundarySearcher<'a> {
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        WordBoundarySearcher::next_match_back(self)
+    }
+
+    #[inline]
+    fn next_reject_back(&mut self) -> Option<(usize, usize)> {
+        WordBoundarySearcher::next_reject_back(self)
+    }
+}
+
+impl<'a> DoubleEndedSearcher<Graphemes<'a>> for WordBoundarySearcher<'a> {}
+
+impl<'a> SeekableSearcher<Graphemes<'a>> for WordBoundarySearcher<'a> {
+    #[inline]
+    fn seek_front(&mut self, offset: usize) -> bool {
+        WordBoundarySearcher::seek_front(self, offset, is_grapheme_boundary)
+    }
+
+    #[inline]
+    fn seek_back(&mut self, offset: usize) -> bool {
+        WordBoundarySearcher::seek_back(self, offset, is_grapheme_boundary)
+    }
+}
+
+impl<'a> Pattern<&'a str> for WordBoundary {
+    type Searcher = WordBoundarySearcher<'a>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &'a str) -> WordBoundarySearcher<'a> {
+        WordBoundarySearcher::new(haystack)
+    }
+}
+
+// Cursors of &str are pointers into the string
+#[inline]
+fn str_cursors(s: &str, (a, b): (usize, usize)) -> (*const u8, *const u8) {
+    unsafe {
+        (s.as_ptr().offset(a as isize), s.as_ptr().offset(b as isize))
+    }
+}
+
+unsafe impl<'a> Searcher<&'a str> for WordBoundarySearcher<'a> {
+    #[inline]
+    fn haystack(&self) -> (*const u8, *const u8) {
+        self.haystack.into_haystack()
+    }
+
+    #[inline]
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<&'a str>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(*const u8, *const u8)> {
+        let s = self.haystack;
+        WordBoundarySearcher::next_match(self).map(|r| str_cursors(s, r))
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(*const u8, *const u8)> {
+        let s = self.haystack;
+        WordBoundarySearcher::next_reject(self).map(|r| str_cursors(s, r))
+    }
+}
+
+unsafe impl<'a> ReverseSearcher<&'a str> for WordBoundarySearcher<'a> {
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(*const u8, *const u8)> {
+        let s = self.haystack;
+        WordBoundarySearcher::next_match_back(self).map(|r| str_cursors(s, r))
+    }
+
+    #[inline]
+    fn next_reject_back(&mut self) -> Option<(*const u8, *const u8)> {
+        let s = self.haystack;
+        WordBoundarySearcher::next_reject_back(self).map(|r| str_cursors(s, r))
+    }
+}
+
+impl<'a> DoubleEndedSearcher<&'a str> for WordBoundarySearcher<'a> {}
+
+impl<'a> SeekableSearcher<&'a str> for WordBoundarySearcher<'a> {
+    #[inline]
+    fn seek_front(&mut self, offset: usize) -> bool {
+        WordBoundarySearcher::seek_front(self, offset, str::is_char_boundary)
+    }
+
+    #[inline]
+    fn seek_back(&mut self, offset: usize) -> bool {
+        WordBoundarySearcher::seek_back(self, offset, str::is_char_boundary)
+    }
+}
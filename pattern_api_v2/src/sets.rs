@@ -0,0 +1,305 @@
+//! Precomputed sets of chars and bytes.
+//!
+//! Searching for any of the chars in a `&[char]` compares every char of
+//! the haystack with every char of the needle. A `CharSet` answers the
+//! same question with a single bit test for ASCII chars, and a binary
+//! search over sorted ranges for all other chars. If the set only
+//! contains ASCII chars, the searchers do not decode the haystack, and
+//! compare bytes directly.
+//!
+//! `ByteSet` is the same for `&[u8]` haystacks, as a 256 bit bitmap.
+
+use ::{Pattern, PatternHaystack, ReverseSearcher};
+use ::{Searcher, DoubleEndedSearcher, SeekableSearcher};
+use slice::{ElemEq, ElemEqPattern};
+
+use core::iter::FromIterator;
+use core::ops::RangeInclusive;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use utf8::CharEq;
+
+///////////////////////////////////////////////////////////////////////////////
+// CharSet
+///////////////////////////////////////////////////////////////////////////////
+
+/// A set of chars, stored as a bitmap for ASCII and sorted ranges otherwise.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CharSet {
+    ascii: u128,
+    // Disjoint and not adjacent, sorted, and above U+007F
+    ranges: Vec<(char, char)>,
+}
+
+#[cfg(feature = "alloc")]
+impl CharSet {
+    #[inline]
+    pub fn new() -> CharSet {
+        CharSet::default()
+    }
+
+    #[inline]
+    pub fn insert(&mut self, c: char) {
+        self.insert_range(c..=c);
+    }
+
+    pub fn insert_range(&mut self, range: RangeInclusive<char>) {
+        let (lo, hi) = (*range.start() as u32, *range.end() as u32);
+        if lo > hi {
+            return;
+        }
+        if lo < 128 {
+            let hi = hi.min(127);
+            self.ascii |= (!0u128 >> (127 - hi)) & (!0u128 << lo);
+        }
+        if hi >= 128 {
+            self.insert_non_ascii(lo.max(128), hi);
+        }
+    }
+
+    fn insert_non_ascii(&mut self, mut lo: u32, mut hi: u32) {
+        // the ranges that overlap or touch the new one are merged into it
+        let start = self.ranges.partition_point(|&(_, h)| (h as u32) + 1 < lo);
+        let end = self.ranges.partition_point(|&(l, _)| (l as u32) <= hi + 1);
+        if start < end {
+            lo = lo.min(self.ranges[start].0 as u32);
+            hi = hi.max(self.ranges[end - 1].1 as u32);
+        }
+        self.ranges.drain(start..end);
+        // both ends are chars, since they are bounds of ranges of chars
+        let to_char = |c| ::core::char::from_u32(c).unwrap();
+        self.ranges.insert(start, (to_char(lo), to_char(hi)));
+    }
+
+    #[inline]
+    pub fn contains(&self, c: char) -> bool {
+        let c32 = c as u32;
+        if c32 < 128 {
+            self.ascii >> c32 & 1 != 0
+        } else {
+            let i = self.ranges.partition_point(|&(_, hi)| hi < c);
+            i < self.ranges.len() && self.ranges[i].0 <= c
+        }
+    }
+
+    /// Whether the set only contains ASCII chars.
+    #[inline]
+    pub fn is_ascii(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ascii == 0 && self.ranges.is_empty()
+    }
+
+    /// The chars that are in this set or in `other`.
+    pub fn union<S: Into<CharSet>>(mut self, other: S) -> CharSet {
+        let other = other.into();
+        self.ascii |= other.ascii;
+        for &(lo, hi) in &other.ranges {
+            self.insert_non_ascii(lo as u32, hi as u32);
+        }
+        self
+    }
+
+    /// The chars that are in this set but not in `other`.
+    pub fn difference<S: Into<CharSet>>(self, other: S) -> CharSet {
+        let other = other.into();
+        let mut ranges = Vec::with_capacity(self.ranges.len());
+        let mut push = |lo: u32, hi: u32| {
+            // the cut ends can fall on surrogates, which are skipped
+            let lo = if (0xD800..0xE000).contains(&lo) { 0xE000 } else { lo };
+            let hi = if (0xD800..0xE000).contains(&hi) { 0xD7FF } else { hi };
+            if lo <= hi {
+                let to_char = |c| ::core::char::from_u32(c).unwrap();
+                ranges.push((to_char(lo), to_char(hi)));
+            }
+        };
+        let mut first = 0;
+        for &(lo, hi) in &self.ranges {
+            let (mut lo, hi) = (lo as u32, hi as u32);
+            while first < other.ranges.len() && (other.ranges[first].1 as u32) < lo {
+                first += 1;
+            }
+            // a range of `other` can overlap several ranges of `self`,
+            // so `first` only skips the ones that are entirely below
+            let mut i = first;
+            while lo <= hi {
+                match other.ranges.get(i) {
+                    Some(&(cut_lo, cut_hi)) if cut_lo as u32 <= hi => {
+                        if lo < cut_lo as u32 {
+                            push(lo, cut_lo as u32 - 1);
+                        }
+                        lo = cut_hi as u32 + 1;
+                        i += 1;
+                    }
+                    _ => {
+                        push(lo, hi);
+                        break;
+                    }
+                }
+            }
+        }
+        CharSet { ascii: self.ascii & !other.ascii, ranges: ranges }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a [char]> for CharSet {
+    fn from(chars: &'a [char]) -> CharSet {
+        chars.iter().cloned().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a [RangeInclusive<char>]> for CharSet {
+    fn from(ranges: &'a [RangeInclusive<char>]) -> CharSet {
+        ranges.iter().cloned().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Extend<char> for CharSet {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            self.insert(c);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Extend<RangeInclusive<char>> for CharSet {
+    fn extend<I: IntoIterator<Item = RangeInclusive<char>>>(&mut self, iter: I) {
+        for r in iter {
+            self.insert_range(r);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromIterator<char> for CharSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> CharSet {
+        let mut set = CharSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromIterator<RangeInclusive<char>> for CharSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<char>>>(iter: I) -> CharSet {
+        let mut set = CharSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> CharEq for &'a CharSet {
+    #[inline]
+    fn matches(&mut self, c: char) -> bool { self.contains(c) }
+
+    #[inline]
+    fn only_ascii(&self) -> bool { self.is_ascii() }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ByteSet
+///////////////////////////////////////////////////////////////////////////////
+
+/// A set of bytes, stored as a bitmap.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ByteSet([u64; 4]);
+
+impl ByteSet {
+    #[inline]
+    pub fn new() -> ByteSet {
+        ByteSet([0; 4])
+    }
+
+    #[inline]
+    pub fn insert(&mut self, b: u8) {
+        self.0[(b >> 6) as usize] |= 1 << (b & 63);
+    }
+
+    pub fn insert_range(&mut self, range: RangeInclusive<u8>) {
+        for b in range {
+            self.insert(b);
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, b: u8) -> bool {
+        self.0[(b >> 6) as usize] >> (b & 63) & 1 != 0
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0 == [0; 4]
+    }
+}
+
+impl<'a> From<&'a [u8]> for ByteSet {
+    fn from(bytes: &'a [u8]) -> ByteSet {
+        bytes.iter().cloned().collect()
+    }
+}
+
+impl Extend<u8> for ByteSet {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for b in iter {
+            self.insert(b);
+        }
+    }
+}
+
+impl FromIterator<u8> for ByteSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> ByteSet {
+        let mut set = ByteSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl ElemEq<u8> for ByteSet {
+    #[inline]
+    fn matches(&mut self, b: &u8) -> bool { self.contains(*b) }
+}
+
+macro_rules! impl_byte_set {
+    ($module:ident, $slice:ty, $cursor:ty) => {
+        pub mod $module {
+            use super::*;
+            use slice::$module::ElemEqSearcher;
+
+            /// Associated type for `<ByteSet as Pattern<&'a [u8]>>::Searcher`.
+            #[derive(Clone)]
+            pub struct ByteSetSearcher<'a>(ElemEqSearcher<'a, u8, ByteSet>);
+
+            unsafe impl<'a> Searcher<$slice> for ByteSetSearcher<'a> {
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
+            }
+
+            unsafe impl<'a> ReverseSearcher<$slice> for ByteSetSearcher<'a> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a> SeekableSearcher<$slice> for ByteSetSearcher<'a> {
+                searcher_methods!(seek, s, s.0);
+            }
+
+            impl<'a> DoubleEndedSearcher<$slice> for ByteSetSearcher<'a> {}
+
+            /// Searches for bytes that are in the set
+            impl<'a> Pattern<$slice> for ByteSet {
+                pattern_methods!(ByteSetSearcher<'a>, ElemEqPattern, ByteSetSearcher, $slice);
+            }
+        }
+    }
+}
+
+impl_byte_set!(shared, &'a [u8], *const u8);
+impl_byte_set!(mutable, &'a mut [u8], *mut u8);
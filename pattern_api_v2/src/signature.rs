@@ -0,0 +1,542 @@
+//! Byte signatures with wildcards, for scanning binaries.
+//!
+//! A `Signature` is parsed from whitespace separated tokens:
+//!
+//! - two hex digits match a byte, as in `4D`,
+//! - `??` (or a single `?`) matches any byte,
+//! - a `?` in place of one hex digit matches any value of that nibble,
+//!   as in `4?` or `?D`,
+//! - `[n]` skips exactly `n` bytes, and `[n-m]` between `n` and `m` bytes.
+//!
+//! So `"4D 5A [2-4] 50 45 ?? 0?"` matches `MZ`, two to four arbitrary
+//! bytes, `PE`, any byte and a byte below `0x10`. A signature must start
+//! and end with a byte, so that matches are never empty.
+//!
+//! The searcher looks for the longest run of exact bytes with the Two-Way
+//! algorithm of `fast_sequence_search`, and only compares the rest of the
+//! signature around its occurrences. Gaps are lazy: each one takes as few
+//! bytes as the rest of the signature allows, from the first gap to the
+//! last. Searching backward fills the gaps from the last to the first
+//! instead, so the two directions can disagree if gaps overlap a match.
+
+use ::{Pattern, PatternHaystack, Searcher, ReverseSearcher, SeekableSearcher};
+use fast_sequence_search::{OrdSlicePattern, OrdSeqSearcher};
+
+use core::cmp;
+use core::fmt;
+use core::ops::Range;
+use core::str::FromStr;
+use alloc::vec::Vec;
+
+/// Why a string is not a valid signature.
+///
+/// The offsets are byte offsets of the offending token in the string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The string contains no tokens.
+    Empty,
+    /// The token is neither a byte nor a gap.
+    InvalidToken(usize),
+    /// The gap has no valid bounds, or its minimum exceeds its maximum.
+    InvalidGap(usize),
+    /// The signature starts or ends with a gap.
+    GapAtEdge(usize),
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SignatureError::Empty =>
+                f.write_str("the signature is empty"),
+            SignatureError::InvalidToken(at) =>
+                write!(f, "invalid byte at offset {} of the signature", at),
+            SignatureError::InvalidGap(at) =>
+                write!(f, "invalid gap at offset {} of the signature", at),
+            SignatureError::GapAtEdge(at) =>
+                write!(f, "the signature starts or ends with the gap at offset {}", at),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for SignatureError {}
+
+// A run of bytes without gaps, preceded by a gap of
+// `gap.0` to `gap.1` bytes (none for the first one)
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Segment {
+    gap: (usize, usize),
+    bytes: Range<usize>,
+}
+
+/// A byte pattern with wildcards and gaps, parsed from a string like
+/// `"4D 5A ?? ?? 50 45"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    values: Vec<u8>,
+    // the bits of each byte that have to match its value
+    masks: Vec<u8>,
+    segments: Vec<Segment>,
+    // the longest run of exact bytes, as a range of `values`
+    anchor: Option<Range<usize>>,
+    // the shortest and longest distance from the start of a
+    // match to the anchor, and from the anchor to the end
+    prefix_len: (usize, usize),
+    suffix_len: (usize, usize),
+}
+
+fn hex_digit(b: u8) -> Option<(u8, u8)> {
+    match b {
+        b'?' => Some((0, 0)),
+        b'0'..=b'9' => Some((b - b'0', 0xF)),
+        b'a'..=b'f' => Some((b - b'a' + 10, 0xF)),
+        b'A'..=b'F' => Some((b - b'A' + 10, 0xF)),
+        _ => None,
+    }
+}
+
+fn parse_byte(token: &str) -> Option<(u8, u8)> {
+    match *token.as_bytes() {
+        [b'?'] => Some((0, 0)),
+        [hi, lo] => {
+            let (hi, hi_mask) = hex_digit(hi)?;
+            let (lo, lo_mask) = hex_digit(lo)?;
+            Some((hi << 4 | lo, hi_mask << 4 | lo_mask))
+        }
+        _ => None,
+    }
+}
+
+fn parse_gap(token: &str) -> Option<(usize, usize)> {
+    let inner = token.strip_prefix('[')?.strip_suffix(']')?;
+    let (min, max) = match inner.find('-') {
+        Some(i) => (&inner[..i], &inner[i + 1..]),
+        None => (inner, inner),
+    };
+    // `usize::from_str` also accepts a leading `+`
+    let number = |s: &str| {
+        if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            s.parse::<usize>().ok()
+        } else {
+            None
+        }
+    };
+    match (number(min), number(max)) {
+        (Some(min), Some(max)) if min <= max => Some((min, max)),
+        _ => None,
+    }
+}
+
+impl Signature {
+    /// Parses a signature, see the module documentation for the syntax.
+    pub fn parse(s: &str) -> Result<Signature, SignatureError> {
+        let mut values = Vec::new();
+        let mut masks = Vec::new();
+        let mut segments: Vec<Segment> = Vec::new();
+        let mut gap = None;
+        for token in s.split(|c: char| c.is_ascii_whitespace()).filter(|t| !t.is_empty()) {
+            let at = token.as_ptr() as usize - s.as_ptr() as usize;
+            if token.starts_with('[') {
+                let (min, max) = parse_gap(token).ok_or(SignatureError::InvalidGap(at))?;
+                if segments.is_empty() {
+                    return Err(SignatureError::GapAtEdge(at));
+                }
+                // adjacent gaps add up
+                let (prev_min, prev_max, first) = gap.unwrap_or((0, 0, at));
+                gap = Some((prev_min + min, prev_max + max, first));
+                continue;
+            }
+            let (value, mask) = parse_byte(token).ok_or(SignatureError::InvalidToken(at))?;
+            let i = values.len();
+            values.push(value & mask);
+            masks.push(mask);
+            match gap.take() {
+                Some((min, max, _)) => segments.push(Segment { gap: (min, max), bytes: i..i + 1 }),
+                None => match segments.last_mut() {
+                    Some(segment) => segment.bytes.end += 1,
+                    None => segments.push(Segment { gap: (0, 0), bytes: i..i + 1 }),
+                },
+            }
+        }
+        if let Some((_, _, at)) = gap {
+            return Err(SignatureError::GapAtEdge(at));
+        }
+        if segments.is_empty() {
+            return Err(SignatureError::Empty);
+        }
+
+        // the longest run of exact bytes, leftmost among equals
+        let mut anchor: Option<(usize, Range<usize>)> = None;
+        for (k, segment) in segments.iter().enumerate() {
+            let mut i = segment.bytes.start;
+            while i < segment.bytes.end {
+                let start = i;
+                while i < segment.bytes.end && masks[i] == 0xFF {
+                    i += 1;
+                }
+                if i - start > anchor.as_ref().map_or(0, |a| a.1.len()) {
+                    anchor = Some((k, start..i));
+                }
+                i += 1;
+            }
+        }
+
+        // without an anchor, the prefix is the whole signature
+        let (k, run) = anchor.clone().unwrap_or((segments.len(), values.len()..values.len()));
+        let mut prefix_len = (run.start, run.start);
+        let mut suffix_len = (values.len() - run.end, values.len() - run.end);
+        for (j, segment) in segments.iter().enumerate().skip(1) {
+            let len = if j <= k { &mut prefix_len } else { &mut suffix_len };
+            len.0 += segment.gap.0;
+            len.1 += segment.gap.1;
+        }
+
+        Ok(Signature {
+            values: values,
+            masks: masks,
+            segments: segments,
+            anchor: anchor.map(|a| a.1),
+            prefix_len: prefix_len,
+            suffix_len: suffix_len,
+        })
+    }
+
+    /// The length of the shortest possible match.
+    #[inline]
+    pub fn min_len(&self) -> usize {
+        self.prefix_len.0 + self.anchor_len() + self.suffix_len.0
+    }
+
+    /// The length of the longest possible match.
+    #[inline]
+    pub fn max_len(&self) -> usize {
+        self.prefix_len.1 + self.anchor_len() + self.suffix_len.1
+    }
+
+    #[inline]
+    fn anchor_len(&self) -> usize {
+        self.anchor.as_ref().map_or(0, |a| a.len())
+    }
+
+    fn segment_matches(&self, segment: &Segment, bytes: &[u8]) -> bool {
+        let values = &self.values[segment.bytes.clone()];
+        let masks = &self.masks[segment.bytes.clone()];
+        bytes.iter().zip(values).zip(masks).all(|((&b, &v), &m)| b & m == v)
+    }
+
+    // The end of the lazy match of the segments from `k` on
+    // that starts at `pos` and does not extend past `back`
+    fn match_from(&self, haystack: &[u8], k: usize, pos: usize, back: usize) -> Option<usize> {
+        let segment = &self.segments[k];
+        let end = pos + segment.bytes.len();
+        if end > back || !self.segment_matches(segment, &haystack[pos..end]) {
+            return None;
+        }
+        let next = match self.segments.get(k + 1) {
+            Some(next) => next,
+            None => return Some(end),
+        };
+        (next.gap.0..next.gap.1 + 1)
+            .take_while(|&n| end + n < back)
+            .filter_map(|n| self.match_from(haystack, k + 1, end + n, back))
+            .next()
+    }
+
+    // The start of the lazy match of the segments up to `k`
+    // that ends at `pos` and does not extend before `front`
+    fn match_to(&self, haystack: &[u8], k: usize, pos: usize, front: usize) -> Option<usize> {
+        let segment = &self.segments[k];
+        let len = segment.bytes.len();
+        if pos < front + len || !self.segment_matches(segment, &haystack[pos - len..pos]) {
+            return None;
+        }
+        let start = pos - len;
+        if k == 0 {
+            return Some(start);
+        }
+        (segment.gap.0..segment.gap.1 + 1)
+            .take_while(|&n| start > front + n)
+            .filter_map(|n| self.match_to(haystack, k - 1, start - n, front))
+            .next()
+    }
+
+    /// Returns the end of the match at `offset`, if any.
+    #[inline]
+    pub fn match_at(&self, haystack: &[u8], offset: usize) -> Option<usize> {
+        if offset > haystack.len() {
+            return None;
+        }
+        self.match_from(haystack, 0, offset, haystack.len())
+    }
+
+    /// Returns the start of the match ending at `offset`, if any.
+    #[inline]
+    pub fn match_ending_at(&self, haystack: &[u8], offset: usize) -> Option<usize> {
+        if offset > haystack.len() {
+            return None;
+        }
+        self.match_to(haystack, self.segments.len() - 1, offset, 0)
+    }
+}
+
+impl FromStr for Signature {
+    type Err = SignatureError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Signature, SignatureError> {
+        Signature::parse(s)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Searcher
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SearchStep {
+    Match(usize, usize),
+    Reject(usize, usize),
+    Done,
+}
+
+/// Associated type for `<&'b Signature as Pattern<&'a [u8]>>::Searcher`.
+#[derive(Clone)]
+pub struct SignatureSearcher<'a, 'b> {
+    haystack: &'a [u8],
+    signature: &'b Signature,
+    // Two-Way searchers for the anchor, only
+    // moved forward and backward respectively
+    forward: Option<OrdSeqSearcher<'b, &'a [u8]>>,
+    backward: Option<OrdSeqSearcher<'b, &'a [u8]>>,
+    front: usize,
+    back: usize,
+    pending_front: Option<(usize, usize)>,
+    pending_back: Option<(usize, usize)>,
+}
+
+impl<'a, 'b> SignatureSearcher<'a, 'b> {
+    fn new(haystack: &'a [u8], signature: &'b Signature) -> SignatureSearcher<'a, 'b> {
+        let anchor = signature.anchor.as_ref().map(|a| &signature.values[a.clone()]);
+        let searcher = anchor.map(|a| OrdSlicePattern(a).into_searcher(haystack));
+        SignatureSearcher {
+            haystack: haystack,
+            signature: signature,
+            forward: searcher.clone(),
+            backward: searcher,
+            front: 0,
+            back: haystack.len(),
+            pending_front: None,
+            pending_back: None,
+        }
+    }
+
+    // The leftmost match in `front..back`
+    fn find_front(&mut self) -> Option<(usize, usize)> {
+        let sig = self.signature;
+        let (pre, post) = (sig.prefix_len, sig.suffix_len);
+        let mut lo = self.front;
+        loop {
+            // the candidate starts are `lo..=hi`
+            let hi = match self.forward {
+                None => self.back.checked_sub(sig.min_len())?,
+                Some(ref mut searcher) => {
+                    // the anchor of a match starting at `s` is at
+                    // `s + pre.0` to `s + pre.1`, so the next one
+                    // bounds the starts worth checking
+                    if !searcher.seek_front(lo + pre.0) {
+                        return None;
+                    }
+                    let (a, _) = searcher.next_match()?;
+                    let at = a as usize - self.haystack.as_ptr() as usize;
+                    if at + sig.anchor_len() + post.0 > self.back {
+                        return None;
+                    }
+                    lo = cmp::max(lo, at.saturating_sub(pre.1));
+                    at - pre.0
+                }
+            };
+            for s in lo..hi + 1 {
+                if let Some(e) = sig.match_from(self.haystack, 0, s, self.back) {
+                    return Some((s, e));
+                }
+            }
+            match self.forward {
+                Some(_) => lo = hi + 1,
+                None => return None,
+            }
+        }
+    }
+
+    // The rightmost match in `front..back`
+    fn find_back(&mut self) -> Option<(usize, usize)> {
+        let sig = self.signature;
+        let (pre, post) = (sig.prefix_len, sig.suffix_len);
+        let last = sig.segments.len() - 1;
+        let mut hi = self.back;
+        loop {
+            // the candidate ends are `lo..=hi`, from the back
+            let lo = match self.backward {
+                None => self.front + sig.min_len(),
+                Some(ref mut searcher) => {
+                    if hi < post.0 || !searcher.seek_back(hi - post.0) {
+                        return None;
+                    }
+                    let (_, b) = searcher.next_match_back()?;
+                    let at = b as usize - self.haystack.as_ptr() as usize;
+                    if at < self.front + sig.anchor_len() + pre.0 {
+                        return None;
+                    }
+                    hi = cmp::min(hi, at + post.1);
+                    at + post.0
+                }
+            };
+            for e in (lo..hi + 1).rev() {
+                if let Some(s) = sig.match_to(self.haystack, last, e, self.front) {
+                    return Some((s, e));
+                }
+            }
+            match self.backward {
+                Some(_) if lo > 0 => hi = lo - 1,
+                _ => return None,
+            }
+        }
+    }
+
+    fn next(&mut self) -> SearchStep {
+        if self.front == self.back {
+            return SearchStep::Done;
+        }
+        let found = match self.pending_front.take() {
+            Some((a, b)) if b <= self.back => Some((a, b)),
+            _ => self.find_front(),
+        };
+        match found {
+            Some((a, b)) if a == self.front => {
+                self.front = b;
+                SearchStep::Match(a, b)
+            }
+            Some((a, b)) => {
+                self.pending_front = Some((a, b));
+                let reject = SearchStep::Reject(self.front, a);
+                self.front = a;
+                reject
+            }
+            None => {
+                let reject = SearchStep::Reject(self.front, self.back);
+                self.front = self.back;
+                reject
+            }
+        }
+    }
+
+    fn next_back(&mut self) -> SearchStep {
+        if self.front == self.back {
+            return SearchStep::Done;
+        }
+        let found = match self.pending_back.take() {
+            Some((a, b)) if a >= self.front => Some((a, b)),
+            _ => self.find_back(),
+        };
+        match found {
+            Some((a, b)) if b == self.back => {
+                self.back = a;
+                SearchStep::Match(a, b)
+            }
+            Some((a, b)) => {
+                self.pending_back = Some((a, b));
+                let reject = SearchStep::Reject(b, self.back);
+                self.back = b;
+                reject
+            }
+            None => {
+                let reject = SearchStep::Reject(self.front, self.back);
+                self.back = self.front;
+                reject
+            }
+        }
+    }
+
+    #[inline]
+    fn cursors(&self, a: usize, b: usize) -> (*const u8, *const u8) {
+        let p = self.haystack.as_ptr();
+        unsafe { (p.add(a), p.add(b)) }
+    }
+}
+
+unsafe impl<'a, 'b> Searcher<&'a [u8]> for SignatureSearcher<'a, 'b> {
+    #[inline]
+    fn haystack(&self) -> (*const u8, *const u8) {
+        self.haystack.into_haystack()
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(*const u8, *const u8)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(a, b) => return Some(self.cursors(a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    #[inline]
+    fn next_reject(&mut self) -> Option<(*const u8, *const u8)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some(self.cursors(a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+unsafe impl<'a, 'b> ReverseSearcher<&'a [u8]> for SignatureSearcher<'a, 'b> {
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(*const u8, *const u8)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(a, b) => return Some(self.cursors(a, b)),
+                SearchStep::Reject(..) => {}
+                SearchStep::Done => return None,
+            }
+        }
+    }
+
+    #[inline]
+    fn next_reject_back(&mut self) -> Option<(*const u8, *const u8)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(..) => {}
+                SearchStep::Reject(a, b) => return Some(self.cursors(a, b)),
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+/// Searches for the leftmost non-overlapping matches of the signature
+impl<'a, 'b> Pattern<&'a [u8]> for &'b Signature {
+    type Searcher = SignatureSearcher<'a, 'b>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &'a [u8]) -> SignatureSearcher<'a, 'b> {
+        SignatureSearcher::new(haystack, self)
+    }
+
+    #[inline]
+    fn is_prefix_of(self, haystack: &'a [u8]) -> bool {
+        self.match_at(haystack, 0).is_some()
+    }
+
+    #[inline]
+    fn is_suffix_of(self, haystack: &'a [u8]) -> bool {
+        self.match_ending_at(haystack, haystack.len()).is_some()
+    }
+
+    #[inline]
+    fn matches_at(self, haystack: &'a [u8], offset: usize) -> Option<usize> {
+        self.match_at(haystack, offset)
+    }
+}
@@ -1,12 +1,14 @@
+use core::ops::{Range, RangeInclusive};
+
 fn ptr_range_len<T>(a: *const T, b: *const T) -> usize {
-    (b as usize - a as usize) / ::std::mem::size_of::<T>()
+    (b as usize - a as usize) / ::core::mem::size_of::<T>()
 }
 
 #[derive(Copy, Clone)]
 pub struct Elem<T>(pub T);
 
-trait ElemEq<T> {
-    fn matches(&mut self, &T) -> bool;
+pub trait ElemEq<T> {
+    fn matches(&mut self, elem: &T) -> bool;
 }
 
 impl<T: Eq> ElemEq<T> for Elem<T> {
@@ -19,7 +21,24 @@ impl<F, T> ElemEq<T> for F where F: FnMut(&T) -> bool {
     fn matches(&mut self, c: &T) -> bool { (*self)(c) }
 }
 
-struct ElemEqPattern<P>(P);
+impl<T: Ord> ElemEq<T> for Range<T> {
+    #[inline]
+    fn matches(&mut self, c: &T) -> bool { self.contains(c) }
+}
+
+impl<T: Ord> ElemEq<T> for RangeInclusive<T> {
+    #[inline]
+    fn matches(&mut self, c: &T) -> bool { self.contains(c) }
+}
+
+impl<'a, T: Ord> ElemEq<T> for &'a [RangeInclusive<T>] {
+    #[inline]
+    fn matches(&mut self, c: &T) -> bool {
+        self.iter().any(|r| r.contains(c))
+    }
+}
+
+pub struct ElemEqPattern<P>(pub P);
 
 macro_rules! impl_both_mutability {
     ($module:ident, $slice:ty,
@@ -31,15 +50,21 @@ macro_rules! impl_both_mutability {
         pub mod $module {
             use core_traits::*;
             use super::ptr_range_len;
+            use core::ops::{Range, RangeInclusive};
 
             #[derive(Copy, Clone)]
             struct Iter<'a, T: 'a> {
                 haystack: ($cursor, $cursor),
                 start: $cursor,
                 end: $cursor,
-                _marker: ::std::marker::PhantomData<$slice>
+                _marker: ::core::marker::PhantomData<$slice>
             }
 
+            // Only reads the elements between `start` and `end`,
+            // which have not been returned yet
+            unsafe impl<'a, T> Send for Iter<'a, T> where $slice: Send {}
+            unsafe impl<'a, T> Sync for Iter<'a, T> where $slice: Sync {}
+
             impl<'a, T> Iter<'a, T> {
                 #[inline]
                 fn new(haystack: $slice) -> Self {
@@ -48,7 +73,7 @@ macro_rules! impl_both_mutability {
                         haystack: (start, end),
                         start: start,
                         end: end,
-                        _marker: ::std::marker::PhantomData,
+                        _marker: ::core::marker::PhantomData,
                     }
                 }
 
@@ -103,6 +128,18 @@ macro_rules! impl_both_mutability {
                     hs.1
                 }
                 fn match_type_len(mt: &Self::MatchType) -> usize { mt.len() }
+
+                fn span_to_cursors(hs: Self::Haystack,
+                                   span: Span) -> Option<(Self::Cursor, Self::Cursor)> {
+                    if span.start <= span.end && span.end <= ptr_range_len(hs.0, hs.1) {
+                        unsafe {
+                            Some((hs.0.offset(span.start as isize),
+                                  hs.0.offset(span.end as isize)))
+                        }
+                    } else {
+                        None
+                    }
+                }
             }
 
             unsafe impl<'a, T> InverseMatchesAreValid for $slice {}
@@ -115,7 +152,7 @@ macro_rules! impl_both_mutability {
             use super::{ElemEq, ElemEqPattern};
 
             #[derive(Clone)]
-            struct ElemEqSearcher<'a, T: 'a, C> {
+            pub struct ElemEqSearcher<'a, T: 'a, C> {
                 elem_eq: C,
                 iter: Iter<'a, T>,
             }
@@ -138,6 +175,13 @@ macro_rules! impl_both_mutability {
                             .unwrap_or(false)
                 }
 
+                fn matches_at(mut self, haystack: $slice, offset: usize) -> Option<usize> {
+                    match haystack.get(offset) {
+                        Some(e) if self.0.matches(e) => Some(offset + 1),
+                        _ => None,
+                    }
+                }
+
                 fn is_suffix_of(mut self, haystack: $slice) -> bool
                     where Self::Searcher: ReverseSearcher<$slice>
                 {
@@ -146,6 +190,28 @@ macro_rules! impl_both_mutability {
                             .map(|c| self.0.matches(c))
                             .unwrap_or(false)
                 }
+
+                fn strip_prefix_of(self, haystack: $slice) -> Option<$slice> {
+                    let mut searcher = self.into_searcher(haystack);
+                    match searcher.iter.next() {
+                        Some(b) if searcher.elem_eq.matches(b) => unsafe {
+                            Some(($cursors_to_haystack)(searcher.iter.start,
+                                                        searcher.iter.end))
+                        },
+                        _ => None,
+                    }
+                }
+
+                fn strip_suffix_of(self, haystack: $slice) -> Option<$slice> {
+                    let mut searcher = self.into_searcher(haystack);
+                    match searcher.iter.next_back() {
+                        Some(b) if searcher.elem_eq.matches(b) => unsafe {
+                            Some(($cursors_to_haystack)(searcher.iter.start,
+                                                        searcher.iter.end))
+                        },
+                        _ => None,
+                    }
+                }
             }
 
             unsafe impl<'a, T, C: ElemEq<T>> Searcher<$slice> for ElemEqSearcher<'a, T, C> {
@@ -154,6 +220,11 @@ macro_rules! impl_both_mutability {
                     self.iter.haystack
                 }
 
+                #[inline]
+                fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<$slice>> {
+                    Some(self)
+                }
+
                 #[inline]
                 fn next_match(&mut self) -> Option<($cursor, $cursor)> {
                     while let Some(b) = self.iter.next() {
@@ -212,6 +283,28 @@ macro_rules! impl_both_mutability {
 
             impl<'a, T, C: ElemEq<T>> DoubleEndedSearcher<$slice> for ElemEqSearcher<'a, T, C> {}
 
+            impl<'a, T, C: ElemEq<T>> SeekableSearcher<$slice> for ElemEqSearcher<'a, T, C> {
+                fn seek_front(&mut self, offset: usize) -> bool {
+                    let hs = self.iter.haystack;
+                    if offset > <$slice>::offset_from_front(hs, self.iter.end) {
+                        return false;
+                    }
+                    self.iter.start = unsafe { hs.0.offset(offset as isize) };
+                    true
+                }
+
+                fn seek_back(&mut self, offset: usize) -> bool {
+                    let hs = self.iter.haystack;
+                    if offset < <$slice>::offset_from_front(hs, self.iter.start)
+                        || offset > <$slice>::haystack_len(hs)
+                    {
+                        return false;
+                    }
+                    self.iter.end = unsafe { hs.0.offset(offset as isize) };
+                    true
+                }
+            }
+
             /////////////////////////////////////////////////////////////////////////////
             // Impl for Elem
             /////////////////////////////////////////////////////////////////////////////
@@ -223,13 +316,17 @@ macro_rules! impl_both_mutability {
             pub struct ElemSearcher<'a, T: 'a>(ElemEqSearcher<'a, T, Elem<T>>);
 
             unsafe impl<'a, T: Eq> Searcher<$slice> for ElemSearcher<'a, T> {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a, T: Eq> ReverseSearcher<$slice> for ElemSearcher<'a, T> {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a, T: Eq> SeekableSearcher<$slice> for ElemSearcher<'a, T> {
+                searcher_methods!(seek, s, s.0);
+            }
+
             impl<'a, T: Eq> DoubleEndedSearcher<$slice> for ElemSearcher<'a, T> {}
 
             /// Searches for chars that are equal to a given char
@@ -249,7 +346,7 @@ macro_rules! impl_both_mutability {
             unsafe impl<'a, T, F> Searcher<$slice> for ElemPredicateSearcher<'a, T, F>
                 where F: FnMut(&T) -> bool
             {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a, T, F> ReverseSearcher<$slice> for ElemPredicateSearcher<'a, T, F>
@@ -258,6 +355,12 @@ macro_rules! impl_both_mutability {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a, T, F> SeekableSearcher<$slice> for ElemPredicateSearcher<'a, T, F>
+                where F: FnMut(&T) -> bool
+            {
+                searcher_methods!(seek, s, s.0);
+            }
+
             impl<'a, T, F> DoubleEndedSearcher<$slice> for ElemPredicateSearcher<'a, T, F>
                 where F: FnMut(&T) -> bool {}
 
@@ -266,18 +369,59 @@ macro_rules! impl_both_mutability {
                 pattern_methods!(ElemPredicateSearcher<'a, T, F>, ElemEqPattern, ElemPredicateSearcher, $slice);
             }
 
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for ranges
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<Range<T> as Pattern<&'a [T]>>::Searcher`,
+            /// and for the other ranges.
+            #[derive(Clone)]
+            pub struct ElemRangeSearcher<'a, T: 'a, R>(ElemEqSearcher<'a, T, R>);
+
+            unsafe impl<'a, T, R: ElemEq<T>> Searcher<$slice> for ElemRangeSearcher<'a, T, R> {
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
+            }
+
+            unsafe impl<'a, T, R: ElemEq<T>> ReverseSearcher<$slice> for ElemRangeSearcher<'a, T, R> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a, T, R: ElemEq<T>> SeekableSearcher<$slice> for ElemRangeSearcher<'a, T, R> {
+                searcher_methods!(seek, s, s.0);
+            }
+
+            impl<'a, T, R: ElemEq<T>> DoubleEndedSearcher<$slice> for ElemRangeSearcher<'a, T, R> {}
+
+            /// Searches for elements inside of the range
+            impl<'a, T: Ord> Pattern<$slice> for Range<T> {
+                pattern_methods!(ElemRangeSearcher<'a, T, Range<T>>, ElemEqPattern,
+                                 ElemRangeSearcher, $slice);
+            }
+
+            /// Searches for elements inside of the range
+            impl<'a, T: Ord> Pattern<$slice> for RangeInclusive<T> {
+                pattern_methods!(ElemRangeSearcher<'a, T, RangeInclusive<T>>, ElemEqPattern,
+                                 ElemRangeSearcher, $slice);
+            }
+
+            /// Searches for elements inside of any of the ranges in the array
+            impl<'a, 'b, T: Ord> Pattern<$slice> for &'b [RangeInclusive<T>] {
+                pattern_methods!(ElemRangeSearcher<'a, T, &'b [RangeInclusive<T>]>, ElemEqPattern,
+                                 ElemRangeSearcher, $slice);
+            }
+
             ////////////////////////////////////////////////////////////////////
             // Impl for &str
             ////////////////////////////////////////////////////////////////////
 
             use fast_sequence_search::{OrdSlice, OrdSlicePattern, OrdSeqSearcher};
-            use fast_sequence_search::{NoOptimization};
+            use fast_sequence_search::SliceElement;
 
-            pub struct SliceSearcher<'a, 'b, T: 'a + 'b + Ord>(OrdSeqSearcher<'b, $slice>);
+            pub struct SliceSearcher<'a, 'b, T: 'a + 'b + SliceElement>(OrdSeqSearcher<'b, $slice>);
 
-            impl<'a, T: Ord + 'a> OrdSlice for $slice {
+            impl<'a, T: SliceElement + 'a> OrdSlice for $slice {
                 type NeedleElement = T;
-                type FastSkipOptimization = NoOptimization;
+                type FastSkipOptimization = T::FastSkipOptimization;
 
                 fn next_valid_pos(hs: &Self::Haystack, pos: usize) -> Option<usize> {
                     let s = Self::haystack_as_slice(hs);
@@ -304,34 +448,29 @@ macro_rules! impl_both_mutability {
                 }
             }
 
-            // TODO: Specialize
-            /*
-            use fast_sequence_search::{ByteOptimization};
-            type ByteSlice<'a, T> = $slice;
-            impl<'a> OrdSlice for ByteSlice<'a, u8> {
-                type FastSkipOptimization = ByteOptimization;
-            }
-            */
-
             /// Non-allocating substring search.
             ///
             /// Will handle the pattern `""` as returning empty matches at each character
             /// boundary.
-            impl<'a, 'b, T: Ord> Pattern<$slice> for &'b [T] {
+            impl<'a, 'b, T: SliceElement> Pattern<$slice> for &'b [T] {
                 pattern_methods!(SliceSearcher<'a, 'b, T>,
                                 OrdSlicePattern,
                                 SliceSearcher,
                                 $slice);
             }
 
-            unsafe impl<'a, 'b, T: Ord> Searcher<$slice> for SliceSearcher<'a, 'b, T> {
-                searcher_methods!(forward, s, s.0, $cursor);
+            unsafe impl<'a, 'b, T: SliceElement> Searcher<$slice> for SliceSearcher<'a, 'b, T> {
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
-            unsafe impl<'a, 'b, T: Ord> ReverseSearcher<$slice> for SliceSearcher<'a, 'b, T> {
+            unsafe impl<'a, 'b, T: SliceElement> ReverseSearcher<$slice> for SliceSearcher<'a, 'b, T> {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a, 'b, T: SliceElement> SeekableSearcher<$slice> for SliceSearcher<'a, 'b, T> {
+                searcher_methods!(seek, s, s.0);
+            }
+
         }
     }
 }
@@ -339,7 +478,7 @@ macro_rules! impl_both_mutability {
 impl_both_mutability!(shared, &'a [T], *const T, &'a T, |ptr: *const T| {
     &*ptr
 }, |start, end| {
-    ::std::slice::from_raw_parts(start, ptr_range_len(start, end))
+    ::core::slice::from_raw_parts(start, ptr_range_len(start, end))
 }, |haystack: &'a [T]| {
     let begin = haystack.as_ptr();
     let end = unsafe {
@@ -351,7 +490,7 @@ impl_both_mutability!(shared, &'a [T], *const T, &'a T, |ptr: *const T| {
 impl_both_mutability!(mutable, &'a mut [T], *mut T, &'a mut T, |ptr: *mut T| {
     &mut *ptr
 }, |start, end| {
-    ::std::slice::from_raw_parts_mut(start, ptr_range_len(start, end))
+    ::core::slice::from_raw_parts_mut(start, ptr_range_len(start, end))
 }, |haystack: &'a mut [T]| {
     let begin = haystack.as_mut_ptr();
     let end = unsafe {
@@ -359,3 +498,5 @@ impl_both_mutability!(mutable, &'a mut [T], *mut T, &'a mut T, |ptr: *mut T| {
     };
     (begin, end)
 });
+
+unsafe impl<'a, T> ::MutableHaystack for &'a mut [T] {}
@@ -1,10 +1,18 @@
 use ::{Pattern, PatternHaystack, Searcher, ReverseSearcher, DoubleEndedSearcher};
+use ::{TryPattern, PatternError};
 use iterators::{Split, RSplit};
 use iterators::{SplitTerminator, RSplitTerminator};
 use iterators::{SplitN, RSplitN};
 use iterators::{Matches, RMatches};
 use iterators::{MatchIndices, RMatchIndices};
+use iterators::{MatchSpans, RMatchSpans};
+use iterators::{SplitIndices, RSplitIndices};
+use iterators::{SplitSpans, RSplitSpans};
+use iterators::{Rejects, RRejects, RejectIndices, RRejectIndices};
+use iterators::{Tokens, RTokens};
 use ::InverseMatchesAreValid;
+use ::MutableHaystack;
+use lines::{LineHaystack, Lines, LineIndices};
 
 pub trait IteratorConstructors: PatternHaystack {
     #[inline]
@@ -27,6 +35,36 @@ pub trait IteratorConstructors: PatternHaystack {
         RSplit::new(self, pat)
     }
 
+    #[inline]
+    fn split_indices<P: Pattern<Self>>(self, pat: P) -> SplitIndices<Self, P>
+        where Self: InverseMatchesAreValid
+    {
+        SplitIndices::new(self, pat)
+    }
+
+    #[inline]
+    fn rsplit_indices<P: Pattern<Self>>(self, pat: P) -> RSplitIndices<Self, P>
+        where P::Searcher: ReverseSearcher<Self>,
+              Self: InverseMatchesAreValid
+    {
+        RSplitIndices::new(self, pat)
+    }
+
+    #[inline]
+    fn split_spans<P: Pattern<Self>>(self, pat: P) -> SplitSpans<Self, P>
+        where Self: InverseMatchesAreValid
+    {
+        SplitSpans::new(self, pat)
+    }
+
+    #[inline]
+    fn rsplit_spans<P: Pattern<Self>>(self, pat: P) -> RSplitSpans<Self, P>
+        where P::Searcher: ReverseSearcher<Self>,
+              Self: InverseMatchesAreValid
+    {
+        RSplitSpans::new(self, pat)
+    }
+
     #[inline]
     fn splitn<P: Pattern<Self>>(self, count: usize, pat: P) -> SplitN<Self, P>
         where Self: InverseMatchesAreValid
@@ -81,6 +119,63 @@ pub trait IteratorConstructors: PatternHaystack {
         RMatchIndices::new(self, pat)
     }
 
+    #[inline]
+    fn match_spans<P: Pattern<Self>>(self, pat: P) -> MatchSpans<Self, P> {
+        MatchSpans::new(self, pat)
+    }
+
+    #[inline]
+    fn rmatch_spans<P: Pattern<Self>>(self, pat: P) -> RMatchSpans<Self, P>
+        where P::Searcher: ReverseSearcher<Self>
+    {
+        RMatchSpans::new(self, pat)
+    }
+
+    #[inline]
+    fn rejects<P: Pattern<Self>>(self, pat: P) -> Rejects<Self, P>
+        where Self: InverseMatchesAreValid
+    {
+        Rejects::new(self, pat)
+    }
+
+    #[inline]
+    fn rrejects<P: Pattern<Self>>(self, pat: P) -> RRejects<Self, P>
+        where P::Searcher: ReverseSearcher<Self>,
+              Self: InverseMatchesAreValid
+    {
+        RRejects::new(self, pat)
+    }
+
+    #[inline]
+    fn reject_indices<P: Pattern<Self>>(self, pat: P) -> RejectIndices<Self, P>
+        where Self: InverseMatchesAreValid
+    {
+        RejectIndices::new(self, pat)
+    }
+
+    #[inline]
+    fn rreject_indices<P: Pattern<Self>>(self, pat: P) -> RRejectIndices<Self, P>
+        where P::Searcher: ReverseSearcher<Self>,
+              Self: InverseMatchesAreValid
+    {
+        RRejectIndices::new(self, pat)
+    }
+
+    #[inline]
+    fn tokens<P: Pattern<Self>>(self, pat: P) -> Tokens<Self, P>
+        where Self: InverseMatchesAreValid
+    {
+        Tokens::new(self, pat)
+    }
+
+    #[inline]
+    fn rtokens<P: Pattern<Self>>(self, pat: P) -> RTokens<Self, P>
+        where P::Searcher: ReverseSearcher<Self>,
+              Self: InverseMatchesAreValid
+    {
+        RTokens::new(self, pat)
+    }
+
     #[inline]
     fn starts_with<P: Pattern<Self>>(self, pat: P) -> bool {
         pat.is_prefix_of(self)
@@ -93,6 +188,21 @@ pub trait IteratorConstructors: PatternHaystack {
         pat.is_suffix_of(self)
     }
 
+    #[inline]
+    fn strip_prefix<P: Pattern<Self>>(self, pat: P) -> Option<Self::MatchType>
+        where Self: InverseMatchesAreValid
+    {
+        pat.strip_prefix_of(self)
+    }
+
+    #[inline]
+    fn strip_suffix<P: Pattern<Self>>(self, pat: P) -> Option<Self::MatchType>
+        where P::Searcher: ReverseSearcher<Self>,
+              Self: InverseMatchesAreValid
+    {
+        pat.strip_suffix_of(self)
+    }
+
     #[inline]
     fn trim_matches<P: Pattern<Self>>(self, pat: P) -> Self::MatchType
         where P::Searcher: DoubleEndedSearcher<Self>
@@ -144,6 +254,94 @@ pub trait IteratorConstructors: PatternHaystack {
         }
     }
 
+    /// Like `split()`, for haystacks that yield unique borrows.
+    ///
+    /// The pieces never overlap, even if the iterator is used from both ends,
+    /// so all of them can be kept and modified at the same time.
+    #[inline]
+    fn split_mut<P: Pattern<Self>>(self, pat: P) -> Split<Self, P>
+        where Self: InverseMatchesAreValid + MutableHaystack
+    {
+        Split::new(self, pat)
+    }
+
+    /// Like `matches()`, for haystacks that yield unique borrows.
+    ///
+    /// The matches never overlap, even if the iterator is used from both ends,
+    /// so all of them can be kept and modified at the same time.
+    #[inline]
+    fn matches_mut<P: Pattern<Self>>(self, pat: P) -> Matches<Self, P>
+        where Self: MutableHaystack
+    {
+        Matches::new(self, pat)
+    }
+
+    /// Like `trim_matches()`, for haystacks that yield unique borrows.
+    #[inline]
+    fn trim_matches_mut<P: Pattern<Self>>(self, pat: P) -> Self::MatchType
+        where P::Searcher: DoubleEndedSearcher<Self>,
+              Self: InverseMatchesAreValid + MutableHaystack
+    {
+        self.trim_matches(pat)
+    }
+
+    /// Splits the haystack into the parts before, at and after the first match.
+    ///
+    /// The three parts are disjoint, so they can be modified independently,
+    /// for example to edit a field of a line in place.
+    #[inline]
+    fn split_at_match_mut<P: Pattern<Self>>(self, pat: P)
+        -> Option<(Self::MatchType, Self::MatchType, Self::MatchType)>
+        where Self: InverseMatchesAreValid + MutableHaystack
+    {
+        let mut searcher = pat.into_searcher(self);
+        let hs = searcher.haystack();
+        searcher.next_match().map(|(a, b)| unsafe {
+            // Searcher is known to return valid indices,
+            // and the ranges do not overlap
+            (Self::range_to_self(hs, Self::cursor_at_front(hs), a),
+             Self::range_to_self(hs, a, b),
+             Self::range_to_self(hs, b, Self::cursor_at_back(hs)))
+        })
+    }
+
+    /// An iterator over the lines of the haystack.
+    ///
+    /// Lines are ended by `\n` or `\r\n`, which are not part of the
+    /// returned lines. A final line terminator does not start an empty line.
+    #[inline]
+    fn lines(self) -> Lines<Self>
+        where Self: LineHaystack
+    {
+        Lines::new(self, false)
+    }
+
+    /// Like `lines()`, but additionally ends lines at a lone `\r`,
+    /// vertical tab, form feed, and the unicode line separators
+    /// U+0085, U+2028 and U+2029.
+    #[inline]
+    fn lines_any(self) -> Lines<Self>
+        where Self: LineHaystack
+    {
+        Lines::new(self, true)
+    }
+
+    /// Like `lines()`, but also yields the byte offset of each line.
+    #[inline]
+    fn line_indices(self) -> LineIndices<Self>
+        where Self: LineHaystack
+    {
+        LineIndices::new(self, false)
+    }
+
+    /// Like `lines_any()`, but also yields the byte offset of each line.
+    #[inline]
+    fn line_indices_any(self) -> LineIndices<Self>
+        where Self: LineHaystack
+    {
+        LineIndices::new(self, true)
+    }
+
     fn find<P: Pattern<Self>>(self, pat: P) -> Option<usize> {
         let mut searcher = pat.into_searcher(self);
         let h = searcher.haystack();
@@ -157,39 +355,179 @@ pub trait IteratorConstructors: PatternHaystack {
         let h = searcher.haystack();
         searcher.next_match_back().map(|(i, _)| Self::offset_from_front(h, i))
     }
+
+    /// Like `find()`, but returns an error instead of panicking
+    /// if the pattern can not be searched for.
+    fn try_find<P: Pattern<Self>>(self, pat: P) -> Result<Option<usize>, PatternError> {
+        let mut searcher = pat.try_into_searcher(self)?;
+        let h = searcher.haystack();
+        Ok(searcher.next_match().map(|(i, _)| Self::offset_from_front(h, i)))
+    }
+
+    /// Like `split()`, but returns an error instead of panicking
+    /// if the pattern can not be searched for.
+    #[inline]
+    fn try_split<P: Pattern<Self>>(self, pat: P) -> Result<Split<Self, P>, PatternError>
+        where Self: InverseMatchesAreValid
+    {
+        pat.validate()?;
+        Ok(Split::new(self, pat))
+    }
 }
 
 impl<T: PatternHaystack> IteratorConstructors for T {}
 
+use byte_str::shared::PartialUnicode as BytesPartialUnicode;
+use byte_str::mutable::PartialUnicode as MutBytesPartialUnicode;
+use byte_str::InvalidUtf8Ranges;
+
+pub trait ByteSliceExtension {
+    /// Searches the bytes as UTF-8 text, skipping invalid sequences.
+    ///
+    /// Use `rejecting_invalid()` on the result to get the
+    /// invalid sequences as rejects.
+    fn for_unicode(&self) -> BytesPartialUnicode<'_>;
+    fn for_unicode_mut(&mut self) -> MutBytesPartialUnicode<'_>;
+
+    /// The byte ranges that are not valid UTF-8.
+    fn invalid_utf8_ranges(&self) -> InvalidUtf8Ranges<'_>;
+}
+
+impl ByteSliceExtension for [u8] {
+    fn for_unicode(&self) -> BytesPartialUnicode<'_> {
+        BytesPartialUnicode::new(self)
+    }
+    fn for_unicode_mut(&mut self) -> MutBytesPartialUnicode<'_> {
+        MutBytesPartialUnicode::new(self)
+    }
+    fn invalid_utf8_ranges(&self) -> InvalidUtf8Ranges<'_> {
+        InvalidUtf8Ranges::new(self)
+    }
+}
+
+// The `OsStr` extensions need the `std` feature
+
+#[cfg(feature = "std")]
 use os_string::shared::PartialUnicode as OsStrPartialUnicode;
+#[cfg(all(feature = "std", unix))]
 use os_string::mutable::PartialUnicode as MutOsStrPartialUnicode;
+#[cfg(feature = "std")]
 use std::ffi::OsStr;
+#[cfg(feature = "std")]
 use std::ffi::OsString;
+#[cfg(feature = "std")]
 use std::char;
+#[cfg(feature = "std")]
+use os_str_bytes;
+#[cfg(feature = "std")]
+use os_string::{self, SurrogateMode};
+#[cfg(feature = "std")]
+use core::ops::Range;
 //use std::os::unix::ffi::OsStrExt;
 
+#[cfg(feature = "std")]
 pub trait OsStrExtension {
-    fn for_unicode(&self) -> OsStrPartialUnicode;
-    fn for_unicode_mut(&mut self) -> MutOsStrPartialUnicode;
+    fn for_unicode(&self) -> OsStrPartialUnicode<'_>;
+    #[cfg(unix)]
+    fn for_unicode_mut(&mut self) -> MutOsStrPartialUnicode<'_>;
+
+    /// The byte ranges that are not valid UTF-8, for example lone
+    /// surrogates on Windows.
+    fn invalid_utf8_ranges(&self) -> InvalidUtf8Ranges<'_>;
 
     fn starts_with_os<S: AsRef<OsStr>>(&self, s: S) -> bool;
     fn ends_with_os<S: AsRef<OsStr>>(&self, s: S) -> bool;
     fn contains_os<S: AsRef<OsStr>>(&self, s: S) -> bool;
 }
 
+#[cfg(feature = "std")]
 pub trait OsStringExtension {
     fn from_wide(s: &[u16]) -> Self;
-    fn push_str(&mut self, &str);
-    fn push_codepoint_unadjusted(&mut self, u32);
+    fn push_str(&mut self, s: &str);
+    fn push_codepoint_unadjusted(&mut self, cp: u32);
+
+    /// Replaces all matches of `pat` with `to`.
+    fn replace<P>(&mut self, pat: P, to: &OsStr)
+        where P: for<'a> Pattern<&'a OsStr>
+    {
+        self.replace_with_mode(pat, to, SurrogateMode::native())
+    }
+
+    /// Like `replace()`, but joins the pieces in the given `SurrogateMode`.
+    fn replace_with_mode<P>(&mut self, pat: P, to: &OsStr, mode: SurrogateMode)
+        where P: for<'a> Pattern<&'a OsStr>;
+
+    /// Removes everything but the matches of `pat`.
+    fn retain_matches<P>(&mut self, pat: P)
+        where P: for<'a> Pattern<&'a OsStr>
+    {
+        self.retain_matches_with_mode(pat, SurrogateMode::native())
+    }
+
+    /// Like `retain_matches()`, but joins the matches in the given `SurrogateMode`.
+    fn retain_matches_with_mode<P>(&mut self, pat: P, mode: SurrogateMode)
+        where P: for<'a> Pattern<&'a OsStr>;
+
+    /// Removes the first match of `pat` and everything after it.
+    ///
+    /// Returns whether there was a match. Nothing is joined, so unlike
+    /// the other edits, this does not depend on the `SurrogateMode`.
+    fn truncate_at_match<P>(&mut self, pat: P) -> bool
+        where P: for<'a> Pattern<&'a OsStr>;
+
+    /// Inserts `s` at the byte offset `idx`, for example one returned by `find()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds, or inside of a char.
+    fn insert_str(&mut self, idx: usize, s: &OsStr) {
+        self.insert_str_with_mode(idx, s, SurrogateMode::native())
+    }
+
+    /// Like `insert_str()`, but in the given `SurrogateMode`.
+    fn insert_str_with_mode(&mut self, idx: usize, s: &OsStr, mode: SurrogateMode) {
+        self.replace_range_with_mode(idx..idx, s, mode);
+    }
+
+    /// Replaces the bytes in `range` with `s`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or starts or ends inside of a char.
+    fn replace_range(&mut self, range: Range<usize>, s: &OsStr) {
+        self.replace_range_with_mode(range, s, SurrogateMode::native())
+    }
+
+    /// Like `replace_range()`, but in the given `SurrogateMode`. In
+    /// `SurrogateMode::Wtf8`, the range can also not start or end inside of
+    /// an encoded surrogate, and a surrogate pair split up by the edit
+    /// is joined.
+    fn replace_range_with_mode(&mut self, range: Range<usize>, s: &OsStr, mode: SurrogateMode);
+
+    /// Removes all matches of `pat`, and returns them.
+    fn drain_matches<P>(&mut self, pat: P) -> Vec<OsString>
+        where P: for<'a> Pattern<&'a OsStr>
+    {
+        self.drain_matches_with_mode(pat, SurrogateMode::native())
+    }
+
+    /// Like `drain_matches()`, but joins the rest in the given `SurrogateMode`.
+    fn drain_matches_with_mode<P>(&mut self, pat: P, mode: SurrogateMode) -> Vec<OsString>
+        where P: for<'a> Pattern<&'a OsStr>;
 }
 
+#[cfg(feature = "std")]
 impl OsStrExtension for OsStr {
-    fn for_unicode(&self) -> OsStrPartialUnicode {
+    fn for_unicode(&self) -> OsStrPartialUnicode<'_> {
         OsStrPartialUnicode { os_str: self }
     }
-    fn for_unicode_mut(&mut self) -> MutOsStrPartialUnicode {
+    #[cfg(unix)]
+    fn for_unicode_mut(&mut self) -> MutOsStrPartialUnicode<'_> {
         MutOsStrPartialUnicode { os_str: self }
     }
+    fn invalid_utf8_ranges(&self) -> InvalidUtf8Ranges<'_> {
+        InvalidUtf8Ranges::new(os_str_bytes::as_bytes(self))
+    }
     fn starts_with_os<S: AsRef<OsStr>>(&self, s: S) -> bool {
         self.starts_with(s.as_ref())
     }
@@ -201,6 +539,7 @@ impl OsStrExtension for OsStr {
     }
 }
 
+#[cfg(feature = "std")]
 impl OsStringExtension for OsString {
     fn from_wide(v: &[u16]) -> OsString {
         let mut string = OsString::with_capacity(v.len());
@@ -219,17 +558,135 @@ impl OsStringExtension for OsString {
     }
     fn push_str(&mut self, s: &str) {
         unsafe {
-            ::std::mem::transmute::<&mut OsString, &mut Vec<u8>>(self)
-                .extend(s.bytes());
+            os_str_bytes::with_vec(self, |v| v.extend(s.bytes()));
         }
     }
     fn push_codepoint_unadjusted(&mut self, cp: u32) {
+        // Encoded by hand instead of going through `char`, since `cp`
+        // might be a lone surrogate (which is valid WTF-8).
+        let mut buf = [0; 4];
+        let len = if cp < 0x80 {
+            buf[0] = cp as u8;
+            1
+        } else if cp < 0x800 {
+            buf[0] = (cp >> 6 & 0x1F) as u8 | 0xC0;
+            buf[1] = (cp & 0x3F) as u8 | 0x80;
+            2
+        } else if cp < 0x10000 {
+            buf[0] = (cp >> 12 & 0x0F) as u8 | 0xE0;
+            buf[1] = (cp >> 6 & 0x3F) as u8 | 0x80;
+            buf[2] = (cp & 0x3F) as u8 | 0x80;
+            3
+        } else {
+            buf[0] = (cp >> 18 & 0x07) as u8 | 0xF0;
+            buf[1] = (cp >> 12 & 0x3F) as u8 | 0x80;
+            buf[2] = (cp >> 6 & 0x3F) as u8 | 0x80;
+            buf[3] = (cp & 0x3F) as u8 | 0x80;
+            4
+        };
         unsafe {
-            let ch = char::from_u32_unchecked(cp as u32);
-            for byte in ch.encode_utf8() {
-                ::std::mem::transmute::<&mut OsString, &mut Vec<u8>>(self)
-                    .push(byte);
+            os_str_bytes::with_vec(self, |v| v.extend_from_slice(&buf[..len]));
+        }
+    }
+
+    fn replace_with_mode<P>(&mut self, pat: P, to: &OsStr, mode: SurrogateMode)
+        where P: for<'a> Pattern<&'a OsStr>
+    {
+        let to = os_str_bytes::as_bytes(to);
+        let ranges = match_ranges(self, pat);
+        let mut pieces = vec![];
+        let mut last = 0;
+        for r in ranges {
+            pieces.push(&os_str_bytes::as_bytes(self)[last..r.start]);
+            pieces.push(to);
+            last = r.end;
+        }
+        pieces.push(&os_str_bytes::as_bytes(self)[last..]);
+        let v = os_string::join_pieces(&pieces, edit_mode(mode));
+        set_bytes(self, v);
+    }
+
+    fn retain_matches_with_mode<P>(&mut self, pat: P, mode: SurrogateMode)
+        where P: for<'a> Pattern<&'a OsStr>
+    {
+        let pieces: Vec<_> = match_ranges(self, pat).into_iter()
+            .map(|r| &os_str_bytes::as_bytes(self)[r])
+            .collect();
+        let v = os_string::join_pieces(&pieces, edit_mode(mode));
+        set_bytes(self, v);
+    }
+
+    fn truncate_at_match<P>(&mut self, pat: P) -> bool
+        where P: for<'a> Pattern<&'a OsStr>
+    {
+        match self.find(pat) {
+            Some(i) => {
+                unsafe {
+                    os_str_bytes::with_vec(self, |v| v.truncate(i));
+                }
+                true
             }
+            None => false,
         }
     }
+
+    fn replace_range_with_mode(&mut self, range: Range<usize>, s: &OsStr, mode: SurrogateMode) {
+        let mode = edit_mode(mode);
+        let v = {
+            let bytes = os_str_bytes::as_bytes(self);
+            assert!(range.start <= range.end
+                    && os_string::is_edit_boundary(bytes, range.start, mode)
+                    && os_string::is_edit_boundary(bytes, range.end, mode),
+                    "range {:?} is not at char boundaries", range);
+            let pieces = [&bytes[..range.start], os_str_bytes::as_bytes(s), &bytes[range.end..]];
+            os_string::join_pieces(&pieces, mode)
+        };
+        set_bytes(self, v);
+    }
+
+    fn drain_matches_with_mode<P>(&mut self, pat: P, mode: SurrogateMode) -> Vec<OsString>
+        where P: for<'a> Pattern<&'a OsStr>
+    {
+        let ranges = match_ranges(self, pat);
+        let drained = ranges.iter()
+            .map(|r| unsafe {
+                os_str_bytes::from_bytes(&os_str_bytes::as_bytes(self)[r.clone()]).to_owned()
+            })
+            .collect();
+        let mut pieces = vec![];
+        let mut last = 0;
+        for r in ranges {
+            pieces.push(&os_str_bytes::as_bytes(self)[last..r.start]);
+            last = r.end;
+        }
+        pieces.push(&os_str_bytes::as_bytes(self)[last..]);
+        let v = os_string::join_pieces(&pieces, edit_mode(mode));
+        set_bytes(self, v);
+        drained
+    }
+}
+
+/// The byte ranges of all matches of `pat`.
+#[cfg(feature = "std")]
+fn match_ranges<'a, P>(s: &'a OsStr, pat: P) -> Vec<Range<usize>>
+    where P: Pattern<&'a OsStr>
+{
+    s.match_indices(pat).map(|(i, m)| i..i + m.len()).collect()
+}
+
+/// The mode an edit uses, since on Windows the bytes of an `OsString`
+/// always have to stay WTF-8.
+#[cfg(feature = "std")]
+#[inline]
+fn edit_mode(mode: SurrogateMode) -> SurrogateMode {
+    if cfg!(windows) { SurrogateMode::Wtf8 } else { mode }
+}
+
+/// Replaces the bytes of `s` with the result of an edit,
+/// which keeps them valid for the platform.
+#[cfg(feature = "std")]
+fn set_bytes(s: &mut OsString, bytes: Vec<u8>) {
+    unsafe {
+        os_str_bytes::with_vec(s, |v| *v = bytes);
+    }
 }
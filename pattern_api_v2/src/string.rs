@@ -12,9 +12,14 @@ macro_rules! impl_both_mutability {
                 haystack: ($cursor, $cursor),
                 start: $cursor,
                 end: $cursor,
-                _marker: ::std::marker::PhantomData<$slice>
+                _marker: ::core::marker::PhantomData<$slice>
             }
 
+            // Only reads the elements between `start` and `end`,
+            // which have not been returned yet
+            unsafe impl<'a> Send for Iter<'a> where $slice: Send {}
+            unsafe impl<'a> Sync for Iter<'a> where $slice: Sync {}
+
             impl<'a> Iter<'a> {
                 #[inline]
                 fn new(haystack: $slice) -> Self {
@@ -23,7 +28,7 @@ macro_rules! impl_both_mutability {
                         haystack: (start, end),
                         start: start,
                         end: end,
-                        _marker: ::std::marker::PhantomData,
+                        _marker: ::core::marker::PhantomData,
                     }
                 }
 
@@ -78,6 +83,26 @@ macro_rules! impl_both_mutability {
                     hs.1
                 }
                 fn match_type_len(mt: &Self::MatchType) -> usize { mt.len() }
+
+                fn span_to_cursors(hs: Self::Haystack,
+                                   span: Span) -> Option<(Self::Cursor, Self::Cursor)> {
+                    let s = unsafe {
+                        let bytes = ::core::slice::from_raw_parts(hs.0 as *const u8,
+                            hs.1 as usize - hs.0 as usize);
+                        ::core::str::from_utf8_unchecked(bytes)
+                    };
+                    if span.start <= span.end
+                        && s.is_char_boundary(span.start)
+                        && s.is_char_boundary(span.end)
+                    {
+                        unsafe {
+                            Some((hs.0.offset(span.start as isize),
+                                  hs.0.offset(span.end as isize)))
+                        }
+                    } else {
+                        None
+                    }
+                }
             }
 
             unsafe impl<'a> InverseMatchesAreValid for $slice {}
@@ -87,6 +112,10 @@ macro_rules! impl_both_mutability {
             //////////////////////////////////////////////////////////////////
 
             use utf8::{self, CharEq, CharEqPattern};
+            use core::ops::{Range, RangeInclusive};
+            #[cfg(feature = "alloc")]
+            use sets::CharSet;
+            use char_classes::CharClass;
 
             #[derive(Clone)]
             pub struct CharEqSearcher<'a, C: CharEq> {
@@ -114,6 +143,13 @@ macro_rules! impl_both_mutability {
                             .unwrap_or(false)
                 }
 
+                fn matches_at(mut self, haystack: $slice, offset: usize) -> Option<usize> {
+                    match haystack.get(offset..).and_then(|s| s.chars().next()) {
+                        Some(c) if self.0.matches(c) => Some(offset + c.len_utf8()),
+                        _ => None,
+                    }
+                }
+
                 fn is_suffix_of(mut self, haystack: $slice) -> bool
                     where Self::Searcher: ReverseSearcher<$slice>
                 {
@@ -122,6 +158,30 @@ macro_rules! impl_both_mutability {
                             .map(|c| self.0.matches(c))
                             .unwrap_or(false)
                 }
+
+                fn strip_prefix_of(self, haystack: $slice) -> Option<$slice> {
+                    let mut searcher = self.into_searcher(haystack);
+                    let c = utf8::next_code_point(|| searcher.iter.next());
+                    match c {
+                        Some(c) if searcher.char_eq.matches(c) => unsafe {
+                            Some(($cursors_to_haystack)(searcher.iter.start,
+                                                        searcher.iter.end))
+                        },
+                        _ => None,
+                    }
+                }
+
+                fn strip_suffix_of(self, haystack: $slice) -> Option<$slice> {
+                    let mut searcher = self.into_searcher(haystack);
+                    let c = utf8::next_code_point_reverse(|| searcher.iter.next_back());
+                    match c {
+                        Some(c) if searcher.char_eq.matches(c) => unsafe {
+                            Some(($cursors_to_haystack)(searcher.iter.start,
+                                                        searcher.iter.end))
+                        },
+                        _ => None,
+                    }
+                }
             }
 
             unsafe impl<'a, C: CharEq> Searcher<$slice> for CharEqSearcher<'a, C> {
@@ -130,6 +190,11 @@ macro_rules! impl_both_mutability {
                     self.iter.haystack
                 }
 
+                #[inline]
+                fn as_seekable(&mut self) -> Option<&mut dyn SeekableSearcher<$slice>> {
+                    Some(self)
+                }
+
                 #[inline]
                 fn next_match(&mut self) -> Option<($cursor, $cursor)> {
                     if self.ascii_only {
@@ -239,6 +304,30 @@ macro_rules! impl_both_mutability {
 
             impl<'a, C: CharEq> DoubleEndedSearcher<$slice> for CharEqSearcher<'a, C> {}
 
+            impl<'a, C: CharEq> SeekableSearcher<$slice> for CharEqSearcher<'a, C> {
+                fn seek_front(&mut self, offset: usize) -> bool {
+                    let hs = self.iter.haystack;
+                    if offset > <$slice>::offset_from_front(hs, self.iter.end)
+                        || !<$slice as OrdSlice>::pos_is_valid(&hs, offset) {
+                        return false;
+                    }
+                    self.iter.start = unsafe { hs.0.offset(offset as isize) };
+                    true
+                }
+
+                fn seek_back(&mut self, offset: usize) -> bool {
+                    let hs = self.iter.haystack;
+                    if offset < <$slice>::offset_from_front(hs, self.iter.start)
+                        || offset > <$slice>::haystack_len(hs)
+                        || !<$slice as OrdSlice>::pos_is_valid(&hs, offset)
+                    {
+                        return false;
+                    }
+                    self.iter.end = unsafe { hs.0.offset(offset as isize) };
+                    true
+                }
+            }
+
             /////////////////////////////////////////////////////////////////////////////
             // Impl for char
             /////////////////////////////////////////////////////////////////////////////
@@ -248,13 +337,17 @@ macro_rules! impl_both_mutability {
             pub struct CharSearcher<'a>(CharEqSearcher<'a, char>);
 
             unsafe impl<'a> Searcher<$slice> for CharSearcher<'a> {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a> ReverseSearcher<$slice> for CharSearcher<'a> {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a> SeekableSearcher<$slice> for CharSearcher<'a> {
+                searcher_methods!(seek, s, s.0);
+            }
+
             impl<'a> DoubleEndedSearcher<$slice> for CharSearcher<'a> {}
 
             /// Searches for chars that are equal to a given char
@@ -273,13 +366,17 @@ macro_rules! impl_both_mutability {
             pub struct CharSliceSearcher<'a, 'b>(CharEqSearcher<'a, &'b [char]>);
 
             unsafe impl<'a, 'b> Searcher<$slice> for CharSliceSearcher<'a, 'b> {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a, 'b> ReverseSearcher<$slice> for CharSliceSearcher<'a, 'b> {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a, 'b> SeekableSearcher<$slice> for CharSliceSearcher<'a, 'b> {
+                searcher_methods!(seek, s, s.0);
+            }
+
             impl<'a, 'b> DoubleEndedSearcher<$slice> for CharSliceSearcher<'a, 'b> {}
 
             /// Searches for chars that are equal to any of the chars in the array
@@ -299,7 +396,7 @@ macro_rules! impl_both_mutability {
             unsafe impl<'a, F> Searcher<$slice> for CharPredicateSearcher<'a, F>
                 where F: FnMut(char) -> bool
             {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a, F> ReverseSearcher<$slice> for CharPredicateSearcher<'a, F>
@@ -308,6 +405,12 @@ macro_rules! impl_both_mutability {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a, F> SeekableSearcher<$slice> for CharPredicateSearcher<'a, F>
+                where F: FnMut(char) -> bool
+            {
+                searcher_methods!(seek, s, s.0);
+            }
+
             impl<'a, F> DoubleEndedSearcher<$slice> for CharPredicateSearcher<'a, F>
                 where F: FnMut(char) -> bool {}
 
@@ -316,6 +419,109 @@ macro_rules! impl_both_mutability {
                 pattern_methods!(CharPredicateSearcher<'a, F>, CharEqPattern, CharPredicateSearcher, $slice);
             }
 
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for char ranges
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<RangeInclusive<char> as Pattern<&'a str>>::Searcher`,
+            /// and for the other char ranges.
+            #[derive(Clone)]
+            pub struct CharRangeSearcher<'a, R: CharEq>(CharEqSearcher<'a, R>);
+
+            unsafe impl<'a, R: CharEq> Searcher<$slice> for CharRangeSearcher<'a, R> {
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
+            }
+
+            unsafe impl<'a, R: CharEq> ReverseSearcher<$slice> for CharRangeSearcher<'a, R> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a, R: CharEq> SeekableSearcher<$slice> for CharRangeSearcher<'a, R> {
+                searcher_methods!(seek, s, s.0);
+            }
+
+            impl<'a, R: CharEq> DoubleEndedSearcher<$slice> for CharRangeSearcher<'a, R> {}
+
+            /// Searches for chars inside of the range
+            impl<'a> Pattern<$slice> for RangeInclusive<char> {
+                pattern_methods!(CharRangeSearcher<'a, RangeInclusive<char>>, CharEqPattern,
+                                 CharRangeSearcher, $slice);
+            }
+
+            /// Searches for chars inside of the range
+            impl<'a> Pattern<$slice> for Range<char> {
+                pattern_methods!(CharRangeSearcher<'a, Range<char>>, CharEqPattern,
+                                 CharRangeSearcher, $slice);
+            }
+
+            /// Searches for chars inside of any of the ranges in the array
+            impl<'a, 'b> Pattern<$slice> for &'b [RangeInclusive<char>] {
+                pattern_methods!(CharRangeSearcher<'a, &'b [RangeInclusive<char>]>, CharEqPattern,
+                                 CharRangeSearcher, $slice);
+            }
+
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for &CharSet
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<&CharSet as Pattern<&'a str>>::Searcher`.
+            #[cfg(feature = "alloc")]
+            #[derive(Clone)]
+            pub struct CharSetSearcher<'a, 'b>(CharEqSearcher<'a, &'b CharSet>);
+
+            #[cfg(feature = "alloc")]
+            unsafe impl<'a, 'b> Searcher<$slice> for CharSetSearcher<'a, 'b> {
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
+            }
+
+            #[cfg(feature = "alloc")]
+            unsafe impl<'a, 'b> ReverseSearcher<$slice> for CharSetSearcher<'a, 'b> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            #[cfg(feature = "alloc")]
+            impl<'a, 'b> SeekableSearcher<$slice> for CharSetSearcher<'a, 'b> {
+                searcher_methods!(seek, s, s.0);
+            }
+
+            #[cfg(feature = "alloc")]
+            impl<'a, 'b> DoubleEndedSearcher<$slice> for CharSetSearcher<'a, 'b> {}
+
+            /// Searches for chars that are in the set
+            #[cfg(feature = "alloc")]
+            impl<'a, 'b> Pattern<$slice> for &'b CharSet {
+                pattern_methods!(CharSetSearcher<'a, 'b>, CharEqPattern,
+                                 CharSetSearcher, $slice);
+            }
+
+            /////////////////////////////////////////////////////////////////////////////
+            // Impl for CharClass
+            /////////////////////////////////////////////////////////////////////////////
+
+            /// Associated type for `<CharClass as Pattern<&'a str>>::Searcher`.
+            #[derive(Clone)]
+            pub struct CharClassSearcher<'a>(CharEqSearcher<'a, CharClass>);
+
+            unsafe impl<'a> Searcher<$slice> for CharClassSearcher<'a> {
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
+            }
+
+            unsafe impl<'a> ReverseSearcher<$slice> for CharClassSearcher<'a> {
+                searcher_methods!(reverse, s, s.0, $cursor);
+            }
+
+            impl<'a> SeekableSearcher<$slice> for CharClassSearcher<'a> {
+                searcher_methods!(seek, s, s.0);
+            }
+
+            impl<'a> DoubleEndedSearcher<$slice> for CharClassSearcher<'a> {}
+
+            /// Searches for chars that are in the class
+            impl<'a> Pattern<$slice> for CharClass {
+                pattern_methods!(CharClassSearcher<'a>, CharEqPattern,
+                                 CharClassSearcher, $slice);
+            }
+
             ////////////////////////////////////////////////////////////////////
             // Impl for &str
             ////////////////////////////////////////////////////////////////////
@@ -331,27 +537,27 @@ macro_rules! impl_both_mutability {
 
                 fn next_valid_pos(hs: &Self::Haystack, pos: usize) -> Option<usize> {
                     let s = unsafe {
-                        ::std::str::from_utf8_unchecked(Self::haystack_as_slice(hs))
+                        ::core::str::from_utf8_unchecked(Self::haystack_as_slice(hs))
                     };
                     s[pos..].chars().next().map(|c| pos + c.len_utf8())
                 }
 
                 fn next_valid_pos_back(hs: &Self::Haystack, pos: usize) -> Option<usize> {
                     let s = unsafe {
-                        ::std::str::from_utf8_unchecked(Self::haystack_as_slice(hs))
+                        ::core::str::from_utf8_unchecked(Self::haystack_as_slice(hs))
                     };
                     s[..pos].chars().next_back().map(|c| pos - c.len_utf8())
                 }
 
                 fn haystack_as_slice<'t>(hs: &'t Self::Haystack) -> &'t [Self::NeedleElement] {
                     unsafe {
-                        ::std::slice::from_raw_parts(hs.0, hs.1 as usize - hs.0 as usize)
+                        ::core::slice::from_raw_parts(hs.0, hs.1 as usize - hs.0 as usize)
                     }
                 }
 
                 fn pos_is_valid(hs: &Self::Haystack, pos: usize) -> bool {
                     let s = unsafe {
-                        ::std::str::from_utf8_unchecked(Self::haystack_as_slice(hs))
+                        ::core::str::from_utf8_unchecked(Self::haystack_as_slice(hs))
                     };
                     s.is_char_boundary(pos)
                 }
@@ -373,21 +579,25 @@ macro_rules! impl_both_mutability {
             }
 
             unsafe impl<'a, 'b> Searcher<$slice> for StrSearcher<'a, 'b> {
-                searcher_methods!(forward, s, s.0, $cursor);
+                searcher_methods!(forward, s, s.0, $cursor, $slice);
             }
 
             unsafe impl<'a, 'b> ReverseSearcher<$slice> for StrSearcher<'a, 'b> {
                 searcher_methods!(reverse, s, s.0, $cursor);
             }
 
+            impl<'a, 'b> SeekableSearcher<$slice> for StrSearcher<'a, 'b> {
+                searcher_methods!(seek, s, s.0);
+            }
+
         }
     }
 }
 
 impl_both_mutability!(shared, &'a str, *const u8, u8, |start, end| {
-    let slice = ::std::slice::from_raw_parts(start,
+    let slice = ::core::slice::from_raw_parts(start,
         end as usize - start as usize);
-    ::std::str::from_utf8_unchecked(slice)
+    ::core::str::from_utf8_unchecked(slice)
 }, |haystack: &str| {
     let begin = haystack.as_ptr();
     let end = unsafe {
@@ -397,11 +607,10 @@ impl_both_mutability!(shared, &'a str, *const u8, u8, |start, end| {
 });
 
 impl_both_mutability!(mutable, &'a mut str, *mut u8, u8, |start, end| {
-    let slice = ::std::slice::from_raw_parts_mut(start,
+    let slice = ::core::slice::from_raw_parts_mut(start,
         end as usize - start as usize);
 
-    // TODO: This should probably be just library support in std
-    ::std::mem::transmute::<&mut [u8], &mut str>(slice)
+    ::core::str::from_utf8_unchecked_mut(slice)
 }, |haystack: &mut str| {
     let begin = haystack.as_ptr() as *mut u8;
     let end = unsafe {
@@ -410,9 +619,11 @@ impl_both_mutability!(mutable, &'a mut str, *mut u8, u8, |start, end| {
     (begin, end)
 });
 
+unsafe impl<'a> ::MutableHaystack for &'a mut str {}
+
 use ::Pattern;
 use ::ReverseSearcher;
-//use ::PatternHaystack;
+use ::PatternHaystack;
 
 /*impl<'b, H, P> Pattern<H> for &'b P
     where P: Pattern<H>,
@@ -420,10 +631,12 @@ use ::ReverseSearcher;
           H: PatternHaystack,
 {}*/
 
-impl<'a, 'b> Pattern<&'a str> for &'b String {
-    pattern_methods!(shared::StrSearcher<'a, 'b>, |s: &'b String| &**s, |s| s, &'a str);
+#[cfg(feature = "alloc")]
+impl<'a, 'b> Pattern<&'a str> for &'b ::alloc::string::String {
+    pattern_methods!(copy shared::StrSearcher<'a, 'b>,
+                     |s: &'b ::alloc::string::String| &**s, |s| s, &'a str);
 }
 
 impl<'a, 'b, 'c> Pattern<&'a str> for &'c &'b str {
-    pattern_methods!(shared::StrSearcher<'a, 'b>, |&s| s, |s| s, &'a str);
+    pattern_methods!(copy shared::StrSearcher<'a, 'b>, |&s| s, |s| s, &'a str);
 }
@@ -0,0 +1,201 @@
+#!/usr/bin/env python3
+#
+# Generates unicode_tables.rs from the Unicode Character Database,
+# as provided by the properties of the `regex` module
+# and the normalization data of the `unicodedata` module.
+#
+# Usage: python3 unicode_tables.py > unicode_tables.rs
+
+import regex
+import unicodedata
+
+ALL_CHARS = ''.join(chr(c) for c in range(0x110000) if not 0xD800 <= c < 0xE000)
+
+
+def ranges(prop):
+    """Returns the sorted list of code point ranges having the given property."""
+    out = []
+    for m in regex.finditer(r'\p{%s}+' % prop, ALL_CHARS):
+        lo, hi = ord(m.group()[0]), ord(m.group()[-1])
+        # surrogates are skipped in ALL_CHARS, so a run can jump over them
+        if lo < 0xD800 < hi:
+            out.append((lo, 0xD7FF))
+            out.append((0xE000, hi))
+        else:
+            out.append((lo, hi))
+    return out
+
+
+def merge(tables):
+    """Merges `{value: ranges}` into one sorted list of `(lo, hi, value)`."""
+    out = sorted((lo, hi, value) for value, rs in tables.items() for lo, hi in rs)
+    for a, b in zip(out, out[1:]):
+        assert a[1] < b[0], (a, b)
+    return out
+
+
+def fmt_char(c):
+    return "'\\u{%x}'" % c
+
+
+def emit_enum(name, variants):
+    print("#[derive(Copy, Clone, Eq, PartialEq, Debug)]")
+    print("pub enum %s {" % name)
+    for v in variants:
+        print("    %s," % v)
+    print("}")
+
+
+def emit_table(name, rows, value_type=None, value_prefix=None):
+    if value_type is None:
+        print("pub const %s: &'static [(char, char)] = &[" % name)
+        for lo, hi in rows:
+            print("    (%s, %s)," % (fmt_char(lo), fmt_char(hi)))
+    else:
+        print("pub const %s: &'static [(char, char, %s)] = &[" % (name, value_type))
+        for lo, hi, v in rows:
+            print("    (%s, %s, %s::%s)," % (fmt_char(lo), fmt_char(hi), value_prefix, v))
+    print("];")
+
+
+GRAPHEME_CATS = ['CR', 'LF', 'Control', 'Extend', 'ZWJ', 'Regional_Indicator',
+                 'Prepend', 'SpacingMark', 'L', 'V', 'T', 'LV', 'LVT']
+
+INCB_CATS = ['Consonant', 'Extend', 'Linker']
+
+WORD_CATS = ['CR', 'LF', 'Newline', 'Extend', 'ZWJ', 'Regional_Indicator',
+             'Format', 'Katakana', 'Hebrew_Letter', 'ALetter', 'Single_Quote',
+             'Double_Quote', 'MidNumLet', 'MidLetter', 'MidNum', 'Numeric',
+             'ExtendNumLet', 'WSegSpace']
+
+GENERAL_CATEGORIES = ['Lu', 'Ll', 'Lt', 'Lm', 'Lo', 'Mn', 'Mc', 'Me', 'Nd', 'Nl', 'No',
+                      'Pc', 'Pd', 'Ps', 'Pe', 'Pi', 'Pf', 'Po', 'Sm', 'Sc', 'Sk', 'So',
+                      'Zs', 'Zl', 'Zp', 'Cc', 'Cf', 'Co']
+
+SCRIPTS = ['Common', 'Inherited', 'Latin', 'Greek', 'Cyrillic', 'Armenian', 'Hebrew',
+           'Arabic', 'Devanagari', 'Bengali', 'Tamil', 'Thai', 'Georgian', 'Hangul',
+           'Hiragana', 'Katakana', 'Han']
+
+
+def canonical_combining_classes():
+    out = []
+    for c in range(0x110000):
+        ccc = unicodedata.combining(chr(c))
+        if ccc == 0:
+            continue
+        if out and out[-1][1] == c - 1 and out[-1][2] == ccc:
+            out[-1] = (out[-1][0], c, ccc)
+        else:
+            out.append((c, c, ccc))
+    return out
+
+
+def is_hangul_syllable(c):
+    return 0xAC00 <= c <= 0xD7A3
+
+
+def canonical_decompositions():
+    """Full canonical decompositions, except for the algorithmic hangul ones."""
+    out = []
+    for c in range(0x110000):
+        if is_hangul_syllable(c) or 0xD800 <= c < 0xE000:
+            continue
+        nfd = unicodedata.normalize('NFD', chr(c))
+        if nfd != chr(c):
+            out.append((c, [ord(d) for d in nfd]))
+    return out
+
+
+def composing_starter_pairs():
+    """Pairs of starters that canonically compose, except for hangul."""
+    out = []
+    for c in range(0x110000):
+        if is_hangul_syllable(c) or 0xD800 <= c < 0xE000:
+            continue
+        d = unicodedata.decomposition(chr(c))
+        if not d or d.startswith('<'):
+            continue
+        parts = [int(x, 16) for x in d.split()]
+        if len(parts) != 2:
+            continue
+        a, b = parts
+        if unicodedata.combining(chr(a)) == 0 and unicodedata.combining(chr(b)) == 0 \
+                and unicodedata.normalize('NFC', chr(a) + chr(b)) == chr(c):
+            out.append((a, b))
+    return sorted(out)
+
+
+def camel(name):
+    return ''.join(part[:1].upper() + part[1:] for part in name.split('_'))
+
+
+def main():
+    print("// NOTE: The following code was generated by \"src/unicode_tables.py\",")
+    print("// do not edit directly!")
+    print()
+    print("#![allow(dead_code, clippy::upper_case_acronyms)]")
+    print()
+
+    emit_enum('GraphemeCat', ['Any'] + [camel(c) for c in GRAPHEME_CATS])
+    print()
+    emit_table('GRAPHEME_CAT_TABLE',
+               merge({camel(c): ranges('Grapheme_Cluster_Break=' + c)
+                      for c in GRAPHEME_CATS}),
+               'GraphemeCat', 'GraphemeCat')
+    print()
+
+    emit_enum('IndicConjunctBreak', ['None'] + INCB_CATS)
+    print()
+    emit_table('INDIC_CONJUNCT_BREAK_TABLE',
+               merge({c: ranges('InCB=' + c) for c in INCB_CATS}),
+               'IndicConjunctBreak', 'IndicConjunctBreak')
+    print()
+
+    emit_table('EXTENDED_PICTOGRAPHIC_TABLE', ranges('Extended_Pictographic'))
+    print()
+
+    emit_enum('WordCat', ['Any'] + [camel(c) for c in WORD_CATS])
+    print()
+    emit_table('WORD_CAT_TABLE',
+               merge({camel(c): ranges('Word_Break=' + c) for c in WORD_CATS}),
+               'WordCat', 'WordCat')
+    print()
+
+    # unassigned chars are not listed, and fall back to `Cn`
+    emit_enum('GeneralCategory', ['Cn'] + GENERAL_CATEGORIES)
+    print()
+    emit_table('GENERAL_CATEGORY_TABLE',
+               merge({c: ranges('General_Category=' + c) for c in GENERAL_CATEGORIES}),
+               'GeneralCategory', 'GeneralCategory')
+    print()
+
+    emit_enum('Script', ['Any'] + SCRIPTS)
+    print()
+    emit_table('SCRIPT_TABLE',
+               merge({s: ranges('Script=' + s) for s in SCRIPTS}),
+               'Script', 'Script')
+    print()
+
+    emit_table('XID_START_TABLE', ranges('XID_Start'))
+    print()
+
+    emit_table('XID_CONTINUE_TABLE', ranges('XID_Continue'))
+    print()
+
+    print("// Normalization data of Unicode %s" % unicodedata.unidata_version)
+    print()
+    print("pub const CANONICAL_COMBINING_CLASS_TABLE: &'static [(char, char, u8)] = &[")
+    for lo, hi, ccc in canonical_combining_classes():
+        print("    (%s, %s, %d)," % (fmt_char(lo), fmt_char(hi), ccc))
+    print("];")
+    print()
+    print("pub const CANONICAL_DECOMPOSITION_TABLE: &'static [(char, &'static [char])] = &[")
+    for c, d in canonical_decompositions():
+        print("    (%s, &[%s])," % (fmt_char(c), ", ".join(fmt_char(x) for x in d)))
+    print("];")
+    print()
+    emit_table('COMPOSING_STARTER_PAIRS', composing_starter_pairs())
+
+
+if __name__ == '__main__':
+    main()
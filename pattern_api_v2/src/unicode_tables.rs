@@ -0,0 +1,11108 @@
+// NOTE: The following code was generated by "src/unicode_tables.py",
+// do not edit directly!
+
+#![allow(dead_code, clippy::upper_case_acronyms)]
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GraphemeCat {
+    Any,
+    CR,
+    LF,
+    Control,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+}
+
+pub const GRAPHEME_CAT_TABLE: &'static [(char, char, GraphemeCat)] = &[
+    ('\u{0}', '\u{9}', GraphemeCat::Control),
+    ('\u{a}', '\u{a}', GraphemeCat::LF),
+    ('\u{b}', '\u{c}', GraphemeCat::Control),
+    ('\u{d}', '\u{d}', GraphemeCat::CR),
+    ('\u{e}', '\u{1f}', GraphemeCat::Control),
+    ('\u{7f}', '\u{9f}', GraphemeCat::Control),
+    ('\u{ad}', '\u{ad}', GraphemeCat::Control),
+    ('\u{300}', '\u{36f}', GraphemeCat::Extend),
+    ('\u{483}', '\u{489}', GraphemeCat::Extend),
+    ('\u{591}', '\u{5bd}', GraphemeCat::Extend),
+    ('\u{5bf}', '\u{5bf}', GraphemeCat::Extend),
+    ('\u{5c1}', '\u{5c2}', GraphemeCat::Extend),
+    ('\u{5c4}', '\u{5c5}', GraphemeCat::Extend),
+    ('\u{5c7}', '\u{5c7}', GraphemeCat::Extend),
+    ('\u{600}', '\u{605}', GraphemeCat::Prepend),
+    ('\u{610}', '\u{61a}', GraphemeCat::Extend),
+    ('\u{61c}', '\u{61c}', GraphemeCat::Control),
+    ('\u{64b}', '\u{65f}', GraphemeCat::Extend),
+    ('\u{670}', '\u{670}', GraphemeCat::Extend),
+    ('\u{6d6}', '\u{6dc}', GraphemeCat::Extend),
+    ('\u{6dd}', '\u{6dd}', GraphemeCat::Prepend),
+    ('\u{6df}', '\u{6e4}', GraphemeCat::Extend),
+    ('\u{6e7}', '\u{6e8}', GraphemeCat::Extend),
+    ('\u{6ea}', '\u{6ed}', GraphemeCat::Extend),
+    ('\u{70f}', '\u{70f}', GraphemeCat::Prepend),
+    ('\u{711}', '\u{711}', GraphemeCat::Extend),
+    ('\u{730}', '\u{74a}', GraphemeCat::Extend),
+    ('\u{7a6}', '\u{7b0}', GraphemeCat::Extend),
+    ('\u{7eb}', '\u{7f3}', GraphemeCat::Extend),
+    ('\u{7fd}', '\u{7fd}', GraphemeCat::Extend),
+    ('\u{816}', '\u{819}', GraphemeCat::Extend),
+    ('\u{81b}', '\u{823}', GraphemeCat::Extend),
+    ('\u{825}', '\u{827}', GraphemeCat::Extend),
+    ('\u{829}', '\u{82d}', GraphemeCat::Extend),
+    ('\u{859}', '\u{85b}', GraphemeCat::Extend),
+    ('\u{890}', '\u{891}', GraphemeCat::Prepend),
+    ('\u{897}', '\u{89f}', GraphemeCat::Extend),
+    ('\u{8ca}', '\u{8e1}', GraphemeCat::Extend),
+    ('\u{8e2}', '\u{8e2}', GraphemeCat::Prepend),
+    ('\u{8e3}', '\u{902}', GraphemeCat::Extend),
+    ('\u{903}', '\u{903}', GraphemeCat::SpacingMark),
+    ('\u{93a}', '\u{93a}', GraphemeCat::Extend),
+    ('\u{93b}', '\u{93b}', GraphemeCat::SpacingMark),
+    ('\u{93c}', '\u{93c}', GraphemeCat::Extend),
+    ('\u{93e}', '\u{940}', GraphemeCat::SpacingMark),
+    ('\u{941}', '\u{948}', GraphemeCat::Extend),
+    ('\u{949}', '\u{94c}', GraphemeCat::SpacingMark),
+    ('\u{94d}', '\u{94d}', GraphemeCat::Extend),
+    ('\u{94e}', '\u{94f}', GraphemeCat::SpacingMark),
+    ('\u{951}', '\u{957}', GraphemeCat::Extend),
+    ('\u{962}', '\u{963}', GraphemeCat::Extend),
+    ('\u{981}', '\u{981}', GraphemeCat::Extend),
+    ('\u{982}', '\u{983}', GraphemeCat::SpacingMark),
+    ('\u{9bc}', '\u{9bc}', GraphemeCat::Extend),
+    ('\u{9be}', '\u{9be}', GraphemeCat::Extend),
+    ('\u{9bf}', '\u{9c0}', GraphemeCat::SpacingMark),
+    ('\u{9c1}', '\u{9c4}', GraphemeCat::Extend),
+    ('\u{9c7}', '\u{9c8}', GraphemeCat::SpacingMark),
+    ('\u{9cb}', '\u{9cc}', GraphemeCat::SpacingMark),
+    ('\u{9cd}', '\u{9cd}', GraphemeCat::Extend),
+    ('\u{9d7}', '\u{9d7}', GraphemeCat::Extend),
+    ('\u{9e2}', '\u{9e3}', GraphemeCat::Extend),
+    ('\u{9fe}', '\u{9fe}', GraphemeCat::Extend),
+    ('\u{a01}', '\u{a02}', GraphemeCat::Extend),
+    ('\u{a03}', '\u{a03}', GraphemeCat::SpacingMark),
+    ('\u{a3c}', '\u{a3c}', GraphemeCat::Extend),
+    ('\u{a3e}', '\u{a40}', GraphemeCat::SpacingMark),
+    ('\u{a41}', '\u{a42}', GraphemeCat::Extend),
+    ('\u{a47}', '\u{a48}', GraphemeCat::Extend),
+    ('\u{a4b}', '\u{a4d}', GraphemeCat::Extend),
+    ('\u{a51}', '\u{a51}', GraphemeCat::Extend),
+    ('\u{a70}', '\u{a71}', GraphemeCat::Extend),
+    ('\u{a75}', '\u{a75}', GraphemeCat::Extend),
+    ('\u{a81}', '\u{a82}', GraphemeCat::Extend),
+    ('\u{a83}', '\u{a83}', GraphemeCat::SpacingMark),
+    ('\u{abc}', '\u{abc}', GraphemeCat::Extend),
+    ('\u{abe}', '\u{ac0}', GraphemeCat::SpacingMark),
+    ('\u{ac1}', '\u{ac5}', GraphemeCat::Extend),
+    ('\u{ac7}', '\u{ac8}', GraphemeCat::Extend),
+    ('\u{ac9}', '\u{ac9}', GraphemeCat::SpacingMark),
+    ('\u{acb}', '\u{acc}', GraphemeCat::SpacingMark),
+    ('\u{acd}', '\u{acd}', GraphemeCat::Extend),
+    ('\u{ae2}', '\u{ae3}', GraphemeCat::Extend),
+    ('\u{afa}', '\u{aff}', GraphemeCat::Extend),
+    ('\u{b01}', '\u{b01}', GraphemeCat::Extend),
+    ('\u{b02}', '\u{b03}', GraphemeCat::SpacingMark),
+    ('\u{b3c}', '\u{b3c}', GraphemeCat::Extend),
+    ('\u{b3e}', '\u{b3f}', GraphemeCat::Extend),
+    ('\u{b40}', '\u{b40}', GraphemeCat::SpacingMark),
+    ('\u{b41}', '\u{b44}', GraphemeCat::Extend),
+    ('\u{b47}', '\u{b48}', GraphemeCat::SpacingMark),
+    ('\u{b4b}', '\u{b4c}', GraphemeCat::SpacingMark),
+    ('\u{b4d}', '\u{b4d}', GraphemeCat::Extend),
+    ('\u{b55}', '\u{b57}', GraphemeCat::Extend),
+    ('\u{b62}', '\u{b63}', GraphemeCat::Extend),
+    ('\u{b82}', '\u{b82}', GraphemeCat::Extend),
+    ('\u{bbe}', '\u{bbe}', GraphemeCat::Extend),
+    ('\u{bbf}', '\u{bbf}', GraphemeCat::SpacingMark),
+    ('\u{bc0}', '\u{bc0}', GraphemeCat::Extend),
+    ('\u{bc1}', '\u{bc2}', GraphemeCat::SpacingMark),
+    ('\u{bc6}', '\u{bc8}', GraphemeCat::SpacingMark),
+    ('\u{bca}', '\u{bcc}', GraphemeCat::SpacingMark),
+    ('\u{bcd}', '\u{bcd}', GraphemeCat::Extend),
+    ('\u{bd7}', '\u{bd7}', GraphemeCat::Extend),
+    ('\u{c00}', '\u{c00}', GraphemeCat::Extend),
+    ('\u{c01}', '\u{c03}', GraphemeCat::SpacingMark),
+    ('\u{c04}', '\u{c04}', GraphemeCat::Extend),
+    ('\u{c3c}', '\u{c3c}', GraphemeCat::Extend),
+    ('\u{c3e}', '\u{c40}', GraphemeCat::Extend),
+    ('\u{c41}', '\u{c44}', GraphemeCat::SpacingMark),
+    ('\u{c46}', '\u{c48}', GraphemeCat::Extend),
+    ('\u{c4a}', '\u{c4d}', GraphemeCat::Extend),
+    ('\u{c55}', '\u{c56}', GraphemeCat::Extend),
+    ('\u{c62}', '\u{c63}', GraphemeCat::Extend),
+    ('\u{c81}', '\u{c81}', GraphemeCat::Extend),
+    ('\u{c82}', '\u{c83}', GraphemeCat::SpacingMark),
+    ('\u{cbc}', '\u{cbc}', GraphemeCat::Extend),
+    ('\u{cbe}', '\u{cbe}', GraphemeCat::SpacingMark),
+    ('\u{cbf}', '\u{cc0}', GraphemeCat::Extend),
+    ('\u{cc1}', '\u{cc1}', GraphemeCat::SpacingMark),
+    ('\u{cc2}', '\u{cc2}', GraphemeCat::Extend),
+    ('\u{cc3}', '\u{cc4}', GraphemeCat::SpacingMark),
+    ('\u{cc6}', '\u{cc8}', GraphemeCat::Extend),
+    ('\u{cca}', '\u{ccd}', GraphemeCat::Extend),
+    ('\u{cd5}', '\u{cd6}', GraphemeCat::Extend),
+    ('\u{ce2}', '\u{ce3}', GraphemeCat::Extend),
+    ('\u{cf3}', '\u{cf3}', GraphemeCat::SpacingMark),
+    ('\u{d00}', '\u{d01}', GraphemeCat::Extend),
+    ('\u{d02}', '\u{d03}', GraphemeCat::SpacingMark),
+    ('\u{d3b}', '\u{d3c}', GraphemeCat::Extend),
+    ('\u{d3e}', '\u{d3e}', GraphemeCat::Extend),
+    ('\u{d3f}', '\u{d40}', GraphemeCat::SpacingMark),
+    ('\u{d41}', '\u{d44}', GraphemeCat::Extend),
+    ('\u{d46}', '\u{d48}', GraphemeCat::SpacingMark),
+    ('\u{d4a}', '\u{d4c}', GraphemeCat::SpacingMark),
+    ('\u{d4d}', '\u{d4d}', GraphemeCat::Extend),
+    ('\u{d4e}', '\u{d4e}', GraphemeCat::Prepend),
+    ('\u{d57}', '\u{d57}', GraphemeCat::Extend),
+    ('\u{d62}', '\u{d63}', GraphemeCat::Extend),
+    ('\u{d81}', '\u{d81}', GraphemeCat::Extend),
+    ('\u{d82}', '\u{d83}', GraphemeCat::SpacingMark),
+    ('\u{dca}', '\u{dca}', GraphemeCat::Extend),
+    ('\u{dcf}', '\u{dcf}', GraphemeCat::Extend),
+    ('\u{dd0}', '\u{dd1}', GraphemeCat::SpacingMark),
+    ('\u{dd2}', '\u{dd4}', GraphemeCat::Extend),
+    ('\u{dd6}', '\u{dd6}', GraphemeCat::Extend),
+    ('\u{dd8}', '\u{dde}', GraphemeCat::SpacingMark),
+    ('\u{ddf}', '\u{ddf}', GraphemeCat::Extend),
+    ('\u{df2}', '\u{df3}', GraphemeCat::SpacingMark),
+    ('\u{e31}', '\u{e31}', GraphemeCat::Extend),
+    ('\u{e33}', '\u{e33}', GraphemeCat::SpacingMark),
+    ('\u{e34}', '\u{e3a}', GraphemeCat::Extend),
+    ('\u{e47}', '\u{e4e}', GraphemeCat::Extend),
+    ('\u{eb1}', '\u{eb1}', GraphemeCat::Extend),
+    ('\u{eb3}', '\u{eb3}', GraphemeCat::SpacingMark),
+    ('\u{eb4}', '\u{ebc}', GraphemeCat::Extend),
+    ('\u{ec8}', '\u{ece}', GraphemeCat::Extend),
+    ('\u{f18}', '\u{f19}', GraphemeCat::Extend),
+    ('\u{f35}', '\u{f35}', GraphemeCat::Extend),
+    ('\u{f37}', '\u{f37}', GraphemeCat::Extend),
+    ('\u{f39}', '\u{f39}', GraphemeCat::Extend),
+    ('\u{f3e}', '\u{f3f}', GraphemeCat::SpacingMark),
+    ('\u{f71}', '\u{f7e}', GraphemeCat::Extend),
+    ('\u{f7f}', '\u{f7f}', GraphemeCat::SpacingMark),
+    ('\u{f80}', '\u{f84}', GraphemeCat::Extend),
+    ('\u{f86}', '\u{f87}', GraphemeCat::Extend),
+    ('\u{f8d}', '\u{f97}', GraphemeCat::Extend),
+    ('\u{f99}', '\u{fbc}', GraphemeCat::Extend),
+    ('\u{fc6}', '\u{fc6}', GraphemeCat::Extend),
+    ('\u{102d}', '\u{1030}', GraphemeCat::Extend),
+    ('\u{1031}', '\u{1031}', GraphemeCat::SpacingMark),
+    ('\u{1032}', '\u{1037}', GraphemeCat::Extend),
+    ('\u{1039}', '\u{103a}', GraphemeCat::Extend),
+    ('\u{103b}', '\u{103c}', GraphemeCat::SpacingMark),
+    ('\u{103d}', '\u{103e}', GraphemeCat::Extend),
+    ('\u{1056}', '\u{1057}', GraphemeCat::SpacingMark),
+    ('\u{1058}', '\u{1059}', GraphemeCat::Extend),
+    ('\u{105e}', '\u{1060}', GraphemeCat::Extend),
+    ('\u{1071}', '\u{1074}', GraphemeCat::Extend),
+    ('\u{1082}', '\u{1082}', GraphemeCat::Extend),
+    ('\u{1084}', '\u{1084}', GraphemeCat::SpacingMark),
+    ('\u{1085}', '\u{1086}', GraphemeCat::Extend),
+    ('\u{108d}', '\u{108d}', GraphemeCat::Extend),
+    ('\u{109d}', '\u{109d}', GraphemeCat::Extend),
+    ('\u{1100}', '\u{115f}', GraphemeCat::L),
+    ('\u{1160}', '\u{11a7}', GraphemeCat::V),
+    ('\u{11a8}', '\u{11ff}', GraphemeCat::T),
+    ('\u{135d}', '\u{135f}', GraphemeCat::Extend),
+    ('\u{1712}', '\u{1715}', GraphemeCat::Extend),
+    ('\u{1732}', '\u{1734}', GraphemeCat::Extend),
+    ('\u{1752}', '\u{1753}', GraphemeCat::Extend),
+    ('\u{1772}', '\u{1773}', GraphemeCat::Extend),
+    ('\u{17b4}', '\u{17b5}', GraphemeCat::Extend),
+    ('\u{17b6}', '\u{17b6}', GraphemeCat::SpacingMark),
+    ('\u{17b7}', '\u{17bd}', GraphemeCat::Extend),
+    ('\u{17be}', '\u{17c5}', GraphemeCat::SpacingMark),
+    ('\u{17c6}', '\u{17c6}', GraphemeCat::Extend),
+    ('\u{17c7}', '\u{17c8}', GraphemeCat::SpacingMark),
+    ('\u{17c9}', '\u{17d3}', GraphemeCat::Extend),
+    ('\u{17dd}', '\u{17dd}', GraphemeCat::Extend),
+    ('\u{180b}', '\u{180d}', GraphemeCat::Extend),
+    ('\u{180e}', '\u{180e}', GraphemeCat::Control),
+    ('\u{180f}', '\u{180f}', GraphemeCat::Extend),
+    ('\u{1885}', '\u{1886}', GraphemeCat::Extend),
+    ('\u{18a9}', '\u{18a9}', GraphemeCat::Extend),
+    ('\u{1920}', '\u{1922}', GraphemeCat::Extend),
+    ('\u{1923}', '\u{1926}', GraphemeCat::SpacingMark),
+    ('\u{1927}', '\u{1928}', GraphemeCat::Extend),
+    ('\u{1929}', '\u{192b}', GraphemeCat::SpacingMark),
+    ('\u{1930}', '\u{1931}', GraphemeCat::SpacingMark),
+    ('\u{1932}', '\u{1932}', GraphemeCat::Extend),
+    ('\u{1933}', '\u{1938}', GraphemeCat::SpacingMark),
+    ('\u{1939}', '\u{193b}', GraphemeCat::Extend),
+    ('\u{1a17}', '\u{1a18}', GraphemeCat::Extend),
+    ('\u{1a19}', '\u{1a1a}', GraphemeCat::SpacingMark),
+    ('\u{1a1b}', '\u{1a1b}', GraphemeCat::Extend),
+    ('\u{1a55}', '\u{1a55}', GraphemeCat::SpacingMark),
+    ('\u{1a56}', '\u{1a56}', GraphemeCat::Extend),
+    ('\u{1a57}', '\u{1a57}', GraphemeCat::SpacingMark),
+    ('\u{1a58}', '\u{1a5e}', GraphemeCat::Extend),
+    ('\u{1a60}', '\u{1a60}', GraphemeCat::Extend),
+    ('\u{1a62}', '\u{1a62}', GraphemeCat::Extend),
+    ('\u{1a65}', '\u{1a6c}', GraphemeCat::Extend),
+    ('\u{1a6d}', '\u{1a72}', GraphemeCat::SpacingMark),
+    ('\u{1a73}', '\u{1a7c}', GraphemeCat::Extend),
+    ('\u{1a7f}', '\u{1a7f}', GraphemeCat::Extend),
+    ('\u{1ab0}', '\u{1add}', GraphemeCat::Extend),
+    ('\u{1ae0}', '\u{1aeb}', GraphemeCat::Extend),
+    ('\u{1b00}', '\u{1b03}', GraphemeCat::Extend),
+    ('\u{1b04}', '\u{1b04}', GraphemeCat::SpacingMark),
+    ('\u{1b34}', '\u{1b3d}', GraphemeCat::Extend),
+    ('\u{1b3e}', '\u{1b41}', GraphemeCat::SpacingMark),
+    ('\u{1b42}', '\u{1b44}', GraphemeCat::Extend),
+    ('\u{1b6b}', '\u{1b73}', GraphemeCat::Extend),
+    ('\u{1b80}', '\u{1b81}', GraphemeCat::Extend),
+    ('\u{1b82}', '\u{1b82}', GraphemeCat::SpacingMark),
+    ('\u{1ba1}', '\u{1ba1}', GraphemeCat::SpacingMark),
+    ('\u{1ba2}', '\u{1ba5}', GraphemeCat::Extend),
+    ('\u{1ba6}', '\u{1ba7}', GraphemeCat::SpacingMark),
+    ('\u{1ba8}', '\u{1bad}', GraphemeCat::Extend),
+    ('\u{1be6}', '\u{1be6}', GraphemeCat::Extend),
+    ('\u{1be7}', '\u{1be7}', GraphemeCat::SpacingMark),
+    ('\u{1be8}', '\u{1be9}', GraphemeCat::Extend),
+    ('\u{1bea}', '\u{1bec}', GraphemeCat::SpacingMark),
+    ('\u{1bed}', '\u{1bed}', GraphemeCat::Extend),
+    ('\u{1bee}', '\u{1bee}', GraphemeCat::SpacingMark),
+    ('\u{1bef}', '\u{1bf3}', GraphemeCat::Extend),
+    ('\u{1c24}', '\u{1c2b}', GraphemeCat::SpacingMark),
+    ('\u{1c2c}', '\u{1c33}', GraphemeCat::Extend),
+    ('\u{1c34}', '\u{1c35}', GraphemeCat::SpacingMark),
+    ('\u{1c36}', '\u{1c37}', GraphemeCat::Extend),
+    ('\u{1cd0}', '\u{1cd2}', GraphemeCat::Extend),
+    ('\u{1cd4}', '\u{1ce0}', GraphemeCat::Extend),
+    ('\u{1ce1}', '\u{1ce1}', GraphemeCat::SpacingMark),
+    ('\u{1ce2}', '\u{1ce8}', GraphemeCat::Extend),
+    ('\u{1ced}', '\u{1ced}', GraphemeCat::Extend),
+    ('\u{1cf4}', '\u{1cf4}', GraphemeCat::Extend),
+    ('\u{1cf7}', '\u{1cf7}', GraphemeCat::SpacingMark),
+    ('\u{1cf8}', '\u{1cf9}', GraphemeCat::Extend),
+    ('\u{1dc0}', '\u{1dff}', GraphemeCat::Extend),
+    ('\u{200b}', '\u{200b}', GraphemeCat::Control),
+    ('\u{200c}', '\u{200c}', GraphemeCat::Extend),
+    ('\u{200d}', '\u{200d}', GraphemeCat::ZWJ),
+    ('\u{200e}', '\u{200f}', GraphemeCat::Control),
+    ('\u{2028}', '\u{202e}', GraphemeCat::Control),
+    ('\u{2060}', '\u{206f}', GraphemeCat::Control),
+    ('\u{20d0}', '\u{20f0}', GraphemeCat::Extend),
+    ('\u{2cef}', '\u{2cf1}', GraphemeCat::Extend),
+    ('\u{2d7f}', '\u{2d7f}', GraphemeCat::Extend),
+    ('\u{2de0}', '\u{2dff}', GraphemeCat::Extend),
+    ('\u{302a}', '\u{302f}', GraphemeCat::Extend),
+    ('\u{3099}', '\u{309a}', GraphemeCat::Extend),
+    ('\u{a66f}', '\u{a672}', GraphemeCat::Extend),
+    ('\u{a674}', '\u{a67d}', GraphemeCat::Extend),
+    ('\u{a69e}', '\u{a69f}', GraphemeCat::Extend),
+    ('\u{a6f0}', '\u{a6f1}', GraphemeCat::Extend),
+    ('\u{a802}', '\u{a802}', GraphemeCat::Extend),
+    ('\u{a806}', '\u{a806}', GraphemeCat::Extend),
+    ('\u{a80b}', '\u{a80b}', GraphemeCat::Extend),
+    ('\u{a823}', '\u{a824}', GraphemeCat::SpacingMark),
+    ('\u{a825}', '\u{a826}', GraphemeCat::Extend),
+    ('\u{a827}', '\u{a827}', GraphemeCat::SpacingMark),
+    ('\u{a82c}', '\u{a82c}', GraphemeCat::Extend),
+    ('\u{a880}', '\u{a881}', GraphemeCat::SpacingMark),
+    ('\u{a8b4}', '\u{a8c3}', GraphemeCat::SpacingMark),
+    ('\u{a8c4}', '\u{a8c5}', GraphemeCat::Extend),
+    ('\u{a8e0}', '\u{a8f1}', GraphemeCat::Extend),
+    ('\u{a8ff}', '\u{a8ff}', GraphemeCat::Extend),
+    ('\u{a926}', '\u{a92d}', GraphemeCat::Extend),
+    ('\u{a947}', '\u{a951}', GraphemeCat::Extend),
+    ('\u{a952}', '\u{a952}', GraphemeCat::SpacingMark),
+    ('\u{a953}', '\u{a953}', GraphemeCat::Extend),
+    ('\u{a960}', '\u{a97c}', GraphemeCat::L),
+    ('\u{a980}', '\u{a982}', GraphemeCat::Extend),
+    ('\u{a983}', '\u{a983}', GraphemeCat::SpacingMark),
+    ('\u{a9b3}', '\u{a9b3}', GraphemeCat::Extend),
+    ('\u{a9b4}', '\u{a9b5}', GraphemeCat::SpacingMark),
+    ('\u{a9b6}', '\u{a9b9}', GraphemeCat::Extend),
+    ('\u{a9ba}', '\u{a9bb}', GraphemeCat::SpacingMark),
+    ('\u{a9bc}', '\u{a9bd}', GraphemeCat::Extend),
+    ('\u{a9be}', '\u{a9bf}', GraphemeCat::SpacingMark),
+    ('\u{a9c0}', '\u{a9c0}', GraphemeCat::Extend),
+    ('\u{a9e5}', '\u{a9e5}', GraphemeCat::Extend),
+    ('\u{aa29}', '\u{aa2e}', GraphemeCat::Extend),
+    ('\u{aa2f}', '\u{aa30}', GraphemeCat::SpacingMark),
+    ('\u{aa31}', '\u{aa32}', GraphemeCat::Extend),
+    ('\u{aa33}', '\u{aa34}', GraphemeCat::SpacingMark),
+    ('\u{aa35}', '\u{aa36}', GraphemeCat::Extend),
+    ('\u{aa43}', '\u{aa43}', GraphemeCat::Extend),
+    ('\u{aa4c}', '\u{aa4c}', GraphemeCat::Extend),
+    ('\u{aa4d}', '\u{aa4d}', GraphemeCat::SpacingMark),
+    ('\u{aa7c}', '\u{aa7c}', GraphemeCat::Extend),
+    ('\u{aab0}', '\u{aab0}', GraphemeCat::Extend),
+    ('\u{aab2}', '\u{aab4}', GraphemeCat::Extend),
+    ('\u{aab7}', '\u{aab8}', GraphemeCat::Extend),
+    ('\u{aabe}', '\u{aabf}', GraphemeCat::Extend),
+    ('\u{aac1}', '\u{aac1}', GraphemeCat::Extend),
+    ('\u{aaeb}', '\u{aaeb}', GraphemeCat::SpacingMark),
+    ('\u{aaec}', '\u{aaed}', GraphemeCat::Extend),
+    ('\u{aaee}', '\u{aaef}', GraphemeCat::SpacingMark),
+    ('\u{aaf5}', '\u{aaf5}', GraphemeCat::SpacingMark),
+    ('\u{aaf6}', '\u{aaf6}', GraphemeCat::Extend),
+    ('\u{abe3}', '\u{abe4}', GraphemeCat::SpacingMark),
+    ('\u{abe5}', '\u{abe5}', GraphemeCat::Extend),
+    ('\u{abe6}', '\u{abe7}', GraphemeCat::SpacingMark),
+    ('\u{abe8}', '\u{abe8}', GraphemeCat::Extend),
+    ('\u{abe9}', '\u{abea}', GraphemeCat::SpacingMark),
+    ('\u{abec}', '\u{abec}', GraphemeCat::SpacingMark),
+    ('\u{abed}', '\u{abed}', GraphemeCat::Extend),
+    ('\u{ac00}', '\u{ac00}', GraphemeCat::LV),
+    ('\u{ac01}', '\u{ac1b}', GraphemeCat::LVT),
+    ('\u{ac1c}', '\u{ac1c}', GraphemeCat::LV),
+    ('\u{ac1d}', '\u{ac37}', GraphemeCat::LVT),
+    ('\u{ac38}', '\u{ac38}', GraphemeCat::LV),
+    ('\u{ac39}', '\u{ac53}', GraphemeCat::LVT),
+    ('\u{ac54}', '\u{ac54}', GraphemeCat::LV),
+    ('\u{ac55}', '\u{ac6f}', GraphemeCat::LVT),
+    ('\u{ac70}', '\u{ac70}', GraphemeCat::LV),
+    ('\u{ac71}', '\u{ac8b}', GraphemeCat::LVT),
+    ('\u{ac8c}', '\u{ac8c}', GraphemeCat::LV),
+    ('\u{ac8d}', '\u{aca7}', GraphemeCat::LVT),
+    ('\u{aca8}', '\u{aca8}', GraphemeCat::LV),
+    ('\u{aca9}', '\u{acc3}', GraphemeCat::LVT),
+    ('\u{acc4}', '\u{acc4}', GraphemeCat::LV),
+    ('\u{acc5}', '\u{acdf}', GraphemeCat::LVT),
+    ('\u{ace0}', '\u{ace0}', GraphemeCat::LV),
+    ('\u{ace1}', '\u{acfb}', GraphemeCat::LVT),
+    ('\u{acfc}', '\u{acfc}', GraphemeCat::LV),
+    ('\u{acfd}', '\u{ad17}', GraphemeCat::LVT),
+    ('\u{ad18}', '\u{ad18}', GraphemeCat::LV),
+    ('\u{ad19}', '\u{ad33}', GraphemeCat::LVT),
+    ('\u{ad34}', '\u{ad34}', GraphemeCat::LV),
+    ('\u{ad35}', '\u{ad4f}', GraphemeCat::LVT),
+    ('\u{ad50}', '\u{ad50}', GraphemeCat::LV),
+    ('\u{ad51}', '\u{ad6b}', GraphemeCat::LVT),
+    ('\u{ad6c}', '\u{ad6c}', GraphemeCat::LV),
+    ('\u{ad6d}', '\u{ad87}', GraphemeCat::LVT),
+    ('\u{ad88}', '\u{ad88}', GraphemeCat::LV),
+    ('\u{ad89}', '\u{ada3}', GraphemeCat::LVT),
+    ('\u{ada4}', '\u{ada4}', GraphemeCat::LV),
+    ('\u{ada5}', '\u{adbf}', GraphemeCat::LVT),
+    ('\u{adc0}', '\u{adc0}', GraphemeCat::LV),
+    ('\u{adc1}', '\u{addb}', GraphemeCat::LVT),
+    ('\u{addc}', '\u{addc}', GraphemeCat::LV),
+    ('\u{addd}', '\u{adf7}', GraphemeCat::LVT),
+    ('\u{adf8}', '\u{adf8}', GraphemeCat::LV),
+    ('\u{adf9}', '\u{ae13}', GraphemeCat::LVT),
+    ('\u{ae14}', '\u{ae14}', GraphemeCat::LV),
+    ('\u{ae15}', '\u{ae2f}', GraphemeCat::LVT),
+    ('\u{ae30}', '\u{ae30}', GraphemeCat::LV),
+    ('\u{ae31}', '\u{ae4b}', GraphemeCat::LVT),
+    ('\u{ae4c}', '\u{ae4c}', GraphemeCat::LV),
+    ('\u{ae4d}', '\u{ae67}', GraphemeCat::LVT),
+    ('\u{ae68}', '\u{ae68}', GraphemeCat::LV),
+    ('\u{ae69}', '\u{ae83}', GraphemeCat::LVT),
+    ('\u{ae84}', '\u{ae84}', GraphemeCat::LV),
+    ('\u{ae85}', '\u{ae9f}', GraphemeCat::LVT),
+    ('\u{aea0}', '\u{aea0}', GraphemeCat::LV),
+    ('\u{aea1}', '\u{aebb}', GraphemeCat::LVT),
+    ('\u{aebc}', '\u{aebc}', GraphemeCat::LV),
+    ('\u{aebd}', '\u{aed7}', GraphemeCat::LVT),
+    ('\u{aed8}', '\u{aed8}', GraphemeCat::LV),
+    ('\u{aed9}', '\u{aef3}', GraphemeCat::LVT),
+    ('\u{aef4}', '\u{aef4}', GraphemeCat::LV),
+    ('\u{aef5}', '\u{af0f}', GraphemeCat::LVT),
+    ('\u{af10}', '\u{af10}', GraphemeCat::LV),
+    ('\u{af11}', '\u{af2b}', GraphemeCat::LVT),
+    ('\u{af2c}', '\u{af2c}', GraphemeCat::LV),
+    ('\u{af2d}', '\u{af47}', GraphemeCat::LVT),
+    ('\u{af48}', '\u{af48}', GraphemeCat::LV),
+    ('\u{af49}', '\u{af63}', GraphemeCat::LVT),
+    ('\u{af64}', '\u{af64}', GraphemeCat::LV),
+    ('\u{af65}', '\u{af7f}', GraphemeCat::LVT),
+    ('\u{af80}', '\u{af80}', GraphemeCat::LV),
+    ('\u{af81}', '\u{af9b}', GraphemeCat::LVT),
+    ('\u{af9c}', '\u{af9c}', GraphemeCat::LV),
+    ('\u{af9d}', '\u{afb7}', GraphemeCat::LVT),
+    ('\u{afb8}', '\u{afb8}', GraphemeCat::LV),
+    ('\u{afb9}', '\u{afd3}', GraphemeCat::LVT),
+    ('\u{afd4}', '\u{afd4}', GraphemeCat::LV),
+    ('\u{afd5}', '\u{afef}', GraphemeCat::LVT),
+    ('\u{aff0}', '\u{aff0}', GraphemeCat::LV),
+    ('\u{aff1}', '\u{b00b}', GraphemeCat::LVT),
+    ('\u{b00c}', '\u{b00c}', GraphemeCat::LV),
+    ('\u{b00d}', '\u{b027}', GraphemeCat::LVT),
+    ('\u{b028}', '\u{b028}', GraphemeCat::LV),
+    ('\u{b029}', '\u{b043}', GraphemeCat::LVT),
+    ('\u{b044}', '\u{b044}', GraphemeCat::LV),
+    ('\u{b045}', '\u{b05f}', GraphemeCat::LVT),
+    ('\u{b060}', '\u{b060}', GraphemeCat::LV),
+    ('\u{b061}', '\u{b07b}', GraphemeCat::LVT),
+    ('\u{b07c}', '\u{b07c}', GraphemeCat::LV),
+    ('\u{b07d}', '\u{b097}', GraphemeCat::LVT),
+    ('\u{b098}', '\u{b098}', GraphemeCat::LV),
+    ('\u{b099}', '\u{b0b3}', GraphemeCat::LVT),
+    ('\u{b0b4}', '\u{b0b4}', GraphemeCat::LV),
+    ('\u{b0b5}', '\u{b0cf}', GraphemeCat::LVT),
+    ('\u{b0d0}', '\u{b0d0}', GraphemeCat::LV),
+    ('\u{b0d1}', '\u{b0eb}', GraphemeCat::LVT),
+    ('\u{b0ec}', '\u{b0ec}', GraphemeCat::LV),
+    ('\u{b0ed}', '\u{b107}', GraphemeCat::LVT),
+    ('\u{b108}', '\u{b108}', GraphemeCat::LV),
+    ('\u{b109}', '\u{b123}', GraphemeCat::LVT),
+    ('\u{b124}', '\u{b124}', GraphemeCat::LV),
+    ('\u{b125}', '\u{b13f}', GraphemeCat::LVT),
+    ('\u{b140}', '\u{b140}', GraphemeCat::LV),
+    ('\u{b141}', '\u{b15b}', GraphemeCat::LVT),
+    ('\u{b15c}', '\u{b15c}', GraphemeCat::LV),
+    ('\u{b15d}', '\u{b177}', GraphemeCat::LVT),
+    ('\u{b178}', '\u{b178}', GraphemeCat::LV),
+    ('\u{b179}', '\u{b193}', GraphemeCat::LVT),
+    ('\u{b194}', '\u{b194}', GraphemeCat::LV),
+    ('\u{b195}', '\u{b1af}', GraphemeCat::LVT),
+    ('\u{b1b0}', '\u{b1b0}', GraphemeCat::LV),
+    ('\u{b1b1}', '\u{b1cb}', GraphemeCat::LVT),
+    ('\u{b1cc}', '\u{b1cc}', GraphemeCat::LV),
+    ('\u{b1cd}', '\u{b1e7}', GraphemeCat::LVT),
+    ('\u{b1e8}', '\u{b1e8}', GraphemeCat::LV),
+    ('\u{b1e9}', '\u{b203}', GraphemeCat::LVT),
+    ('\u{b204}', '\u{b204}', GraphemeCat::LV),
+    ('\u{b205}', '\u{b21f}', GraphemeCat::LVT),
+    ('\u{b220}', '\u{b220}', GraphemeCat::LV),
+    ('\u{b221}', '\u{b23b}', GraphemeCat::LVT),
+    ('\u{b23c}', '\u{b23c}', GraphemeCat::LV),
+    ('\u{b23d}', '\u{b257}', GraphemeCat::LVT),
+    ('\u{b258}', '\u{b258}', GraphemeCat::LV),
+    ('\u{b259}', '\u{b273}', GraphemeCat::LVT),
+    ('\u{b274}', '\u{b274}', GraphemeCat::LV),
+    ('\u{b275}', '\u{b28f}', GraphemeCat::LVT),
+    ('\u{b290}', '\u{b290}', GraphemeCat::LV),
+    ('\u{b291}', '\u{b2ab}', GraphemeCat::LVT),
+    ('\u{b2ac}', '\u{b2ac}', GraphemeCat::LV),
+    ('\u{b2ad}', '\u{b2c7}', GraphemeCat::LVT),
+    ('\u{b2c8}', '\u{b2c8}', GraphemeCat::LV),
+    ('\u{b2c9}', '\u{b2e3}', GraphemeCat::LVT),
+    ('\u{b2e4}', '\u{b2e4}', GraphemeCat::LV),
+    ('\u{b2e5}', '\u{b2ff}', GraphemeCat::LVT),
+    ('\u{b300}', '\u{b300}', GraphemeCat::LV),
+    ('\u{b301}', '\u{b31b}', GraphemeCat::LVT),
+    ('\u{b31c}', '\u{b31c}', GraphemeCat::LV),
+    ('\u{b31d}', '\u{b337}', GraphemeCat::LVT),
+    ('\u{b338}', '\u{b338}', GraphemeCat::LV),
+    ('\u{b339}', '\u{b353}', GraphemeCat::LVT),
+    ('\u{b354}', '\u{b354}', GraphemeCat::LV),
+    ('\u{b355}', '\u{b36f}', GraphemeCat::LVT),
+    ('\u{b370}', '\u{b370}', GraphemeCat::LV),
+    ('\u{b371}', '\u{b38b}', GraphemeCat::LVT),
+    ('\u{b38c}', '\u{b38c}', GraphemeCat::LV),
+    ('\u{b38d}', '\u{b3a7}', GraphemeCat::LVT),
+    ('\u{b3a8}', '\u{b3a8}', GraphemeCat::LV),
+    ('\u{b3a9}', '\u{b3c3}', GraphemeCat::LVT),
+    ('\u{b3c4}', '\u{b3c4}', GraphemeCat::LV),
+    ('\u{b3c5}', '\u{b3df}', GraphemeCat::LVT),
+    ('\u{b3e0}', '\u{b3e0}', GraphemeCat::LV),
+    ('\u{b3e1}', '\u{b3fb}', GraphemeCat::LVT),
+    ('\u{b3fc}', '\u{b3fc}', GraphemeCat::LV),
+    ('\u{b3fd}', '\u{b417}', GraphemeCat::LVT),
+    ('\u{b418}', '\u{b418}', GraphemeCat::LV),
+    ('\u{b419}', '\u{b433}', GraphemeCat::LVT),
+    ('\u{b434}', '\u{b434}', GraphemeCat::LV),
+    ('\u{b435}', '\u{b44f}', GraphemeCat::LVT),
+    ('\u{b450}', '\u{b450}', GraphemeCat::LV),
+    ('\u{b451}', '\u{b46b}', GraphemeCat::LVT),
+    ('\u{b46c}', '\u{b46c}', GraphemeCat::LV),
+    ('\u{b46d}', '\u{b487}', GraphemeCat::LVT),
+    ('\u{b488}', '\u{b488}', GraphemeCat::LV),
+    ('\u{b489}', '\u{b4a3}', GraphemeCat::LVT),
+    ('\u{b4a4}', '\u{b4a4}', GraphemeCat::LV),
+    ('\u{b4a5}', '\u{b4bf}', GraphemeCat::LVT),
+    ('\u{b4c0}', '\u{b4c0}', GraphemeCat::LV),
+    ('\u{b4c1}', '\u{b4db}', GraphemeCat::LVT),
+    ('\u{b4dc}', '\u{b4dc}', GraphemeCat::LV),
+    ('\u{b4dd}', '\u{b4f7}', GraphemeCat::LVT),
+    ('\u{b4f8}', '\u{b4f8}', GraphemeCat::LV),
+    ('\u{b4f9}', '\u{b513}', GraphemeCat::LVT),
+    ('\u{b514}', '\u{b514}', GraphemeCat::LV),
+    ('\u{b515}', '\u{b52f}', GraphemeCat::LVT),
+    ('\u{b530}', '\u{b530}', GraphemeCat::LV),
+    ('\u{b531}', '\u{b54b}', GraphemeCat::LVT),
+    ('\u{b54c}', '\u{b54c}', GraphemeCat::LV),
+    ('\u{b54d}', '\u{b567}', GraphemeCat::LVT),
+    ('\u{b568}', '\u{b568}', GraphemeCat::LV),
+    ('\u{b569}', '\u{b583}', GraphemeCat::LVT),
+    ('\u{b584}', '\u{b584}', GraphemeCat::LV),
+    ('\u{b585}', '\u{b59f}', GraphemeCat::LVT),
+    ('\u{b5a0}', '\u{b5a0}', GraphemeCat::LV),
+    ('\u{b5a1}', '\u{b5bb}', GraphemeCat::LVT),
+    ('\u{b5bc}', '\u{b5bc}', GraphemeCat::LV),
+    ('\u{b5bd}', '\u{b5d7}', GraphemeCat::LVT),
+    ('\u{b5d8}', '\u{b5d8}', GraphemeCat::LV),
+    ('\u{b5d9}', '\u{b5f3}', GraphemeCat::LVT),
+    ('\u{b5f4}', '\u{b5f4}', GraphemeCat::LV),
+    ('\u{b5f5}', '\u{b60f}', GraphemeCat::LVT),
+    ('\u{b610}', '\u{b610}', GraphemeCat::LV),
+    ('\u{b611}', '\u{b62b}', GraphemeCat::LVT),
+    ('\u{b62c}', '\u{b62c}', GraphemeCat::LV),
+    ('\u{b62d}', '\u{b647}', GraphemeCat::LVT),
+    ('\u{b648}', '\u{b648}', GraphemeCat::LV),
+    ('\u{b649}', '\u{b663}', GraphemeCat::LVT),
+    ('\u{b664}', '\u{b664}', GraphemeCat::LV),
+    ('\u{b665}', '\u{b67f}', GraphemeCat::LVT),
+    ('\u{b680}', '\u{b680}', GraphemeCat::LV),
+    ('\u{b681}', '\u{b69b}', GraphemeCat::LVT),
+    ('\u{b69c}', '\u{b69c}', GraphemeCat::LV),
+    ('\u{b69d}', '\u{b6b7}', GraphemeCat::LVT),
+    ('\u{b6b8}', '\u{b6b8}', GraphemeCat::LV),
+    ('\u{b6b9}', '\u{b6d3}', GraphemeCat::LVT),
+    ('\u{b6d4}', '\u{b6d4}', GraphemeCat::LV),
+    ('\u{b6d5}', '\u{b6ef}', GraphemeCat::LVT),
+    ('\u{b6f0}', '\u{b6f0}', GraphemeCat::LV),
+    ('\u{b6f1}', '\u{b70b}', GraphemeCat::LVT),
+    ('\u{b70c}', '\u{b70c}', GraphemeCat::LV),
+    ('\u{b70d}', '\u{b727}', GraphemeCat::LVT),
+    ('\u{b728}', '\u{b728}', GraphemeCat::LV),
+    ('\u{b729}', '\u{b743}', GraphemeCat::LVT),
+    ('\u{b744}', '\u{b744}', GraphemeCat::LV),
+    ('\u{b745}', '\u{b75f}', GraphemeCat::LVT),
+    ('\u{b760}', '\u{b760}', GraphemeCat::LV),
+    ('\u{b761}', '\u{b77b}', GraphemeCat::LVT),
+    ('\u{b77c}', '\u{b77c}', GraphemeCat::LV),
+    ('\u{b77d}', '\u{b797}', GraphemeCat::LVT),
+    ('\u{b798}', '\u{b798}', GraphemeCat::LV),
+    ('\u{b799}', '\u{b7b3}', GraphemeCat::LVT),
+    ('\u{b7b4}', '\u{b7b4}', GraphemeCat::LV),
+    ('\u{b7b5}', '\u{b7cf}', GraphemeCat::LVT),
+    ('\u{b7d0}', '\u{b7d0}', GraphemeCat::LV),
+    ('\u{b7d1}', '\u{b7eb}', GraphemeCat::LVT),
+    ('\u{b7ec}', '\u{b7ec}', GraphemeCat::LV),
+    ('\u{b7ed}', '\u{b807}', GraphemeCat::LVT),
+    ('\u{b808}', '\u{b808}', GraphemeCat::LV),
+    ('\u{b809}', '\u{b823}', GraphemeCat::LVT),
+    ('\u{b824}', '\u{b824}', GraphemeCat::LV),
+    ('\u{b825}', '\u{b83f}', GraphemeCat::LVT),
+    ('\u{b840}', '\u{b840}', GraphemeCat::LV),
+    ('\u{b841}', '\u{b85b}', GraphemeCat::LVT),
+    ('\u{b85c}', '\u{b85c}', GraphemeCat::LV),
+    ('\u{b85d}', '\u{b877}', GraphemeCat::LVT),
+    ('\u{b878}', '\u{b878}', GraphemeCat::LV),
+    ('\u{b879}', '\u{b893}', GraphemeCat::LVT),
+    ('\u{b894}', '\u{b894}', GraphemeCat::LV),
+    ('\u{b895}', '\u{b8af}', GraphemeCat::LVT),
+    ('\u{b8b0}', '\u{b8b0}', GraphemeCat::LV),
+    ('\u{b8b1}', '\u{b8cb}', GraphemeCat::LVT),
+    ('\u{b8cc}', '\u{b8cc}', GraphemeCat::LV),
+    ('\u{b8cd}', '\u{b8e7}', GraphemeCat::LVT),
+    ('\u{b8e8}', '\u{b8e8}', GraphemeCat::LV),
+    ('\u{b8e9}', '\u{b903}', GraphemeCat::LVT),
+    ('\u{b904}', '\u{b904}', GraphemeCat::LV),
+    ('\u{b905}', '\u{b91f}', GraphemeCat::LVT),
+    ('\u{b920}', '\u{b920}', GraphemeCat::LV),
+    ('\u{b921}', '\u{b93b}', GraphemeCat::LVT),
+    ('\u{b93c}', '\u{b93c}', GraphemeCat::LV),
+    ('\u{b93d}', '\u{b957}', GraphemeCat::LVT),
+    ('\u{b958}', '\u{b958}', GraphemeCat::LV),
+    ('\u{b959}', '\u{b973}', GraphemeCat::LVT),
+    ('\u{b974}', '\u{b974}', GraphemeCat::LV),
+    ('\u{b975}', '\u{b98f}', GraphemeCat::LVT),
+    ('\u{b990}', '\u{b990}', GraphemeCat::LV),
+    ('\u{b991}', '\u{b9ab}', GraphemeCat::LVT),
+    ('\u{b9ac}', '\u{b9ac}', GraphemeCat::LV),
+    ('\u{b9ad}', '\u{b9c7}', GraphemeCat::LVT),
+    ('\u{b9c8}', '\u{b9c8}', GraphemeCat::LV),
+    ('\u{b9c9}', '\u{b9e3}', GraphemeCat::LVT),
+    ('\u{b9e4}', '\u{b9e4}', GraphemeCat::LV),
+    ('\u{b9e5}', '\u{b9ff}', GraphemeCat::LVT),
+    ('\u{ba00}', '\u{ba00}', GraphemeCat::LV),
+    ('\u{ba01}', '\u{ba1b}', GraphemeCat::LVT),
+    ('\u{ba1c}', '\u{ba1c}', GraphemeCat::LV),
+    ('\u{ba1d}', '\u{ba37}', GraphemeCat::LVT),
+    ('\u{ba38}', '\u{ba38}', GraphemeCat::LV),
+    ('\u{ba39}', '\u{ba53}', GraphemeCat::LVT),
+    ('\u{ba54}', '\u{ba54}', GraphemeCat::LV),
+    ('\u{ba55}', '\u{ba6f}', GraphemeCat::LVT),
+    ('\u{ba70}', '\u{ba70}', GraphemeCat::LV),
+    ('\u{ba71}', '\u{ba8b}', GraphemeCat::LVT),
+    ('\u{ba8c}', '\u{ba8c}', GraphemeCat::LV),
+    ('\u{ba8d}', '\u{baa7}', GraphemeCat::LVT),
+    ('\u{baa8}', '\u{baa8}', GraphemeCat::LV),
+    ('\u{baa9}', '\u{bac3}', GraphemeCat::LVT),
+    ('\u{bac4}', '\u{bac4}', GraphemeCat::LV),
+    ('\u{bac5}', '\u{badf}', GraphemeCat::LVT),
+    ('\u{bae0}', '\u{bae0}', GraphemeCat::LV),
+    ('\u{bae1}', '\u{bafb}', GraphemeCat::LVT),
+    ('\u{bafc}', '\u{bafc}', GraphemeCat::LV),
+    ('\u{bafd}', '\u{bb17}', GraphemeCat::LVT),
+    ('\u{bb18}', '\u{bb18}', GraphemeCat::LV),
+    ('\u{bb19}', '\u{bb33}', GraphemeCat::LVT),
+    ('\u{bb34}', '\u{bb34}', GraphemeCat::LV),
+    ('\u{bb35}', '\u{bb4f}', GraphemeCat::LVT),
+    ('\u{bb50}', '\u{bb50}', GraphemeCat::LV),
+    ('\u{bb51}', '\u{bb6b}', GraphemeCat::LVT),
+    ('\u{bb6c}', '\u{bb6c}', GraphemeCat::LV),
+    ('\u{bb6d}', '\u{bb87}', GraphemeCat::LVT),
+    ('\u{bb88}', '\u{bb88}', GraphemeCat::LV),
+    ('\u{bb89}', '\u{bba3}', GraphemeCat::LVT),
+    ('\u{bba4}', '\u{bba4}', GraphemeCat::LV),
+    ('\u{bba5}', '\u{bbbf}', GraphemeCat::LVT),
+    ('\u{bbc0}', '\u{bbc0}', GraphemeCat::LV),
+    ('\u{bbc1}', '\u{bbdb}', GraphemeCat::LVT),
+    ('\u{bbdc}', '\u{bbdc}', GraphemeCat::LV),
+    ('\u{bbdd}', '\u{bbf7}', GraphemeCat::LVT),
+    ('\u{bbf8}', '\u{bbf8}', GraphemeCat::LV),
+    ('\u{bbf9}', '\u{bc13}', GraphemeCat::LVT),
+    ('\u{bc14}', '\u{bc14}', GraphemeCat::LV),
+    ('\u{bc15}', '\u{bc2f}', GraphemeCat::LVT),
+    ('\u{bc30}', '\u{bc30}', GraphemeCat::LV),
+    ('\u{bc31}', '\u{bc4b}', GraphemeCat::LVT),
+    ('\u{bc4c}', '\u{bc4c}', GraphemeCat::LV),
+    ('\u{bc4d}', '\u{bc67}', GraphemeCat::LVT),
+    ('\u{bc68}', '\u{bc68}', GraphemeCat::LV),
+    ('\u{bc69}', '\u{bc83}', GraphemeCat::LVT),
+    ('\u{bc84}', '\u{bc84}', GraphemeCat::LV),
+    ('\u{bc85}', '\u{bc9f}', GraphemeCat::LVT),
+    ('\u{bca0}', '\u{bca0}', GraphemeCat::LV),
+    ('\u{bca1}', '\u{bcbb}', GraphemeCat::LVT),
+    ('\u{bcbc}', '\u{bcbc}', GraphemeCat::LV),
+    ('\u{bcbd}', '\u{bcd7}', GraphemeCat::LVT),
+    ('\u{bcd8}', '\u{bcd8}', GraphemeCat::LV),
+    ('\u{bcd9}', '\u{bcf3}', GraphemeCat::LVT),
+    ('\u{bcf4}', '\u{bcf4}', GraphemeCat::LV),
+    ('\u{bcf5}', '\u{bd0f}', GraphemeCat::LVT),
+    ('\u{bd10}', '\u{bd10}', GraphemeCat::LV),
+    ('\u{bd11}', '\u{bd2b}', GraphemeCat::LVT),
+    ('\u{bd2c}', '\u{bd2c}', GraphemeCat::LV),
+    ('\u{bd2d}', '\u{bd47}', GraphemeCat::LVT),
+    ('\u{bd48}', '\u{bd48}', GraphemeCat::LV),
+    ('\u{bd49}', '\u{bd63}', GraphemeCat::LVT),
+    ('\u{bd64}', '\u{bd64}', GraphemeCat::LV),
+    ('\u{bd65}', '\u{bd7f}', GraphemeCat::LVT),
+    ('\u{bd80}', '\u{bd80}', GraphemeCat::LV),
+    ('\u{bd81}', '\u{bd9b}', GraphemeCat::LVT),
+    ('\u{bd9c}', '\u{bd9c}', GraphemeCat::LV),
+    ('\u{bd9d}', '\u{bdb7}', GraphemeCat::LVT),
+    ('\u{bdb8}', '\u{bdb8}', GraphemeCat::LV),
+    ('\u{bdb9}', '\u{bdd3}', GraphemeCat::LVT),
+    ('\u{bdd4}', '\u{bdd4}', GraphemeCat::LV),
+    ('\u{bdd5}', '\u{bdef}', GraphemeCat::LVT),
+    ('\u{bdf0}', '\u{bdf0}', GraphemeCat::LV),
+    ('\u{bdf1}', '\u{be0b}', GraphemeCat::LVT),
+    ('\u{be0c}', '\u{be0c}', GraphemeCat::LV),
+    ('\u{be0d}', '\u{be27}', GraphemeCat::LVT),
+    ('\u{be28}', '\u{be28}', GraphemeCat::LV),
+    ('\u{be29}', '\u{be43}', GraphemeCat::LVT),
+    ('\u{be44}', '\u{be44}', GraphemeCat::LV),
+    ('\u{be45}', '\u{be5f}', GraphemeCat::LVT),
+    ('\u{be60}', '\u{be60}', GraphemeCat::LV),
+    ('\u{be61}', '\u{be7b}', GraphemeCat::LVT),
+    ('\u{be7c}', '\u{be7c}', GraphemeCat::LV),
+    ('\u{be7d}', '\u{be97}', GraphemeCat::LVT),
+    ('\u{be98}', '\u{be98}', GraphemeCat::LV),
+    ('\u{be99}', '\u{beb3}', GraphemeCat::LVT),
+    ('\u{beb4}', '\u{beb4}', GraphemeCat::LV),
+    ('\u{beb5}', '\u{becf}', GraphemeCat::LVT),
+    ('\u{bed0}', '\u{bed0}', GraphemeCat::LV),
+    ('\u{bed1}', '\u{beeb}', GraphemeCat::LVT),
+    ('\u{beec}', '\u{beec}', GraphemeCat::LV),
+    ('\u{beed}', '\u{bf07}', GraphemeCat::LVT),
+    ('\u{bf08}', '\u{bf08}', GraphemeCat::LV),
+    ('\u{bf09}', '\u{bf23}', GraphemeCat::LVT),
+    ('\u{bf24}', '\u{bf24}', GraphemeCat::LV),
+    ('\u{bf25}', '\u{bf3f}', GraphemeCat::LVT),
+    ('\u{bf40}', '\u{bf40}', GraphemeCat::LV),
+    ('\u{bf41}', '\u{bf5b}', GraphemeCat::LVT),
+    ('\u{bf5c}', '\u{bf5c}', GraphemeCat::LV),
+    ('\u{bf5d}', '\u{bf77}', GraphemeCat::LVT),
+    ('\u{bf78}', '\u{bf78}', GraphemeCat::LV),
+    ('\u{bf79}', '\u{bf93}', GraphemeCat::LVT),
+    ('\u{bf94}', '\u{bf94}', GraphemeCat::LV),
+    ('\u{bf95}', '\u{bfaf}', GraphemeCat::LVT),
+    ('\u{bfb0}', '\u{bfb0}', GraphemeCat::LV),
+    ('\u{bfb1}', '\u{bfcb}', GraphemeCat::LVT),
+    ('\u{bfcc}', '\u{bfcc}', GraphemeCat::LV),
+    ('\u{bfcd}', '\u{bfe7}', GraphemeCat::LVT),
+    ('\u{bfe8}', '\u{bfe8}', GraphemeCat::LV),
+    ('\u{bfe9}', '\u{c003}', GraphemeCat::LVT),
+    ('\u{c004}', '\u{c004}', GraphemeCat::LV),
+    ('\u{c005}', '\u{c01f}', GraphemeCat::LVT),
+    ('\u{c020}', '\u{c020}', GraphemeCat::LV),
+    ('\u{c021}', '\u{c03b}', GraphemeCat::LVT),
+    ('\u{c03c}', '\u{c03c}', GraphemeCat::LV),
+    ('\u{c03d}', '\u{c057}', GraphemeCat::LVT),
+    ('\u{c058}', '\u{c058}', GraphemeCat::LV),
+    ('\u{c059}', '\u{c073}', GraphemeCat::LVT),
+    ('\u{c074}', '\u{c074}', GraphemeCat::LV),
+    ('\u{c075}', '\u{c08f}', GraphemeCat::LVT),
+    ('\u{c090}', '\u{c090}', GraphemeCat::LV),
+    ('\u{c091}', '\u{c0ab}', GraphemeCat::LVT),
+    ('\u{c0ac}', '\u{c0ac}', GraphemeCat::LV),
+    ('\u{c0ad}', '\u{c0c7}', GraphemeCat::LVT),
+    ('\u{c0c8}', '\u{c0c8}', GraphemeCat::LV),
+    ('\u{c0c9}', '\u{c0e3}', GraphemeCat::LVT),
+    ('\u{c0e4}', '\u{c0e4}', GraphemeCat::LV),
+    ('\u{c0e5}', '\u{c0ff}', GraphemeCat::LVT),
+    ('\u{c100}', '\u{c100}', GraphemeCat::LV),
+    ('\u{c101}', '\u{c11b}', GraphemeCat::LVT),
+    ('\u{c11c}', '\u{c11c}', GraphemeCat::LV),
+    ('\u{c11d}', '\u{c137}', GraphemeCat::LVT),
+    ('\u{c138}', '\u{c138}', GraphemeCat::LV),
+    ('\u{c139}', '\u{c153}', GraphemeCat::LVT),
+    ('\u{c154}', '\u{c154}', GraphemeCat::LV),
+    ('\u{c155}', '\u{c16f}', GraphemeCat::LVT),
+    ('\u{c170}', '\u{c170}', GraphemeCat::LV),
+    ('\u{c171}', '\u{c18b}', GraphemeCat::LVT),
+    ('\u{c18c}', '\u{c18c}', GraphemeCat::LV),
+    ('\u{c18d}', '\u{c1a7}', GraphemeCat::LVT),
+    ('\u{c1a8}', '\u{c1a8}', GraphemeCat::LV),
+    ('\u{c1a9}', '\u{c1c3}', GraphemeCat::LVT),
+    ('\u{c1c4}', '\u{c1c4}', GraphemeCat::LV),
+    ('\u{c1c5}', '\u{c1df}', GraphemeCat::LVT),
+    ('\u{c1e0}', '\u{c1e0}', GraphemeCat::LV),
+    ('\u{c1e1}', '\u{c1fb}', GraphemeCat::LVT),
+    ('\u{c1fc}', '\u{c1fc}', GraphemeCat::LV),
+    ('\u{c1fd}', '\u{c217}', GraphemeCat::LVT),
+    ('\u{c218}', '\u{c218}', GraphemeCat::LV),
+    ('\u{c219}', '\u{c233}', GraphemeCat::LVT),
+    ('\u{c234}', '\u{c234}', GraphemeCat::LV),
+    ('\u{c235}', '\u{c24f}', GraphemeCat::LVT),
+    ('\u{c250}', '\u{c250}', GraphemeCat::LV),
+    ('\u{c251}', '\u{c26b}', GraphemeCat::LVT),
+    ('\u{c26c}', '\u{c26c}', GraphemeCat::LV),
+    ('\u{c26d}', '\u{c287}', GraphemeCat::LVT),
+    ('\u{c288}', '\u{c288}', GraphemeCat::LV),
+    ('\u{c289}', '\u{c2a3}', GraphemeCat::LVT),
+    ('\u{c2a4}', '\u{c2a4}', GraphemeCat::LV),
+    ('\u{c2a5}', '\u{c2bf}', GraphemeCat::LVT),
+    ('\u{c2c0}', '\u{c2c0}', GraphemeCat::LV),
+    ('\u{c2c1}', '\u{c2db}', GraphemeCat::LVT),
+    ('\u{c2dc}', '\u{c2dc}', GraphemeCat::LV),
+    ('\u{c2dd}', '\u{c2f7}', GraphemeCat::LVT),
+    ('\u{c2f8}', '\u{c2f8}', GraphemeCat::LV),
+    ('\u{c2f9}', '\u{c313}', GraphemeCat::LVT),
+    ('\u{c314}', '\u{c314}', GraphemeCat::LV),
+    ('\u{c315}', '\u{c32f}', GraphemeCat::LVT),
+    ('\u{c330}', '\u{c330}', GraphemeCat::LV),
+    ('\u{c331}', '\u{c34b}', GraphemeCat::LVT),
+    ('\u{c34c}', '\u{c34c}', GraphemeCat::LV),
+    ('\u{c34d}', '\u{c367}', GraphemeCat::LVT),
+    ('\u{c368}', '\u{c368}', GraphemeCat::LV),
+    ('\u{c369}', '\u{c383}', GraphemeCat::LVT),
+    ('\u{c384}', '\u{c384}', GraphemeCat::LV),
+    ('\u{c385}', '\u{c39f}', GraphemeCat::LVT),
+    ('\u{c3a0}', '\u{c3a0}', GraphemeCat::LV),
+    ('\u{c3a1}', '\u{c3bb}', GraphemeCat::LVT),
+    ('\u{c3bc}', '\u{c3bc}', GraphemeCat::LV),
+    ('\u{c3bd}', '\u{c3d7}', GraphemeCat::LVT),
+    ('\u{c3d8}', '\u{c3d8}', GraphemeCat::LV),
+    ('\u{c3d9}', '\u{c3f3}', GraphemeCat::LVT),
+    ('\u{c3f4}', '\u{c3f4}', GraphemeCat::LV),
+    ('\u{c3f5}', '\u{c40f}', GraphemeCat::LVT),
+    ('\u{c410}', '\u{c410}', GraphemeCat::LV),
+    ('\u{c411}', '\u{c42b}', GraphemeCat::LVT),
+    ('\u{c42c}', '\u{c42c}', GraphemeCat::LV),
+    ('\u{c42d}', '\u{c447}', GraphemeCat::LVT),
+    ('\u{c448}', '\u{c448}', GraphemeCat::LV),
+    ('\u{c449}', '\u{c463}', GraphemeCat::LVT),
+    ('\u{c464}', '\u{c464}', GraphemeCat::LV),
+    ('\u{c465}', '\u{c47f}', GraphemeCat::LVT),
+    ('\u{c480}', '\u{c480}', GraphemeCat::LV),
+    ('\u{c481}', '\u{c49b}', GraphemeCat::LVT),
+    ('\u{c49c}', '\u{c49c}', GraphemeCat::LV),
+    ('\u{c49d}', '\u{c4b7}', GraphemeCat::LVT),
+    ('\u{c4b8}', '\u{c4b8}', GraphemeCat::LV),
+    ('\u{c4b9}', '\u{c4d3}', GraphemeCat::LVT),
+    ('\u{c4d4}', '\u{c4d4}', GraphemeCat::LV),
+    ('\u{c4d5}', '\u{c4ef}', GraphemeCat::LVT),
+    ('\u{c4f0}', '\u{c4f0}', GraphemeCat::LV),
+    ('\u{c4f1}', '\u{c50b}', GraphemeCat::LVT),
+    ('\u{c50c}', '\u{c50c}', GraphemeCat::LV),
+    ('\u{c50d}', '\u{c527}', GraphemeCat::LVT),
+    ('\u{c528}', '\u{c528}', GraphemeCat::LV),
+    ('\u{c529}', '\u{c543}', GraphemeCat::LVT),
+    ('\u{c544}', '\u{c544}', GraphemeCat::LV),
+    ('\u{c545}', '\u{c55f}', GraphemeCat::LVT),
+    ('\u{c560}', '\u{c560}', GraphemeCat::LV),
+    ('\u{c561}', '\u{c57b}', GraphemeCat::LVT),
+    ('\u{c57c}', '\u{c57c}', GraphemeCat::LV),
+    ('\u{c57d}', '\u{c597}', GraphemeCat::LVT),
+    ('\u{c598}', '\u{c598}', GraphemeCat::LV),
+    ('\u{c599}', '\u{c5b3}', GraphemeCat::LVT),
+    ('\u{c5b4}', '\u{c5b4}', GraphemeCat::LV),
+    ('\u{c5b5}', '\u{c5cf}', GraphemeCat::LVT),
+    ('\u{c5d0}', '\u{c5d0}', GraphemeCat::LV),
+    ('\u{c5d1}', '\u{c5eb}', GraphemeCat::LVT),
+    ('\u{c5ec}', '\u{c5ec}', GraphemeCat::LV),
+    ('\u{c5ed}', '\u{c607}', GraphemeCat::LVT),
+    ('\u{c608}', '\u{c608}', GraphemeCat::LV),
+    ('\u{c609}', '\u{c623}', GraphemeCat::LVT),
+    ('\u{c624}', '\u{c624}', GraphemeCat::LV),
+    ('\u{c625}', '\u{c63f}', GraphemeCat::LVT),
+    ('\u{c640}', '\u{c640}', GraphemeCat::LV),
+    ('\u{c641}', '\u{c65b}', GraphemeCat::LVT),
+    ('\u{c65c}', '\u{c65c}', GraphemeCat::LV),
+    ('\u{c65d}', '\u{c677}', GraphemeCat::LVT),
+    ('\u{c678}', '\u{c678}', GraphemeCat::LV),
+    ('\u{c679}', '\u{c693}', GraphemeCat::LVT),
+    ('\u{c694}', '\u{c694}', GraphemeCat::LV),
+    ('\u{c695}', '\u{c6af}', GraphemeCat::LVT),
+    ('\u{c6b0}', '\u{c6b0}', GraphemeCat::LV),
+    ('\u{c6b1}', '\u{c6cb}', GraphemeCat::LVT),
+    ('\u{c6cc}', '\u{c6cc}', GraphemeCat::LV),
+    ('\u{c6cd}', '\u{c6e7}', GraphemeCat::LVT),
+    ('\u{c6e8}', '\u{c6e8}', GraphemeCat::LV),
+    ('\u{c6e9}', '\u{c703}', GraphemeCat::LVT),
+    ('\u{c704}', '\u{c704}', GraphemeCat::LV),
+    ('\u{c705}', '\u{c71f}', GraphemeCat::LVT),
+    ('\u{c720}', '\u{c720}', GraphemeCat::LV),
+    ('\u{c721}', '\u{c73b}', GraphemeCat::LVT),
+    ('\u{c73c}', '\u{c73c}', GraphemeCat::LV),
+    ('\u{c73d}', '\u{c757}', GraphemeCat::LVT),
+    ('\u{c758}', '\u{c758}', GraphemeCat::LV),
+    ('\u{c759}', '\u{c773}', GraphemeCat::LVT),
+    ('\u{c774}', '\u{c774}', GraphemeCat::LV),
+    ('\u{c775}', '\u{c78f}', GraphemeCat::LVT),
+    ('\u{c790}', '\u{c790}', GraphemeCat::LV),
+    ('\u{c791}', '\u{c7ab}', GraphemeCat::LVT),
+    ('\u{c7ac}', '\u{c7ac}', GraphemeCat::LV),
+    ('\u{c7ad}', '\u{c7c7}', GraphemeCat::LVT),
+    ('\u{c7c8}', '\u{c7c8}', GraphemeCat::LV),
+    ('\u{c7c9}', '\u{c7e3}', GraphemeCat::LVT),
+    ('\u{c7e4}', '\u{c7e4}', GraphemeCat::LV),
+    ('\u{c7e5}', '\u{c7ff}', GraphemeCat::LVT),
+    ('\u{c800}', '\u{c800}', GraphemeCat::LV),
+    ('\u{c801}', '\u{c81b}', GraphemeCat::LVT),
+    ('\u{c81c}', '\u{c81c}', GraphemeCat::LV),
+    ('\u{c81d}', '\u{c837}', GraphemeCat::LVT),
+    ('\u{c838}', '\u{c838}', GraphemeCat::LV),
+    ('\u{c839}', '\u{c853}', GraphemeCat::LVT),
+    ('\u{c854}', '\u{c854}', GraphemeCat::LV),
+    ('\u{c855}', '\u{c86f}', GraphemeCat::LVT),
+    ('\u{c870}', '\u{c870}', GraphemeCat::LV),
+    ('\u{c871}', '\u{c88b}', GraphemeCat::LVT),
+    ('\u{c88c}', '\u{c88c}', GraphemeCat::LV),
+    ('\u{c88d}', '\u{c8a7}', GraphemeCat::LVT),
+    ('\u{c8a8}', '\u{c8a8}', GraphemeCat::LV),
+    ('\u{c8a9}', '\u{c8c3}', GraphemeCat::LVT),
+    ('\u{c8c4}', '\u{c8c4}', GraphemeCat::LV),
+    ('\u{c8c5}', '\u{c8df}', GraphemeCat::LVT),
+    ('\u{c8e0}', '\u{c8e0}', GraphemeCat::LV),
+    ('\u{c8e1}', '\u{c8fb}', GraphemeCat::LVT),
+    ('\u{c8fc}', '\u{c8fc}', GraphemeCat::LV),
+    ('\u{c8fd}', '\u{c917}', GraphemeCat::LVT),
+    ('\u{c918}', '\u{c918}', GraphemeCat::LV),
+    ('\u{c919}', '\u{c933}', GraphemeCat::LVT),
+    ('\u{c934}', '\u{c934}', GraphemeCat::LV),
+    ('\u{c935}', '\u{c94f}', GraphemeCat::LVT),
+    ('\u{c950}', '\u{c950}', GraphemeCat::LV),
+    ('\u{c951}', '\u{c96b}', GraphemeCat::LVT),
+    ('\u{c96c}', '\u{c96c}', GraphemeCat::LV),
+    ('\u{c96d}', '\u{c987}', GraphemeCat::LVT),
+    ('\u{c988}', '\u{c988}', GraphemeCat::LV),
+    ('\u{c989}', '\u{c9a3}', GraphemeCat::LVT),
+    ('\u{c9a4}', '\u{c9a4}', GraphemeCat::LV),
+    ('\u{c9a5}', '\u{c9bf}', GraphemeCat::LVT),
+    ('\u{c9c0}', '\u{c9c0}', GraphemeCat::LV),
+    ('\u{c9c1}', '\u{c9db}', GraphemeCat::LVT),
+    ('\u{c9dc}', '\u{c9dc}', GraphemeCat::LV),
+    ('\u{c9dd}', '\u{c9f7}', GraphemeCat::LVT),
+    ('\u{c9f8}', '\u{c9f8}', GraphemeCat::LV),
+    ('\u{c9f9}', '\u{ca13}', GraphemeCat::LVT),
+    ('\u{ca14}', '\u{ca14}', GraphemeCat::LV),
+    ('\u{ca15}', '\u{ca2f}', GraphemeCat::LVT),
+    ('\u{ca30}', '\u{ca30}', GraphemeCat::LV),
+    ('\u{ca31}', '\u{ca4b}', GraphemeCat::LVT),
+    ('\u{ca4c}', '\u{ca4c}', GraphemeCat::LV),
+    ('\u{ca4d}', '\u{ca67}', GraphemeCat::LVT),
+    ('\u{ca68}', '\u{ca68}', GraphemeCat::LV),
+    ('\u{ca69}', '\u{ca83}', GraphemeCat::LVT),
+    ('\u{ca84}', '\u{ca84}', GraphemeCat::LV),
+    ('\u{ca85}', '\u{ca9f}', GraphemeCat::LVT),
+    ('\u{caa0}', '\u{caa0}', GraphemeCat::LV),
+    ('\u{caa1}', '\u{cabb}', GraphemeCat::LVT),
+    ('\u{cabc}', '\u{cabc}', GraphemeCat::LV),
+    ('\u{cabd}', '\u{cad7}', GraphemeCat::LVT),
+    ('\u{cad8}', '\u{cad8}', GraphemeCat::LV),
+    ('\u{cad9}', '\u{caf3}', GraphemeCat::LVT),
+    ('\u{caf4}', '\u{caf4}', GraphemeCat::LV),
+    ('\u{caf5}', '\u{cb0f}', GraphemeCat::LVT),
+    ('\u{cb10}', '\u{cb10}', GraphemeCat::LV),
+    ('\u{cb11}', '\u{cb2b}', GraphemeCat::LVT),
+    ('\u{cb2c}', '\u{cb2c}', GraphemeCat::LV),
+    ('\u{cb2d}', '\u{cb47}', GraphemeCat::LVT),
+    ('\u{cb48}', '\u{cb48}', GraphemeCat::LV),
+    ('\u{cb49}', '\u{cb63}', GraphemeCat::LVT),
+    ('\u{cb64}', '\u{cb64}', GraphemeCat::LV),
+    ('\u{cb65}', '\u{cb7f}', GraphemeCat::LVT),
+    ('\u{cb80}', '\u{cb80}', GraphemeCat::LV),
+    ('\u{cb81}', '\u{cb9b}', GraphemeCat::LVT),
+    ('\u{cb9c}', '\u{cb9c}', GraphemeCat::LV),
+    ('\u{cb9d}', '\u{cbb7}', GraphemeCat::LVT),
+    ('\u{cbb8}', '\u{cbb8}', GraphemeCat::LV),
+    ('\u{cbb9}', '\u{cbd3}', GraphemeCat::LVT),
+    ('\u{cbd4}', '\u{cbd4}', GraphemeCat::LV),
+    ('\u{cbd5}', '\u{cbef}', GraphemeCat::LVT),
+    ('\u{cbf0}', '\u{cbf0}', GraphemeCat::LV),
+    ('\u{cbf1}', '\u{cc0b}', GraphemeCat::LVT),
+    ('\u{cc0c}', '\u{cc0c}', GraphemeCat::LV),
+    ('\u{cc0d}', '\u{cc27}', GraphemeCat::LVT),
+    ('\u{cc28}', '\u{cc28}', GraphemeCat::LV),
+    ('\u{cc29}', '\u{cc43}', GraphemeCat::LVT),
+    ('\u{cc44}', '\u{cc44}', GraphemeCat::LV),
+    ('\u{cc45}', '\u{cc5f}', GraphemeCat::LVT),
+    ('\u{cc60}', '\u{cc60}', GraphemeCat::LV),
+    ('\u{cc61}', '\u{cc7b}', GraphemeCat::LVT),
+    ('\u{cc7c}', '\u{cc7c}', GraphemeCat::LV),
+    ('\u{cc7d}', '\u{cc97}', GraphemeCat::LVT),
+    ('\u{cc98}', '\u{cc98}', GraphemeCat::LV),
+    ('\u{cc99}', '\u{ccb3}', GraphemeCat::LVT),
+    ('\u{ccb4}', '\u{ccb4}', GraphemeCat::LV),
+    ('\u{ccb5}', '\u{cccf}', GraphemeCat::LVT),
+    ('\u{ccd0}', '\u{ccd0}', GraphemeCat::LV),
+    ('\u{ccd1}', '\u{cceb}', GraphemeCat::LVT),
+    ('\u{ccec}', '\u{ccec}', GraphemeCat::LV),
+    ('\u{cced}', '\u{cd07}', GraphemeCat::LVT),
+    ('\u{cd08}', '\u{cd08}', GraphemeCat::LV),
+    ('\u{cd09}', '\u{cd23}', GraphemeCat::LVT),
+    ('\u{cd24}', '\u{cd24}', GraphemeCat::LV),
+    ('\u{cd25}', '\u{cd3f}', GraphemeCat::LVT),
+    ('\u{cd40}', '\u{cd40}', GraphemeCat::LV),
+    ('\u{cd41}', '\u{cd5b}', GraphemeCat::LVT),
+    ('\u{cd5c}', '\u{cd5c}', GraphemeCat::LV),
+    ('\u{cd5d}', '\u{cd77}', GraphemeCat::LVT),
+    ('\u{cd78}', '\u{cd78}', GraphemeCat::LV),
+    ('\u{cd79}', '\u{cd93}', GraphemeCat::LVT),
+    ('\u{cd94}', '\u{cd94}', GraphemeCat::LV),
+    ('\u{cd95}', '\u{cdaf}', GraphemeCat::LVT),
+    ('\u{cdb0}', '\u{cdb0}', GraphemeCat::LV),
+    ('\u{cdb1}', '\u{cdcb}', GraphemeCat::LVT),
+    ('\u{cdcc}', '\u{cdcc}', GraphemeCat::LV),
+    ('\u{cdcd}', '\u{cde7}', GraphemeCat::LVT),
+    ('\u{cde8}', '\u{cde8}', GraphemeCat::LV),
+    ('\u{cde9}', '\u{ce03}', GraphemeCat::LVT),
+    ('\u{ce04}', '\u{ce04}', GraphemeCat::LV),
+    ('\u{ce05}', '\u{ce1f}', GraphemeCat::LVT),
+    ('\u{ce20}', '\u{ce20}', GraphemeCat::LV),
+    ('\u{ce21}', '\u{ce3b}', GraphemeCat::LVT),
+    ('\u{ce3c}', '\u{ce3c}', GraphemeCat::LV),
+    ('\u{ce3d}', '\u{ce57}', GraphemeCat::LVT),
+    ('\u{ce58}', '\u{ce58}', GraphemeCat::LV),
+    ('\u{ce59}', '\u{ce73}', GraphemeCat::LVT),
+    ('\u{ce74}', '\u{ce74}', GraphemeCat::LV),
+    ('\u{ce75}', '\u{ce8f}', GraphemeCat::LVT),
+    ('\u{ce90}', '\u{ce90}', GraphemeCat::LV),
+    ('\u{ce91}', '\u{ceab}', GraphemeCat::LVT),
+    ('\u{ceac}', '\u{ceac}', GraphemeCat::LV),
+    ('\u{cead}', '\u{cec7}', GraphemeCat::LVT),
+    ('\u{cec8}', '\u{cec8}', GraphemeCat::LV),
+    ('\u{cec9}', '\u{cee3}', GraphemeCat::LVT),
+    ('\u{cee4}', '\u{cee4}', GraphemeCat::LV),
+    ('\u{cee5}', '\u{ceff}', GraphemeCat::LVT),
+    ('\u{cf00}', '\u{cf00}', GraphemeCat::LV),
+    ('\u{cf01}', '\u{cf1b}', GraphemeCat::LVT),
+    ('\u{cf1c}', '\u{cf1c}', GraphemeCat::LV),
+    ('\u{cf1d}', '\u{cf37}', GraphemeCat::LVT),
+    ('\u{cf38}', '\u{cf38}', GraphemeCat::LV),
+    ('\u{cf39}', '\u{cf53}', GraphemeCat::LVT),
+    ('\u{cf54}', '\u{cf54}', GraphemeCat::LV),
+    ('\u{cf55}', '\u{cf6f}', GraphemeCat::LVT),
+    ('\u{cf70}', '\u{cf70}', GraphemeCat::LV),
+    ('\u{cf71}', '\u{cf8b}', GraphemeCat::LVT),
+    ('\u{cf8c}', '\u{cf8c}', GraphemeCat::LV),
+    ('\u{cf8d}', '\u{cfa7}', GraphemeCat::LVT),
+    ('\u{cfa8}', '\u{cfa8}', GraphemeCat::LV),
+    ('\u{cfa9}', '\u{cfc3}', GraphemeCat::LVT),
+    ('\u{cfc4}', '\u{cfc4}', GraphemeCat::LV),
+    ('\u{cfc5}', '\u{cfdf}', GraphemeCat::LVT),
+    ('\u{cfe0}', '\u{cfe0}', GraphemeCat::LV),
+    ('\u{cfe1}', '\u{cffb}', GraphemeCat::LVT),
+    ('\u{cffc}', '\u{cffc}', GraphemeCat::LV),
+    ('\u{cffd}', '\u{d017}', GraphemeCat::LVT),
+    ('\u{d018}', '\u{d018}', GraphemeCat::LV),
+    ('\u{d019}', '\u{d033}', GraphemeCat::LVT),
+    ('\u{d034}', '\u{d034}', GraphemeCat::LV),
+    ('\u{d035}', '\u{d04f}', GraphemeCat::LVT),
+    ('\u{d050}', '\u{d050}', GraphemeCat::LV),
+    ('\u{d051}', '\u{d06b}', GraphemeCat::LVT),
+    ('\u{d06c}', '\u{d06c}', GraphemeCat::LV),
+    ('\u{d06d}', '\u{d087}', GraphemeCat::LVT),
+    ('\u{d088}', '\u{d088}', GraphemeCat::LV),
+    ('\u{d089}', '\u{d0a3}', GraphemeCat::LVT),
+    ('\u{d0a4}', '\u{d0a4}', GraphemeCat::LV),
+    ('\u{d0a5}', '\u{d0bf}', GraphemeCat::LVT),
+    ('\u{d0c0}', '\u{d0c0}', GraphemeCat::LV),
+    ('\u{d0c1}', '\u{d0db}', GraphemeCat::LVT),
+    ('\u{d0dc}', '\u{d0dc}', GraphemeCat::LV),
+    ('\u{d0dd}', '\u{d0f7}', GraphemeCat::LVT),
+    ('\u{d0f8}', '\u{d0f8}', GraphemeCat::LV),
+    ('\u{d0f9}', '\u{d113}', GraphemeCat::LVT),
+    ('\u{d114}', '\u{d114}', GraphemeCat::LV),
+    ('\u{d115}', '\u{d12f}', GraphemeCat::LVT),
+    ('\u{d130}', '\u{d130}', GraphemeCat::LV),
+    ('\u{d131}', '\u{d14b}', GraphemeCat::LVT),
+    ('\u{d14c}', '\u{d14c}', GraphemeCat::LV),
+    ('\u{d14d}', '\u{d167}', GraphemeCat::LVT),
+    ('\u{d168}', '\u{d168}', GraphemeCat::LV),
+    ('\u{d169}', '\u{d183}', GraphemeCat::LVT),
+    ('\u{d184}', '\u{d184}', GraphemeCat::LV),
+    ('\u{d185}', '\u{d19f}', GraphemeCat::LVT),
+    ('\u{d1a0}', '\u{d1a0}', GraphemeCat::LV),
+    ('\u{d1a1}', '\u{d1bb}', GraphemeCat::LVT),
+    ('\u{d1bc}', '\u{d1bc}', GraphemeCat::LV),
+    ('\u{d1bd}', '\u{d1d7}', GraphemeCat::LVT),
+    ('\u{d1d8}', '\u{d1d8}', GraphemeCat::LV),
+    ('\u{d1d9}', '\u{d1f3}', GraphemeCat::LVT),
+    ('\u{d1f4}', '\u{d1f4}', GraphemeCat::LV),
+    ('\u{d1f5}', '\u{d20f}', GraphemeCat::LVT),
+    ('\u{d210}', '\u{d210}', GraphemeCat::LV),
+    ('\u{d211}', '\u{d22b}', GraphemeCat::LVT),
+    ('\u{d22c}', '\u{d22c}', GraphemeCat::LV),
+    ('\u{d22d}', '\u{d247}', GraphemeCat::LVT),
+    ('\u{d248}', '\u{d248}', GraphemeCat::LV),
+    ('\u{d249}', '\u{d263}', GraphemeCat::LVT),
+    ('\u{d264}', '\u{d264}', GraphemeCat::LV),
+    ('\u{d265}', '\u{d27f}', GraphemeCat::LVT),
+    ('\u{d280}', '\u{d280}', GraphemeCat::LV),
+    ('\u{d281}', '\u{d29b}', GraphemeCat::LVT),
+    ('\u{d29c}', '\u{d29c}', GraphemeCat::LV),
+    ('\u{d29d}', '\u{d2b7}', GraphemeCat::LVT),
+    ('\u{d2b8}', '\u{d2b8}', GraphemeCat::LV),
+    ('\u{d2b9}', '\u{d2d3}', GraphemeCat::LVT),
+    ('\u{d2d4}', '\u{d2d4}', GraphemeCat::LV),
+    ('\u{d2d5}', '\u{d2ef}', GraphemeCat::LVT),
+    ('\u{d2f0}', '\u{d2f0}', GraphemeCat::LV),
+    ('\u{d2f1}', '\u{d30b}', GraphemeCat::LVT),
+    ('\u{d30c}', '\u{d30c}', GraphemeCat::LV),
+    ('\u{d30d}', '\u{d327}', GraphemeCat::LVT),
+    ('\u{d328}', '\u{d328}', GraphemeCat::LV),
+    ('\u{d329}', '\u{d343}', GraphemeCat::LVT),
+    ('\u{d344}', '\u{d344}', GraphemeCat::LV),
+    ('\u{d345}', '\u{d35f}', GraphemeCat::LVT),
+    ('\u{d360}', '\u{d360}', GraphemeCat::LV),
+    ('\u{d361}', '\u{d37b}', GraphemeCat::LVT),
+    ('\u{d37c}', '\u{d37c}', GraphemeCat::LV),
+    ('\u{d37d}', '\u{d397}', GraphemeCat::LVT),
+    ('\u{d398}', '\u{d398}', GraphemeCat::LV),
+    ('\u{d399}', '\u{d3b3}', GraphemeCat::LVT),
+    ('\u{d3b4}', '\u{d3b4}', GraphemeCat::LV),
+    ('\u{d3b5}', '\u{d3cf}', GraphemeCat::LVT),
+    ('\u{d3d0}', '\u{d3d0}', GraphemeCat::LV),
+    ('\u{d3d1}', '\u{d3eb}', GraphemeCat::LVT),
+    ('\u{d3ec}', '\u{d3ec}', GraphemeCat::LV),
+    ('\u{d3ed}', '\u{d407}', GraphemeCat::LVT),
+    ('\u{d408}', '\u{d408}', GraphemeCat::LV),
+    ('\u{d409}', '\u{d423}', GraphemeCat::LVT),
+    ('\u{d424}', '\u{d424}', GraphemeCat::LV),
+    ('\u{d425}', '\u{d43f}', GraphemeCat::LVT),
+    ('\u{d440}', '\u{d440}', GraphemeCat::LV),
+    ('\u{d441}', '\u{d45b}', GraphemeCat::LVT),
+    ('\u{d45c}', '\u{d45c}', GraphemeCat::LV),
+    ('\u{d45d}', '\u{d477}', GraphemeCat::LVT),
+    ('\u{d478}', '\u{d478}', GraphemeCat::LV),
+    ('\u{d479}', '\u{d493}', GraphemeCat::LVT),
+    ('\u{d494}', '\u{d494}', GraphemeCat::LV),
+    ('\u{d495}', '\u{d4af}', GraphemeCat::LVT),
+    ('\u{d4b0}', '\u{d4b0}', GraphemeCat::LV),
+    ('\u{d4b1}', '\u{d4cb}', GraphemeCat::LVT),
+    ('\u{d4cc}', '\u{d4cc}', GraphemeCat::LV),
+    ('\u{d4cd}', '\u{d4e7}', GraphemeCat::LVT),
+    ('\u{d4e8}', '\u{d4e8}', GraphemeCat::LV),
+    ('\u{d4e9}', '\u{d503}', GraphemeCat::LVT),
+    ('\u{d504}', '\u{d504}', GraphemeCat::LV),
+    ('\u{d505}', '\u{d51f}', GraphemeCat::LVT),
+    ('\u{d520}', '\u{d520}', GraphemeCat::LV),
+    ('\u{d521}', '\u{d53b}', GraphemeCat::LVT),
+    ('\u{d53c}', '\u{d53c}', GraphemeCat::LV),
+    ('\u{d53d}', '\u{d557}', GraphemeCat::LVT),
+    ('\u{d558}', '\u{d558}', GraphemeCat::LV),
+    ('\u{d559}', '\u{d573}', GraphemeCat::LVT),
+    ('\u{d574}', '\u{d574}', GraphemeCat::LV),
+    ('\u{d575}', '\u{d58f}', GraphemeCat::LVT),
+    ('\u{d590}', '\u{d590}', GraphemeCat::LV),
+    ('\u{d591}', '\u{d5ab}', GraphemeCat::LVT),
+    ('\u{d5ac}', '\u{d5ac}', GraphemeCat::LV),
+    ('\u{d5ad}', '\u{d5c7}', GraphemeCat::LVT),
+    ('\u{d5c8}', '\u{d5c8}', GraphemeCat::LV),
+    ('\u{d5c9}', '\u{d5e3}', GraphemeCat::LVT),
+    ('\u{d5e4}', '\u{d5e4}', GraphemeCat::LV),
+    ('\u{d5e5}', '\u{d5ff}', GraphemeCat::LVT),
+    ('\u{d600}', '\u{d600}', GraphemeCat::LV),
+    ('\u{d601}', '\u{d61b}', GraphemeCat::LVT),
+    ('\u{d61c}', '\u{d61c}', GraphemeCat::LV),
+    ('\u{d61d}', '\u{d637}', GraphemeCat::LVT),
+    ('\u{d638}', '\u{d638}', GraphemeCat::LV),
+    ('\u{d639}', '\u{d653}', GraphemeCat::LVT),
+    ('\u{d654}', '\u{d654}', GraphemeCat::LV),
+    ('\u{d655}', '\u{d66f}', GraphemeCat::LVT),
+    ('\u{d670}', '\u{d670}', GraphemeCat::LV),
+    ('\u{d671}', '\u{d68b}', GraphemeCat::LVT),
+    ('\u{d68c}', '\u{d68c}', GraphemeCat::LV),
+    ('\u{d68d}', '\u{d6a7}', GraphemeCat::LVT),
+    ('\u{d6a8}', '\u{d6a8}', GraphemeCat::LV),
+    ('\u{d6a9}', '\u{d6c3}', GraphemeCat::LVT),
+    ('\u{d6c4}', '\u{d6c4}', GraphemeCat::LV),
+    ('\u{d6c5}', '\u{d6df}', GraphemeCat::LVT),
+    ('\u{d6e0}', '\u{d6e0}', GraphemeCat::LV),
+    ('\u{d6e1}', '\u{d6fb}', GraphemeCat::LVT),
+    ('\u{d6fc}', '\u{d6fc}', GraphemeCat::LV),
+    ('\u{d6fd}', '\u{d717}', GraphemeCat::LVT),
+    ('\u{d718}', '\u{d718}', GraphemeCat::LV),
+    ('\u{d719}', '\u{d733}', GraphemeCat::LVT),
+    ('\u{d734}', '\u{d734}', GraphemeCat::LV),
+    ('\u{d735}', '\u{d74f}', GraphemeCat::LVT),
+    ('\u{d750}', '\u{d750}', GraphemeCat::LV),
+    ('\u{d751}', '\u{d76b}', GraphemeCat::LVT),
+    ('\u{d76c}', '\u{d76c}', GraphemeCat::LV),
+    ('\u{d76d}', '\u{d787}', GraphemeCat::LVT),
+    ('\u{d788}', '\u{d788}', GraphemeCat::LV),
+    ('\u{d789}', '\u{d7a3}', GraphemeCat::LVT),
+    ('\u{d7b0}', '\u{d7c6}', GraphemeCat::V),
+    ('\u{d7cb}', '\u{d7fb}', GraphemeCat::T),
+    ('\u{fb1e}', '\u{fb1e}', GraphemeCat::Extend),
+    ('\u{fe00}', '\u{fe0f}', GraphemeCat::Extend),
+    ('\u{fe20}', '\u{fe2f}', GraphemeCat::Extend),
+    ('\u{feff}', '\u{feff}', GraphemeCat::Control),
+    ('\u{ff9e}', '\u{ff9f}', GraphemeCat::Extend),
+    ('\u{fff0}', '\u{fffb}', GraphemeCat::Control),
+    ('\u{101fd}', '\u{101fd}', GraphemeCat::Extend),
+    ('\u{102e0}', '\u{102e0}', GraphemeCat::Extend),
+    ('\u{10376}', '\u{1037a}', GraphemeCat::Extend),
+    ('\u{10a01}', '\u{10a03}', GraphemeCat::Extend),
+    ('\u{10a05}', '\u{10a06}', GraphemeCat::Extend),
+    ('\u{10a0c}', '\u{10a0f}', GraphemeCat::Extend),
+    ('\u{10a38}', '\u{10a3a}', GraphemeCat::Extend),
+    ('\u{10a3f}', '\u{10a3f}', GraphemeCat::Extend),
+    ('\u{10ae5}', '\u{10ae6}', GraphemeCat::Extend),
+    ('\u{10d24}', '\u{10d27}', GraphemeCat::Extend),
+    ('\u{10d69}', '\u{10d6d}', GraphemeCat::Extend),
+    ('\u{10eab}', '\u{10eac}', GraphemeCat::Extend),
+    ('\u{10efa}', '\u{10eff}', GraphemeCat::Extend),
+    ('\u{10f46}', '\u{10f50}', GraphemeCat::Extend),
+    ('\u{10f82}', '\u{10f85}', GraphemeCat::Extend),
+    ('\u{11000}', '\u{11000}', GraphemeCat::SpacingMark),
+    ('\u{11001}', '\u{11001}', GraphemeCat::Extend),
+    ('\u{11002}', '\u{11002}', GraphemeCat::SpacingMark),
+    ('\u{11038}', '\u{11046}', GraphemeCat::Extend),
+    ('\u{11070}', '\u{11070}', GraphemeCat::Extend),
+    ('\u{11073}', '\u{11074}', GraphemeCat::Extend),
+    ('\u{1107f}', '\u{11081}', GraphemeCat::Extend),
+    ('\u{11082}', '\u{11082}', GraphemeCat::SpacingMark),
+    ('\u{110b0}', '\u{110b2}', GraphemeCat::SpacingMark),
+    ('\u{110b3}', '\u{110b6}', GraphemeCat::Extend),
+    ('\u{110b7}', '\u{110b8}', GraphemeCat::SpacingMark),
+    ('\u{110b9}', '\u{110ba}', GraphemeCat::Extend),
+    ('\u{110bd}', '\u{110bd}', GraphemeCat::Prepend),
+    ('\u{110c2}', '\u{110c2}', GraphemeCat::Extend),
+    ('\u{110cd}', '\u{110cd}', GraphemeCat::Prepend),
+    ('\u{11100}', '\u{11102}', GraphemeCat::Extend),
+    ('\u{11127}', '\u{1112b}', GraphemeCat::Extend),
+    ('\u{1112c}', '\u{1112c}', GraphemeCat::SpacingMark),
+    ('\u{1112d}', '\u{11134}', GraphemeCat::Extend),
+    ('\u{11145}', '\u{11146}', GraphemeCat::SpacingMark),
+    ('\u{11173}', '\u{11173}', GraphemeCat::Extend),
+    ('\u{11180}', '\u{11181}', GraphemeCat::Extend),
+    ('\u{11182}', '\u{11182}', GraphemeCat::SpacingMark),
+    ('\u{111b3}', '\u{111b5}', GraphemeCat::SpacingMark),
+    ('\u{111b6}', '\u{111be}', GraphemeCat::Extend),
+    ('\u{111bf}', '\u{111bf}', GraphemeCat::SpacingMark),
+    ('\u{111c0}', '\u{111c0}', GraphemeCat::Extend),
+    ('\u{111c2}', '\u{111c3}', GraphemeCat::Prepend),
+    ('\u{111c9}', '\u{111cc}', GraphemeCat::Extend),
+    ('\u{111ce}', '\u{111ce}', GraphemeCat::SpacingMark),
+    ('\u{111cf}', '\u{111cf}', GraphemeCat::Extend),
+    ('\u{1122c}', '\u{1122e}', GraphemeCat::SpacingMark),
+    ('\u{1122f}', '\u{11231}', GraphemeCat::Extend),
+    ('\u{11232}', '\u{11233}', GraphemeCat::SpacingMark),
+    ('\u{11234}', '\u{11237}', GraphemeCat::Extend),
+    ('\u{1123e}', '\u{1123e}', GraphemeCat::Extend),
+    ('\u{11241}', '\u{11241}', GraphemeCat::Extend),
+    ('\u{112df}', '\u{112df}', GraphemeCat::Extend),
+    ('\u{112e0}', '\u{112e2}', GraphemeCat::SpacingMark),
+    ('\u{112e3}', '\u{112ea}', GraphemeCat::Extend),
+    ('\u{11300}', '\u{11301}', GraphemeCat::Extend),
+    ('\u{11302}', '\u{11303}', GraphemeCat::SpacingMark),
+    ('\u{1133b}', '\u{1133c}', GraphemeCat::Extend),
+    ('\u{1133e}', '\u{1133e}', GraphemeCat::Extend),
+    ('\u{1133f}', '\u{1133f}', GraphemeCat::SpacingMark),
+    ('\u{11340}', '\u{11340}', GraphemeCat::Extend),
+    ('\u{11341}', '\u{11344}', GraphemeCat::SpacingMark),
+    ('\u{11347}', '\u{11348}', GraphemeCat::SpacingMark),
+    ('\u{1134b}', '\u{1134c}', GraphemeCat::SpacingMark),
+    ('\u{1134d}', '\u{1134d}', GraphemeCat::Extend),
+    ('\u{11357}', '\u{11357}', GraphemeCat::Extend),
+    ('\u{11362}', '\u{11363}', GraphemeCat::SpacingMark),
+    ('\u{11366}', '\u{1136c}', GraphemeCat::Extend),
+    ('\u{11370}', '\u{11374}', GraphemeCat::Extend),
+    ('\u{113b8}', '\u{113b8}', GraphemeCat::Extend),
+    ('\u{113b9}', '\u{113ba}', GraphemeCat::SpacingMark),
+    ('\u{113bb}', '\u{113c0}', GraphemeCat::Extend),
+    ('\u{113c2}', '\u{113c2}', GraphemeCat::Extend),
+    ('\u{113c5}', '\u{113c5}', GraphemeCat::Extend),
+    ('\u{113c7}', '\u{113c9}', GraphemeCat::Extend),
+    ('\u{113ca}', '\u{113ca}', GraphemeCat::SpacingMark),
+    ('\u{113cc}', '\u{113cd}', GraphemeCat::SpacingMark),
+    ('\u{113ce}', '\u{113d0}', GraphemeCat::Extend),
+    ('\u{113d1}', '\u{113d1}', GraphemeCat::Prepend),
+    ('\u{113d2}', '\u{113d2}', GraphemeCat::Extend),
+    ('\u{113e1}', '\u{113e2}', GraphemeCat::Extend),
+    ('\u{11435}', '\u{11437}', GraphemeCat::SpacingMark),
+    ('\u{11438}', '\u{1143f}', GraphemeCat::Extend),
+    ('\u{11440}', '\u{11441}', GraphemeCat::SpacingMark),
+    ('\u{11442}', '\u{11444}', GraphemeCat::Extend),
+    ('\u{11445}', '\u{11445}', GraphemeCat::SpacingMark),
+    ('\u{11446}', '\u{11446}', GraphemeCat::Extend),
+    ('\u{1145e}', '\u{1145e}', GraphemeCat::Extend),
+    ('\u{114b0}', '\u{114b0}', GraphemeCat::Extend),
+    ('\u{114b1}', '\u{114b2}', GraphemeCat::SpacingMark),
+    ('\u{114b3}', '\u{114b8}', GraphemeCat::Extend),
+    ('\u{114b9}', '\u{114b9}', GraphemeCat::SpacingMark),
+    ('\u{114ba}', '\u{114ba}', GraphemeCat::Extend),
+    ('\u{114bb}', '\u{114bc}', GraphemeCat::SpacingMark),
+    ('\u{114bd}', '\u{114bd}', GraphemeCat::Extend),
+    ('\u{114be}', '\u{114be}', GraphemeCat::SpacingMark),
+    ('\u{114bf}', '\u{114c0}', GraphemeCat::Extend),
+    ('\u{114c1}', '\u{114c1}', GraphemeCat::SpacingMark),
+    ('\u{114c2}', '\u{114c3}', GraphemeCat::Extend),
+    ('\u{115af}', '\u{115af}', GraphemeCat::Extend),
+    ('\u{115b0}', '\u{115b1}', GraphemeCat::SpacingMark),
+    ('\u{115b2}', '\u{115b5}', GraphemeCat::Extend),
+    ('\u{115b8}', '\u{115bb}', GraphemeCat::SpacingMark),
+    ('\u{115bc}', '\u{115bd}', GraphemeCat::Extend),
+    ('\u{115be}', '\u{115be}', GraphemeCat::SpacingMark),
+    ('\u{115bf}', '\u{115c0}', GraphemeCat::Extend),
+    ('\u{115dc}', '\u{115dd}', GraphemeCat::Extend),
+    ('\u{11630}', '\u{11632}', GraphemeCat::SpacingMark),
+    ('\u{11633}', '\u{1163a}', GraphemeCat::Extend),
+    ('\u{1163b}', '\u{1163c}', GraphemeCat::SpacingMark),
+    ('\u{1163d}', '\u{1163d}', GraphemeCat::Extend),
+    ('\u{1163e}', '\u{1163e}', GraphemeCat::SpacingMark),
+    ('\u{1163f}', '\u{11640}', GraphemeCat::Extend),
+    ('\u{116ab}', '\u{116ab}', GraphemeCat::Extend),
+    ('\u{116ac}', '\u{116ac}', GraphemeCat::SpacingMark),
+    ('\u{116ad}', '\u{116ad}', GraphemeCat::Extend),
+    ('\u{116ae}', '\u{116af}', GraphemeCat::SpacingMark),
+    ('\u{116b0}', '\u{116b7}', GraphemeCat::Extend),
+    ('\u{1171d}', '\u{1171d}', GraphemeCat::Extend),
+    ('\u{1171e}', '\u{1171e}', GraphemeCat::SpacingMark),
+    ('\u{1171f}', '\u{1171f}', GraphemeCat::Extend),
+    ('\u{11722}', '\u{11725}', GraphemeCat::Extend),
+    ('\u{11726}', '\u{11726}', GraphemeCat::SpacingMark),
+    ('\u{11727}', '\u{1172b}', GraphemeCat::Extend),
+    ('\u{1182c}', '\u{1182e}', GraphemeCat::SpacingMark),
+    ('\u{1182f}', '\u{11837}', GraphemeCat::Extend),
+    ('\u{11838}', '\u{11838}', GraphemeCat::SpacingMark),
+    ('\u{11839}', '\u{1183a}', GraphemeCat::Extend),
+    ('\u{11930}', '\u{11930}', GraphemeCat::Extend),
+    ('\u{11931}', '\u{11935}', GraphemeCat::SpacingMark),
+    ('\u{11937}', '\u{11938}', GraphemeCat::SpacingMark),
+    ('\u{1193b}', '\u{1193e}', GraphemeCat::Extend),
+    ('\u{1193f}', '\u{1193f}', GraphemeCat::Prepend),
+    ('\u{11940}', '\u{11940}', GraphemeCat::SpacingMark),
+    ('\u{11941}', '\u{11941}', GraphemeCat::Prepend),
+    ('\u{11942}', '\u{11942}', GraphemeCat::SpacingMark),
+    ('\u{11943}', '\u{11943}', GraphemeCat::Extend),
+    ('\u{119d1}', '\u{119d3}', GraphemeCat::SpacingMark),
+    ('\u{119d4}', '\u{119d7}', GraphemeCat::Extend),
+    ('\u{119da}', '\u{119db}', GraphemeCat::Extend),
+    ('\u{119dc}', '\u{119df}', GraphemeCat::SpacingMark),
+    ('\u{119e0}', '\u{119e0}', GraphemeCat::Extend),
+    ('\u{119e4}', '\u{119e4}', GraphemeCat::SpacingMark),
+    ('\u{11a01}', '\u{11a0a}', GraphemeCat::Extend),
+    ('\u{11a33}', '\u{11a38}', GraphemeCat::Extend),
+    ('\u{11a39}', '\u{11a39}', GraphemeCat::SpacingMark),
+    ('\u{11a3b}', '\u{11a3e}', GraphemeCat::Extend),
+    ('\u{11a47}', '\u{11a47}', GraphemeCat::Extend),
+    ('\u{11a51}', '\u{11a56}', GraphemeCat::Extend),
+    ('\u{11a57}', '\u{11a58}', GraphemeCat::SpacingMark),
+    ('\u{11a59}', '\u{11a5b}', GraphemeCat::Extend),
+    ('\u{11a84}', '\u{11a89}', GraphemeCat::Prepend),
+    ('\u{11a8a}', '\u{11a96}', GraphemeCat::Extend),
+    ('\u{11a97}', '\u{11a97}', GraphemeCat::SpacingMark),
+    ('\u{11a98}', '\u{11a99}', GraphemeCat::Extend),
+    ('\u{11b60}', '\u{11b60}', GraphemeCat::Extend),
+    ('\u{11b61}', '\u{11b61}', GraphemeCat::SpacingMark),
+    ('\u{11b62}', '\u{11b64}', GraphemeCat::Extend),
+    ('\u{11b65}', '\u{11b65}', GraphemeCat::SpacingMark),
+    ('\u{11b66}', '\u{11b66}', GraphemeCat::Extend),
+    ('\u{11b67}', '\u{11b67}', GraphemeCat::SpacingMark),
+    ('\u{11c2f}', '\u{11c2f}', GraphemeCat::SpacingMark),
+    ('\u{11c30}', '\u{11c36}', GraphemeCat::Extend),
+    ('\u{11c38}', '\u{11c3d}', GraphemeCat::Extend),
+    ('\u{11c3e}', '\u{11c3e}', GraphemeCat::SpacingMark),
+    ('\u{11c3f}', '\u{11c3f}', GraphemeCat::Extend),
+    ('\u{11c92}', '\u{11ca7}', GraphemeCat::Extend),
+    ('\u{11ca9}', '\u{11ca9}', GraphemeCat::SpacingMark),
+    ('\u{11caa}', '\u{11cb0}', GraphemeCat::Extend),
+    ('\u{11cb1}', '\u{11cb1}', GraphemeCat::SpacingMark),
+    ('\u{11cb2}', '\u{11cb3}', GraphemeCat::Extend),
+    ('\u{11cb4}', '\u{11cb4}', GraphemeCat::SpacingMark),
+    ('\u{11cb5}', '\u{11cb6}', GraphemeCat::Extend),
+    ('\u{11d31}', '\u{11d36}', GraphemeCat::Extend),
+    ('\u{11d3a}', '\u{11d3a}', GraphemeCat::Extend),
+    ('\u{11d3c}', '\u{11d3d}', GraphemeCat::Extend),
+    ('\u{11d3f}', '\u{11d45}', GraphemeCat::Extend),
+    ('\u{11d46}', '\u{11d46}', GraphemeCat::Prepend),
+    ('\u{11d47}', '\u{11d47}', GraphemeCat::Extend),
+    ('\u{11d8a}', '\u{11d8e}', GraphemeCat::SpacingMark),
+    ('\u{11d90}', '\u{11d91}', GraphemeCat::Extend),
+    ('\u{11d93}', '\u{11d94}', GraphemeCat::SpacingMark),
+    ('\u{11d95}', '\u{11d95}', GraphemeCat::Extend),
+    ('\u{11d96}', '\u{11d96}', GraphemeCat::SpacingMark),
+    ('\u{11d97}', '\u{11d97}', GraphemeCat::Extend),
+    ('\u{11ef3}', '\u{11ef4}', GraphemeCat::Extend),
+    ('\u{11ef5}', '\u{11ef6}', GraphemeCat::SpacingMark),
+    ('\u{11f00}', '\u{11f01}', GraphemeCat::Extend),
+    ('\u{11f02}', '\u{11f02}', GraphemeCat::Prepend),
+    ('\u{11f03}', '\u{11f03}', GraphemeCat::SpacingMark),
+    ('\u{11f34}', '\u{11f35}', GraphemeCat::SpacingMark),
+    ('\u{11f36}', '\u{11f3a}', GraphemeCat::Extend),
+    ('\u{11f3e}', '\u{11f3f}', GraphemeCat::SpacingMark),
+    ('\u{11f40}', '\u{11f42}', GraphemeCat::Extend),
+    ('\u{11f5a}', '\u{11f5a}', GraphemeCat::Extend),
+    ('\u{13430}', '\u{1343f}', GraphemeCat::Control),
+    ('\u{13440}', '\u{13440}', GraphemeCat::Extend),
+    ('\u{13447}', '\u{13455}', GraphemeCat::Extend),
+    ('\u{1611e}', '\u{16129}', GraphemeCat::Extend),
+    ('\u{1612a}', '\u{1612c}', GraphemeCat::SpacingMark),
+    ('\u{1612d}', '\u{1612f}', GraphemeCat::Extend),
+    ('\u{16af0}', '\u{16af4}', GraphemeCat::Extend),
+    ('\u{16b30}', '\u{16b36}', GraphemeCat::Extend),
+    ('\u{16d63}', '\u{16d63}', GraphemeCat::V),
+    ('\u{16d67}', '\u{16d6a}', GraphemeCat::V),
+    ('\u{16f4f}', '\u{16f4f}', GraphemeCat::Extend),
+    ('\u{16f51}', '\u{16f87}', GraphemeCat::SpacingMark),
+    ('\u{16f8f}', '\u{16f92}', GraphemeCat::Extend),
+    ('\u{16fe4}', '\u{16fe4}', GraphemeCat::Extend),
+    ('\u{16ff0}', '\u{16ff1}', GraphemeCat::Extend),
+    ('\u{1bc9d}', '\u{1bc9e}', GraphemeCat::Extend),
+    ('\u{1bca0}', '\u{1bca3}', GraphemeCat::Control),
+    ('\u{1cf00}', '\u{1cf2d}', GraphemeCat::Extend),
+    ('\u{1cf30}', '\u{1cf46}', GraphemeCat::Extend),
+    ('\u{1d165}', '\u{1d169}', GraphemeCat::Extend),
+    ('\u{1d16d}', '\u{1d172}', GraphemeCat::Extend),
+    ('\u{1d173}', '\u{1d17a}', GraphemeCat::Control),
+    ('\u{1d17b}', '\u{1d182}', GraphemeCat::Extend),
+    ('\u{1d185}', '\u{1d18b}', GraphemeCat::Extend),
+    ('\u{1d1aa}', '\u{1d1ad}', GraphemeCat::Extend),
+    ('\u{1d242}', '\u{1d244}', GraphemeCat::Extend),
+    ('\u{1da00}', '\u{1da36}', GraphemeCat::Extend),
+    ('\u{1da3b}', '\u{1da6c}', GraphemeCat::Extend),
+    ('\u{1da75}', '\u{1da75}', GraphemeCat::Extend),
+    ('\u{1da84}', '\u{1da84}', GraphemeCat::Extend),
+    ('\u{1da9b}', '\u{1da9f}', GraphemeCat::Extend),
+    ('\u{1daa1}', '\u{1daaf}', GraphemeCat::Extend),
+    ('\u{1e000}', '\u{1e006}', GraphemeCat::Extend),
+    ('\u{1e008}', '\u{1e018}', GraphemeCat::Extend),
+    ('\u{1e01b}', '\u{1e021}', GraphemeCat::Extend),
+    ('\u{1e023}', '\u{1e024}', GraphemeCat::Extend),
+    ('\u{1e026}', '\u{1e02a}', GraphemeCat::Extend),
+    ('\u{1e08f}', '\u{1e08f}', GraphemeCat::Extend),
+    ('\u{1e130}', '\u{1e136}', GraphemeCat::Extend),
+    ('\u{1e2ae}', '\u{1e2ae}', GraphemeCat::Extend),
+    ('\u{1e2ec}', '\u{1e2ef}', GraphemeCat::Extend),
+    ('\u{1e4ec}', '\u{1e4ef}', GraphemeCat::Extend),
+    ('\u{1e5ee}', '\u{1e5ef}', GraphemeCat::Extend),
+    ('\u{1e6e3}', '\u{1e6e3}', GraphemeCat::Extend),
+    ('\u{1e6e6}', '\u{1e6e6}', GraphemeCat::Extend),
+    ('\u{1e6ee}', '\u{1e6ef}', GraphemeCat::Extend),
+    ('\u{1e6f5}', '\u{1e6f5}', GraphemeCat::Extend),
+    ('\u{1e8d0}', '\u{1e8d6}', GraphemeCat::Extend),
+    ('\u{1e944}', '\u{1e94a}', GraphemeCat::Extend),
+    ('\u{1f1e6}', '\u{1f1ff}', GraphemeCat::RegionalIndicator),
+    ('\u{1f3fb}', '\u{1f3ff}', GraphemeCat::Extend),
+    ('\u{e0000}', '\u{e001f}', GraphemeCat::Control),
+    ('\u{e0020}', '\u{e007f}', GraphemeCat::Extend),
+    ('\u{e0080}', '\u{e00ff}', GraphemeCat::Control),
+    ('\u{e0100}', '\u{e01ef}', GraphemeCat::Extend),
+    ('\u{e01f0}', '\u{e0fff}', GraphemeCat::Control),
+];
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum IndicConjunctBreak {
+    None,
+    Consonant,
+    Extend,
+    Linker,
+}
+
+pub const INDIC_CONJUNCT_BREAK_TABLE: &'static [(char, char, IndicConjunctBreak)] = &[
+    ('\u{300}', '\u{36f}', IndicConjunctBreak::Extend),
+    ('\u{483}', '\u{489}', IndicConjunctBreak::Extend),
+    ('\u{591}', '\u{5bd}', IndicConjunctBreak::Extend),
+    ('\u{5bf}', '\u{5bf}', IndicConjunctBreak::Extend),
+    ('\u{5c1}', '\u{5c2}', IndicConjunctBreak::Extend),
+    ('\u{5c4}', '\u{5c5}', IndicConjunctBreak::Extend),
+    ('\u{5c7}', '\u{5c7}', IndicConjunctBreak::Extend),
+    ('\u{610}', '\u{61a}', IndicConjunctBreak::Extend),
+    ('\u{64b}', '\u{65f}', IndicConjunctBreak::Extend),
+    ('\u{670}', '\u{670}', IndicConjunctBreak::Extend),
+    ('\u{6d6}', '\u{6dc}', IndicConjunctBreak::Extend),
+    ('\u{6df}', '\u{6e4}', IndicConjunctBreak::Extend),
+    ('\u{6e7}', '\u{6e8}', IndicConjunctBreak::Extend),
+    ('\u{6ea}', '\u{6ed}', IndicConjunctBreak::Extend),
+    ('\u{711}', '\u{711}', IndicConjunctBreak::Extend),
+    ('\u{730}', '\u{74a}', IndicConjunctBreak::Extend),
+    ('\u{7a6}', '\u{7b0}', IndicConjunctBreak::Extend),
+    ('\u{7eb}', '\u{7f3}', IndicConjunctBreak::Extend),
+    ('\u{7fd}', '\u{7fd}', IndicConjunctBreak::Extend),
+    ('\u{816}', '\u{819}', IndicConjunctBreak::Extend),
+    ('\u{81b}', '\u{823}', IndicConjunctBreak::Extend),
+    ('\u{825}', '\u{827}', IndicConjunctBreak::Extend),
+    ('\u{829}', '\u{82d}', IndicConjunctBreak::Extend),
+    ('\u{859}', '\u{85b}', IndicConjunctBreak::Extend),
+    ('\u{897}', '\u{89f}', IndicConjunctBreak::Extend),
+    ('\u{8ca}', '\u{8e1}', IndicConjunctBreak::Extend),
+    ('\u{8e3}', '\u{902}', IndicConjunctBreak::Extend),
+    ('\u{915}', '\u{939}', IndicConjunctBreak::Consonant),
+    ('\u{93a}', '\u{93a}', IndicConjunctBreak::Extend),
+    ('\u{93c}', '\u{93c}', IndicConjunctBreak::Extend),
+    ('\u{941}', '\u{948}', IndicConjunctBreak::Extend),
+    ('\u{94d}', '\u{94d}', IndicConjunctBreak::Linker),
+    ('\u{951}', '\u{957}', IndicConjunctBreak::Extend),
+    ('\u{958}', '\u{95f}', IndicConjunctBreak::Consonant),
+    ('\u{962}', '\u{963}', IndicConjunctBreak::Extend),
+    ('\u{978}', '\u{97f}', IndicConjunctBreak::Consonant),
+    ('\u{981}', '\u{981}', IndicConjunctBreak::Extend),
+    ('\u{995}', '\u{9a8}', IndicConjunctBreak::Consonant),
+    ('\u{9aa}', '\u{9b0}', IndicConjunctBreak::Consonant),
+    ('\u{9b2}', '\u{9b2}', IndicConjunctBreak::Consonant),
+    ('\u{9b6}', '\u{9b9}', IndicConjunctBreak::Consonant),
+    ('\u{9bc}', '\u{9bc}', IndicConjunctBreak::Extend),
+    ('\u{9be}', '\u{9be}', IndicConjunctBreak::Extend),
+    ('\u{9c1}', '\u{9c4}', IndicConjunctBreak::Extend),
+    ('\u{9cd}', '\u{9cd}', IndicConjunctBreak::Linker),
+    ('\u{9d7}', '\u{9d7}', IndicConjunctBreak::Extend),
+    ('\u{9dc}', '\u{9dd}', IndicConjunctBreak::Consonant),
+    ('\u{9df}', '\u{9df}', IndicConjunctBreak::Consonant),
+    ('\u{9e2}', '\u{9e3}', IndicConjunctBreak::Extend),
+    ('\u{9f0}', '\u{9f1}', IndicConjunctBreak::Consonant),
+    ('\u{9fe}', '\u{9fe}', IndicConjunctBreak::Extend),
+    ('\u{a01}', '\u{a02}', IndicConjunctBreak::Extend),
+    ('\u{a3c}', '\u{a3c}', IndicConjunctBreak::Extend),
+    ('\u{a41}', '\u{a42}', IndicConjunctBreak::Extend),
+    ('\u{a47}', '\u{a48}', IndicConjunctBreak::Extend),
+    ('\u{a4b}', '\u{a4d}', IndicConjunctBreak::Extend),
+    ('\u{a51}', '\u{a51}', IndicConjunctBreak::Extend),
+    ('\u{a70}', '\u{a71}', IndicConjunctBreak::Extend),
+    ('\u{a75}', '\u{a75}', IndicConjunctBreak::Extend),
+    ('\u{a81}', '\u{a82}', IndicConjunctBreak::Extend),
+    ('\u{a95}', '\u{aa8}', IndicConjunctBreak::Consonant),
+    ('\u{aaa}', '\u{ab0}', IndicConjunctBreak::Consonant),
+    ('\u{ab2}', '\u{ab3}', IndicConjunctBreak::Consonant),
+    ('\u{ab5}', '\u{ab9}', IndicConjunctBreak::Consonant),
+    ('\u{abc}', '\u{abc}', IndicConjunctBreak::Extend),
+    ('\u{ac1}', '\u{ac5}', IndicConjunctBreak::Extend),
+    ('\u{ac7}', '\u{ac8}', IndicConjunctBreak::Extend),
+    ('\u{acd}', '\u{acd}', IndicConjunctBreak::Linker),
+    ('\u{ae2}', '\u{ae3}', IndicConjunctBreak::Extend),
+    ('\u{af9}', '\u{af9}', IndicConjunctBreak::Consonant),
+    ('\u{afa}', '\u{aff}', IndicConjunctBreak::Extend),
+    ('\u{b01}', '\u{b01}', IndicConjunctBreak::Extend),
+    ('\u{b15}', '\u{b28}', IndicConjunctBreak::Consonant),
+    ('\u{b2a}', '\u{b30}', IndicConjunctBreak::Consonant),
+    ('\u{b32}', '\u{b33}', IndicConjunctBreak::Consonant),
+    ('\u{b35}', '\u{b39}', IndicConjunctBreak::Consonant),
+    ('\u{b3c}', '\u{b3c}', IndicConjunctBreak::Extend),
+    ('\u{b3e}', '\u{b3f}', IndicConjunctBreak::Extend),
+    ('\u{b41}', '\u{b44}', IndicConjunctBreak::Extend),
+    ('\u{b4d}', '\u{b4d}', IndicConjunctBreak::Linker),
+    ('\u{b55}', '\u{b57}', IndicConjunctBreak::Extend),
+    ('\u{b5c}', '\u{b5d}', IndicConjunctBreak::Consonant),
+    ('\u{b5f}', '\u{b5f}', IndicConjunctBreak::Consonant),
+    ('\u{b62}', '\u{b63}', IndicConjunctBreak::Extend),
+    ('\u{b71}', '\u{b71}', IndicConjunctBreak::Consonant),
+    ('\u{b82}', '\u{b82}', IndicConjunctBreak::Extend),
+    ('\u{bbe}', '\u{bbe}', IndicConjunctBreak::Extend),
+    ('\u{bc0}', '\u{bc0}', IndicConjunctBreak::Extend),
+    ('\u{bcd}', '\u{bcd}', IndicConjunctBreak::Extend),
+    ('\u{bd7}', '\u{bd7}', IndicConjunctBreak::Extend),
+    ('\u{c00}', '\u{c00}', IndicConjunctBreak::Extend),
+    ('\u{c04}', '\u{c04}', IndicConjunctBreak::Extend),
+    ('\u{c15}', '\u{c28}', IndicConjunctBreak::Consonant),
+    ('\u{c2a}', '\u{c39}', IndicConjunctBreak::Consonant),
+    ('\u{c3c}', '\u{c3c}', IndicConjunctBreak::Extend),
+    ('\u{c3e}', '\u{c40}', IndicConjunctBreak::Extend),
+    ('\u{c46}', '\u{c48}', IndicConjunctBreak::Extend),
+    ('\u{c4a}', '\u{c4c}', IndicConjunctBreak::Extend),
+    ('\u{c4d}', '\u{c4d}', IndicConjunctBreak::Linker),
+    ('\u{c55}', '\u{c56}', IndicConjunctBreak::Extend),
+    ('\u{c58}', '\u{c5a}', IndicConjunctBreak::Consonant),
+    ('\u{c62}', '\u{c63}', IndicConjunctBreak::Extend),
+    ('\u{c81}', '\u{c81}', IndicConjunctBreak::Extend),
+    ('\u{cbc}', '\u{cbc}', IndicConjunctBreak::Extend),
+    ('\u{cbf}', '\u{cc0}', IndicConjunctBreak::Extend),
+    ('\u{cc2}', '\u{cc2}', IndicConjunctBreak::Extend),
+    ('\u{cc6}', '\u{cc8}', IndicConjunctBreak::Extend),
+    ('\u{cca}', '\u{ccd}', IndicConjunctBreak::Extend),
+    ('\u{cd5}', '\u{cd6}', IndicConjunctBreak::Extend),
+    ('\u{ce2}', '\u{ce3}', IndicConjunctBreak::Extend),
+    ('\u{d00}', '\u{d01}', IndicConjunctBreak::Extend),
+    ('\u{d15}', '\u{d3a}', IndicConjunctBreak::Consonant),
+    ('\u{d3b}', '\u{d3c}', IndicConjunctBreak::Extend),
+    ('\u{d3e}', '\u{d3e}', IndicConjunctBreak::Extend),
+    ('\u{d41}', '\u{d44}', IndicConjunctBreak::Extend),
+    ('\u{d4d}', '\u{d4d}', IndicConjunctBreak::Linker),
+    ('\u{d57}', '\u{d57}', IndicConjunctBreak::Extend),
+    ('\u{d62}', '\u{d63}', IndicConjunctBreak::Extend),
+    ('\u{d81}', '\u{d81}', IndicConjunctBreak::Extend),
+    ('\u{dca}', '\u{dca}', IndicConjunctBreak::Extend),
+    ('\u{dcf}', '\u{dcf}', IndicConjunctBreak::Extend),
+    ('\u{dd2}', '\u{dd4}', IndicConjunctBreak::Extend),
+    ('\u{dd6}', '\u{dd6}', IndicConjunctBreak::Extend),
+    ('\u{ddf}', '\u{ddf}', IndicConjunctBreak::Extend),
+    ('\u{e31}', '\u{e31}', IndicConjunctBreak::Extend),
+    ('\u{e34}', '\u{e3a}', IndicConjunctBreak::Extend),
+    ('\u{e47}', '\u{e4e}', IndicConjunctBreak::Extend),
+    ('\u{eb1}', '\u{eb1}', IndicConjunctBreak::Extend),
+    ('\u{eb4}', '\u{ebc}', IndicConjunctBreak::Extend),
+    ('\u{ec8}', '\u{ece}', IndicConjunctBreak::Extend),
+    ('\u{f18}', '\u{f19}', IndicConjunctBreak::Extend),
+    ('\u{f35}', '\u{f35}', IndicConjunctBreak::Extend),
+    ('\u{f37}', '\u{f37}', IndicConjunctBreak::Extend),
+    ('\u{f39}', '\u{f39}', IndicConjunctBreak::Extend),
+    ('\u{f71}', '\u{f7e}', IndicConjunctBreak::Extend),
+    ('\u{f80}', '\u{f84}', IndicConjunctBreak::Extend),
+    ('\u{f86}', '\u{f87}', IndicConjunctBreak::Extend),
+    ('\u{f8d}', '\u{f97}', IndicConjunctBreak::Extend),
+    ('\u{f99}', '\u{fbc}', IndicConjunctBreak::Extend),
+    ('\u{fc6}', '\u{fc6}', IndicConjunctBreak::Extend),
+    ('\u{1000}', '\u{102a}', IndicConjunctBreak::Consonant),
+    ('\u{102d}', '\u{1030}', IndicConjunctBreak::Extend),
+    ('\u{1032}', '\u{1037}', IndicConjunctBreak::Extend),
+    ('\u{1039}', '\u{1039}', IndicConjunctBreak::Linker),
+    ('\u{103a}', '\u{103a}', IndicConjunctBreak::Extend),
+    ('\u{103d}', '\u{103e}', IndicConjunctBreak::Extend),
+    ('\u{103f}', '\u{103f}', IndicConjunctBreak::Consonant),
+    ('\u{1050}', '\u{1055}', IndicConjunctBreak::Consonant),
+    ('\u{1058}', '\u{1059}', IndicConjunctBreak::Extend),
+    ('\u{105a}', '\u{105d}', IndicConjunctBreak::Consonant),
+    ('\u{105e}', '\u{1060}', IndicConjunctBreak::Extend),
+    ('\u{1061}', '\u{1061}', IndicConjunctBreak::Consonant),
+    ('\u{1065}', '\u{1066}', IndicConjunctBreak::Consonant),
+    ('\u{106e}', '\u{1070}', IndicConjunctBreak::Consonant),
+    ('\u{1071}', '\u{1074}', IndicConjunctBreak::Extend),
+    ('\u{1075}', '\u{1081}', IndicConjunctBreak::Consonant),
+    ('\u{1082}', '\u{1082}', IndicConjunctBreak::Extend),
+    ('\u{1085}', '\u{1086}', IndicConjunctBreak::Extend),
+    ('\u{108d}', '\u{108d}', IndicConjunctBreak::Extend),
+    ('\u{108e}', '\u{108e}', IndicConjunctBreak::Consonant),
+    ('\u{109d}', '\u{109d}', IndicConjunctBreak::Extend),
+    ('\u{135d}', '\u{135f}', IndicConjunctBreak::Extend),
+    ('\u{1712}', '\u{1715}', IndicConjunctBreak::Extend),
+    ('\u{1732}', '\u{1734}', IndicConjunctBreak::Extend),
+    ('\u{1752}', '\u{1753}', IndicConjunctBreak::Extend),
+    ('\u{1772}', '\u{1773}', IndicConjunctBreak::Extend),
+    ('\u{1780}', '\u{17b3}', IndicConjunctBreak::Consonant),
+    ('\u{17b4}', '\u{17b5}', IndicConjunctBreak::Extend),
+    ('\u{17b7}', '\u{17bd}', IndicConjunctBreak::Extend),
+    ('\u{17c6}', '\u{17c6}', IndicConjunctBreak::Extend),
+    ('\u{17c9}', '\u{17d1}', IndicConjunctBreak::Extend),
+    ('\u{17d2}', '\u{17d2}', IndicConjunctBreak::Linker),
+    ('\u{17d3}', '\u{17d3}', IndicConjunctBreak::Extend),
+    ('\u{17dd}', '\u{17dd}', IndicConjunctBreak::Extend),
+    ('\u{180b}', '\u{180d}', IndicConjunctBreak::Extend),
+    ('\u{180f}', '\u{180f}', IndicConjunctBreak::Extend),
+    ('\u{1885}', '\u{1886}', IndicConjunctBreak::Extend),
+    ('\u{18a9}', '\u{18a9}', IndicConjunctBreak::Extend),
+    ('\u{1920}', '\u{1922}', IndicConjunctBreak::Extend),
+    ('\u{1927}', '\u{1928}', IndicConjunctBreak::Extend),
+    ('\u{1932}', '\u{1932}', IndicConjunctBreak::Extend),
+    ('\u{1939}', '\u{193b}', IndicConjunctBreak::Extend),
+    ('\u{1a17}', '\u{1a18}', IndicConjunctBreak::Extend),
+    ('\u{1a1b}', '\u{1a1b}', IndicConjunctBreak::Extend),
+    ('\u{1a20}', '\u{1a54}', IndicConjunctBreak::Consonant),
+    ('\u{1a56}', '\u{1a56}', IndicConjunctBreak::Extend),
+    ('\u{1a58}', '\u{1a5e}', IndicConjunctBreak::Extend),
+    ('\u{1a60}', '\u{1a60}', IndicConjunctBreak::Linker),
+    ('\u{1a62}', '\u{1a62}', IndicConjunctBreak::Extend),
+    ('\u{1a65}', '\u{1a6c}', IndicConjunctBreak::Extend),
+    ('\u{1a73}', '\u{1a7c}', IndicConjunctBreak::Extend),
+    ('\u{1a7f}', '\u{1a7f}', IndicConjunctBreak::Extend),
+    ('\u{1ab0}', '\u{1add}', IndicConjunctBreak::Extend),
+    ('\u{1ae0}', '\u{1aeb}', IndicConjunctBreak::Extend),
+    ('\u{1b00}', '\u{1b03}', IndicConjunctBreak::Extend),
+    ('\u{1b0b}', '\u{1b0c}', IndicConjunctBreak::Consonant),
+    ('\u{1b13}', '\u{1b33}', IndicConjunctBreak::Consonant),
+    ('\u{1b34}', '\u{1b3d}', IndicConjunctBreak::Extend),
+    ('\u{1b42}', '\u{1b43}', IndicConjunctBreak::Extend),
+    ('\u{1b44}', '\u{1b44}', IndicConjunctBreak::Linker),
+    ('\u{1b45}', '\u{1b4c}', IndicConjunctBreak::Consonant),
+    ('\u{1b6b}', '\u{1b73}', IndicConjunctBreak::Extend),
+    ('\u{1b80}', '\u{1b81}', IndicConjunctBreak::Extend),
+    ('\u{1b83}', '\u{1ba0}', IndicConjunctBreak::Consonant),
+    ('\u{1ba2}', '\u{1ba5}', IndicConjunctBreak::Extend),
+    ('\u{1ba8}', '\u{1baa}', IndicConjunctBreak::Extend),
+    ('\u{1bab}', '\u{1bab}', IndicConjunctBreak::Linker),
+    ('\u{1bac}', '\u{1bad}', IndicConjunctBreak::Extend),
+    ('\u{1bae}', '\u{1baf}', IndicConjunctBreak::Consonant),
+    ('\u{1bbb}', '\u{1bbd}', IndicConjunctBreak::Consonant),
+    ('\u{1be6}', '\u{1be6}', IndicConjunctBreak::Extend),
+    ('\u{1be8}', '\u{1be9}', IndicConjunctBreak::Extend),
+    ('\u{1bed}', '\u{1bed}', IndicConjunctBreak::Extend),
+    ('\u{1bef}', '\u{1bf3}', IndicConjunctBreak::Extend),
+    ('\u{1c2c}', '\u{1c33}', IndicConjunctBreak::Extend),
+    ('\u{1c36}', '\u{1c37}', IndicConjunctBreak::Extend),
+    ('\u{1cd0}', '\u{1cd2}', IndicConjunctBreak::Extend),
+    ('\u{1cd4}', '\u{1ce0}', IndicConjunctBreak::Extend),
+    ('\u{1ce2}', '\u{1ce8}', IndicConjunctBreak::Extend),
+    ('\u{1ced}', '\u{1ced}', IndicConjunctBreak::Extend),
+    ('\u{1cf4}', '\u{1cf4}', IndicConjunctBreak::Extend),
+    ('\u{1cf8}', '\u{1cf9}', IndicConjunctBreak::Extend),
+    ('\u{1dc0}', '\u{1dff}', IndicConjunctBreak::Extend),
+    ('\u{200d}', '\u{200d}', IndicConjunctBreak::Extend),
+    ('\u{20d0}', '\u{20f0}', IndicConjunctBreak::Extend),
+    ('\u{2cef}', '\u{2cf1}', IndicConjunctBreak::Extend),
+    ('\u{2d7f}', '\u{2d7f}', IndicConjunctBreak::Extend),
+    ('\u{2de0}', '\u{2dff}', IndicConjunctBreak::Extend),
+    ('\u{302a}', '\u{302f}', IndicConjunctBreak::Extend),
+    ('\u{3099}', '\u{309a}', IndicConjunctBreak::Extend),
+    ('\u{a66f}', '\u{a672}', IndicConjunctBreak::Extend),
+    ('\u{a674}', '\u{a67d}', IndicConjunctBreak::Extend),
+    ('\u{a69e}', '\u{a69f}', IndicConjunctBreak::Extend),
+    ('\u{a6f0}', '\u{a6f1}', IndicConjunctBreak::Extend),
+    ('\u{a802}', '\u{a802}', IndicConjunctBreak::Extend),
+    ('\u{a806}', '\u{a806}', IndicConjunctBreak::Extend),
+    ('\u{a80b}', '\u{a80b}', IndicConjunctBreak::Extend),
+    ('\u{a825}', '\u{a826}', IndicConjunctBreak::Extend),
+    ('\u{a82c}', '\u{a82c}', IndicConjunctBreak::Extend),
+    ('\u{a8c4}', '\u{a8c5}', IndicConjunctBreak::Extend),
+    ('\u{a8e0}', '\u{a8f1}', IndicConjunctBreak::Extend),
+    ('\u{a8ff}', '\u{a8ff}', IndicConjunctBreak::Extend),
+    ('\u{a926}', '\u{a92d}', IndicConjunctBreak::Extend),
+    ('\u{a947}', '\u{a951}', IndicConjunctBreak::Extend),
+    ('\u{a953}', '\u{a953}', IndicConjunctBreak::Extend),
+    ('\u{a980}', '\u{a982}', IndicConjunctBreak::Extend),
+    ('\u{a989}', '\u{a98b}', IndicConjunctBreak::Consonant),
+    ('\u{a98f}', '\u{a9b2}', IndicConjunctBreak::Consonant),
+    ('\u{a9b3}', '\u{a9b3}', IndicConjunctBreak::Extend),
+    ('\u{a9b6}', '\u{a9b9}', IndicConjunctBreak::Extend),
+    ('\u{a9bc}', '\u{a9bd}', IndicConjunctBreak::Extend),
+    ('\u{a9c0}', '\u{a9c0}', IndicConjunctBreak::Linker),
+    ('\u{a9e0}', '\u{a9e4}', IndicConjunctBreak::Consonant),
+    ('\u{a9e5}', '\u{a9e5}', IndicConjunctBreak::Extend),
+    ('\u{a9e7}', '\u{a9ef}', IndicConjunctBreak::Consonant),
+    ('\u{a9fa}', '\u{a9fe}', IndicConjunctBreak::Consonant),
+    ('\u{aa29}', '\u{aa2e}', IndicConjunctBreak::Extend),
+    ('\u{aa31}', '\u{aa32}', IndicConjunctBreak::Extend),
+    ('\u{aa35}', '\u{aa36}', IndicConjunctBreak::Extend),
+    ('\u{aa43}', '\u{aa43}', IndicConjunctBreak::Extend),
+    ('\u{aa4c}', '\u{aa4c}', IndicConjunctBreak::Extend),
+    ('\u{aa60}', '\u{aa6f}', IndicConjunctBreak::Consonant),
+    ('\u{aa71}', '\u{aa73}', IndicConjunctBreak::Consonant),
+    ('\u{aa7a}', '\u{aa7a}', IndicConjunctBreak::Consonant),
+    ('\u{aa7c}', '\u{aa7c}', IndicConjunctBreak::Extend),
+    ('\u{aa7e}', '\u{aa7f}', IndicConjunctBreak::Consonant),
+    ('\u{aab0}', '\u{aab0}', IndicConjunctBreak::Extend),
+    ('\u{aab2}', '\u{aab4}', IndicConjunctBreak::Extend),
+    ('\u{aab7}', '\u{aab8}', IndicConjunctBreak::Extend),
+    ('\u{aabe}', '\u{aabf}', IndicConjunctBreak::Extend),
+    ('\u{aac1}', '\u{aac1}', IndicConjunctBreak::Extend),
+    ('\u{aae0}', '\u{aaea}', IndicConjunctBreak::Consonant),
+    ('\u{aaec}', '\u{aaed}', IndicConjunctBreak::Extend),
+    ('\u{aaf6}', '\u{aaf6}', IndicConjunctBreak::Linker),
+    ('\u{abc0}', '\u{abda}', IndicConjunctBreak::Consonant),
+    ('\u{abe5}', '\u{abe5}', IndicConjunctBreak::Extend),
+    ('\u{abe8}', '\u{abe8}', IndicConjunctBreak::Extend),
+    ('\u{abed}', '\u{abed}', IndicConjunctBreak::Extend),
+    ('\u{fb1e}', '\u{fb1e}', IndicConjunctBreak::Extend),
+    ('\u{fe00}', '\u{fe0f}', IndicConjunctBreak::Extend),
+    ('\u{fe20}', '\u{fe2f}', IndicConjunctBreak::Extend),
+    ('\u{ff9e}', '\u{ff9f}', IndicConjunctBreak::Extend),
+    ('\u{101fd}', '\u{101fd}', IndicConjunctBreak::Extend),
+    ('\u{102e0}', '\u{102e0}', IndicConjunctBreak::Extend),
+    ('\u{10376}', '\u{1037a}', IndicConjunctBreak::Extend),
+    ('\u{10a00}', '\u{10a00}', IndicConjunctBreak::Consonant),
+    ('\u{10a01}', '\u{10a03}', IndicConjunctBreak::Extend),
+    ('\u{10a05}', '\u{10a06}', IndicConjunctBreak::Extend),
+    ('\u{10a0c}', '\u{10a0f}', IndicConjunctBreak::Extend),
+    ('\u{10a10}', '\u{10a13}', IndicConjunctBreak::Consonant),
+    ('\u{10a15}', '\u{10a17}', IndicConjunctBreak::Consonant),
+    ('\u{10a19}', '\u{10a35}', IndicConjunctBreak::Consonant),
+    ('\u{10a38}', '\u{10a3a}', IndicConjunctBreak::Extend),
+    ('\u{10a3f}', '\u{10a3f}', IndicConjunctBreak::Linker),
+    ('\u{10ae5}', '\u{10ae6}', IndicConjunctBreak::Extend),
+    ('\u{10d24}', '\u{10d27}', IndicConjunctBreak::Extend),
+    ('\u{10d69}', '\u{10d6d}', IndicConjunctBreak::Extend),
+    ('\u{10eab}', '\u{10eac}', IndicConjunctBreak::Extend),
+    ('\u{10efa}', '\u{10eff}', IndicConjunctBreak::Extend),
+    ('\u{10f46}', '\u{10f50}', IndicConjunctBreak::Extend),
+    ('\u{10f82}', '\u{10f85}', IndicConjunctBreak::Extend),
+    ('\u{11001}', '\u{11001}', IndicConjunctBreak::Extend),
+    ('\u{11038}', '\u{11046}', IndicConjunctBreak::Extend),
+    ('\u{11070}', '\u{11070}', IndicConjunctBreak::Extend),
+    ('\u{11073}', '\u{11074}', IndicConjunctBreak::Extend),
+    ('\u{1107f}', '\u{11081}', IndicConjunctBreak::Extend),
+    ('\u{110b3}', '\u{110b6}', IndicConjunctBreak::Extend),
+    ('\u{110b9}', '\u{110ba}', IndicConjunctBreak::Extend),
+    ('\u{110c2}', '\u{110c2}', IndicConjunctBreak::Extend),
+    ('\u{11100}', '\u{11102}', IndicConjunctBreak::Extend),
+    ('\u{11103}', '\u{11126}', IndicConjunctBreak::Consonant),
+    ('\u{11127}', '\u{1112b}', IndicConjunctBreak::Extend),
+    ('\u{1112d}', '\u{11132}', IndicConjunctBreak::Extend),
+    ('\u{11133}', '\u{11133}', IndicConjunctBreak::Linker),
+    ('\u{11134}', '\u{11134}', IndicConjunctBreak::Extend),
+    ('\u{11144}', '\u{11144}', IndicConjunctBreak::Consonant),
+    ('\u{11147}', '\u{11147}', IndicConjunctBreak::Consonant),
+    ('\u{11173}', '\u{11173}', IndicConjunctBreak::Extend),
+    ('\u{11180}', '\u{11181}', IndicConjunctBreak::Extend),
+    ('\u{111b6}', '\u{111be}', IndicConjunctBreak::Extend),
+    ('\u{111c0}', '\u{111c0}', IndicConjunctBreak::Extend),
+    ('\u{111c9}', '\u{111cc}', IndicConjunctBreak::Extend),
+    ('\u{111cf}', '\u{111cf}', IndicConjunctBreak::Extend),
+    ('\u{1122f}', '\u{11231}', IndicConjunctBreak::Extend),
+    ('\u{11234}', '\u{11237}', IndicConjunctBreak::Extend),
+    ('\u{1123e}', '\u{1123e}', IndicConjunctBreak::Extend),
+    ('\u{11241}', '\u{11241}', IndicConjunctBreak::Extend),
+    ('\u{112df}', '\u{112df}', IndicConjunctBreak::Extend),
+    ('\u{112e3}', '\u{112ea}', IndicConjunctBreak::Extend),
+    ('\u{11300}', '\u{11301}', IndicConjunctBreak::Extend),
+    ('\u{1133b}', '\u{1133c}', IndicConjunctBreak::Extend),
+    ('\u{1133e}', '\u{1133e}', IndicConjunctBreak::Extend),
+    ('\u{11340}', '\u{11340}', IndicConjunctBreak::Extend),
+    ('\u{1134d}', '\u{1134d}', IndicConjunctBreak::Extend),
+    ('\u{11357}', '\u{11357}', IndicConjunctBreak::Extend),
+    ('\u{11366}', '\u{1136c}', IndicConjunctBreak::Extend),
+    ('\u{11370}', '\u{11374}', IndicConjunctBreak::Extend),
+    ('\u{11380}', '\u{11389}', IndicConjunctBreak::Consonant),
+    ('\u{1138b}', '\u{1138b}', IndicConjunctBreak::Consonant),
+    ('\u{1138e}', '\u{1138e}', IndicConjunctBreak::Consonant),
+    ('\u{11390}', '\u{113b5}', IndicConjunctBreak::Consonant),
+    ('\u{113b8}', '\u{113b8}', IndicConjunctBreak::Extend),
+    ('\u{113bb}', '\u{113c0}', IndicConjunctBreak::Extend),
+    ('\u{113c2}', '\u{113c2}', IndicConjunctBreak::Extend),
+    ('\u{113c5}', '\u{113c5}', IndicConjunctBreak::Extend),
+    ('\u{113c7}', '\u{113c9}', IndicConjunctBreak::Extend),
+    ('\u{113ce}', '\u{113cf}', IndicConjunctBreak::Extend),
+    ('\u{113d0}', '\u{113d0}', IndicConjunctBreak::Linker),
+    ('\u{113d2}', '\u{113d2}', IndicConjunctBreak::Extend),
+    ('\u{113e1}', '\u{113e2}', IndicConjunctBreak::Extend),
+    ('\u{11438}', '\u{1143f}', IndicConjunctBreak::Extend),
+    ('\u{11442}', '\u{11444}', IndicConjunctBreak::Extend),
+    ('\u{11446}', '\u{11446}', IndicConjunctBreak::Extend),
+    ('\u{1145e}', '\u{1145e}', IndicConjunctBreak::Extend),
+    ('\u{114b0}', '\u{114b0}', IndicConjunctBreak::Extend),
+    ('\u{114b3}', '\u{114b8}', IndicConjunctBreak::Extend),
+    ('\u{114ba}', '\u{114ba}', IndicConjunctBreak::Extend),
+    ('\u{114bd}', '\u{114bd}', IndicConjunctBreak::Extend),
+    ('\u{114bf}', '\u{114c0}', IndicConjunctBreak::Extend),
+    ('\u{114c2}', '\u{114c3}', IndicConjunctBreak::Extend),
+    ('\u{115af}', '\u{115af}', IndicConjunctBreak::Extend),
+    ('\u{115b2}', '\u{115b5}', IndicConjunctBreak::Extend),
+    ('\u{115bc}', '\u{115bd}', IndicConjunctBreak::Extend),
+    ('\u{115bf}', '\u{115c0}', IndicConjunctBreak::Extend),
+    ('\u{115dc}', '\u{115dd}', IndicConjunctBreak::Extend),
+    ('\u{11633}', '\u{1163a}', IndicConjunctBreak::Extend),
+    ('\u{1163d}', '\u{1163d}', IndicConjunctBreak::Extend),
+    ('\u{1163f}', '\u{11640}', IndicConjunctBreak::Extend),
+    ('\u{116ab}', '\u{116ab}', IndicConjunctBreak::Extend),
+    ('\u{116ad}', '\u{116ad}', IndicConjunctBreak::Extend),
+    ('\u{116b0}', '\u{116b7}', IndicConjunctBreak::Extend),
+    ('\u{1171d}', '\u{1171d}', IndicConjunctBreak::Extend),
+    ('\u{1171f}', '\u{1171f}', IndicConjunctBreak::Extend),
+    ('\u{11722}', '\u{11725}', IndicConjunctBreak::Extend),
+    ('\u{11727}', '\u{1172b}', IndicConjunctBreak::Extend),
+    ('\u{1182f}', '\u{11837}', IndicConjunctBreak::Extend),
+    ('\u{11839}', '\u{1183a}', IndicConjunctBreak::Extend),
+    ('\u{11900}', '\u{11906}', IndicConjunctBreak::Consonant),
+    ('\u{11909}', '\u{11909}', IndicConjunctBreak::Consonant),
+    ('\u{1190c}', '\u{11913}', IndicConjunctBreak::Consonant),
+    ('\u{11915}', '\u{11916}', IndicConjunctBreak::Consonant),
+    ('\u{11918}', '\u{1192f}', IndicConjunctBreak::Consonant),
+    ('\u{11930}', '\u{11930}', IndicConjunctBreak::Extend),
+    ('\u{1193b}', '\u{1193d}', IndicConjunctBreak::Extend),
+    ('\u{1193e}', '\u{1193e}', IndicConjunctBreak::Linker),
+    ('\u{11943}', '\u{11943}', IndicConjunctBreak::Extend),
+    ('\u{119d4}', '\u{119d7}', IndicConjunctBreak::Extend),
+    ('\u{119da}', '\u{119db}', IndicConjunctBreak::Extend),
+    ('\u{119e0}', '\u{119e0}', IndicConjunctBreak::Extend),
+    ('\u{11a00}', '\u{11a00}', IndicConjunctBreak::Consonant),
+    ('\u{11a01}', '\u{11a0a}', IndicConjunctBreak::Extend),
+    ('\u{11a0b}', '\u{11a32}', IndicConjunctBreak::Consonant),
+    ('\u{11a33}', '\u{11a38}', IndicConjunctBreak::Extend),
+    ('\u{11a3b}', '\u{11a3e}', IndicConjunctBreak::Extend),
+    ('\u{11a47}', '\u{11a47}', IndicConjunctBreak::Linker),
+    ('\u{11a50}', '\u{11a50}', IndicConjunctBreak::Consonant),
+    ('\u{11a51}', '\u{11a56}', IndicConjunctBreak::Extend),
+    ('\u{11a59}', '\u{11a5b}', IndicConjunctBreak::Extend),
+    ('\u{11a5c}', '\u{11a83}', IndicConjunctBreak::Consonant),
+    ('\u{11a8a}', '\u{11a96}', IndicConjunctBreak::Extend),
+    ('\u{11a98}', '\u{11a98}', IndicConjunctBreak::Extend),
+    ('\u{11a99}', '\u{11a99}', IndicConjunctBreak::Linker),
+    ('\u{11b60}', '\u{11b60}', IndicConjunctBreak::Extend),
+    ('\u{11b62}', '\u{11b64}', IndicConjunctBreak::Extend),
+    ('\u{11b66}', '\u{11b66}', IndicConjunctBreak::Extend),
+    ('\u{11c30}', '\u{11c36}', IndicConjunctBreak::Extend),
+    ('\u{11c38}', '\u{11c3d}', IndicConjunctBreak::Extend),
+    ('\u{11c3f}', '\u{11c3f}', IndicConjunctBreak::Extend),
+    ('\u{11c92}', '\u{11ca7}', IndicConjunctBreak::Extend),
+    ('\u{11caa}', '\u{11cb0}', IndicConjunctBreak::Extend),
+    ('\u{11cb2}', '\u{11cb3}', IndicConjunctBreak::Extend),
+    ('\u{11cb5}', '\u{11cb6}', IndicConjunctBreak::Extend),
+    ('\u{11d31}', '\u{11d36}', IndicConjunctBreak::Extend),
+    ('\u{11d3a}', '\u{11d3a}', IndicConjunctBreak::Extend),
+    ('\u{11d3c}', '\u{11d3d}', IndicConjunctBreak::Extend),
+    ('\u{11d3f}', '\u{11d45}', IndicConjunctBreak::Extend),
+    ('\u{11d47}', '\u{11d47}', IndicConjunctBreak::Extend),
+    ('\u{11d90}', '\u{11d91}', IndicConjunctBreak::Extend),
+    ('\u{11d95}', '\u{11d95}', IndicConjunctBreak::Extend),
+    ('\u{11d97}', '\u{11d97}', IndicConjunctBreak::Extend),
+    ('\u{11ef3}', '\u{11ef4}', IndicConjunctBreak::Extend),
+    ('\u{11f00}', '\u{11f01}', IndicConjunctBreak::Extend),
+    ('\u{11f04}', '\u{11f10}', IndicConjunctBreak::Consonant),
+    ('\u{11f12}', '\u{11f33}', IndicConjunctBreak::Consonant),
+    ('\u{11f36}', '\u{11f3a}', IndicConjunctBreak::Extend),
+    ('\u{11f40}', '\u{11f41}', IndicConjunctBreak::Extend),
+    ('\u{11f42}', '\u{11f42}', IndicConjunctBreak::Linker),
+    ('\u{11f5a}', '\u{11f5a}', IndicConjunctBreak::Extend),
+    ('\u{13440}', '\u{13440}', IndicConjunctBreak::Extend),
+    ('\u{13447}', '\u{13455}', IndicConjunctBreak::Extend),
+    ('\u{1611e}', '\u{16129}', IndicConjunctBreak::Extend),
+    ('\u{1612d}', '\u{1612f}', IndicConjunctBreak::Extend),
+    ('\u{16af0}', '\u{16af4}', IndicConjunctBreak::Extend),
+    ('\u{16b30}', '\u{16b36}', IndicConjunctBreak::Extend),
+    ('\u{16f4f}', '\u{16f4f}', IndicConjunctBreak::Extend),
+    ('\u{16f8f}', '\u{16f92}', IndicConjunctBreak::Extend),
+    ('\u{16fe4}', '\u{16fe4}', IndicConjunctBreak::Extend),
+    ('\u{16ff0}', '\u{16ff1}', IndicConjunctBreak::Extend),
+    ('\u{1bc9d}', '\u{1bc9e}', IndicConjunctBreak::Extend),
+    ('\u{1cf00}', '\u{1cf2d}', IndicConjunctBreak::Extend),
+    ('\u{1cf30}', '\u{1cf46}', IndicConjunctBreak::Extend),
+    ('\u{1d165}', '\u{1d169}', IndicConjunctBreak::Extend),
+    ('\u{1d16d}', '\u{1d172}', IndicConjunctBreak::Extend),
+    ('\u{1d17b}', '\u{1d182}', IndicConjunctBreak::Extend),
+    ('\u{1d185}', '\u{1d18b}', IndicConjunctBreak::Extend),
+    ('\u{1d1aa}', '\u{1d1ad}', IndicConjunctBreak::Extend),
+    ('\u{1d242}', '\u{1d244}', IndicConjunctBreak::Extend),
+    ('\u{1da00}', '\u{1da36}', IndicConjunctBreak::Extend),
+    ('\u{1da3b}', '\u{1da6c}', IndicConjunctBreak::Extend),
+    ('\u{1da75}', '\u{1da75}', IndicConjunctBreak::Extend),
+    ('\u{1da84}', '\u{1da84}', IndicConjunctBreak::Extend),
+    ('\u{1da9b}', '\u{1da9f}', IndicConjunctBreak::Extend),
+    ('\u{1daa1}', '\u{1daaf}', IndicConjunctBreak::Extend),
+    ('\u{1e000}', '\u{1e006}', IndicConjunctBreak::Extend),
+    ('\u{1e008}', '\u{1e018}', IndicConjunctBreak::Extend),
+    ('\u{1e01b}', '\u{1e021}', IndicConjunctBreak::Extend),
+    ('\u{1e023}', '\u{1e024}', IndicConjunctBreak::Extend),
+    ('\u{1e026}', '\u{1e02a}', IndicConjunctBreak::Extend),
+    ('\u{1e08f}', '\u{1e08f}', IndicConjunctBreak::Extend),
+    ('\u{1e130}', '\u{1e136}', IndicConjunctBreak::Extend),
+    ('\u{1e2ae}', '\u{1e2ae}', IndicConjunctBreak::Extend),
+    ('\u{1e2ec}', '\u{1e2ef}', IndicConjunctBreak::Extend),
+    ('\u{1e4ec}', '\u{1e4ef}', IndicConjunctBreak::Extend),
+    ('\u{1e5ee}', '\u{1e5ef}', IndicConjunctBreak::Extend),
+    ('\u{1e6e3}', '\u{1e6e3}', IndicConjunctBreak::Extend),
+    ('\u{1e6e6}', '\u{1e6e6}', IndicConjunctBreak::Extend),
+    ('\u{1e6ee}', '\u{1e6ef}', IndicConjunctBreak::Extend),
+    ('\u{1e6f5}', '\u{1e6f5}', IndicConjunctBreak::Extend),
+    ('\u{1e8d0}', '\u{1e8d6}', IndicConjunctBreak::Extend),
+    ('\u{1e944}', '\u{1e94a}', IndicConjunctBreak::Extend),
+    ('\u{1f3fb}', '\u{1f3ff}', IndicConjunctBreak::Extend),
+    ('\u{e0020}', '\u{e007f}', IndicConjunctBreak::Extend),
+    ('\u{e0100}', '\u{e01ef}', IndicConjunctBreak::Extend),
+];
+
+pub const EXTENDED_PICTOGRAPHIC_TABLE: &'static [(char, char)] = &[
+    ('\u{a9}', '\u{a9}'),
+    ('\u{ae}', '\u{ae}'),
+    ('\u{203c}', '\u{203c}'),
+    ('\u{2049}', '\u{2049}'),
+    ('\u{2122}', '\u{2122}'),
+    ('\u{2139}', '\u{2139}'),
+    ('\u{2194}', '\u{2199}'),
+    ('\u{21a9}', '\u{21aa}'),
+    ('\u{231a}', '\u{231b}'),
+    ('\u{2328}', '\u{2328}'),
+    ('\u{23cf}', '\u{23cf}'),
+    ('\u{23e9}', '\u{23f3}'),
+    ('\u{23f8}', '\u{23fa}'),
+    ('\u{24c2}', '\u{24c2}'),
+    ('\u{25aa}', '\u{25ab}'),
+    ('\u{25b6}', '\u{25b6}'),
+    ('\u{25c0}', '\u{25c0}'),
+    ('\u{25fb}', '\u{25fe}'),
+    ('\u{2600}', '\u{2604}'),
+    ('\u{260e}', '\u{260e}'),
+    ('\u{2611}', '\u{2611}'),
+    ('\u{2614}', '\u{2615}'),
+    ('\u{2618}', '\u{2618}'),
+    ('\u{261d}', '\u{261d}'),
+    ('\u{2620}', '\u{2620}'),
+    ('\u{2622}', '\u{2623}'),
+    ('\u{2626}', '\u{2626}'),
+    ('\u{262a}', '\u{262a}'),
+    ('\u{262e}', '\u{262f}'),
+    ('\u{2638}', '\u{263a}'),
+    ('\u{2640}', '\u{2640}'),
+    ('\u{2642}', '\u{2642}'),
+    ('\u{2648}', '\u{2653}'),
+    ('\u{265f}', '\u{2660}'),
+    ('\u{2663}', '\u{2663}'),
+    ('\u{2665}', '\u{2666}'),
+    ('\u{2668}', '\u{2668}'),
+    ('\u{267b}', '\u{267b}'),
+    ('\u{267e}', '\u{267f}'),
+    ('\u{2692}', '\u{2697}'),
+    ('\u{2699}', '\u{2699}'),
+    ('\u{269b}', '\u{269c}'),
+    ('\u{26a0}', '\u{26a1}'),
+    ('\u{26a7}', '\u{26a7}'),
+    ('\u{26aa}', '\u{26ab}'),
+    ('\u{26b0}', '\u{26b1}'),
+    ('\u{26bd}', '\u{26be}'),
+    ('\u{26c4}', '\u{26c5}'),
+    ('\u{26c8}', '\u{26c8}'),
+    ('\u{26ce}', '\u{26cf}'),
+    ('\u{26d1}', '\u{26d1}'),
+    ('\u{26d3}', '\u{26d4}'),
+    ('\u{26e9}', '\u{26ea}'),
+    ('\u{26f0}', '\u{26f5}'),
+    ('\u{26f7}', '\u{26fa}'),
+    ('\u{26fd}', '\u{26fd}'),
+    ('\u{2702}', '\u{2702}'),
+    ('\u{2705}', '\u{2705}'),
+    ('\u{2708}', '\u{270d}'),
+    ('\u{270f}', '\u{270f}'),
+    ('\u{2712}', '\u{2712}'),
+    ('\u{2714}', '\u{2714}'),
+    ('\u{2716}', '\u{2716}'),
+    ('\u{271d}', '\u{271d}'),
+    ('\u{2721}', '\u{2721}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{2733}', '\u{2734}'),
+    ('\u{2744}', '\u{2744}'),
+    ('\u{2747}', '\u{2747}'),
+    ('\u{274c}', '\u{274c}'),
+    ('\u{274e}', '\u{274e}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2763}', '\u{2764}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27a1}', '\u{27a1}'),
+    ('\u{27b0}', '\u{27b0}'),
+    ('\u{27bf}', '\u{27bf}'),
+    ('\u{2934}', '\u{2935}'),
+    ('\u{2b05}', '\u{2b07}'),
+    ('\u{2b1b}', '\u{2b1c}'),
+    ('\u{2b50}', '\u{2b50}'),
+    ('\u{2b55}', '\u{2b55}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{303d}', '\u{303d}'),
+    ('\u{3297}', '\u{3297}'),
+    ('\u{3299}', '\u{3299}'),
+    ('\u{1f004}', '\u{1f004}'),
+    ('\u{1f02c}', '\u{1f02f}'),
+    ('\u{1f094}', '\u{1f09f}'),
+    ('\u{1f0af}', '\u{1f0b0}'),
+    ('\u{1f0c0}', '\u{1f0c0}'),
+    ('\u{1f0cf}', '\u{1f0d0}'),
+    ('\u{1f0f6}', '\u{1f0ff}'),
+    ('\u{1f170}', '\u{1f171}'),
+    ('\u{1f17e}', '\u{1f17f}'),
+    ('\u{1f18e}', '\u{1f18e}'),
+    ('\u{1f191}', '\u{1f19a}'),
+    ('\u{1f1ae}', '\u{1f1e5}'),
+    ('\u{1f201}', '\u{1f20f}'),
+    ('\u{1f21a}', '\u{1f21a}'),
+    ('\u{1f22f}', '\u{1f22f}'),
+    ('\u{1f232}', '\u{1f23a}'),
+    ('\u{1f23c}', '\u{1f23f}'),
+    ('\u{1f249}', '\u{1f25f}'),
+    ('\u{1f266}', '\u{1f321}'),
+    ('\u{1f324}', '\u{1f393}'),
+    ('\u{1f396}', '\u{1f397}'),
+    ('\u{1f399}', '\u{1f39b}'),
+    ('\u{1f39e}', '\u{1f3f0}'),
+    ('\u{1f3f3}', '\u{1f3f5}'),
+    ('\u{1f3f7}', '\u{1f3fa}'),
+    ('\u{1f400}', '\u{1f4fd}'),
+    ('\u{1f4ff}', '\u{1f53d}'),
+    ('\u{1f549}', '\u{1f54e}'),
+    ('\u{1f550}', '\u{1f567}'),
+    ('\u{1f56f}', '\u{1f570}'),
+    ('\u{1f573}', '\u{1f57a}'),
+    ('\u{1f587}', '\u{1f587}'),
+    ('\u{1f58a}', '\u{1f58d}'),
+    ('\u{1f590}', '\u{1f590}'),
+    ('\u{1f595}', '\u{1f596}'),
+    ('\u{1f5a4}', '\u{1f5a5}'),
+    ('\u{1f5a8}', '\u{1f5a8}'),
+    ('\u{1f5b1}', '\u{1f5b2}'),
+    ('\u{1f5bc}', '\u{1f5bc}'),
+    ('\u{1f5c2}', '\u{1f5c4}'),
+    ('\u{1f5d1}', '\u{1f5d3}'),
+    ('\u{1f5dc}', '\u{1f5de}'),
+    ('\u{1f5e1}', '\u{1f5e1}'),
+    ('\u{1f5e3}', '\u{1f5e3}'),
+    ('\u{1f5e8}', '\u{1f5e8}'),
+    ('\u{1f5ef}', '\u{1f5ef}'),
+    ('\u{1f5f3}', '\u{1f5f3}'),
+    ('\u{1f5fa}', '\u{1f64f}'),
+    ('\u{1f680}', '\u{1f6c5}'),
+    ('\u{1f6cb}', '\u{1f6d2}'),
+    ('\u{1f6d5}', '\u{1f6e5}'),
+    ('\u{1f6e9}', '\u{1f6e9}'),
+    ('\u{1f6eb}', '\u{1f6f0}'),
+    ('\u{1f6f3}', '\u{1f6ff}'),
+    ('\u{1f7da}', '\u{1f7ff}'),
+    ('\u{1f80c}', '\u{1f80f}'),
+    ('\u{1f848}', '\u{1f84f}'),
+    ('\u{1f85a}', '\u{1f85f}'),
+    ('\u{1f888}', '\u{1f88f}'),
+    ('\u{1f8ae}', '\u{1f8af}'),
+    ('\u{1f8bc}', '\u{1f8bf}'),
+    ('\u{1f8c2}', '\u{1f8cf}'),
+    ('\u{1f8d9}', '\u{1f8ff}'),
+    ('\u{1f90c}', '\u{1f93a}'),
+    ('\u{1f93c}', '\u{1f945}'),
+    ('\u{1f947}', '\u{1f9ff}'),
+    ('\u{1fa58}', '\u{1fa5f}'),
+    ('\u{1fa6e}', '\u{1faff}'),
+    ('\u{1fc00}', '\u{1fffd}'),
+];
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum WordCat {
+    Any,
+    CR,
+    LF,
+    Newline,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Format,
+    Katakana,
+    HebrewLetter,
+    ALetter,
+    SingleQuote,
+    DoubleQuote,
+    MidNumLet,
+    MidLetter,
+    MidNum,
+    Numeric,
+    ExtendNumLet,
+    WSegSpace,
+}
+
+pub const WORD_CAT_TABLE: &'static [(char, char, WordCat)] = &[
+    ('\u{a}', '\u{a}', WordCat::LF),
+    ('\u{b}', '\u{c}', WordCat::Newline),
+    ('\u{d}', '\u{d}', WordCat::CR),
+    ('\u{20}', '\u{20}', WordCat::WSegSpace),
+    ('\u{22}', '\u{22}', WordCat::DoubleQuote),
+    ('\u{27}', '\u{27}', WordCat::SingleQuote),
+    ('\u{2c}', '\u{2c}', WordCat::MidNum),
+    ('\u{2e}', '\u{2e}', WordCat::MidNumLet),
+    ('\u{30}', '\u{39}', WordCat::Numeric),
+    ('\u{3a}', '\u{3a}', WordCat::MidLetter),
+    ('\u{3b}', '\u{3b}', WordCat::MidNum),
+    ('\u{41}', '\u{5a}', WordCat::ALetter),
+    ('\u{5f}', '\u{5f}', WordCat::ExtendNumLet),
+    ('\u{61}', '\u{7a}', WordCat::ALetter),
+    ('\u{85}', '\u{85}', WordCat::Newline),
+    ('\u{aa}', '\u{aa}', WordCat::ALetter),
+    ('\u{ad}', '\u{ad}', WordCat::Format),
+    ('\u{b5}', '\u{b5}', WordCat::ALetter),
+    ('\u{b7}', '\u{b7}', WordCat::MidLetter),
+    ('\u{b8}', '\u{b8}', WordCat::ALetter),
+    ('\u{ba}', '\u{ba}', WordCat::ALetter),
+    ('\u{c0}', '\u{d6}', WordCat::ALetter),
+    ('\u{d8}', '\u{f6}', WordCat::ALetter),
+    ('\u{f8}', '\u{2d7}', WordCat::ALetter),
+    ('\u{2de}', '\u{2ff}', WordCat::ALetter),
+    ('\u{300}', '\u{36f}', WordCat::Extend),
+    ('\u{370}', '\u{374}', WordCat::ALetter),
+    ('\u{376}', '\u{377}', WordCat::ALetter),
+    ('\u{37a}', '\u{37d}', WordCat::ALetter),
+    ('\u{37e}', '\u{37e}', WordCat::MidNum),
+    ('\u{37f}', '\u{37f}', WordCat::ALetter),
+    ('\u{386}', '\u{386}', WordCat::ALetter),
+    ('\u{387}', '\u{387}', WordCat::MidLetter),
+    ('\u{388}', '\u{38a}', WordCat::ALetter),
+    ('\u{38c}', '\u{38c}', WordCat::ALetter),
+    ('\u{38e}', '\u{3a1}', WordCat::ALetter),
+    ('\u{3a3}', '\u{3f5}', WordCat::ALetter),
+    ('\u{3f7}', '\u{481}', WordCat::ALetter),
+    ('\u{483}', '\u{489}', WordCat::Extend),
+    ('\u{48a}', '\u{52f}', WordCat::ALetter),
+    ('\u{531}', '\u{556}', WordCat::ALetter),
+    ('\u{559}', '\u{55c}', WordCat::ALetter),
+    ('\u{55e}', '\u{55e}', WordCat::ALetter),
+    ('\u{55f}', '\u{55f}', WordCat::MidLetter),
+    ('\u{560}', '\u{588}', WordCat::ALetter),
+    ('\u{589}', '\u{589}', WordCat::MidNum),
+    ('\u{58a}', '\u{58a}', WordCat::ALetter),
+    ('\u{591}', '\u{5bd}', WordCat::Extend),
+    ('\u{5bf}', '\u{5bf}', WordCat::Extend),
+    ('\u{5c1}', '\u{5c2}', WordCat::Extend),
+    ('\u{5c4}', '\u{5c5}', WordCat::Extend),
+    ('\u{5c7}', '\u{5c7}', WordCat::Extend),
+    ('\u{5d0}', '\u{5ea}', WordCat::HebrewLetter),
+    ('\u{5ef}', '\u{5f2}', WordCat::HebrewLetter),
+    ('\u{5f3}', '\u{5f3}', WordCat::ALetter),
+    ('\u{5f4}', '\u{5f4}', WordCat::MidLetter),
+    ('\u{600}', '\u{605}', WordCat::Numeric),
+    ('\u{60c}', '\u{60d}', WordCat::MidNum),
+    ('\u{610}', '\u{61a}', WordCat::Extend),
+    ('\u{61c}', '\u{61c}', WordCat::Format),
+    ('\u{620}', '\u{64a}', WordCat::ALetter),
+    ('\u{64b}', '\u{65f}', WordCat::Extend),
+    ('\u{660}', '\u{669}', WordCat::Numeric),
+    ('\u{66b}', '\u{66b}', WordCat::Numeric),
+    ('\u{66c}', '\u{66c}', WordCat::MidNum),
+    ('\u{66e}', '\u{66f}', WordCat::ALetter),
+    ('\u{670}', '\u{670}', WordCat::Extend),
+    ('\u{671}', '\u{6d3}', WordCat::ALetter),
+    ('\u{6d5}', '\u{6d5}', WordCat::ALetter),
+    ('\u{6d6}', '\u{6dc}', WordCat::Extend),
+    ('\u{6dd}', '\u{6dd}', WordCat::Numeric),
+    ('\u{6df}', '\u{6e4}', WordCat::Extend),
+    ('\u{6e5}', '\u{6e6}', WordCat::ALetter),
+    ('\u{6e7}', '\u{6e8}', WordCat::Extend),
+    ('\u{6ea}', '\u{6ed}', WordCat::Extend),
+    ('\u{6ee}', '\u{6ef}', WordCat::ALetter),
+    ('\u{6f0}', '\u{6f9}', WordCat::Numeric),
+    ('\u{6fa}', '\u{6fc}', WordCat::ALetter),
+    ('\u{6ff}', '\u{6ff}', WordCat::ALetter),
+    ('\u{70f}', '\u{710}', WordCat::ALetter),
+    ('\u{711}', '\u{711}', WordCat::Extend),
+    ('\u{712}', '\u{72f}', WordCat::ALetter),
+    ('\u{730}', '\u{74a}', WordCat::Extend),
+    ('\u{74d}', '\u{7a5}', WordCat::ALetter),
+    ('\u{7a6}', '\u{7b0}', WordCat::Extend),
+    ('\u{7b1}', '\u{7b1}', WordCat::ALetter),
+    ('\u{7c0}', '\u{7c9}', WordCat::Numeric),
+    ('\u{7ca}', '\u{7ea}', WordCat::ALetter),
+    ('\u{7eb}', '\u{7f3}', WordCat::Extend),
+    ('\u{7f4}', '\u{7f5}', WordCat::ALetter),
+    ('\u{7f8}', '\u{7f8}', WordCat::MidNum),
+    ('\u{7fa}', '\u{7fa}', WordCat::ALetter),
+    ('\u{7fd}', '\u{7fd}', WordCat::Extend),
+    ('\u{800}', '\u{815}', WordCat::ALetter),
+    ('\u{816}', '\u{819}', WordCat::Extend),
+    ('\u{81a}', '\u{81a}', WordCat::ALetter),
+    ('\u{81b}', '\u{823}', WordCat::Extend),
+    ('\u{824}', '\u{824}', WordCat::ALetter),
+    ('\u{825}', '\u{827}', WordCat::Extend),
+    ('\u{828}', '\u{828}', WordCat::ALetter),
+    ('\u{829}', '\u{82d}', WordCat::Extend),
+    ('\u{840}', '\u{858}', WordCat::ALetter),
+    ('\u{859}', '\u{85b}', WordCat::Extend),
+    ('\u{860}', '\u{86a}', WordCat::ALetter),
+    ('\u{870}', '\u{887}', WordCat::ALetter),
+    ('\u{889}', '\u{88f}', WordCat::ALetter),
+    ('\u{890}', '\u{891}', WordCat::Numeric),
+    ('\u{897}', '\u{89f}', WordCat::Extend),
+    ('\u{8a0}', '\u{8c9}', WordCat::ALetter),
+    ('\u{8ca}', '\u{8e1}', WordCat::Extend),
+    ('\u{8e2}', '\u{8e2}', WordCat::Numeric),
+    ('\u{8e3}', '\u{903}', WordCat::Extend),
+    ('\u{904}', '\u{939}', WordCat::ALetter),
+    ('\u{93a}', '\u{93c}', WordCat::Extend),
+    ('\u{93d}', '\u{93d}', WordCat::ALetter),
+    ('\u{93e}', '\u{94f}', WordCat::Extend),
+    ('\u{950}', '\u{950}', WordCat::ALetter),
+    ('\u{951}', '\u{957}', WordCat::Extend),
+    ('\u{958}', '\u{961}', WordCat::ALetter),
+    ('\u{962}', '\u{963}', WordCat::Extend),
+    ('\u{966}', '\u{96f}', WordCat::Numeric),
+    ('\u{971}', '\u{980}', WordCat::ALetter),
+    ('\u{981}', '\u{983}', WordCat::Extend),
+    ('\u{985}', '\u{98c}', WordCat::ALetter),
+    ('\u{98f}', '\u{990}', WordCat::ALetter),
+    ('\u{993}', '\u{9a8}', WordCat::ALetter),
+    ('\u{9aa}', '\u{9b0}', WordCat::ALetter),
+    ('\u{9b2}', '\u{9b2}', WordCat::ALetter),
+    ('\u{9b6}', '\u{9b9}', WordCat::ALetter),
+    ('\u{9bc}', '\u{9bc}', WordCat::Extend),
+    ('\u{9bd}', '\u{9bd}', WordCat::ALetter),
+    ('\u{9be}', '\u{9c4}', WordCat::Extend),
+    ('\u{9c7}', '\u{9c8}', WordCat::Extend),
+    ('\u{9cb}', '\u{9cd}', WordCat::Extend),
+    ('\u{9ce}', '\u{9ce}', WordCat::ALetter),
+    ('\u{9d7}', '\u{9d7}', WordCat::Extend),
+    ('\u{9dc}', '\u{9dd}', WordCat::ALetter),
+    ('\u{9df}', '\u{9e1}', WordCat::ALetter),
+    ('\u{9e2}', '\u{9e3}', WordCat::Extend),
+    ('\u{9e6}', '\u{9ef}', WordCat::Numeric),
+    ('\u{9f0}', '\u{9f1}', WordCat::ALetter),
+    ('\u{9fc}', '\u{9fc}', WordCat::ALetter),
+    ('\u{9fe}', '\u{9fe}', WordCat::Extend),
+    ('\u{a01}', '\u{a03}', WordCat::Extend),
+    ('\u{a05}', '\u{a0a}', WordCat::ALetter),
+    ('\u{a0f}', '\u{a10}', WordCat::ALetter),
+    ('\u{a13}', '\u{a28}', WordCat::ALetter),
+    ('\u{a2a}', '\u{a30}', WordCat::ALetter),
+    ('\u{a32}', '\u{a33}', WordCat::ALetter),
+    ('\u{a35}', '\u{a36}', WordCat::ALetter),
+    ('\u{a38}', '\u{a39}', WordCat::ALetter),
+    ('\u{a3c}', '\u{a3c}', WordCat::Extend),
+    ('\u{a3e}', '\u{a42}', WordCat::Extend),
+    ('\u{a47}', '\u{a48}', WordCat::Extend),
+    ('\u{a4b}', '\u{a4d}', WordCat::Extend),
+    ('\u{a51}', '\u{a51}', WordCat::Extend),
+    ('\u{a59}', '\u{a5c}', WordCat::ALetter),
+    ('\u{a5e}', '\u{a5e}', WordCat::ALetter),
+    ('\u{a66}', '\u{a6f}', WordCat::Numeric),
+    ('\u{a70}', '\u{a71}', WordCat::Extend),
+    ('\u{a72}', '\u{a74}', WordCat::ALetter),
+    ('\u{a75}', '\u{a75}', WordCat::Extend),
+    ('\u{a81}', '\u{a83}', WordCat::Extend),
+    ('\u{a85}', '\u{a8d}', WordCat::ALetter),
+    ('\u{a8f}', '\u{a91}', WordCat::ALetter),
+    ('\u{a93}', '\u{aa8}', WordCat::ALetter),
+    ('\u{aaa}', '\u{ab0}', WordCat::ALetter),
+    ('\u{ab2}', '\u{ab3}', WordCat::ALetter),
+    ('\u{ab5}', '\u{ab9}', WordCat::ALetter),
+    ('\u{abc}', '\u{abc}', WordCat::Extend),
+    ('\u{abd}', '\u{abd}', WordCat::ALetter),
+    ('\u{abe}', '\u{ac5}', WordCat::Extend),
+    ('\u{ac7}', '\u{ac9}', WordCat::Extend),
+    ('\u{acb}', '\u{acd}', WordCat::Extend),
+    ('\u{ad0}', '\u{ad0}', WordCat::ALetter),
+    ('\u{ae0}', '\u{ae1}', WordCat::ALetter),
+    ('\u{ae2}', '\u{ae3}', WordCat::Extend),
+    ('\u{ae6}', '\u{aef}', WordCat::Numeric),
+    ('\u{af9}', '\u{af9}', WordCat::ALetter),
+    ('\u{afa}', '\u{aff}', WordCat::Extend),
+    ('\u{b01}', '\u{b03}', WordCat::Extend),
+    ('\u{b05}', '\u{b0c}', WordCat::ALetter),
+    ('\u{b0f}', '\u{b10}', WordCat::ALetter),
+    ('\u{b13}', '\u{b28}', WordCat::ALetter),
+    ('\u{b2a}', '\u{b30}', WordCat::ALetter),
+    ('\u{b32}', '\u{b33}', WordCat::ALetter),
+    ('\u{b35}', '\u{b39}', WordCat::ALetter),
+    ('\u{b3c}', '\u{b3c}', WordCat::Extend),
+    ('\u{b3d}', '\u{b3d}', WordCat::ALetter),
+    ('\u{b3e}', '\u{b44}', WordCat::Extend),
+    ('\u{b47}', '\u{b48}', WordCat::Extend),
+    ('\u{b4b}', '\u{b4d}', WordCat::Extend),
+    ('\u{b55}', '\u{b57}', WordCat::Extend),
+    ('\u{b5c}', '\u{b5d}', WordCat::ALetter),
+    ('\u{b5f}', '\u{b61}', WordCat::ALetter),
+    ('\u{b62}', '\u{b63}', WordCat::Extend),
+    ('\u{b66}', '\u{b6f}', WordCat::Numeric),
+    ('\u{b71}', '\u{b71}', WordCat::ALetter),
+    ('\u{b82}', '\u{b82}', WordCat::Extend),
+    ('\u{b83}', '\u{b83}', WordCat::ALetter),
+    ('\u{b85}', '\u{b8a}', WordCat::ALetter),
+    ('\u{b8e}', '\u{b90}', WordCat::ALetter),
+    ('\u{b92}', '\u{b95}', WordCat::ALetter),
+    ('\u{b99}', '\u{b9a}', WordCat::ALetter),
+    ('\u{b9c}', '\u{b9c}', WordCat::ALetter),
+    ('\u{b9e}', '\u{b9f}', WordCat::ALetter),
+    ('\u{ba3}', '\u{ba4}', WordCat::ALetter),
+    ('\u{ba8}', '\u{baa}', WordCat::ALetter),
+    ('\u{bae}', '\u{bb9}', WordCat::ALetter),
+    ('\u{bbe}', '\u{bc2}', WordCat::Extend),
+    ('\u{bc6}', '\u{bc8}', WordCat::Extend),
+    ('\u{bca}', '\u{bcd}', WordCat::Extend),
+    ('\u{bd0}', '\u{bd0}', WordCat::ALetter),
+    ('\u{bd7}', '\u{bd7}', WordCat::Extend),
+    ('\u{be6}', '\u{bef}', WordCat::Numeric),
+    ('\u{c00}', '\u{c04}', WordCat::Extend),
+    ('\u{c05}', '\u{c0c}', WordCat::ALetter),
+    ('\u{c0e}', '\u{c10}', WordCat::ALetter),
+    ('\u{c12}', '\u{c28}', WordCat::ALetter),
+    ('\u{c2a}', '\u{c39}', WordCat::ALetter),
+    ('\u{c3c}', '\u{c3c}', WordCat::Extend),
+    ('\u{c3d}', '\u{c3d}', WordCat::ALetter),
+    ('\u{c3e}', '\u{c44}', WordCat::Extend),
+    ('\u{c46}', '\u{c48}', WordCat::Extend),
+    ('\u{c4a}', '\u{c4d}', WordCat::Extend),
+    ('\u{c55}', '\u{c56}', WordCat::Extend),
+    ('\u{c58}', '\u{c5a}', WordCat::ALetter),
+    ('\u{c5c}', '\u{c5d}', WordCat::ALetter),
+    ('\u{c60}', '\u{c61}', WordCat::ALetter),
+    ('\u{c62}', '\u{c63}', WordCat::Extend),
+    ('\u{c66}', '\u{c6f}', WordCat::Numeric),
+    ('\u{c80}', '\u{c80}', WordCat::ALetter),
+    ('\u{c81}', '\u{c83}', WordCat::Extend),
+    ('\u{c85}', '\u{c8c}', WordCat::ALetter),
+    ('\u{c8e}', '\u{c90}', WordCat::ALetter),
+    ('\u{c92}', '\u{ca8}', WordCat::ALetter),
+    ('\u{caa}', '\u{cb3}', WordCat::ALetter),
+    ('\u{cb5}', '\u{cb9}', WordCat::ALetter),
+    ('\u{cbc}', '\u{cbc}', WordCat::Extend),
+    ('\u{cbd}', '\u{cbd}', WordCat::ALetter),
+    ('\u{cbe}', '\u{cc4}', WordCat::Extend),
+    ('\u{cc6}', '\u{cc8}', WordCat::Extend),
+    ('\u{cca}', '\u{ccd}', WordCat::Extend),
+    ('\u{cd5}', '\u{cd6}', WordCat::Extend),
+    ('\u{cdc}', '\u{cde}', WordCat::ALetter),
+    ('\u{ce0}', '\u{ce1}', WordCat::ALetter),
+    ('\u{ce2}', '\u{ce3}', WordCat::Extend),
+    ('\u{ce6}', '\u{cef}', WordCat::Numeric),
+    ('\u{cf1}', '\u{cf2}', WordCat::ALetter),
+    ('\u{cf3}', '\u{cf3}', WordCat::Extend),
+    ('\u{d00}', '\u{d03}', WordCat::Extend),
+    ('\u{d04}', '\u{d0c}', WordCat::ALetter),
+    ('\u{d0e}', '\u{d10}', WordCat::ALetter),
+    ('\u{d12}', '\u{d3a}', WordCat::ALetter),
+    ('\u{d3b}', '\u{d3c}', WordCat::Extend),
+    ('\u{d3d}', '\u{d3d}', WordCat::ALetter),
+    ('\u{d3e}', '\u{d44}', WordCat::Extend),
+    ('\u{d46}', '\u{d48}', WordCat::Extend),
+    ('\u{d4a}', '\u{d4d}', WordCat::Extend),
+    ('\u{d4e}', '\u{d4e}', WordCat::ALetter),
+    ('\u{d54}', '\u{d56}', WordCat::ALetter),
+    ('\u{d57}', '\u{d57}', WordCat::Extend),
+    ('\u{d5f}', '\u{d61}', WordCat::ALetter),
+    ('\u{d62}', '\u{d63}', WordCat::Extend),
+    ('\u{d66}', '\u{d6f}', WordCat::Numeric),
+    ('\u{d7a}', '\u{d7f}', WordCat::ALetter),
+    ('\u{d81}', '\u{d83}', WordCat::Extend),
+    ('\u{d85}', '\u{d96}', WordCat::ALetter),
+    ('\u{d9a}', '\u{db1}', WordCat::ALetter),
+    ('\u{db3}', '\u{dbb}', WordCat::ALetter),
+    ('\u{dbd}', '\u{dbd}', WordCat::ALetter),
+    ('\u{dc0}', '\u{dc6}', WordCat::ALetter),
+    ('\u{dca}', '\u{dca}', WordCat::Extend),
+    ('\u{dcf}', '\u{dd4}', WordCat::Extend),
+    ('\u{dd6}', '\u{dd6}', WordCat::Extend),
+    ('\u{dd8}', '\u{ddf}', WordCat::Extend),
+    ('\u{de6}', '\u{def}', WordCat::Numeric),
+    ('\u{df2}', '\u{df3}', WordCat::Extend),
+    ('\u{e31}', '\u{e31}', WordCat::Extend),
+    ('\u{e34}', '\u{e3a}', WordCat::Extend),
+    ('\u{e47}', '\u{e4e}', WordCat::Extend),
+    ('\u{e50}', '\u{e59}', WordCat::Numeric),
+    ('\u{eb1}', '\u{eb1}', WordCat::Extend),
+    ('\u{eb4}', '\u{ebc}', WordCat::Extend),
+    ('\u{ec8}', '\u{ece}', WordCat::Extend),
+    ('\u{ed0}', '\u{ed9}', WordCat::Numeric),
+    ('\u{f00}', '\u{f00}', WordCat::ALetter),
+    ('\u{f18}', '\u{f19}', WordCat::Extend),
+    ('\u{f20}', '\u{f29}', WordCat::Numeric),
+    ('\u{f35}', '\u{f35}', WordCat::Extend),
+    ('\u{f37}', '\u{f37}', WordCat::Extend),
+    ('\u{f39}', '\u{f39}', WordCat::Extend),
+    ('\u{f3e}', '\u{f3f}', WordCat::Extend),
+    ('\u{f40}', '\u{f47}', WordCat::ALetter),
+    ('\u{f49}', '\u{f6c}', WordCat::ALetter),
+    ('\u{f71}', '\u{f84}', WordCat::Extend),
+    ('\u{f86}', '\u{f87}', WordCat::Extend),
+    ('\u{f88}', '\u{f8c}', WordCat::ALetter),
+    ('\u{f8d}', '\u{f97}', WordCat::Extend),
+    ('\u{f99}', '\u{fbc}', WordCat::Extend),
+    ('\u{fc6}', '\u{fc6}', WordCat::Extend),
+    ('\u{102b}', '\u{103e}', WordCat::Extend),
+    ('\u{1040}', '\u{1049}', WordCat::Numeric),
+    ('\u{1056}', '\u{1059}', WordCat::Extend),
+    ('\u{105e}', '\u{1060}', WordCat::Extend),
+    ('\u{1062}', '\u{1064}', WordCat::Extend),
+    ('\u{1067}', '\u{106d}', WordCat::Extend),
+    ('\u{1071}', '\u{1074}', WordCat::Extend),
+    ('\u{1082}', '\u{108d}', WordCat::Extend),
+    ('\u{108f}', '\u{108f}', WordCat::Extend),
+    ('\u{1090}', '\u{1099}', WordCat::Numeric),
+    ('\u{109a}', '\u{109d}', WordCat::Extend),
+    ('\u{10a0}', '\u{10c5}', WordCat::ALetter),
+    ('\u{10c7}', '\u{10c7}', WordCat::ALetter),
+    ('\u{10cd}', '\u{10cd}', WordCat::ALetter),
+    ('\u{10d0}', '\u{10fa}', WordCat::ALetter),
+    ('\u{10fc}', '\u{1248}', WordCat::ALetter),
+    ('\u{124a}', '\u{124d}', WordCat::ALetter),
+    ('\u{1250}', '\u{1256}', WordCat::ALetter),
+    ('\u{1258}', '\u{1258}', WordCat::ALetter),
+    ('\u{125a}', '\u{125d}', WordCat::ALetter),
+    ('\u{1260}', '\u{1288}', WordCat::ALetter),
+    ('\u{128a}', '\u{128d}', WordCat::ALetter),
+    ('\u{1290}', '\u{12b0}', WordCat::ALetter),
+    ('\u{12b2}', '\u{12b5}', WordCat::ALetter),
+    ('\u{12b8}', '\u{12be}', WordCat::ALetter),
+    ('\u{12c0}', '\u{12c0}', WordCat::ALetter),
+    ('\u{12c2}', '\u{12c5}', WordCat::ALetter),
+    ('\u{12c8}', '\u{12d6}', WordCat::ALetter),
+    ('\u{12d8}', '\u{1310}', WordCat::ALetter),
+    ('\u{1312}', '\u{1315}', WordCat::ALetter),
+    ('\u{1318}', '\u{135a}', WordCat::ALetter),
+    ('\u{135d}', '\u{135f}', WordCat::Extend),
+    ('\u{1380}', '\u{138f}', WordCat::ALetter),
+    ('\u{13a0}', '\u{13f5}', WordCat::ALetter),
+    ('\u{13f8}', '\u{13fd}', WordCat::ALetter),
+    ('\u{1401}', '\u{166c}', WordCat::ALetter),
+    ('\u{166f}', '\u{167f}', WordCat::ALetter),
+    ('\u{1680}', '\u{1680}', WordCat::WSegSpace),
+    ('\u{1681}', '\u{169a}', WordCat::ALetter),
+    ('\u{16a0}', '\u{16ea}', WordCat::ALetter),
+    ('\u{16ee}', '\u{16f8}', WordCat::ALetter),
+    ('\u{1700}', '\u{1711}', WordCat::ALetter),
+    ('\u{1712}', '\u{1715}', WordCat::Extend),
+    ('\u{171f}', '\u{1731}', WordCat::ALetter),
+    ('\u{1732}', '\u{1734}', WordCat::Extend),
+    ('\u{1740}', '\u{1751}', WordCat::ALetter),
+    ('\u{1752}', '\u{1753}', WordCat::Extend),
+    ('\u{1760}', '\u{176c}', WordCat::ALetter),
+    ('\u{176e}', '\u{1770}', WordCat::ALetter),
+    ('\u{1772}', '\u{1773}', WordCat::Extend),
+    ('\u{17b4}', '\u{17d3}', WordCat::Extend),
+    ('\u{17dd}', '\u{17dd}', WordCat::Extend),
+    ('\u{17e0}', '\u{17e9}', WordCat::Numeric),
+    ('\u{180b}', '\u{180d}', WordCat::Extend),
+    ('\u{180e}', '\u{180e}', WordCat::Format),
+    ('\u{180f}', '\u{180f}', WordCat::Extend),
+    ('\u{1810}', '\u{1819}', WordCat::Numeric),
+    ('\u{1820}', '\u{1878}', WordCat::ALetter),
+    ('\u{1880}', '\u{1884}', WordCat::ALetter),
+    ('\u{1885}', '\u{1886}', WordCat::Extend),
+    ('\u{1887}', '\u{18a8}', WordCat::ALetter),
+    ('\u{18a9}', '\u{18a9}', WordCat::Extend),
+    ('\u{18aa}', '\u{18aa}', WordCat::ALetter),
+    ('\u{18b0}', '\u{18f5}', WordCat::ALetter),
+    ('\u{1900}', '\u{191e}', WordCat::ALetter),
+    ('\u{1920}', '\u{192b}', WordCat::Extend),
+    ('\u{1930}', '\u{193b}', WordCat::Extend),
+    ('\u{1946}', '\u{194f}', WordCat::Numeric),
+    ('\u{19d0}', '\u{19da}', WordCat::Numeric),
+    ('\u{1a00}', '\u{1a16}', WordCat::ALetter),
+    ('\u{1a17}', '\u{1a1b}', WordCat::Extend),
+    ('\u{1a55}', '\u{1a5e}', WordCat::Extend),
+    ('\u{1a60}', '\u{1a7c}', WordCat::Extend),
+    ('\u{1a7f}', '\u{1a7f}', WordCat::Extend),
+    ('\u{1a80}', '\u{1a89}', WordCat::Numeric),
+    ('\u{1a90}', '\u{1a99}', WordCat::Numeric),
+    ('\u{1ab0}', '\u{1add}', WordCat::Extend),
+    ('\u{1ae0}', '\u{1aeb}', WordCat::Extend),
+    ('\u{1b00}', '\u{1b04}', WordCat::Extend),
+    ('\u{1b05}', '\u{1b33}', WordCat::ALetter),
+    ('\u{1b34}', '\u{1b44}', WordCat::Extend),
+    ('\u{1b45}', '\u{1b4c}', WordCat::ALetter),
+    ('\u{1b50}', '\u{1b59}', WordCat::Numeric),
+    ('\u{1b6b}', '\u{1b73}', WordCat::Extend),
+    ('\u{1b80}', '\u{1b82}', WordCat::Extend),
+    ('\u{1b83}', '\u{1ba0}', WordCat::ALetter),
+    ('\u{1ba1}', '\u{1bad}', WordCat::Extend),
+    ('\u{1bae}', '\u{1baf}', WordCat::ALetter),
+    ('\u{1bb0}', '\u{1bb9}', WordCat::Numeric),
+    ('\u{1bba}', '\u{1be5}', WordCat::ALetter),
+    ('\u{1be6}', '\u{1bf3}', WordCat::Extend),
+    ('\u{1c00}', '\u{1c23}', WordCat::ALetter),
+    ('\u{1c24}', '\u{1c37}', WordCat::Extend),
+    ('\u{1c40}', '\u{1c49}', WordCat::Numeric),
+    ('\u{1c4d}', '\u{1c4f}', WordCat::ALetter),
+    ('\u{1c50}', '\u{1c59}', WordCat::Numeric),
+    ('\u{1c5a}', '\u{1c7d}', WordCat::ALetter),
+    ('\u{1c80}', '\u{1c8a}', WordCat::ALetter),
+    ('\u{1c90}', '\u{1cba}', WordCat::ALetter),
+    ('\u{1cbd}', '\u{1cbf}', WordCat::ALetter),
+    ('\u{1cd0}', '\u{1cd2}', WordCat::Extend),
+    ('\u{1cd4}', '\u{1ce8}', WordCat::Extend),
+    ('\u{1ce9}', '\u{1cec}', WordCat::ALetter),
+    ('\u{1ced}', '\u{1ced}', WordCat::Extend),
+    ('\u{1cee}', '\u{1cf3}', WordCat::ALetter),
+    ('\u{1cf4}', '\u{1cf4}', WordCat::Extend),
+    ('\u{1cf5}', '\u{1cf6}', WordCat::ALetter),
+    ('\u{1cf7}', '\u{1cf9}', WordCat::Extend),
+    ('\u{1cfa}', '\u{1cfa}', WordCat::ALetter),
+    ('\u{1d00}', '\u{1dbf}', WordCat::ALetter),
+    ('\u{1dc0}', '\u{1dff}', WordCat::Extend),
+    ('\u{1e00}', '\u{1f15}', WordCat::ALetter),
+    ('\u{1f18}', '\u{1f1d}', WordCat::ALetter),
+    ('\u{1f20}', '\u{1f45}', WordCat::ALetter),
+    ('\u{1f48}', '\u{1f4d}', WordCat::ALetter),
+    ('\u{1f50}', '\u{1f57}', WordCat::ALetter),
+    ('\u{1f59}', '\u{1f59}', WordCat::ALetter),
+    ('\u{1f5b}', '\u{1f5b}', WordCat::ALetter),
+    ('\u{1f5d}', '\u{1f5d}', WordCat::ALetter),
+    ('\u{1f5f}', '\u{1f7d}', WordCat::ALetter),
+    ('\u{1f80}', '\u{1fb4}', WordCat::ALetter),
+    ('\u{1fb6}', '\u{1fbc}', WordCat::ALetter),
+    ('\u{1fbe}', '\u{1fbe}', WordCat::ALetter),
+    ('\u{1fc2}', '\u{1fc4}', WordCat::ALetter),
+    ('\u{1fc6}', '\u{1fcc}', WordCat::ALetter),
+    ('\u{1fd0}', '\u{1fd3}', WordCat::ALetter),
+    ('\u{1fd6}', '\u{1fdb}', WordCat::ALetter),
+    ('\u{1fe0}', '\u{1fec}', WordCat::ALetter),
+    ('\u{1ff2}', '\u{1ff4}', WordCat::ALetter),
+    ('\u{1ff6}', '\u{1ffc}', WordCat::ALetter),
+    ('\u{2000}', '\u{2006}', WordCat::WSegSpace),
+    ('\u{2008}', '\u{200a}', WordCat::WSegSpace),
+    ('\u{200c}', '\u{200c}', WordCat::Extend),
+    ('\u{200d}', '\u{200d}', WordCat::ZWJ),
+    ('\u{200e}', '\u{200f}', WordCat::Format),
+    ('\u{2018}', '\u{2019}', WordCat::MidNumLet),
+    ('\u{2024}', '\u{2024}', WordCat::MidNumLet),
+    ('\u{2027}', '\u{2027}', WordCat::MidLetter),
+    ('\u{2028}', '\u{2029}', WordCat::Newline),
+    ('\u{202a}', '\u{202e}', WordCat::Format),
+    ('\u{202f}', '\u{202f}', WordCat::ExtendNumLet),
+    ('\u{203f}', '\u{2040}', WordCat::ExtendNumLet),
+    ('\u{2044}', '\u{2044}', WordCat::MidNum),
+    ('\u{2054}', '\u{2054}', WordCat::ExtendNumLet),
+    ('\u{205f}', '\u{205f}', WordCat::WSegSpace),
+    ('\u{2060}', '\u{2064}', WordCat::Format),
+    ('\u{2066}', '\u{206f}', WordCat::Format),
+    ('\u{2071}', '\u{2071}', WordCat::ALetter),
+    ('\u{207f}', '\u{207f}', WordCat::ALetter),
+    ('\u{2090}', '\u{209c}', WordCat::ALetter),
+    ('\u{20d0}', '\u{20f0}', WordCat::Extend),
+    ('\u{2102}', '\u{2102}', WordCat::ALetter),
+    ('\u{2107}', '\u{2107}', WordCat::ALetter),
+    ('\u{210a}', '\u{2113}', WordCat::ALetter),
+    ('\u{2115}', '\u{2115}', WordCat::ALetter),
+    ('\u{2119}', '\u{211d}', WordCat::ALetter),
+    ('\u{2124}', '\u{2124}', WordCat::ALetter),
+    ('\u{2126}', '\u{2126}', WordCat::ALetter),
+    ('\u{2128}', '\u{2128}', WordCat::ALetter),
+    ('\u{212a}', '\u{212d}', WordCat::ALetter),
+    ('\u{212f}', '\u{2139}', WordCat::ALetter),
+    ('\u{213c}', '\u{213f}', WordCat::ALetter),
+    ('\u{2145}', '\u{2149}', WordCat::ALetter),
+    ('\u{214e}', '\u{214e}', WordCat::ALetter),
+    ('\u{2160}', '\u{2188}', WordCat::ALetter),
+    ('\u{24b6}', '\u{24e9}', WordCat::ALetter),
+    ('\u{2c00}', '\u{2ce4}', WordCat::ALetter),
+    ('\u{2ceb}', '\u{2cee}', WordCat::ALetter),
+    ('\u{2cef}', '\u{2cf1}', WordCat::Extend),
+    ('\u{2cf2}', '\u{2cf3}', WordCat::ALetter),
+    ('\u{2d00}', '\u{2d25}', WordCat::ALetter),
+    ('\u{2d27}', '\u{2d27}', WordCat::ALetter),
+    ('\u{2d2d}', '\u{2d2d}', WordCat::ALetter),
+    ('\u{2d30}', '\u{2d67}', WordCat::ALetter),
+    ('\u{2d6f}', '\u{2d6f}', WordCat::ALetter),
+    ('\u{2d7f}', '\u{2d7f}', WordCat::Extend),
+    ('\u{2d80}', '\u{2d96}', WordCat::ALetter),
+    ('\u{2da0}', '\u{2da6}', WordCat::ALetter),
+    ('\u{2da8}', '\u{2dae}', WordCat::ALetter),
+    ('\u{2db0}', '\u{2db6}', WordCat::ALetter),
+    ('\u{2db8}', '\u{2dbe}', WordCat::ALetter),
+    ('\u{2dc0}', '\u{2dc6}', WordCat::ALetter),
+    ('\u{2dc8}', '\u{2dce}', WordCat::ALetter),
+    ('\u{2dd0}', '\u{2dd6}', WordCat::ALetter),
+    ('\u{2dd8}', '\u{2dde}', WordCat::ALetter),
+    ('\u{2de0}', '\u{2dff}', WordCat::Extend),
+    ('\u{2e2f}', '\u{2e2f}', WordCat::ALetter),
+    ('\u{3000}', '\u{3000}', WordCat::WSegSpace),
+    ('\u{3005}', '\u{3005}', WordCat::ALetter),
+    ('\u{302a}', '\u{302f}', WordCat::Extend),
+    ('\u{3031}', '\u{3035}', WordCat::Katakana),
+    ('\u{303b}', '\u{303c}', WordCat::ALetter),
+    ('\u{3099}', '\u{309a}', WordCat::Extend),
+    ('\u{309b}', '\u{309c}', WordCat::Katakana),
+    ('\u{30a0}', '\u{30fa}', WordCat::Katakana),
+    ('\u{30fc}', '\u{30ff}', WordCat::Katakana),
+    ('\u{3105}', '\u{312f}', WordCat::ALetter),
+    ('\u{3131}', '\u{318e}', WordCat::ALetter),
+    ('\u{31a0}', '\u{31bf}', WordCat::ALetter),
+    ('\u{31f0}', '\u{31ff}', WordCat::Katakana),
+    ('\u{32d0}', '\u{32fe}', WordCat::Katakana),
+    ('\u{3300}', '\u{3357}', WordCat::Katakana),
+    ('\u{a000}', '\u{a48c}', WordCat::ALetter),
+    ('\u{a4d0}', '\u{a4fd}', WordCat::ALetter),
+    ('\u{a500}', '\u{a60c}', WordCat::ALetter),
+    ('\u{a610}', '\u{a61f}', WordCat::ALetter),
+    ('\u{a620}', '\u{a629}', WordCat::Numeric),
+    ('\u{a62a}', '\u{a62b}', WordCat::ALetter),
+    ('\u{a640}', '\u{a66e}', WordCat::ALetter),
+    ('\u{a66f}', '\u{a672}', WordCat::Extend),
+    ('\u{a674}', '\u{a67d}', WordCat::Extend),
+    ('\u{a67f}', '\u{a69d}', WordCat::ALetter),
+    ('\u{a69e}', '\u{a69f}', WordCat::Extend),
+    ('\u{a6a0}', '\u{a6ef}', WordCat::ALetter),
+    ('\u{a6f0}', '\u{a6f1}', WordCat::Extend),
+    ('\u{a708}', '\u{a7dc}', WordCat::ALetter),
+    ('\u{a7f1}', '\u{a801}', WordCat::ALetter),
+    ('\u{a802}', '\u{a802}', WordCat::Extend),
+    ('\u{a803}', '\u{a805}', WordCat::ALetter),
+    ('\u{a806}', '\u{a806}', WordCat::Extend),
+    ('\u{a807}', '\u{a80a}', WordCat::ALetter),
+    ('\u{a80b}', '\u{a80b}', WordCat::Extend),
+    ('\u{a80c}', '\u{a822}', WordCat::ALetter),
+    ('\u{a823}', '\u{a827}', WordCat::Extend),
+    ('\u{a82c}', '\u{a82c}', WordCat::Extend),
+    ('\u{a840}', '\u{a873}', WordCat::ALetter),
+    ('\u{a880}', '\u{a881}', WordCat::Extend),
+    ('\u{a882}', '\u{a8b3}', WordCat::ALetter),
+    ('\u{a8b4}', '\u{a8c5}', WordCat::Extend),
+    ('\u{a8d0}', '\u{a8d9}', WordCat::Numeric),
+    ('\u{a8e0}', '\u{a8f1}', WordCat::Extend),
+    ('\u{a8f2}', '\u{a8f7}', WordCat::ALetter),
+    ('\u{a8fb}', '\u{a8fb}', WordCat::ALetter),
+    ('\u{a8fd}', '\u{a8fe}', WordCat::ALetter),
+    ('\u{a8ff}', '\u{a8ff}', WordCat::Extend),
+    ('\u{a900}', '\u{a909}', WordCat::Numeric),
+    ('\u{a90a}', '\u{a925}', WordCat::ALetter),
+    ('\u{a926}', '\u{a92d}', WordCat::Extend),
+    ('\u{a930}', '\u{a946}', WordCat::ALetter),
+    ('\u{a947}', '\u{a953}', WordCat::Extend),
+    ('\u{a960}', '\u{a97c}', WordCat::ALetter),
+    ('\u{a980}', '\u{a983}', WordCat::Extend),
+    ('\u{a984}', '\u{a9b2}', WordCat::ALetter),
+    ('\u{a9b3}', '\u{a9c0}', WordCat::Extend),
+    ('\u{a9cf}', '\u{a9cf}', WordCat::ALetter),
+    ('\u{a9d0}', '\u{a9d9}', WordCat::Numeric),
+    ('\u{a9e5}', '\u{a9e5}', WordCat::Extend),
+    ('\u{a9f0}', '\u{a9f9}', WordCat::Numeric),
+    ('\u{aa00}', '\u{aa28}', WordCat::ALetter),
+    ('\u{aa29}', '\u{aa36}', WordCat::Extend),
+    ('\u{aa40}', '\u{aa42}', WordCat::ALetter),
+    ('\u{aa43}', '\u{aa43}', WordCat::Extend),
+    ('\u{aa44}', '\u{aa4b}', WordCat::ALetter),
+    ('\u{aa4c}', '\u{aa4d}', WordCat::Extend),
+    ('\u{aa50}', '\u{aa59}', WordCat::Numeric),
+    ('\u{aa7b}', '\u{aa7d}', WordCat::Extend),
+    ('\u{aab0}', '\u{aab0}', WordCat::Extend),
+    ('\u{aab2}', '\u{aab4}', WordCat::Extend),
+    ('\u{aab7}', '\u{aab8}', WordCat::Extend),
+    ('\u{aabe}', '\u{aabf}', WordCat::Extend),
+    ('\u{aac1}', '\u{aac1}', WordCat::Extend),
+    ('\u{aae0}', '\u{aaea}', WordCat::ALetter),
+    ('\u{aaeb}', '\u{aaef}', WordCat::Extend),
+    ('\u{aaf2}', '\u{aaf4}', WordCat::ALetter),
+    ('\u{aaf5}', '\u{aaf6}', WordCat::Extend),
+    ('\u{ab01}', '\u{ab06}', WordCat::ALetter),
+    ('\u{ab09}', '\u{ab0e}', WordCat::ALetter),
+    ('\u{ab11}', '\u{ab16}', WordCat::ALetter),
+    ('\u{ab20}', '\u{ab26}', WordCat::ALetter),
+    ('\u{ab28}', '\u{ab2e}', WordCat::ALetter),
+    ('\u{ab30}', '\u{ab69}', WordCat::ALetter),
+    ('\u{ab70}', '\u{abe2}', WordCat::ALetter),
+    ('\u{abe3}', '\u{abea}', WordCat::Extend),
+    ('\u{abec}', '\u{abed}', WordCat::Extend),
+    ('\u{abf0}', '\u{abf9}', WordCat::Numeric),
+    ('\u{ac00}', '\u{d7a3}', WordCat::ALetter),
+    ('\u{d7b0}', '\u{d7c6}', WordCat::ALetter),
+    ('\u{d7cb}', '\u{d7fb}', WordCat::ALetter),
+    ('\u{fb00}', '\u{fb06}', WordCat::ALetter),
+    ('\u{fb13}', '\u{fb17}', WordCat::ALetter),
+    ('\u{fb1d}', '\u{fb1d}', WordCat::HebrewLetter),
+    ('\u{fb1e}', '\u{fb1e}', WordCat::Extend),
+    ('\u{fb1f}', '\u{fb28}', WordCat::HebrewLetter),
+    ('\u{fb2a}', '\u{fb36}', WordCat::HebrewLetter),
+    ('\u{fb38}', '\u{fb3c}', WordCat::HebrewLetter),
+    ('\u{fb3e}', '\u{fb3e}', WordCat::HebrewLetter),
+    ('\u{fb40}', '\u{fb41}', WordCat::HebrewLetter),
+    ('\u{fb43}', '\u{fb44}', WordCat::HebrewLetter),
+    ('\u{fb46}', '\u{fb4f}', WordCat::HebrewLetter),
+    ('\u{fb50}', '\u{fbb1}', WordCat::ALetter),
+    ('\u{fbd3}', '\u{fd3d}', WordCat::ALetter),
+    ('\u{fd50}', '\u{fd8f}', WordCat::ALetter),
+    ('\u{fd92}', '\u{fdc7}', WordCat::ALetter),
+    ('\u{fdf0}', '\u{fdfb}', WordCat::ALetter),
+    ('\u{fe00}', '\u{fe0f}', WordCat::Extend),
+    ('\u{fe13}', '\u{fe13}', WordCat::MidLetter),
+    ('\u{fe20}', '\u{fe2f}', WordCat::Extend),
+    ('\u{fe33}', '\u{fe34}', WordCat::ExtendNumLet),
+    ('\u{fe4d}', '\u{fe4f}', WordCat::ExtendNumLet),
+    ('\u{fe50}', '\u{fe50}', WordCat::MidNum),
+    ('\u{fe52}', '\u{fe52}', WordCat::MidNumLet),
+    ('\u{fe54}', '\u{fe54}', WordCat::MidNum),
+    ('\u{fe55}', '\u{fe55}', WordCat::MidLetter),
+    ('\u{fe70}', '\u{fe74}', WordCat::ALetter),
+    ('\u{fe76}', '\u{fefc}', WordCat::ALetter),
+    ('\u{feff}', '\u{feff}', WordCat::Format),
+    ('\u{ff07}', '\u{ff07}', WordCat::MidNumLet),
+    ('\u{ff0c}', '\u{ff0c}', WordCat::MidNum),
+    ('\u{ff0e}', '\u{ff0e}', WordCat::MidNumLet),
+    ('\u{ff10}', '\u{ff19}', WordCat::Numeric),
+    ('\u{ff1a}', '\u{ff1a}', WordCat::MidLetter),
+    ('\u{ff1b}', '\u{ff1b}', WordCat::MidNum),
+    ('\u{ff21}', '\u{ff3a}', WordCat::ALetter),
+    ('\u{ff3f}', '\u{ff3f}', WordCat::ExtendNumLet),
+    ('\u{ff41}', '\u{ff5a}', WordCat::ALetter),
+    ('\u{ff66}', '\u{ff9d}', WordCat::Katakana),
+    ('\u{ff9e}', '\u{ff9f}', WordCat::Extend),
+    ('\u{ffa0}', '\u{ffbe}', WordCat::ALetter),
+    ('\u{ffc2}', '\u{ffc7}', WordCat::ALetter),
+    ('\u{ffca}', '\u{ffcf}', WordCat::ALetter),
+    ('\u{ffd2}', '\u{ffd7}', WordCat::ALetter),
+    ('\u{ffda}', '\u{ffdc}', WordCat::ALetter),
+    ('\u{fff9}', '\u{fffb}', WordCat::Format),
+    ('\u{10000}', '\u{1000b}', WordCat::ALetter),
+    ('\u{1000d}', '\u{10026}', WordCat::ALetter),
+    ('\u{10028}', '\u{1003a}', WordCat::ALetter),
+    ('\u{1003c}', '\u{1003d}', WordCat::ALetter),
+    ('\u{1003f}', '\u{1004d}', WordCat::ALetter),
+    ('\u{10050}', '\u{1005d}', WordCat::ALetter),
+    ('\u{10080}', '\u{100fa}', WordCat::ALetter),
+    ('\u{10140}', '\u{10174}', WordCat::ALetter),
+    ('\u{101fd}', '\u{101fd}', WordCat::Extend),
+    ('\u{10280}', '\u{1029c}', WordCat::ALetter),
+    ('\u{102a0}', '\u{102d0}', WordCat::ALetter),
+    ('\u{102e0}', '\u{102e0}', WordCat::Extend),
+    ('\u{10300}', '\u{1031f}', WordCat::ALetter),
+    ('\u{1032d}', '\u{1034a}', WordCat::ALetter),
+    ('\u{10350}', '\u{10375}', WordCat::ALetter),
+    ('\u{10376}', '\u{1037a}', WordCat::Extend),
+    ('\u{10380}', '\u{1039d}', WordCat::ALetter),
+    ('\u{103a0}', '\u{103c3}', WordCat::ALetter),
+    ('\u{103c8}', '\u{103cf}', WordCat::ALetter),
+    ('\u{103d1}', '\u{103d5}', WordCat::ALetter),
+    ('\u{10400}', '\u{1049d}', WordCat::ALetter),
+    ('\u{104a0}', '\u{104a9}', WordCat::Numeric),
+    ('\u{104b0}', '\u{104d3}', WordCat::ALetter),
+    ('\u{104d8}', '\u{104fb}', WordCat::ALetter),
+    ('\u{10500}', '\u{10527}', WordCat::ALetter),
+    ('\u{10530}', '\u{10563}', WordCat::ALetter),
+    ('\u{10570}', '\u{1057a}', WordCat::ALetter),
+    ('\u{1057c}', '\u{1058a}', WordCat::ALetter),
+    ('\u{1058c}', '\u{10592}', WordCat::ALetter),
+    ('\u{10594}', '\u{10595}', WordCat::ALetter),
+    ('\u{10597}', '\u{105a1}', WordCat::ALetter),
+    ('\u{105a3}', '\u{105b1}', WordCat::ALetter),
+    ('\u{105b3}', '\u{105b9}', WordCat::ALetter),
+    ('\u{105bb}', '\u{105bc}', WordCat::ALetter),
+    ('\u{105c0}', '\u{105f3}', WordCat::ALetter),
+    ('\u{10600}', '\u{10736}', WordCat::ALetter),
+    ('\u{10740}', '\u{10755}', WordCat::ALetter),
+    ('\u{10760}', '\u{10767}', WordCat::ALetter),
+    ('\u{10780}', '\u{10785}', WordCat::ALetter),
+    ('\u{10787}', '\u{107b0}', WordCat::ALetter),
+    ('\u{107b2}', '\u{107ba}', WordCat::ALetter),
+    ('\u{10800}', '\u{10805}', WordCat::ALetter),
+    ('\u{10808}', '\u{10808}', WordCat::ALetter),
+    ('\u{1080a}', '\u{10835}', WordCat::ALetter),
+    ('\u{10837}', '\u{10838}', WordCat::ALetter),
+    ('\u{1083c}', '\u{1083c}', WordCat::ALetter),
+    ('\u{1083f}', '\u{10855}', WordCat::ALetter),
+    ('\u{10860}', '\u{10876}', WordCat::ALetter),
+    ('\u{10880}', '\u{1089e}', WordCat::ALetter),
+    ('\u{108e0}', '\u{108f2}', WordCat::ALetter),
+    ('\u{108f4}', '\u{108f5}', WordCat::ALetter),
+    ('\u{10900}', '\u{10915}', WordCat::ALetter),
+    ('\u{10920}', '\u{10939}', WordCat::ALetter),
+    ('\u{10940}', '\u{10959}', WordCat::ALetter),
+    ('\u{10980}', '\u{109b7}', WordCat::ALetter),
+    ('\u{109be}', '\u{109bf}', WordCat::ALetter),
+    ('\u{10a00}', '\u{10a00}', WordCat::ALetter),
+    ('\u{10a01}', '\u{10a03}', WordCat::Extend),
+    ('\u{10a05}', '\u{10a06}', WordCat::Extend),
+    ('\u{10a0c}', '\u{10a0f}', WordCat::Extend),
+    ('\u{10a10}', '\u{10a13}', WordCat::ALetter),
+    ('\u{10a15}', '\u{10a17}', WordCat::ALetter),
+    ('\u{10a19}', '\u{10a35}', WordCat::ALetter),
+    ('\u{10a38}', '\u{10a3a}', WordCat::Extend),
+    ('\u{10a3f}', '\u{10a3f}', WordCat::Extend),
+    ('\u{10a60}', '\u{10a7c}', WordCat::ALetter),
+    ('\u{10a80}', '\u{10a9c}', WordCat::ALetter),
+    ('\u{10ac0}', '\u{10ac7}', WordCat::ALetter),
+    ('\u{10ac9}', '\u{10ae4}', WordCat::ALetter),
+    ('\u{10ae5}', '\u{10ae6}', WordCat::Extend),
+    ('\u{10b00}', '\u{10b35}', WordCat::ALetter),
+    ('\u{10b40}', '\u{10b55}', WordCat::ALetter),
+    ('\u{10b60}', '\u{10b72}', WordCat::ALetter),
+    ('\u{10b80}', '\u{10b91}', WordCat::ALetter),
+    ('\u{10c00}', '\u{10c48}', WordCat::ALetter),
+    ('\u{10c80}', '\u{10cb2}', WordCat::ALetter),
+    ('\u{10cc0}', '\u{10cf2}', WordCat::ALetter),
+    ('\u{10d00}', '\u{10d23}', WordCat::ALetter),
+    ('\u{10d24}', '\u{10d27}', WordCat::Extend),
+    ('\u{10d30}', '\u{10d39}', WordCat::Numeric),
+    ('\u{10d40}', '\u{10d49}', WordCat::Numeric),
+    ('\u{10d4a}', '\u{10d65}', WordCat::ALetter),
+    ('\u{10d69}', '\u{10d6d}', WordCat::Extend),
+    ('\u{10d6f}', '\u{10d85}', WordCat::ALetter),
+    ('\u{10e80}', '\u{10ea9}', WordCat::ALetter),
+    ('\u{10eab}', '\u{10eac}', WordCat::Extend),
+    ('\u{10eb0}', '\u{10eb1}', WordCat::ALetter),
+    ('\u{10ec2}', '\u{10ec7}', WordCat::ALetter),
+    ('\u{10efa}', '\u{10eff}', WordCat::Extend),
+    ('\u{10f00}', '\u{10f1c}', WordCat::ALetter),
+    ('\u{10f27}', '\u{10f27}', WordCat::ALetter),
+    ('\u{10f30}', '\u{10f45}', WordCat::ALetter),
+    ('\u{10f46}', '\u{10f50}', WordCat::Extend),
+    ('\u{10f70}', '\u{10f81}', WordCat::ALetter),
+    ('\u{10f82}', '\u{10f85}', WordCat::Extend),
+    ('\u{10fb0}', '\u{10fc4}', WordCat::ALetter),
+    ('\u{10fe0}', '\u{10ff6}', WordCat::ALetter),
+    ('\u{11000}', '\u{11002}', WordCat::Extend),
+    ('\u{11003}', '\u{11037}', WordCat::ALetter),
+    ('\u{11038}', '\u{11046}', WordCat::Extend),
+    ('\u{11066}', '\u{1106f}', WordCat::Numeric),
+    ('\u{11070}', '\u{11070}', WordCat::Extend),
+    ('\u{11071}', '\u{11072}', WordCat::ALetter),
+    ('\u{11073}', '\u{11074}', WordCat::Extend),
+    ('\u{11075}', '\u{11075}', WordCat::ALetter),
+    ('\u{1107f}', '\u{11082}', WordCat::Extend),
+    ('\u{11083}', '\u{110af}', WordCat::ALetter),
+    ('\u{110b0}', '\u{110ba}', WordCat::Extend),
+    ('\u{110bd}', '\u{110bd}', WordCat::Numeric),
+    ('\u{110c2}', '\u{110c2}', WordCat::Extend),
+    ('\u{110cd}', '\u{110cd}', WordCat::Numeric),
+    ('\u{110d0}', '\u{110e8}', WordCat::ALetter),
+    ('\u{110f0}', '\u{110f9}', WordCat::Numeric),
+    ('\u{11100}', '\u{11102}', WordCat::Extend),
+    ('\u{11103}', '\u{11126}', WordCat::ALetter),
+    ('\u{11127}', '\u{11134}', WordCat::Extend),
+    ('\u{11136}', '\u{1113f}', WordCat::Numeric),
+    ('\u{11144}', '\u{11144}', WordCat::ALetter),
+    ('\u{11145}', '\u{11146}', WordCat::Extend),
+    ('\u{11147}', '\u{11147}', WordCat::ALetter),
+    ('\u{11150}', '\u{11172}', WordCat::ALetter),
+    ('\u{11173}', '\u{11173}', WordCat::Extend),
+    ('\u{11176}', '\u{11176}', WordCat::ALetter),
+    ('\u{11180}', '\u{11182}', WordCat::Extend),
+    ('\u{11183}', '\u{111b2}', WordCat::ALetter),
+    ('\u{111b3}', '\u{111c0}', WordCat::Extend),
+    ('\u{111c1}', '\u{111c4}', WordCat::ALetter),
+    ('\u{111c9}', '\u{111cc}', WordCat::Extend),
+    ('\u{111ce}', '\u{111cf}', WordCat::Extend),
+    ('\u{111d0}', '\u{111d9}', WordCat::Numeric),
+    ('\u{111da}', '\u{111da}', WordCat::ALetter),
+    ('\u{111dc}', '\u{111dc}', WordCat::ALetter),
+    ('\u{11200}', '\u{11211}', WordCat::ALetter),
+    ('\u{11213}', '\u{1122b}', WordCat::ALetter),
+    ('\u{1122c}', '\u{11237}', WordCat::Extend),
+    ('\u{1123e}', '\u{1123e}', WordCat::Extend),
+    ('\u{1123f}', '\u{11240}', WordCat::ALetter),
+    ('\u{11241}', '\u{11241}', WordCat::Extend),
+    ('\u{11280}', '\u{11286}', WordCat::ALetter),
+    ('\u{11288}', '\u{11288}', WordCat::ALetter),
+    ('\u{1128a}', '\u{1128d}', WordCat::ALetter),
+    ('\u{1128f}', '\u{1129d}', WordCat::ALetter),
+    ('\u{1129f}', '\u{112a8}', WordCat::ALetter),
+    ('\u{112b0}', '\u{112de}', WordCat::ALetter),
+    ('\u{112df}', '\u{112ea}', WordCat::Extend),
+    ('\u{112f0}', '\u{112f9}', WordCat::Numeric),
+    ('\u{11300}', '\u{11303}', WordCat::Extend),
+    ('\u{11305}', '\u{1130c}', WordCat::ALetter),
+    ('\u{1130f}', '\u{11310}', WordCat::ALetter),
+    ('\u{11313}', '\u{11328}', WordCat::ALetter),
+    ('\u{1132a}', '\u{11330}', WordCat::ALetter),
+    ('\u{11332}', '\u{11333}', WordCat::ALetter),
+    ('\u{11335}', '\u{11339}', WordCat::ALetter),
+    ('\u{1133b}', '\u{1133c}', WordCat::Extend),
+    ('\u{1133d}', '\u{1133d}', WordCat::ALetter),
+    ('\u{1133e}', '\u{11344}', WordCat::Extend),
+    ('\u{11347}', '\u{11348}', WordCat::Extend),
+    ('\u{1134b}', '\u{1134d}', WordCat::Extend),
+    ('\u{11350}', '\u{11350}', WordCat::ALetter),
+    ('\u{11357}', '\u{11357}', WordCat::Extend),
+    ('\u{1135d}', '\u{11361}', WordCat::ALetter),
+    ('\u{11362}', '\u{11363}', WordCat::Extend),
+    ('\u{11366}', '\u{1136c}', WordCat::Extend),
+    ('\u{11370}', '\u{11374}', WordCat::Extend),
+    ('\u{11380}', '\u{11389}', WordCat::ALetter),
+    ('\u{1138b}', '\u{1138b}', WordCat::ALetter),
+    ('\u{1138e}', '\u{1138e}', WordCat::ALetter),
+    ('\u{11390}', '\u{113b5}', WordCat::ALetter),
+    ('\u{113b7}', '\u{113b7}', WordCat::ALetter),
+    ('\u{113b8}', '\u{113c0}', WordCat::Extend),
+    ('\u{113c2}', '\u{113c2}', WordCat::Extend),
+    ('\u{113c5}', '\u{113c5}', WordCat::Extend),
+    ('\u{113c7}', '\u{113ca}', WordCat::Extend),
+    ('\u{113cc}', '\u{113d0}', WordCat::Extend),
+    ('\u{113d1}', '\u{113d1}', WordCat::ALetter),
+    ('\u{113d2}', '\u{113d2}', WordCat::Extend),
+    ('\u{113d3}', '\u{113d3}', WordCat::ALetter),
+    ('\u{113e1}', '\u{113e2}', WordCat::Extend),
+    ('\u{11400}', '\u{11434}', WordCat::ALetter),
+    ('\u{11435}', '\u{11446}', WordCat::Extend),
+    ('\u{11447}', '\u{1144a}', WordCat::ALetter),
+    ('\u{11450}', '\u{11459}', WordCat::Numeric),
+    ('\u{1145e}', '\u{1145e}', WordCat::Extend),
+    ('\u{1145f}', '\u{11461}', WordCat::ALetter),
+    ('\u{11480}', '\u{114af}', WordCat::ALetter),
+    ('\u{114b0}', '\u{114c3}', WordCat::Extend),
+    ('\u{114c4}', '\u{114c5}', WordCat::ALetter),
+    ('\u{114c7}', '\u{114c7}', WordCat::ALetter),
+    ('\u{114d0}', '\u{114d9}', WordCat::Numeric),
+    ('\u{11580}', '\u{115ae}', WordCat::ALetter),
+    ('\u{115af}', '\u{115b5}', WordCat::Extend),
+    ('\u{115b8}', '\u{115c0}', WordCat::Extend),
+    ('\u{115d8}', '\u{115db}', WordCat::ALetter),
+    ('\u{115dc}', '\u{115dd}', WordCat::Extend),
+    ('\u{11600}', '\u{1162f}', WordCat::ALetter),
+    ('\u{11630}', '\u{11640}', WordCat::Extend),
+    ('\u{11644}', '\u{11644}', WordCat::ALetter),
+    ('\u{11650}', '\u{11659}', WordCat::Numeric),
+    ('\u{11680}', '\u{116aa}', WordCat::ALetter),
+    ('\u{116ab}', '\u{116b7}', WordCat::Extend),
+    ('\u{116b8}', '\u{116b8}', WordCat::ALetter),
+    ('\u{116c0}', '\u{116c9}', WordCat::Numeric),
+    ('\u{116d0}', '\u{116e3}', WordCat::Numeric),
+    ('\u{1171d}', '\u{1172b}', WordCat::Extend),
+    ('\u{11730}', '\u{11739}', WordCat::Numeric),
+    ('\u{11800}', '\u{1182b}', WordCat::ALetter),
+    ('\u{1182c}', '\u{1183a}', WordCat::Extend),
+    ('\u{118a0}', '\u{118df}', WordCat::ALetter),
+    ('\u{118e0}', '\u{118e9}', WordCat::Numeric),
+    ('\u{118ff}', '\u{11906}', WordCat::ALetter),
+    ('\u{11909}', '\u{11909}', WordCat::ALetter),
+    ('\u{1190c}', '\u{11913}', WordCat::ALetter),
+    ('\u{11915}', '\u{11916}', WordCat::ALetter),
+    ('\u{11918}', '\u{1192f}', WordCat::ALetter),
+    ('\u{11930}', '\u{11935}', WordCat::Extend),
+    ('\u{11937}', '\u{11938}', WordCat::Extend),
+    ('\u{1193b}', '\u{1193e}', WordCat::Extend),
+    ('\u{1193f}', '\u{1193f}', WordCat::ALetter),
+    ('\u{11940}', '\u{11940}', WordCat::Extend),
+    ('\u{11941}', '\u{11941}', WordCat::ALetter),
+    ('\u{11942}', '\u{11943}', WordCat::Extend),
+    ('\u{11950}', '\u{11959}', WordCat::Numeric),
+    ('\u{119a0}', '\u{119a7}', WordCat::ALetter),
+    ('\u{119aa}', '\u{119d0}', WordCat::ALetter),
+    ('\u{119d1}', '\u{119d7}', WordCat::Extend),
+    ('\u{119da}', '\u{119e0}', WordCat::Extend),
+    ('\u{119e1}', '\u{119e1}', WordCat::ALetter),
+    ('\u{119e3}', '\u{119e3}', WordCat::ALetter),
+    ('\u{119e4}', '\u{119e4}', WordCat::Extend),
+    ('\u{11a00}', '\u{11a00}', WordCat::ALetter),
+    ('\u{11a01}', '\u{11a0a}', WordCat::Extend),
+    ('\u{11a0b}', '\u{11a32}', WordCat::ALetter),
+    ('\u{11a33}', '\u{11a39}', WordCat::Extend),
+    ('\u{11a3a}', '\u{11a3a}', WordCat::ALetter),
+    ('\u{11a3b}', '\u{11a3e}', WordCat::Extend),
+    ('\u{11a47}', '\u{11a47}', WordCat::Extend),
+    ('\u{11a50}', '\u{11a50}', WordCat::ALetter),
+    ('\u{11a51}', '\u{11a5b}', WordCat::Extend),
+    ('\u{11a5c}', '\u{11a89}', WordCat::ALetter),
+    ('\u{11a8a}', '\u{11a99}', WordCat::Extend),
+    ('\u{11a9d}', '\u{11a9d}', WordCat::ALetter),
+    ('\u{11ab0}', '\u{11af8}', WordCat::ALetter),
+    ('\u{11b60}', '\u{11b67}', WordCat::Extend),
+    ('\u{11bc0}', '\u{11be0}', WordCat::ALetter),
+    ('\u{11bf0}', '\u{11bf9}', WordCat::Numeric),
+    ('\u{11c00}', '\u{11c08}', WordCat::ALetter),
+    ('\u{11c0a}', '\u{11c2e}', WordCat::ALetter),
+    ('\u{11c2f}', '\u{11c36}', WordCat::Extend),
+    ('\u{11c38}', '\u{11c3f}', WordCat::Extend),
+    ('\u{11c40}', '\u{11c40}', WordCat::ALetter),
+    ('\u{11c50}', '\u{11c59}', WordCat::Numeric),
+    ('\u{11c72}', '\u{11c8f}', WordCat::ALetter),
+    ('\u{11c92}', '\u{11ca7}', WordCat::Extend),
+    ('\u{11ca9}', '\u{11cb6}', WordCat::Extend),
+    ('\u{11d00}', '\u{11d06}', WordCat::ALetter),
+    ('\u{11d08}', '\u{11d09}', WordCat::ALetter),
+    ('\u{11d0b}', '\u{11d30}', WordCat::ALetter),
+    ('\u{11d31}', '\u{11d36}', WordCat::Extend),
+    ('\u{11d3a}', '\u{11d3a}', WordCat::Extend),
+    ('\u{11d3c}', '\u{11d3d}', WordCat::Extend),
+    ('\u{11d3f}', '\u{11d45}', WordCat::Extend),
+    ('\u{11d46}', '\u{11d46}', WordCat::ALetter),
+    ('\u{11d47}', '\u{11d47}', WordCat::Extend),
+    ('\u{11d50}', '\u{11d59}', WordCat::Numeric),
+    ('\u{11d60}', '\u{11d65}', WordCat::ALetter),
+    ('\u{11d67}', '\u{11d68}', WordCat::ALetter),
+    ('\u{11d6a}', '\u{11d89}', WordCat::ALetter),
+    ('\u{11d8a}', '\u{11d8e}', WordCat::Extend),
+    ('\u{11d90}', '\u{11d91}', WordCat::Extend),
+    ('\u{11d93}', '\u{11d97}', WordCat::Extend),
+    ('\u{11d98}', '\u{11d98}', WordCat::ALetter),
+    ('\u{11da0}', '\u{11da9}', WordCat::Numeric),
+    ('\u{11db0}', '\u{11ddb}', WordCat::ALetter),
+    ('\u{11de0}', '\u{11de9}', WordCat::Numeric),
+    ('\u{11ee0}', '\u{11ef2}', WordCat::ALetter),
+    ('\u{11ef3}', '\u{11ef6}', WordCat::Extend),
+    ('\u{11f00}', '\u{11f01}', WordCat::Extend),
+    ('\u{11f02}', '\u{11f02}', WordCat::ALetter),
+    ('\u{11f03}', '\u{11f03}', WordCat::Extend),
+    ('\u{11f04}', '\u{11f10}', WordCat::ALetter),
+    ('\u{11f12}', '\u{11f33}', WordCat::ALetter),
+    ('\u{11f34}', '\u{11f3a}', WordCat::Extend),
+    ('\u{11f3e}', '\u{11f42}', WordCat::Extend),
+    ('\u{11f50}', '\u{11f59}', WordCat::Numeric),
+    ('\u{11f5a}', '\u{11f5a}', WordCat::Extend),
+    ('\u{11fb0}', '\u{11fb0}', WordCat::ALetter),
+    ('\u{12000}', '\u{12399}', WordCat::ALetter),
+    ('\u{12400}', '\u{1246e}', WordCat::ALetter),
+    ('\u{12480}', '\u{12543}', WordCat::ALetter),
+    ('\u{12f90}', '\u{12ff0}', WordCat::ALetter),
+    ('\u{13000}', '\u{1342f}', WordCat::ALetter),
+    ('\u{13430}', '\u{1343f}', WordCat::Format),
+    ('\u{13440}', '\u{13440}', WordCat::Extend),
+    ('\u{13441}', '\u{13446}', WordCat::ALetter),
+    ('\u{13447}', '\u{13455}', WordCat::Extend),
+    ('\u{13460}', '\u{143fa}', WordCat::ALetter),
+    ('\u{14400}', '\u{14646}', WordCat::ALetter),
+    ('\u{16100}', '\u{1611d}', WordCat::ALetter),
+    ('\u{1611e}', '\u{1612f}', WordCat::Extend),
+    ('\u{16130}', '\u{16139}', WordCat::Numeric),
+    ('\u{16800}', '\u{16a38}', WordCat::ALetter),
+    ('\u{16a40}', '\u{16a5e}', WordCat::ALetter),
+    ('\u{16a60}', '\u{16a69}', WordCat::Numeric),
+    ('\u{16a70}', '\u{16abe}', WordCat::ALetter),
+    ('\u{16ac0}', '\u{16ac9}', WordCat::Numeric),
+    ('\u{16ad0}', '\u{16aed}', WordCat::ALetter),
+    ('\u{16af0}', '\u{16af4}', WordCat::Extend),
+    ('\u{16b00}', '\u{16b2f}', WordCat::ALetter),
+    ('\u{16b30}', '\u{16b36}', WordCat::Extend),
+    ('\u{16b40}', '\u{16b43}', WordCat::ALetter),
+    ('\u{16b50}', '\u{16b59}', WordCat::Numeric),
+    ('\u{16b63}', '\u{16b77}', WordCat::ALetter),
+    ('\u{16b7d}', '\u{16b8f}', WordCat::ALetter),
+    ('\u{16d40}', '\u{16d6c}', WordCat::ALetter),
+    ('\u{16d70}', '\u{16d79}', WordCat::Numeric),
+    ('\u{16e40}', '\u{16e7f}', WordCat::ALetter),
+    ('\u{16ea0}', '\u{16eb8}', WordCat::ALetter),
+    ('\u{16ebb}', '\u{16ed3}', WordCat::ALetter),
+    ('\u{16f00}', '\u{16f4a}', WordCat::ALetter),
+    ('\u{16f4f}', '\u{16f4f}', WordCat::Extend),
+    ('\u{16f50}', '\u{16f50}', WordCat::ALetter),
+    ('\u{16f51}', '\u{16f87}', WordCat::Extend),
+    ('\u{16f8f}', '\u{16f92}', WordCat::Extend),
+    ('\u{16f93}', '\u{16f9f}', WordCat::ALetter),
+    ('\u{16fe0}', '\u{16fe1}', WordCat::ALetter),
+    ('\u{16fe3}', '\u{16fe3}', WordCat::ALetter),
+    ('\u{16fe4}', '\u{16fe4}', WordCat::Extend),
+    ('\u{16ff0}', '\u{16ff1}', WordCat::Extend),
+    ('\u{1aff0}', '\u{1aff3}', WordCat::Katakana),
+    ('\u{1aff5}', '\u{1affb}', WordCat::Katakana),
+    ('\u{1affd}', '\u{1affe}', WordCat::Katakana),
+    ('\u{1b000}', '\u{1b000}', WordCat::Katakana),
+    ('\u{1b120}', '\u{1b122}', WordCat::Katakana),
+    ('\u{1b155}', '\u{1b155}', WordCat::Katakana),
+    ('\u{1b164}', '\u{1b167}', WordCat::Katakana),
+    ('\u{1bc00}', '\u{1bc6a}', WordCat::ALetter),
+    ('\u{1bc70}', '\u{1bc7c}', WordCat::ALetter),
+    ('\u{1bc80}', '\u{1bc88}', WordCat::ALetter),
+    ('\u{1bc90}', '\u{1bc99}', WordCat::ALetter),
+    ('\u{1bc9d}', '\u{1bc9e}', WordCat::Extend),
+    ('\u{1bca0}', '\u{1bca3}', WordCat::Format),
+    ('\u{1ccf0}', '\u{1ccf9}', WordCat::Numeric),
+    ('\u{1cf00}', '\u{1cf2d}', WordCat::Extend),
+    ('\u{1cf30}', '\u{1cf46}', WordCat::Extend),
+    ('\u{1d165}', '\u{1d169}', WordCat::Extend),
+    ('\u{1d16d}', '\u{1d172}', WordCat::Extend),
+    ('\u{1d173}', '\u{1d17a}', WordCat::Format),
+    ('\u{1d17b}', '\u{1d182}', WordCat::Extend),
+    ('\u{1d185}', '\u{1d18b}', WordCat::Extend),
+    ('\u{1d1aa}', '\u{1d1ad}', WordCat::Extend),
+    ('\u{1d242}', '\u{1d244}', WordCat::Extend),
+    ('\u{1d400}', '\u{1d454}', WordCat::ALetter),
+    ('\u{1d456}', '\u{1d49c}', WordCat::ALetter),
+    ('\u{1d49e}', '\u{1d49f}', WordCat::ALetter),
+    ('\u{1d4a2}', '\u{1d4a2}', WordCat::ALetter),
+    ('\u{1d4a5}', '\u{1d4a6}', WordCat::ALetter),
+    ('\u{1d4a9}', '\u{1d4ac}', WordCat::ALetter),
+    ('\u{1d4ae}', '\u{1d4b9}', WordCat::ALetter),
+    ('\u{1d4bb}', '\u{1d4bb}', WordCat::ALetter),
+    ('\u{1d4bd}', '\u{1d4c3}', WordCat::ALetter),
+    ('\u{1d4c5}', '\u{1d505}', WordCat::ALetter),
+    ('\u{1d507}', '\u{1d50a}', WordCat::ALetter),
+    ('\u{1d50d}', '\u{1d514}', WordCat::ALetter),
+    ('\u{1d516}', '\u{1d51c}', WordCat::ALetter),
+    ('\u{1d51e}', '\u{1d539}', WordCat::ALetter),
+    ('\u{1d53b}', '\u{1d53e}', WordCat::ALetter),
+    ('\u{1d540}', '\u{1d544}', WordCat::ALetter),
+    ('\u{1d546}', '\u{1d546}', WordCat::ALetter),
+    ('\u{1d54a}', '\u{1d550}', WordCat::ALetter),
+    ('\u{1d552}', '\u{1d6a5}', WordCat::ALetter),
+    ('\u{1d6a8}', '\u{1d6c0}', WordCat::ALetter),
+    ('\u{1d6c2}', '\u{1d6da}', WordCat::ALetter),
+    ('\u{1d6dc}', '\u{1d6fa}', WordCat::ALetter),
+    ('\u{1d6fc}', '\u{1d714}', WordCat::ALetter),
+    ('\u{1d716}', '\u{1d734}', WordCat::ALetter),
+    ('\u{1d736}', '\u{1d74e}', WordCat::ALetter),
+    ('\u{1d750}', '\u{1d76e}', WordCat::ALetter),
+    ('\u{1d770}', '\u{1d788}', WordCat::ALetter),
+    ('\u{1d78a}', '\u{1d7a8}', WordCat::ALetter),
+    ('\u{1d7aa}', '\u{1d7c2}', WordCat::ALetter),
+    ('\u{1d7c4}', '\u{1d7cb}', WordCat::ALetter),
+    ('\u{1d7ce}', '\u{1d7ff}', WordCat::Numeric),
+    ('\u{1da00}', '\u{1da36}', WordCat::Extend),
+    ('\u{1da3b}', '\u{1da6c}', WordCat::Extend),
+    ('\u{1da75}', '\u{1da75}', WordCat::Extend),
+    ('\u{1da84}', '\u{1da84}', WordCat::Extend),
+    ('\u{1da9b}', '\u{1da9f}', WordCat::Extend),
+    ('\u{1daa1}', '\u{1daaf}', WordCat::Extend),
+    ('\u{1df00}', '\u{1df1e}', WordCat::ALetter),
+    ('\u{1df25}', '\u{1df2a}', WordCat::ALetter),
+    ('\u{1e000}', '\u{1e006}', WordCat::Extend),
+    ('\u{1e008}', '\u{1e018}', WordCat::Extend),
+    ('\u{1e01b}', '\u{1e021}', WordCat::Extend),
+    ('\u{1e023}', '\u{1e024}', WordCat::Extend),
+    ('\u{1e026}', '\u{1e02a}', WordCat::Extend),
+    ('\u{1e030}', '\u{1e06d}', WordCat::ALetter),
+    ('\u{1e08f}', '\u{1e08f}', WordCat::Extend),
+    ('\u{1e100}', '\u{1e12c}', WordCat::ALetter),
+    ('\u{1e130}', '\u{1e136}', WordCat::Extend),
+    ('\u{1e137}', '\u{1e13d}', WordCat::ALetter),
+    ('\u{1e140}', '\u{1e149}', WordCat::Numeric),
+    ('\u{1e14e}', '\u{1e14e}', WordCat::ALetter),
+    ('\u{1e290}', '\u{1e2ad}', WordCat::ALetter),
+    ('\u{1e2ae}', '\u{1e2ae}', WordCat::Extend),
+    ('\u{1e2c0}', '\u{1e2eb}', WordCat::ALetter),
+    ('\u{1e2ec}', '\u{1e2ef}', WordCat::Extend),
+    ('\u{1e2f0}', '\u{1e2f9}', WordCat::Numeric),
+    ('\u{1e4d0}', '\u{1e4eb}', WordCat::ALetter),
+    ('\u{1e4ec}', '\u{1e4ef}', WordCat::Extend),
+    ('\u{1e4f0}', '\u{1e4f9}', WordCat::Numeric),
+    ('\u{1e5d0}', '\u{1e5ed}', WordCat::ALetter),
+    ('\u{1e5ee}', '\u{1e5ef}', WordCat::Extend),
+    ('\u{1e5f0}', '\u{1e5f0}', WordCat::ALetter),
+    ('\u{1e5f1}', '\u{1e5fa}', WordCat::Numeric),
+    ('\u{1e6c0}', '\u{1e6de}', WordCat::ALetter),
+    ('\u{1e6e0}', '\u{1e6e2}', WordCat::ALetter),
+    ('\u{1e6e3}', '\u{1e6e3}', WordCat::Extend),
+    ('\u{1e6e4}', '\u{1e6e5}', WordCat::ALetter),
+    ('\u{1e6e6}', '\u{1e6e6}', WordCat::Extend),
+    ('\u{1e6e7}', '\u{1e6ed}', WordCat::ALetter),
+    ('\u{1e6ee}', '\u{1e6ef}', WordCat::Extend),
+    ('\u{1e6f0}', '\u{1e6f4}', WordCat::ALetter),
+    ('\u{1e6f5}', '\u{1e6f5}', WordCat::Extend),
+    ('\u{1e6fe}', '\u{1e6ff}', WordCat::ALetter),
+    ('\u{1e7e0}', '\u{1e7e6}', WordCat::ALetter),
+    ('\u{1e7e8}', '\u{1e7eb}', WordCat::ALetter),
+    ('\u{1e7ed}', '\u{1e7ee}', WordCat::ALetter),
+    ('\u{1e7f0}', '\u{1e7fe}', WordCat::ALetter),
+    ('\u{1e800}', '\u{1e8c4}', WordCat::ALetter),
+    ('\u{1e8d0}', '\u{1e8d6}', WordCat::Extend),
+    ('\u{1e900}', '\u{1e943}', WordCat::ALetter),
+    ('\u{1e944}', '\u{1e94a}', WordCat::Extend),
+    ('\u{1e94b}', '\u{1e94b}', WordCat::ALetter),
+    ('\u{1e950}', '\u{1e959}', WordCat::Numeric),
+    ('\u{1ee00}', '\u{1ee03}', WordCat::ALetter),
+    ('\u{1ee05}', '\u{1ee1f}', WordCat::ALetter),
+    ('\u{1ee21}', '\u{1ee22}', WordCat::ALetter),
+    ('\u{1ee24}', '\u{1ee24}', WordCat::ALetter),
+    ('\u{1ee27}', '\u{1ee27}', WordCat::ALetter),
+    ('\u{1ee29}', '\u{1ee32}', WordCat::ALetter),
+    ('\u{1ee34}', '\u{1ee37}', WordCat::ALetter),
+    ('\u{1ee39}', '\u{1ee39}', WordCat::ALetter),
+    ('\u{1ee3b}', '\u{1ee3b}', WordCat::ALetter),
+    ('\u{1ee42}', '\u{1ee42}', WordCat::ALetter),
+    ('\u{1ee47}', '\u{1ee47}', WordCat::ALetter),
+    ('\u{1ee49}', '\u{1ee49}', WordCat::ALetter),
+    ('\u{1ee4b}', '\u{1ee4b}', WordCat::ALetter),
+    ('\u{1ee4d}', '\u{1ee4f}', WordCat::ALetter),
+    ('\u{1ee51}', '\u{1ee52}', WordCat::ALetter),
+    ('\u{1ee54}', '\u{1ee54}', WordCat::ALetter),
+    ('\u{1ee57}', '\u{1ee57}', WordCat::ALetter),
+    ('\u{1ee59}', '\u{1ee59}', WordCat::ALetter),
+    ('\u{1ee5b}', '\u{1ee5b}', WordCat::ALetter),
+    ('\u{1ee5d}', '\u{1ee5d}', WordCat::ALetter),
+    ('\u{1ee5f}', '\u{1ee5f}', WordCat::ALetter),
+    ('\u{1ee61}', '\u{1ee62}', WordCat::ALetter),
+    ('\u{1ee64}', '\u{1ee64}', WordCat::ALetter),
+    ('\u{1ee67}', '\u{1ee6a}', WordCat::ALetter),
+    ('\u{1ee6c}', '\u{1ee72}', WordCat::ALetter),
+    ('\u{1ee74}', '\u{1ee77}', WordCat::ALetter),
+    ('\u{1ee79}', '\u{1ee7c}', WordCat::ALetter),
+    ('\u{1ee7e}', '\u{1ee7e}', WordCat::ALetter),
+    ('\u{1ee80}', '\u{1ee89}', WordCat::ALetter),
+    ('\u{1ee8b}', '\u{1ee9b}', WordCat::ALetter),
+    ('\u{1eea1}', '\u{1eea3}', WordCat::ALetter),
+    ('\u{1eea5}', '\u{1eea9}', WordCat::ALetter),
+    ('\u{1eeab}', '\u{1eebb}', WordCat::ALetter),
+    ('\u{1f130}', '\u{1f149}', WordCat::ALetter),
+    ('\u{1f150}', '\u{1f169}', WordCat::ALetter),
+    ('\u{1f170}', '\u{1f189}', WordCat::ALetter),
+    ('\u{1f1e6}', '\u{1f1ff}', WordCat::RegionalIndicator),
+    ('\u{1f3fb}', '\u{1f3ff}', WordCat::Extend),
+    ('\u{1fbf0}', '\u{1fbf9}', WordCat::Numeric),
+    ('\u{e0001}', '\u{e0001}', WordCat::Format),
+    ('\u{e0020}', '\u{e007f}', WordCat::Extend),
+    ('\u{e0100}', '\u{e01ef}', WordCat::Extend),
+];
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GeneralCategory {
+    Cn,
+    Lu,
+    Ll,
+    Lt,
+    Lm,
+    Lo,
+    Mn,
+    Mc,
+    Me,
+    Nd,
+    Nl,
+    No,
+    Pc,
+    Pd,
+    Ps,
+    Pe,
+    Pi,
+    Pf,
+    Po,
+    Sm,
+    Sc,
+    Sk,
+    So,
+    Zs,
+    Zl,
+    Zp,
+    Cc,
+    Cf,
+    Co,
+}
+
+pub const GENERAL_CATEGORY_TABLE: &'static [(char, char, GeneralCategory)] = &[
+    ('\u{0}', '\u{1f}', GeneralCategory::Cc),
+    ('\u{20}', '\u{20}', GeneralCategory::Zs),
+    ('\u{21}', '\u{23}', GeneralCategory::Po),
+    ('\u{24}', '\u{24}', GeneralCategory::Sc),
+    ('\u{25}', '\u{27}', GeneralCategory::Po),
+    ('\u{28}', '\u{28}', GeneralCategory::Ps),
+    ('\u{29}', '\u{29}', GeneralCategory::Pe),
+    ('\u{2a}', '\u{2a}', GeneralCategory::Po),
+    ('\u{2b}', '\u{2b}', GeneralCategory::Sm),
+    ('\u{2c}', '\u{2c}', GeneralCategory::Po),
+    ('\u{2d}', '\u{2d}', GeneralCategory::Pd),
+    ('\u{2e}', '\u{2f}', GeneralCategory::Po),
+    ('\u{30}', '\u{39}', GeneralCategory::Nd),
+    ('\u{3a}', '\u{3b}', GeneralCategory::Po),
+    ('\u{3c}', '\u{3e}', GeneralCategory::Sm),
+    ('\u{3f}', '\u{40}', GeneralCategory::Po),
+    ('\u{41}', '\u{5a}', GeneralCategory::Lu),
+    ('\u{5b}', '\u{5b}', GeneralCategory::Ps),
+    ('\u{5c}', '\u{5c}', GeneralCategory::Po),
+    ('\u{5d}', '\u{5d}', GeneralCategory::Pe),
+    ('\u{5e}', '\u{5e}', GeneralCategory::Sk),
+    ('\u{5f}', '\u{5f}', GeneralCategory::Pc),
+    ('\u{60}', '\u{60}', GeneralCategory::Sk),
+    ('\u{61}', '\u{7a}', GeneralCategory::Ll),
+    ('\u{7b}', '\u{7b}', GeneralCategory::Ps),
+    ('\u{7c}', '\u{7c}', GeneralCategory::Sm),
+    ('\u{7d}', '\u{7d}', GeneralCategory::Pe),
+    ('\u{7e}', '\u{7e}', GeneralCategory::Sm),
+    ('\u{7f}', '\u{9f}', GeneralCategory::Cc),
+    ('\u{a0}', '\u{a0}', GeneralCategory::Zs),
+    ('\u{a1}', '\u{a1}', GeneralCategory::Po),
+    ('\u{a2}', '\u{a5}', GeneralCategory::Sc),
+    ('\u{a6}', '\u{a6}', GeneralCategory::So),
+    ('\u{a7}', '\u{a7}', GeneralCategory::Po),
+    ('\u{a8}', '\u{a8}', GeneralCategory::Sk),
+    ('\u{a9}', '\u{a9}', GeneralCategory::So),
+    ('\u{aa}', '\u{aa}', GeneralCategory::Lo),
+    ('\u{ab}', '\u{ab}', GeneralCategory::Pi),
+    ('\u{ac}', '\u{ac}', GeneralCategory::Sm),
+    ('\u{ad}', '\u{ad}', GeneralCategory::Cf),
+    ('\u{ae}', '\u{ae}', GeneralCategory::So),
+    ('\u{af}', '\u{af}', GeneralCategory::Sk),
+    ('\u{b0}', '\u{b0}', GeneralCategory::So),
+    ('\u{b1}', '\u{b1}', GeneralCategory::Sm),
+    ('\u{b2}', '\u{b3}', GeneralCategory::No),
+    ('\u{b4}', '\u{b4}', GeneralCategory::Sk),
+    ('\u{b5}', '\u{b5}', GeneralCategory::Ll),
+    ('\u{b6}', '\u{b7}', GeneralCategory::Po),
+    ('\u{b8}', '\u{b8}', GeneralCategory::Sk),
+    ('\u{b9}', '\u{b9}', GeneralCategory::No),
+    ('\u{ba}', '\u{ba}', GeneralCategory::Lo),
+    ('\u{bb}', '\u{bb}', GeneralCategory::Pf),
+    ('\u{bc}', '\u{be}', GeneralCategory::No),
+    ('\u{bf}', '\u{bf}', GeneralCategory::Po),
+    ('\u{c0}', '\u{d6}', GeneralCategory::Lu),
+    ('\u{d7}', '\u{d7}', GeneralCategory::Sm),
+    ('\u{d8}', '\u{de}', GeneralCategory::Lu),
+    ('\u{df}', '\u{f6}', GeneralCategory::Ll),
+    ('\u{f7}', '\u{f7}', GeneralCategory::Sm),
+    ('\u{f8}', '\u{ff}', GeneralCategory::Ll),
+    ('\u{100}', '\u{100}', GeneralCategory::Lu),
+    ('\u{101}', '\u{101}', GeneralCategory::Ll),
+    ('\u{102}', '\u{102}', GeneralCategory::Lu),
+    ('\u{103}', '\u{103}', GeneralCategory::Ll),
+    ('\u{104}', '\u{104}', GeneralCategory::Lu),
+    ('\u{105}', '\u{105}', GeneralCategory::Ll),
+    ('\u{106}', '\u{106}', GeneralCategory::Lu),
+    ('\u{107}', '\u{107}', GeneralCategory::Ll),
+    ('\u{108}', '\u{108}', GeneralCategory::Lu),
+    ('\u{109}', '\u{109}', GeneralCategory::Ll),
+    ('\u{10a}', '\u{10a}', GeneralCategory::Lu),
+    ('\u{10b}', '\u{10b}', GeneralCategory::Ll),
+    ('\u{10c}', '\u{10c}', GeneralCategory::Lu),
+    ('\u{10d}', '\u{10d}', GeneralCategory::Ll),
+    ('\u{10e}', '\u{10e}', GeneralCategory::Lu),
+    ('\u{10f}', '\u{10f}', GeneralCategory::Ll),
+    ('\u{110}', '\u{110}', GeneralCategory::Lu),
+    ('\u{111}', '\u{111}', GeneralCategory::Ll),
+    ('\u{112}', '\u{112}', GeneralCategory::Lu),
+    ('\u{113}', '\u{113}', GeneralCategory::Ll),
+    ('\u{114}', '\u{114}', GeneralCategory::Lu),
+    ('\u{115}', '\u{115}', GeneralCategory::Ll),
+    ('\u{116}', '\u{116}', GeneralCategory::Lu),
+    ('\u{117}', '\u{117}', GeneralCategory::Ll),
+    ('\u{118}', '\u{118}', GeneralCategory::Lu),
+    ('\u{119}', '\u{119}', GeneralCategory::Ll),
+    ('\u{11a}', '\u{11a}', GeneralCategory::Lu),
+    ('\u{11b}', '\u{11b}', GeneralCategory::Ll),
+    ('\u{11c}', '\u{11c}', GeneralCategory::Lu),
+    ('\u{11d}', '\u{11d}', GeneralCategory::Ll),
+    ('\u{11e}', '\u{11e}', GeneralCategory::Lu),
+    ('\u{11f}', '\u{11f}', GeneralCategory::Ll),
+    ('\u{120}', '\u{120}', GeneralCategory::Lu),
+    ('\u{121}', '\u{121}', GeneralCategory::Ll),
+    ('\u{122}', '\u{122}', GeneralCategory::Lu),
+    ('\u{123}', '\u{123}', GeneralCategory::Ll),
+    ('\u{124}', '\u{124}', GeneralCategory::Lu),
+    ('\u{125}', '\u{125}', GeneralCategory::Ll),
+    ('\u{126}', '\u{126}', GeneralCategory::Lu),
+    ('\u{127}', '\u{127}', GeneralCategory::Ll),
+    ('\u{128}', '\u{128}', GeneralCategory::Lu),
+    ('\u{129}', '\u{129}', GeneralCategory::Ll),
+    ('\u{12a}', '\u{12a}', GeneralCategory::Lu),
+    ('\u{12b}', '\u{12b}', GeneralCategory::Ll),
+    ('\u{12c}', '\u{12c}', GeneralCategory::Lu),
+    ('\u{12d}', '\u{12d}', GeneralCategory::Ll),
+    ('\u{12e}', '\u{12e}', GeneralCategory::Lu),
+    ('\u{12f}', '\u{12f}', GeneralCategory::Ll),
+    ('\u{130}', '\u{130}', GeneralCategory::Lu),
+    ('\u{131}', '\u{131}', GeneralCategory::Ll),
+    ('\u{132}', '\u{132}', GeneralCategory::Lu),
+    ('\u{133}', '\u{133}', GeneralCategory::Ll),
+    ('\u{134}', '\u{134}', GeneralCategory::Lu),
+    ('\u{135}', '\u{135}', GeneralCategory::Ll),
+    ('\u{136}', '\u{136}', GeneralCategory::Lu),
+    ('\u{137}', '\u{138}', GeneralCategory::Ll),
+    ('\u{139}', '\u{139}', GeneralCategory::Lu),
+    ('\u{13a}', '\u{13a}', GeneralCategory::Ll),
+    ('\u{13b}', '\u{13b}', GeneralCategory::Lu),
+    ('\u{13c}', '\u{13c}', GeneralCategory::Ll),
+    ('\u{13d}', '\u{13d}', GeneralCategory::Lu),
+    ('\u{13e}', '\u{13e}', GeneralCategory::Ll),
+    ('\u{13f}', '\u{13f}', GeneralCategory::Lu),
+    ('\u{140}', '\u{140}', GeneralCategory::Ll),
+    ('\u{141}', '\u{141}', GeneralCategory::Lu),
+    ('\u{142}', '\u{142}', GeneralCategory::Ll),
+    ('\u{143}', '\u{143}', GeneralCategory::Lu),
+    ('\u{144}', '\u{144}', GeneralCategory::Ll),
+    ('\u{145}', '\u{145}', GeneralCategory::Lu),
+    ('\u{146}', '\u{146}', GeneralCategory::Ll),
+    ('\u{147}', '\u{147}', GeneralCategory::Lu),
+    ('\u{148}', '\u{149}', GeneralCategory::Ll),
+    ('\u{14a}', '\u{14a}', GeneralCategory::Lu),
+    ('\u{14b}', '\u{14b}', GeneralCategory::Ll),
+    ('\u{14c}', '\u{14c}', GeneralCategory::Lu),
+    ('\u{14d}', '\u{14d}', GeneralCategory::Ll),
+    ('\u{14e}', '\u{14e}', GeneralCategory::Lu),
+    ('\u{14f}', '\u{14f}', GeneralCategory::Ll),
+    ('\u{150}', '\u{150}', GeneralCategory::Lu),
+    ('\u{151}', '\u{151}', GeneralCategory::Ll),
+    ('\u{152}', '\u{152}', GeneralCategory::Lu),
+    ('\u{153}', '\u{153}', GeneralCategory::Ll),
+    ('\u{154}', '\u{154}', GeneralCategory::Lu),
+    ('\u{155}', '\u{155}', GeneralCategory::Ll),
+    ('\u{156}', '\u{156}', GeneralCategory::Lu),
+    ('\u{157}', '\u{157}', GeneralCategory::Ll),
+    ('\u{158}', '\u{158}', GeneralCategory::Lu),
+    ('\u{159}', '\u{159}', GeneralCategory::Ll),
+    ('\u{15a}', '\u{15a}', GeneralCategory::Lu),
+    ('\u{15b}', '\u{15b}', GeneralCategory::Ll),
+    ('\u{15c}', '\u{15c}', GeneralCategory::Lu),
+    ('\u{15d}', '\u{15d}', GeneralCategory::Ll),
+    ('\u{15e}', '\u{15e}', GeneralCategory::Lu),
+    ('\u{15f}', '\u{15f}', GeneralCategory::Ll),
+    ('\u{160}', '\u{160}', GeneralCategory::Lu),
+    ('\u{161}', '\u{161}', GeneralCategory::Ll),
+    ('\u{162}', '\u{162}', GeneralCategory::Lu),
+    ('\u{163}', '\u{163}', GeneralCategory::Ll),
+    ('\u{164}', '\u{164}', GeneralCategory::Lu),
+    ('\u{165}', '\u{165}', GeneralCategory::Ll),
+    ('\u{166}', '\u{166}', GeneralCategory::Lu),
+    ('\u{167}', '\u{167}', GeneralCategory::Ll),
+    ('\u{168}', '\u{168}', GeneralCategory::Lu),
+    ('\u{169}', '\u{169}', GeneralCategory::Ll),
+    ('\u{16a}', '\u{16a}', GeneralCategory::Lu),
+    ('\u{16b}', '\u{16b}', GeneralCategory::Ll),
+    ('\u{16c}', '\u{16c}', GeneralCategory::Lu),
+    ('\u{16d}', '\u{16d}', GeneralCategory::Ll),
+    ('\u{16e}', '\u{16e}', GeneralCategory::Lu),
+    ('\u{16f}', '\u{16f}', GeneralCategory::Ll),
+    ('\u{170}', '\u{170}', GeneralCategory::Lu),
+    ('\u{171}', '\u{171}', GeneralCategory::Ll),
+    ('\u{172}', '\u{172}', GeneralCategory::Lu),
+    ('\u{173}', '\u{173}', GeneralCategory::Ll),
+    ('\u{174}', '\u{174}', GeneralCategory::Lu),
+    ('\u{175}', '\u{175}', GeneralCategory::Ll),
+    ('\u{176}', '\u{176}', GeneralCategory::Lu),
+    ('\u{177}', '\u{177}', GeneralCategory::Ll),
+    ('\u{178}', '\u{179}', GeneralCategory::Lu),
+    ('\u{17a}', '\u{17a}', GeneralCategory::Ll),
+    ('\u{17b}', '\u{17b}', GeneralCategory::Lu),
+    ('\u{17c}', '\u{17c}', GeneralCategory::Ll),
+    ('\u{17d}', '\u{17d}', GeneralCategory::Lu),
+    ('\u{17e}', '\u{180}', GeneralCategory::Ll),
+    ('\u{181}', '\u{182}', GeneralCategory::Lu),
+    ('\u{183}', '\u{183}', GeneralCategory::Ll),
+    ('\u{184}', '\u{184}', GeneralCategory::Lu),
+    ('\u{185}', '\u{185}', GeneralCategory::Ll),
+    ('\u{186}', '\u{187}', GeneralCategory::Lu),
+    ('\u{188}', '\u{188}', GeneralCategory::Ll),
+    ('\u{189}', '\u{18b}', GeneralCategory::Lu),
+    ('\u{18c}', '\u{18d}', GeneralCategory::Ll),
+    ('\u{18e}', '\u{191}', GeneralCategory::Lu),
+    ('\u{192}', '\u{192}', GeneralCategory::Ll),
+    ('\u{193}', '\u{194}', GeneralCategory::Lu),
+    ('\u{195}', '\u{195}', GeneralCategory::Ll),
+    ('\u{196}', '\u{198}', GeneralCategory::Lu),
+    ('\u{199}', '\u{19b}', GeneralCategory::Ll),
+    ('\u{19c}', '\u{19d}', GeneralCategory::Lu),
+    ('\u{19e}', '\u{19e}', GeneralCategory::Ll),
+    ('\u{19f}', '\u{1a0}', GeneralCategory::Lu),
+    ('\u{1a1}', '\u{1a1}', GeneralCategory::Ll),
+    ('\u{1a2}', '\u{1a2}', GeneralCategory::Lu),
+    ('\u{1a3}', '\u{1a3}', GeneralCategory::Ll),
+    ('\u{1a4}', '\u{1a4}', GeneralCategory::Lu),
+    ('\u{1a5}', '\u{1a5}', GeneralCategory::Ll),
+    ('\u{1a6}', '\u{1a7}', GeneralCategory::Lu),
+    ('\u{1a8}', '\u{1a8}', GeneralCategory::Ll),
+    ('\u{1a9}', '\u{1a9}', GeneralCategory::Lu),
+    ('\u{1aa}', '\u{1ab}', GeneralCategory::Ll),
+    ('\u{1ac}', '\u{1ac}', GeneralCategory::Lu),
+    ('\u{1ad}', '\u{1ad}', GeneralCategory::Ll),
+    ('\u{1ae}', '\u{1af}', GeneralCategory::Lu),
+    ('\u{1b0}', '\u{1b0}', GeneralCategory::Ll),
+    ('\u{1b1}', '\u{1b3}', GeneralCategory::Lu),
+    ('\u{1b4}', '\u{1b4}', GeneralCategory::Ll),
+    ('\u{1b5}', '\u{1b5}', GeneralCategory::Lu),
+    ('\u{1b6}', '\u{1b6}', GeneralCategory::Ll),
+    ('\u{1b7}', '\u{1b8}', GeneralCategory::Lu),
+    ('\u{1b9}', '\u{1ba}', GeneralCategory::Ll),
+    ('\u{1bb}', '\u{1bb}', GeneralCategory::Lo),
+    ('\u{1bc}', '\u{1bc}', GeneralCategory::Lu),
+    ('\u{1bd}', '\u{1bf}', GeneralCategory::Ll),
+    ('\u{1c0}', '\u{1c3}', GeneralCategory::Lo),
+    ('\u{1c4}', '\u{1c4}', GeneralCategory::Lu),
+    ('\u{1c5}', '\u{1c5}', GeneralCategory::Lt),
+    ('\u{1c6}', '\u{1c6}', GeneralCategory::Ll),
+    ('\u{1c7}', '\u{1c7}', GeneralCategory::Lu),
+    ('\u{1c8}', '\u{1c8}', GeneralCategory::Lt),
+    ('\u{1c9}', '\u{1c9}', GeneralCategory::Ll),
+    ('\u{1ca}', '\u{1ca}', GeneralCategory::Lu),
+    ('\u{1cb}', '\u{1cb}', GeneralCategory::Lt),
+    ('\u{1cc}', '\u{1cc}', GeneralCategory::Ll),
+    ('\u{1cd}', '\u{1cd}', GeneralCategory::Lu),
+    ('\u{1ce}', '\u{1ce}', GeneralCategory::Ll),
+    ('\u{1cf}', '\u{1cf}', GeneralCategory::Lu),
+    ('\u{1d0}', '\u{1d0}', GeneralCategory::Ll),
+    ('\u{1d1}', '\u{1d1}', GeneralCategory::Lu),
+    ('\u{1d2}', '\u{1d2}', GeneralCategory::Ll),
+    ('\u{1d3}', '\u{1d3}', GeneralCategory::Lu),
+    ('\u{1d4}', '\u{1d4}', GeneralCategory::Ll),
+    ('\u{1d5}', '\u{1d5}', GeneralCategory::Lu),
+    ('\u{1d6}', '\u{1d6}', GeneralCategory::Ll),
+    ('\u{1d7}', '\u{1d7}', GeneralCategory::Lu),
+    ('\u{1d8}', '\u{1d8}', GeneralCategory::Ll),
+    ('\u{1d9}', '\u{1d9}', GeneralCategory::Lu),
+    ('\u{1da}', '\u{1da}', GeneralCategory::Ll),
+    ('\u{1db}', '\u{1db}', GeneralCategory::Lu),
+    ('\u{1dc}', '\u{1dd}', GeneralCategory::Ll),
+    ('\u{1de}', '\u{1de}', GeneralCategory::Lu),
+    ('\u{1df}', '\u{1df}', GeneralCategory::Ll),
+    ('\u{1e0}', '\u{1e0}', GeneralCategory::Lu),
+    ('\u{1e1}', '\u{1e1}', GeneralCategory::Ll),
+    ('\u{1e2}', '\u{1e2}', GeneralCategory::Lu),
+    ('\u{1e3}', '\u{1e3}', GeneralCategory::Ll),
+    ('\u{1e4}', '\u{1e4}', GeneralCategory::Lu),
+    ('\u{1e5}', '\u{1e5}', GeneralCategory::Ll),
+    ('\u{1e6}', '\u{1e6}', GeneralCategory::Lu),
+    ('\u{1e7}', '\u{1e7}', GeneralCategory::Ll),
+    ('\u{1e8}', '\u{1e8}', GeneralCategory::Lu),
+    ('\u{1e9}', '\u{1e9}', GeneralCategory::Ll),
+    ('\u{1ea}', '\u{1ea}', GeneralCategory::Lu),
+    ('\u{1eb}', '\u{1eb}', GeneralCategory::Ll),
+    ('\u{1ec}', '\u{1ec}', GeneralCategory::Lu),
+    ('\u{1ed}', '\u{1ed}', GeneralCategory::Ll),
+    ('\u{1ee}', '\u{1ee}', GeneralCategory::Lu),
+    ('\u{1ef}', '\u{1f0}', GeneralCategory::Ll),
+    ('\u{1f1}', '\u{1f1}', GeneralCategory::Lu),
+    ('\u{1f2}', '\u{1f2}', GeneralCategory::Lt),
+    ('\u{1f3}', '\u{1f3}', GeneralCategory::Ll),
+    ('\u{1f4}', '\u{1f4}', GeneralCategory::Lu),
+    ('\u{1f5}', '\u{1f5}', GeneralCategory::Ll),
+    ('\u{1f6}', '\u{1f8}', GeneralCategory::Lu),
+    ('\u{1f9}', '\u{1f9}', GeneralCategory::Ll),
+    ('\u{1fa}', '\u{1fa}', GeneralCategory::Lu),
+    ('\u{1fb}', '\u{1fb}', GeneralCategory::Ll),
+    ('\u{1fc}', '\u{1fc}', GeneralCategory::Lu),
+    ('\u{1fd}', '\u{1fd}', GeneralCategory::Ll),
+    ('\u{1fe}', '\u{1fe}', GeneralCategory::Lu),
+    ('\u{1ff}', '\u{1ff}', GeneralCategory::Ll),
+    ('\u{200}', '\u{200}', GeneralCategory::Lu),
+    ('\u{201}', '\u{201}', GeneralCategory::Ll),
+    ('\u{202}', '\u{202}', GeneralCategory::Lu),
+    ('\u{203}', '\u{203}', GeneralCategory::Ll),
+    ('\u{204}', '\u{204}', GeneralCategory::Lu),
+    ('\u{205}', '\u{205}', GeneralCategory::Ll),
+    ('\u{206}', '\u{206}', GeneralCategory::Lu),
+    ('\u{207}', '\u{207}', GeneralCategory::Ll),
+    ('\u{208}', '\u{208}', GeneralCategory::Lu),
+    ('\u{209}', '\u{209}', GeneralCategory::Ll),
+    ('\u{20a}', '\u{20a}', GeneralCategory::Lu),
+    ('\u{20b}', '\u{20b}', GeneralCategory::Ll),
+    ('\u{20c}', '\u{20c}', GeneralCategory::Lu),
+    ('\u{20d}', '\u{20d}', GeneralCategory::Ll),
+    ('\u{20e}', '\u{20e}', GeneralCategory::Lu),
+    ('\u{20f}', '\u{20f}', GeneralCategory::Ll),
+    ('\u{210}', '\u{210}', GeneralCategory::Lu),
+    ('\u{211}', '\u{211}', GeneralCategory::Ll),
+    ('\u{212}', '\u{212}', GeneralCategory::Lu),
+    ('\u{213}', '\u{213}', GeneralCategory::Ll),
+    ('\u{214}', '\u{214}', GeneralCategory::Lu),
+    ('\u{215}', '\u{215}', GeneralCategory::Ll),
+    ('\u{216}', '\u{216}', GeneralCategory::Lu),
+    ('\u{217}', '\u{217}', GeneralCategory::Ll),
+    ('\u{218}', '\u{218}', GeneralCategory::Lu),
+    ('\u{219}', '\u{219}', GeneralCategory::Ll),
+    ('\u{21a}', '\u{21a}', GeneralCategory::Lu),
+    ('\u{21b}', '\u{21b}', GeneralCategory::Ll),
+    ('\u{21c}', '\u{21c}', GeneralCategory::Lu),
+    ('\u{21d}', '\u{21d}', GeneralCategory::Ll),
+    ('\u{21e}', '\u{21e}', GeneralCategory::Lu),
+    ('\u{21f}', '\u{21f}', GeneralCategory::Ll),
+    ('\u{220}', '\u{220}', GeneralCategory::Lu),
+    ('\u{221}', '\u{221}', GeneralCategory::Ll),
+    ('\u{222}', '\u{222}', GeneralCategory::Lu),
+    ('\u{223}', '\u{223}', GeneralCategory::Ll),
+    ('\u{224}', '\u{224}', GeneralCategory::Lu),
+    ('\u{225}', '\u{225}', GeneralCategory::Ll),
+    ('\u{226}', '\u{226}', GeneralCategory::Lu),
+    ('\u{227}', '\u{227}', GeneralCategory::Ll),
+    ('\u{228}', '\u{228}', GeneralCategory::Lu),
+    ('\u{229}', '\u{229}', GeneralCategory::Ll),
+    ('\u{22a}', '\u{22a}', GeneralCategory::Lu),
+    ('\u{22b}', '\u{22b}', GeneralCategory::Ll),
+    ('\u{22c}', '\u{22c}', GeneralCategory::Lu),
+    ('\u{22d}', '\u{22d}', GeneralCategory::Ll),
+    ('\u{22e}', '\u{22e}', GeneralCategory::Lu),
+    ('\u{22f}', '\u{22f}', GeneralCategory::Ll),
+    ('\u{230}', '\u{230}', GeneralCategory::Lu),
+    ('\u{231}', '\u{231}', GeneralCategory::Ll),
+    ('\u{232}', '\u{232}', GeneralCategory::Lu),
+    ('\u{233}', '\u{239}', GeneralCategory::Ll),
+    ('\u{23a}', '\u{23b}', GeneralCategory::Lu),
+    ('\u{23c}', '\u{23c}', GeneralCategory::Ll),
+    ('\u{23d}', '\u{23e}', GeneralCategory::Lu),
+    ('\u{23f}', '\u{240}', GeneralCategory::Ll),
+    ('\u{241}', '\u{241}', GeneralCategory::Lu),
+    ('\u{242}', '\u{242}', GeneralCategory::Ll),
+    ('\u{243}', '\u{246}', GeneralCategory::Lu),
+    ('\u{247}', '\u{247}', GeneralCategory::Ll),
+    ('\u{248}', '\u{248}', GeneralCategory::Lu),
+    ('\u{249}', '\u{249}', GeneralCategory::Ll),
+    ('\u{24a}', '\u{24a}', GeneralCategory::Lu),
+    ('\u{24b}', '\u{24b}', GeneralCategory::Ll),
+    ('\u{24c}', '\u{24c}', GeneralCategory::Lu),
+    ('\u{24d}', '\u{24d}', GeneralCategory::Ll),
+    ('\u{24e}', '\u{24e}', GeneralCategory::Lu),
+    ('\u{24f}', '\u{293}', GeneralCategory::Ll),
+    ('\u{294}', '\u{295}', GeneralCategory::Lo),
+    ('\u{296}', '\u{2af}', GeneralCategory::Ll),
+    ('\u{2b0}', '\u{2c1}', GeneralCategory::Lm),
+    ('\u{2c2}', '\u{2c5}', GeneralCategory::Sk),
+    ('\u{2c6}', '\u{2d1}', GeneralCategory::Lm),
+    ('\u{2d2}', '\u{2df}', GeneralCategory::Sk),
+    ('\u{2e0}', '\u{2e4}', GeneralCategory::Lm),
+    ('\u{2e5}', '\u{2eb}', GeneralCategory::Sk),
+    ('\u{2ec}', '\u{2ec}', GeneralCategory::Lm),
+    ('\u{2ed}', '\u{2ed}', GeneralCategory::Sk),
+    ('\u{2ee}', '\u{2ee}', GeneralCategory::Lm),
+    ('\u{2ef}', '\u{2ff}', GeneralCategory::Sk),
+    ('\u{300}', '\u{36f}', GeneralCategory::Mn),
+    ('\u{370}', '\u{370}', GeneralCategory::Lu),
+    ('\u{371}', '\u{371}', GeneralCategory::Ll),
+    ('\u{372}', '\u{372}', GeneralCategory::Lu),
+    ('\u{373}', '\u{373}', GeneralCategory::Ll),
+    ('\u{374}', '\u{374}', GeneralCategory::Lm),
+    ('\u{375}', '\u{375}', GeneralCategory::Sk),
+    ('\u{376}', '\u{376}', GeneralCategory::Lu),
+    ('\u{377}', '\u{377}', GeneralCategory::Ll),
+    ('\u{37a}', '\u{37a}', GeneralCategory::Lm),
+    ('\u{37b}', '\u{37d}', GeneralCategory::Ll),
+    ('\u{37e}', '\u{37e}', GeneralCategory::Po),
+    ('\u{37f}', '\u{37f}', GeneralCategory::Lu),
+    ('\u{384}', '\u{385}', GeneralCategory::Sk),
+    ('\u{386}', '\u{386}', GeneralCategory::Lu),
+    ('\u{387}', '\u{387}', GeneralCategory::Po),
+    ('\u{388}', '\u{38a}', GeneralCategory::Lu),
+    ('\u{38c}', '\u{38c}', GeneralCategory::Lu),
+    ('\u{38e}', '\u{38f}', GeneralCategory::Lu),
+    ('\u{390}', '\u{390}', GeneralCategory::Ll),
+    ('\u{391}', '\u{3a1}', GeneralCategory::Lu),
+    ('\u{3a3}', '\u{3ab}', GeneralCategory::Lu),
+    ('\u{3ac}', '\u{3ce}', GeneralCategory::Ll),
+    ('\u{3cf}', '\u{3cf}', GeneralCategory::Lu),
+    ('\u{3d0}', '\u{3d1}', GeneralCategory::Ll),
+    ('\u{3d2}', '\u{3d4}', GeneralCategory::Lu),
+    ('\u{3d5}', '\u{3d7}', GeneralCategory::Ll),
+    ('\u{3d8}', '\u{3d8}', GeneralCategory::Lu),
+    ('\u{3d9}', '\u{3d9}', GeneralCategory::Ll),
+    ('\u{3da}', '\u{3da}', GeneralCategory::Lu),
+    ('\u{3db}', '\u{3db}', GeneralCategory::Ll),
+    ('\u{3dc}', '\u{3dc}', GeneralCategory::Lu),
+    ('\u{3dd}', '\u{3dd}', GeneralCategory::Ll),
+    ('\u{3de}', '\u{3de}', GeneralCategory::Lu),
+    ('\u{3df}', '\u{3df}', GeneralCategory::Ll),
+    ('\u{3e0}', '\u{3e0}', GeneralCategory::Lu),
+    ('\u{3e1}', '\u{3e1}', GeneralCategory::Ll),
+    ('\u{3e2}', '\u{3e2}', GeneralCategory::Lu),
+    ('\u{3e3}', '\u{3e3}', GeneralCategory::Ll),
+    ('\u{3e4}', '\u{3e4}', GeneralCategory::Lu),
+    ('\u{3e5}', '\u{3e5}', GeneralCategory::Ll),
+    ('\u{3e6}', '\u{3e6}', GeneralCategory::Lu),
+    ('\u{3e7}', '\u{3e7}', GeneralCategory::Ll),
+    ('\u{3e8}', '\u{3e8}', GeneralCategory::Lu),
+    ('\u{3e9}', '\u{3e9}', GeneralCategory::Ll),
+    ('\u{3ea}', '\u{3ea}', GeneralCategory::Lu),
+    ('\u{3eb}', '\u{3eb}', GeneralCategory::Ll),
+    ('\u{3ec}', '\u{3ec}', GeneralCategory::Lu),
+    ('\u{3ed}', '\u{3ed}', GeneralCategory::Ll),
+    ('\u{3ee}', '\u{3ee}', GeneralCategory::Lu),
+    ('\u{3ef}', '\u{3f3}', GeneralCategory::Ll),
+    ('\u{3f4}', '\u{3f4}', GeneralCategory::Lu),
+    ('\u{3f5}', '\u{3f5}', GeneralCategory::Ll),
+    ('\u{3f6}', '\u{3f6}', GeneralCategory::Sm),
+    ('\u{3f7}', '\u{3f7}', GeneralCategory::Lu),
+    ('\u{3f8}', '\u{3f8}', GeneralCategory::Ll),
+    ('\u{3f9}', '\u{3fa}', GeneralCategory::Lu),
+    ('\u{3fb}', '\u{3fc}', GeneralCategory::Ll),
+    ('\u{3fd}', '\u{42f}', GeneralCategory::Lu),
+    ('\u{430}', '\u{45f}', GeneralCategory::Ll),
+    ('\u{460}', '\u{460}', GeneralCategory::Lu),
+    ('\u{461}', '\u{461}', GeneralCategory::Ll),
+    ('\u{462}', '\u{462}', GeneralCategory::Lu),
+    ('\u{463}', '\u{463}', GeneralCategory::Ll),
+    ('\u{464}', '\u{464}', GeneralCategory::Lu),
+    ('\u{465}', '\u{465}', GeneralCategory::Ll),
+    ('\u{466}', '\u{466}', GeneralCategory::Lu),
+    ('\u{467}', '\u{467}', GeneralCategory::Ll),
+    ('\u{468}', '\u{468}', GeneralCategory::Lu),
+    ('\u{469}', '\u{469}', GeneralCategory::Ll),
+    ('\u{46a}', '\u{46a}', GeneralCategory::Lu),
+    ('\u{46b}', '\u{46b}', GeneralCategory::Ll),
+    ('\u{46c}', '\u{46c}', GeneralCategory::Lu),
+    ('\u{46d}', '\u{46d}', GeneralCategory::Ll),
+    ('\u{46e}', '\u{46e}', GeneralCategory::Lu),
+    ('\u{46f}', '\u{46f}', GeneralCategory::Ll),
+    ('\u{470}', '\u{470}', GeneralCategory::Lu),
+    ('\u{471}', '\u{471}', GeneralCategory::Ll),
+    ('\u{472}', '\u{472}', GeneralCategory::Lu),
+    ('\u{473}', '\u{473}', GeneralCategory::Ll),
+    ('\u{474}', '\u{474}', GeneralCategory::Lu),
+    ('\u{475}', '\u{475}', GeneralCategory::Ll),
+    ('\u{476}', '\u{476}', GeneralCategory::Lu),
+    ('\u{477}', '\u{477}', GeneralCategory::Ll),
+    ('\u{478}', '\u{478}', GeneralCategory::Lu),
+    ('\u{479}', '\u{479}', GeneralCategory::Ll),
+    ('\u{47a}', '\u{47a}', GeneralCategory::Lu),
+    ('\u{47b}', '\u{47b}', GeneralCategory::Ll),
+    ('\u{47c}', '\u{47c}', GeneralCategory::Lu),
+    ('\u{47d}', '\u{47d}', GeneralCategory::Ll),
+    ('\u{47e}', '\u{47e}', GeneralCategory::Lu),
+    ('\u{47f}', '\u{47f}', GeneralCategory::Ll),
+    ('\u{480}', '\u{480}', GeneralCategory::Lu),
+    ('\u{481}', '\u{481}', GeneralCategory::Ll),
+    ('\u{482}', '\u{482}', GeneralCategory::So),
+    ('\u{483}', '\u{487}', GeneralCategory::Mn),
+    ('\u{488}', '\u{489}', GeneralCategory::Me),
+    ('\u{48a}', '\u{48a}', GeneralCategory::Lu),
+    ('\u{48b}', '\u{48b}', GeneralCategory::Ll),
+    ('\u{48c}', '\u{48c}', GeneralCategory::Lu),
+    ('\u{48d}', '\u{48d}', GeneralCategory::Ll),
+    ('\u{48e}', '\u{48e}', GeneralCategory::Lu),
+    ('\u{48f}', '\u{48f}', GeneralCategory::Ll),
+    ('\u{490}', '\u{490}', GeneralCategory::Lu),
+    ('\u{491}', '\u{491}', GeneralCategory::Ll),
+    ('\u{492}', '\u{492}', GeneralCategory::Lu),
+    ('\u{493}', '\u{493}', GeneralCategory::Ll),
+    ('\u{494}', '\u{494}', GeneralCategory::Lu),
+    ('\u{495}', '\u{495}', GeneralCategory::Ll),
+    ('\u{496}', '\u{496}', GeneralCategory::Lu),
+    ('\u{497}', '\u{497}', GeneralCategory::Ll),
+    ('\u{498}', '\u{498}', GeneralCategory::Lu),
+    ('\u{499}', '\u{499}', GeneralCategory::Ll),
+    ('\u{49a}', '\u{49a}', GeneralCategory::Lu),
+    ('\u{49b}', '\u{49b}', GeneralCategory::Ll),
+    ('\u{49c}', '\u{49c}', GeneralCategory::Lu),
+    ('\u{49d}', '\u{49d}', GeneralCategory::Ll),
+    ('\u{49e}', '\u{49e}', GeneralCategory::Lu),
+    ('\u{49f}', '\u{49f}', GeneralCategory::Ll),
+    ('\u{4a0}', '\u{4a0}', GeneralCategory::Lu),
+    ('\u{4a1}', '\u{4a1}', GeneralCategory::Ll),
+    ('\u{4a2}', '\u{4a2}', GeneralCategory::Lu),
+    ('\u{4a3}', '\u{4a3}', GeneralCategory::Ll),
+    ('\u{4a4}', '\u{4a4}', GeneralCategory::Lu),
+    ('\u{4a5}', '\u{4a5}', GeneralCategory::Ll),
+    ('\u{4a6}', '\u{4a6}', GeneralCategory::Lu),
+    ('\u{4a7}', '\u{4a7}', GeneralCategory::Ll),
+    ('\u{4a8}', '\u{4a8}', GeneralCategory::Lu),
+    ('\u{4a9}', '\u{4a9}', GeneralCategory::Ll),
+    ('\u{4aa}', '\u{4aa}', GeneralCategory::Lu),
+    ('\u{4ab}', '\u{4ab}', GeneralCategory::Ll),
+    ('\u{4ac}', '\u{4ac}', GeneralCategory::Lu),
+    ('\u{4ad}', '\u{4ad}', GeneralCategory::Ll),
+    ('\u{4ae}', '\u{4ae}', GeneralCategory::Lu),
+    ('\u{4af}', '\u{4af}', GeneralCategory::Ll),
+    ('\u{4b0}', '\u{4b0}', GeneralCategory::Lu),
+    ('\u{4b1}', '\u{4b1}', GeneralCategory::Ll),
+    ('\u{4b2}', '\u{4b2}', GeneralCategory::Lu),
+    ('\u{4b3}', '\u{4b3}', GeneralCategory::Ll),
+    ('\u{4b4}', '\u{4b4}', GeneralCategory::Lu),
+    ('\u{4b5}', '\u{4b5}', GeneralCategory::Ll),
+    ('\u{4b6}', '\u{4b6}', GeneralCategory::Lu),
+    ('\u{4b7}', '\u{4b7}', GeneralCategory::Ll),
+    ('\u{4b8}', '\u{4b8}', GeneralCategory::Lu),
+    ('\u{4b9}', '\u{4b9}', GeneralCategory::Ll),
+    ('\u{4ba}', '\u{4ba}', GeneralCategory::Lu),
+    ('\u{4bb}', '\u{4bb}', GeneralCategory::Ll),
+    ('\u{4bc}', '\u{4bc}', GeneralCategory::Lu),
+    ('\u{4bd}', '\u{4bd}', GeneralCategory::Ll),
+    ('\u{4be}', '\u{4be}', GeneralCategory::Lu),
+    ('\u{4bf}', '\u{4bf}', GeneralCategory::Ll),
+    ('\u{4c0}', '\u{4c1}', GeneralCategory::Lu),
+    ('\u{4c2}', '\u{4c2}', GeneralCategory::Ll),
+    ('\u{4c3}', '\u{4c3}', GeneralCategory::Lu),
+    ('\u{4c4}', '\u{4c4}', GeneralCategory::Ll),
+    ('\u{4c5}', '\u{4c5}', GeneralCategory::Lu),
+    ('\u{4c6}', '\u{4c6}', GeneralCategory::Ll),
+    ('\u{4c7}', '\u{4c7}', GeneralCategory::Lu),
+    ('\u{4c8}', '\u{4c8}', GeneralCategory::Ll),
+    ('\u{4c9}', '\u{4c9}', GeneralCategory::Lu),
+    ('\u{4ca}', '\u{4ca}', GeneralCategory::Ll),
+    ('\u{4cb}', '\u{4cb}', GeneralCategory::Lu),
+    ('\u{4cc}', '\u{4cc}', GeneralCategory::Ll),
+    ('\u{4cd}', '\u{4cd}', GeneralCategory::Lu),
+    ('\u{4ce}', '\u{4cf}', GeneralCategory::Ll),
+    ('\u{4d0}', '\u{4d0}', GeneralCategory::Lu),
+    ('\u{4d1}', '\u{4d1}', GeneralCategory::Ll),
+    ('\u{4d2}', '\u{4d2}', GeneralCategory::Lu),
+    ('\u{4d3}', '\u{4d3}', GeneralCategory::Ll),
+    ('\u{4d4}', '\u{4d4}', GeneralCategory::Lu),
+    ('\u{4d5}', '\u{4d5}', GeneralCategory::Ll),
+    ('\u{4d6}', '\u{4d6}', GeneralCategory::Lu),
+    ('\u{4d7}', '\u{4d7}', GeneralCategory::Ll),
+    ('\u{4d8}', '\u{4d8}', GeneralCategory::Lu),
+    ('\u{4d9}', '\u{4d9}', GeneralCategory::Ll),
+    ('\u{4da}', '\u{4da}', GeneralCategory::Lu),
+    ('\u{4db}', '\u{4db}', GeneralCategory::Ll),
+    ('\u{4dc}', '\u{4dc}', GeneralCategory::Lu),
+    ('\u{4dd}', '\u{4dd}', GeneralCategory::Ll),
+    ('\u{4de}', '\u{4de}', GeneralCategory::Lu),
+    ('\u{4df}', '\u{4df}', GeneralCategory::Ll),
+    ('\u{4e0}', '\u{4e0}', GeneralCategory::Lu),
+    ('\u{4e1}', '\u{4e1}', GeneralCategory::Ll),
+    ('\u{4e2}', '\u{4e2}', GeneralCategory::Lu),
+    ('\u{4e3}', '\u{4e3}', GeneralCategory::Ll),
+    ('\u{4e4}', '\u{4e4}', GeneralCategory::Lu),
+    ('\u{4e5}', '\u{4e5}', GeneralCategory::Ll),
+    ('\u{4e6}', '\u{4e6}', GeneralCategory::Lu),
+    ('\u{4e7}', '\u{4e7}', GeneralCategory::Ll),
+    ('\u{4e8}', '\u{4e8}', GeneralCategory::Lu),
+    ('\u{4e9}', '\u{4e9}', GeneralCategory::Ll),
+    ('\u{4ea}', '\u{4ea}', GeneralCategory::Lu),
+    ('\u{4eb}', '\u{4eb}', GeneralCategory::Ll),
+    ('\u{4ec}', '\u{4ec}', GeneralCategory::Lu),
+    ('\u{4ed}', '\u{4ed}', GeneralCategory::Ll),
+    ('\u{4ee}', '\u{4ee}', GeneralCategory::Lu),
+    ('\u{4ef}', '\u{4ef}', GeneralCategory::Ll),
+    ('\u{4f0}', '\u{4f0}', GeneralCategory::Lu),
+    ('\u{4f1}', '\u{4f1}', GeneralCategory::Ll),
+    ('\u{4f2}', '\u{4f2}', GeneralCategory::Lu),
+    ('\u{4f3}', '\u{4f3}', GeneralCategory::Ll),
+    ('\u{4f4}', '\u{4f4}', GeneralCategory::Lu),
+    ('\u{4f5}', '\u{4f5}', GeneralCategory::Ll),
+    ('\u{4f6}', '\u{4f6}', GeneralCategory::Lu),
+    ('\u{4f7}', '\u{4f7}', GeneralCategory::Ll),
+    ('\u{4f8}', '\u{4f8}', GeneralCategory::Lu),
+    ('\u{4f9}', '\u{4f9}', GeneralCategory::Ll),
+    ('\u{4fa}', '\u{4fa}', GeneralCategory::Lu),
+    ('\u{4fb}', '\u{4fb}', GeneralCategory::Ll),
+    ('\u{4fc}', '\u{4fc}', GeneralCategory::Lu),
+    ('\u{4fd}', '\u{4fd}', GeneralCategory::Ll),
+    ('\u{4fe}', '\u{4fe}', GeneralCategory::Lu),
+    ('\u{4ff}', '\u{4ff}', GeneralCategory::Ll),
+    ('\u{500}', '\u{500}', GeneralCategory::Lu),
+    ('\u{501}', '\u{501}', GeneralCategory::Ll),
+    ('\u{502}', '\u{502}', GeneralCategory::Lu),
+    ('\u{503}', '\u{503}', GeneralCategory::Ll),
+    ('\u{504}', '\u{504}', GeneralCategory::Lu),
+    ('\u{505}', '\u{505}', GeneralCategory::Ll),
+    ('\u{506}', '\u{506}', GeneralCategory::Lu),
+    ('\u{507}', '\u{507}', GeneralCategory::Ll),
+    ('\u{508}', '\u{508}', GeneralCategory::Lu),
+    ('\u{509}', '\u{509}', GeneralCategory::Ll),
+    ('\u{50a}', '\u{50a}', GeneralCategory::Lu),
+    ('\u{50b}', '\u{50b}', GeneralCategory::Ll),
+    ('\u{50c}', '\u{50c}', GeneralCategory::Lu),
+    ('\u{50d}', '\u{50d}', GeneralCategory::Ll),
+    ('\u{50e}', '\u{50e}', GeneralCategory::Lu),
+    ('\u{50f}', '\u{50f}', GeneralCategory::Ll),
+    ('\u{510}', '\u{510}', GeneralCategory::Lu),
+    ('\u{511}', '\u{511}', GeneralCategory::Ll),
+    ('\u{512}', '\u{512}', GeneralCategory::Lu),
+    ('\u{513}', '\u{513}', GeneralCategory::Ll),
+    ('\u{514}', '\u{514}', GeneralCategory::Lu),
+    ('\u{515}', '\u{515}', GeneralCategory::Ll),
+    ('\u{516}', '\u{516}', GeneralCategory::Lu),
+    ('\u{517}', '\u{517}', GeneralCategory::Ll),
+    ('\u{518}', '\u{518}', GeneralCategory::Lu),
+    ('\u{519}', '\u{519}', GeneralCategory::Ll),
+    ('\u{51a}', '\u{51a}', GeneralCategory::Lu),
+    ('\u{51b}', '\u{51b}', GeneralCategory::Ll),
+    ('\u{51c}', '\u{51c}', GeneralCategory::Lu),
+    ('\u{51d}', '\u{51d}', GeneralCategory::Ll),
+    ('\u{51e}', '\u{51e}', GeneralCategory::Lu),
+    ('\u{51f}', '\u{51f}', GeneralCategory::Ll),
+    ('\u{520}', '\u{520}', GeneralCategory::Lu),
+    ('\u{521}', '\u{521}', GeneralCategory::Ll),
+    ('\u{522}', '\u{522}', GeneralCategory::Lu),
+    ('\u{523}', '\u{523}', GeneralCategory::Ll),
+    ('\u{524}', '\u{524}', GeneralCategory::Lu),
+    ('\u{525}', '\u{525}', GeneralCategory::Ll),
+    ('\u{526}', '\u{526}', GeneralCategory::Lu),
+    ('\u{527}', '\u{527}', GeneralCategory::Ll),
+    ('\u{528}', '\u{528}', GeneralCategory::Lu),
+    ('\u{529}', '\u{529}', GeneralCategory::Ll),
+    ('\u{52a}', '\u{52a}', GeneralCategory::Lu),
+    ('\u{52b}', '\u{52b}', GeneralCategory::Ll),
+    ('\u{52c}', '\u{52c}', GeneralCategory::Lu),
+    ('\u{52d}', '\u{52d}', GeneralCategory::Ll),
+    ('\u{52e}', '\u{52e}', GeneralCategory::Lu),
+    ('\u{52f}', '\u{52f}', GeneralCategory::Ll),
+    ('\u{531}', '\u{556}', GeneralCategory::Lu),
+    ('\u{559}', '\u{559}', GeneralCategory::Lm),
+    ('\u{55a}', '\u{55f}', GeneralCategory::Po),
+    ('\u{560}', '\u{588}', GeneralCategory::Ll),
+    ('\u{589}', '\u{589}', GeneralCategory::Po),
+    ('\u{58a}', '\u{58a}', GeneralCategory::Pd),
+    ('\u{58d}', '\u{58e}', GeneralCategory::So),
+    ('\u{58f}', '\u{58f}', GeneralCategory::Sc),
+    ('\u{591}', '\u{5bd}', GeneralCategory::Mn),
+    ('\u{5be}', '\u{5be}', GeneralCategory::Pd),
+    ('\u{5bf}', '\u{5bf}', GeneralCategory::Mn),
+    ('\u{5c0}', '\u{5c0}', GeneralCategory::Po),
+    ('\u{5c1}', '\u{5c2}', GeneralCategory::Mn),
+    ('\u{5c3}', '\u{5c3}', GeneralCategory::Po),
+    ('\u{5c4}', '\u{5c5}', GeneralCategory::Mn),
+    ('\u{5c6}', '\u{5c6}', GeneralCategory::Po),
+    ('\u{5c7}', '\u{5c7}', GeneralCategory::Mn),
+    ('\u{5d0}', '\u{5ea}', GeneralCategory::Lo),
+    ('\u{5ef}', '\u{5f2}', GeneralCategory::Lo),
+    ('\u{5f3}', '\u{5f4}', GeneralCategory::Po),
+    ('\u{600}', '\u{605}', GeneralCategory::Cf),
+    ('\u{606}', '\u{608}', GeneralCategory::Sm),
+    ('\u{609}', '\u{60a}', GeneralCategory::Po),
+    ('\u{60b}', '\u{60b}', GeneralCategory::Sc),
+    ('\u{60c}', '\u{60d}', GeneralCategory::Po),
+    ('\u{60e}', '\u{60f}', GeneralCategory::So),
+    ('\u{610}', '\u{61a}', GeneralCategory::Mn),
+    ('\u{61b}', '\u{61b}', GeneralCategory::Po),
+    ('\u{61c}', '\u{61c}', GeneralCategory::Cf),
+    ('\u{61d}', '\u{61f}', GeneralCategory::Po),
+    ('\u{620}', '\u{63f}', GeneralCategory::Lo),
+    ('\u{640}', '\u{640}', GeneralCategory::Lm),
+    ('\u{641}', '\u{64a}', GeneralCategory::Lo),
+    ('\u{64b}', '\u{65f}', GeneralCategory::Mn),
+    ('\u{660}', '\u{669}', GeneralCategory::Nd),
+    ('\u{66a}', '\u{66d}', GeneralCategory::Po),
+    ('\u{66e}', '\u{66f}', GeneralCategory::Lo),
+    ('\u{670}', '\u{670}', GeneralCategory::Mn),
+    ('\u{671}', '\u{6d3}', GeneralCategory::Lo),
+    ('\u{6d4}', '\u{6d4}', GeneralCategory::Po),
+    ('\u{6d5}', '\u{6d5}', GeneralCategory::Lo),
+    ('\u{6d6}', '\u{6dc}', GeneralCategory::Mn),
+    ('\u{6dd}', '\u{6dd}', GeneralCategory::Cf),
+    ('\u{6de}', '\u{6de}', GeneralCategory::So),
+    ('\u{6df}', '\u{6e4}', GeneralCategory::Mn),
+    ('\u{6e5}', '\u{6e6}', GeneralCategory::Lm),
+    ('\u{6e7}', '\u{6e8}', GeneralCategory::Mn),
+    ('\u{6e9}', '\u{6e9}', GeneralCategory::So),
+    ('\u{6ea}', '\u{6ed}', GeneralCategory::Mn),
+    ('\u{6ee}', '\u{6ef}', GeneralCategory::Lo),
+    ('\u{6f0}', '\u{6f9}', GeneralCategory::Nd),
+    ('\u{6fa}', '\u{6fc}', GeneralCategory::Lo),
+    ('\u{6fd}', '\u{6fe}', GeneralCategory::So),
+    ('\u{6ff}', '\u{6ff}', GeneralCategory::Lo),
+    ('\u{700}', '\u{70d}', GeneralCategory::Po),
+    ('\u{70f}', '\u{70f}', GeneralCategory::Cf),
+    ('\u{710}', '\u{710}', GeneralCategory::Lo),
+    ('\u{711}', '\u{711}', GeneralCategory::Mn),
+    ('\u{712}', '\u{72f}', GeneralCategory::Lo),
+    ('\u{730}', '\u{74a}', GeneralCategory::Mn),
+    ('\u{74d}', '\u{7a5}', GeneralCategory::Lo),
+    ('\u{7a6}', '\u{7b0}', GeneralCategory::Mn),
+    ('\u{7b1}', '\u{7b1}', GeneralCategory::Lo),
+    ('\u{7c0}', '\u{7c9}', GeneralCategory::Nd),
+    ('\u{7ca}', '\u{7ea}', GeneralCategory::Lo),
+    ('\u{7eb}', '\u{7f3}', GeneralCategory::Mn),
+    ('\u{7f4}', '\u{7f5}', GeneralCategory::Lm),
+    ('\u{7f6}', '\u{7f6}', GeneralCategory::So),
+    ('\u{7f7}', '\u{7f9}', GeneralCategory::Po),
+    ('\u{7fa}', '\u{7fa}', GeneralCategory::Lm),
+    ('\u{7fd}', '\u{7fd}', GeneralCategory::Mn),
+    ('\u{7fe}', '\u{7ff}', GeneralCategory::Sc),
+    ('\u{800}', '\u{815}', GeneralCategory::Lo),
+    ('\u{816}', '\u{819}', GeneralCategory::Mn),
+    ('\u{81a}', '\u{81a}', GeneralCategory::Lm),
+    ('\u{81b}', '\u{823}', GeneralCategory::Mn),
+    ('\u{824}', '\u{824}', GeneralCategory::Lm),
+    ('\u{825}', '\u{827}', GeneralCategory::Mn),
+    ('\u{828}', '\u{828}', GeneralCategory::Lm),
+    ('\u{829}', '\u{82d}', GeneralCategory::Mn),
+    ('\u{830}', '\u{83e}', GeneralCategory::Po),
+    ('\u{840}', '\u{858}', GeneralCategory::Lo),
+    ('\u{859}', '\u{85b}', GeneralCategory::Mn),
+    ('\u{85e}', '\u{85e}', GeneralCategory::Po),
+    ('\u{860}', '\u{86a}', GeneralCategory::Lo),
+    ('\u{870}', '\u{887}', GeneralCategory::Lo),
+    ('\u{888}', '\u{888}', GeneralCategory::Sk),
+    ('\u{889}', '\u{88f}', GeneralCategory::Lo),
+    ('\u{890}', '\u{891}', GeneralCategory::Cf),
+    ('\u{897}', '\u{89f}', GeneralCategory::Mn),
+    ('\u{8a0}', '\u{8c8}', GeneralCategory::Lo),
+    ('\u{8c9}', '\u{8c9}', GeneralCategory::Lm),
+    ('\u{8ca}', '\u{8e1}', GeneralCategory::Mn),
+    ('\u{8e2}', '\u{8e2}', GeneralCategory::Cf),
+    ('\u{8e3}', '\u{902}', GeneralCategory::Mn),
+    ('\u{903}', '\u{903}', GeneralCategory::Mc),
+    ('\u{904}', '\u{939}', GeneralCategory::Lo),
+    ('\u{93a}', '\u{93a}', GeneralCategory::Mn),
+    ('\u{93b}', '\u{93b}', GeneralCategory::Mc),
+    ('\u{93c}', '\u{93c}', GeneralCategory::Mn),
+    ('\u{93d}', '\u{93d}', GeneralCategory::Lo),
+    ('\u{93e}', '\u{940}', GeneralCategory::Mc),
+    ('\u{941}', '\u{948}', GeneralCategory::Mn),
+    ('\u{949}', '\u{94c}', GeneralCategory::Mc),
+    ('\u{94d}', '\u{94d}', GeneralCategory::Mn),
+    ('\u{94e}', '\u{94f}', GeneralCategory::Mc),
+    ('\u{950}', '\u{950}', GeneralCategory::Lo),
+    ('\u{951}', '\u{957}', GeneralCategory::Mn),
+    ('\u{958}', '\u{961}', GeneralCategory::Lo),
+    ('\u{962}', '\u{963}', GeneralCategory::Mn),
+    ('\u{964}', '\u{965}', GeneralCategory::Po),
+    ('\u{966}', '\u{96f}', GeneralCategory::Nd),
+    ('\u{970}', '\u{970}', GeneralCategory::Po),
+    ('\u{971}', '\u{971}', GeneralCategory::Lm),
+    ('\u{972}', '\u{980}', GeneralCategory::Lo),
+    ('\u{981}', '\u{981}', GeneralCategory::Mn),
+    ('\u{982}', '\u{983}', GeneralCategory::Mc),
+    ('\u{985}', '\u{98c}', GeneralCategory::Lo),
+    ('\u{98f}', '\u{990}', GeneralCategory::Lo),
+    ('\u{993}', '\u{9a8}', GeneralCategory::Lo),
+    ('\u{9aa}', '\u{9b0}', GeneralCategory::Lo),
+    ('\u{9b2}', '\u{9b2}', GeneralCategory::Lo),
+    ('\u{9b6}', '\u{9b9}', GeneralCategory::Lo),
+    ('\u{9bc}', '\u{9bc}', GeneralCategory::Mn),
+    ('\u{9bd}', '\u{9bd}', GeneralCategory::Lo),
+    ('\u{9be}', '\u{9c0}', GeneralCategory::Mc),
+    ('\u{9c1}', '\u{9c4}', GeneralCategory::Mn),
+    ('\u{9c7}', '\u{9c8}', GeneralCategory::Mc),
+    ('\u{9cb}', '\u{9cc}', GeneralCategory::Mc),
+    ('\u{9cd}', '\u{9cd}', GeneralCategory::Mn),
+    ('\u{9ce}', '\u{9ce}', GeneralCategory::Lo),
+    ('\u{9d7}', '\u{9d7}', GeneralCategory::Mc),
+    ('\u{9dc}', '\u{9dd}', GeneralCategory::Lo),
+    ('\u{9df}', '\u{9e1}', GeneralCategory::Lo),
+    ('\u{9e2}', '\u{9e3}', GeneralCategory::Mn),
+    ('\u{9e6}', '\u{9ef}', GeneralCategory::Nd),
+    ('\u{9f0}', '\u{9f1}', GeneralCategory::Lo),
+    ('\u{9f2}', '\u{9f3}', GeneralCategory::Sc),
+    ('\u{9f4}', '\u{9f9}', GeneralCategory::No),
+    ('\u{9fa}', '\u{9fa}', GeneralCategory::So),
+    ('\u{9fb}', '\u{9fb}', GeneralCategory::Sc),
+    ('\u{9fc}', '\u{9fc}', GeneralCategory::Lo),
+    ('\u{9fd}', '\u{9fd}', GeneralCategory::Po),
+    ('\u{9fe}', '\u{9fe}', GeneralCategory::Mn),
+    ('\u{a01}', '\u{a02}', GeneralCategory::Mn),
+    ('\u{a03}', '\u{a03}', GeneralCategory::Mc),
+    ('\u{a05}', '\u{a0a}', GeneralCategory::Lo),
+    ('\u{a0f}', '\u{a10}', GeneralCategory::Lo),
+    ('\u{a13}', '\u{a28}', GeneralCategory::Lo),
+    ('\u{a2a}', '\u{a30}', GeneralCategory::Lo),
+    ('\u{a32}', '\u{a33}', GeneralCategory::Lo),
+    ('\u{a35}', '\u{a36}', GeneralCategory::Lo),
+    ('\u{a38}', '\u{a39}', GeneralCategory::Lo),
+    ('\u{a3c}', '\u{a3c}', GeneralCategory::Mn),
+    ('\u{a3e}', '\u{a40}', GeneralCategory::Mc),
+    ('\u{a41}', '\u{a42}', GeneralCategory::Mn),
+    ('\u{a47}', '\u{a48}', GeneralCategory::Mn),
+    ('\u{a4b}', '\u{a4d}', GeneralCategory::Mn),
+    ('\u{a51}', '\u{a51}', GeneralCategory::Mn),
+    ('\u{a59}', '\u{a5c}', GeneralCategory::Lo),
+    ('\u{a5e}', '\u{a5e}', GeneralCategory::Lo),
+    ('\u{a66}', '\u{a6f}', GeneralCategory::Nd),
+    ('\u{a70}', '\u{a71}', GeneralCategory::Mn),
+    ('\u{a72}', '\u{a74}', GeneralCategory::Lo),
+    ('\u{a75}', '\u{a75}', GeneralCategory::Mn),
+    ('\u{a76}', '\u{a76}', GeneralCategory::Po),
+    ('\u{a81}', '\u{a82}', GeneralCategory::Mn),
+    ('\u{a83}', '\u{a83}', GeneralCategory::Mc),
+    ('\u{a85}', '\u{a8d}', GeneralCategory::Lo),
+    ('\u{a8f}', '\u{a91}', GeneralCategory::Lo),
+    ('\u{a93}', '\u{aa8}', GeneralCategory::Lo),
+    ('\u{aaa}', '\u{ab0}', GeneralCategory::Lo),
+    ('\u{ab2}', '\u{ab3}', GeneralCategory::Lo),
+    ('\u{ab5}', '\u{ab9}', GeneralCategory::Lo),
+    ('\u{abc}', '\u{abc}', GeneralCategory::Mn),
+    ('\u{abd}', '\u{abd}', GeneralCategory::Lo),
+    ('\u{abe}', '\u{ac0}', GeneralCategory::Mc),
+    ('\u{ac1}', '\u{ac5}', GeneralCategory::Mn),
+    ('\u{ac7}', '\u{ac8}', GeneralCategory::Mn),
+    ('\u{ac9}', '\u{ac9}', GeneralCategory::Mc),
+    ('\u{acb}', '\u{acc}', GeneralCategory::Mc),
+    ('\u{acd}', '\u{acd}', GeneralCategory::Mn),
+    ('\u{ad0}', '\u{ad0}', GeneralCategory::Lo),
+    ('\u{ae0}', '\u{ae1}', GeneralCategory::Lo),
+    ('\u{ae2}', '\u{ae3}', GeneralCategory::Mn),
+    ('\u{ae6}', '\u{aef}', GeneralCategory::Nd),
+    ('\u{af0}', '\u{af0}', GeneralCategory::Po),
+    ('\u{af1}', '\u{af1}', GeneralCategory::Sc),
+    ('\u{af9}', '\u{af9}', GeneralCategory::Lo),
+    ('\u{afa}', '\u{aff}', GeneralCategory::Mn),
+    ('\u{b01}', '\u{b01}', GeneralCategory::Mn),
+    ('\u{b02}', '\u{b03}', GeneralCategory::Mc),
+    ('\u{b05}', '\u{b0c}', GeneralCategory::Lo),
+    ('\u{b0f}', '\u{b10}', GeneralCategory::Lo),
+    ('\u{b13}', '\u{b28}', GeneralCategory::Lo),
+    ('\u{b2a}', '\u{b30}', GeneralCategory::Lo),
+    ('\u{b32}', '\u{b33}', GeneralCategory::Lo),
+    ('\u{b35}', '\u{b39}', GeneralCategory::Lo),
+    ('\u{b3c}', '\u{b3c}', GeneralCategory::Mn),
+    ('\u{b3d}', '\u{b3d}', GeneralCategory::Lo),
+    ('\u{b3e}', '\u{b3e}', GeneralCategory::Mc),
+    ('\u{b3f}', '\u{b3f}', GeneralCategory::Mn),
+    ('\u{b40}', '\u{b40}', GeneralCategory::Mc),
+    ('\u{b41}', '\u{b44}', GeneralCategory::Mn),
+    ('\u{b47}', '\u{b48}', GeneralCategory::Mc),
+    ('\u{b4b}', '\u{b4c}', GeneralCategory::Mc),
+    ('\u{b4d}', '\u{b4d}', GeneralCategory::Mn),
+    ('\u{b55}', '\u{b56}', GeneralCategory::Mn),
+    ('\u{b57}', '\u{b57}', GeneralCategory::Mc),
+    ('\u{b5c}', '\u{b5d}', GeneralCategory::Lo),
+    ('\u{b5f}', '\u{b61}', GeneralCategory::Lo),
+    ('\u{b62}', '\u{b63}', GeneralCategory::Mn),
+    ('\u{b66}', '\u{b6f}', GeneralCategory::Nd),
+    ('\u{b70}', '\u{b70}', GeneralCategory::So),
+    ('\u{b71}', '\u{b71}', GeneralCategory::Lo),
+    ('\u{b72}', '\u{b77}', GeneralCategory::No),
+    ('\u{b82}', '\u{b82}', GeneralCategory::Mn),
+    ('\u{b83}', '\u{b83}', GeneralCategory::Lo),
+    ('\u{b85}', '\u{b8a}', GeneralCategory::Lo),
+    ('\u{b8e}', '\u{b90}', GeneralCategory::Lo),
+    ('\u{b92}', '\u{b95}', GeneralCategory::Lo),
+    ('\u{b99}', '\u{b9a}', GeneralCategory::Lo),
+    ('\u{b9c}', '\u{b9c}', GeneralCategory::Lo),
+    ('\u{b9e}', '\u{b9f}', GeneralCategory::Lo),
+    ('\u{ba3}', '\u{ba4}', GeneralCategory::Lo),
+    ('\u{ba8}', '\u{baa}', GeneralCategory::Lo),
+    ('\u{bae}', '\u{bb9}', GeneralCategory::Lo),
+    ('\u{bbe}', '\u{bbf}', GeneralCategory::Mc),
+    ('\u{bc0}', '\u{bc0}', GeneralCategory::Mn),
+    ('\u{bc1}', '\u{bc2}', GeneralCategory::Mc),
+    ('\u{bc6}', '\u{bc8}', GeneralCategory::Mc),
+    ('\u{bca}', '\u{bcc}', GeneralCategory::Mc),
+    ('\u{bcd}', '\u{bcd}', GeneralCategory::Mn),
+    ('\u{bd0}', '\u{bd0}', GeneralCategory::Lo),
+    ('\u{bd7}', '\u{bd7}', GeneralCategory::Mc),
+    ('\u{be6}', '\u{bef}', GeneralCategory::Nd),
+    ('\u{bf0}', '\u{bf2}', GeneralCategory::No),
+    ('\u{bf3}', '\u{bf8}', GeneralCategory::So),
+    ('\u{bf9}', '\u{bf9}', GeneralCategory::Sc),
+    ('\u{bfa}', '\u{bfa}', GeneralCategory::So),
+    ('\u{c00}', '\u{c00}', GeneralCategory::Mn),
+    ('\u{c01}', '\u{c03}', GeneralCategory::Mc),
+    ('\u{c04}', '\u{c04}', GeneralCategory::Mn),
+    ('\u{c05}', '\u{c0c}', GeneralCategory::Lo),
+    ('\u{c0e}', '\u{c10}', GeneralCategory::Lo),
+    ('\u{c12}', '\u{c28}', GeneralCategory::Lo),
+    ('\u{c2a}', '\u{c39}', GeneralCategory::Lo),
+    ('\u{c3c}', '\u{c3c}', GeneralCategory::Mn),
+    ('\u{c3d}', '\u{c3d}', GeneralCategory::Lo),
+    ('\u{c3e}', '\u{c40}', GeneralCategory::Mn),
+    ('\u{c41}', '\u{c44}', GeneralCategory::Mc),
+    ('\u{c46}', '\u{c48}', GeneralCategory::Mn),
+    ('\u{c4a}', '\u{c4d}', GeneralCategory::Mn),
+    ('\u{c55}', '\u{c56}', GeneralCategory::Mn),
+    ('\u{c58}', '\u{c5a}', GeneralCategory::Lo),
+    ('\u{c5c}', '\u{c5d}', GeneralCategory::Lo),
+    ('\u{c60}', '\u{c61}', GeneralCategory::Lo),
+    ('\u{c62}', '\u{c63}', GeneralCategory::Mn),
+    ('\u{c66}', '\u{c6f}', GeneralCategory::Nd),
+    ('\u{c77}', '\u{c77}', GeneralCategory::Po),
+    ('\u{c78}', '\u{c7e}', GeneralCategory::No),
+    ('\u{c7f}', '\u{c7f}', GeneralCategory::So),
+    ('\u{c80}', '\u{c80}', GeneralCategory::Lo),
+    ('\u{c81}', '\u{c81}', GeneralCategory::Mn),
+    ('\u{c82}', '\u{c83}', GeneralCategory::Mc),
+    ('\u{c84}', '\u{c84}', GeneralCategory::Po),
+    ('\u{c85}', '\u{c8c}', GeneralCategory::Lo),
+    ('\u{c8e}', '\u{c90}', GeneralCategory::Lo),
+    ('\u{c92}', '\u{ca8}', GeneralCategory::Lo),
+    ('\u{caa}', '\u{cb3}', GeneralCategory::Lo),
+    ('\u{cb5}', '\u{cb9}', GeneralCategory::Lo),
+    ('\u{cbc}', '\u{cbc}', GeneralCategory::Mn),
+    ('\u{cbd}', '\u{cbd}', GeneralCategory::Lo),
+    ('\u{cbe}', '\u{cbe}', GeneralCategory::Mc),
+    ('\u{cbf}', '\u{cbf}', GeneralCategory::Mn),
+    ('\u{cc0}', '\u{cc4}', GeneralCategory::Mc),
+    ('\u{cc6}', '\u{cc6}', GeneralCategory::Mn),
+    ('\u{cc7}', '\u{cc8}', GeneralCategory::Mc),
+    ('\u{cca}', '\u{ccb}', GeneralCategory::Mc),
+    ('\u{ccc}', '\u{ccd}', GeneralCategory::Mn),
+    ('\u{cd5}', '\u{cd6}', GeneralCategory::Mc),
+    ('\u{cdc}', '\u{cde}', GeneralCategory::Lo),
+    ('\u{ce0}', '\u{ce1}', GeneralCategory::Lo),
+    ('\u{ce2}', '\u{ce3}', GeneralCategory::Mn),
+    ('\u{ce6}', '\u{cef}', GeneralCategory::Nd),
+    ('\u{cf1}', '\u{cf2}', GeneralCategory::Lo),
+    ('\u{cf3}', '\u{cf3}', GeneralCategory::Mc),
+    ('\u{d00}', '\u{d01}', GeneralCategory::Mn),
+    ('\u{d02}', '\u{d03}', GeneralCategory::Mc),
+    ('\u{d04}', '\u{d0c}', GeneralCategory::Lo),
+    ('\u{d0e}', '\u{d10}', GeneralCategory::Lo),
+    ('\u{d12}', '\u{d3a}', GeneralCategory::Lo),
+    ('\u{d3b}', '\u{d3c}', GeneralCategory::Mn),
+    ('\u{d3d}', '\u{d3d}', GeneralCategory::Lo),
+    ('\u{d3e}', '\u{d40}', GeneralCategory::Mc),
+    ('\u{d41}', '\u{d44}', GeneralCategory::Mn),
+    ('\u{d46}', '\u{d48}', GeneralCategory::Mc),
+    ('\u{d4a}', '\u{d4c}', GeneralCategory::Mc),
+    ('\u{d4d}', '\u{d4d}', GeneralCategory::Mn),
+    ('\u{d4e}', '\u{d4e}', GeneralCategory::Lo),
+    ('\u{d4f}', '\u{d4f}', GeneralCategory::So),
+    ('\u{d54}', '\u{d56}', GeneralCategory::Lo),
+    ('\u{d57}', '\u{d57}', GeneralCategory::Mc),
+    ('\u{d58}', '\u{d5e}', GeneralCategory::No),
+    ('\u{d5f}', '\u{d61}', GeneralCategory::Lo),
+    ('\u{d62}', '\u{d63}', GeneralCategory::Mn),
+    ('\u{d66}', '\u{d6f}', GeneralCategory::Nd),
+    ('\u{d70}', '\u{d78}', GeneralCategory::No),
+    ('\u{d79}', '\u{d79}', GeneralCategory::So),
+    ('\u{d7a}', '\u{d7f}', GeneralCategory::Lo),
+    ('\u{d81}', '\u{d81}', GeneralCategory::Mn),
+    ('\u{d82}', '\u{d83}', GeneralCategory::Mc),
+    ('\u{d85}', '\u{d96}', GeneralCategory::Lo),
+    ('\u{d9a}', '\u{db1}', GeneralCategory::Lo),
+    ('\u{db3}', '\u{dbb}', GeneralCategory::Lo),
+    ('\u{dbd}', '\u{dbd}', GeneralCategory::Lo),
+    ('\u{dc0}', '\u{dc6}', GeneralCategory::Lo),
+    ('\u{dca}', '\u{dca}', GeneralCategory::Mn),
+    ('\u{dcf}', '\u{dd1}', GeneralCategory::Mc),
+    ('\u{dd2}', '\u{dd4}', GeneralCategory::Mn),
+    ('\u{dd6}', '\u{dd6}', GeneralCategory::Mn),
+    ('\u{dd8}', '\u{ddf}', GeneralCategory::Mc),
+    ('\u{de6}', '\u{def}', GeneralCategory::Nd),
+    ('\u{df2}', '\u{df3}', GeneralCategory::Mc),
+    ('\u{df4}', '\u{df4}', GeneralCategory::Po),
+    ('\u{e01}', '\u{e30}', GeneralCategory::Lo),
+    ('\u{e31}', '\u{e31}', GeneralCategory::Mn),
+    ('\u{e32}', '\u{e33}', GeneralCategory::Lo),
+    ('\u{e34}', '\u{e3a}', GeneralCategory::Mn),
+    ('\u{e3f}', '\u{e3f}', GeneralCategory::Sc),
+    ('\u{e40}', '\u{e45}', GeneralCategory::Lo),
+    ('\u{e46}', '\u{e46}', GeneralCategory::Lm),
+    ('\u{e47}', '\u{e4e}', GeneralCategory::Mn),
+    ('\u{e4f}', '\u{e4f}', GeneralCategory::Po),
+    ('\u{e50}', '\u{e59}', GeneralCategory::Nd),
+    ('\u{e5a}', '\u{e5b}', GeneralCategory::Po),
+    ('\u{e81}', '\u{e82}', GeneralCategory::Lo),
+    ('\u{e84}', '\u{e84}', GeneralCategory::Lo),
+    ('\u{e86}', '\u{e8a}', GeneralCategory::Lo),
+    ('\u{e8c}', '\u{ea3}', GeneralCategory::Lo),
+    ('\u{ea5}', '\u{ea5}', GeneralCategory::Lo),
+    ('\u{ea7}', '\u{eb0}', GeneralCategory::Lo),
+    ('\u{eb1}', '\u{eb1}', GeneralCategory::Mn),
+    ('\u{eb2}', '\u{eb3}', GeneralCategory::Lo),
+    ('\u{eb4}', '\u{ebc}', GeneralCategory::Mn),
+    ('\u{ebd}', '\u{ebd}', GeneralCategory::Lo),
+    ('\u{ec0}', '\u{ec4}', GeneralCategory::Lo),
+    ('\u{ec6}', '\u{ec6}', GeneralCategory::Lm),
+    ('\u{ec8}', '\u{ece}', GeneralCategory::Mn),
+    ('\u{ed0}', '\u{ed9}', GeneralCategory::Nd),
+    ('\u{edc}', '\u{edf}', GeneralCategory::Lo),
+    ('\u{f00}', '\u{f00}', GeneralCategory::Lo),
+    ('\u{f01}', '\u{f03}', GeneralCategory::So),
+    ('\u{f04}', '\u{f12}', GeneralCategory::Po),
+    ('\u{f13}', '\u{f13}', GeneralCategory::So),
+    ('\u{f14}', '\u{f14}', GeneralCategory::Po),
+    ('\u{f15}', '\u{f17}', GeneralCategory::So),
+    ('\u{f18}', '\u{f19}', GeneralCategory::Mn),
+    ('\u{f1a}', '\u{f1f}', GeneralCategory::So),
+    ('\u{f20}', '\u{f29}', GeneralCategory::Nd),
+    ('\u{f2a}', '\u{f33}', GeneralCategory::No),
+    ('\u{f34}', '\u{f34}', GeneralCategory::So),
+    ('\u{f35}', '\u{f35}', GeneralCategory::Mn),
+    ('\u{f36}', '\u{f36}', GeneralCategory::So),
+    ('\u{f37}', '\u{f37}', GeneralCategory::Mn),
+    ('\u{f38}', '\u{f38}', GeneralCategory::So),
+    ('\u{f39}', '\u{f39}', GeneralCategory::Mn),
+    ('\u{f3a}', '\u{f3a}', GeneralCategory::Ps),
+    ('\u{f3b}', '\u{f3b}', GeneralCategory::Pe),
+    ('\u{f3c}', '\u{f3c}', GeneralCategory::Ps),
+    ('\u{f3d}', '\u{f3d}', GeneralCategory::Pe),
+    ('\u{f3e}', '\u{f3f}', GeneralCategory::Mc),
+    ('\u{f40}', '\u{f47}', GeneralCategory::Lo),
+    ('\u{f49}', '\u{f6c}', GeneralCategory::Lo),
+    ('\u{f71}', '\u{f7e}', GeneralCategory::Mn),
+    ('\u{f7f}', '\u{f7f}', GeneralCategory::Mc),
+    ('\u{f80}', '\u{f84}', GeneralCategory::Mn),
+    ('\u{f85}', '\u{f85}', GeneralCategory::Po),
+    ('\u{f86}', '\u{f87}', GeneralCategory::Mn),
+    ('\u{f88}', '\u{f8c}', GeneralCategory::Lo),
+    ('\u{f8d}', '\u{f97}', GeneralCategory::Mn),
+    ('\u{f99}', '\u{fbc}', GeneralCategory::Mn),
+    ('\u{fbe}', '\u{fc5}', GeneralCategory::So),
+    ('\u{fc6}', '\u{fc6}', GeneralCategory::Mn),
+    ('\u{fc7}', '\u{fcc}', GeneralCategory::So),
+    ('\u{fce}', '\u{fcf}', GeneralCategory::So),
+    ('\u{fd0}', '\u{fd4}', GeneralCategory::Po),
+    ('\u{fd5}', '\u{fd8}', GeneralCategory::So),
+    ('\u{fd9}', '\u{fda}', GeneralCategory::Po),
+    ('\u{1000}', '\u{102a}', GeneralCategory::Lo),
+    ('\u{102b}', '\u{102c}', GeneralCategory::Mc),
+    ('\u{102d}', '\u{1030}', GeneralCategory::Mn),
+    ('\u{1031}', '\u{1031}', GeneralCategory::Mc),
+    ('\u{1032}', '\u{1037}', GeneralCategory::Mn),
+    ('\u{1038}', '\u{1038}', GeneralCategory::Mc),
+    ('\u{1039}', '\u{103a}', GeneralCategory::Mn),
+    ('\u{103b}', '\u{103c}', GeneralCategory::Mc),
+    ('\u{103d}', '\u{103e}', GeneralCategory::Mn),
+    ('\u{103f}', '\u{103f}', GeneralCategory::Lo),
+    ('\u{1040}', '\u{1049}', GeneralCategory::Nd),
+    ('\u{104a}', '\u{104f}', GeneralCategory::Po),
+    ('\u{1050}', '\u{1055}', GeneralCategory::Lo),
+    ('\u{1056}', '\u{1057}', GeneralCategory::Mc),
+    ('\u{1058}', '\u{1059}', GeneralCategory::Mn),
+    ('\u{105a}', '\u{105d}', GeneralCategory::Lo),
+    ('\u{105e}', '\u{1060}', GeneralCategory::Mn),
+    ('\u{1061}', '\u{1061}', GeneralCategory::Lo),
+    ('\u{1062}', '\u{1064}', GeneralCategory::Mc),
+    ('\u{1065}', '\u{1066}', GeneralCategory::Lo),
+    ('\u{1067}', '\u{106d}', GeneralCategory::Mc),
+    ('\u{106e}', '\u{1070}', GeneralCategory::Lo),
+    ('\u{1071}', '\u{1074}', GeneralCategory::Mn),
+    ('\u{1075}', '\u{1081}', GeneralCategory::Lo),
+    ('\u{1082}', '\u{1082}', GeneralCategory::Mn),
+    ('\u{1083}', '\u{1084}', GeneralCategory::Mc),
+    ('\u{1085}', '\u{1086}', GeneralCategory::Mn),
+    ('\u{1087}', '\u{108c}', GeneralCategory::Mc),
+    ('\u{108d}', '\u{108d}', GeneralCategory::Mn),
+    ('\u{108e}', '\u{108e}', GeneralCategory::Lo),
+    ('\u{108f}', '\u{108f}', GeneralCategory::Mc),
+    ('\u{1090}', '\u{1099}', GeneralCategory::Nd),
+    ('\u{109a}', '\u{109c}', GeneralCategory::Mc),
+    ('\u{109d}', '\u{109d}', GeneralCategory::Mn),
+    ('\u{109e}', '\u{109f}', GeneralCategory::So),
+    ('\u{10a0}', '\u{10c5}', GeneralCategory::Lu),
+    ('\u{10c7}', '\u{10c7}', GeneralCategory::Lu),
+    ('\u{10cd}', '\u{10cd}', GeneralCategory::Lu),
+    ('\u{10d0}', '\u{10fa}', GeneralCategory::Ll),
+    ('\u{10fb}', '\u{10fb}', GeneralCategory::Po),
+    ('\u{10fc}', '\u{10fc}', GeneralCategory::Lm),
+    ('\u{10fd}', '\u{10ff}', GeneralCategory::Ll),
+    ('\u{1100}', '\u{1248}', GeneralCategory::Lo),
+    ('\u{124a}', '\u{124d}', GeneralCategory::Lo),
+    ('\u{1250}', '\u{1256}', GeneralCategory::Lo),
+    ('\u{1258}', '\u{1258}', GeneralCategory::Lo),
+    ('\u{125a}', '\u{125d}', GeneralCategory::Lo),
+    ('\u{1260}', '\u{1288}', GeneralCategory::Lo),
+    ('\u{128a}', '\u{128d}', GeneralCategory::Lo),
+    ('\u{1290}', '\u{12b0}', GeneralCategory::Lo),
+    ('\u{12b2}', '\u{12b5}', GeneralCategory::Lo),
+    ('\u{12b8}', '\u{12be}', GeneralCategory::Lo),
+    ('\u{12c0}', '\u{12c0}', GeneralCategory::Lo),
+    ('\u{12c2}', '\u{12c5}', GeneralCategory::Lo),
+    ('\u{12c8}', '\u{12d6}', GeneralCategory::Lo),
+    ('\u{12d8}', '\u{1310}', GeneralCategory::Lo),
+    ('\u{1312}', '\u{1315}', GeneralCategory::Lo),
+    ('\u{1318}', '\u{135a}', GeneralCategory::Lo),
+    ('\u{135d}', '\u{135f}', GeneralCategory::Mn),
+    ('\u{1360}', '\u{1368}', GeneralCategory::Po),
+    ('\u{1369}', '\u{137c}', GeneralCategory::No),
+    ('\u{1380}', '\u{138f}', GeneralCategory::Lo),
+    ('\u{1390}', '\u{1399}', GeneralCategory::So),
+    ('\u{13a0}', '\u{13f5}', GeneralCategory::Lu),
+    ('\u{13f8}', '\u{13fd}', GeneralCategory::Ll),
+    ('\u{1400}', '\u{1400}', GeneralCategory::Pd),
+    ('\u{1401}', '\u{166c}', GeneralCategory::Lo),
+    ('\u{166d}', '\u{166d}', GeneralCategory::So),
+    ('\u{166e}', '\u{166e}', GeneralCategory::Po),
+    ('\u{166f}', '\u{167f}', GeneralCategory::Lo),
+    ('\u{1680}', '\u{1680}', GeneralCategory::Zs),
+    ('\u{1681}', '\u{169a}', GeneralCategory::Lo),
+    ('\u{169b}', '\u{169b}', GeneralCategory::Ps),
+    ('\u{169c}', '\u{169c}', GeneralCategory::Pe),
+    ('\u{16a0}', '\u{16ea}', GeneralCategory::Lo),
+    ('\u{16eb}', '\u{16ed}', GeneralCategory::Po),
+    ('\u{16ee}', '\u{16f0}', GeneralCategory::Nl),
+    ('\u{16f1}', '\u{16f8}', GeneralCategory::Lo),
+    ('\u{1700}', '\u{1711}', GeneralCategory::Lo),
+    ('\u{1712}', '\u{1714}', GeneralCategory::Mn),
+    ('\u{1715}', '\u{1715}', GeneralCategory::Mc),
+    ('\u{171f}', '\u{1731}', GeneralCategory::Lo),
+    ('\u{1732}', '\u{1733}', GeneralCategory::Mn),
+    ('\u{1734}', '\u{1734}', GeneralCategory::Mc),
+    ('\u{1735}', '\u{1736}', GeneralCategory::Po),
+    ('\u{1740}', '\u{1751}', GeneralCategory::Lo),
+    ('\u{1752}', '\u{1753}', GeneralCategory::Mn),
+    ('\u{1760}', '\u{176c}', GeneralCategory::Lo),
+    ('\u{176e}', '\u{1770}', GeneralCategory::Lo),
+    ('\u{1772}', '\u{1773}', GeneralCategory::Mn),
+    ('\u{1780}', '\u{17b3}', GeneralCategory::Lo),
+    ('\u{17b4}', '\u{17b5}', GeneralCategory::Mn),
+    ('\u{17b6}', '\u{17b6}', GeneralCategory::Mc),
+    ('\u{17b7}', '\u{17bd}', GeneralCategory::Mn),
+    ('\u{17be}', '\u{17c5}', GeneralCategory::Mc),
+    ('\u{17c6}', '\u{17c6}', GeneralCategory::Mn),
+    ('\u{17c7}', '\u{17c8}', GeneralCategory::Mc),
+    ('\u{17c9}', '\u{17d3}', GeneralCategory::Mn),
+    ('\u{17d4}', '\u{17d6}', GeneralCategory::Po),
+    ('\u{17d7}', '\u{17d7}', GeneralCategory::Lm),
+    ('\u{17d8}', '\u{17da}', GeneralCategory::Po),
+    ('\u{17db}', '\u{17db}', GeneralCategory::Sc),
+    ('\u{17dc}', '\u{17dc}', GeneralCategory::Lo),
+    ('\u{17dd}', '\u{17dd}', GeneralCategory::Mn),
+    ('\u{17e0}', '\u{17e9}', GeneralCategory::Nd),
+    ('\u{17f0}', '\u{17f9}', GeneralCategory::No),
+    ('\u{1800}', '\u{1805}', GeneralCategory::Po),
+    ('\u{1806}', '\u{1806}', GeneralCategory::Pd),
+    ('\u{1807}', '\u{180a}', GeneralCategory::Po),
+    ('\u{180b}', '\u{180d}', GeneralCategory::Mn),
+    ('\u{180e}', '\u{180e}', GeneralCategory::Cf),
+    ('\u{180f}', '\u{180f}', GeneralCategory::Mn),
+    ('\u{1810}', '\u{1819}', GeneralCategory::Nd),
+    ('\u{1820}', '\u{1842}', GeneralCategory::Lo),
+    ('\u{1843}', '\u{1843}', GeneralCategory::Lm),
+    ('\u{1844}', '\u{1878}', GeneralCategory::Lo),
+    ('\u{1880}', '\u{1884}', GeneralCategory::Lo),
+    ('\u{1885}', '\u{1886}', GeneralCategory::Mn),
+    ('\u{1887}', '\u{18a8}', GeneralCategory::Lo),
+    ('\u{18a9}', '\u{18a9}', GeneralCategory::Mn),
+    ('\u{18aa}', '\u{18aa}', GeneralCategory::Lo),
+    ('\u{18b0}', '\u{18f5}', GeneralCategory::Lo),
+    ('\u{1900}', '\u{191e}', GeneralCategory::Lo),
+    ('\u{1920}', '\u{1922}', GeneralCategory::Mn),
+    ('\u{1923}', '\u{1926}', GeneralCategory::Mc),
+    ('\u{1927}', '\u{1928}', GeneralCategory::Mn),
+    ('\u{1929}', '\u{192b}', GeneralCategory::Mc),
+    ('\u{1930}', '\u{1931}', GeneralCategory::Mc),
+    ('\u{1932}', '\u{1932}', GeneralCategory::Mn),
+    ('\u{1933}', '\u{1938}', GeneralCategory::Mc),
+    ('\u{1939}', '\u{193b}', GeneralCategory::Mn),
+    ('\u{1940}', '\u{1940}', GeneralCategory::So),
+    ('\u{1944}', '\u{1945}', GeneralCategory::Po),
+    ('\u{1946}', '\u{194f}', GeneralCategory::Nd),
+    ('\u{1950}', '\u{196d}', GeneralCategory::Lo),
+    ('\u{1970}', '\u{1974}', GeneralCategory::Lo),
+    ('\u{1980}', '\u{19ab}', GeneralCategory::Lo),
+    ('\u{19b0}', '\u{19c9}', GeneralCategory::Lo),
+    ('\u{19d0}', '\u{19d9}', GeneralCategory::Nd),
+    ('\u{19da}', '\u{19da}', GeneralCategory::No),
+    ('\u{19de}', '\u{19ff}', GeneralCategory::So),
+    ('\u{1a00}', '\u{1a16}', GeneralCategory::Lo),
+    ('\u{1a17}', '\u{1a18}', GeneralCategory::Mn),
+    ('\u{1a19}', '\u{1a1a}', GeneralCategory::Mc),
+    ('\u{1a1b}', '\u{1a1b}', GeneralCategory::Mn),
+    ('\u{1a1e}', '\u{1a1f}', GeneralCategory::Po),
+    ('\u{1a20}', '\u{1a54}', GeneralCategory::Lo),
+    ('\u{1a55}', '\u{1a55}', GeneralCategory::Mc),
+    ('\u{1a56}', '\u{1a56}', GeneralCategory::Mn),
+    ('\u{1a57}', '\u{1a57}', GeneralCategory::Mc),
+    ('\u{1a58}', '\u{1a5e}', GeneralCategory::Mn),
+    ('\u{1a60}', '\u{1a60}', GeneralCategory::Mn),
+    ('\u{1a61}', '\u{1a61}', GeneralCategory::Mc),
+    ('\u{1a62}', '\u{1a62}', GeneralCategory::Mn),
+    ('\u{1a63}', '\u{1a64}', GeneralCategory::Mc),
+    ('\u{1a65}', '\u{1a6c}', GeneralCategory::Mn),
+    ('\u{1a6d}', '\u{1a72}', GeneralCategory::Mc),
+    ('\u{1a73}', '\u{1a7c}', GeneralCategory::Mn),
+    ('\u{1a7f}', '\u{1a7f}', GeneralCategory::Mn),
+    ('\u{1a80}', '\u{1a89}', GeneralCategory::Nd),
+    ('\u{1a90}', '\u{1a99}', GeneralCategory::Nd),
+    ('\u{1aa0}', '\u{1aa6}', GeneralCategory::Po),
+    ('\u{1aa7}', '\u{1aa7}', GeneralCategory::Lm),
+    ('\u{1aa8}', '\u{1aad}', GeneralCategory::Po),
+    ('\u{1ab0}', '\u{1abd}', GeneralCategory::Mn),
+    ('\u{1abe}', '\u{1abe}', GeneralCategory::Me),
+    ('\u{1abf}', '\u{1add}', GeneralCategory::Mn),
+    ('\u{1ae0}', '\u{1aeb}', GeneralCategory::Mn),
+    ('\u{1b00}', '\u{1b03}', GeneralCategory::Mn),
+    ('\u{1b04}', '\u{1b04}', GeneralCategory::Mc),
+    ('\u{1b05}', '\u{1b33}', GeneralCategory::Lo),
+    ('\u{1b34}', '\u{1b34}', GeneralCategory::Mn),
+    ('\u{1b35}', '\u{1b35}', GeneralCategory::Mc),
+    ('\u{1b36}', '\u{1b3a}', GeneralCategory::Mn),
+    ('\u{1b3b}', '\u{1b3b}', GeneralCategory::Mc),
+    ('\u{1b3c}', '\u{1b3c}', GeneralCategory::Mn),
+    ('\u{1b3d}', '\u{1b41}', GeneralCategory::Mc),
+    ('\u{1b42}', '\u{1b42}', GeneralCategory::Mn),
+    ('\u{1b43}', '\u{1b44}', GeneralCategory::Mc),
+    ('\u{1b45}', '\u{1b4c}', GeneralCategory::Lo),
+    ('\u{1b4e}', '\u{1b4f}', GeneralCategory::Po),
+    ('\u{1b50}', '\u{1b59}', GeneralCategory::Nd),
+    ('\u{1b5a}', '\u{1b60}', GeneralCategory::Po),
+    ('\u{1b61}', '\u{1b6a}', GeneralCategory::So),
+    ('\u{1b6b}', '\u{1b73}', GeneralCategory::Mn),
+    ('\u{1b74}', '\u{1b7c}', GeneralCategory::So),
+    ('\u{1b7d}', '\u{1b7f}', GeneralCategory::Po),
+    ('\u{1b80}', '\u{1b81}', GeneralCategory::Mn),
+    ('\u{1b82}', '\u{1b82}', GeneralCategory::Mc),
+    ('\u{1b83}', '\u{1ba0}', GeneralCategory::Lo),
+    ('\u{1ba1}', '\u{1ba1}', GeneralCategory::Mc),
+    ('\u{1ba2}', '\u{1ba5}', GeneralCategory::Mn),
+    ('\u{1ba6}', '\u{1ba7}', GeneralCategory::Mc),
+    ('\u{1ba8}', '\u{1ba9}', GeneralCategory::Mn),
+    ('\u{1baa}', '\u{1baa}', GeneralCategory::Mc),
+    ('\u{1bab}', '\u{1bad}', GeneralCategory::Mn),
+    ('\u{1bae}', '\u{1baf}', GeneralCategory::Lo),
+    ('\u{1bb0}', '\u{1bb9}', GeneralCategory::Nd),
+    ('\u{1bba}', '\u{1be5}', GeneralCategory::Lo),
+    ('\u{1be6}', '\u{1be6}', GeneralCategory::Mn),
+    ('\u{1be7}', '\u{1be7}', GeneralCategory::Mc),
+    ('\u{1be8}', '\u{1be9}', GeneralCategory::Mn),
+    ('\u{1bea}', '\u{1bec}', GeneralCategory::Mc),
+    ('\u{1bed}', '\u{1bed}', GeneralCategory::Mn),
+    ('\u{1bee}', '\u{1bee}', GeneralCategory::Mc),
+    ('\u{1bef}', '\u{1bf1}', GeneralCategory::Mn),
+    ('\u{1bf2}', '\u{1bf3}', GeneralCategory::Mc),
+    ('\u{1bfc}', '\u{1bff}', GeneralCategory::Po),
+    ('\u{1c00}', '\u{1c23}', GeneralCategory::Lo),
+    ('\u{1c24}', '\u{1c2b}', GeneralCategory::Mc),
+    ('\u{1c2c}', '\u{1c33}', GeneralCategory::Mn),
+    ('\u{1c34}', '\u{1c35}', GeneralCategory::Mc),
+    ('\u{1c36}', '\u{1c37}', GeneralCategory::Mn),
+    ('\u{1c3b}', '\u{1c3f}', GeneralCategory::Po),
+    ('\u{1c40}', '\u{1c49}', GeneralCategory::Nd),
+    ('\u{1c4d}', '\u{1c4f}', GeneralCategory::Lo),
+    ('\u{1c50}', '\u{1c59}', GeneralCategory::Nd),
+    ('\u{1c5a}', '\u{1c77}', GeneralCategory::Lo),
+    ('\u{1c78}', '\u{1c7d}', GeneralCategory::Lm),
+    ('\u{1c7e}', '\u{1c7f}', GeneralCategory::Po),
+    ('\u{1c80}', '\u{1c88}', GeneralCategory::Ll),
+    ('\u{1c89}', '\u{1c89}', GeneralCategory::Lu),
+    ('\u{1c8a}', '\u{1c8a}', GeneralCategory::Ll),
+    ('\u{1c90}', '\u{1cba}', GeneralCategory::Lu),
+    ('\u{1cbd}', '\u{1cbf}', GeneralCategory::Lu),
+    ('\u{1cc0}', '\u{1cc7}', GeneralCategory::Po),
+    ('\u{1cd0}', '\u{1cd2}', GeneralCategory::Mn),
+    ('\u{1cd3}', '\u{1cd3}', GeneralCategory::Po),
+    ('\u{1cd4}', '\u{1ce0}', GeneralCategory::Mn),
+    ('\u{1ce1}', '\u{1ce1}', GeneralCategory::Mc),
+    ('\u{1ce2}', '\u{1ce8}', GeneralCategory::Mn),
+    ('\u{1ce9}', '\u{1cec}', GeneralCategory::Lo),
+    ('\u{1ced}', '\u{1ced}', GeneralCategory::Mn),
+    ('\u{1cee}', '\u{1cf3}', GeneralCategory::Lo),
+    ('\u{1cf4}', '\u{1cf4}', GeneralCategory::Mn),
+    ('\u{1cf5}', '\u{1cf6}', GeneralCategory::Lo),
+    ('\u{1cf7}', '\u{1cf7}', GeneralCategory::Mc),
+    ('\u{1cf8}', '\u{1cf9}', GeneralCategory::Mn),
+    ('\u{1cfa}', '\u{1cfa}', GeneralCategory::Lo),
+    ('\u{1d00}', '\u{1d2b}', GeneralCategory::Ll),
+    ('\u{1d2c}', '\u{1d6a}', GeneralCategory::Lm),
+    ('\u{1d6b}', '\u{1d77}', GeneralCategory::Ll),
+    ('\u{1d78}', '\u{1d78}', GeneralCategory::Lm),
+    ('\u{1d79}', '\u{1d9a}', GeneralCategory::Ll),
+    ('\u{1d9b}', '\u{1dbf}', GeneralCategory::Lm),
+    ('\u{1dc0}', '\u{1dff}', GeneralCategory::Mn),
+    ('\u{1e00}', '\u{1e00}', GeneralCategory::Lu),
+    ('\u{1e01}', '\u{1e01}', GeneralCategory::Ll),
+    ('\u{1e02}', '\u{1e02}', GeneralCategory::Lu),
+    ('\u{1e03}', '\u{1e03}', GeneralCategory::Ll),
+    ('\u{1e04}', '\u{1e04}', GeneralCategory::Lu),
+    ('\u{1e05}', '\u{1e05}', GeneralCategory::Ll),
+    ('\u{1e06}', '\u{1e06}', GeneralCategory::Lu),
+    ('\u{1e07}', '\u{1e07}', GeneralCategory::Ll),
+    ('\u{1e08}', '\u{1e08}', GeneralCategory::Lu),
+    ('\u{1e09}', '\u{1e09}', GeneralCategory::Ll),
+    ('\u{1e0a}', '\u{1e0a}', GeneralCategory::Lu),
+    ('\u{1e0b}', '\u{1e0b}', GeneralCategory::Ll),
+    ('\u{1e0c}', '\u{1e0c}', GeneralCategory::Lu),
+    ('\u{1e0d}', '\u{1e0d}', GeneralCategory::Ll),
+    ('\u{1e0e}', '\u{1e0e}', GeneralCategory::Lu),
+    ('\u{1e0f}', '\u{1e0f}', GeneralCategory::Ll),
+    ('\u{1e10}', '\u{1e10}', GeneralCategory::Lu),
+    ('\u{1e11}', '\u{1e11}', GeneralCategory::Ll),
+    ('\u{1e12}', '\u{1e12}', GeneralCategory::Lu),
+    ('\u{1e13}', '\u{1e13}', GeneralCategory::Ll),
+    ('\u{1e14}', '\u{1e14}', GeneralCategory::Lu),
+    ('\u{1e15}', '\u{1e15}', GeneralCategory::Ll),
+    ('\u{1e16}', '\u{1e16}', GeneralCategory::Lu),
+    ('\u{1e17}', '\u{1e17}', GeneralCategory::Ll),
+    ('\u{1e18}', '\u{1e18}', GeneralCategory::Lu),
+    ('\u{1e19}', '\u{1e19}', GeneralCategory::Ll),
+    ('\u{1e1a}', '\u{1e1a}', GeneralCategory::Lu),
+    ('\u{1e1b}', '\u{1e1b}', GeneralCategory::Ll),
+    ('\u{1e1c}', '\u{1e1c}', GeneralCategory::Lu),
+    ('\u{1e1d}', '\u{1e1d}', GeneralCategory::Ll),
+    ('\u{1e1e}', '\u{1e1e}', GeneralCategory::Lu),
+    ('\u{1e1f}', '\u{1e1f}', GeneralCategory::Ll),
+    ('\u{1e20}', '\u{1e20}', GeneralCategory::Lu),
+    ('\u{1e21}', '\u{1e21}', GeneralCategory::Ll),
+    ('\u{1e22}', '\u{1e22}', GeneralCategory::Lu),
+    ('\u{1e23}', '\u{1e23}', GeneralCategory::Ll),
+    ('\u{1e24}', '\u{1e24}', GeneralCategory::Lu),
+    ('\u{1e25}', '\u{1e25}', GeneralCategory::Ll),
+    ('\u{1e26}', '\u{1e26}', GeneralCategory::Lu),
+    ('\u{1e27}', '\u{1e27}', GeneralCategory::Ll),
+    ('\u{1e28}', '\u{1e28}', GeneralCategory::Lu),
+    ('\u{1e29}', '\u{1e29}', GeneralCategory::Ll),
+    ('\u{1e2a}', '\u{1e2a}', GeneralCategory::Lu),
+    ('\u{1e2b}', '\u{1e2b}', GeneralCategory::Ll),
+    ('\u{1e2c}', '\u{1e2c}', GeneralCategory::Lu),
+    ('\u{1e2d}', '\u{1e2d}', GeneralCategory::Ll),
+    ('\u{1e2e}', '\u{1e2e}', GeneralCategory::Lu),
+    ('\u{1e2f}', '\u{1e2f}', GeneralCategory::Ll),
+    ('\u{1e30}', '\u{1e30}', GeneralCategory::Lu),
+    ('\u{1e31}', '\u{1e31}', GeneralCategory::Ll),
+    ('\u{1e32}', '\u{1e32}', GeneralCategory::Lu),
+    ('\u{1e33}', '\u{1e33}', GeneralCategory::Ll),
+    ('\u{1e34}', '\u{1e34}', GeneralCategory::Lu),
+    ('\u{1e35}', '\u{1e35}', GeneralCategory::Ll),
+    ('\u{1e36}', '\u{1e36}', GeneralCategory::Lu),
+    ('\u{1e37}', '\u{1e37}', GeneralCategory::Ll),
+    ('\u{1e38}', '\u{1e38}', GeneralCategory::Lu),
+    ('\u{1e39}', '\u{1e39}', GeneralCategory::Ll),
+    ('\u{1e3a}', '\u{1e3a}', GeneralCategory::Lu),
+    ('\u{1e3b}', '\u{1e3b}', GeneralCategory::Ll),
+    ('\u{1e3c}', '\u{1e3c}', GeneralCategory::Lu),
+    ('\u{1e3d}', '\u{1e3d}', GeneralCategory::Ll),
+    ('\u{1e3e}', '\u{1e3e}', GeneralCategory::Lu),
+    ('\u{1e3f}', '\u{1e3f}', GeneralCategory::Ll),
+    ('\u{1e40}', '\u{1e40}', GeneralCategory::Lu),
+    ('\u{1e41}', '\u{1e41}', GeneralCategory::Ll),
+    ('\u{1e42}', '\u{1e42}', GeneralCategory::Lu),
+    ('\u{1e43}', '\u{1e43}', GeneralCategory::Ll),
+    ('\u{1e44}', '\u{1e44}', GeneralCategory::Lu),
+    ('\u{1e45}', '\u{1e45}', GeneralCategory::Ll),
+    ('\u{1e46}', '\u{1e46}', GeneralCategory::Lu),
+    ('\u{1e47}', '\u{1e47}', GeneralCategory::Ll),
+    ('\u{1e48}', '\u{1e48}', GeneralCategory::Lu),
+    ('\u{1e49}', '\u{1e49}', GeneralCategory::Ll),
+    ('\u{1e4a}', '\u{1e4a}', GeneralCategory::Lu),
+    ('\u{1e4b}', '\u{1e4b}', GeneralCategory::Ll),
+    ('\u{1e4c}', '\u{1e4c}', GeneralCategory::Lu),
+    ('\u{1e4d}', '\u{1e4d}', GeneralCategory::Ll),
+    ('\u{1e4e}', '\u{1e4e}', GeneralCategory::Lu),
+    ('\u{1e4f}', '\u{1e4f}', GeneralCategory::Ll),
+    ('\u{1e50}', '\u{1e50}', GeneralCategory::Lu),
+    ('\u{1e51}', '\u{1e51}', GeneralCategory::Ll),
+    ('\u{1e52}', '\u{1e52}', GeneralCategory::Lu),
+    ('\u{1e53}', '\u{1e53}', GeneralCategory::Ll),
+    ('\u{1e54}', '\u{1e54}', GeneralCategory::Lu),
+    ('\u{1e55}', '\u{1e55}', GeneralCategory::Ll),
+    ('\u{1e56}', '\u{1e56}', GeneralCategory::Lu),
+    ('\u{1e57}', '\u{1e57}', GeneralCategory::Ll),
+    ('\u{1e58}', '\u{1e58}', GeneralCategory::Lu),
+    ('\u{1e59}', '\u{1e59}', GeneralCategory::Ll),
+    ('\u{1e5a}', '\u{1e5a}', GeneralCategory::Lu),
+    ('\u{1e5b}', '\u{1e5b}', GeneralCategory::Ll),
+    ('\u{1e5c}', '\u{1e5c}', GeneralCategory::Lu),
+    ('\u{1e5d}', '\u{1e5d}', GeneralCategory::Ll),
+    ('\u{1e5e}', '\u{1e5e}', GeneralCategory::Lu),
+    ('\u{1e5f}', '\u{1e5f}', GeneralCategory::Ll),
+    ('\u{1e60}', '\u{1e60}', GeneralCategory::Lu),
+    ('\u{1e61}', '\u{1e61}', GeneralCategory::Ll),
+    ('\u{1e62}', '\u{1e62}', GeneralCategory::Lu),
+    ('\u{1e63}', '\u{1e63}', GeneralCategory::Ll),
+    ('\u{1e64}', '\u{1e64}', GeneralCategory::Lu),
+    ('\u{1e65}', '\u{1e65}', GeneralCategory::Ll),
+    ('\u{1e66}', '\u{1e66}', GeneralCategory::Lu),
+    ('\u{1e67}', '\u{1e67}', GeneralCategory::Ll),
+    ('\u{1e68}', '\u{1e68}', GeneralCategory::Lu),
+    ('\u{1e69}', '\u{1e69}', GeneralCategory::Ll),
+    ('\u{1e6a}', '\u{1e6a}', GeneralCategory::Lu),
+    ('\u{1e6b}', '\u{1e6b}', GeneralCategory::Ll),
+    ('\u{1e6c}', '\u{1e6c}', GeneralCategory::Lu),
+    ('\u{1e6d}', '\u{1e6d}', GeneralCategory::Ll),
+    ('\u{1e6e}', '\u{1e6e}', GeneralCategory::Lu),
+    ('\u{1e6f}', '\u{1e6f}', GeneralCategory::Ll),
+    ('\u{1e70}', '\u{1e70}', GeneralCategory::Lu),
+    ('\u{1e71}', '\u{1e71}', GeneralCategory::Ll),
+    ('\u{1e72}', '\u{1e72}', GeneralCategory::Lu),
+    ('\u{1e73}', '\u{1e73}', GeneralCategory::Ll),
+    ('\u{1e74}', '\u{1e74}', GeneralCategory::Lu),
+    ('\u{1e75}', '\u{1e75}', GeneralCategory::Ll),
+    ('\u{1e76}', '\u{1e76}', GeneralCategory::Lu),
+    ('\u{1e77}', '\u{1e77}', GeneralCategory::Ll),
+    ('\u{1e78}', '\u{1e78}', GeneralCategory::Lu),
+    ('\u{1e79}', '\u{1e79}', GeneralCategory::Ll),
+    ('\u{1e7a}', '\u{1e7a}', GeneralCategory::Lu),
+    ('\u{1e7b}', '\u{1e7b}', GeneralCategory::Ll),
+    ('\u{1e7c}', '\u{1e7c}', GeneralCategory::Lu),
+    ('\u{1e7d}', '\u{1e7d}', GeneralCategory::Ll),
+    ('\u{1e7e}', '\u{1e7e}', GeneralCategory::Lu),
+    ('\u{1e7f}', '\u{1e7f}', GeneralCategory::Ll),
+    ('\u{1e80}', '\u{1e80}', GeneralCategory::Lu),
+    ('\u{1e81}', '\u{1e81}', GeneralCategory::Ll),
+    ('\u{1e82}', '\u{1e82}', GeneralCategory::Lu),
+    ('\u{1e83}', '\u{1e83}', GeneralCategory::Ll),
+    ('\u{1e84}', '\u{1e84}', GeneralCategory::Lu),
+    ('\u{1e85}', '\u{1e85}', GeneralCategory::Ll),
+    ('\u{1e86}', '\u{1e86}', GeneralCategory::Lu),
+    ('\u{1e87}', '\u{1e87}', GeneralCategory::Ll),
+    ('\u{1e88}', '\u{1e88}', GeneralCategory::Lu),
+    ('\u{1e89}', '\u{1e89}', GeneralCategory::Ll),
+    ('\u{1e8a}', '\u{1e8a}', GeneralCategory::Lu),
+    ('\u{1e8b}', '\u{1e8b}', GeneralCategory::Ll),
+    ('\u{1e8c}', '\u{1e8c}', GeneralCategory::Lu),
+    ('\u{1e8d}', '\u{1e8d}', GeneralCategory::Ll),
+    ('\u{1e8e}', '\u{1e8e}', GeneralCategory::Lu),
+    ('\u{1e8f}', '\u{1e8f}', GeneralCategory::Ll),
+    ('\u{1e90}', '\u{1e90}', GeneralCategory::Lu),
+    ('\u{1e91}', '\u{1e91}', GeneralCategory::Ll),
+    ('\u{1e92}', '\u{1e92}', GeneralCategory::Lu),
+    ('\u{1e93}', '\u{1e93}', GeneralCategory::Ll),
+    ('\u{1e94}', '\u{1e94}', GeneralCategory::Lu),
+    ('\u{1e95}', '\u{1e9d}', GeneralCategory::Ll),
+    ('\u{1e9e}', '\u{1e9e}', GeneralCategory::Lu),
+    ('\u{1e9f}', '\u{1e9f}', GeneralCategory::Ll),
+    ('\u{1ea0}', '\u{1ea0}', GeneralCategory::Lu),
+    ('\u{1ea1}', '\u{1ea1}', GeneralCategory::Ll),
+    ('\u{1ea2}', '\u{1ea2}', GeneralCategory::Lu),
+    ('\u{1ea3}', '\u{1ea3}', GeneralCategory::Ll),
+    ('\u{1ea4}', '\u{1ea4}', GeneralCategory::Lu),
+    ('\u{1ea5}', '\u{1ea5}', GeneralCategory::Ll),
+    ('\u{1ea6}', '\u{1ea6}', GeneralCategory::Lu),
+    ('\u{1ea7}', '\u{1ea7}', GeneralCategory::Ll),
+    ('\u{1ea8}', '\u{1ea8}', GeneralCategory::Lu),
+    ('\u{1ea9}', '\u{1ea9}', GeneralCategory::Ll),
+    ('\u{1eaa}', '\u{1eaa}', GeneralCategory::Lu),
+    ('\u{1eab}', '\u{1eab}', GeneralCategory::Ll),
+    ('\u{1eac}', '\u{1eac}', GeneralCategory::Lu),
+    ('\u{1ead}', '\u{1ead}', GeneralCategory::Ll),
+    ('\u{1eae}', '\u{1eae}', GeneralCategory::Lu),
+    ('\u{1eaf}', '\u{1eaf}', GeneralCategory::Ll),
+    ('\u{1eb0}', '\u{1eb0}', GeneralCategory::Lu),
+    ('\u{1eb1}', '\u{1eb1}', GeneralCategory::Ll),
+    ('\u{1eb2}', '\u{1eb2}', GeneralCategory::Lu),
+    ('\u{1eb3}', '\u{1eb3}', GeneralCategory::Ll),
+    ('\u{1eb4}', '\u{1eb4}', GeneralCategory::Lu),
+    ('\u{1eb5}', '\u{1eb5}', GeneralCategory::Ll),
+    ('\u{1eb6}', '\u{1eb6}', GeneralCategory::Lu),
+    ('\u{1eb7}', '\u{1eb7}', GeneralCategory::Ll),
+    ('\u{1eb8}', '\u{1eb8}', GeneralCategory::Lu),
+    ('\u{1eb9}', '\u{1eb9}', GeneralCategory::Ll),
+    ('\u{1eba}', '\u{1eba}', GeneralCategory::Lu),
+    ('\u{1ebb}', '\u{1ebb}', GeneralCategory::Ll),
+    ('\u{1ebc}', '\u{1ebc}', GeneralCategory::Lu),
+    ('\u{1ebd}', '\u{1ebd}', GeneralCategory::Ll),
+    ('\u{1ebe}', '\u{1ebe}', GeneralCategory::Lu),
+    ('\u{1ebf}', '\u{1ebf}', GeneralCategory::Ll),
+    ('\u{1ec0}', '\u{1ec0}', GeneralCategory::Lu),
+    ('\u{1ec1}', '\u{1ec1}', GeneralCategory::Ll),
+    ('\u{1ec2}', '\u{1ec2}', GeneralCategory::Lu),
+    ('\u{1ec3}', '\u{1ec3}', GeneralCategory::Ll),
+    ('\u{1ec4}', '\u{1ec4}', GeneralCategory::Lu),
+    ('\u{1ec5}', '\u{1ec5}', GeneralCategory::Ll),
+    ('\u{1ec6}', '\u{1ec6}', GeneralCategory::Lu),
+    ('\u{1ec7}', '\u{1ec7}', GeneralCategory::Ll),
+    ('\u{1ec8}', '\u{1ec8}', GeneralCategory::Lu),
+    ('\u{1ec9}', '\u{1ec9}', GeneralCategory::Ll),
+    ('\u{1eca}', '\u{1eca}', GeneralCategory::Lu),
+    ('\u{1ecb}', '\u{1ecb}', GeneralCategory::Ll),
+    ('\u{1ecc}', '\u{1ecc}', GeneralCategory::Lu),
+    ('\u{1ecd}', '\u{1ecd}', GeneralCategory::Ll),
+    ('\u{1ece}', '\u{1ece}', GeneralCategory::Lu),
+    ('\u{1ecf}', '\u{1ecf}', GeneralCategory::Ll),
+    ('\u{1ed0}', '\u{1ed0}', GeneralCategory::Lu),
+    ('\u{1ed1}', '\u{1ed1}', GeneralCategory::Ll),
+    ('\u{1ed2}', '\u{1ed2}', GeneralCategory::Lu),
+    ('\u{1ed3}', '\u{1ed3}', GeneralCategory::Ll),
+    ('\u{1ed4}', '\u{1ed4}', GeneralCategory::Lu),
+    ('\u{1ed5}', '\u{1ed5}', GeneralCategory::Ll),
+    ('\u{1ed6}', '\u{1ed6}', GeneralCategory::Lu),
+    ('\u{1ed7}', '\u{1ed7}', GeneralCategory::Ll),
+    ('\u{1ed8}', '\u{1ed8}', GeneralCategory::Lu),
+    ('\u{1ed9}', '\u{1ed9}', GeneralCategory::Ll),
+    ('\u{1eda}', '\u{1eda}', GeneralCategory::Lu),
+    ('\u{1edb}', '\u{1edb}', GeneralCategory::Ll),
+    ('\u{1edc}', '\u{1edc}', GeneralCategory::Lu),
+    ('\u{1edd}', '\u{1edd}', GeneralCategory::Ll),
+    ('\u{1ede}', '\u{1ede}', GeneralCategory::Lu),
+    ('\u{1edf}', '\u{1edf}', GeneralCategory::Ll),
+    ('\u{1ee0}', '\u{1ee0}', GeneralCategory::Lu),
+    ('\u{1ee1}', '\u{1ee1}', GeneralCategory::Ll),
+    ('\u{1ee2}', '\u{1ee2}', GeneralCategory::Lu),
+    ('\u{1ee3}', '\u{1ee3}', GeneralCategory::Ll),
+    ('\u{1ee4}', '\u{1ee4}', GeneralCategory::Lu),
+    ('\u{1ee5}', '\u{1ee5}', GeneralCategory::Ll),
+    ('\u{1ee6}', '\u{1ee6}', GeneralCategory::Lu),
+    ('\u{1ee7}', '\u{1ee7}', GeneralCategory::Ll),
+    ('\u{1ee8}', '\u{1ee8}', GeneralCategory::Lu),
+    ('\u{1ee9}', '\u{1ee9}', GeneralCategory::Ll),
+    ('\u{1eea}', '\u{1eea}', GeneralCategory::Lu),
+    ('\u{1eeb}', '\u{1eeb}', GeneralCategory::Ll),
+    ('\u{1eec}', '\u{1eec}', GeneralCategory::Lu),
+    ('\u{1eed}', '\u{1eed}', GeneralCategory::Ll),
+    ('\u{1eee}', '\u{1eee}', GeneralCategory::Lu),
+    ('\u{1eef}', '\u{1eef}', GeneralCategory::Ll),
+    ('\u{1ef0}', '\u{1ef0}', GeneralCategory::Lu),
+    ('\u{1ef1}', '\u{1ef1}', GeneralCategory::Ll),
+    ('\u{1ef2}', '\u{1ef2}', GeneralCategory::Lu),
+    ('\u{1ef3}', '\u{1ef3}', GeneralCategory::Ll),
+    ('\u{1ef4}', '\u{1ef4}', GeneralCategory::Lu),
+    ('\u{1ef5}', '\u{1ef5}', GeneralCategory::Ll),
+    ('\u{1ef6}', '\u{1ef6}', GeneralCategory::Lu),
+    ('\u{1ef7}', '\u{1ef7}', GeneralCategory::Ll),
+    ('\u{1ef8}', '\u{1ef8}', GeneralCategory::Lu),
+    ('\u{1ef9}', '\u{1ef9}', GeneralCategory::Ll),
+    ('\u{1efa}', '\u{1efa}', GeneralCategory::Lu),
+    ('\u{1efb}', '\u{1efb}', GeneralCategory::Ll),
+    ('\u{1efc}', '\u{1efc}', GeneralCategory::Lu),
+    ('\u{1efd}', '\u{1efd}', GeneralCategory::Ll),
+    ('\u{1efe}', '\u{1efe}', GeneralCategory::Lu),
+    ('\u{1eff}', '\u{1f07}', GeneralCategory::Ll),
+    ('\u{1f08}', '\u{1f0f}', GeneralCategory::Lu),
+    ('\u{1f10}', '\u{1f15}', GeneralCategory::Ll),
+    ('\u{1f18}', '\u{1f1d}', GeneralCategory::Lu),
+    ('\u{1f20}', '\u{1f27}', GeneralCategory::Ll),
+    ('\u{1f28}', '\u{1f2f}', GeneralCategory::Lu),
+    ('\u{1f30}', '\u{1f37}', GeneralCategory::Ll),
+    ('\u{1f38}', '\u{1f3f}', GeneralCategory::Lu),
+    ('\u{1f40}', '\u{1f45}', GeneralCategory::Ll),
+    ('\u{1f48}', '\u{1f4d}', GeneralCategory::Lu),
+    ('\u{1f50}', '\u{1f57}', GeneralCategory::Ll),
+    ('\u{1f59}', '\u{1f59}', GeneralCategory::Lu),
+    ('\u{1f5b}', '\u{1f5b}', GeneralCategory::Lu),
+    ('\u{1f5d}', '\u{1f5d}', GeneralCategory::Lu),
+    ('\u{1f5f}', '\u{1f5f}', GeneralCategory::Lu),
+    ('\u{1f60}', '\u{1f67}', GeneralCategory::Ll),
+    ('\u{1f68}', '\u{1f6f}', GeneralCategory::Lu),
+    ('\u{1f70}', '\u{1f7d}', GeneralCategory::Ll),
+    ('\u{1f80}', '\u{1f87}', GeneralCategory::Ll),
+    ('\u{1f88}', '\u{1f8f}', GeneralCategory::Lt),
+    ('\u{1f90}', '\u{1f97}', GeneralCategory::Ll),
+    ('\u{1f98}', '\u{1f9f}', GeneralCategory::Lt),
+    ('\u{1fa0}', '\u{1fa7}', GeneralCategory::Ll),
+    ('\u{1fa8}', '\u{1faf}', GeneralCategory::Lt),
+    ('\u{1fb0}', '\u{1fb4}', GeneralCategory::Ll),
+    ('\u{1fb6}', '\u{1fb7}', GeneralCategory::Ll),
+    ('\u{1fb8}', '\u{1fbb}', GeneralCategory::Lu),
+    ('\u{1fbc}', '\u{1fbc}', GeneralCategory::Lt),
+    ('\u{1fbd}', '\u{1fbd}', GeneralCategory::Sk),
+    ('\u{1fbe}', '\u{1fbe}', GeneralCategory::Ll),
+    ('\u{1fbf}', '\u{1fc1}', GeneralCategory::Sk),
+    ('\u{1fc2}', '\u{1fc4}', GeneralCategory::Ll),
+    ('\u{1fc6}', '\u{1fc7}', GeneralCategory::Ll),
+    ('\u{1fc8}', '\u{1fcb}', GeneralCategory::Lu),
+    ('\u{1fcc}', '\u{1fcc}', GeneralCategory::Lt),
+    ('\u{1fcd}', '\u{1fcf}', GeneralCategory::Sk),
+    ('\u{1fd0}', '\u{1fd3}', GeneralCategory::Ll),
+    ('\u{1fd6}', '\u{1fd7}', GeneralCategory::Ll),
+    ('\u{1fd8}', '\u{1fdb}', GeneralCategory::Lu),
+    ('\u{1fdd}', '\u{1fdf}', GeneralCategory::Sk),
+    ('\u{1fe0}', '\u{1fe7}', GeneralCategory::Ll),
+    ('\u{1fe8}', '\u{1fec}', GeneralCategory::Lu),
+    ('\u{1fed}', '\u{1fef}', GeneralCategory::Sk),
+    ('\u{1ff2}', '\u{1ff4}', GeneralCategory::Ll),
+    ('\u{1ff6}', '\u{1ff7}', GeneralCategory::Ll),
+    ('\u{1ff8}', '\u{1ffb}', GeneralCategory::Lu),
+    ('\u{1ffc}', '\u{1ffc}', GeneralCategory::Lt),
+    ('\u{1ffd}', '\u{1ffe}', GeneralCategory::Sk),
+    ('\u{2000}', '\u{200a}', GeneralCategory::Zs),
+    ('\u{200b}', '\u{200f}', GeneralCategory::Cf),
+    ('\u{2010}', '\u{2015}', GeneralCategory::Pd),
+    ('\u{2016}', '\u{2017}', GeneralCategory::Po),
+    ('\u{2018}', '\u{2018}', GeneralCategory::Pi),
+    ('\u{2019}', '\u{2019}', GeneralCategory::Pf),
+    ('\u{201a}', '\u{201a}', GeneralCategory::Ps),
+    ('\u{201b}', '\u{201c}', GeneralCategory::Pi),
+    ('\u{201d}', '\u{201d}', GeneralCategory::Pf),
+    ('\u{201e}', '\u{201e}', GeneralCategory::Ps),
+    ('\u{201f}', '\u{201f}', GeneralCategory::Pi),
+    ('\u{2020}', '\u{2027}', GeneralCategory::Po),
+    ('\u{2028}', '\u{2028}', GeneralCategory::Zl),
+    ('\u{2029}', '\u{2029}', GeneralCategory::Zp),
+    ('\u{202a}', '\u{202e}', GeneralCategory::Cf),
+    ('\u{202f}', '\u{202f}', GeneralCategory::Zs),
+    ('\u{2030}', '\u{2038}', GeneralCategory::Po),
+    ('\u{2039}', '\u{2039}', GeneralCategory::Pi),
+    ('\u{203a}', '\u{203a}', GeneralCategory::Pf),
+    ('\u{203b}', '\u{203e}', GeneralCategory::Po),
+    ('\u{203f}', '\u{2040}', GeneralCategory::Pc),
+    ('\u{2041}', '\u{2043}', GeneralCategory::Po),
+    ('\u{2044}', '\u{2044}', GeneralCategory::Sm),
+    ('\u{2045}', '\u{2045}', GeneralCategory::Ps),
+    ('\u{2046}', '\u{2046}', GeneralCategory::Pe),
+    ('\u{2047}', '\u{2051}', GeneralCategory::Po),
+    ('\u{2052}', '\u{2052}', GeneralCategory::Sm),
+    ('\u{2053}', '\u{2053}', GeneralCategory::Po),
+    ('\u{2054}', '\u{2054}', GeneralCategory::Pc),
+    ('\u{2055}', '\u{205e}', GeneralCategory::Po),
+    ('\u{205f}', '\u{205f}', GeneralCategory::Zs),
+    ('\u{2060}', '\u{2064}', GeneralCategory::Cf),
+    ('\u{2066}', '\u{206f}', GeneralCategory::Cf),
+    ('\u{2070}', '\u{2070}', GeneralCategory::No),
+    ('\u{2071}', '\u{2071}', GeneralCategory::Lm),
+    ('\u{2074}', '\u{2079}', GeneralCategory::No),
+    ('\u{207a}', '\u{207c}', GeneralCategory::Sm),
+    ('\u{207d}', '\u{207d}', GeneralCategory::Ps),
+    ('\u{207e}', '\u{207e}', GeneralCategory::Pe),
+    ('\u{207f}', '\u{207f}', GeneralCategory::Lm),
+    ('\u{2080}', '\u{2089}', GeneralCategory::No),
+    ('\u{208a}', '\u{208c}', GeneralCategory::Sm),
+    ('\u{208d}', '\u{208d}', GeneralCategory::Ps),
+    ('\u{208e}', '\u{208e}', GeneralCategory::Pe),
+    ('\u{2090}', '\u{209c}', GeneralCategory::Lm),
+    ('\u{20a0}', '\u{20c1}', GeneralCategory::Sc),
+    ('\u{20d0}', '\u{20dc}', GeneralCategory::Mn),
+    ('\u{20dd}', '\u{20e0}', GeneralCategory::Me),
+    ('\u{20e1}', '\u{20e1}', GeneralCategory::Mn),
+    ('\u{20e2}', '\u{20e4}', GeneralCategory::Me),
+    ('\u{20e5}', '\u{20f0}', GeneralCategory::Mn),
+    ('\u{2100}', '\u{2101}', GeneralCategory::So),
+    ('\u{2102}', '\u{2102}', GeneralCategory::Lu),
+    ('\u{2103}', '\u{2106}', GeneralCategory::So),
+    ('\u{2107}', '\u{2107}', GeneralCategory::Lu),
+    ('\u{2108}', '\u{2109}', GeneralCategory::So),
+    ('\u{210a}', '\u{210a}', GeneralCategory::Ll),
+    ('\u{210b}', '\u{210d}', GeneralCategory::Lu),
+    ('\u{210e}', '\u{210f}', GeneralCategory::Ll),
+    ('\u{2110}', '\u{2112}', GeneralCategory::Lu),
+    ('\u{2113}', '\u{2113}', GeneralCategory::Ll),
+    ('\u{2114}', '\u{2114}', GeneralCategory::So),
+    ('\u{2115}', '\u{2115}', GeneralCategory::Lu),
+    ('\u{2116}', '\u{2117}', GeneralCategory::So),
+    ('\u{2118}', '\u{2118}', GeneralCategory::Sm),
+    ('\u{2119}', '\u{211d}', GeneralCategory::Lu),
+    ('\u{211e}', '\u{2123}', GeneralCategory::So),
+    ('\u{2124}', '\u{2124}', GeneralCategory::Lu),
+    ('\u{2125}', '\u{2125}', GeneralCategory::So),
+    ('\u{2126}', '\u{2126}', GeneralCategory::Lu),
+    ('\u{2127}', '\u{2127}', GeneralCategory::So),
+    ('\u{2128}', '\u{2128}', GeneralCategory::Lu),
+    ('\u{2129}', '\u{2129}', GeneralCategory::So),
+    ('\u{212a}', '\u{212d}', GeneralCategory::Lu),
+    ('\u{212e}', '\u{212e}', GeneralCategory::So),
+    ('\u{212f}', '\u{212f}', GeneralCategory::Ll),
+    ('\u{2130}', '\u{2133}', GeneralCategory::Lu),
+    ('\u{2134}', '\u{2134}', GeneralCategory::Ll),
+    ('\u{2135}', '\u{2138}', GeneralCategory::Lo),
+    ('\u{2139}', '\u{2139}', GeneralCategory::Ll),
+    ('\u{213a}', '\u{213b}', GeneralCategory::So),
+    ('\u{213c}', '\u{213d}', GeneralCategory::Ll),
+    ('\u{213e}', '\u{213f}', GeneralCategory::Lu),
+    ('\u{2140}', '\u{2144}', GeneralCategory::Sm),
+    ('\u{2145}', '\u{2145}', GeneralCategory::Lu),
+    ('\u{2146}', '\u{2149}', GeneralCategory::Ll),
+    ('\u{214a}', '\u{214a}', GeneralCategory::So),
+    ('\u{214b}', '\u{214b}', GeneralCategory::Sm),
+    ('\u{214c}', '\u{214d}', GeneralCategory::So),
+    ('\u{214e}', '\u{214e}', GeneralCategory::Ll),
+    ('\u{214f}', '\u{214f}', GeneralCategory::So),
+    ('\u{2150}', '\u{215f}', GeneralCategory::No),
+    ('\u{2160}', '\u{2182}', GeneralCategory::Nl),
+    ('\u{2183}', '\u{2183}', GeneralCategory::Lu),
+    ('\u{2184}', '\u{2184}', GeneralCategory::Ll),
+    ('\u{2185}', '\u{2188}', GeneralCategory::Nl),
+    ('\u{2189}', '\u{2189}', GeneralCategory::No),
+    ('\u{218a}', '\u{218b}', GeneralCategory::So),
+    ('\u{2190}', '\u{2194}', GeneralCategory::Sm),
+    ('\u{2195}', '\u{2199}', GeneralCategory::So),
+    ('\u{219a}', '\u{219b}', GeneralCategory::Sm),
+    ('\u{219c}', '\u{219f}', GeneralCategory::So),
+    ('\u{21a0}', '\u{21a0}', GeneralCategory::Sm),
+    ('\u{21a1}', '\u{21a2}', GeneralCategory::So),
+    ('\u{21a3}', '\u{21a3}', GeneralCategory::Sm),
+    ('\u{21a4}', '\u{21a5}', GeneralCategory::So),
+    ('\u{21a6}', '\u{21a6}', GeneralCategory::Sm),
+    ('\u{21a7}', '\u{21ad}', GeneralCategory::So),
+    ('\u{21ae}', '\u{21ae}', GeneralCategory::Sm),
+    ('\u{21af}', '\u{21cd}', GeneralCategory::So),
+    ('\u{21ce}', '\u{21cf}', GeneralCategory::Sm),
+    ('\u{21d0}', '\u{21d1}', GeneralCategory::So),
+    ('\u{21d2}', '\u{21d2}', GeneralCategory::Sm),
+    ('\u{21d3}', '\u{21d3}', GeneralCategory::So),
+    ('\u{21d4}', '\u{21d4}', GeneralCategory::Sm),
+    ('\u{21d5}', '\u{21f3}', GeneralCategory::So),
+    ('\u{21f4}', '\u{22ff}', GeneralCategory::Sm),
+    ('\u{2300}', '\u{2307}', GeneralCategory::So),
+    ('\u{2308}', '\u{2308}', GeneralCategory::Ps),
+    ('\u{2309}', '\u{2309}', GeneralCategory::Pe),
+    ('\u{230a}', '\u{230a}', GeneralCategory::Ps),
+    ('\u{230b}', '\u{230b}', GeneralCategory::Pe),
+    ('\u{230c}', '\u{231f}', GeneralCategory::So),
+    ('\u{2320}', '\u{2321}', GeneralCategory::Sm),
+    ('\u{2322}', '\u{2328}', GeneralCategory::So),
+    ('\u{2329}', '\u{2329}', GeneralCategory::Ps),
+    ('\u{232a}', '\u{232a}', GeneralCategory::Pe),
+    ('\u{232b}', '\u{237b}', GeneralCategory::So),
+    ('\u{237c}', '\u{237c}', GeneralCategory::Sm),
+    ('\u{237d}', '\u{239a}', GeneralCategory::So),
+    ('\u{239b}', '\u{23b3}', GeneralCategory::Sm),
+    ('\u{23b4}', '\u{23db}', GeneralCategory::So),
+    ('\u{23dc}', '\u{23e1}', GeneralCategory::Sm),
+    ('\u{23e2}', '\u{2429}', GeneralCategory::So),
+    ('\u{2440}', '\u{244a}', GeneralCategory::So),
+    ('\u{2460}', '\u{249b}', GeneralCategory::No),
+    ('\u{249c}', '\u{24e9}', GeneralCategory::So),
+    ('\u{24ea}', '\u{24ff}', GeneralCategory::No),
+    ('\u{2500}', '\u{25b6}', GeneralCategory::So),
+    ('\u{25b7}', '\u{25b7}', GeneralCategory::Sm),
+    ('\u{25b8}', '\u{25c0}', GeneralCategory::So),
+    ('\u{25c1}', '\u{25c1}', GeneralCategory::Sm),
+    ('\u{25c2}', '\u{25f7}', GeneralCategory::So),
+    ('\u{25f8}', '\u{25ff}', GeneralCategory::Sm),
+    ('\u{2600}', '\u{266e}', GeneralCategory::So),
+    ('\u{266f}', '\u{266f}', GeneralCategory::Sm),
+    ('\u{2670}', '\u{2767}', GeneralCategory::So),
+    ('\u{2768}', '\u{2768}', GeneralCategory::Ps),
+    ('\u{2769}', '\u{2769}', GeneralCategory::Pe),
+    ('\u{276a}', '\u{276a}', GeneralCategory::Ps),
+    ('\u{276b}', '\u{276b}', GeneralCategory::Pe),
+    ('\u{276c}', '\u{276c}', GeneralCategory::Ps),
+    ('\u{276d}', '\u{276d}', GeneralCategory::Pe),
+    ('\u{276e}', '\u{276e}', GeneralCategory::Ps),
+    ('\u{276f}', '\u{276f}', GeneralCategory::Pe),
+    ('\u{2770}', '\u{2770}', GeneralCategory::Ps),
+    ('\u{2771}', '\u{2771}', GeneralCategory::Pe),
+    ('\u{2772}', '\u{2772}', GeneralCategory::Ps),
+    ('\u{2773}', '\u{2773}', GeneralCategory::Pe),
+    ('\u{2774}', '\u{2774}', GeneralCategory::Ps),
+    ('\u{2775}', '\u{2775}', GeneralCategory::Pe),
+    ('\u{2776}', '\u{2793}', GeneralCategory::No),
+    ('\u{2794}', '\u{27bf}', GeneralCategory::So),
+    ('\u{27c0}', '\u{27c4}', GeneralCategory::Sm),
+    ('\u{27c5}', '\u{27c5}', GeneralCategory::Ps),
+    ('\u{27c6}', '\u{27c6}', GeneralCategory::Pe),
+    ('\u{27c7}', '\u{27e5}', GeneralCategory::Sm),
+    ('\u{27e6}', '\u{27e6}', GeneralCategory::Ps),
+    ('\u{27e7}', '\u{27e7}', GeneralCategory::Pe),
+    ('\u{27e8}', '\u{27e8}', GeneralCategory::Ps),
+    ('\u{27e9}', '\u{27e9}', GeneralCategory::Pe),
+    ('\u{27ea}', '\u{27ea}', GeneralCategory::Ps),
+    ('\u{27eb}', '\u{27eb}', GeneralCategory::Pe),
+    ('\u{27ec}', '\u{27ec}', GeneralCategory::Ps),
+    ('\u{27ed}', '\u{27ed}', GeneralCategory::Pe),
+    ('\u{27ee}', '\u{27ee}', GeneralCategory::Ps),
+    ('\u{27ef}', '\u{27ef}', GeneralCategory::Pe),
+    ('\u{27f0}', '\u{27ff}', GeneralCategory::Sm),
+    ('\u{2800}', '\u{28ff}', GeneralCategory::So),
+    ('\u{2900}', '\u{2982}', GeneralCategory::Sm),
+    ('\u{2983}', '\u{2983}', GeneralCategory::Ps),
+    ('\u{2984}', '\u{2984}', GeneralCategory::Pe),
+    ('\u{2985}', '\u{2985}', GeneralCategory::Ps),
+    ('\u{2986}', '\u{2986}', GeneralCategory::Pe),
+    ('\u{2987}', '\u{2987}', GeneralCategory::Ps),
+    ('\u{2988}', '\u{2988}', GeneralCategory::Pe),
+    ('\u{2989}', '\u{2989}', GeneralCategory::Ps),
+    ('\u{298a}', '\u{298a}', GeneralCategory::Pe),
+    ('\u{298b}', '\u{298b}', GeneralCategory::Ps),
+    ('\u{298c}', '\u{298c}', GeneralCategory::Pe),
+    ('\u{298d}', '\u{298d}', GeneralCategory::Ps),
+    ('\u{298e}', '\u{298e}', GeneralCategory::Pe),
+    ('\u{298f}', '\u{298f}', GeneralCategory::Ps),
+    ('\u{2990}', '\u{2990}', GeneralCategory::Pe),
+    ('\u{2991}', '\u{2991}', GeneralCategory::Ps),
+    ('\u{2992}', '\u{2992}', GeneralCategory::Pe),
+    ('\u{2993}', '\u{2993}', GeneralCategory::Ps),
+    ('\u{2994}', '\u{2994}', GeneralCategory::Pe),
+    ('\u{2995}', '\u{2995}', GeneralCategory::Ps),
+    ('\u{2996}', '\u{2996}', GeneralCategory::Pe),
+    ('\u{2997}', '\u{2997}', GeneralCategory::Ps),
+    ('\u{2998}', '\u{2998}', GeneralCategory::Pe),
+    ('\u{2999}', '\u{29d7}', GeneralCategory::Sm),
+    ('\u{29d8}', '\u{29d8}', GeneralCategory::Ps),
+    ('\u{29d9}', '\u{29d9}', GeneralCategory::Pe),
+    ('\u{29da}', '\u{29da}', GeneralCategory::Ps),
+    ('\u{29db}', '\u{29db}', GeneralCategory::Pe),
+    ('\u{29dc}', '\u{29fb}', GeneralCategory::Sm),
+    ('\u{29fc}', '\u{29fc}', GeneralCategory::Ps),
+    ('\u{29fd}', '\u{29fd}', GeneralCategory::Pe),
+    ('\u{29fe}', '\u{2aff}', GeneralCategory::Sm),
+    ('\u{2b00}', '\u{2b2f}', GeneralCategory::So),
+    ('\u{2b30}', '\u{2b44}', GeneralCategory::Sm),
+    ('\u{2b45}', '\u{2b46}', GeneralCategory::So),
+    ('\u{2b47}', '\u{2b4c}', GeneralCategory::Sm),
+    ('\u{2b4d}', '\u{2b73}', GeneralCategory::So),
+    ('\u{2b76}', '\u{2bff}', GeneralCategory::So),
+    ('\u{2c00}', '\u{2c2f}', GeneralCategory::Lu),
+    ('\u{2c30}', '\u{2c5f}', GeneralCategory::Ll),
+    ('\u{2c60}', '\u{2c60}', GeneralCategory::Lu),
+    ('\u{2c61}', '\u{2c61}', GeneralCategory::Ll),
+    ('\u{2c62}', '\u{2c64}', GeneralCategory::Lu),
+    ('\u{2c65}', '\u{2c66}', GeneralCategory::Ll),
+    ('\u{2c67}', '\u{2c67}', GeneralCategory::Lu),
+    ('\u{2c68}', '\u{2c68}', GeneralCategory::Ll),
+    ('\u{2c69}', '\u{2c69}', GeneralCategory::Lu),
+    ('\u{2c6a}', '\u{2c6a}', GeneralCategory::Ll),
+    ('\u{2c6b}', '\u{2c6b}', GeneralCategory::Lu),
+    ('\u{2c6c}', '\u{2c6c}', GeneralCategory::Ll),
+    ('\u{2c6d}', '\u{2c70}', GeneralCategory::Lu),
+    ('\u{2c71}', '\u{2c71}', GeneralCategory::Ll),
+    ('\u{2c72}', '\u{2c72}', GeneralCategory::Lu),
+    ('\u{2c73}', '\u{2c74}', GeneralCategory::Ll),
+    ('\u{2c75}', '\u{2c75}', GeneralCategory::Lu),
+    ('\u{2c76}', '\u{2c7b}', GeneralCategory::Ll),
+    ('\u{2c7c}', '\u{2c7d}', GeneralCategory::Lm),
+    ('\u{2c7e}', '\u{2c80}', GeneralCategory::Lu),
+    ('\u{2c81}', '\u{2c81}', GeneralCategory::Ll),
+    ('\u{2c82}', '\u{2c82}', GeneralCategory::Lu),
+    ('\u{2c83}', '\u{2c83}', GeneralCategory::Ll),
+    ('\u{2c84}', '\u{2c84}', GeneralCategory::Lu),
+    ('\u{2c85}', '\u{2c85}', GeneralCategory::Ll),
+    ('\u{2c86}', '\u{2c86}', GeneralCategory::Lu),
+    ('\u{2c87}', '\u{2c87}', GeneralCategory::Ll),
+    ('\u{2c88}', '\u{2c88}', GeneralCategory::Lu),
+    ('\u{2c89}', '\u{2c89}', GeneralCategory::Ll),
+    ('\u{2c8a}', '\u{2c8a}', GeneralCategory::Lu),
+    ('\u{2c8b}', '\u{2c8b}', GeneralCategory::Ll),
+    ('\u{2c8c}', '\u{2c8c}', GeneralCategory::Lu),
+    ('\u{2c8d}', '\u{2c8d}', GeneralCategory::Ll),
+    ('\u{2c8e}', '\u{2c8e}', GeneralCategory::Lu),
+    ('\u{2c8f}', '\u{2c8f}', GeneralCategory::Ll),
+    ('\u{2c90}', '\u{2c90}', GeneralCategory::Lu),
+    ('\u{2c91}', '\u{2c91}', GeneralCategory::Ll),
+    ('\u{2c92}', '\u{2c92}', GeneralCategory::Lu),
+    ('\u{2c93}', '\u{2c93}', GeneralCategory::Ll),
+    ('\u{2c94}', '\u{2c94}', GeneralCategory::Lu),
+    ('\u{2c95}', '\u{2c95}', GeneralCategory::Ll),
+    ('\u{2c96}', '\u{2c96}', GeneralCategory::Lu),
+    ('\u{2c97}', '\u{2c97}', GeneralCategory::Ll),
+    ('\u{2c98}', '\u{2c98}', GeneralCategory::Lu),
+    ('\u{2c99}', '\u{2c99}', GeneralCategory::Ll),
+    ('\u{2c9a}', '\u{2c9a}', GeneralCategory::Lu),
+    ('\u{2c9b}', '\u{2c9b}', GeneralCategory::Ll),
+    ('\u{2c9c}', '\u{2c9c}', GeneralCategory::Lu),
+    ('\u{2c9d}', '\u{2c9d}', GeneralCategory::Ll),
+    ('\u{2c9e}', '\u{2c9e}', GeneralCategory::Lu),
+    ('\u{2c9f}', '\u{2c9f}', GeneralCategory::Ll),
+    ('\u{2ca0}', '\u{2ca0}', GeneralCategory::Lu),
+    ('\u{2ca1}', '\u{2ca1}', GeneralCategory::Ll),
+    ('\u{2ca2}', '\u{2ca2}', GeneralCategory::Lu),
+    ('\u{2ca3}', '\u{2ca3}', GeneralCategory::Ll),
+    ('\u{2ca4}', '\u{2ca4}', GeneralCategory::Lu),
+    ('\u{2ca5}', '\u{2ca5}', GeneralCategory::Ll),
+    ('\u{2ca6}', '\u{2ca6}', GeneralCategory::Lu),
+    ('\u{2ca7}', '\u{2ca7}', GeneralCategory::Ll),
+    ('\u{2ca8}', '\u{2ca8}', GeneralCategory::Lu),
+    ('\u{2ca9}', '\u{2ca9}', GeneralCategory::Ll),
+    ('\u{2caa}', '\u{2caa}', GeneralCategory::Lu),
+    ('\u{2cab}', '\u{2cab}', GeneralCategory::Ll),
+    ('\u{2cac}', '\u{2cac}', GeneralCategory::Lu),
+    ('\u{2cad}', '\u{2cad}', GeneralCategory::Ll),
+    ('\u{2cae}', '\u{2cae}', GeneralCategory::Lu),
+    ('\u{2caf}', '\u{2caf}', GeneralCategory::Ll),
+    ('\u{2cb0}', '\u{2cb0}', GeneralCategory::Lu),
+    ('\u{2cb1}', '\u{2cb1}', GeneralCategory::Ll),
+    ('\u{2cb2}', '\u{2cb2}', GeneralCategory::Lu),
+    ('\u{2cb3}', '\u{2cb3}', GeneralCategory::Ll),
+    ('\u{2cb4}', '\u{2cb4}', GeneralCategory::Lu),
+    ('\u{2cb5}', '\u{2cb5}', GeneralCategory::Ll),
+    ('\u{2cb6}', '\u{2cb6}', GeneralCategory::Lu),
+    ('\u{2cb7}', '\u{2cb7}', GeneralCategory::Ll),
+    ('\u{2cb8}', '\u{2cb8}', GeneralCategory::Lu),
+    ('\u{2cb9}', '\u{2cb9}', GeneralCategory::Ll),
+    ('\u{2cba}', '\u{2cba}', GeneralCategory::Lu),
+    ('\u{2cbb}', '\u{2cbb}', GeneralCategory::Ll),
+    ('\u{2cbc}', '\u{2cbc}', GeneralCategory::Lu),
+    ('\u{2cbd}', '\u{2cbd}', GeneralCategory::Ll),
+    ('\u{2cbe}', '\u{2cbe}', GeneralCategory::Lu),
+    ('\u{2cbf}', '\u{2cbf}', GeneralCategory::Ll),
+    ('\u{2cc0}', '\u{2cc0}', GeneralCategory::Lu),
+    ('\u{2cc1}', '\u{2cc1}', GeneralCategory::Ll),
+    ('\u{2cc2}', '\u{2cc2}', GeneralCategory::Lu),
+    ('\u{2cc3}', '\u{2cc3}', GeneralCategory::Ll),
+    ('\u{2cc4}', '\u{2cc4}', GeneralCategory::Lu),
+    ('\u{2cc5}', '\u{2cc5}', GeneralCategory::Ll),
+    ('\u{2cc6}', '\u{2cc6}', GeneralCategory::Lu),
+    ('\u{2cc7}', '\u{2cc7}', GeneralCategory::Ll),
+    ('\u{2cc8}', '\u{2cc8}', GeneralCategory::Lu),
+    ('\u{2cc9}', '\u{2cc9}', GeneralCategory::Ll),
+    ('\u{2cca}', '\u{2cca}', GeneralCategory::Lu),
+    ('\u{2ccb}', '\u{2ccb}', GeneralCategory::Ll),
+    ('\u{2ccc}', '\u{2ccc}', GeneralCategory::Lu),
+    ('\u{2ccd}', '\u{2ccd}', GeneralCategory::Ll),
+    ('\u{2cce}', '\u{2cce}', GeneralCategory::Lu),
+    ('\u{2ccf}', '\u{2ccf}', GeneralCategory::Ll),
+    ('\u{2cd0}', '\u{2cd0}', GeneralCategory::Lu),
+    ('\u{2cd1}', '\u{2cd1}', GeneralCategory::Ll),
+    ('\u{2cd2}', '\u{2cd2}', GeneralCategory::Lu),
+    ('\u{2cd3}', '\u{2cd3}', GeneralCategory::Ll),
+    ('\u{2cd4}', '\u{2cd4}', GeneralCategory::Lu),
+    ('\u{2cd5}', '\u{2cd5}', GeneralCategory::Ll),
+    ('\u{2cd6}', '\u{2cd6}', GeneralCategory::Lu),
+    ('\u{2cd7}', '\u{2cd7}', GeneralCategory::Ll),
+    ('\u{2cd8}', '\u{2cd8}', GeneralCategory::Lu),
+    ('\u{2cd9}', '\u{2cd9}', GeneralCategory::Ll),
+    ('\u{2cda}', '\u{2cda}', GeneralCategory::Lu),
+    ('\u{2cdb}', '\u{2cdb}', GeneralCategory::Ll),
+    ('\u{2cdc}', '\u{2cdc}', GeneralCategory::Lu),
+    ('\u{2cdd}', '\u{2cdd}', GeneralCategory::Ll),
+    ('\u{2cde}', '\u{2cde}', GeneralCategory::Lu),
+    ('\u{2cdf}', '\u{2cdf}', GeneralCategory::Ll),
+    ('\u{2ce0}', '\u{2ce0}', GeneralCategory::Lu),
+    ('\u{2ce1}', '\u{2ce1}', GeneralCategory::Ll),
+    ('\u{2ce2}', '\u{2ce2}', GeneralCategory::Lu),
+    ('\u{2ce3}', '\u{2ce4}', GeneralCategory::Ll),
+    ('\u{2ce5}', '\u{2cea}', GeneralCategory::So),
+    ('\u{2ceb}', '\u{2ceb}', GeneralCategory::Lu),
+    ('\u{2cec}', '\u{2cec}', GeneralCategory::Ll),
+    ('\u{2ced}', '\u{2ced}', GeneralCategory::Lu),
+    ('\u{2cee}', '\u{2cee}', GeneralCategory::Ll),
+    ('\u{2cef}', '\u{2cf1}', GeneralCategory::Mn),
+    ('\u{2cf2}', '\u{2cf2}', GeneralCategory::Lu),
+    ('\u{2cf3}', '\u{2cf3}', GeneralCategory::Ll),
+    ('\u{2cf9}', '\u{2cfc}', GeneralCategory::Po),
+    ('\u{2cfd}', '\u{2cfd}', GeneralCategory::No),
+    ('\u{2cfe}', '\u{2cff}', GeneralCategory::Po),
+    ('\u{2d00}', '\u{2d25}', GeneralCategory::Ll),
+    ('\u{2d27}', '\u{2d27}', GeneralCategory::Ll),
+    ('\u{2d2d}', '\u{2d2d}', GeneralCategory::Ll),
+    ('\u{2d30}', '\u{2d67}', GeneralCategory::Lo),
+    ('\u{2d6f}', '\u{2d6f}', GeneralCategory::Lm),
+    ('\u{2d70}', '\u{2d70}', GeneralCategory::Po),
+    ('\u{2d7f}', '\u{2d7f}', GeneralCategory::Mn),
+    ('\u{2d80}', '\u{2d96}', GeneralCategory::Lo),
+    ('\u{2da0}', '\u{2da6}', GeneralCategory::Lo),
+    ('\u{2da8}', '\u{2dae}', GeneralCategory::Lo),
+    ('\u{2db0}', '\u{2db6}', GeneralCategory::Lo),
+    ('\u{2db8}', '\u{2dbe}', GeneralCategory::Lo),
+    ('\u{2dc0}', '\u{2dc6}', GeneralCategory::Lo),
+    ('\u{2dc8}', '\u{2dce}', GeneralCategory::Lo),
+    ('\u{2dd0}', '\u{2dd6}', GeneralCategory::Lo),
+    ('\u{2dd8}', '\u{2dde}', GeneralCategory::Lo),
+    ('\u{2de0}', '\u{2dff}', GeneralCategory::Mn),
+    ('\u{2e00}', '\u{2e01}', GeneralCategory::Po),
+    ('\u{2e02}', '\u{2e02}', GeneralCategory::Pi),
+    ('\u{2e03}', '\u{2e03}', GeneralCategory::Pf),
+    ('\u{2e04}', '\u{2e04}', GeneralCategory::Pi),
+    ('\u{2e05}', '\u{2e05}', GeneralCategory::Pf),
+    ('\u{2e06}', '\u{2e08}', GeneralCategory::Po),
+    ('\u{2e09}', '\u{2e09}', GeneralCategory::Pi),
+    ('\u{2e0a}', '\u{2e0a}', GeneralCategory::Pf),
+    ('\u{2e0b}', '\u{2e0b}', GeneralCategory::Po),
+    ('\u{2e0c}', '\u{2e0c}', GeneralCategory::Pi),
+    ('\u{2e0d}', '\u{2e0d}', GeneralCategory::Pf),
+    ('\u{2e0e}', '\u{2e16}', GeneralCategory::Po),
+    ('\u{2e17}', '\u{2e17}', GeneralCategory::Pd),
+    ('\u{2e18}', '\u{2e19}', GeneralCategory::Po),
+    ('\u{2e1a}', '\u{2e1a}', GeneralCategory::Pd),
+    ('\u{2e1b}', '\u{2e1b}', GeneralCategory::Po),
+    ('\u{2e1c}', '\u{2e1c}', GeneralCategory::Pi),
+    ('\u{2e1d}', '\u{2e1d}', GeneralCategory::Pf),
+    ('\u{2e1e}', '\u{2e1f}', GeneralCategory::Po),
+    ('\u{2e20}', '\u{2e20}', GeneralCategory::Pi),
+    ('\u{2e21}', '\u{2e21}', GeneralCategory::Pf),
+    ('\u{2e22}', '\u{2e22}', GeneralCategory::Ps),
+    ('\u{2e23}', '\u{2e23}', GeneralCategory::Pe),
+    ('\u{2e24}', '\u{2e24}', GeneralCategory::Ps),
+    ('\u{2e25}', '\u{2e25}', GeneralCategory::Pe),
+    ('\u{2e26}', '\u{2e26}', GeneralCategory::Ps),
+    ('\u{2e27}', '\u{2e27}', GeneralCategory::Pe),
+    ('\u{2e28}', '\u{2e28}', GeneralCategory::Ps),
+    ('\u{2e29}', '\u{2e29}', GeneralCategory::Pe),
+    ('\u{2e2a}', '\u{2e2e}', GeneralCategory::Po),
+    ('\u{2e2f}', '\u{2e2f}', GeneralCategory::Lm),
+    ('\u{2e30}', '\u{2e39}', GeneralCategory::Po),
+    ('\u{2e3a}', '\u{2e3b}', GeneralCategory::Pd),
+    ('\u{2e3c}', '\u{2e3f}', GeneralCategory::Po),
+    ('\u{2e40}', '\u{2e40}', GeneralCategory::Pd),
+    ('\u{2e41}', '\u{2e41}', GeneralCategory::Po),
+    ('\u{2e42}', '\u{2e42}', GeneralCategory::Ps),
+    ('\u{2e43}', '\u{2e4f}', GeneralCategory::Po),
+    ('\u{2e50}', '\u{2e51}', GeneralCategory::So),
+    ('\u{2e52}', '\u{2e54}', GeneralCategory::Po),
+    ('\u{2e55}', '\u{2e55}', GeneralCategory::Ps),
+    ('\u{2e56}', '\u{2e56}', GeneralCategory::Pe),
+    ('\u{2e57}', '\u{2e57}', GeneralCategory::Ps),
+    ('\u{2e58}', '\u{2e58}', GeneralCategory::Pe),
+    ('\u{2e59}', '\u{2e59}', GeneralCategory::Ps),
+    ('\u{2e5a}', '\u{2e5a}', GeneralCategory::Pe),
+    ('\u{2e5b}', '\u{2e5b}', GeneralCategory::Ps),
+    ('\u{2e5c}', '\u{2e5c}', GeneralCategory::Pe),
+    ('\u{2e5d}', '\u{2e5d}', GeneralCategory::Pd),
+    ('\u{2e80}', '\u{2e99}', GeneralCategory::So),
+    ('\u{2e9b}', '\u{2ef3}', GeneralCategory::So),
+    ('\u{2f00}', '\u{2fd5}', GeneralCategory::So),
+    ('\u{2ff0}', '\u{2fff}', GeneralCategory::So),
+    ('\u{3000}', '\u{3000}', GeneralCategory::Zs),
+    ('\u{3001}', '\u{3003}', GeneralCategory::Po),
+    ('\u{3004}', '\u{3004}', GeneralCategory::So),
+    ('\u{3005}', '\u{3005}', GeneralCategory::Lm),
+    ('\u{3006}', '\u{3006}', GeneralCategory::Lo),
+    ('\u{3007}', '\u{3007}', GeneralCategory::Nl),
+    ('\u{3008}', '\u{3008}', GeneralCategory::Ps),
+    ('\u{3009}', '\u{3009}', GeneralCategory::Pe),
+    ('\u{300a}', '\u{300a}', GeneralCategory::Ps),
+    ('\u{300b}', '\u{300b}', GeneralCategory::Pe),
+    ('\u{300c}', '\u{300c}', GeneralCategory::Ps),
+    ('\u{300d}', '\u{300d}', GeneralCategory::Pe),
+    ('\u{300e}', '\u{300e}', GeneralCategory::Ps),
+    ('\u{300f}', '\u{300f}', GeneralCategory::Pe),
+    ('\u{3010}', '\u{3010}', GeneralCategory::Ps),
+    ('\u{3011}', '\u{3011}', GeneralCategory::Pe),
+    ('\u{3012}', '\u{3013}', GeneralCategory::So),
+    ('\u{3014}', '\u{3014}', GeneralCategory::Ps),
+    ('\u{3015}', '\u{3015}', GeneralCategory::Pe),
+    ('\u{3016}', '\u{3016}', GeneralCategory::Ps),
+    ('\u{3017}', '\u{3017}', GeneralCategory::Pe),
+    ('\u{3018}', '\u{3018}', GeneralCategory::Ps),
+    ('\u{3019}', '\u{3019}', GeneralCategory::Pe),
+    ('\u{301a}', '\u{301a}', GeneralCategory::Ps),
+    ('\u{301b}', '\u{301b}', GeneralCategory::Pe),
+    ('\u{301c}', '\u{301c}', GeneralCategory::Pd),
+    ('\u{301d}', '\u{301d}', GeneralCategory::Ps),
+    ('\u{301e}', '\u{301f}', GeneralCategory::Pe),
+    ('\u{3020}', '\u{3020}', GeneralCategory::So),
+    ('\u{3021}', '\u{3029}', GeneralCategory::Nl),
+    ('\u{302a}', '\u{302d}', GeneralCategory::Mn),
+    ('\u{302e}', '\u{302f}', GeneralCategory::Mc),
+    ('\u{3030}', '\u{3030}', GeneralCategory::Pd),
+    ('\u{3031}', '\u{3035}', GeneralCategory::Lm),
+    ('\u{3036}', '\u{3037}', GeneralCategory::So),
+    ('\u{3038}', '\u{303a}', GeneralCategory::Nl),
+    ('\u{303b}', '\u{303b}', GeneralCategory::Lm),
+    ('\u{303c}', '\u{303c}', GeneralCategory::Lo),
+    ('\u{303d}', '\u{303d}', GeneralCategory::Po),
+    ('\u{303e}', '\u{303f}', GeneralCategory::So),
+    ('\u{3041}', '\u{3096}', GeneralCategory::Lo),
+    ('\u{3099}', '\u{309a}', GeneralCategory::Mn),
+    ('\u{309b}', '\u{309c}', GeneralCategory::Sk),
+    ('\u{309d}', '\u{309e}', GeneralCategory::Lm),
+    ('\u{309f}', '\u{309f}', GeneralCategory::Lo),
+    ('\u{30a0}', '\u{30a0}', GeneralCategory::Pd),
+    ('\u{30a1}', '\u{30fa}', GeneralCategory::Lo),
+    ('\u{30fb}', '\u{30fb}', GeneralCategory::Po),
+    ('\u{30fc}', '\u{30fe}', GeneralCategory::Lm),
+    ('\u{30ff}', '\u{30ff}', GeneralCategory::Lo),
+    ('\u{3105}', '\u{312f}', GeneralCategory::Lo),
+    ('\u{3131}', '\u{318e}', GeneralCategory::Lo),
+    ('\u{3190}', '\u{3191}', GeneralCategory::So),
+    ('\u{3192}', '\u{3195}', GeneralCategory::No),
+    ('\u{3196}', '\u{319f}', GeneralCategory::So),
+    ('\u{31a0}', '\u{31bf}', GeneralCategory::Lo),
+    ('\u{31c0}', '\u{31e5}', GeneralCategory::So),
+    ('\u{31ef}', '\u{31ef}', GeneralCategory::So),
+    ('\u{31f0}', '\u{31ff}', GeneralCategory::Lo),
+    ('\u{3200}', '\u{321e}', GeneralCategory::So),
+    ('\u{3220}', '\u{3229}', GeneralCategory::No),
+    ('\u{322a}', '\u{3247}', GeneralCategory::So),
+    ('\u{3248}', '\u{324f}', GeneralCategory::No),
+    ('\u{3250}', '\u{3250}', GeneralCategory::So),
+    ('\u{3251}', '\u{325f}', GeneralCategory::No),
+    ('\u{3260}', '\u{327f}', GeneralCategory::So),
+    ('\u{3280}', '\u{3289}', GeneralCategory::No),
+    ('\u{328a}', '\u{32b0}', GeneralCategory::So),
+    ('\u{32b1}', '\u{32bf}', GeneralCategory::No),
+    ('\u{32c0}', '\u{33ff}', GeneralCategory::So),
+    ('\u{3400}', '\u{4dbf}', GeneralCategory::Lo),
+    ('\u{4dc0}', '\u{4dff}', GeneralCategory::So),
+    ('\u{4e00}', '\u{a014}', GeneralCategory::Lo),
+    ('\u{a015}', '\u{a015}', GeneralCategory::Lm),
+    ('\u{a016}', '\u{a48c}', GeneralCategory::Lo),
+    ('\u{a490}', '\u{a4c6}', GeneralCategory::So),
+    ('\u{a4d0}', '\u{a4f7}', GeneralCategory::Lo),
+    ('\u{a4f8}', '\u{a4fd}', GeneralCategory::Lm),
+    ('\u{a4fe}', '\u{a4ff}', GeneralCategory::Po),
+    ('\u{a500}', '\u{a60b}', GeneralCategory::Lo),
+    ('\u{a60c}', '\u{a60c}', GeneralCategory::Lm),
+    ('\u{a60d}', '\u{a60f}', GeneralCategory::Po),
+    ('\u{a610}', '\u{a61f}', GeneralCategory::Lo),
+    ('\u{a620}', '\u{a629}', GeneralCategory::Nd),
+    ('\u{a62a}', '\u{a62b}', GeneralCategory::Lo),
+    ('\u{a640}', '\u{a640}', GeneralCategory::Lu),
+    ('\u{a641}', '\u{a641}', GeneralCategory::Ll),
+    ('\u{a642}', '\u{a642}', GeneralCategory::Lu),
+    ('\u{a643}', '\u{a643}', GeneralCategory::Ll),
+    ('\u{a644}', '\u{a644}', GeneralCategory::Lu),
+    ('\u{a645}', '\u{a645}', GeneralCategory::Ll),
+    ('\u{a646}', '\u{a646}', GeneralCategory::Lu),
+    ('\u{a647}', '\u{a647}', GeneralCategory::Ll),
+    ('\u{a648}', '\u{a648}', GeneralCategory::Lu),
+    ('\u{a649}', '\u{a649}', GeneralCategory::Ll),
+    ('\u{a64a}', '\u{a64a}', GeneralCategory::Lu),
+    ('\u{a64b}', '\u{a64b}', GeneralCategory::Ll),
+    ('\u{a64c}', '\u{a64c}', GeneralCategory::Lu),
+    ('\u{a64d}', '\u{a64d}', GeneralCategory::Ll),
+    ('\u{a64e}', '\u{a64e}', GeneralCategory::Lu),
+    ('\u{a64f}', '\u{a64f}', GeneralCategory::Ll),
+    ('\u{a650}', '\u{a650}', GeneralCategory::Lu),
+    ('\u{a651}', '\u{a651}', GeneralCategory::Ll),
+    ('\u{a652}', '\u{a652}', GeneralCategory::Lu),
+    ('\u{a653}', '\u{a653}', GeneralCategory::Ll),
+    ('\u{a654}', '\u{a654}', GeneralCategory::Lu),
+    ('\u{a655}', '\u{a655}', GeneralCategory::Ll),
+    ('\u{a656}', '\u{a656}', GeneralCategory::Lu),
+    ('\u{a657}', '\u{a657}', GeneralCategory::Ll),
+    ('\u{a658}', '\u{a658}', GeneralCategory::Lu),
+    ('\u{a659}', '\u{a659}', GeneralCategory::Ll),
+    ('\u{a65a}', '\u{a65a}', GeneralCategory::Lu),
+    ('\u{a65b}', '\u{a65b}', GeneralCategory::Ll),
+    ('\u{a65c}', '\u{a65c}', GeneralCategory::Lu),
+    ('\u{a65d}', '\u{a65d}', GeneralCategory::Ll),
+    ('\u{a65e}', '\u{a65e}', GeneralCategory::Lu),
+    ('\u{a65f}', '\u{a65f}', GeneralCategory::Ll),
+    ('\u{a660}', '\u{a660}', GeneralCategory::Lu),
+    ('\u{a661}', '\u{a661}', GeneralCategory::Ll),
+    ('\u{a662}', '\u{a662}', GeneralCategory::Lu),
+    ('\u{a663}', '\u{a663}', GeneralCategory::Ll),
+    ('\u{a664}', '\u{a664}', GeneralCategory::Lu),
+    ('\u{a665}', '\u{a665}', GeneralCategory::Ll),
+    ('\u{a666}', '\u{a666}', GeneralCategory::Lu),
+    ('\u{a667}', '\u{a667}', GeneralCategory::Ll),
+    ('\u{a668}', '\u{a668}', GeneralCategory::Lu),
+    ('\u{a669}', '\u{a669}', GeneralCategory::Ll),
+    ('\u{a66a}', '\u{a66a}', GeneralCategory::Lu),
+    ('\u{a66b}', '\u{a66b}', GeneralCategory::Ll),
+    ('\u{a66c}', '\u{a66c}', GeneralCategory::Lu),
+    ('\u{a66d}', '\u{a66d}', GeneralCategory::Ll),
+    ('\u{a66e}', '\u{a66e}', GeneralCategory::Lo),
+    ('\u{a66f}', '\u{a66f}', GeneralCategory::Mn),
+    ('\u{a670}', '\u{a672}', GeneralCategory::Me),
+    ('\u{a673}', '\u{a673}', GeneralCategory::Po),
+    ('\u{a674}', '\u{a67d}', GeneralCategory::Mn),
+    ('\u{a67e}', '\u{a67e}', GeneralCategory::Po),
+    ('\u{a67f}', '\u{a67f}', GeneralCategory::Lm),
+    ('\u{a680}', '\u{a680}', GeneralCategory::Lu),
+    ('\u{a681}', '\u{a681}', GeneralCategory::Ll),
+    ('\u{a682}', '\u{a682}', GeneralCategory::Lu),
+    ('\u{a683}', '\u{a683}', GeneralCategory::Ll),
+    ('\u{a684}', '\u{a684}', GeneralCategory::Lu),
+    ('\u{a685}', '\u{a685}', GeneralCategory::Ll),
+    ('\u{a686}', '\u{a686}', GeneralCategory::Lu),
+    ('\u{a687}', '\u{a687}', GeneralCategory::Ll),
+    ('\u{a688}', '\u{a688}', GeneralCategory::Lu),
+    ('\u{a689}', '\u{a689}', GeneralCategory::Ll),
+    ('\u{a68a}', '\u{a68a}', GeneralCategory::Lu),
+    ('\u{a68b}', '\u{a68b}', GeneralCategory::Ll),
+    ('\u{a68c}', '\u{a68c}', GeneralCategory::Lu),
+    ('\u{a68d}', '\u{a68d}', GeneralCategory::Ll),
+    ('\u{a68e}', '\u{a68e}', GeneralCategory::Lu),
+    ('\u{a68f}', '\u{a68f}', GeneralCategory::Ll),
+    ('\u{a690}', '\u{a690}', GeneralCategory::Lu),
+    ('\u{a691}', '\u{a691}', GeneralCategory::Ll),
+    ('\u{a692}', '\u{a692}', GeneralCategory::Lu),
+    ('\u{a693}', '\u{a693}', GeneralCategory::Ll),
+    ('\u{a694}', '\u{a694}', GeneralCategory::Lu),
+    ('\u{a695}', '\u{a695}', GeneralCategory::Ll),
+    ('\u{a696}', '\u{a696}', GeneralCategory::Lu),
+    ('\u{a697}', '\u{a697}', GeneralCategory::Ll),
+    ('\u{a698}', '\u{a698}', GeneralCategory::Lu),
+    ('\u{a699}', '\u{a699}', GeneralCategory::Ll),
+    ('\u{a69a}', '\u{a69a}', GeneralCategory::Lu),
+    ('\u{a69b}', '\u{a69b}', GeneralCategory::Ll),
+    ('\u{a69c}', '\u{a69d}', GeneralCategory::Lm),
+    ('\u{a69e}', '\u{a69f}', GeneralCategory::Mn),
+    ('\u{a6a0}', '\u{a6e5}', GeneralCategory::Lo),
+    ('\u{a6e6}', '\u{a6ef}', GeneralCategory::Nl),
+    ('\u{a6f0}', '\u{a6f1}', GeneralCategory::Mn),
+    ('\u{a6f2}', '\u{a6f7}', GeneralCategory::Po),
+    ('\u{a700}', '\u{a716}', GeneralCategory::Sk),
+    ('\u{a717}', '\u{a71f}', GeneralCategory::Lm),
+    ('\u{a720}', '\u{a721}', GeneralCategory::Sk),
+    ('\u{a722}', '\u{a722}', GeneralCategory::Lu),
+    ('\u{a723}', '\u{a723}', GeneralCategory::Ll),
+    ('\u{a724}', '\u{a724}', GeneralCategory::Lu),
+    ('\u{a725}', '\u{a725}', GeneralCategory::Ll),
+    ('\u{a726}', '\u{a726}', GeneralCategory::Lu),
+    ('\u{a727}', '\u{a727}', GeneralCategory::Ll),
+    ('\u{a728}', '\u{a728}', GeneralCategory::Lu),
+    ('\u{a729}', '\u{a729}', GeneralCategory::Ll),
+    ('\u{a72a}', '\u{a72a}', GeneralCategory::Lu),
+    ('\u{a72b}', '\u{a72b}', GeneralCategory::Ll),
+    ('\u{a72c}', '\u{a72c}', GeneralCategory::Lu),
+    ('\u{a72d}', '\u{a72d}', GeneralCategory::Ll),
+    ('\u{a72e}', '\u{a72e}', GeneralCategory::Lu),
+    ('\u{a72f}', '\u{a731}', GeneralCategory::Ll),
+    ('\u{a732}', '\u{a732}', GeneralCategory::Lu),
+    ('\u{a733}', '\u{a733}', GeneralCategory::Ll),
+    ('\u{a734}', '\u{a734}', GeneralCategory::Lu),
+    ('\u{a735}', '\u{a735}', GeneralCategory::Ll),
+    ('\u{a736}', '\u{a736}', GeneralCategory::Lu),
+    ('\u{a737}', '\u{a737}', GeneralCategory::Ll),
+    ('\u{a738}', '\u{a738}', GeneralCategory::Lu),
+    ('\u{a739}', '\u{a739}', GeneralCategory::Ll),
+    ('\u{a73a}', '\u{a73a}', GeneralCategory::Lu),
+    ('\u{a73b}', '\u{a73b}', GeneralCategory::Ll),
+    ('\u{a73c}', '\u{a73c}', GeneralCategory::Lu),
+    ('\u{a73d}', '\u{a73d}', GeneralCategory::Ll),
+    ('\u{a73e}', '\u{a73e}', GeneralCategory::Lu),
+    ('\u{a73f}', '\u{a73f}', GeneralCategory::Ll),
+    ('\u{a740}', '\u{a740}', GeneralCategory::Lu),
+    ('\u{a741}', '\u{a741}', GeneralCategory::Ll),
+    ('\u{a742}', '\u{a742}', GeneralCategory::Lu),
+    ('\u{a743}', '\u{a743}', GeneralCategory::Ll),
+    ('\u{a744}', '\u{a744}', GeneralCategory::Lu),
+    ('\u{a745}', '\u{a745}', GeneralCategory::Ll),
+    ('\u{a746}', '\u{a746}', GeneralCategory::Lu),
+    ('\u{a747}', '\u{a747}', GeneralCategory::Ll),
+    ('\u{a748}', '\u{a748}', GeneralCategory::Lu),
+    ('\u{a749}', '\u{a749}', GeneralCategory::Ll),
+    ('\u{a74a}', '\u{a74a}', GeneralCategory::Lu),
+    ('\u{a74b}', '\u{a74b}', GeneralCategory::Ll),
+    ('\u{a74c}', '\u{a74c}', GeneralCategory::Lu),
+    ('\u{a74d}', '\u{a74d}', GeneralCategory::Ll),
+    ('\u{a74e}', '\u{a74e}', GeneralCategory::Lu),
+    ('\u{a74f}', '\u{a74f}', GeneralCategory::Ll),
+    ('\u{a750}', '\u{a750}', GeneralCategory::Lu),
+    ('\u{a751}', '\u{a751}', GeneralCategory::Ll),
+    ('\u{a752}', '\u{a752}', GeneralCategory::Lu),
+    ('\u{a753}', '\u{a753}', GeneralCategory::Ll),
+    ('\u{a754}', '\u{a754}', GeneralCategory::Lu),
+    ('\u{a755}', '\u{a755}', GeneralCategory::Ll),
+    ('\u{a756}', '\u{a756}', GeneralCategory::Lu),
+    ('\u{a757}', '\u{a757}', GeneralCategory::Ll),
+    ('\u{a758}', '\u{a758}', GeneralCategory::Lu),
+    ('\u{a759}', '\u{a759}', GeneralCategory::Ll),
+    ('\u{a75a}', '\u{a75a}', GeneralCategory::Lu),
+    ('\u{a75b}', '\u{a75b}', GeneralCategory::Ll),
+    ('\u{a75c}', '\u{a75c}', GeneralCategory::Lu),
+    ('\u{a75d}', '\u{a75d}', GeneralCategory::Ll),
+    ('\u{a75e}', '\u{a75e}', GeneralCategory::Lu),
+    ('\u{a75f}', '\u{a75f}', GeneralCategory::Ll),
+    ('\u{a760}', '\u{a760}', GeneralCategory::Lu),
+    ('\u{a761}', '\u{a761}', GeneralCategory::Ll),
+    ('\u{a762}', '\u{a762}', GeneralCategory::Lu),
+    ('\u{a763}', '\u{a763}', GeneralCategory::Ll),
+    ('\u{a764}', '\u{a764}', GeneralCategory::Lu),
+    ('\u{a765}', '\u{a765}', GeneralCategory::Ll),
+    ('\u{a766}', '\u{a766}', GeneralCategory::Lu),
+    ('\u{a767}', '\u{a767}', GeneralCategory::Ll),
+    ('\u{a768}', '\u{a768}', GeneralCategory::Lu),
+    ('\u{a769}', '\u{a769}', GeneralCategory::Ll),
+    ('\u{a76a}', '\u{a76a}', GeneralCategory::Lu),
+    ('\u{a76b}', '\u{a76b}', GeneralCategory::Ll),
+    ('\u{a76c}', '\u{a76c}', GeneralCategory::Lu),
+    ('\u{a76d}', '\u{a76d}', GeneralCategory::Ll),
+    ('\u{a76e}', '\u{a76e}', GeneralCategory::Lu),
+    ('\u{a76f}', '\u{a76f}', GeneralCategory::Ll),
+    ('\u{a770}', '\u{a770}', GeneralCategory::Lm),
+    ('\u{a771}', '\u{a778}', GeneralCategory::Ll),
+    ('\u{a779}', '\u{a779}', GeneralCategory::Lu),
+    ('\u{a77a}', '\u{a77a}', GeneralCategory::Ll),
+    ('\u{a77b}', '\u{a77b}', GeneralCategory::Lu),
+    ('\u{a77c}', '\u{a77c}', GeneralCategory::Ll),
+    ('\u{a77d}', '\u{a77e}', GeneralCategory::Lu),
+    ('\u{a77f}', '\u{a77f}', GeneralCategory::Ll),
+    ('\u{a780}', '\u{a780}', GeneralCategory::Lu),
+    ('\u{a781}', '\u{a781}', GeneralCategory::Ll),
+    ('\u{a782}', '\u{a782}', GeneralCategory::Lu),
+    ('\u{a783}', '\u{a783}', GeneralCategory::Ll),
+    ('\u{a784}', '\u{a784}', GeneralCategory::Lu),
+    ('\u{a785}', '\u{a785}', GeneralCategory::Ll),
+    ('\u{a786}', '\u{a786}', GeneralCategory::Lu),
+    ('\u{a787}', '\u{a787}', GeneralCategory::Ll),
+    ('\u{a788}', '\u{a788}', GeneralCategory::Lm),
+    ('\u{a789}', '\u{a78a}', GeneralCategory::Sk),
+    ('\u{a78b}', '\u{a78b}', GeneralCategory::Lu),
+    ('\u{a78c}', '\u{a78c}', GeneralCategory::Ll),
+    ('\u{a78d}', '\u{a78d}', GeneralCategory::Lu),
+    ('\u{a78e}', '\u{a78e}', GeneralCategory::Ll),
+    ('\u{a78f}', '\u{a78f}', GeneralCategory::Lo),
+    ('\u{a790}', '\u{a790}', GeneralCategory::Lu),
+    ('\u{a791}', '\u{a791}', GeneralCategory::Ll),
+    ('\u{a792}', '\u{a792}', GeneralCategory::Lu),
+    ('\u{a793}', '\u{a795}', GeneralCategory::Ll),
+    ('\u{a796}', '\u{a796}', GeneralCategory::Lu),
+    ('\u{a797}', '\u{a797}', GeneralCategory::Ll),
+    ('\u{a798}', '\u{a798}', GeneralCategory::Lu),
+    ('\u{a799}', '\u{a799}', GeneralCategory::Ll),
+    ('\u{a79a}', '\u{a79a}', GeneralCategory::Lu),
+    ('\u{a79b}', '\u{a79b}', GeneralCategory::Ll),
+    ('\u{a79c}', '\u{a79c}', GeneralCategory::Lu),
+    ('\u{a79d}', '\u{a79d}', GeneralCategory::Ll),
+    ('\u{a79e}', '\u{a79e}', GeneralCategory::Lu),
+    ('\u{a79f}', '\u{a79f}', GeneralCategory::Ll),
+    ('\u{a7a0}', '\u{a7a0}', GeneralCategory::Lu),
+    ('\u{a7a1}', '\u{a7a1}', GeneralCategory::Ll),
+    ('\u{a7a2}', '\u{a7a2}', GeneralCategory::Lu),
+    ('\u{a7a3}', '\u{a7a3}', GeneralCategory::Ll),
+    ('\u{a7a4}', '\u{a7a4}', GeneralCategory::Lu),
+    ('\u{a7a5}', '\u{a7a5}', GeneralCategory::Ll),
+    ('\u{a7a6}', '\u{a7a6}', GeneralCategory::Lu),
+    ('\u{a7a7}', '\u{a7a7}', GeneralCategory::Ll),
+    ('\u{a7a8}', '\u{a7a8}', GeneralCategory::Lu),
+    ('\u{a7a9}', '\u{a7a9}', GeneralCategory::Ll),
+    ('\u{a7aa}', '\u{a7ae}', GeneralCategory::Lu),
+    ('\u{a7af}', '\u{a7af}', GeneralCategory::Ll),
+    ('\u{a7b0}', '\u{a7b4}', GeneralCategory::Lu),
+    ('\u{a7b5}', '\u{a7b5}', GeneralCategory::Ll),
+    ('\u{a7b6}', '\u{a7b6}', GeneralCategory::Lu),
+    ('\u{a7b7}', '\u{a7b7}', GeneralCategory::Ll),
+    ('\u{a7b8}', '\u{a7b8}', GeneralCategory::Lu),
+    ('\u{a7b9}', '\u{a7b9}', GeneralCategory::Ll),
+    ('\u{a7ba}', '\u{a7ba}', GeneralCategory::Lu),
+    ('\u{a7bb}', '\u{a7bb}', GeneralCategory::Ll),
+    ('\u{a7bc}', '\u{a7bc}', GeneralCategory::Lu),
+    ('\u{a7bd}', '\u{a7bd}', GeneralCategory::Ll),
+    ('\u{a7be}', '\u{a7be}', GeneralCategory::Lu),
+    ('\u{a7bf}', '\u{a7bf}', GeneralCategory::Ll),
+    ('\u{a7c0}', '\u{a7c0}', GeneralCategory::Lu),
+    ('\u{a7c1}', '\u{a7c1}', GeneralCategory::Ll),
+    ('\u{a7c2}', '\u{a7c2}', GeneralCategory::Lu),
+    ('\u{a7c3}', '\u{a7c3}', GeneralCategory::Ll),
+    ('\u{a7c4}', '\u{a7c7}', GeneralCategory::Lu),
+    ('\u{a7c8}', '\u{a7c8}', GeneralCategory::Ll),
+    ('\u{a7c9}', '\u{a7c9}', GeneralCategory::Lu),
+    ('\u{a7ca}', '\u{a7ca}', GeneralCategory::Ll),
+    ('\u{a7cb}', '\u{a7cc}', GeneralCategory::Lu),
+    ('\u{a7cd}', '\u{a7cd}', GeneralCategory::Ll),
+    ('\u{a7ce}', '\u{a7ce}', GeneralCategory::Lu),
+    ('\u{a7cf}', '\u{a7cf}', GeneralCategory::Ll),
+    ('\u{a7d0}', '\u{a7d0}', GeneralCategory::Lu),
+    ('\u{a7d1}', '\u{a7d1}', GeneralCategory::Ll),
+    ('\u{a7d2}', '\u{a7d2}', GeneralCategory::Lu),
+    ('\u{a7d3}', '\u{a7d3}', GeneralCategory::Ll),
+    ('\u{a7d4}', '\u{a7d4}', GeneralCategory::Lu),
+    ('\u{a7d5}', '\u{a7d5}', GeneralCategory::Ll),
+    ('\u{a7d6}', '\u{a7d6}', GeneralCategory::Lu),
+    ('\u{a7d7}', '\u{a7d7}', GeneralCategory::Ll),
+    ('\u{a7d8}', '\u{a7d8}', GeneralCategory::Lu),
+    ('\u{a7d9}', '\u{a7d9}', GeneralCategory::Ll),
+    ('\u{a7da}', '\u{a7da}', GeneralCategory::Lu),
+    ('\u{a7db}', '\u{a7db}', GeneralCategory::Ll),
+    ('\u{a7dc}', '\u{a7dc}', GeneralCategory::Lu),
+    ('\u{a7f1}', '\u{a7f4}', GeneralCategory::Lm),
+    ('\u{a7f5}', '\u{a7f5}', GeneralCategory::Lu),
+    ('\u{a7f6}', '\u{a7f6}', GeneralCategory::Ll),
+    ('\u{a7f7}', '\u{a7f7}', GeneralCategory::Lo),
+    ('\u{a7f8}', '\u{a7f9}', GeneralCategory::Lm),
+    ('\u{a7fa}', '\u{a7fa}', GeneralCategory::Ll),
+    ('\u{a7fb}', '\u{a801}', GeneralCategory::Lo),
+    ('\u{a802}', '\u{a802}', GeneralCategory::Mn),
+    ('\u{a803}', '\u{a805}', GeneralCategory::Lo),
+    ('\u{a806}', '\u{a806}', GeneralCategory::Mn),
+    ('\u{a807}', '\u{a80a}', GeneralCategory::Lo),
+    ('\u{a80b}', '\u{a80b}', GeneralCategory::Mn),
+    ('\u{a80c}', '\u{a822}', GeneralCategory::Lo),
+    ('\u{a823}', '\u{a824}', GeneralCategory::Mc),
+    ('\u{a825}', '\u{a826}', GeneralCategory::Mn),
+    ('\u{a827}', '\u{a827}', GeneralCategory::Mc),
+    ('\u{a828}', '\u{a82b}', GeneralCategory::So),
+    ('\u{a82c}', '\u{a82c}', GeneralCategory::Mn),
+    ('\u{a830}', '\u{a835}', GeneralCategory::No),
+    ('\u{a836}', '\u{a837}', GeneralCategory::So),
+    ('\u{a838}', '\u{a838}', GeneralCategory::Sc),
+    ('\u{a839}', '\u{a839}', GeneralCategory::So),
+    ('\u{a840}', '\u{a873}', GeneralCategory::Lo),
+    ('\u{a874}', '\u{a877}', GeneralCategory::Po),
+    ('\u{a880}', '\u{a881}', GeneralCategory::Mc),
+    ('\u{a882}', '\u{a8b3}', GeneralCategory::Lo),
+    ('\u{a8b4}', '\u{a8c3}', GeneralCategory::Mc),
+    ('\u{a8c4}', '\u{a8c5}', GeneralCategory::Mn),
+    ('\u{a8ce}', '\u{a8cf}', GeneralCategory::Po),
+    ('\u{a8d0}', '\u{a8d9}', GeneralCategory::Nd),
+    ('\u{a8e0}', '\u{a8f1}', GeneralCategory::Mn),
+    ('\u{a8f2}', '\u{a8f7}', GeneralCategory::Lo),
+    ('\u{a8f8}', '\u{a8fa}', GeneralCategory::Po),
+    ('\u{a8fb}', '\u{a8fb}', GeneralCategory::Lo),
+    ('\u{a8fc}', '\u{a8fc}', GeneralCategory::Po),
+    ('\u{a8fd}', '\u{a8fe}', GeneralCategory::Lo),
+    ('\u{a8ff}', '\u{a8ff}', GeneralCategory::Mn),
+    ('\u{a900}', '\u{a909}', GeneralCategory::Nd),
+    ('\u{a90a}', '\u{a925}', GeneralCategory::Lo),
+    ('\u{a926}', '\u{a92d}', GeneralCategory::Mn),
+    ('\u{a92e}', '\u{a92f}', GeneralCategory::Po),
+    ('\u{a930}', '\u{a946}', GeneralCategory::Lo),
+    ('\u{a947}', '\u{a951}', GeneralCategory::Mn),
+    ('\u{a952}', '\u{a953}', GeneralCategory::Mc),
+    ('\u{a95f}', '\u{a95f}', GeneralCategory::Po),
+    ('\u{a960}', '\u{a97c}', GeneralCategory::Lo),
+    ('\u{a980}', '\u{a982}', GeneralCategory::Mn),
+    ('\u{a983}', '\u{a983}', GeneralCategory::Mc),
+    ('\u{a984}', '\u{a9b2}', GeneralCategory::Lo),
+    ('\u{a9b3}', '\u{a9b3}', GeneralCategory::Mn),
+    ('\u{a9b4}', '\u{a9b5}', GeneralCategory::Mc),
+    ('\u{a9b6}', '\u{a9b9}', GeneralCategory::Mn),
+    ('\u{a9ba}', '\u{a9bb}', GeneralCategory::Mc),
+    ('\u{a9bc}', '\u{a9bd}', GeneralCategory::Mn),
+    ('\u{a9be}', '\u{a9c0}', GeneralCategory::Mc),
+    ('\u{a9c1}', '\u{a9cd}', GeneralCategory::Po),
+    ('\u{a9cf}', '\u{a9cf}', GeneralCategory::Lm),
+    ('\u{a9d0}', '\u{a9d9}', GeneralCategory::Nd),
+    ('\u{a9de}', '\u{a9df}', GeneralCategory::Po),
+    ('\u{a9e0}', '\u{a9e4}', GeneralCategory::Lo),
+    ('\u{a9e5}', '\u{a9e5}', GeneralCategory::Mn),
+    ('\u{a9e6}', '\u{a9e6}', GeneralCategory::Lm),
+    ('\u{a9e7}', '\u{a9ef}', GeneralCategory::Lo),
+    ('\u{a9f0}', '\u{a9f9}', GeneralCategory::Nd),
+    ('\u{a9fa}', '\u{a9fe}', GeneralCategory::Lo),
+    ('\u{aa00}', '\u{aa28}', GeneralCategory::Lo),
+    ('\u{aa29}', '\u{aa2e}', GeneralCategory::Mn),
+    ('\u{aa2f}', '\u{aa30}', GeneralCategory::Mc),
+    ('\u{aa31}', '\u{aa32}', GeneralCategory::Mn),
+    ('\u{aa33}', '\u{aa34}', GeneralCategory::Mc),
+    ('\u{aa35}', '\u{aa36}', GeneralCategory::Mn),
+    ('\u{aa40}', '\u{aa42}', GeneralCategory::Lo),
+    ('\u{aa43}', '\u{aa43}', GeneralCategory::Mn),
+    ('\u{aa44}', '\u{aa4b}', GeneralCategory::Lo),
+    ('\u{aa4c}', '\u{aa4c}', GeneralCategory::Mn),
+    ('\u{aa4d}', '\u{aa4d}', GeneralCategory::Mc),
+    ('\u{aa50}', '\u{aa59}', GeneralCategory::Nd),
+    ('\u{aa5c}', '\u{aa5f}', GeneralCategory::Po),
+    ('\u{aa60}', '\u{aa6f}', GeneralCategory::Lo),
+    ('\u{aa70}', '\u{aa70}', GeneralCategory::Lm),
+    ('\u{aa71}', '\u{aa76}', GeneralCategory::Lo),
+    ('\u{aa77}', '\u{aa79}', GeneralCategory::So),
+    ('\u{aa7a}', '\u{aa7a}', GeneralCategory::Lo),
+    ('\u{aa7b}', '\u{aa7b}', GeneralCategory::Mc),
+    ('\u{aa7c}', '\u{aa7c}', GeneralCategory::Mn),
+    ('\u{aa7d}', '\u{aa7d}', GeneralCategory::Mc),
+    ('\u{aa7e}', '\u{aaaf}', GeneralCategory::Lo),
+    ('\u{aab0}', '\u{aab0}', GeneralCategory::Mn),
+    ('\u{aab1}', '\u{aab1}', GeneralCategory::Lo),
+    ('\u{aab2}', '\u{aab4}', GeneralCategory::Mn),
+    ('\u{aab5}', '\u{aab6}', GeneralCategory::Lo),
+    ('\u{aab7}', '\u{aab8}', GeneralCategory::Mn),
+    ('\u{aab9}', '\u{aabd}', GeneralCategory::Lo),
+    ('\u{aabe}', '\u{aabf}', GeneralCategory::Mn),
+    ('\u{aac0}', '\u{aac0}', GeneralCategory::Lo),
+    ('\u{aac1}', '\u{aac1}', GeneralCategory::Mn),
+    ('\u{aac2}', '\u{aac2}', GeneralCategory::Lo),
+    ('\u{aadb}', '\u{aadc}', GeneralCategory::Lo),
+    ('\u{aadd}', '\u{aadd}', GeneralCategory::Lm),
+    ('\u{aade}', '\u{aadf}', GeneralCategory::Po),
+    ('\u{aae0}', '\u{aaea}', GeneralCategory::Lo),
+    ('\u{aaeb}', '\u{aaeb}', GeneralCategory::Mc),
+    ('\u{aaec}', '\u{aaed}', GeneralCategory::Mn),
+    ('\u{aaee}', '\u{aaef}', GeneralCategory::Mc),
+    ('\u{aaf0}', '\u{aaf1}', GeneralCategory::Po),
+    ('\u{aaf2}', '\u{aaf2}', GeneralCategory::Lo),
+    ('\u{aaf3}', '\u{aaf4}', GeneralCategory::Lm),
+    ('\u{aaf5}', '\u{aaf5}', GeneralCategory::Mc),
+    ('\u{aaf6}', '\u{aaf6}', GeneralCategory::Mn),
+    ('\u{ab01}', '\u{ab06}', GeneralCategory::Lo),
+    ('\u{ab09}', '\u{ab0e}', GeneralCategory::Lo),
+    ('\u{ab11}', '\u{ab16}', GeneralCategory::Lo),
+    ('\u{ab20}', '\u{ab26}', GeneralCategory::Lo),
+    ('\u{ab28}', '\u{ab2e}', GeneralCategory::Lo),
+    ('\u{ab30}', '\u{ab5a}', GeneralCategory::Ll),
+    ('\u{ab5b}', '\u{ab5b}', GeneralCategory::Sk),
+    ('\u{ab5c}', '\u{ab5f}', GeneralCategory::Lm),
+    ('\u{ab60}', '\u{ab68}', GeneralCategory::Ll),
+    ('\u{ab69}', '\u{ab69}', GeneralCategory::Lm),
+    ('\u{ab6a}', '\u{ab6b}', GeneralCategory::Sk),
+    ('\u{ab70}', '\u{abbf}', GeneralCategory::Ll),
+    ('\u{abc0}', '\u{abe2}', GeneralCategory::Lo),
+    ('\u{abe3}', '\u{abe4}', GeneralCategory::Mc),
+    ('\u{abe5}', '\u{abe5}', GeneralCategory::Mn),
+    ('\u{abe6}', '\u{abe7}', GeneralCategory::Mc),
+    ('\u{abe8}', '\u{abe8}', GeneralCategory::Mn),
+    ('\u{abe9}', '\u{abea}', GeneralCategory::Mc),
+    ('\u{abeb}', '\u{abeb}', GeneralCategory::Po),
+    ('\u{abec}', '\u{abec}', GeneralCategory::Mc),
+    ('\u{abed}', '\u{abed}', GeneralCategory::Mn),
+    ('\u{abf0}', '\u{abf9}', GeneralCategory::Nd),
+    ('\u{ac00}', '\u{d7a3}', GeneralCategory::Lo),
+    ('\u{d7b0}', '\u{d7c6}', GeneralCategory::Lo),
+    ('\u{d7cb}', '\u{d7fb}', GeneralCategory::Lo),
+    ('\u{e000}', '\u{f8ff}', GeneralCategory::Co),
+    ('\u{f900}', '\u{fa6d}', GeneralCategory::Lo),
+    ('\u{fa70}', '\u{fad9}', GeneralCategory::Lo),
+    ('\u{fb00}', '\u{fb06}', GeneralCategory::Ll),
+    ('\u{fb13}', '\u{fb17}', GeneralCategory::Ll),
+    ('\u{fb1d}', '\u{fb1d}', GeneralCategory::Lo),
+    ('\u{fb1e}', '\u{fb1e}', GeneralCategory::Mn),
+    ('\u{fb1f}', '\u{fb28}', GeneralCategory::Lo),
+    ('\u{fb29}', '\u{fb29}', GeneralCategory::Sm),
+    ('\u{fb2a}', '\u{fb36}', GeneralCategory::Lo),
+    ('\u{fb38}', '\u{fb3c}', GeneralCategory::Lo),
+    ('\u{fb3e}', '\u{fb3e}', GeneralCategory::Lo),
+    ('\u{fb40}', '\u{fb41}', GeneralCategory::Lo),
+    ('\u{fb43}', '\u{fb44}', GeneralCategory::Lo),
+    ('\u{fb46}', '\u{fbb1}', GeneralCategory::Lo),
+    ('\u{fbb2}', '\u{fbc2}', GeneralCategory::Sk),
+    ('\u{fbc3}', '\u{fbd2}', GeneralCategory::So),
+    ('\u{fbd3}', '\u{fd3d}', GeneralCategory::Lo),
+    ('\u{fd3e}', '\u{fd3e}', GeneralCategory::Pe),
+    ('\u{fd3f}', '\u{fd3f}', GeneralCategory::Ps),
+    ('\u{fd40}', '\u{fd4f}', GeneralCategory::So),
+    ('\u{fd50}', '\u{fd8f}', GeneralCategory::Lo),
+    ('\u{fd90}', '\u{fd91}', GeneralCategory::So),
+    ('\u{fd92}', '\u{fdc7}', GeneralCategory::Lo),
+    ('\u{fdc8}', '\u{fdcf}', GeneralCategory::So),
+    ('\u{fdf0}', '\u{fdfb}', GeneralCategory::Lo),
+    ('\u{fdfc}', '\u{fdfc}', GeneralCategory::Sc),
+    ('\u{fdfd}', '\u{fdff}', GeneralCategory::So),
+    ('\u{fe00}', '\u{fe0f}', GeneralCategory::Mn),
+    ('\u{fe10}', '\u{fe16}', GeneralCategory::Po),
+    ('\u{fe17}', '\u{fe17}', GeneralCategory::Ps),
+    ('\u{fe18}', '\u{fe18}', GeneralCategory::Pe),
+    ('\u{fe19}', '\u{fe19}', GeneralCategory::Po),
+    ('\u{fe20}', '\u{fe2f}', GeneralCategory::Mn),
+    ('\u{fe30}', '\u{fe30}', GeneralCategory::Po),
+    ('\u{fe31}', '\u{fe32}', GeneralCategory::Pd),
+    ('\u{fe33}', '\u{fe34}', GeneralCategory::Pc),
+    ('\u{fe35}', '\u{fe35}', GeneralCategory::Ps),
+    ('\u{fe36}', '\u{fe36}', GeneralCategory::Pe),
+    ('\u{fe37}', '\u{fe37}', GeneralCategory::Ps),
+    ('\u{fe38}', '\u{fe38}', GeneralCategory::Pe),
+    ('\u{fe39}', '\u{fe39}', GeneralCategory::Ps),
+    ('\u{fe3a}', '\u{fe3a}', GeneralCategory::Pe),
+    ('\u{fe3b}', '\u{fe3b}', GeneralCategory::Ps),
+    ('\u{fe3c}', '\u{fe3c}', GeneralCategory::Pe),
+    ('\u{fe3d}', '\u{fe3d}', GeneralCategory::Ps),
+    ('\u{fe3e}', '\u{fe3e}', GeneralCategory::Pe),
+    ('\u{fe3f}', '\u{fe3f}', GeneralCategory::Ps),
+    ('\u{fe40}', '\u{fe40}', GeneralCategory::Pe),
+    ('\u{fe41}', '\u{fe41}', GeneralCategory::Ps),
+    ('\u{fe42}', '\u{fe42}', GeneralCategory::Pe),
+    ('\u{fe43}', '\u{fe43}', GeneralCategory::Ps),
+    ('\u{fe44}', '\u{fe44}', GeneralCategory::Pe),
+    ('\u{fe45}', '\u{fe46}', GeneralCategory::Po),
+    ('\u{fe47}', '\u{fe47}', GeneralCategory::Ps),
+    ('\u{fe48}', '\u{fe48}', GeneralCategory::Pe),
+    ('\u{fe49}', '\u{fe4c}', GeneralCategory::Po),
+    ('\u{fe4d}', '\u{fe4f}', GeneralCategory::Pc),
+    ('\u{fe50}', '\u{fe52}', GeneralCategory::Po),
+    ('\u{fe54}', '\u{fe57}', GeneralCategory::Po),
+    ('\u{fe58}', '\u{fe58}', GeneralCategory::Pd),
+    ('\u{fe59}', '\u{fe59}', GeneralCategory::Ps),
+    ('\u{fe5a}', '\u{fe5a}', GeneralCategory::Pe),
+    ('\u{fe5b}', '\u{fe5b}', GeneralCategory::Ps),
+    ('\u{fe5c}', '\u{fe5c}', GeneralCategory::Pe),
+    ('\u{fe5d}', '\u{fe5d}', GeneralCategory::Ps),
+    ('\u{fe5e}', '\u{fe5e}', GeneralCategory::Pe),
+    ('\u{fe5f}', '\u{fe61}', GeneralCategory::Po),
+    ('\u{fe62}', '\u{fe62}', GeneralCategory::Sm),
+    ('\u{fe63}', '\u{fe63}', GeneralCategory::Pd),
+    ('\u{fe64}', '\u{fe66}', GeneralCategory::Sm),
+    ('\u{fe68}', '\u{fe68}', GeneralCategory::Po),
+    ('\u{fe69}', '\u{fe69}', GeneralCategory::Sc),
+    ('\u{fe6a}', '\u{fe6b}', GeneralCategory::Po),
+    ('\u{fe70}', '\u{fe74}', GeneralCategory::Lo),
+    ('\u{fe76}', '\u{fefc}', GeneralCategory::Lo),
+    ('\u{feff}', '\u{feff}', GeneralCategory::Cf),
+    ('\u{ff01}', '\u{ff03}', GeneralCategory::Po),
+    ('\u{ff04}', '\u{ff04}', GeneralCategory::Sc),
+    ('\u{ff05}', '\u{ff07}', GeneralCategory::Po),
+    ('\u{ff08}', '\u{ff08}', GeneralCategory::Ps),
+    ('\u{ff09}', '\u{ff09}', GeneralCategory::Pe),
+    ('\u{ff0a}', '\u{ff0a}', GeneralCategory::Po),
+    ('\u{ff0b}', '\u{ff0b}', GeneralCategory::Sm),
+    ('\u{ff0c}', '\u{ff0c}', GeneralCategory::Po),
+    ('\u{ff0d}', '\u{ff0d}', GeneralCategory::Pd),
+    ('\u{ff0e}', '\u{ff0f}', GeneralCategory::Po),
+    ('\u{ff10}', '\u{ff19}', GeneralCategory::Nd),
+    ('\u{ff1a}', '\u{ff1b}', GeneralCategory::Po),
+    ('\u{ff1c}', '\u{ff1e}', GeneralCategory::Sm),
+    ('\u{ff1f}', '\u{ff20}', GeneralCategory::Po),
+    ('\u{ff21}', '\u{ff3a}', GeneralCategory::Lu),
+    ('\u{ff3b}', '\u{ff3b}', GeneralCategory::Ps),
+    ('\u{ff3c}', '\u{ff3c}', GeneralCategory::Po),
+    ('\u{ff3d}', '\u{ff3d}', GeneralCategory::Pe),
+    ('\u{ff3e}', '\u{ff3e}', GeneralCategory::Sk),
+    ('\u{ff3f}', '\u{ff3f}', GeneralCategory::Pc),
+    ('\u{ff40}', '\u{ff40}', GeneralCategory::Sk),
+    ('\u{ff41}', '\u{ff5a}', GeneralCategory::Ll),
+    ('\u{ff5b}', '\u{ff5b}', GeneralCategory::Ps),
+    ('\u{ff5c}', '\u{ff5c}', GeneralCategory::Sm),
+    ('\u{ff5d}', '\u{ff5d}', GeneralCategory::Pe),
+    ('\u{ff5e}', '\u{ff5e}', GeneralCategory::Sm),
+    ('\u{ff5f}', '\u{ff5f}', GeneralCategory::Ps),
+    ('\u{ff60}', '\u{ff60}', GeneralCategory::Pe),
+    ('\u{ff61}', '\u{ff61}', GeneralCategory::Po),
+    ('\u{ff62}', '\u{ff62}', GeneralCategory::Ps),
+    ('\u{ff63}', '\u{ff63}', GeneralCategory::Pe),
+    ('\u{ff64}', '\u{ff65}', GeneralCategory::Po),
+    ('\u{ff66}', '\u{ff6f}', GeneralCategory::Lo),
+    ('\u{ff70}', '\u{ff70}', GeneralCategory::Lm),
+    ('\u{ff71}', '\u{ff9d}', GeneralCategory::Lo),
+    ('\u{ff9e}', '\u{ff9f}', GeneralCategory::Lm),
+    ('\u{ffa0}', '\u{ffbe}', GeneralCategory::Lo),
+    ('\u{ffc2}', '\u{ffc7}', GeneralCategory::Lo),
+    ('\u{ffca}', '\u{ffcf}', GeneralCategory::Lo),
+    ('\u{ffd2}', '\u{ffd7}', GeneralCategory::Lo),
+    ('\u{ffda}', '\u{ffdc}', GeneralCategory::Lo),
+    ('\u{ffe0}', '\u{ffe1}', GeneralCategory::Sc),
+    ('\u{ffe2}', '\u{ffe2}', GeneralCategory::Sm),
+    ('\u{ffe3}', '\u{ffe3}', GeneralCategory::Sk),
+    ('\u{ffe4}', '\u{ffe4}', GeneralCategory::So),
+    ('\u{ffe5}', '\u{ffe6}', GeneralCategory::Sc),
+    ('\u{ffe8}', '\u{ffe8}', GeneralCategory::So),
+    ('\u{ffe9}', '\u{ffec}', GeneralCategory::Sm),
+    ('\u{ffed}', '\u{ffee}', GeneralCategory::So),
+    ('\u{fff9}', '\u{fffb}', GeneralCategory::Cf),
+    ('\u{fffc}', '\u{fffd}', GeneralCategory::So),
+    ('\u{10000}', '\u{1000b}', GeneralCategory::Lo),
+    ('\u{1000d}', '\u{10026}', GeneralCategory::Lo),
+    ('\u{10028}', '\u{1003a}', GeneralCategory::Lo),
+    ('\u{1003c}', '\u{1003d}', GeneralCategory::Lo),
+    ('\u{1003f}', '\u{1004d}', GeneralCategory::Lo),
+    ('\u{10050}', '\u{1005d}', GeneralCategory::Lo),
+    ('\u{10080}', '\u{100fa}', GeneralCategory::Lo),
+    ('\u{10100}', '\u{10102}', GeneralCategory::Po),
+    ('\u{10107}', '\u{10133}', GeneralCategory::No),
+    ('\u{10137}', '\u{1013f}', GeneralCategory::So),
+    ('\u{10140}', '\u{10174}', GeneralCategory::Nl),
+    ('\u{10175}', '\u{10178}', GeneralCategory::No),
+    ('\u{10179}', '\u{10189}', GeneralCategory::So),
+    ('\u{1018a}', '\u{1018b}', GeneralCategory::No),
+    ('\u{1018c}', '\u{1018e}', GeneralCategory::So),
+    ('\u{10190}', '\u{1019c}', GeneralCategory::So),
+    ('\u{101a0}', '\u{101a0}', GeneralCategory::So),
+    ('\u{101d0}', '\u{101fc}', GeneralCategory::So),
+    ('\u{101fd}', '\u{101fd}', GeneralCategory::Mn),
+    ('\u{10280}', '\u{1029c}', GeneralCategory::Lo),
+    ('\u{102a0}', '\u{102d0}', GeneralCategory::Lo),
+    ('\u{102e0}', '\u{102e0}', GeneralCategory::Mn),
+    ('\u{102e1}', '\u{102fb}', GeneralCategory::No),
+    ('\u{10300}', '\u{1031f}', GeneralCategory::Lo),
+    ('\u{10320}', '\u{10323}', GeneralCategory::No),
+    ('\u{1032d}', '\u{10340}', GeneralCategory::Lo),
+    ('\u{10341}', '\u{10341}', GeneralCategory::Nl),
+    ('\u{10342}', '\u{10349}', GeneralCategory::Lo),
+    ('\u{1034a}', '\u{1034a}', GeneralCategory::Nl),
+    ('\u{10350}', '\u{10375}', GeneralCategory::Lo),
+    ('\u{10376}', '\u{1037a}', GeneralCategory::Mn),
+    ('\u{10380}', '\u{1039d}', GeneralCategory::Lo),
+    ('\u{1039f}', '\u{1039f}', GeneralCategory::Po),
+    ('\u{103a0}', '\u{103c3}', GeneralCategory::Lo),
+    ('\u{103c8}', '\u{103cf}', GeneralCategory::Lo),
+    ('\u{103d0}', '\u{103d0}', GeneralCategory::Po),
+    ('\u{103d1}', '\u{103d5}', GeneralCategory::Nl),
+    ('\u{10400}', '\u{10427}', GeneralCategory::Lu),
+    ('\u{10428}', '\u{1044f}', GeneralCategory::Ll),
+    ('\u{10450}', '\u{1049d}', GeneralCategory::Lo),
+    ('\u{104a0}', '\u{104a9}', GeneralCategory::Nd),
+    ('\u{104b0}', '\u{104d3}', GeneralCategory::Lu),
+    ('\u{104d8}', '\u{104fb}', GeneralCategory::Ll),
+    ('\u{10500}', '\u{10527}', GeneralCategory::Lo),
+    ('\u{10530}', '\u{10563}', GeneralCategory::Lo),
+    ('\u{1056f}', '\u{1056f}', GeneralCategory::Po),
+    ('\u{10570}', '\u{1057a}', GeneralCategory::Lu),
+    ('\u{1057c}', '\u{1058a}', GeneralCategory::Lu),
+    ('\u{1058c}', '\u{10592}', GeneralCategory::Lu),
+    ('\u{10594}', '\u{10595}', GeneralCategory::Lu),
+    ('\u{10597}', '\u{105a1}', GeneralCategory::Ll),
+    ('\u{105a3}', '\u{105b1}', GeneralCategory::Ll),
+    ('\u{105b3}', '\u{105b9}', GeneralCategory::Ll),
+    ('\u{105bb}', '\u{105bc}', GeneralCategory::Ll),
+    ('\u{105c0}', '\u{105f3}', GeneralCategory::Lo),
+    ('\u{10600}', '\u{10736}', GeneralCategory::Lo),
+    ('\u{10740}', '\u{10755}', GeneralCategory::Lo),
+    ('\u{10760}', '\u{10767}', GeneralCategory::Lo),
+    ('\u{10780}', '\u{10785}', GeneralCategory::Lm),
+    ('\u{10787}', '\u{107b0}', GeneralCategory::Lm),
+    ('\u{107b2}', '\u{107ba}', GeneralCategory::Lm),
+    ('\u{10800}', '\u{10805}', GeneralCategory::Lo),
+    ('\u{10808}', '\u{10808}', GeneralCategory::Lo),
+    ('\u{1080a}', '\u{10835}', GeneralCategory::Lo),
+    ('\u{10837}', '\u{10838}', GeneralCategory::Lo),
+    ('\u{1083c}', '\u{1083c}', GeneralCategory::Lo),
+    ('\u{1083f}', '\u{10855}', GeneralCategory::Lo),
+    ('\u{10857}', '\u{10857}', GeneralCategory::Po),
+    ('\u{10858}', '\u{1085f}', GeneralCategory::No),
+    ('\u{10860}', '\u{10876}', GeneralCategory::Lo),
+    ('\u{10877}', '\u{10878}', GeneralCategory::So),
+    ('\u{10879}', '\u{1087f}', GeneralCategory::No),
+    ('\u{10880}', '\u{1089e}', GeneralCategory::Lo),
+    ('\u{108a7}', '\u{108af}', GeneralCategory::No),
+    ('\u{108e0}', '\u{108f2}', GeneralCategory::Lo),
+    ('\u{108f4}', '\u{108f5}', GeneralCategory::Lo),
+    ('\u{108fb}', '\u{108ff}', GeneralCategory::No),
+    ('\u{10900}', '\u{10915}', GeneralCategory::Lo),
+    ('\u{10916}', '\u{1091b}', GeneralCategory::No),
+    ('\u{1091f}', '\u{1091f}', GeneralCategory::Po),
+    ('\u{10920}', '\u{10939}', GeneralCategory::Lo),
+    ('\u{1093f}', '\u{1093f}', GeneralCategory::Po),
+    ('\u{10940}', '\u{10959}', GeneralCategory::Lo),
+    ('\u{10980}', '\u{109b7}', GeneralCategory::Lo),
+    ('\u{109bc}', '\u{109bd}', GeneralCategory::No),
+    ('\u{109be}', '\u{109bf}', GeneralCategory::Lo),
+    ('\u{109c0}', '\u{109cf}', GeneralCategory::No),
+    ('\u{109d2}', '\u{109ff}', GeneralCategory::No),
+    ('\u{10a00}', '\u{10a00}', GeneralCategory::Lo),
+    ('\u{10a01}', '\u{10a03}', GeneralCategory::Mn),
+    ('\u{10a05}', '\u{10a06}', GeneralCategory::Mn),
+    ('\u{10a0c}', '\u{10a0f}', GeneralCategory::Mn),
+    ('\u{10a10}', '\u{10a13}', GeneralCategory::Lo),
+    ('\u{10a15}', '\u{10a17}', GeneralCategory::Lo),
+    ('\u{10a19}', '\u{10a35}', GeneralCategory::Lo),
+    ('\u{10a38}', '\u{10a3a}', GeneralCategory::Mn),
+    ('\u{10a3f}', '\u{10a3f}', GeneralCategory::Mn),
+    ('\u{10a40}', '\u{10a48}', GeneralCategory::No),
+    ('\u{10a50}', '\u{10a58}', GeneralCategory::Po),
+    ('\u{10a60}', '\u{10a7c}', GeneralCategory::Lo),
+    ('\u{10a7d}', '\u{10a7e}', GeneralCategory::No),
+    ('\u{10a7f}', '\u{10a7f}', GeneralCategory::Po),
+    ('\u{10a80}', '\u{10a9c}', GeneralCategory::Lo),
+    ('\u{10a9d}', '\u{10a9f}', GeneralCategory::No),
+    ('\u{10ac0}', '\u{10ac7}', GeneralCategory::Lo),
+    ('\u{10ac8}', '\u{10ac8}', GeneralCategory::So),
+    ('\u{10ac9}', '\u{10ae4}', GeneralCategory::Lo),
+    ('\u{10ae5}', '\u{10ae6}', GeneralCategory::Mn),
+    ('\u{10aeb}', '\u{10aef}', GeneralCategory::No),
+    ('\u{10af0}', '\u{10af6}', GeneralCategory::Po),
+    ('\u{10b00}', '\u{10b35}', GeneralCategory::Lo),
+    ('\u{10b39}', '\u{10b3f}', GeneralCategory::Po),
+    ('\u{10b40}', '\u{10b55}', GeneralCategory::Lo),
+    ('\u{10b58}', '\u{10b5f}', GeneralCategory::No),
+    ('\u{10b60}', '\u{10b72}', GeneralCategory::Lo),
+    ('\u{10b78}', '\u{10b7f}', GeneralCategory::No),
+    ('\u{10b80}', '\u{10b91}', GeneralCategory::Lo),
+    ('\u{10b99}', '\u{10b9c}', GeneralCategory::Po),
+    ('\u{10ba9}', '\u{10baf}', GeneralCategory::No),
+    ('\u{10c00}', '\u{10c48}', GeneralCategory::Lo),
+    ('\u{10c80}', '\u{10cb2}', GeneralCategory::Lu),
+    ('\u{10cc0}', '\u{10cf2}', GeneralCategory::Ll),
+    ('\u{10cfa}', '\u{10cff}', GeneralCategory::No),
+    ('\u{10d00}', '\u{10d23}', GeneralCategory::Lo),
+    ('\u{10d24}', '\u{10d27}', GeneralCategory::Mn),
+    ('\u{10d30}', '\u{10d39}', GeneralCategory::Nd),
+    ('\u{10d40}', '\u{10d49}', GeneralCategory::Nd),
+    ('\u{10d4a}', '\u{10d4d}', GeneralCategory::Lo),
+    ('\u{10d4e}', '\u{10d4e}', GeneralCategory::Lm),
+    ('\u{10d4f}', '\u{10d4f}', GeneralCategory::Lo),
+    ('\u{10d50}', '\u{10d65}', GeneralCategory::Lu),
+    ('\u{10d69}', '\u{10d6d}', GeneralCategory::Mn),
+    ('\u{10d6e}', '\u{10d6e}', GeneralCategory::Pd),
+    ('\u{10d6f}', '\u{10d6f}', GeneralCategory::Lm),
+    ('\u{10d70}', '\u{10d85}', GeneralCategory::Ll),
+    ('\u{10d8e}', '\u{10d8f}', GeneralCategory::Sm),
+    ('\u{10e60}', '\u{10e7e}', GeneralCategory::No),
+    ('\u{10e80}', '\u{10ea9}', GeneralCategory::Lo),
+    ('\u{10eab}', '\u{10eac}', GeneralCategory::Mn),
+    ('\u{10ead}', '\u{10ead}', GeneralCategory::Pd),
+    ('\u{10eb0}', '\u{10eb1}', GeneralCategory::Lo),
+    ('\u{10ec2}', '\u{10ec4}', GeneralCategory::Lo),
+    ('\u{10ec5}', '\u{10ec5}', GeneralCategory::Lm),
+    ('\u{10ec6}', '\u{10ec7}', GeneralCategory::Lo),
+    ('\u{10ed0}', '\u{10ed0}', GeneralCategory::Po),
+    ('\u{10ed1}', '\u{10ed8}', GeneralCategory::So),
+    ('\u{10efa}', '\u{10eff}', GeneralCategory::Mn),
+    ('\u{10f00}', '\u{10f1c}', GeneralCategory::Lo),
+    ('\u{10f1d}', '\u{10f26}', GeneralCategory::No),
+    ('\u{10f27}', '\u{10f27}', GeneralCategory::Lo),
+    ('\u{10f30}', '\u{10f45}', GeneralCategory::Lo),
+    ('\u{10f46}', '\u{10f50}', GeneralCategory::Mn),
+    ('\u{10f51}', '\u{10f54}', GeneralCategory::No),
+    ('\u{10f55}', '\u{10f59}', GeneralCategory::Po),
+    ('\u{10f70}', '\u{10f81}', GeneralCategory::Lo),
+    ('\u{10f82}', '\u{10f85}', GeneralCategory::Mn),
+    ('\u{10f86}', '\u{10f89}', GeneralCategory::Po),
+    ('\u{10fb0}', '\u{10fc4}', GeneralCategory::Lo),
+    ('\u{10fc5}', '\u{10fcb}', GeneralCategory::No),
+    ('\u{10fe0}', '\u{10ff6}', GeneralCategory::Lo),
+    ('\u{11000}', '\u{11000}', GeneralCategory::Mc),
+    ('\u{11001}', '\u{11001}', GeneralCategory::Mn),
+    ('\u{11002}', '\u{11002}', GeneralCategory::Mc),
+    ('\u{11003}', '\u{11037}', GeneralCategory::Lo),
+    ('\u{11038}', '\u{11046}', GeneralCategory::Mn),
+    ('\u{11047}', '\u{1104d}', GeneralCategory::Po),
+    ('\u{11052}', '\u{11065}', GeneralCategory::No),
+    ('\u{11066}', '\u{1106f}', GeneralCategory::Nd),
+    ('\u{11070}', '\u{11070}', GeneralCategory::Mn),
+    ('\u{11071}', '\u{11072}', GeneralCategory::Lo),
+    ('\u{11073}', '\u{11074}', GeneralCategory::Mn),
+    ('\u{11075}', '\u{11075}', GeneralCategory::Lo),
+    ('\u{1107f}', '\u{11081}', GeneralCategory::Mn),
+    ('\u{11082}', '\u{11082}', GeneralCategory::Mc),
+    ('\u{11083}', '\u{110af}', GeneralCategory::Lo),
+    ('\u{110b0}', '\u{110b2}', GeneralCategory::Mc),
+    ('\u{110b3}', '\u{110b6}', GeneralCategory::Mn),
+    ('\u{110b7}', '\u{110b8}', GeneralCategory::Mc),
+    ('\u{110b9}', '\u{110ba}', GeneralCategory::Mn),
+    ('\u{110bb}', '\u{110bc}', GeneralCategory::Po),
+    ('\u{110bd}', '\u{110bd}', GeneralCategory::Cf),
+    ('\u{110be}', '\u{110c1}', GeneralCategory::Po),
+    ('\u{110c2}', '\u{110c2}', GeneralCategory::Mn),
+    ('\u{110cd}', '\u{110cd}', GeneralCategory::Cf),
+    ('\u{110d0}', '\u{110e8}', GeneralCategory::Lo),
+    ('\u{110f0}', '\u{110f9}', GeneralCategory::Nd),
+    ('\u{11100}', '\u{11102}', GeneralCategory::Mn),
+    ('\u{11103}', '\u{11126}', GeneralCategory::Lo),
+    ('\u{11127}', '\u{1112b}', GeneralCategory::Mn),
+    ('\u{1112c}', '\u{1112c}', GeneralCategory::Mc),
+    ('\u{1112d}', '\u{11134}', GeneralCategory::Mn),
+    ('\u{11136}', '\u{1113f}', GeneralCategory::Nd),
+    ('\u{11140}', '\u{11143}', GeneralCategory::Po),
+    ('\u{11144}', '\u{11144}', GeneralCategory::Lo),
+    ('\u{11145}', '\u{11146}', GeneralCategory::Mc),
+    ('\u{11147}', '\u{11147}', GeneralCategory::Lo),
+    ('\u{11150}', '\u{11172}', GeneralCategory::Lo),
+    ('\u{11173}', '\u{11173}', GeneralCategory::Mn),
+    ('\u{11174}', '\u{11175}', GeneralCategory::Po),
+    ('\u{11176}', '\u{11176}', GeneralCategory::Lo),
+    ('\u{11180}', '\u{11181}', GeneralCategory::Mn),
+    ('\u{11182}', '\u{11182}', GeneralCategory::Mc),
+    ('\u{11183}', '\u{111b2}', GeneralCategory::Lo),
+    ('\u{111b3}', '\u{111b5}', GeneralCategory::Mc),
+    ('\u{111b6}', '\u{111be}', GeneralCategory::Mn),
+    ('\u{111bf}', '\u{111c0}', GeneralCategory::Mc),
+    ('\u{111c1}', '\u{111c4}', GeneralCategory::Lo),
+    ('\u{111c5}', '\u{111c8}', GeneralCategory::Po),
+    ('\u{111c9}', '\u{111cc}', GeneralCategory::Mn),
+    ('\u{111cd}', '\u{111cd}', GeneralCategory::Po),
+    ('\u{111ce}', '\u{111ce}', GeneralCategory::Mc),
+    ('\u{111cf}', '\u{111cf}', GeneralCategory::Mn),
+    ('\u{111d0}', '\u{111d9}', GeneralCategory::Nd),
+    ('\u{111da}', '\u{111da}', GeneralCategory::Lo),
+    ('\u{111db}', '\u{111db}', GeneralCategory::Po),
+    ('\u{111dc}', '\u{111dc}', GeneralCategory::Lo),
+    ('\u{111dd}', '\u{111df}', GeneralCategory::Po),
+    ('\u{111e1}', '\u{111f4}', GeneralCategory::No),
+    ('\u{11200}', '\u{11211}', GeneralCategory::Lo),
+    ('\u{11213}', '\u{1122b}', GeneralCategory::Lo),
+    ('\u{1122c}', '\u{1122e}', GeneralCategory::Mc),
+    ('\u{1122f}', '\u{11231}', GeneralCategory::Mn),
+    ('\u{11232}', '\u{11233}', GeneralCategory::Mc),
+    ('\u{11234}', '\u{11234}', GeneralCategory::Mn),
+    ('\u{11235}', '\u{11235}', GeneralCategory::Mc),
+    ('\u{11236}', '\u{11237}', GeneralCategory::Mn),
+    ('\u{11238}', '\u{1123d}', GeneralCategory::Po),
+    ('\u{1123e}', '\u{1123e}', GeneralCategory::Mn),
+    ('\u{1123f}', '\u{11240}', GeneralCategory::Lo),
+    ('\u{11241}', '\u{11241}', GeneralCategory::Mn),
+    ('\u{11280}', '\u{11286}', GeneralCategory::Lo),
+    ('\u{11288}', '\u{11288}', GeneralCategory::Lo),
+    ('\u{1128a}', '\u{1128d}', GeneralCategory::Lo),
+    ('\u{1128f}', '\u{1129d}', GeneralCategory::Lo),
+    ('\u{1129f}', '\u{112a8}', GeneralCategory::Lo),
+    ('\u{112a9}', '\u{112a9}', GeneralCategory::Po),
+    ('\u{112b0}', '\u{112de}', GeneralCategory::Lo),
+    ('\u{112df}', '\u{112df}', GeneralCategory::Mn),
+    ('\u{112e0}', '\u{112e2}', GeneralCategory::Mc),
+    ('\u{112e3}', '\u{112ea}', GeneralCategory::Mn),
+    ('\u{112f0}', '\u{112f9}', GeneralCategory::Nd),
+    ('\u{11300}', '\u{11301}', GeneralCategory::Mn),
+    ('\u{11302}', '\u{11303}', GeneralCategory::Mc),
+    ('\u{11305}', '\u{1130c}', GeneralCategory::Lo),
+    ('\u{1130f}', '\u{11310}', GeneralCategory::Lo),
+    ('\u{11313}', '\u{11328}', GeneralCategory::Lo),
+    ('\u{1132a}', '\u{11330}', GeneralCategory::Lo),
+    ('\u{11332}', '\u{11333}', GeneralCategory::Lo),
+    ('\u{11335}', '\u{11339}', GeneralCategory::Lo),
+    ('\u{1133b}', '\u{1133c}', GeneralCategory::Mn),
+    ('\u{1133d}', '\u{1133d}', GeneralCategory::Lo),
+    ('\u{1133e}', '\u{1133f}', GeneralCategory::Mc),
+    ('\u{11340}', '\u{11340}', GeneralCategory::Mn),
+    ('\u{11341}', '\u{11344}', GeneralCategory::Mc),
+    ('\u{11347}', '\u{11348}', GeneralCategory::Mc),
+    ('\u{1134b}', '\u{1134d}', GeneralCategory::Mc),
+    ('\u{11350}', '\u{11350}', GeneralCategory::Lo),
+    ('\u{11357}', '\u{11357}', GeneralCategory::Mc),
+    ('\u{1135d}', '\u{11361}', GeneralCategory::Lo),
+    ('\u{11362}', '\u{11363}', GeneralCategory::Mc),
+    ('\u{11366}', '\u{1136c}', GeneralCategory::Mn),
+    ('\u{11370}', '\u{11374}', GeneralCategory::Mn),
+    ('\u{11380}', '\u{11389}', GeneralCategory::Lo),
+    ('\u{1138b}', '\u{1138b}', GeneralCategory::Lo),
+    ('\u{1138e}', '\u{1138e}', GeneralCategory::Lo),
+    ('\u{11390}', '\u{113b5}', GeneralCategory::Lo),
+    ('\u{113b7}', '\u{113b7}', GeneralCategory::Lo),
+    ('\u{113b8}', '\u{113ba}', GeneralCategory::Mc),
+    ('\u{113bb}', '\u{113c0}', GeneralCategory::Mn),
+    ('\u{113c2}', '\u{113c2}', GeneralCategory::Mc),
+    ('\u{113c5}', '\u{113c5}', GeneralCategory::Mc),
+    ('\u{113c7}', '\u{113ca}', GeneralCategory::Mc),
+    ('\u{113cc}', '\u{113cd}', GeneralCategory::Mc),
+    ('\u{113ce}', '\u{113ce}', GeneralCategory::Mn),
+    ('\u{113cf}', '\u{113cf}', GeneralCategory::Mc),
+    ('\u{113d0}', '\u{113d0}', GeneralCategory::Mn),
+    ('\u{113d1}', '\u{113d1}', GeneralCategory::Lo),
+    ('\u{113d2}', '\u{113d2}', GeneralCategory::Mn),
+    ('\u{113d3}', '\u{113d3}', GeneralCategory::Lo),
+    ('\u{113d4}', '\u{113d5}', GeneralCategory::Po),
+    ('\u{113d7}', '\u{113d8}', GeneralCategory::Po),
+    ('\u{113e1}', '\u{113e2}', GeneralCategory::Mn),
+    ('\u{11400}', '\u{11434}', GeneralCategory::Lo),
+    ('\u{11435}', '\u{11437}', GeneralCategory::Mc),
+    ('\u{11438}', '\u{1143f}', GeneralCategory::Mn),
+    ('\u{11440}', '\u{11441}', GeneralCategory::Mc),
+    ('\u{11442}', '\u{11444}', GeneralCategory::Mn),
+    ('\u{11445}', '\u{11445}', GeneralCategory::Mc),
+    ('\u{11446}', '\u{11446}', GeneralCategory::Mn),
+    ('\u{11447}', '\u{1144a}', GeneralCategory::Lo),
+    ('\u{1144b}', '\u{1144f}', GeneralCategory::Po),
+    ('\u{11450}', '\u{11459}', GeneralCategory::Nd),
+    ('\u{1145a}', '\u{1145b}', GeneralCategory::Po),
+    ('\u{1145d}', '\u{1145d}', GeneralCategory::Po),
+    ('\u{1145e}', '\u{1145e}', GeneralCategory::Mn),
+    ('\u{1145f}', '\u{11461}', GeneralCategory::Lo),
+    ('\u{11480}', '\u{114af}', GeneralCategory::Lo),
+    ('\u{114b0}', '\u{114b2}', GeneralCategory::Mc),
+    ('\u{114b3}', '\u{114b8}', GeneralCategory::Mn),
+    ('\u{114b9}', '\u{114b9}', GeneralCategory::Mc),
+    ('\u{114ba}', '\u{114ba}', GeneralCategory::Mn),
+    ('\u{114bb}', '\u{114be}', GeneralCategory::Mc),
+    ('\u{114bf}', '\u{114c0}', GeneralCategory::Mn),
+    ('\u{114c1}', '\u{114c1}', GeneralCategory::Mc),
+    ('\u{114c2}', '\u{114c3}', GeneralCategory::Mn),
+    ('\u{114c4}', '\u{114c5}', GeneralCategory::Lo),
+    ('\u{114c6}', '\u{114c6}', GeneralCategory::Po),
+    ('\u{114c7}', '\u{114c7}', GeneralCategory::Lo),
+    ('\u{114d0}', '\u{114d9}', GeneralCategory::Nd),
+    ('\u{11580}', '\u{115ae}', GeneralCategory::Lo),
+    ('\u{115af}', '\u{115b1}', GeneralCategory::Mc),
+    ('\u{115b2}', '\u{115b5}', GeneralCategory::Mn),
+    ('\u{115b8}', '\u{115bb}', GeneralCategory::Mc),
+    ('\u{115bc}', '\u{115bd}', GeneralCategory::Mn),
+    ('\u{115be}', '\u{115be}', GeneralCategory::Mc),
+    ('\u{115bf}', '\u{115c0}', GeneralCategory::Mn),
+    ('\u{115c1}', '\u{115d7}', GeneralCategory::Po),
+    ('\u{115d8}', '\u{115db}', GeneralCategory::Lo),
+    ('\u{115dc}', '\u{115dd}', GeneralCategory::Mn),
+    ('\u{11600}', '\u{1162f}', GeneralCategory::Lo),
+    ('\u{11630}', '\u{11632}', GeneralCategory::Mc),
+    ('\u{11633}', '\u{1163a}', GeneralCategory::Mn),
+    ('\u{1163b}', '\u{1163c}', GeneralCategory::Mc),
+    ('\u{1163d}', '\u{1163d}', GeneralCategory::Mn),
+    ('\u{1163e}', '\u{1163e}', GeneralCategory::Mc),
+    ('\u{1163f}', '\u{11640}', GeneralCategory::Mn),
+    ('\u{11641}', '\u{11643}', GeneralCategory::Po),
+    ('\u{11644}', '\u{11644}', GeneralCategory::Lo),
+    ('\u{11650}', '\u{11659}', GeneralCategory::Nd),
+    ('\u{11660}', '\u{1166c}', GeneralCategory::Po),
+    ('\u{11680}', '\u{116aa}', GeneralCategory::Lo),
+    ('\u{116ab}', '\u{116ab}', GeneralCategory::Mn),
+    ('\u{116ac}', '\u{116ac}', GeneralCategory::Mc),
+    ('\u{116ad}', '\u{116ad}', GeneralCategory::Mn),
+    ('\u{116ae}', '\u{116af}', GeneralCategory::Mc),
+    ('\u{116b0}', '\u{116b5}', GeneralCategory::Mn),
+    ('\u{116b6}', '\u{116b6}', GeneralCategory::Mc),
+    ('\u{116b7}', '\u{116b7}', GeneralCategory::Mn),
+    ('\u{116b8}', '\u{116b8}', GeneralCategory::Lo),
+    ('\u{116b9}', '\u{116b9}', GeneralCategory::Po),
+    ('\u{116c0}', '\u{116c9}', GeneralCategory::Nd),
+    ('\u{116d0}', '\u{116e3}', GeneralCategory::Nd),
+    ('\u{11700}', '\u{1171a}', GeneralCategory::Lo),
+    ('\u{1171d}', '\u{1171d}', GeneralCategory::Mn),
+    ('\u{1171e}', '\u{1171e}', GeneralCategory::Mc),
+    ('\u{1171f}', '\u{1171f}', GeneralCategory::Mn),
+    ('\u{11720}', '\u{11721}', GeneralCategory::Mc),
+    ('\u{11722}', '\u{11725}', GeneralCategory::Mn),
+    ('\u{11726}', '\u{11726}', GeneralCategory::Mc),
+    ('\u{11727}', '\u{1172b}', GeneralCategory::Mn),
+    ('\u{11730}', '\u{11739}', GeneralCategory::Nd),
+    ('\u{1173a}', '\u{1173b}', GeneralCategory::No),
+    ('\u{1173c}', '\u{1173e}', GeneralCategory::Po),
+    ('\u{1173f}', '\u{1173f}', GeneralCategory::So),
+    ('\u{11740}', '\u{11746}', GeneralCategory::Lo),
+    ('\u{11800}', '\u{1182b}', GeneralCategory::Lo),
+    ('\u{1182c}', '\u{1182e}', GeneralCategory::Mc),
+    ('\u{1182f}', '\u{11837}', GeneralCategory::Mn),
+    ('\u{11838}', '\u{11838}', GeneralCategory::Mc),
+    ('\u{11839}', '\u{1183a}', GeneralCategory::Mn),
+    ('\u{1183b}', '\u{1183b}', GeneralCategory::Po),
+    ('\u{118a0}', '\u{118bf}', GeneralCategory::Lu),
+    ('\u{118c0}', '\u{118df}', GeneralCategory::Ll),
+    ('\u{118e0}', '\u{118e9}', GeneralCategory::Nd),
+    ('\u{118ea}', '\u{118f2}', GeneralCategory::No),
+    ('\u{118ff}', '\u{11906}', GeneralCategory::Lo),
+    ('\u{11909}', '\u{11909}', GeneralCategory::Lo),
+    ('\u{1190c}', '\u{11913}', GeneralCategory::Lo),
+    ('\u{11915}', '\u{11916}', GeneralCategory::Lo),
+    ('\u{11918}', '\u{1192f}', GeneralCategory::Lo),
+    ('\u{11930}', '\u{11935}', GeneralCategory::Mc),
+    ('\u{11937}', '\u{11938}', GeneralCategory::Mc),
+    ('\u{1193b}', '\u{1193c}', GeneralCategory::Mn),
+    ('\u{1193d}', '\u{1193d}', GeneralCategory::Mc),
+    ('\u{1193e}', '\u{1193e}', GeneralCategory::Mn),
+    ('\u{1193f}', '\u{1193f}', GeneralCategory::Lo),
+    ('\u{11940}', '\u{11940}', GeneralCategory::Mc),
+    ('\u{11941}', '\u{11941}', GeneralCategory::Lo),
+    ('\u{11942}', '\u{11942}', GeneralCategory::Mc),
+    ('\u{11943}', '\u{11943}', GeneralCategory::Mn),
+    ('\u{11944}', '\u{11946}', GeneralCategory::Po),
+    ('\u{11950}', '\u{11959}', GeneralCategory::Nd),
+    ('\u{119a0}', '\u{119a7}', GeneralCategory::Lo),
+    ('\u{119aa}', '\u{119d0}', GeneralCategory::Lo),
+    ('\u{119d1}', '\u{119d3}', GeneralCategory::Mc),
+    ('\u{119d4}', '\u{119d7}', GeneralCategory::Mn),
+    ('\u{119da}', '\u{119db}', GeneralCategory::Mn),
+    ('\u{119dc}', '\u{119df}', GeneralCategory::Mc),
+    ('\u{119e0}', '\u{119e0}', GeneralCategory::Mn),
+    ('\u{119e1}', '\u{119e1}', GeneralCategory::Lo),
+    ('\u{119e2}', '\u{119e2}', GeneralCategory::Po),
+    ('\u{119e3}', '\u{119e3}', GeneralCategory::Lo),
+    ('\u{119e4}', '\u{119e4}', GeneralCategory::Mc),
+    ('\u{11a00}', '\u{11a00}', GeneralCategory::Lo),
+    ('\u{11a01}', '\u{11a0a}', GeneralCategory::Mn),
+    ('\u{11a0b}', '\u{11a32}', GeneralCategory::Lo),
+    ('\u{11a33}', '\u{11a38}', GeneralCategory::Mn),
+    ('\u{11a39}', '\u{11a39}', GeneralCategory::Mc),
+    ('\u{11a3a}', '\u{11a3a}', GeneralCategory::Lo),
+    ('\u{11a3b}', '\u{11a3e}', GeneralCategory::Mn),
+    ('\u{11a3f}', '\u{11a46}', GeneralCategory::Po),
+    ('\u{11a47}', '\u{11a47}', GeneralCategory::Mn),
+    ('\u{11a50}', '\u{11a50}', GeneralCategory::Lo),
+    ('\u{11a51}', '\u{11a56}', GeneralCategory::Mn),
+    ('\u{11a57}', '\u{11a58}', GeneralCategory::Mc),
+    ('\u{11a59}', '\u{11a5b}', GeneralCategory::Mn),
+    ('\u{11a5c}', '\u{11a89}', GeneralCategory::Lo),
+    ('\u{11a8a}', '\u{11a96}', GeneralCategory::Mn),
+    ('\u{11a97}', '\u{11a97}', GeneralCategory::Mc),
+    ('\u{11a98}', '\u{11a99}', GeneralCategory::Mn),
+    ('\u{11a9a}', '\u{11a9c}', GeneralCategory::Po),
+    ('\u{11a9d}', '\u{11a9d}', GeneralCategory::Lo),
+    ('\u{11a9e}', '\u{11aa2}', GeneralCategory::Po),
+    ('\u{11ab0}', '\u{11af8}', GeneralCategory::Lo),
+    ('\u{11b00}', '\u{11b09}', GeneralCategory::Po),
+    ('\u{11b60}', '\u{11b60}', GeneralCategory::Mn),
+    ('\u{11b61}', '\u{11b61}', GeneralCategory::Mc),
+    ('\u{11b62}', '\u{11b64}', GeneralCategory::Mn),
+    ('\u{11b65}', '\u{11b65}', GeneralCategory::Mc),
+    ('\u{11b66}', '\u{11b66}', GeneralCategory::Mn),
+    ('\u{11b67}', '\u{11b67}', GeneralCategory::Mc),
+    ('\u{11bc0}', '\u{11be0}', GeneralCategory::Lo),
+    ('\u{11be1}', '\u{11be1}', GeneralCategory::Po),
+    ('\u{11bf0}', '\u{11bf9}', GeneralCategory::Nd),
+    ('\u{11c00}', '\u{11c08}', GeneralCategory::Lo),
+    ('\u{11c0a}', '\u{11c2e}', GeneralCategory::Lo),
+    ('\u{11c2f}', '\u{11c2f}', GeneralCategory::Mc),
+    ('\u{11c30}', '\u{11c36}', GeneralCategory::Mn),
+    ('\u{11c38}', '\u{11c3d}', GeneralCategory::Mn),
+    ('\u{11c3e}', '\u{11c3e}', GeneralCategory::Mc),
+    ('\u{11c3f}', '\u{11c3f}', GeneralCategory::Mn),
+    ('\u{11c40}', '\u{11c40}', GeneralCategory::Lo),
+    ('\u{11c41}', '\u{11c45}', GeneralCategory::Po),
+    ('\u{11c50}', '\u{11c59}', GeneralCategory::Nd),
+    ('\u{11c5a}', '\u{11c6c}', GeneralCategory::No),
+    ('\u{11c70}', '\u{11c71}', GeneralCategory::Po),
+    ('\u{11c72}', '\u{11c8f}', GeneralCategory::Lo),
+    ('\u{11c92}', '\u{11ca7}', GeneralCategory::Mn),
+    ('\u{11ca9}', '\u{11ca9}', GeneralCategory::Mc),
+    ('\u{11caa}', '\u{11cb0}', GeneralCategory::Mn),
+    ('\u{11cb1}', '\u{11cb1}', GeneralCategory::Mc),
+    ('\u{11cb2}', '\u{11cb3}', GeneralCategory::Mn),
+    ('\u{11cb4}', '\u{11cb4}', GeneralCategory::Mc),
+    ('\u{11cb5}', '\u{11cb6}', GeneralCategory::Mn),
+    ('\u{11d00}', '\u{11d06}', GeneralCategory::Lo),
+    ('\u{11d08}', '\u{11d09}', GeneralCategory::Lo),
+    ('\u{11d0b}', '\u{11d30}', GeneralCategory::Lo),
+    ('\u{11d31}', '\u{11d36}', GeneralCategory::Mn),
+    ('\u{11d3a}', '\u{11d3a}', GeneralCategory::Mn),
+    ('\u{11d3c}', '\u{11d3d}', GeneralCategory::Mn),
+    ('\u{11d3f}', '\u{11d45}', GeneralCategory::Mn),
+    ('\u{11d46}', '\u{11d46}', GeneralCategory::Lo),
+    ('\u{11d47}', '\u{11d47}', GeneralCategory::Mn),
+    ('\u{11d50}', '\u{11d59}', GeneralCategory::Nd),
+    ('\u{11d60}', '\u{11d65}', GeneralCategory::Lo),
+    ('\u{11d67}', '\u{11d68}', GeneralCategory::Lo),
+    ('\u{11d6a}', '\u{11d89}', GeneralCategory::Lo),
+    ('\u{11d8a}', '\u{11d8e}', GeneralCategory::Mc),
+    ('\u{11d90}', '\u{11d91}', GeneralCategory::Mn),
+    ('\u{11d93}', '\u{11d94}', GeneralCategory::Mc),
+    ('\u{11d95}', '\u{11d95}', GeneralCategory::Mn),
+    ('\u{11d96}', '\u{11d96}', GeneralCategory::Mc),
+    ('\u{11d97}', '\u{11d97}', GeneralCategory::Mn),
+    ('\u{11d98}', '\u{11d98}', GeneralCategory::Lo),
+    ('\u{11da0}', '\u{11da9}', GeneralCategory::Nd),
+    ('\u{11db0}', '\u{11dd8}', GeneralCategory::Lo),
+    ('\u{11dd9}', '\u{11dd9}', GeneralCategory::Lm),
+    ('\u{11dda}', '\u{11ddb}', GeneralCategory::Lo),
+    ('\u{11de0}', '\u{11de9}', GeneralCategory::Nd),
+    ('\u{11ee0}', '\u{11ef2}', GeneralCategory::Lo),
+    ('\u{11ef3}', '\u{11ef4}', GeneralCategory::Mn),
+    ('\u{11ef5}', '\u{11ef6}', GeneralCategory::Mc),
+    ('\u{11ef7}', '\u{11ef8}', GeneralCategory::Po),
+    ('\u{11f00}', '\u{11f01}', GeneralCategory::Mn),
+    ('\u{11f02}', '\u{11f02}', GeneralCategory::Lo),
+    ('\u{11f03}', '\u{11f03}', GeneralCategory::Mc),
+    ('\u{11f04}', '\u{11f10}', GeneralCategory::Lo),
+    ('\u{11f12}', '\u{11f33}', GeneralCategory::Lo),
+    ('\u{11f34}', '\u{11f35}', GeneralCategory::Mc),
+    ('\u{11f36}', '\u{11f3a}', GeneralCategory::Mn),
+    ('\u{11f3e}', '\u{11f3f}', GeneralCategory::Mc),
+    ('\u{11f40}', '\u{11f40}', GeneralCategory::Mn),
+    ('\u{11f41}', '\u{11f41}', GeneralCategory::Mc),
+    ('\u{11f42}', '\u{11f42}', GeneralCategory::Mn),
+    ('\u{11f43}', '\u{11f4f}', GeneralCategory::Po),
+    ('\u{11f50}', '\u{11f59}', GeneralCategory::Nd),
+    ('\u{11f5a}', '\u{11f5a}', GeneralCategory::Mn),
+    ('\u{11fb0}', '\u{11fb0}', GeneralCategory::Lo),
+    ('\u{11fc0}', '\u{11fd4}', GeneralCategory::No),
+    ('\u{11fd5}', '\u{11fdc}', GeneralCategory::So),
+    ('\u{11fdd}', '\u{11fe0}', GeneralCategory::Sc),
+    ('\u{11fe1}', '\u{11ff1}', GeneralCategory::So),
+    ('\u{11fff}', '\u{11fff}', GeneralCategory::Po),
+    ('\u{12000}', '\u{12399}', GeneralCategory::Lo),
+    ('\u{12400}', '\u{1246e}', GeneralCategory::Nl),
+    ('\u{12470}', '\u{12474}', GeneralCategory::Po),
+    ('\u{12480}', '\u{12543}', GeneralCategory::Lo),
+    ('\u{12f90}', '\u{12ff0}', GeneralCategory::Lo),
+    ('\u{12ff1}', '\u{12ff2}', GeneralCategory::Po),
+    ('\u{13000}', '\u{1342f}', GeneralCategory::Lo),
+    ('\u{13430}', '\u{1343f}', GeneralCategory::Cf),
+    ('\u{13440}', '\u{13440}', GeneralCategory::Mn),
+    ('\u{13441}', '\u{13446}', GeneralCategory::Lo),
+    ('\u{13447}', '\u{13455}', GeneralCategory::Mn),
+    ('\u{13460}', '\u{143fa}', GeneralCategory::Lo),
+    ('\u{14400}', '\u{14646}', GeneralCategory::Lo),
+    ('\u{16100}', '\u{1611d}', GeneralCategory::Lo),
+    ('\u{1611e}', '\u{16129}', GeneralCategory::Mn),
+    ('\u{1612a}', '\u{1612c}', GeneralCategory::Mc),
+    ('\u{1612d}', '\u{1612f}', GeneralCategory::Mn),
+    ('\u{16130}', '\u{16139}', GeneralCategory::Nd),
+    ('\u{16800}', '\u{16a38}', GeneralCategory::Lo),
+    ('\u{16a40}', '\u{16a5e}', GeneralCategory::Lo),
+    ('\u{16a60}', '\u{16a69}', GeneralCategory::Nd),
+    ('\u{16a6e}', '\u{16a6f}', GeneralCategory::Po),
+    ('\u{16a70}', '\u{16abe}', GeneralCategory::Lo),
+    ('\u{16ac0}', '\u{16ac9}', GeneralCategory::Nd),
+    ('\u{16ad0}', '\u{16aed}', GeneralCategory::Lo),
+    ('\u{16af0}', '\u{16af4}', GeneralCategory::Mn),
+    ('\u{16af5}', '\u{16af5}', GeneralCategory::Po),
+    ('\u{16b00}', '\u{16b2f}', GeneralCategory::Lo),
+    ('\u{16b30}', '\u{16b36}', GeneralCategory::Mn),
+    ('\u{16b37}', '\u{16b3b}', GeneralCategory::Po),
+    ('\u{16b3c}', '\u{16b3f}', GeneralCategory::So),
+    ('\u{16b40}', '\u{16b43}', GeneralCategory::Lm),
+    ('\u{16b44}', '\u{16b44}', GeneralCategory::Po),
+    ('\u{16b45}', '\u{16b45}', GeneralCategory::So),
+    ('\u{16b50}', '\u{16b59}', GeneralCategory::Nd),
+    ('\u{16b5b}', '\u{16b61}', GeneralCategory::No),
+    ('\u{16b63}', '\u{16b77}', GeneralCategory::Lo),
+    ('\u{16b7d}', '\u{16b8f}', GeneralCategory::Lo),
+    ('\u{16d40}', '\u{16d42}', GeneralCategory::Lm),
+    ('\u{16d43}', '\u{16d6a}', GeneralCategory::Lo),
+    ('\u{16d6b}', '\u{16d6c}', GeneralCategory::Lm),
+    ('\u{16d6d}', '\u{16d6f}', GeneralCategory::Po),
+    ('\u{16d70}', '\u{16d79}', GeneralCategory::Nd),
+    ('\u{16e40}', '\u{16e5f}', GeneralCategory::Lu),
+    ('\u{16e60}', '\u{16e7f}', GeneralCategory::Ll),
+    ('\u{16e80}', '\u{16e96}', GeneralCategory::No),
+    ('\u{16e97}', '\u{16e9a}', GeneralCategory::Po),
+    ('\u{16ea0}', '\u{16eb8}', GeneralCategory::Lu),
+    ('\u{16ebb}', '\u{16ed3}', GeneralCategory::Ll),
+    ('\u{16f00}', '\u{16f4a}', GeneralCategory::Lo),
+    ('\u{16f4f}', '\u{16f4f}', GeneralCategory::Mn),
+    ('\u{16f50}', '\u{16f50}', GeneralCategory::Lo),
+    ('\u{16f51}', '\u{16f87}', GeneralCategory::Mc),
+    ('\u{16f8f}', '\u{16f92}', GeneralCategory::Mn),
+    ('\u{16f93}', '\u{16f9f}', GeneralCategory::Lm),
+    ('\u{16fe0}', '\u{16fe1}', GeneralCategory::Lm),
+    ('\u{16fe2}', '\u{16fe2}', GeneralCategory::Po),
+    ('\u{16fe3}', '\u{16fe3}', GeneralCategory::Lm),
+    ('\u{16fe4}', '\u{16fe4}', GeneralCategory::Mn),
+    ('\u{16ff0}', '\u{16ff1}', GeneralCategory::Mc),
+    ('\u{16ff2}', '\u{16ff3}', GeneralCategory::Lm),
+    ('\u{16ff4}', '\u{16ff6}', GeneralCategory::Nl),
+    ('\u{17000}', '\u{18cd5}', GeneralCategory::Lo),
+    ('\u{18cff}', '\u{18d1e}', GeneralCategory::Lo),
+    ('\u{18d80}', '\u{18df2}', GeneralCategory::Lo),
+    ('\u{1aff0}', '\u{1aff3}', GeneralCategory::Lm),
+    ('\u{1aff5}', '\u{1affb}', GeneralCategory::Lm),
+    ('\u{1affd}', '\u{1affe}', GeneralCategory::Lm),
+    ('\u{1b000}', '\u{1b122}', GeneralCategory::Lo),
+    ('\u{1b132}', '\u{1b132}', GeneralCategory::Lo),
+    ('\u{1b150}', '\u{1b152}', GeneralCategory::Lo),
+    ('\u{1b155}', '\u{1b155}', GeneralCategory::Lo),
+    ('\u{1b164}', '\u{1b167}', GeneralCategory::Lo),
+    ('\u{1b170}', '\u{1b2fb}', GeneralCategory::Lo),
+    ('\u{1bc00}', '\u{1bc6a}', GeneralCategory::Lo),
+    ('\u{1bc70}', '\u{1bc7c}', GeneralCategory::Lo),
+    ('\u{1bc80}', '\u{1bc88}', GeneralCategory::Lo),
+    ('\u{1bc90}', '\u{1bc99}', GeneralCategory::Lo),
+    ('\u{1bc9c}', '\u{1bc9c}', GeneralCategory::So),
+    ('\u{1bc9d}', '\u{1bc9e}', GeneralCategory::Mn),
+    ('\u{1bc9f}', '\u{1bc9f}', GeneralCategory::Po),
+    ('\u{1bca0}', '\u{1bca3}', GeneralCategory::Cf),
+    ('\u{1cc00}', '\u{1ccef}', GeneralCategory::So),
+    ('\u{1ccf0}', '\u{1ccf9}', GeneralCategory::Nd),
+    ('\u{1ccfa}', '\u{1ccfc}', GeneralCategory::So),
+    ('\u{1cd00}', '\u{1ceb3}', GeneralCategory::So),
+    ('\u{1ceba}', '\u{1ced0}', GeneralCategory::So),
+    ('\u{1cee0}', '\u{1ceef}', GeneralCategory::So),
+    ('\u{1cef0}', '\u{1cef0}', GeneralCategory::Sm),
+    ('\u{1cf00}', '\u{1cf2d}', GeneralCategory::Mn),
+    ('\u{1cf30}', '\u{1cf46}', GeneralCategory::Mn),
+    ('\u{1cf50}', '\u{1cfc3}', GeneralCategory::So),
+    ('\u{1d000}', '\u{1d0f5}', GeneralCategory::So),
+    ('\u{1d100}', '\u{1d126}', GeneralCategory::So),
+    ('\u{1d129}', '\u{1d164}', GeneralCategory::So),
+    ('\u{1d165}', '\u{1d166}', GeneralCategory::Mc),
+    ('\u{1d167}', '\u{1d169}', GeneralCategory::Mn),
+    ('\u{1d16a}', '\u{1d16c}', GeneralCategory::So),
+    ('\u{1d16d}', '\u{1d172}', GeneralCategory::Mc),
+    ('\u{1d173}', '\u{1d17a}', GeneralCategory::Cf),
+    ('\u{1d17b}', '\u{1d182}', GeneralCategory::Mn),
+    ('\u{1d183}', '\u{1d184}', GeneralCategory::So),
+    ('\u{1d185}', '\u{1d18b}', GeneralCategory::Mn),
+    ('\u{1d18c}', '\u{1d1a9}', GeneralCategory::So),
+    ('\u{1d1aa}', '\u{1d1ad}', GeneralCategory::Mn),
+    ('\u{1d1ae}', '\u{1d1ea}', GeneralCategory::So),
+    ('\u{1d200}', '\u{1d241}', GeneralCategory::So),
+    ('\u{1d242}', '\u{1d244}', GeneralCategory::Mn),
+    ('\u{1d245}', '\u{1d245}', GeneralCategory::So),
+    ('\u{1d2c0}', '\u{1d2d3}', GeneralCategory::No),
+    ('\u{1d2e0}', '\u{1d2f3}', GeneralCategory::No),
+    ('\u{1d300}', '\u{1d356}', GeneralCategory::So),
+    ('\u{1d360}', '\u{1d378}', GeneralCategory::No),
+    ('\u{1d400}', '\u{1d419}', GeneralCategory::Lu),
+    ('\u{1d41a}', '\u{1d433}', GeneralCategory::Ll),
+    ('\u{1d434}', '\u{1d44d}', GeneralCategory::Lu),
+    ('\u{1d44e}', '\u{1d454}', GeneralCategory::Ll),
+    ('\u{1d456}', '\u{1d467}', GeneralCategory::Ll),
+    ('\u{1d468}', '\u{1d481}', GeneralCategory::Lu),
+    ('\u{1d482}', '\u{1d49b}', GeneralCategory::Ll),
+    ('\u{1d49c}', '\u{1d49c}', GeneralCategory::Lu),
+    ('\u{1d49e}', '\u{1d49f}', GeneralCategory::Lu),
+    ('\u{1d4a2}', '\u{1d4a2}', GeneralCategory::Lu),
+    ('\u{1d4a5}', '\u{1d4a6}', GeneralCategory::Lu),
+    ('\u{1d4a9}', '\u{1d4ac}', GeneralCategory::Lu),
+    ('\u{1d4ae}', '\u{1d4b5}', GeneralCategory::Lu),
+    ('\u{1d4b6}', '\u{1d4b9}', GeneralCategory::Ll),
+    ('\u{1d4bb}', '\u{1d4bb}', GeneralCategory::Ll),
+    ('\u{1d4bd}', '\u{1d4c3}', GeneralCategory::Ll),
+    ('\u{1d4c5}', '\u{1d4cf}', GeneralCategory::Ll),
+    ('\u{1d4d0}', '\u{1d4e9}', GeneralCategory::Lu),
+    ('\u{1d4ea}', '\u{1d503}', GeneralCategory::Ll),
+    ('\u{1d504}', '\u{1d505}', GeneralCategory::Lu),
+    ('\u{1d507}', '\u{1d50a}', GeneralCategory::Lu),
+    ('\u{1d50d}', '\u{1d514}', GeneralCategory::Lu),
+    ('\u{1d516}', '\u{1d51c}', GeneralCategory::Lu),
+    ('\u{1d51e}', '\u{1d537}', GeneralCategory::Ll),
+    ('\u{1d538}', '\u{1d539}', GeneralCategory::Lu),
+    ('\u{1d53b}', '\u{1d53e}', GeneralCategory::Lu),
+    ('\u{1d540}', '\u{1d544}', GeneralCategory::Lu),
+    ('\u{1d546}', '\u{1d546}', GeneralCategory::Lu),
+    ('\u{1d54a}', '\u{1d550}', GeneralCategory::Lu),
+    ('\u{1d552}', '\u{1d56b}', GeneralCategory::Ll),
+    ('\u{1d56c}', '\u{1d585}', GeneralCategory::Lu),
+    ('\u{1d586}', '\u{1d59f}', GeneralCategory::Ll),
+    ('\u{1d5a0}', '\u{1d5b9}', GeneralCategory::Lu),
+    ('\u{1d5ba}', '\u{1d5d3}', GeneralCategory::Ll),
+    ('\u{1d5d4}', '\u{1d5ed}', GeneralCategory::Lu),
+    ('\u{1d5ee}', '\u{1d607}', GeneralCategory::Ll),
+    ('\u{1d608}', '\u{1d621}', GeneralCategory::Lu),
+    ('\u{1d622}', '\u{1d63b}', GeneralCategory::Ll),
+    ('\u{1d63c}', '\u{1d655}', GeneralCategory::Lu),
+    ('\u{1d656}', '\u{1d66f}', GeneralCategory::Ll),
+    ('\u{1d670}', '\u{1d689}', GeneralCategory::Lu),
+    ('\u{1d68a}', '\u{1d6a5}', GeneralCategory::Ll),
+    ('\u{1d6a8}', '\u{1d6c0}', GeneralCategory::Lu),
+    ('\u{1d6c1}', '\u{1d6c1}', GeneralCategory::Sm),
+    ('\u{1d6c2}', '\u{1d6da}', GeneralCategory::Ll),
+    ('\u{1d6db}', '\u{1d6db}', GeneralCategory::Sm),
+    ('\u{1d6dc}', '\u{1d6e1}', GeneralCategory::Ll),
+    ('\u{1d6e2}', '\u{1d6fa}', GeneralCategory::Lu),
+    ('\u{1d6fb}', '\u{1d6fb}', GeneralCategory::Sm),
+    ('\u{1d6fc}', '\u{1d714}', GeneralCategory::Ll),
+    ('\u{1d715}', '\u{1d715}', GeneralCategory::Sm),
+    ('\u{1d716}', '\u{1d71b}', GeneralCategory::Ll),
+    ('\u{1d71c}', '\u{1d734}', GeneralCategory::Lu),
+    ('\u{1d735}', '\u{1d735}', GeneralCategory::Sm),
+    ('\u{1d736}', '\u{1d74e}', GeneralCategory::Ll),
+    ('\u{1d74f}', '\u{1d74f}', GeneralCategory::Sm),
+    ('\u{1d750}', '\u{1d755}', GeneralCategory::Ll),
+    ('\u{1d756}', '\u{1d76e}', GeneralCategory::Lu),
+    ('\u{1d76f}', '\u{1d76f}', GeneralCategory::Sm),
+    ('\u{1d770}', '\u{1d788}', GeneralCategory::Ll),
+    ('\u{1d789}', '\u{1d789}', GeneralCategory::Sm),
+    ('\u{1d78a}', '\u{1d78f}', GeneralCategory::Ll),
+    ('\u{1d790}', '\u{1d7a8}', GeneralCategory::Lu),
+    ('\u{1d7a9}', '\u{1d7a9}', GeneralCategory::Sm),
+    ('\u{1d7aa}', '\u{1d7c2}', GeneralCategory::Ll),
+    ('\u{1d7c3}', '\u{1d7c3}', GeneralCategory::Sm),
+    ('\u{1d7c4}', '\u{1d7c9}', GeneralCategory::Ll),
+    ('\u{1d7ca}', '\u{1d7ca}', GeneralCategory::Lu),
+    ('\u{1d7cb}', '\u{1d7cb}', GeneralCategory::Ll),
+    ('\u{1d7ce}', '\u{1d7ff}', GeneralCategory::Nd),
+    ('\u{1d800}', '\u{1d9ff}', GeneralCategory::So),
+    ('\u{1da00}', '\u{1da36}', GeneralCategory::Mn),
+    ('\u{1da37}', '\u{1da3a}', GeneralCategory::So),
+    ('\u{1da3b}', '\u{1da6c}', GeneralCategory::Mn),
+    ('\u{1da6d}', '\u{1da74}', GeneralCategory::So),
+    ('\u{1da75}', '\u{1da75}', GeneralCategory::Mn),
+    ('\u{1da76}', '\u{1da83}', GeneralCategory::So),
+    ('\u{1da84}', '\u{1da84}', GeneralCategory::Mn),
+    ('\u{1da85}', '\u{1da86}', GeneralCategory::So),
+    ('\u{1da87}', '\u{1da8b}', GeneralCategory::Po),
+    ('\u{1da9b}', '\u{1da9f}', GeneralCategory::Mn),
+    ('\u{1daa1}', '\u{1daaf}', GeneralCategory::Mn),
+    ('\u{1df00}', '\u{1df09}', GeneralCategory::Ll),
+    ('\u{1df0a}', '\u{1df0a}', GeneralCategory::Lo),
+    ('\u{1df0b}', '\u{1df1e}', GeneralCategory::Ll),
+    ('\u{1df25}', '\u{1df2a}', GeneralCategory::Ll),
+    ('\u{1e000}', '\u{1e006}', GeneralCategory::Mn),
+    ('\u{1e008}', '\u{1e018}', GeneralCategory::Mn),
+    ('\u{1e01b}', '\u{1e021}', GeneralCategory::Mn),
+    ('\u{1e023}', '\u{1e024}', GeneralCategory::Mn),
+    ('\u{1e026}', '\u{1e02a}', GeneralCategory::Mn),
+    ('\u{1e030}', '\u{1e06d}', GeneralCategory::Lm),
+    ('\u{1e08f}', '\u{1e08f}', GeneralCategory::Mn),
+    ('\u{1e100}', '\u{1e12c}', GeneralCategory::Lo),
+    ('\u{1e130}', '\u{1e136}', GeneralCategory::Mn),
+    ('\u{1e137}', '\u{1e13d}', GeneralCategory::Lm),
+    ('\u{1e140}', '\u{1e149}', GeneralCategory::Nd),
+    ('\u{1e14e}', '\u{1e14e}', GeneralCategory::Lo),
+    ('\u{1e14f}', '\u{1e14f}', GeneralCategory::So),
+    ('\u{1e290}', '\u{1e2ad}', GeneralCategory::Lo),
+    ('\u{1e2ae}', '\u{1e2ae}', GeneralCategory::Mn),
+    ('\u{1e2c0}', '\u{1e2eb}', GeneralCategory::Lo),
+    ('\u{1e2ec}', '\u{1e2ef}', GeneralCategory::Mn),
+    ('\u{1e2f0}', '\u{1e2f9}', GeneralCategory::Nd),
+    ('\u{1e2ff}', '\u{1e2ff}', GeneralCategory::Sc),
+    ('\u{1e4d0}', '\u{1e4ea}', GeneralCategory::Lo),
+    ('\u{1e4eb}', '\u{1e4eb}', GeneralCategory::Lm),
+    ('\u{1e4ec}', '\u{1e4ef}', GeneralCategory::Mn),
+    ('\u{1e4f0}', '\u{1e4f9}', GeneralCategory::Nd),
+    ('\u{1e5d0}', '\u{1e5ed}', GeneralCategory::Lo),
+    ('\u{1e5ee}', '\u{1e5ef}', GeneralCategory::Mn),
+    ('\u{1e5f0}', '\u{1e5f0}', GeneralCategory::Lo),
+    ('\u{1e5f1}', '\u{1e5fa}', GeneralCategory::Nd),
+    ('\u{1e5ff}', '\u{1e5ff}', GeneralCategory::Po),
+    ('\u{1e6c0}', '\u{1e6de}', GeneralCategory::Lo),
+    ('\u{1e6e0}', '\u{1e6e2}', GeneralCategory::Lo),
+    ('\u{1e6e3}', '\u{1e6e3}', GeneralCategory::Mn),
+    ('\u{1e6e4}', '\u{1e6e5}', GeneralCategory::Lo),
+    ('\u{1e6e6}', '\u{1e6e6}', GeneralCategory::Mn),
+    ('\u{1e6e7}', '\u{1e6ed}', GeneralCategory::Lo),
+    ('\u{1e6ee}', '\u{1e6ef}', GeneralCategory::Mn),
+    ('\u{1e6f0}', '\u{1e6f4}', GeneralCategory::Lo),
+    ('\u{1e6f5}', '\u{1e6f5}', GeneralCategory::Mn),
+    ('\u{1e6fe}', '\u{1e6fe}', GeneralCategory::Lo),
+    ('\u{1e6ff}', '\u{1e6ff}', GeneralCategory::Lm),
+    ('\u{1e7e0}', '\u{1e7e6}', GeneralCategory::Lo),
+    ('\u{1e7e8}', '\u{1e7eb}', GeneralCategory::Lo),
+    ('\u{1e7ed}', '\u{1e7ee}', GeneralCategory::Lo),
+    ('\u{1e7f0}', '\u{1e7fe}', GeneralCategory::Lo),
+    ('\u{1e800}', '\u{1e8c4}', GeneralCategory::Lo),
+    ('\u{1e8c7}', '\u{1e8cf}', GeneralCategory::No),
+    ('\u{1e8d0}', '\u{1e8d6}', GeneralCategory::Mn),
+    ('\u{1e900}', '\u{1e921}', GeneralCategory::Lu),
+    ('\u{1e922}', '\u{1e943}', GeneralCategory::Ll),
+    ('\u{1e944}', '\u{1e94a}', GeneralCategory::Mn),
+    ('\u{1e94b}', '\u{1e94b}', GeneralCategory::Lm),
+    ('\u{1e950}', '\u{1e959}', GeneralCategory::Nd),
+    ('\u{1e95e}', '\u{1e95f}', GeneralCategory::Po),
+    ('\u{1ec71}', '\u{1ecab}', GeneralCategory::No),
+    ('\u{1ecac}', '\u{1ecac}', GeneralCategory::So),
+    ('\u{1ecad}', '\u{1ecaf}', GeneralCategory::No),
+    ('\u{1ecb0}', '\u{1ecb0}', GeneralCategory::Sc),
+    ('\u{1ecb1}', '\u{1ecb4}', GeneralCategory::No),
+    ('\u{1ed01}', '\u{1ed2d}', GeneralCategory::No),
+    ('\u{1ed2e}', '\u{1ed2e}', GeneralCategory::So),
+    ('\u{1ed2f}', '\u{1ed3d}', GeneralCategory::No),
+    ('\u{1ee00}', '\u{1ee03}', GeneralCategory::Lo),
+    ('\u{1ee05}', '\u{1ee1f}', GeneralCategory::Lo),
+    ('\u{1ee21}', '\u{1ee22}', GeneralCategory::Lo),
+    ('\u{1ee24}', '\u{1ee24}', GeneralCategory::Lo),
+    ('\u{1ee27}', '\u{1ee27}', GeneralCategory::Lo),
+    ('\u{1ee29}', '\u{1ee32}', GeneralCategory::Lo),
+    ('\u{1ee34}', '\u{1ee37}', GeneralCategory::Lo),
+    ('\u{1ee39}', '\u{1ee39}', GeneralCategory::Lo),
+    ('\u{1ee3b}', '\u{1ee3b}', GeneralCategory::Lo),
+    ('\u{1ee42}', '\u{1ee42}', GeneralCategory::Lo),
+    ('\u{1ee47}', '\u{1ee47}', GeneralCategory::Lo),
+    ('\u{1ee49}', '\u{1ee49}', GeneralCategory::Lo),
+    ('\u{1ee4b}', '\u{1ee4b}', GeneralCategory::Lo),
+    ('\u{1ee4d}', '\u{1ee4f}', GeneralCategory::Lo),
+    ('\u{1ee51}', '\u{1ee52}', GeneralCategory::Lo),
+    ('\u{1ee54}', '\u{1ee54}', GeneralCategory::Lo),
+    ('\u{1ee57}', '\u{1ee57}', GeneralCategory::Lo),
+    ('\u{1ee59}', '\u{1ee59}', GeneralCategory::Lo),
+    ('\u{1ee5b}', '\u{1ee5b}', GeneralCategory::Lo),
+    ('\u{1ee5d}', '\u{1ee5d}', GeneralCategory::Lo),
+    ('\u{1ee5f}', '\u{1ee5f}', GeneralCategory::Lo),
+    ('\u{1ee61}', '\u{1ee62}', GeneralCategory::Lo),
+    ('\u{1ee64}', '\u{1ee64}', GeneralCategory::Lo),
+    ('\u{1ee67}', '\u{1ee6a}', GeneralCategory::Lo),
+    ('\u{1ee6c}', '\u{1ee72}', GeneralCategory::Lo),
+    ('\u{1ee74}', '\u{1ee77}', GeneralCategory::Lo),
+    ('\u{1ee79}', '\u{1ee7c}', GeneralCategory::Lo),
+    ('\u{1ee7e}', '\u{1ee7e}', GeneralCategory::Lo),
+    ('\u{1ee80}', '\u{1ee89}', GeneralCategory::Lo),
+    ('\u{1ee8b}', '\u{1ee9b}', GeneralCategory::Lo),
+    ('\u{1eea1}', '\u{1eea3}', GeneralCategory::Lo),
+    ('\u{1eea5}', '\u{1eea9}', GeneralCategory::Lo),
+    ('\u{1eeab}', '\u{1eebb}', GeneralCategory::Lo),
+    ('\u{1eef0}', '\u{1eef1}', GeneralCategory::Sm),
+    ('\u{1f000}', '\u{1f02b}', GeneralCategory::So),
+    ('\u{1f030}', '\u{1f093}', GeneralCategory::So),
+    ('\u{1f0a0}', '\u{1f0ae}', GeneralCategory::So),
+    ('\u{1f0b1}', '\u{1f0bf}', GeneralCategory::So),
+    ('\u{1f0c1}', '\u{1f0cf}', GeneralCategory::So),
+    ('\u{1f0d1}', '\u{1f0f5}', GeneralCategory::So),
+    ('\u{1f100}', '\u{1f10c}', GeneralCategory::No),
+    ('\u{1f10d}', '\u{1f1ad}', GeneralCategory::So),
+    ('\u{1f1e6}', '\u{1f202}', GeneralCategory::So),
+    ('\u{1f210}', '\u{1f23b}', GeneralCategory::So),
+    ('\u{1f240}', '\u{1f248}', GeneralCategory::So),
+    ('\u{1f250}', '\u{1f251}', GeneralCategory::So),
+    ('\u{1f260}', '\u{1f265}', GeneralCategory::So),
+    ('\u{1f300}', '\u{1f3fa}', GeneralCategory::So),
+    ('\u{1f3fb}', '\u{1f3ff}', GeneralCategory::Sk),
+    ('\u{1f400}', '\u{1f6d8}', GeneralCategory::So),
+    ('\u{1f6dc}', '\u{1f6ec}', GeneralCategory::So),
+    ('\u{1f6f0}', '\u{1f6fc}', GeneralCategory::So),
+    ('\u{1f700}', '\u{1f7d9}', GeneralCategory::So),
+    ('\u{1f7e0}', '\u{1f7eb}', GeneralCategory::So),
+    ('\u{1f7f0}', '\u{1f7f0}', GeneralCategory::So),
+    ('\u{1f800}', '\u{1f80b}', GeneralCategory::So),
+    ('\u{1f810}', '\u{1f847}', GeneralCategory::So),
+    ('\u{1f850}', '\u{1f859}', GeneralCategory::So),
+    ('\u{1f860}', '\u{1f887}', GeneralCategory::So),
+    ('\u{1f890}', '\u{1f8ad}', GeneralCategory::So),
+    ('\u{1f8b0}', '\u{1f8bb}', GeneralCategory::So),
+    ('\u{1f8c0}', '\u{1f8c1}', GeneralCategory::So),
+    ('\u{1f8d0}', '\u{1f8d8}', GeneralCategory::Sm),
+    ('\u{1f900}', '\u{1fa57}', GeneralCategory::So),
+    ('\u{1fa60}', '\u{1fa6d}', GeneralCategory::So),
+    ('\u{1fa70}', '\u{1fa7c}', GeneralCategory::So),
+    ('\u{1fa80}', '\u{1fa8a}', GeneralCategory::So),
+    ('\u{1fa8e}', '\u{1fac6}', GeneralCategory::So),
+    ('\u{1fac8}', '\u{1fac8}', GeneralCategory::So),
+    ('\u{1facd}', '\u{1fadc}', GeneralCategory::So),
+    ('\u{1fadf}', '\u{1faea}', GeneralCategory::So),
+    ('\u{1faef}', '\u{1faf8}', GeneralCategory::So),
+    ('\u{1fb00}', '\u{1fb92}', GeneralCategory::So),
+    ('\u{1fb94}', '\u{1fbef}', GeneralCategory::So),
+    ('\u{1fbf0}', '\u{1fbf9}', GeneralCategory::Nd),
+    ('\u{1fbfa}', '\u{1fbfa}', GeneralCategory::So),
+    ('\u{20000}', '\u{2a6df}', GeneralCategory::Lo),
+    ('\u{2a700}', '\u{2b81d}', GeneralCategory::Lo),
+    ('\u{2b820}', '\u{2cead}', GeneralCategory::Lo),
+    ('\u{2ceb0}', '\u{2ebe0}', GeneralCategory::Lo),
+    ('\u{2ebf0}', '\u{2ee5d}', GeneralCategory::Lo),
+    ('\u{2f800}', '\u{2fa1d}', GeneralCategory::Lo),
+    ('\u{30000}', '\u{3134a}', GeneralCategory::Lo),
+    ('\u{31350}', '\u{33479}', GeneralCategory::Lo),
+    ('\u{e0001}', '\u{e0001}', GeneralCategory::Cf),
+    ('\u{e0020}', '\u{e007f}', GeneralCategory::Cf),
+    ('\u{e0100}', '\u{e01ef}', GeneralCategory::Mn),
+    ('\u{f0000}', '\u{ffffd}', GeneralCategory::Co),
+    ('\u{100000}', '\u{10fffd}', GeneralCategory::Co),
+];
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Script {
+    Any,
+    Common,
+    Inherited,
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Bengali,
+    Tamil,
+    Thai,
+    Georgian,
+    Hangul,
+    Hiragana,
+    Katakana,
+    Han,
+}
+
+pub const SCRIPT_TABLE: &'static [(char, char, Script)] = &[
+    ('\u{0}', '\u{40}', Script::Common),
+    ('\u{41}', '\u{5a}', Script::Latin),
+    ('\u{5b}', '\u{60}', Script::Common),
+    ('\u{61}', '\u{7a}', Script::Latin),
+    ('\u{7b}', '\u{a9}', Script::Common),
+    ('\u{aa}', '\u{aa}', Script::Latin),
+    ('\u{ab}', '\u{b9}', Script::Common),
+    ('\u{ba}', '\u{ba}', Script::Latin),
+    ('\u{bb}', '\u{bf}', Script::Common),
+    ('\u{c0}', '\u{d6}', Script::Latin),
+    ('\u{d7}', '\u{d7}', Script::Common),
+    ('\u{d8}', '\u{f6}', Script::Latin),
+    ('\u{f7}', '\u{f7}', Script::Common),
+    ('\u{f8}', '\u{2b8}', Script::Latin),
+    ('\u{2b9}', '\u{2df}', Script::Common),
+    ('\u{2e0}', '\u{2e4}', Script::Latin),
+    ('\u{2e5}', '\u{2e9}', Script::Common),
+    ('\u{2ec}', '\u{2ff}', Script::Common),
+    ('\u{300}', '\u{36f}', Script::Inherited),
+    ('\u{370}', '\u{373}', Script::Greek),
+    ('\u{374}', '\u{374}', Script::Common),
+    ('\u{375}', '\u{377}', Script::Greek),
+    ('\u{37a}', '\u{37d}', Script::Greek),
+    ('\u{37e}', '\u{37e}', Script::Common),
+    ('\u{37f}', '\u{37f}', Script::Greek),
+    ('\u{384}', '\u{384}', Script::Greek),
+    ('\u{385}', '\u{385}', Script::Common),
+    ('\u{386}', '\u{386}', Script::Greek),
+    ('\u{387}', '\u{387}', Script::Common),
+    ('\u{388}', '\u{38a}', Script::Greek),
+    ('\u{38c}', '\u{38c}', Script::Greek),
+    ('\u{38e}', '\u{3a1}', Script::Greek),
+    ('\u{3a3}', '\u{3e1}', Script::Greek),
+    ('\u{3f0}', '\u{3ff}', Script::Greek),
+    ('\u{400}', '\u{484}', Script::Cyrillic),
+    ('\u{485}', '\u{486}', Script::Inherited),
+    ('\u{487}', '\u{52f}', Script::Cyrillic),
+    ('\u{531}', '\u{556}', Script::Armenian),
+    ('\u{559}', '\u{58a}', Script::Armenian),
+    ('\u{58d}', '\u{58f}', Script::Armenian),
+    ('\u{591}', '\u{5c7}', Script::Hebrew),
+    ('\u{5d0}', '\u{5ea}', Script::Hebrew),
+    ('\u{5ef}', '\u{5f4}', Script::Hebrew),
+    ('\u{600}', '\u{604}', Script::Arabic),
+    ('\u{605}', '\u{605}', Script::Common),
+    ('\u{606}', '\u{60b}', Script::Arabic),
+    ('\u{60c}', '\u{60c}', Script::Common),
+    ('\u{60d}', '\u{61a}', Script::Arabic),
+    ('\u{61b}', '\u{61b}', Script::Common),
+    ('\u{61c}', '\u{61e}', Script::Arabic),
+    ('\u{61f}', '\u{61f}', Script::Common),
+    ('\u{620}', '\u{63f}', Script::Arabic),
+    ('\u{640}', '\u{640}', Script::Common),
+    ('\u{641}', '\u{64a}', Script::Arabic),
+    ('\u{64b}', '\u{655}', Script::Inherited),
+    ('\u{656}', '\u{66f}', Script::Arabic),
+    ('\u{670}', '\u{670}', Script::Inherited),
+    ('\u{671}', '\u{6dc}', Script::Arabic),
+    ('\u{6dd}', '\u{6dd}', Script::Common),
+    ('\u{6de}', '\u{6ff}', Script::Arabic),
+    ('\u{750}', '\u{77f}', Script::Arabic),
+    ('\u{870}', '\u{891}', Script::Arabic),
+    ('\u{897}', '\u{8e1}', Script::Arabic),
+    ('\u{8e2}', '\u{8e2}', Script::Common),
+    ('\u{8e3}', '\u{8ff}', Script::Arabic),
+    ('\u{900}', '\u{950}', Script::Devanagari),
+    ('\u{951}', '\u{954}', Script::Inherited),
+    ('\u{955}', '\u{963}', Script::Devanagari),
+    ('\u{964}', '\u{965}', Script::Common),
+    ('\u{966}', '\u{97f}', Script::Devanagari),
+    ('\u{980}', '\u{983}', Script::Bengali),
+    ('\u{985}', '\u{98c}', Script::Bengali),
+    ('\u{98f}', '\u{990}', Script::Bengali),
+    ('\u{993}', '\u{9a8}', Script::Bengali),
+    ('\u{9aa}', '\u{9b0}', Script::Bengali),
+    ('\u{9b2}', '\u{9b2}', Script::Bengali),
+    ('\u{9b6}', '\u{9b9}', Script::Bengali),
+    ('\u{9bc}', '\u{9c4}', Script::Bengali),
+    ('\u{9c7}', '\u{9c8}', Script::Bengali),
+    ('\u{9cb}', '\u{9ce}', Script::Bengali),
+    ('\u{9d7}', '\u{9d7}', Script::Bengali),
+    ('\u{9dc}', '\u{9dd}', Script::Bengali),
+    ('\u{9df}', '\u{9e3}', Script::Bengali),
+    ('\u{9e6}', '\u{9fe}', Script::Bengali),
+    ('\u{b82}', '\u{b83}', Script::Tamil),
+    ('\u{b85}', '\u{b8a}', Script::Tamil),
+    ('\u{b8e}', '\u{b90}', Script::Tamil),
+    ('\u{b92}', '\u{b95}', Script::Tamil),
+    ('\u{b99}', '\u{b9a}', Script::Tamil),
+    ('\u{b9c}', '\u{b9c}', Script::Tamil),
+    ('\u{b9e}', '\u{b9f}', Script::Tamil),
+    ('\u{ba3}', '\u{ba4}', Script::Tamil),
+    ('\u{ba8}', '\u{baa}', Script::Tamil),
+    ('\u{bae}', '\u{bb9}', Script::Tamil),
+    ('\u{bbe}', '\u{bc2}', Script::Tamil),
+    ('\u{bc6}', '\u{bc8}', Script::Tamil),
+    ('\u{bca}', '\u{bcd}', Script::Tamil),
+    ('\u{bd0}', '\u{bd0}', Script::Tamil),
+    ('\u{bd7}', '\u{bd7}', Script::Tamil),
+    ('\u{be6}', '\u{bfa}', Script::Tamil),
+    ('\u{e01}', '\u{e3a}', Script::Thai),
+    ('\u{e3f}', '\u{e3f}', Script::Common),
+    ('\u{e40}', '\u{e5b}', Script::Thai),
+    ('\u{fd5}', '\u{fd8}', Script::Common),
+    ('\u{10a0}', '\u{10c5}', Script::Georgian),
+    ('\u{10c7}', '\u{10c7}', Script::Georgian),
+    ('\u{10cd}', '\u{10cd}', Script::Georgian),
+    ('\u{10d0}', '\u{10fa}', Script::Georgian),
+    ('\u{10fb}', '\u{10fb}', Script::Common),
+    ('\u{10fc}', '\u{10ff}', Script::Georgian),
+    ('\u{1100}', '\u{11ff}', Script::Hangul),
+    ('\u{16eb}', '\u{16ed}', Script::Common),
+    ('\u{1735}', '\u{1736}', Script::Common),
+    ('\u{1802}', '\u{1803}', Script::Common),
+    ('\u{1805}', '\u{1805}', Script::Common),
+    ('\u{1ab0}', '\u{1add}', Script::Inherited),
+    ('\u{1ae0}', '\u{1aeb}', Script::Inherited),
+    ('\u{1c80}', '\u{1c8a}', Script::Cyrillic),
+    ('\u{1c90}', '\u{1cba}', Script::Georgian),
+    ('\u{1cbd}', '\u{1cbf}', Script::Georgian),
+    ('\u{1cd0}', '\u{1cd2}', Script::Inherited),
+    ('\u{1cd3}', '\u{1cd3}', Script::Common),
+    ('\u{1cd4}', '\u{1ce0}', Script::Inherited),
+    ('\u{1ce1}', '\u{1ce1}', Script::Common),
+    ('\u{1ce2}', '\u{1ce8}', Script::Inherited),
+    ('\u{1ce9}', '\u{1cec}', Script::Common),
+    ('\u{1ced}', '\u{1ced}', Script::Inherited),
+    ('\u{1cee}', '\u{1cf3}', Script::Common),
+    ('\u{1cf4}', '\u{1cf4}', Script::Inherited),
+    ('\u{1cf5}', '\u{1cf7}', Script::Common),
+    ('\u{1cf8}', '\u{1cf9}', Script::Inherited),
+    ('\u{1cfa}', '\u{1cfa}', Script::Common),
+    ('\u{1d00}', '\u{1d25}', Script::Latin),
+    ('\u{1d26}', '\u{1d2a}', Script::Greek),
+    ('\u{1d2b}', '\u{1d2b}', Script::Cyrillic),
+    ('\u{1d2c}', '\u{1d5c}', Script::Latin),
+    ('\u{1d5d}', '\u{1d61}', Script::Greek),
+    ('\u{1d62}', '\u{1d65}', Script::Latin),
+    ('\u{1d66}', '\u{1d6a}', Script::Greek),
+    ('\u{1d6b}', '\u{1d77}', Script::Latin),
+    ('\u{1d78}', '\u{1d78}', Script::Cyrillic),
+    ('\u{1d79}', '\u{1dbe}', Script::Latin),
+    ('\u{1dbf}', '\u{1dbf}', Script::Greek),
+    ('\u{1dc0}', '\u{1dff}', Script::Inherited),
+    ('\u{1e00}', '\u{1eff}', Script::Latin),
+    ('\u{1f00}', '\u{1f15}', Script::Greek),
+    ('\u{1f18}', '\u{1f1d}', Script::Greek),
+    ('\u{1f20}', '\u{1f45}', Script::Greek),
+    ('\u{1f48}', '\u{1f4d}', Script::Greek),
+    ('\u{1f50}', '\u{1f57}', Script::Greek),
+    ('\u{1f59}', '\u{1f59}', Script::Greek),
+    ('\u{1f5b}', '\u{1f5b}', Script::Greek),
+    ('\u{1f5d}', '\u{1f5d}', Script::Greek),
+    ('\u{1f5f}', '\u{1f7d}', Script::Greek),
+    ('\u{1f80}', '\u{1fb4}', Script::Greek),
+    ('\u{1fb6}', '\u{1fc4}', Script::Greek),
+    ('\u{1fc6}', '\u{1fd3}', Script::Greek),
+    ('\u{1fd6}', '\u{1fdb}', Script::Greek),
+    ('\u{1fdd}', '\u{1fef}', Script::Greek),
+    ('\u{1ff2}', '\u{1ff4}', Script::Greek),
+    ('\u{1ff6}', '\u{1ffe}', Script::Greek),
+    ('\u{2000}', '\u{200b}', Script::Common),
+    ('\u{200c}', '\u{200d}', Script::Inherited),
+    ('\u{200e}', '\u{2064}', Script::Common),
+    ('\u{2066}', '\u{2070}', Script::Common),
+    ('\u{2071}', '\u{2071}', Script::Latin),
+    ('\u{2074}', '\u{207e}', Script::Common),
+    ('\u{207f}', '\u{207f}', Script::Latin),
+    ('\u{2080}', '\u{208e}', Script::Common),
+    ('\u{2090}', '\u{209c}', Script::Latin),
+    ('\u{20a0}', '\u{20c1}', Script::Common),
+    ('\u{20d0}', '\u{20f0}', Script::Inherited),
+    ('\u{2100}', '\u{2125}', Script::Common),
+    ('\u{2126}', '\u{2126}', Script::Greek),
+    ('\u{2127}', '\u{2129}', Script::Common),
+    ('\u{212a}', '\u{212b}', Script::Latin),
+    ('\u{212c}', '\u{2131}', Script::Common),
+    ('\u{2132}', '\u{2132}', Script::Latin),
+    ('\u{2133}', '\u{214d}', Script::Common),
+    ('\u{214e}', '\u{214e}', Script::Latin),
+    ('\u{214f}', '\u{215f}', Script::Common),
+    ('\u{2160}', '\u{2188}', Script::Latin),
+    ('\u{2189}', '\u{218b}', Script::Common),
+    ('\u{2190}', '\u{2429}', Script::Common),
+    ('\u{2440}', '\u{244a}', Script::Common),
+    ('\u{2460}', '\u{27ff}', Script::Common),
+    ('\u{2900}', '\u{2b73}', Script::Common),
+    ('\u{2b76}', '\u{2bff}', Script::Common),
+    ('\u{2c60}', '\u{2c7f}', Script::Latin),
+    ('\u{2d00}', '\u{2d25}', Script::Georgian),
+    ('\u{2d27}', '\u{2d27}', Script::Georgian),
+    ('\u{2d2d}', '\u{2d2d}', Script::Georgian),
+    ('\u{2de0}', '\u{2dff}', Script::Cyrillic),
+    ('\u{2e00}', '\u{2e5d}', Script::Common),
+    ('\u{2e80}', '\u{2e99}', Script::Han),
+    ('\u{2e9b}', '\u{2ef3}', Script::Han),
+    ('\u{2f00}', '\u{2fd5}', Script::Han),
+    ('\u{2ff0}', '\u{3004}', Script::Common),
+    ('\u{3005}', '\u{3005}', Script::Han),
+    ('\u{3006}', '\u{3006}', Script::Common),
+    ('\u{3007}', '\u{3007}', Script::Han),
+    ('\u{3008}', '\u{3020}', Script::Common),
+    ('\u{3021}', '\u{3029}', Script::Han),
+    ('\u{302a}', '\u{302d}', Script::Inherited),
+    ('\u{302e}', '\u{302f}', Script::Hangul),
+    ('\u{3030}', '\u{3037}', Script::Common),
+    ('\u{3038}', '\u{303b}', Script::Han),
+    ('\u{303c}', '\u{303f}', Script::Common),
+    ('\u{3041}', '\u{3096}', Script::Hiragana),
+    ('\u{3099}', '\u{309a}', Script::Inherited),
+    ('\u{309b}', '\u{309c}', Script::Common),
+    ('\u{309d}', '\u{309f}', Script::Hiragana),
+    ('\u{30a0}', '\u{30a0}', Script::Common),
+    ('\u{30a1}', '\u{30fa}', Script::Katakana),
+    ('\u{30fb}', '\u{30fc}', Script::Common),
+    ('\u{30fd}', '\u{30ff}', Script::Katakana),
+    ('\u{3131}', '\u{318e}', Script::Hangul),
+    ('\u{3190}', '\u{319f}', Script::Common),
+    ('\u{31c0}', '\u{31e5}', Script::Common),
+    ('\u{31ef}', '\u{31ef}', Script::Common),
+    ('\u{31f0}', '\u{31ff}', Script::Katakana),
+    ('\u{3200}', '\u{321e}', Script::Hangul),
+    ('\u{3220}', '\u{325f}', Script::Common),
+    ('\u{3260}', '\u{327e}', Script::Hangul),
+    ('\u{327f}', '\u{32cf}', Script::Common),
+    ('\u{32d0}', '\u{32fe}', Script::Katakana),
+    ('\u{32ff}', '\u{32ff}', Script::Common),
+    ('\u{3300}', '\u{3357}', Script::Katakana),
+    ('\u{3358}', '\u{33ff}', Script::Common),
+    ('\u{3400}', '\u{4dbf}', Script::Han),
+    ('\u{4dc0}', '\u{4dff}', Script::Common),
+    ('\u{4e00}', '\u{9fff}', Script::Han),
+    ('\u{a640}', '\u{a69f}', Script::Cyrillic),
+    ('\u{a700}', '\u{a721}', Script::Common),
+    ('\u{a722}', '\u{a787}', Script::Latin),
+    ('\u{a788}', '\u{a78a}', Script::Common),
+    ('\u{a78b}', '\u{a7dc}', Script::Latin),
+    ('\u{a7f1}', '\u{a7ff}', Script::Latin),
+    ('\u{a830}', '\u{a839}', Script::Common),
+    ('\u{a8e0}', '\u{a8ff}', Script::Devanagari),
+    ('\u{a92e}', '\u{a92e}', Script::Common),
+    ('\u{a960}', '\u{a97c}', Script::Hangul),
+    ('\u{a9cf}', '\u{a9cf}', Script::Common),
+    ('\u{ab30}', '\u{ab5a}', Script::Latin),
+    ('\u{ab5b}', '\u{ab5b}', Script::Common),
+    ('\u{ab5c}', '\u{ab64}', Script::Latin),
+    ('\u{ab65}', '\u{ab65}', Script::Greek),
+    ('\u{ab66}', '\u{ab69}', Script::Latin),
+    ('\u{ab6a}', '\u{ab6b}', Script::Common),
+    ('\u{ac00}', '\u{d7a3}', Script::Hangul),
+    ('\u{d7b0}', '\u{d7c6}', Script::Hangul),
+    ('\u{d7cb}', '\u{d7fb}', Script::Hangul),
+    ('\u{f900}', '\u{fa6d}', Script::Han),
+    ('\u{fa70}', '\u{fad9}', Script::Han),
+    ('\u{fb00}', '\u{fb06}', Script::Latin),
+    ('\u{fb13}', '\u{fb17}', Script::Armenian),
+    ('\u{fb1d}', '\u{fb36}', Script::Hebrew),
+    ('\u{fb38}', '\u{fb3c}', Script::Hebrew),
+    ('\u{fb3e}', '\u{fb3e}', Script::Hebrew),
+    ('\u{fb40}', '\u{fb41}', Script::Hebrew),
+    ('\u{fb43}', '\u{fb44}', Script::Hebrew),
+    ('\u{fb46}', '\u{fb4f}', Script::Hebrew),
+    ('\u{fb50}', '\u{fd3d}', Script::Arabic),
+    ('\u{fd3e}', '\u{fd3f}', Script::Common),
+    ('\u{fd40}', '\u{fdcf}', Script::Arabic),
+    ('\u{fdf0}', '\u{fdff}', Script::Arabic),
+    ('\u{fe00}', '\u{fe0f}', Script::Inherited),
+    ('\u{fe10}', '\u{fe19}', Script::Common),
+    ('\u{fe20}', '\u{fe2d}', Script::Inherited),
+    ('\u{fe2e}', '\u{fe2f}', Script::Cyrillic),
+    ('\u{fe30}', '\u{fe52}', Script::Common),
+    ('\u{fe54}', '\u{fe66}', Script::Common),
+    ('\u{fe68}', '\u{fe6b}', Script::Common),
+    ('\u{fe70}', '\u{fe74}', Script::Arabic),
+    ('\u{fe76}', '\u{fefc}', Script::Arabic),
+    ('\u{feff}', '\u{feff}', Script::Common),
+    ('\u{ff01}', '\u{ff20}', Script::Common),
+    ('\u{ff21}', '\u{ff3a}', Script::Latin),
+    ('\u{ff3b}', '\u{ff40}', Script::Common),
+    ('\u{ff41}', '\u{ff5a}', Script::Latin),
+    ('\u{ff5b}', '\u{ff65}', Script::Common),
+    ('\u{ff66}', '\u{ff6f}', Script::Katakana),
+    ('\u{ff70}', '\u{ff70}', Script::Common),
+    ('\u{ff71}', '\u{ff9d}', Script::Katakana),
+    ('\u{ff9e}', '\u{ff9f}', Script::Common),
+    ('\u{ffa0}', '\u{ffbe}', Script::Hangul),
+    ('\u{ffc2}', '\u{ffc7}', Script::Hangul),
+    ('\u{ffca}', '\u{ffcf}', Script::Hangul),
+    ('\u{ffd2}', '\u{ffd7}', Script::Hangul),
+    ('\u{ffda}', '\u{ffdc}', Script::Hangul),
+    ('\u{ffe0}', '\u{ffe6}', Script::Common),
+    ('\u{ffe8}', '\u{ffee}', Script::Common),
+    ('\u{fff9}', '\u{fffd}', Script::Common),
+    ('\u{10100}', '\u{10102}', Script::Common),
+    ('\u{10107}', '\u{10133}', Script::Common),
+    ('\u{10137}', '\u{1013f}', Script::Common),
+    ('\u{10140}', '\u{1018e}', Script::Greek),
+    ('\u{10190}', '\u{1019c}', Script::Common),
+    ('\u{101a0}', '\u{101a0}', Script::Greek),
+    ('\u{101d0}', '\u{101fc}', Script::Common),
+    ('\u{101fd}', '\u{101fd}', Script::Inherited),
+    ('\u{102e0}', '\u{102e0}', Script::Inherited),
+    ('\u{102e1}', '\u{102fb}', Script::Common),
+    ('\u{10780}', '\u{10785}', Script::Latin),
+    ('\u{10787}', '\u{107b0}', Script::Latin),
+    ('\u{107b2}', '\u{107ba}', Script::Latin),
+    ('\u{10e60}', '\u{10e7e}', Script::Arabic),
+    ('\u{10ec2}', '\u{10ec7}', Script::Arabic),
+    ('\u{10ed0}', '\u{10ed8}', Script::Arabic),
+    ('\u{10efa}', '\u{10eff}', Script::Arabic),
+    ('\u{1133b}', '\u{1133b}', Script::Inherited),
+    ('\u{11b00}', '\u{11b09}', Script::Devanagari),
+    ('\u{11fc0}', '\u{11ff1}', Script::Tamil),
+    ('\u{11fff}', '\u{11fff}', Script::Tamil),
+    ('\u{16fe2}', '\u{16fe3}', Script::Han),
+    ('\u{16ff0}', '\u{16ff6}', Script::Han),
+    ('\u{1aff0}', '\u{1aff3}', Script::Katakana),
+    ('\u{1aff5}', '\u{1affb}', Script::Katakana),
+    ('\u{1affd}', '\u{1affe}', Script::Katakana),
+    ('\u{1b000}', '\u{1b000}', Script::Katakana),
+    ('\u{1b001}', '\u{1b11f}', Script::Hiragana),
+    ('\u{1b120}', '\u{1b122}', Script::Katakana),
+    ('\u{1b132}', '\u{1b132}', Script::Hiragana),
+    ('\u{1b150}', '\u{1b152}', Script::Hiragana),
+    ('\u{1b155}', '\u{1b155}', Script::Katakana),
+    ('\u{1b164}', '\u{1b167}', Script::Katakana),
+    ('\u{1bca0}', '\u{1bca3}', Script::Common),
+    ('\u{1cc00}', '\u{1ccfc}', Script::Common),
+    ('\u{1cd00}', '\u{1ceb3}', Script::Common),
+    ('\u{1ceba}', '\u{1ced0}', Script::Common),
+    ('\u{1cee0}', '\u{1cef0}', Script::Common),
+    ('\u{1cf00}', '\u{1cf2d}', Script::Inherited),
+    ('\u{1cf30}', '\u{1cf46}', Script::Inherited),
+    ('\u{1cf50}', '\u{1cfc3}', Script::Common),
+    ('\u{1d000}', '\u{1d0f5}', Script::Common),
+    ('\u{1d100}', '\u{1d126}', Script::Common),
+    ('\u{1d129}', '\u{1d166}', Script::Common),
+    ('\u{1d167}', '\u{1d169}', Script::Inherited),
+    ('\u{1d16a}', '\u{1d17a}', Script::Common),
+    ('\u{1d17b}', '\u{1d182}', Script::Inherited),
+    ('\u{1d183}', '\u{1d184}', Script::Common),
+    ('\u{1d185}', '\u{1d18b}', Script::Inherited),
+    ('\u{1d18c}', '\u{1d1a9}', Script::Common),
+    ('\u{1d1aa}', '\u{1d1ad}', Script::Inherited),
+    ('\u{1d1ae}', '\u{1d1ea}', Script::Common),
+    ('\u{1d200}', '\u{1d245}', Script::Greek),
+    ('\u{1d2c0}', '\u{1d2d3}', Script::Common),
+    ('\u{1d2e0}', '\u{1d2f3}', Script::Common),
+    ('\u{1d300}', '\u{1d356}', Script::Common),
+    ('\u{1d360}', '\u{1d378}', Script::Common),
+    ('\u{1d400}', '\u{1d454}', Script::Common),
+    ('\u{1d456}', '\u{1d49c}', Script::Common),
+    ('\u{1d49e}', '\u{1d49f}', Script::Common),
+    ('\u{1d4a2}', '\u{1d4a2}', Script::Common),
+    ('\u{1d4a5}', '\u{1d4a6}', Script::Common),
+    ('\u{1d4a9}', '\u{1d4ac}', Script::Common),
+    ('\u{1d4ae}', '\u{1d4b9}', Script::Common),
+    ('\u{1d4bb}', '\u{1d4bb}', Script::Common),
+    ('\u{1d4bd}', '\u{1d4c3}', Script::Common),
+    ('\u{1d4c5}', '\u{1d505}', Script::Common),
+    ('\u{1d507}', '\u{1d50a}', Script::Common),
+    ('\u{1d50d}', '\u{1d514}', Script::Common),
+    ('\u{1d516}', '\u{1d51c}', Script::Common),
+    ('\u{1d51e}', '\u{1d539}', Script::Common),
+    ('\u{1d53b}', '\u{1d53e}', Script::Common),
+    ('\u{1d540}', '\u{1d544}', Script::Common),
+    ('\u{1d546}', '\u{1d546}', Script::Common),
+    ('\u{1d54a}', '\u{1d550}', Script::Common),
+    ('\u{1d552}', '\u{1d6a5}', Script::Common),
+    ('\u{1d6a8}', '\u{1d7cb}', Script::Common),
+    ('\u{1d7ce}', '\u{1d7ff}', Script::Common),
+    ('\u{1df00}', '\u{1df1e}', Script::Latin),
+    ('\u{1df25}', '\u{1df2a}', Script::Latin),
+    ('\u{1e030}', '\u{1e06d}', Script::Cyrillic),
+    ('\u{1e08f}', '\u{1e08f}', Script::Cyrillic),
+    ('\u{1ec71}', '\u{1ecb4}', Script::Common),
+    ('\u{1ed01}', '\u{1ed3d}', Script::Common),
+    ('\u{1ee00}', '\u{1ee03}', Script::Arabic),
+    ('\u{1ee05}', '\u{1ee1f}', Script::Arabic),
+    ('\u{1ee21}', '\u{1ee22}', Script::Arabic),
+    ('\u{1ee24}', '\u{1ee24}', Script::Arabic),
+    ('\u{1ee27}', '\u{1ee27}', Script::Arabic),
+    ('\u{1ee29}', '\u{1ee32}', Script::Arabic),
+    ('\u{1ee34}', '\u{1ee37}', Script::Arabic),
+    ('\u{1ee39}', '\u{1ee39}', Script::Arabic),
+    ('\u{1ee3b}', '\u{1ee3b}', Script::Arabic),
+    ('\u{1ee42}', '\u{1ee42}', Script::Arabic),
+    ('\u{1ee47}', '\u{1ee47}', Script::Arabic),
+    ('\u{1ee49}', '\u{1ee49}', Script::Arabic),
+    ('\u{1ee4b}', '\u{1ee4b}', Script::Arabic),
+    ('\u{1ee4d}', '\u{1ee4f}', Script::Arabic),
+    ('\u{1ee51}', '\u{1ee52}', Script::Arabic),
+    ('\u{1ee54}', '\u{1ee54}', Script::Arabic),
+    ('\u{1ee57}', '\u{1ee57}', Script::Arabic),
+    ('\u{1ee59}', '\u{1ee59}', Script::Arabic),
+    ('\u{1ee5b}', '\u{1ee5b}', Script::Arabic),
+    ('\u{1ee5d}', '\u{1ee5d}', Script::Arabic),
+    ('\u{1ee5f}', '\u{1ee5f}', Script::Arabic),
+    ('\u{1ee61}', '\u{1ee62}', Script::Arabic),
+    ('\u{1ee64}', '\u{1ee64}', Script::Arabic),
+    ('\u{1ee67}', '\u{1ee6a}', Script::Arabic),
+    ('\u{1ee6c}', '\u{1ee72}', Script::Arabic),
+    ('\u{1ee74}', '\u{1ee77}', Script::Arabic),
+    ('\u{1ee79}', '\u{1ee7c}', Script::Arabic),
+    ('\u{1ee7e}', '\u{1ee7e}', Script::Arabic),
+    ('\u{1ee80}', '\u{1ee89}', Script::Arabic),
+    ('\u{1ee8b}', '\u{1ee9b}', Script::Arabic),
+    ('\u{1eea1}', '\u{1eea3}', Script::Arabic),
+    ('\u{1eea5}', '\u{1eea9}', Script::Arabic),
+    ('\u{1eeab}', '\u{1eebb}', Script::Arabic),
+    ('\u{1eef0}', '\u{1eef1}', Script::Arabic),
+    ('\u{1f000}', '\u{1f02b}', Script::Common),
+    ('\u{1f030}', '\u{1f093}', Script::Common),
+    ('\u{1f0a0}', '\u{1f0ae}', Script::Common),
+    ('\u{1f0b1}', '\u{1f0bf}', Script::Common),
+    ('\u{1f0c1}', '\u{1f0cf}', Script::Common),
+    ('\u{1f0d1}', '\u{1f0f5}', Script::Common),
+    ('\u{1f100}', '\u{1f1ad}', Script::Common),
+    ('\u{1f1e6}', '\u{1f1ff}', Script::Common),
+    ('\u{1f200}', '\u{1f200}', Script::Hiragana),
+    ('\u{1f201}', '\u{1f202}', Script::Common),
+    ('\u{1f210}', '\u{1f23b}', Script::Common),
+    ('\u{1f240}', '\u{1f248}', Script::Common),
+    ('\u{1f250}', '\u{1f251}', Script::Common),
+    ('\u{1f260}', '\u{1f265}', Script::Common),
+    ('\u{1f300}', '\u{1f6d8}', Script::Common),
+    ('\u{1f6dc}', '\u{1f6ec}', Script::Common),
+    ('\u{1f6f0}', '\u{1f6fc}', Script::Common),
+    ('\u{1f700}', '\u{1f7d9}', Script::Common),
+    ('\u{1f7e0}', '\u{1f7eb}', Script::Common),
+    ('\u{1f7f0}', '\u{1f7f0}', Script::Common),
+    ('\u{1f800}', '\u{1f80b}', Script::Common),
+    ('\u{1f810}', '\u{1f847}', Script::Common),
+    ('\u{1f850}', '\u{1f859}', Script::Common),
+    ('\u{1f860}', '\u{1f887}', Script::Common),
+    ('\u{1f890}', '\u{1f8ad}', Script::Common),
+    ('\u{1f8b0}', '\u{1f8bb}', Script::Common),
+    ('\u{1f8c0}', '\u{1f8c1}', Script::Common),
+    ('\u{1f8d0}', '\u{1f8d8}', Script::Common),
+    ('\u{1f900}', '\u{1fa57}', Script::Common),
+    ('\u{1fa60}', '\u{1fa6d}', Script::Common),
+    ('\u{1fa70}', '\u{1fa7c}', Script::Common),
+    ('\u{1fa80}', '\u{1fa8a}', Script::Common),
+    ('\u{1fa8e}', '\u{1fac6}', Script::Common),
+    ('\u{1fac8}', '\u{1fac8}', Script::Common),
+    ('\u{1facd}', '\u{1fadc}', Script::Common),
+    ('\u{1fadf}', '\u{1faea}', Script::Common),
+    ('\u{1faef}', '\u{1faf8}', Script::Common),
+    ('\u{1fb00}', '\u{1fb92}', Script::Common),
+    ('\u{1fb94}', '\u{1fbfa}', Script::Common),
+    ('\u{20000}', '\u{2a6df}', Script::Han),
+    ('\u{2a700}', '\u{2b81d}', Script::Han),
+    ('\u{2b820}', '\u{2cead}', Script::Han),
+    ('\u{2ceb0}', '\u{2ebe0}', Script::Han),
+    ('\u{2ebf0}', '\u{2ee5d}', Script::Han),
+    ('\u{2f800}', '\u{2fa1d}', Script::Han),
+    ('\u{30000}', '\u{3134a}', Script::Han),
+    ('\u{31350}', '\u{33479}', Script::Han),
+    ('\u{e0001}', '\u{e0001}', Script::Common),
+    ('\u{e0020}', '\u{e007f}', Script::Common),
+    ('\u{e0100}', '\u{e01ef}', Script::Inherited),
+];
+
+pub const XID_START_TABLE: &'static [(char, char)] = &[
+    ('\u{41}', '\u{5a}'),
+    ('\u{61}', '\u{7a}'),
+    ('\u{aa}', '\u{aa}'),
+    ('\u{b5}', '\u{b5}'),
+    ('\u{ba}', '\u{ba}'),
+    ('\u{c0}', '\u{d6}'),
+    ('\u{d8}', '\u{f6}'),
+    ('\u{f8}', '\u{2c1}'),
+    ('\u{2c6}', '\u{2d1}'),
+    ('\u{2e0}', '\u{2e4}'),
+    ('\u{2ec}', '\u{2ec}'),
+    ('\u{2ee}', '\u{2ee}'),
+    ('\u{370}', '\u{374}'),
+    ('\u{376}', '\u{377}'),
+    ('\u{37b}', '\u{37d}'),
+    ('\u{37f}', '\u{37f}'),
+    ('\u{386}', '\u{386}'),
+    ('\u{388}', '\u{38a}'),
+    ('\u{38c}', '\u{38c}'),
+    ('\u{38e}', '\u{3a1}'),
+    ('\u{3a3}', '\u{3f5}'),
+    ('\u{3f7}', '\u{481}'),
+    ('\u{48a}', '\u{52f}'),
+    ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{559}'),
+    ('\u{560}', '\u{588}'),
+    ('\u{5d0}', '\u{5ea}'),
+    ('\u{5ef}', '\u{5f2}'),
+    ('\u{620}', '\u{64a}'),
+    ('\u{66e}', '\u{66f}'),
+    ('\u{671}', '\u{6d3}'),
+    ('\u{6d5}', '\u{6d5}'),
+    ('\u{6e5}', '\u{6e6}'),
+    ('\u{6ee}', '\u{6ef}'),
+    ('\u{6fa}', '\u{6fc}'),
+    ('\u{6ff}', '\u{6ff}'),
+    ('\u{710}', '\u{710}'),
+    ('\u{712}', '\u{72f}'),
+    ('\u{74d}', '\u{7a5}'),
+    ('\u{7b1}', '\u{7b1}'),
+    ('\u{7ca}', '\u{7ea}'),
+    ('\u{7f4}', '\u{7f5}'),
+    ('\u{7fa}', '\u{7fa}'),
+    ('\u{800}', '\u{815}'),
+    ('\u{81a}', '\u{81a}'),
+    ('\u{824}', '\u{824}'),
+    ('\u{828}', '\u{828}'),
+    ('\u{840}', '\u{858}'),
+    ('\u{860}', '\u{86a}'),
+    ('\u{870}', '\u{887}'),
+    ('\u{889}', '\u{88f}'),
+    ('\u{8a0}', '\u{8c9}'),
+    ('\u{904}', '\u{939}'),
+    ('\u{93d}', '\u{93d}'),
+    ('\u{950}', '\u{950}'),
+    ('\u{958}', '\u{961}'),
+    ('\u{971}', '\u{980}'),
+    ('\u{985}', '\u{98c}'),
+    ('\u{98f}', '\u{990}'),
+    ('\u{993}', '\u{9a8}'),
+    ('\u{9aa}', '\u{9b0}'),
+    ('\u{9b2}', '\u{9b2}'),
+    ('\u{9b6}', '\u{9b9}'),
+    ('\u{9bd}', '\u{9bd}'),
+    ('\u{9ce}', '\u{9ce}'),
+    ('\u{9dc}', '\u{9dd}'),
+    ('\u{9df}', '\u{9e1}'),
+    ('\u{9f0}', '\u{9f1}'),
+    ('\u{9fc}', '\u{9fc}'),
+    ('\u{a05}', '\u{a0a}'),
+    ('\u{a0f}', '\u{a10}'),
+    ('\u{a13}', '\u{a28}'),
+    ('\u{a2a}', '\u{a30}'),
+    ('\u{a32}', '\u{a33}'),
+    ('\u{a35}', '\u{a36}'),
+    ('\u{a38}', '\u{a39}'),
+    ('\u{a59}', '\u{a5c}'),
+    ('\u{a5e}', '\u{a5e}'),
+    ('\u{a72}', '\u{a74}'),
+    ('\u{a85}', '\u{a8d}'),
+    ('\u{a8f}', '\u{a91}'),
+    ('\u{a93}', '\u{aa8}'),
+    ('\u{aaa}', '\u{ab0}'),
+    ('\u{ab2}', '\u{ab3}'),
+    ('\u{ab5}', '\u{ab9}'),
+    ('\u{abd}', '\u{abd}'),
+    ('\u{ad0}', '\u{ad0}'),
+    ('\u{ae0}', '\u{ae1}'),
+    ('\u{af9}', '\u{af9}'),
+    ('\u{b05}', '\u{b0c}'),
+    ('\u{b0f}', '\u{b10}'),
+    ('\u{b13}', '\u{b28}'),
+    ('\u{b2a}', '\u{b30}'),
+    ('\u{b32}', '\u{b33}'),
+    ('\u{b35}', '\u{b39}'),
+    ('\u{b3d}', '\u{b3d}'),
+    ('\u{b5c}', '\u{b5d}'),
+    ('\u{b5f}', '\u{b61}'),
+    ('\u{b71}', '\u{b71}'),
+    ('\u{b83}', '\u{b83}'),
+    ('\u{b85}', '\u{b8a}'),
+    ('\u{b8e}', '\u{b90}'),
+    ('\u{b92}', '\u{b95}'),
+    ('\u{b99}', '\u{b9a}'),
+    ('\u{b9c}', '\u{b9c}'),
+    ('\u{b9e}', '\u{b9f}'),
+    ('\u{ba3}', '\u{ba4}'),
+    ('\u{ba8}', '\u{baa}'),
+    ('\u{bae}', '\u{bb9}'),
+    ('\u{bd0}', '\u{bd0}'),
+    ('\u{c05}', '\u{c0c}'),
+    ('\u{c0e}', '\u{c10}'),
+    ('\u{c12}', '\u{c28}'),
+    ('\u{c2a}', '\u{c39}'),
+    ('\u{c3d}', '\u{c3d}'),
+    ('\u{c58}', '\u{c5a}'),
+    ('\u{c5c}', '\u{c5d}'),
+    ('\u{c60}', '\u{c61}'),
+    ('\u{c80}', '\u{c80}'),
+    ('\u{c85}', '\u{c8c}'),
+    ('\u{c8e}', '\u{c90}'),
+    ('\u{c92}', '\u{ca8}'),
+    ('\u{caa}', '\u{cb3}'),
+    ('\u{cb5}', '\u{cb9}'),
+    ('\u{cbd}', '\u{cbd}'),
+    ('\u{cdc}', '\u{cde}'),
+    ('\u{ce0}', '\u{ce1}'),
+    ('\u{cf1}', '\u{cf2}'),
+    ('\u{d04}', '\u{d0c}'),
+    ('\u{d0e}', '\u{d10}'),
+    ('\u{d12}', '\u{d3a}'),
+    ('\u{d3d}', '\u{d3d}'),
+    ('\u{d4e}', '\u{d4e}'),
+    ('\u{d54}', '\u{d56}'),
+    ('\u{d5f}', '\u{d61}'),
+    ('\u{d7a}', '\u{d7f}'),
+    ('\u{d85}', '\u{d96}'),
+    ('\u{d9a}', '\u{db1}'),
+    ('\u{db3}', '\u{dbb}'),
+    ('\u{dbd}', '\u{dbd}'),
+    ('\u{dc0}', '\u{dc6}'),
+    ('\u{e01}', '\u{e30}'),
+    ('\u{e32}', '\u{e32}'),
+    ('\u{e40}', '\u{e46}'),
+    ('\u{e81}', '\u{e82}'),
+    ('\u{e84}', '\u{e84}'),
+    ('\u{e86}', '\u{e8a}'),
+    ('\u{e8c}', '\u{ea3}'),
+    ('\u{ea5}', '\u{ea5}'),
+    ('\u{ea7}', '\u{eb0}'),
+    ('\u{eb2}', '\u{eb2}'),
+    ('\u{ebd}', '\u{ebd}'),
+    ('\u{ec0}', '\u{ec4}'),
+    ('\u{ec6}', '\u{ec6}'),
+    ('\u{edc}', '\u{edf}'),
+    ('\u{f00}', '\u{f00}'),
+    ('\u{f40}', '\u{f47}'),
+    ('\u{f49}', '\u{f6c}'),
+    ('\u{f88}', '\u{f8c}'),
+    ('\u{1000}', '\u{102a}'),
+    ('\u{103f}', '\u{103f}'),
+    ('\u{1050}', '\u{1055}'),
+    ('\u{105a}', '\u{105d}'),
+    ('\u{1061}', '\u{1061}'),
+    ('\u{1065}', '\u{1066}'),
+    ('\u{106e}', '\u{1070}'),
+    ('\u{1075}', '\u{1081}'),
+    ('\u{108e}', '\u{108e}'),
+    ('\u{10a0}', '\u{10c5}'),
+    ('\u{10c7}', '\u{10c7}'),
+    ('\u{10cd}', '\u{10cd}'),
+    ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{1248}'),
+    ('\u{124a}', '\u{124d}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128a}', '\u{128d}'),
+    ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'),
+    ('\u{12b8}', '\u{12be}'),
+    ('\u{12c0}', '\u{12c0}'),
+    ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'),
+    ('\u{12d8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135a}'),
+    ('\u{1380}', '\u{138f}'),
+    ('\u{13a0}', '\u{13f5}'),
+    ('\u{13f8}', '\u{13fd}'),
+    ('\u{1401}', '\u{166c}'),
+    ('\u{166f}', '\u{167f}'),
+    ('\u{1681}', '\u{169a}'),
+    ('\u{16a0}', '\u{16ea}'),
+    ('\u{16ee}', '\u{16f8}'),
+    ('\u{1700}', '\u{1711}'),
+    ('\u{171f}', '\u{1731}'),
+    ('\u{1740}', '\u{1751}'),
+    ('\u{1760}', '\u{176c}'),
+    ('\u{176e}', '\u{1770}'),
+    ('\u{1780}', '\u{17b3}'),
+    ('\u{17d7}', '\u{17d7}'),
+    ('\u{17dc}', '\u{17dc}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18a8}'),
+    ('\u{18aa}', '\u{18aa}'),
+    ('\u{18b0}', '\u{18f5}'),
+    ('\u{1900}', '\u{191e}'),
+    ('\u{1950}', '\u{196d}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19ab}'),
+    ('\u{19b0}', '\u{19c9}'),
+    ('\u{1a00}', '\u{1a16}'),
+    ('\u{1a20}', '\u{1a54}'),
+    ('\u{1aa7}', '\u{1aa7}'),
+    ('\u{1b05}', '\u{1b33}'),
+    ('\u{1b45}', '\u{1b4c}'),
+    ('\u{1b83}', '\u{1ba0}'),
+    ('\u{1bae}', '\u{1baf}'),
+    ('\u{1bba}', '\u{1be5}'),
+    ('\u{1c00}', '\u{1c23}'),
+    ('\u{1c4d}', '\u{1c4f}'),
+    ('\u{1c5a}', '\u{1c7d}'),
+    ('\u{1c80}', '\u{1c8a}'),
+    ('\u{1c90}', '\u{1cba}'),
+    ('\u{1cbd}', '\u{1cbf}'),
+    ('\u{1ce9}', '\u{1cec}'),
+    ('\u{1cee}', '\u{1cf3}'),
+    ('\u{1cf5}', '\u{1cf6}'),
+    ('\u{1cfa}', '\u{1cfa}'),
+    ('\u{1d00}', '\u{1dbf}'),
+    ('\u{1e00}', '\u{1f15}'),
+    ('\u{1f18}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{1f45}'),
+    ('\u{1f48}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{1f57}'),
+    ('\u{1f59}', '\u{1f59}'),
+    ('\u{1f5b}', '\u{1f5b}'),
+    ('\u{1f5d}', '\u{1f5d}'),
+    ('\u{1f5f}', '\u{1f7d}'),
+    ('\u{1f80}', '\u{1fb4}'),
+    ('\u{1fb6}', '\u{1fbc}'),
+    ('\u{1fbe}', '\u{1fbe}'),
+    ('\u{1fc2}', '\u{1fc4}'),
+    ('\u{1fc6}', '\u{1fcc}'),
+    ('\u{1fd0}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fdb}'),
+    ('\u{1fe0}', '\u{1fec}'),
+    ('\u{1ff2}', '\u{1ff4}'),
+    ('\u{1ff6}', '\u{1ffc}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207f}', '\u{207f}'),
+    ('\u{2090}', '\u{209c}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210a}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211d}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212a}', '\u{2139}'),
+    ('\u{213c}', '\u{213f}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214e}', '\u{214e}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2c00}', '\u{2ce4}'),
+    ('\u{2ceb}', '\u{2cee}'),
+    ('\u{2cf2}', '\u{2cf3}'),
+    ('\u{2d00}', '\u{2d25}'),
+    ('\u{2d27}', '\u{2d27}'),
+    ('\u{2d2d}', '\u{2d2d}'),
+    ('\u{2d30}', '\u{2d67}'),
+    ('\u{2d6f}', '\u{2d6f}'),
+    ('\u{2d80}', '\u{2d96}'),
+    ('\u{2da0}', '\u{2da6}'),
+    ('\u{2da8}', '\u{2dae}'),
+    ('\u{2db0}', '\u{2db6}'),
+    ('\u{2db8}', '\u{2dbe}'),
+    ('\u{2dc0}', '\u{2dc6}'),
+    ('\u{2dc8}', '\u{2dce}'),
+    ('\u{2dd0}', '\u{2dd6}'),
+    ('\u{2dd8}', '\u{2dde}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303c}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{309d}', '\u{309f}'),
+    ('\u{30a1}', '\u{30fa}'),
+    ('\u{30fc}', '\u{30ff}'),
+    ('\u{3105}', '\u{312f}'),
+    ('\u{3131}', '\u{318e}'),
+    ('\u{31a0}', '\u{31bf}'),
+    ('\u{31f0}', '\u{31ff}'),
+    ('\u{3400}', '\u{4dbf}'),
+    ('\u{4e00}', '\u{a48c}'),
+    ('\u{a4d0}', '\u{a4fd}'),
+    ('\u{a500}', '\u{a60c}'),
+    ('\u{a610}', '\u{a61f}'),
+    ('\u{a62a}', '\u{a62b}'),
+    ('\u{a640}', '\u{a66e}'),
+    ('\u{a67f}', '\u{a69d}'),
+    ('\u{a6a0}', '\u{a6ef}'),
+    ('\u{a717}', '\u{a71f}'),
+    ('\u{a722}', '\u{a788}'),
+    ('\u{a78b}', '\u{a7dc}'),
+    ('\u{a7f1}', '\u{a801}'),
+    ('\u{a803}', '\u{a805}'),
+    ('\u{a807}', '\u{a80a}'),
+    ('\u{a80c}', '\u{a822}'),
+    ('\u{a840}', '\u{a873}'),
+    ('\u{a882}', '\u{a8b3}'),
+    ('\u{a8f2}', '\u{a8f7}'),
+    ('\u{a8fb}', '\u{a8fb}'),
+    ('\u{a8fd}', '\u{a8fe}'),
+    ('\u{a90a}', '\u{a925}'),
+    ('\u{a930}', '\u{a946}'),
+    ('\u{a960}', '\u{a97c}'),
+    ('\u{a984}', '\u{a9b2}'),
+    ('\u{a9cf}', '\u{a9cf}'),
+    ('\u{a9e0}', '\u{a9e4}'),
+    ('\u{a9e6}', '\u{a9ef}'),
+    ('\u{a9fa}', '\u{a9fe}'),
+    ('\u{aa00}', '\u{aa28}'),
+    ('\u{aa40}', '\u{aa42}'),
+    ('\u{aa44}', '\u{aa4b}'),
+    ('\u{aa60}', '\u{aa76}'),
+    ('\u{aa7a}', '\u{aa7a}'),
+    ('\u{aa7e}', '\u{aaaf}'),
+    ('\u{aab1}', '\u{aab1}'),
+    ('\u{aab5}', '\u{aab6}'),
+    ('\u{aab9}', '\u{aabd}'),
+    ('\u{aac0}', '\u{aac0}'),
+    ('\u{aac2}', '\u{aac2}'),
+    ('\u{aadb}', '\u{aadd}'),
+    ('\u{aae0}', '\u{aaea}'),
+    ('\u{aaf2}', '\u{aaf4}'),
+    ('\u{ab01}', '\u{ab06}'),
+    ('\u{ab09}', '\u{ab0e}'),
+    ('\u{ab11}', '\u{ab16}'),
+    ('\u{ab20}', '\u{ab26}'),
+    ('\u{ab28}', '\u{ab2e}'),
+    ('\u{ab30}', '\u{ab5a}'),
+    ('\u{ab5c}', '\u{ab69}'),
+    ('\u{ab70}', '\u{abe2}'),
+    ('\u{ac00}', '\u{d7a3}'),
+    ('\u{d7b0}', '\u{d7c6}'),
+    ('\u{d7cb}', '\u{d7fb}'),
+    ('\u{f900}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'),
+    ('\u{fb00}', '\u{fb06}'),
+    ('\u{fb13}', '\u{fb17}'),
+    ('\u{fb1d}', '\u{fb1d}'),
+    ('\u{fb1f}', '\u{fb28}'),
+    ('\u{fb2a}', '\u{fb36}'),
+    ('\u{fb38}', '\u{fb3c}'),
+    ('\u{fb3e}', '\u{fb3e}'),
+    ('\u{fb40}', '\u{fb41}'),
+    ('\u{fb43}', '\u{fb44}'),
+    ('\u{fb46}', '\u{fbb1}'),
+    ('\u{fbd3}', '\u{fc5d}'),
+    ('\u{fc64}', '\u{fd3d}'),
+    ('\u{fd50}', '\u{fd8f}'),
+    ('\u{fd92}', '\u{fdc7}'),
+    ('\u{fdf0}', '\u{fdf9}'),
+    ('\u{fe71}', '\u{fe71}'),
+    ('\u{fe73}', '\u{fe73}'),
+    ('\u{fe77}', '\u{fe77}'),
+    ('\u{fe79}', '\u{fe79}'),
+    ('\u{fe7b}', '\u{fe7b}'),
+    ('\u{fe7d}', '\u{fe7d}'),
+    ('\u{fe7f}', '\u{fefc}'),
+    ('\u{ff21}', '\u{ff3a}'),
+    ('\u{ff41}', '\u{ff5a}'),
+    ('\u{ff66}', '\u{ff9d}'),
+    ('\u{ffa0}', '\u{ffbe}'),
+    ('\u{ffc2}', '\u{ffc7}'),
+    ('\u{ffca}', '\u{ffcf}'),
+    ('\u{ffd2}', '\u{ffd7}'),
+    ('\u{ffda}', '\u{ffdc}'),
+    ('\u{10000}', '\u{1000b}'),
+    ('\u{1000d}', '\u{10026}'),
+    ('\u{10028}', '\u{1003a}'),
+    ('\u{1003c}', '\u{1003d}'),
+    ('\u{1003f}', '\u{1004d}'),
+    ('\u{10050}', '\u{1005d}'),
+    ('\u{10080}', '\u{100fa}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{10280}', '\u{1029c}'),
+    ('\u{102a0}', '\u{102d0}'),
+    ('\u{10300}', '\u{1031f}'),
+    ('\u{1032d}', '\u{1034a}'),
+    ('\u{10350}', '\u{10375}'),
+    ('\u{10380}', '\u{1039d}'),
+    ('\u{103a0}', '\u{103c3}'),
+    ('\u{103c8}', '\u{103cf}'),
+    ('\u{103d1}', '\u{103d5}'),
+    ('\u{10400}', '\u{1049d}'),
+    ('\u{104b0}', '\u{104d3}'),
+    ('\u{104d8}', '\u{104fb}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057a}'),
+    ('\u{1057c}', '\u{1058a}'),
+    ('\u{1058c}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105a1}'),
+    ('\u{105a3}', '\u{105b1}'),
+    ('\u{105b3}', '\u{105b9}'),
+    ('\u{105bb}', '\u{105bc}'),
+    ('\u{105c0}', '\u{105f3}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107b0}'),
+    ('\u{107b2}', '\u{107ba}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080a}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083c}', '\u{1083c}'),
+    ('\u{1083f}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089e}'),
+    ('\u{108e0}', '\u{108f2}'),
+    ('\u{108f4}', '\u{108f5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10940}', '\u{10959}'),
+    ('\u{10980}', '\u{109b7}'),
+    ('\u{109be}', '\u{109bf}'),
+    ('\u{10a00}', '\u{10a00}'),
+    ('\u{10a10}', '\u{10a13}'),
+    ('\u{10a15}', '\u{10a17}'),
+    ('\u{10a19}', '\u{10a35}'),
+    ('\u{10a60}', '\u{10a7c}'),
+    ('\u{10a80}', '\u{10a9c}'),
+    ('\u{10ac0}', '\u{10ac7}'),
+    ('\u{10ac9}', '\u{10ae4}'),
+    ('\u{10b00}', '\u{10b35}'),
+    ('\u{10b40}', '\u{10b55}'),
+    ('\u{10b60}', '\u{10b72}'),
+    ('\u{10b80}', '\u{10b91}'),
+    ('\u{10c00}', '\u{10c48}'),
+    ('\u{10c80}', '\u{10cb2}'),
+    ('\u{10cc0}', '\u{10cf2}'),
+    ('\u{10d00}', '\u{10d23}'),
+    ('\u{10d4a}', '\u{10d65}'),
+    ('\u{10d6f}', '\u{10d85}'),
+    ('\u{10e80}', '\u{10ea9}'),
+    ('\u{10eb0}', '\u{10eb1}'),
+    ('\u{10ec2}', '\u{10ec7}'),
+    ('\u{10f00}', '\u{10f1c}'),
+    ('\u{10f27}', '\u{10f27}'),
+    ('\u{10f30}', '\u{10f45}'),
+    ('\u{10f70}', '\u{10f81}'),
+    ('\u{10fb0}', '\u{10fc4}'),
+    ('\u{10fe0}', '\u{10ff6}'),
+    ('\u{11003}', '\u{11037}'),
+    ('\u{11071}', '\u{11072}'),
+    ('\u{11075}', '\u{11075}'),
+    ('\u{11083}', '\u{110af}'),
+    ('\u{110d0}', '\u{110e8}'),
+    ('\u{11103}', '\u{11126}'),
+    ('\u{11144}', '\u{11144}'),
+    ('\u{11147}', '\u{11147}'),
+    ('\u{11150}', '\u{11172}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11183}', '\u{111b2}'),
+    ('\u{111c1}', '\u{111c4}'),
+    ('\u{111da}', '\u{111da}'),
+    ('\u{111dc}', '\u{111dc}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{1122b}'),
+    ('\u{1123f}', '\u{11240}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128a}', '\u{1128d}'),
+    ('\u{1128f}', '\u{1129d}'),
+    ('\u{1129f}', '\u{112a8}'),
+    ('\u{112b0}', '\u{112de}'),
+    ('\u{11305}', '\u{1130c}'),
+    ('\u{1130f}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132a}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133d}', '\u{1133d}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{1135d}', '\u{11361}'),
+    ('\u{11380}', '\u{11389}'),
+    ('\u{1138b}', '\u{1138b}'),
+    ('\u{1138e}', '\u{1138e}'),
+    ('\u{11390}', '\u{113b5}'),
+    ('\u{113b7}', '\u{113b7}'),
+    ('\u{113d1}', '\u{113d1}'),
+    ('\u{113d3}', '\u{113d3}'),
+    ('\u{11400}', '\u{11434}'),
+    ('\u{11447}', '\u{1144a}'),
+    ('\u{1145f}', '\u{11461}'),
+    ('\u{11480}', '\u{114af}'),
+    ('\u{114c4}', '\u{114c5}'),
+    ('\u{114c7}', '\u{114c7}'),
+    ('\u{11580}', '\u{115ae}'),
+    ('\u{115d8}', '\u{115db}'),
+    ('\u{11600}', '\u{1162f}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11680}', '\u{116aa}'),
+    ('\u{116b8}', '\u{116b8}'),
+    ('\u{11700}', '\u{1171a}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1182b}'),
+    ('\u{118a0}', '\u{118df}'),
+    ('\u{118ff}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190c}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{1192f}'),
+    ('\u{1193f}', '\u{1193f}'),
+    ('\u{11941}', '\u{11941}'),
+    ('\u{119a0}', '\u{119a7}'),
+    ('\u{119aa}', '\u{119d0}'),
+    ('\u{119e1}', '\u{119e1}'),
+    ('\u{119e3}', '\u{119e3}'),
+    ('\u{11a00}', '\u{11a00}'),
+    ('\u{11a0b}', '\u{11a32}'),
+    ('\u{11a3a}', '\u{11a3a}'),
+    ('\u{11a50}', '\u{11a50}'),
+    ('\u{11a5c}', '\u{11a89}'),
+    ('\u{11a9d}', '\u{11a9d}'),
+    ('\u{11ab0}', '\u{11af8}'),
+    ('\u{11bc0}', '\u{11be0}'),
+    ('\u{11c00}', '\u{11c08}'),
+    ('\u{11c0a}', '\u{11c2e}'),
+    ('\u{11c40}', '\u{11c40}'),
+    ('\u{11c72}', '\u{11c8f}'),
+    ('\u{11d00}', '\u{11d06}'),
+    ('\u{11d08}', '\u{11d09}'),
+    ('\u{11d0b}', '\u{11d30}'),
+    ('\u{11d46}', '\u{11d46}'),
+    ('\u{11d60}', '\u{11d65}'),
+    ('\u{11d67}', '\u{11d68}'),
+    ('\u{11d6a}', '\u{11d89}'),
+    ('\u{11d98}', '\u{11d98}'),
+    ('\u{11db0}', '\u{11ddb}'),
+    ('\u{11ee0}', '\u{11ef2}'),
+    ('\u{11f02}', '\u{11f02}'),
+    ('\u{11f04}', '\u{11f10}'),
+    ('\u{11f12}', '\u{11f33}'),
+    ('\u{11fb0}', '\u{11fb0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246e}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12f90}', '\u{12ff0}'),
+    ('\u{13000}', '\u{1342f}'),
+    ('\u{13441}', '\u{13446}'),
+    ('\u{13460}', '\u{143fa}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16100}', '\u{1611d}'),
+    ('\u{16800}', '\u{16a38}'),
+    ('\u{16a40}', '\u{16a5e}'),
+    ('\u{16a70}', '\u{16abe}'),
+    ('\u{16ad0}', '\u{16aed}'),
+    ('\u{16b00}', '\u{16b2f}'),
+    ('\u{16b40}', '\u{16b43}'),
+    ('\u{16b63}', '\u{16b77}'),
+    ('\u{16b7d}', '\u{16b8f}'),
+    ('\u{16d40}', '\u{16d6c}'),
+    ('\u{16e40}', '\u{16e7f}'),
+    ('\u{16ea0}', '\u{16eb8}'),
+    ('\u{16ebb}', '\u{16ed3}'),
+    ('\u{16f00}', '\u{16f4a}'),
+    ('\u{16f50}', '\u{16f50}'),
+    ('\u{16f93}', '\u{16f9f}'),
+    ('\u{16fe0}', '\u{16fe1}'),
+    ('\u{16fe3}', '\u{16fe3}'),
+    ('\u{16ff2}', '\u{16ff6}'),
+    ('\u{17000}', '\u{18cd5}'),
+    ('\u{18cff}', '\u{18d1e}'),
+    ('\u{18d80}', '\u{18df2}'),
+    ('\u{1aff0}', '\u{1aff3}'),
+    ('\u{1aff5}', '\u{1affb}'),
+    ('\u{1affd}', '\u{1affe}'),
+    ('\u{1b000}', '\u{1b122}'),
+    ('\u{1b132}', '\u{1b132}'),
+    ('\u{1b150}', '\u{1b152}'),
+    ('\u{1b155}', '\u{1b155}'),
+    ('\u{1b164}', '\u{1b167}'),
+    ('\u{1b170}', '\u{1b2fb}'),
+    ('\u{1bc00}', '\u{1bc6a}'),
+    ('\u{1bc70}', '\u{1bc7c}'),
+    ('\u{1bc80}', '\u{1bc88}'),
+    ('\u{1bc90}', '\u{1bc99}'),
+    ('\u{1d400}', '\u{1d454}'),
+    ('\u{1d456}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'),
+    ('\u{1d4a2}', '\u{1d4a2}'),
+    ('\u{1d4a5}', '\u{1d4a6}'),
+    ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b9}'),
+    ('\u{1d4bb}', '\u{1d4bb}'),
+    ('\u{1d4bd}', '\u{1d4c3}'),
+    ('\u{1d4c5}', '\u{1d505}'),
+    ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'),
+    ('\u{1d516}', '\u{1d51c}'),
+    ('\u{1d51e}', '\u{1d539}'),
+    ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'),
+    ('\u{1d546}', '\u{1d546}'),
+    ('\u{1d54a}', '\u{1d550}'),
+    ('\u{1d552}', '\u{1d6a5}'),
+    ('\u{1d6a8}', '\u{1d6c0}'),
+    ('\u{1d6c2}', '\u{1d6da}'),
+    ('\u{1d6dc}', '\u{1d6fa}'),
+    ('\u{1d6fc}', '\u{1d714}'),
+    ('\u{1d716}', '\u{1d734}'),
+    ('\u{1d736}', '\u{1d74e}'),
+    ('\u{1d750}', '\u{1d76e}'),
+    ('\u{1d770}', '\u{1d788}'),
+    ('\u{1d78a}', '\u{1d7a8}'),
+    ('\u{1d7aa}', '\u{1d7c2}'),
+    ('\u{1d7c4}', '\u{1d7cb}'),
+    ('\u{1df00}', '\u{1df1e}'),
+    ('\u{1df25}', '\u{1df2a}'),
+    ('\u{1e030}', '\u{1e06d}'),
+    ('\u{1e100}', '\u{1e12c}'),
+    ('\u{1e137}', '\u{1e13d}'),
+    ('\u{1e14e}', '\u{1e14e}'),
+    ('\u{1e290}', '\u{1e2ad}'),
+    ('\u{1e2c0}', '\u{1e2eb}'),
+    ('\u{1e4d0}', '\u{1e4eb}'),
+    ('\u{1e5d0}', '\u{1e5ed}'),
+    ('\u{1e5f0}', '\u{1e5f0}'),
+    ('\u{1e6c0}', '\u{1e6de}'),
+    ('\u{1e6e0}', '\u{1e6e2}'),
+    ('\u{1e6e4}', '\u{1e6e5}'),
+    ('\u{1e6e7}', '\u{1e6ed}'),
+    ('\u{1e6f0}', '\u{1e6f4}'),
+    ('\u{1e6fe}', '\u{1e6ff}'),
+    ('\u{1e7e0}', '\u{1e7e6}'),
+    ('\u{1e7e8}', '\u{1e7eb}'),
+    ('\u{1e7ed}', '\u{1e7ee}'),
+    ('\u{1e7f0}', '\u{1e7fe}'),
+    ('\u{1e800}', '\u{1e8c4}'),
+    ('\u{1e900}', '\u{1e943}'),
+    ('\u{1e94b}', '\u{1e94b}'),
+    ('\u{1ee00}', '\u{1ee03}'),
+    ('\u{1ee05}', '\u{1ee1f}'),
+    ('\u{1ee21}', '\u{1ee22}'),
+    ('\u{1ee24}', '\u{1ee24}'),
+    ('\u{1ee27}', '\u{1ee27}'),
+    ('\u{1ee29}', '\u{1ee32}'),
+    ('\u{1ee34}', '\u{1ee37}'),
+    ('\u{1ee39}', '\u{1ee39}'),
+    ('\u{1ee3b}', '\u{1ee3b}'),
+    ('\u{1ee42}', '\u{1ee42}'),
+    ('\u{1ee47}', '\u{1ee47}'),
+    ('\u{1ee49}', '\u{1ee49}'),
+    ('\u{1ee4b}', '\u{1ee4b}'),
+    ('\u{1ee4d}', '\u{1ee4f}'),
+    ('\u{1ee51}', '\u{1ee52}'),
+    ('\u{1ee54}', '\u{1ee54}'),
+    ('\u{1ee57}', '\u{1ee57}'),
+    ('\u{1ee59}', '\u{1ee59}'),
+    ('\u{1ee5b}', '\u{1ee5b}'),
+    ('\u{1ee5d}', '\u{1ee5d}'),
+    ('\u{1ee5f}', '\u{1ee5f}'),
+    ('\u{1ee61}', '\u{1ee62}'),
+    ('\u{1ee64}', '\u{1ee64}'),
+    ('\u{1ee67}', '\u{1ee6a}'),
+    ('\u{1ee6c}', '\u{1ee72}'),
+    ('\u{1ee74}', '\u{1ee77}'),
+    ('\u{1ee79}', '\u{1ee7c}'),
+    ('\u{1ee7e}', '\u{1ee7e}'),
+    ('\u{1ee80}', '\u{1ee89}'),
+    ('\u{1ee8b}', '\u{1ee9b}'),
+    ('\u{1eea1}', '\u{1eea3}'),
+    ('\u{1eea5}', '\u{1eea9}'),
+    ('\u{1eeab}', '\u{1eebb}'),
+    ('\u{20000}', '\u{2a6df}'),
+    ('\u{2a700}', '\u{2b81d}'),
+    ('\u{2b820}', '\u{2cead}'),
+    ('\u{2ceb0}', '\u{2ebe0}'),
+    ('\u{2ebf0}', '\u{2ee5d}'),
+    ('\u{2f800}', '\u{2fa1d}'),
+    ('\u{30000}', '\u{3134a}'),
+    ('\u{31350}', '\u{33479}'),
+];
+
+pub const XID_CONTINUE_TABLE: &'static [(char, char)] = &[
+    ('\u{30}', '\u{39}'),
+    ('\u{41}', '\u{5a}'),
+    ('\u{5f}', '\u{5f}'),
+    ('\u{61}', '\u{7a}'),
+    ('\u{aa}', '\u{aa}'),
+    ('\u{b5}', '\u{b5}'),
+    ('\u{b7}', '\u{b7}'),
+    ('\u{ba}', '\u{ba}'),
+    ('\u{c0}', '\u{d6}'),
+    ('\u{d8}', '\u{f6}'),
+    ('\u{f8}', '\u{2c1}'),
+    ('\u{2c6}', '\u{2d1}'),
+    ('\u{2e0}', '\u{2e4}'),
+    ('\u{2ec}', '\u{2ec}'),
+    ('\u{2ee}', '\u{2ee}'),
+    ('\u{300}', '\u{374}'),
+    ('\u{376}', '\u{377}'),
+    ('\u{37b}', '\u{37d}'),
+    ('\u{37f}', '\u{37f}'),
+    ('\u{386}', '\u{38a}'),
+    ('\u{38c}', '\u{38c}'),
+    ('\u{38e}', '\u{3a1}'),
+    ('\u{3a3}', '\u{3f5}'),
+    ('\u{3f7}', '\u{481}'),
+    ('\u{483}', '\u{487}'),
+    ('\u{48a}', '\u{52f}'),
+    ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{559}'),
+    ('\u{560}', '\u{588}'),
+    ('\u{591}', '\u{5bd}'),
+    ('\u{5bf}', '\u{5bf}'),
+    ('\u{5c1}', '\u{5c2}'),
+    ('\u{5c4}', '\u{5c5}'),
+    ('\u{5c7}', '\u{5c7}'),
+    ('\u{5d0}', '\u{5ea}'),
+    ('\u{5ef}', '\u{5f2}'),
+    ('\u{610}', '\u{61a}'),
+    ('\u{620}', '\u{669}'),
+    ('\u{66e}', '\u{6d3}'),
+    ('\u{6d5}', '\u{6dc}'),
+    ('\u{6df}', '\u{6e8}'),
+    ('\u{6ea}', '\u{6fc}'),
+    ('\u{6ff}', '\u{6ff}'),
+    ('\u{710}', '\u{74a}'),
+    ('\u{74d}', '\u{7b1}'),
+    ('\u{7c0}', '\u{7f5}'),
+    ('\u{7fa}', '\u{7fa}'),
+    ('\u{7fd}', '\u{7fd}'),
+    ('\u{800}', '\u{82d}'),
+    ('\u{840}', '\u{85b}'),
+    ('\u{860}', '\u{86a}'),
+    ('\u{870}', '\u{887}'),
+    ('\u{889}', '\u{88f}'),
+    ('\u{897}', '\u{8e1}'),
+    ('\u{8e3}', '\u{963}'),
+    ('\u{966}', '\u{96f}'),
+    ('\u{971}', '\u{983}'),
+    ('\u{985}', '\u{98c}'),
+    ('\u{98f}', '\u{990}'),
+    ('\u{993}', '\u{9a8}'),
+    ('\u{9aa}', '\u{9b0}'),
+    ('\u{9b2}', '\u{9b2}'),
+    ('\u{9b6}', '\u{9b9}'),
+    ('\u{9bc}', '\u{9c4}'),
+    ('\u{9c7}', '\u{9c8}'),
+    ('\u{9cb}', '\u{9ce}'),
+    ('\u{9d7}', '\u{9d7}'),
+    ('\u{9dc}', '\u{9dd}'),
+    ('\u{9df}', '\u{9e3}'),
+    ('\u{9e6}', '\u{9f1}'),
+    ('\u{9fc}', '\u{9fc}'),
+    ('\u{9fe}', '\u{9fe}'),
+    ('\u{a01}', '\u{a03}'),
+    ('\u{a05}', '\u{a0a}'),
+    ('\u{a0f}', '\u{a10}'),
+    ('\u{a13}', '\u{a28}'),
+    ('\u{a2a}', '\u{a30}'),
+    ('\u{a32}', '\u{a33}'),
+    ('\u{a35}', '\u{a36}'),
+    ('\u{a38}', '\u{a39}'),
+    ('\u{a3c}', '\u{a3c}'),
+    ('\u{a3e}', '\u{a42}'),
+    ('\u{a47}', '\u{a48}'),
+    ('\u{a4b}', '\u{a4d}'),
+    ('\u{a51}', '\u{a51}'),
+    ('\u{a59}', '\u{a5c}'),
+    ('\u{a5e}', '\u{a5e}'),
+    ('\u{a66}', '\u{a75}'),
+    ('\u{a81}', '\u{a83}'),
+    ('\u{a85}', '\u{a8d}'),
+    ('\u{a8f}', '\u{a91}'),
+    ('\u{a93}', '\u{aa8}'),
+    ('\u{aaa}', '\u{ab0}'),
+    ('\u{ab2}', '\u{ab3}'),
+    ('\u{ab5}', '\u{ab9}'),
+    ('\u{abc}', '\u{ac5}'),
+    ('\u{ac7}', '\u{ac9}'),
+    ('\u{acb}', '\u{acd}'),
+    ('\u{ad0}', '\u{ad0}'),
+    ('\u{ae0}', '\u{ae3}'),
+    ('\u{ae6}', '\u{aef}'),
+    ('\u{af9}', '\u{aff}'),
+    ('\u{b01}', '\u{b03}'),
+    ('\u{b05}', '\u{b0c}'),
+    ('\u{b0f}', '\u{b10}'),
+    ('\u{b13}', '\u{b28}'),
+    ('\u{b2a}', '\u{b30}'),
+    ('\u{b32}', '\u{b33}'),
+    ('\u{b35}', '\u{b39}'),
+    ('\u{b3c}', '\u{b44}'),
+    ('\u{b47}', '\u{b48}'),
+    ('\u{b4b}', '\u{b4d}'),
+    ('\u{b55}', '\u{b57}'),
+    ('\u{b5c}', '\u{b5d}'),
+    ('\u{b5f}', '\u{b63}'),
+    ('\u{b66}', '\u{b6f}'),
+    ('\u{b71}', '\u{b71}'),
+    ('\u{b82}', '\u{b83}'),
+    ('\u{b85}', '\u{b8a}'),
+    ('\u{b8e}', '\u{b90}'),
+    ('\u{b92}', '\u{b95}'),
+    ('\u{b99}', '\u{b9a}'),
+    ('\u{b9c}', '\u{b9c}'),
+    ('\u{b9e}', '\u{b9f}'),
+    ('\u{ba3}', '\u{ba4}'),
+    ('\u{ba8}', '\u{baa}'),
+    ('\u{bae}', '\u{bb9}'),
+    ('\u{bbe}', '\u{bc2}'),
+    ('\u{bc6}', '\u{bc8}'),
+    ('\u{bca}', '\u{bcd}'),
+    ('\u{bd0}', '\u{bd0}'),
+    ('\u{bd7}', '\u{bd7}'),
+    ('\u{be6}', '\u{bef}'),
+    ('\u{c00}', '\u{c0c}'),
+    ('\u{c0e}', '\u{c10}'),
+    ('\u{c12}', '\u{c28}'),
+    ('\u{c2a}', '\u{c39}'),
+    ('\u{c3c}', '\u{c44}'),
+    ('\u{c46}', '\u{c48}'),
+    ('\u{c4a}', '\u{c4d}'),
+    ('\u{c55}', '\u{c56}'),
+    ('\u{c58}', '\u{c5a}'),
+    ('\u{c5c}', '\u{c5d}'),
+    ('\u{c60}', '\u{c63}'),
+    ('\u{c66}', '\u{c6f}'),
+    ('\u{c80}', '\u{c83}'),
+    ('\u{c85}', '\u{c8c}'),
+    ('\u{c8e}', '\u{c90}'),
+    ('\u{c92}', '\u{ca8}'),
+    ('\u{caa}', '\u{cb3}'),
+    ('\u{cb5}', '\u{cb9}'),
+    ('\u{cbc}', '\u{cc4}'),
+    ('\u{cc6}', '\u{cc8}'),
+    ('\u{cca}', '\u{ccd}'),
+    ('\u{cd5}', '\u{cd6}'),
+    ('\u{cdc}', '\u{cde}'),
+    ('\u{ce0}', '\u{ce3}'),
+    ('\u{ce6}', '\u{cef}'),
+    ('\u{cf1}', '\u{cf3}'),
+    ('\u{d00}', '\u{d0c}'),
+    ('\u{d0e}', '\u{d10}'),
+    ('\u{d12}', '\u{d44}'),
+    ('\u{d46}', '\u{d48}'),
+    ('\u{d4a}', '\u{d4e}'),
+    ('\u{d54}', '\u{d57}'),
+    ('\u{d5f}', '\u{d63}'),
+    ('\u{d66}', '\u{d6f}'),
+    ('\u{d7a}', '\u{d7f}'),
+    ('\u{d81}', '\u{d83}'),
+    ('\u{d85}', '\u{d96}'),
+    ('\u{d9a}', '\u{db1}'),
+    ('\u{db3}', '\u{dbb}'),
+    ('\u{dbd}', '\u{dbd}'),
+    ('\u{dc0}', '\u{dc6}'),
+    ('\u{dca}', '\u{dca}'),
+    ('\u{dcf}', '\u{dd4}'),
+    ('\u{dd6}', '\u{dd6}'),
+    ('\u{dd8}', '\u{ddf}'),
+    ('\u{de6}', '\u{def}'),
+    ('\u{df2}', '\u{df3}'),
+    ('\u{e01}', '\u{e3a}'),
+    ('\u{e40}', '\u{e4e}'),
+    ('\u{e50}', '\u{e59}'),
+    ('\u{e81}', '\u{e82}'),
+    ('\u{e84}', '\u{e84}'),
+    ('\u{e86}', '\u{e8a}'),
+    ('\u{e8c}', '\u{ea3}'),
+    ('\u{ea5}', '\u{ea5}'),
+    ('\u{ea7}', '\u{ebd}'),
+    ('\u{ec0}', '\u{ec4}'),
+    ('\u{ec6}', '\u{ec6}'),
+    ('\u{ec8}', '\u{ece}'),
+    ('\u{ed0}', '\u{ed9}'),
+    ('\u{edc}', '\u{edf}'),
+    ('\u{f00}', '\u{f00}'),
+    ('\u{f18}', '\u{f19}'),
+    ('\u{f20}', '\u{f29}'),
+    ('\u{f35}', '\u{f35}'),
+    ('\u{f37}', '\u{f37}'),
+    ('\u{f39}', '\u{f39}'),
+    ('\u{f3e}', '\u{f47}'),
+    ('\u{f49}', '\u{f6c}'),
+    ('\u{f71}', '\u{f84}'),
+    ('\u{f86}', '\u{f97}'),
+    ('\u{f99}', '\u{fbc}'),
+    ('\u{fc6}', '\u{fc6}'),
+    ('\u{1000}', '\u{1049}'),
+    ('\u{1050}', '\u{109d}'),
+    ('\u{10a0}', '\u{10c5}'),
+    ('\u{10c7}', '\u{10c7}'),
+    ('\u{10cd}', '\u{10cd}'),
+    ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{1248}'),
+    ('\u{124a}', '\u{124d}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128a}', '\u{128d}'),
+    ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'),
+    ('\u{12b8}', '\u{12be}'),
+    ('\u{12c0}', '\u{12c0}'),
+    ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'),
+    ('\u{12d8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135a}'),
+    ('\u{135d}', '\u{135f}'),
+    ('\u{1369}', '\u{1371}'),
+    ('\u{1380}', '\u{138f}'),
+    ('\u{13a0}', '\u{13f5}'),
+    ('\u{13f8}', '\u{13fd}'),
+    ('\u{1401}', '\u{166c}'),
+    ('\u{166f}', '\u{167f}'),
+    ('\u{1681}', '\u{169a}'),
+    ('\u{16a0}', '\u{16ea}'),
+    ('\u{16ee}', '\u{16f8}'),
+    ('\u{1700}', '\u{1715}'),
+    ('\u{171f}', '\u{1734}'),
+    ('\u{1740}', '\u{1753}'),
+    ('\u{1760}', '\u{176c}'),
+    ('\u{176e}', '\u{1770}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{1780}', '\u{17d3}'),
+    ('\u{17d7}', '\u{17d7}'),
+    ('\u{17dc}', '\u{17dd}'),
+    ('\u{17e0}', '\u{17e9}'),
+    ('\u{180b}', '\u{180d}'),
+    ('\u{180f}', '\u{1819}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18aa}'),
+    ('\u{18b0}', '\u{18f5}'),
+    ('\u{1900}', '\u{191e}'),
+    ('\u{1920}', '\u{192b}'),
+    ('\u{1930}', '\u{193b}'),
+    ('\u{1946}', '\u{196d}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19ab}'),
+    ('\u{19b0}', '\u{19c9}'),
+    ('\u{19d0}', '\u{19da}'),
+    ('\u{1a00}', '\u{1a1b}'),
+    ('\u{1a20}', '\u{1a5e}'),
+    ('\u{1a60}', '\u{1a7c}'),
+    ('\u{1a7f}', '\u{1a89}'),
+    ('\u{1a90}', '\u{1a99}'),
+    ('\u{1aa7}', '\u{1aa7}'),
+    ('\u{1ab0}', '\u{1abd}'),
+    ('\u{1abf}', '\u{1add}'),
+    ('\u{1ae0}', '\u{1aeb}'),
+    ('\u{1b00}', '\u{1b4c}'),
+    ('\u{1b50}', '\u{1b59}'),
+    ('\u{1b6b}', '\u{1b73}'),
+    ('\u{1b80}', '\u{1bf3}'),
+    ('\u{1c00}', '\u{1c37}'),
+    ('\u{1c40}', '\u{1c49}'),
+    ('\u{1c4d}', '\u{1c7d}'),
+    ('\u{1c80}', '\u{1c8a}'),
+    ('\u{1c90}', '\u{1cba}'),
+    ('\u{1cbd}', '\u{1cbf}'),
+    ('\u{1cd0}', '\u{1cd2}'),
+    ('\u{1cd4}', '\u{1cfa}'),
+    ('\u{1d00}', '\u{1f15}'),
+    ('\u{1f18}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{1f45}'),
+    ('\u{1f48}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{1f57}'),
+    ('\u{1f59}', '\u{1f59}'),
+    ('\u{1f5b}', '\u{1f5b}'),
+    ('\u{1f5d}', '\u{1f5d}'),
+    ('\u{1f5f}', '\u{1f7d}'),
+    ('\u{1f80}', '\u{1fb4}'),
+    ('\u{1fb6}', '\u{1fbc}'),
+    ('\u{1fbe}', '\u{1fbe}'),
+    ('\u{1fc2}', '\u{1fc4}'),
+    ('\u{1fc6}', '\u{1fcc}'),
+    ('\u{1fd0}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fdb}'),
+    ('\u{1fe0}', '\u{1fec}'),
+    ('\u{1ff2}', '\u{1ff4}'),
+    ('\u{1ff6}', '\u{1ffc}'),
+    ('\u{200c}', '\u{200d}'),
+    ('\u{203f}', '\u{2040}'),
+    ('\u{2054}', '\u{2054}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207f}', '\u{207f}'),
+    ('\u{2090}', '\u{209c}'),
+    ('\u{20d0}', '\u{20dc}'),
+    ('\u{20e1}', '\u{20e1}'),
+    ('\u{20e5}', '\u{20f0}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210a}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211d}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212a}', '\u{2139}'),
+    ('\u{213c}', '\u{213f}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214e}', '\u{214e}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2c00}', '\u{2ce4}'),
+    ('\u{2ceb}', '\u{2cf3}'),
+    ('\u{2d00}', '\u{2d25}'),
+    ('\u{2d27}', '\u{2d27}'),
+    ('\u{2d2d}', '\u{2d2d}'),
+    ('\u{2d30}', '\u{2d67}'),
+    ('\u{2d6f}', '\u{2d6f}'),
+    ('\u{2d7f}', '\u{2d96}'),
+    ('\u{2da0}', '\u{2da6}'),
+    ('\u{2da8}', '\u{2dae}'),
+    ('\u{2db0}', '\u{2db6}'),
+    ('\u{2db8}', '\u{2dbe}'),
+    ('\u{2dc0}', '\u{2dc6}'),
+    ('\u{2dc8}', '\u{2dce}'),
+    ('\u{2dd0}', '\u{2dd6}'),
+    ('\u{2dd8}', '\u{2dde}'),
+    ('\u{2de0}', '\u{2dff}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{302f}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303c}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{3099}', '\u{309a}'),
+    ('\u{309d}', '\u{309f}'),
+    ('\u{30a1}', '\u{30ff}'),
+    ('\u{3105}', '\u{312f}'),
+    ('\u{3131}', '\u{318e}'),
+    ('\u{31a0}', '\u{31bf}'),
+    ('\u{31f0}', '\u{31ff}'),
+    ('\u{3400}', '\u{4dbf}'),
+    ('\u{4e00}', '\u{a48c}'),
+    ('\u{a4d0}', '\u{a4fd}'),
+    ('\u{a500}', '\u{a60c}'),
+    ('\u{a610}', '\u{a62b}'),
+    ('\u{a640}', '\u{a66f}'),
+    ('\u{a674}', '\u{a67d}'),
+    ('\u{a67f}', '\u{a6f1}'),
+    ('\u{a717}', '\u{a71f}'),
+    ('\u{a722}', '\u{a788}'),
+    ('\u{a78b}', '\u{a7dc}'),
+    ('\u{a7f1}', '\u{a827}'),
+    ('\u{a82c}', '\u{a82c}'),
+    ('\u{a840}', '\u{a873}'),
+    ('\u{a880}', '\u{a8c5}'),
+    ('\u{a8d0}', '\u{a8d9}'),
+    ('\u{a8e0}', '\u{a8f7}'),
+    ('\u{a8fb}', '\u{a8fb}'),
+    ('\u{a8fd}', '\u{a92d}'),
+    ('\u{a930}', '\u{a953}'),
+    ('\u{a960}', '\u{a97c}'),
+    ('\u{a980}', '\u{a9c0}'),
+    ('\u{a9cf}', '\u{a9d9}'),
+    ('\u{a9e0}', '\u{a9fe}'),
+    ('\u{aa00}', '\u{aa36}'),
+    ('\u{aa40}', '\u{aa4d}'),
+    ('\u{aa50}', '\u{aa59}'),
+    ('\u{aa60}', '\u{aa76}'),
+    ('\u{aa7a}', '\u{aac2}'),
+    ('\u{aadb}', '\u{aadd}'),
+    ('\u{aae0}', '\u{aaef}'),
+    ('\u{aaf2}', '\u{aaf6}'),
+    ('\u{ab01}', '\u{ab06}'),
+    ('\u{ab09}', '\u{ab0e}'),
+    ('\u{ab11}', '\u{ab16}'),
+    ('\u{ab20}', '\u{ab26}'),
+    ('\u{ab28}', '\u{ab2e}'),
+    ('\u{ab30}', '\u{ab5a}'),
+    ('\u{ab5c}', '\u{ab69}'),
+    ('\u{ab70}', '\u{abea}'),
+    ('\u{abec}', '\u{abed}'),
+    ('\u{abf0}', '\u{abf9}'),
+    ('\u{ac00}', '\u{d7a3}'),
+    ('\u{d7b0}', '\u{d7c6}'),
+    ('\u{d7cb}', '\u{d7fb}'),
+    ('\u{f900}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'),
+    ('\u{fb00}', '\u{fb06}'),
+    ('\u{fb13}', '\u{fb17}'),
+    ('\u{fb1d}', '\u{fb28}'),
+    ('\u{fb2a}', '\u{fb36}'),
+    ('\u{fb38}', '\u{fb3c}'),
+    ('\u{fb3e}', '\u{fb3e}'),
+    ('\u{fb40}', '\u{fb41}'),
+    ('\u{fb43}', '\u{fb44}'),
+    ('\u{fb46}', '\u{fbb1}'),
+    ('\u{fbd3}', '\u{fc5d}'),
+    ('\u{fc64}', '\u{fd3d}'),
+    ('\u{fd50}', '\u{fd8f}'),
+    ('\u{fd92}', '\u{fdc7}'),
+    ('\u{fdf0}', '\u{fdf9}'),
+    ('\u{fe00}', '\u{fe0f}'),
+    ('\u{fe20}', '\u{fe2f}'),
+    ('\u{fe33}', '\u{fe34}'),
+    ('\u{fe4d}', '\u{fe4f}'),
+    ('\u{fe71}', '\u{fe71}'),
+    ('\u{fe73}', '\u{fe73}'),
+    ('\u{fe77}', '\u{fe77}'),
+    ('\u{fe79}', '\u{fe79}'),
+    ('\u{fe7b}', '\u{fe7b}'),
+    ('\u{fe7d}', '\u{fe7d}'),
+    ('\u{fe7f}', '\u{fefc}'),
+    ('\u{ff10}', '\u{ff19}'),
+    ('\u{ff21}', '\u{ff3a}'),
+    ('\u{ff3f}', '\u{ff3f}'),
+    ('\u{ff41}', '\u{ff5a}'),
+    ('\u{ff65}', '\u{ffbe}'),
+    ('\u{ffc2}', '\u{ffc7}'),
+    ('\u{ffca}', '\u{ffcf}'),
+    ('\u{ffd2}', '\u{ffd7}'),
+    ('\u{ffda}', '\u{ffdc}'),
+    ('\u{10000}', '\u{1000b}'),
+    ('\u{1000d}', '\u{10026}'),
+    ('\u{10028}', '\u{1003a}'),
+    ('\u{1003c}', '\u{1003d}'),
+    ('\u{1003f}', '\u{1004d}'),
+    ('\u{10050}', '\u{1005d}'),
+    ('\u{10080}', '\u{100fa}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{101fd}', '\u{101fd}'),
+    ('\u{10280}', '\u{1029c}'),
+    ('\u{102a0}', '\u{102d0}'),
+    ('\u{102e0}', '\u{102e0}'),
+    ('\u{10300}', '\u{1031f}'),
+    ('\u{1032d}', '\u{1034a}'),
+    ('\u{10350}', '\u{1037a}'),
+    ('\u{10380}', '\u{1039d}'),
+    ('\u{103a0}', '\u{103c3}'),
+    ('\u{103c8}', '\u{103cf}'),
+    ('\u{103d1}', '\u{103d5}'),
+    ('\u{10400}', '\u{1049d}'),
+    ('\u{104a0}', '\u{104a9}'),
+    ('\u{104b0}', '\u{104d3}'),
+    ('\u{104d8}', '\u{104fb}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057a}'),
+    ('\u{1057c}', '\u{1058a}'),
+    ('\u{1058c}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105a1}'),
+    ('\u{105a3}', '\u{105b1}'),
+    ('\u{105b3}', '\u{105b9}'),
+    ('\u{105bb}', '\u{105bc}'),
+    ('\u{105c0}', '\u{105f3}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107b0}'),
+    ('\u{107b2}', '\u{107ba}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080a}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083c}', '\u{1083c}'),
+    ('\u{1083f}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089e}'),
+    ('\u{108e0}', '\u{108f2}'),
+    ('\u{108f4}', '\u{108f5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10940}', '\u{10959}'),
+    ('\u{10980}', '\u{109b7}'),
+    ('\u{109be}', '\u{109bf}'),
+    ('\u{10a00}', '\u{10a03}'),
+    ('\u{10a05}', '\u{10a06}'),
+    ('\u{10a0c}', '\u{10a13}'),
+    ('\u{10a15}', '\u{10a17}'),
+    ('\u{10a19}', '\u{10a35}'),
+    ('\u{10a38}', '\u{10a3a}'),
+    ('\u{10a3f}', '\u{10a3f}'),
+    ('\u{10a60}', '\u{10a7c}'),
+    ('\u{10a80}', '\u{10a9c}'),
+    ('\u{10ac0}', '\u{10ac7}'),
+    ('\u{10ac9}', '\u{10ae6}'),
+    ('\u{10b00}', '\u{10b35}'),
+    ('\u{10b40}', '\u{10b55}'),
+    ('\u{10b60}', '\u{10b72}'),
+    ('\u{10b80}', '\u{10b91}'),
+    ('\u{10c00}', '\u{10c48}'),
+    ('\u{10c80}', '\u{10cb2}'),
+    ('\u{10cc0}', '\u{10cf2}'),
+    ('\u{10d00}', '\u{10d27}'),
+    ('\u{10d30}', '\u{10d39}'),
+    ('\u{10d40}', '\u{10d65}'),
+    ('\u{10d69}', '\u{10d6d}'),
+    ('\u{10d6f}', '\u{10d85}'),
+    ('\u{10e80}', '\u{10ea9}'),
+    ('\u{10eab}', '\u{10eac}'),
+    ('\u{10eb0}', '\u{10eb1}'),
+    ('\u{10ec2}', '\u{10ec7}'),
+    ('\u{10efa}', '\u{10f1c}'),
+    ('\u{10f27}', '\u{10f27}'),
+    ('\u{10f30}', '\u{10f50}'),
+    ('\u{10f70}', '\u{10f85}'),
+    ('\u{10fb0}', '\u{10fc4}'),
+    ('\u{10fe0}', '\u{10ff6}'),
+    ('\u{11000}', '\u{11046}'),
+    ('\u{11066}', '\u{11075}'),
+    ('\u{1107f}', '\u{110ba}'),
+    ('\u{110c2}', '\u{110c2}'),
+    ('\u{110d0}', '\u{110e8}'),
+    ('\u{110f0}', '\u{110f9}'),
+    ('\u{11100}', '\u{11134}'),
+    ('\u{11136}', '\u{1113f}'),
+    ('\u{11144}', '\u{11147}'),
+    ('\u{11150}', '\u{11173}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11180}', '\u{111c4}'),
+    ('\u{111c9}', '\u{111cc}'),
+    ('\u{111ce}', '\u{111da}'),
+    ('\u{111dc}', '\u{111dc}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{11237}'),
+    ('\u{1123e}', '\u{11241}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128a}', '\u{1128d}'),
+    ('\u{1128f}', '\u{1129d}'),
+    ('\u{1129f}', '\u{112a8}'),
+    ('\u{112b0}', '\u{112ea}'),
+    ('\u{112f0}', '\u{112f9}'),
+    ('\u{11300}', '\u{11303}'),
+    ('\u{11305}', '\u{1130c}'),
+    ('\u{1130f}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132a}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133b}', '\u{11344}'),
+    ('\u{11347}', '\u{11348}'),
+    ('\u{1134b}', '\u{1134d}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{11357}', '\u{11357}'),
+    ('\u{1135d}', '\u{11363}'),
+    ('\u{11366}', '\u{1136c}'),
+    ('\u{11370}', '\u{11374}'),
+    ('\u{11380}', '\u{11389}'),
+    ('\u{1138b}', '\u{1138b}'),
+    ('\u{1138e}', '\u{1138e}'),
+    ('\u{11390}', '\u{113b5}'),
+    ('\u{113b7}', '\u{113c0}'),
+    ('\u{113c2}', '\u{113c2}'),
+    ('\u{113c5}', '\u{113c5}'),
+    ('\u{113c7}', '\u{113ca}'),
+    ('\u{113cc}', '\u{113d3}'),
+    ('\u{113e1}', '\u{113e2}'),
+    ('\u{11400}', '\u{1144a}'),
+    ('\u{11450}', '\u{11459}'),
+    ('\u{1145e}', '\u{11461}'),
+    ('\u{11480}', '\u{114c5}'),
+    ('\u{114c7}', '\u{114c7}'),
+    ('\u{114d0}', '\u{114d9}'),
+    ('\u{11580}', '\u{115b5}'),
+    ('\u{115b8}', '\u{115c0}'),
+    ('\u{115d8}', '\u{115dd}'),
+    ('\u{11600}', '\u{11640}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11650}', '\u{11659}'),
+    ('\u{11680}', '\u{116b8}'),
+    ('\u{116c0}', '\u{116c9}'),
+    ('\u{116d0}', '\u{116e3}'),
+    ('\u{11700}', '\u{1171a}'),
+    ('\u{1171d}', '\u{1172b}'),
+    ('\u{11730}', '\u{11739}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1183a}'),
+    ('\u{118a0}', '\u{118e9}'),
+    ('\u{118ff}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190c}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{11935}'),
+    ('\u{11937}', '\u{11938}'),
+    ('\u{1193b}', '\u{11943}'),
+    ('\u{11950}', '\u{11959}'),
+    ('\u{119a0}', '\u{119a7}'),
+    ('\u{119aa}', '\u{119d7}'),
+    ('\u{119da}', '\u{119e1}'),
+    ('\u{119e3}', '\u{119e4}'),
+    ('\u{11a00}', '\u{11a3e}'),
+    ('\u{11a47}', '\u{11a47}'),
+    ('\u{11a50}', '\u{11a99}'),
+    ('\u{11a9d}', '\u{11a9d}'),
+    ('\u{11ab0}', '\u{11af8}'),
+    ('\u{11b60}', '\u{11b67}'),
+    ('\u{11bc0}', '\u{11be0}'),
+    ('\u{11bf0}', '\u{11bf9}'),
+    ('\u{11c00}', '\u{11c08}'),
+    ('\u{11c0a}', '\u{11c36}'),
+    ('\u{11c38}', '\u{11c40}'),
+    ('\u{11c50}', '\u{11c59}'),
+    ('\u{11c72}', '\u{11c8f}'),
+    ('\u{11c92}', '\u{11ca7}'),
+    ('\u{11ca9}', '\u{11cb6}'),
+    ('\u{11d00}', '\u{11d06}'),
+    ('\u{11d08}', '\u{11d09}'),
+    ('\u{11d0b}', '\u{11d36}'),
+    ('\u{11d3a}', '\u{11d3a}'),
+    ('\u{11d3c}', '\u{11d3d}'),
+    ('\u{11d3f}', '\u{11d47}'),
+    ('\u{11d50}', '\u{11d59}'),
+    ('\u{11d60}', '\u{11d65}'),
+    ('\u{11d67}', '\u{11d68}'),
+    ('\u{11d6a}', '\u{11d8e}'),
+    ('\u{11d90}', '\u{11d91}'),
+    ('\u{11d93}', '\u{11d98}'),
+    ('\u{11da0}', '\u{11da9}'),
+    ('\u{11db0}', '\u{11ddb}'),
+    ('\u{11de0}', '\u{11de9}'),
+    ('\u{11ee0}', '\u{11ef6}'),
+    ('\u{11f00}', '\u{11f10}'),
+    ('\u{11f12}', '\u{11f3a}'),
+    ('\u{11f3e}', '\u{11f42}'),
+    ('\u{11f50}', '\u{11f5a}'),
+    ('\u{11fb0}', '\u{11fb0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246e}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12f90}', '\u{12ff0}'),
+    ('\u{13000}', '\u{1342f}'),
+    ('\u{13440}', '\u{13455}'),
+    ('\u{13460}', '\u{143fa}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16100}', '\u{16139}'),
+    ('\u{16800}', '\u{16a38}'),
+    ('\u{16a40}', '\u{16a5e}'),
+    ('\u{16a60}', '\u{16a69}'),
+    ('\u{16a70}', '\u{16abe}'),
+    ('\u{16ac0}', '\u{16ac9}'),
+    ('\u{16ad0}', '\u{16aed}'),
+    ('\u{16af0}', '\u{16af4}'),
+    ('\u{16b00}', '\u{16b36}'),
+    ('\u{16b40}', '\u{16b43}'),
+    ('\u{16b50}', '\u{16b59}'),
+    ('\u{16b63}', '\u{16b77}'),
+    ('\u{16b7d}', '\u{16b8f}'),
+    ('\u{16d40}', '\u{16d6c}'),
+    ('\u{16d70}', '\u{16d79}'),
+    ('\u{16e40}', '\u{16e7f}'),
+    ('\u{16ea0}', '\u{16eb8}'),
+    ('\u{16ebb}', '\u{16ed3}'),
+    ('\u{16f00}', '\u{16f4a}'),
+    ('\u{16f4f}', '\u{16f87}'),
+    ('\u{16f8f}', '\u{16f9f}'),
+    ('\u{16fe0}', '\u{16fe1}'),
+    ('\u{16fe3}', '\u{16fe4}'),
+    ('\u{16ff0}', '\u{16ff6}'),
+    ('\u{17000}', '\u{18cd5}'),
+    ('\u{18cff}', '\u{18d1e}'),
+    ('\u{18d80}', '\u{18df2}'),
+    ('\u{1aff0}', '\u{1aff3}'),
+    ('\u{1aff5}', '\u{1affb}'),
+    ('\u{1affd}', '\u{1affe}'),
+    ('\u{1b000}', '\u{1b122}'),
+    ('\u{1b132}', '\u{1b132}'),
+    ('\u{1b150}', '\u{1b152}'),
+    ('\u{1b155}', '\u{1b155}'),
+    ('\u{1b164}', '\u{1b167}'),
+    ('\u{1b170}', '\u{1b2fb}'),
+    ('\u{1bc00}', '\u{1bc6a}'),
+    ('\u{1bc70}', '\u{1bc7c}'),
+    ('\u{1bc80}', '\u{1bc88}'),
+    ('\u{1bc90}', '\u{1bc99}'),
+    ('\u{1bc9d}', '\u{1bc9e}'),
+    ('\u{1ccf0}', '\u{1ccf9}'),
+    ('\u{1cf00}', '\u{1cf2d}'),
+    ('\u{1cf30}', '\u{1cf46}'),
+    ('\u{1d165}', '\u{1d169}'),
+    ('\u{1d16d}', '\u{1d172}'),
+    ('\u{1d17b}', '\u{1d182}'),
+    ('\u{1d185}', '\u{1d18b}'),
+    ('\u{1d1aa}', '\u{1d1ad}'),
+    ('\u{1d242}', '\u{1d244}'),
+    ('\u{1d400}', '\u{1d454}'),
+    ('\u{1d456}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'),
+    ('\u{1d4a2}', '\u{1d4a2}'),
+    ('\u{1d4a5}', '\u{1d4a6}'),
+    ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b9}'),
+    ('\u{1d4bb}', '\u{1d4bb}'),
+    ('\u{1d4bd}', '\u{1d4c3}'),
+    ('\u{1d4c5}', '\u{1d505}'),
+    ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'),
+    ('\u{1d516}', '\u{1d51c}'),
+    ('\u{1d51e}', '\u{1d539}'),
+    ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'),
+    ('\u{1d546}', '\u{1d546}'),
+    ('\u{1d54a}', '\u{1d550}'),
+    ('\u{1d552}', '\u{1d6a5}'),
+    ('\u{1d6a8}', '\u{1d6c0}'),
+    ('\u{1d6c2}', '\u{1d6da}'),
+    ('\u{1d6dc}', '\u{1d6fa}'),
+    ('\u{1d6fc}', '\u{1d714}'),
+    ('\u{1d716}', '\u{1d734}'),
+    ('\u{1d736}', '\u{1d74e}'),
+    ('\u{1d750}', '\u{1d76e}'),
+    ('\u{1d770}', '\u{1d788}'),
+    ('\u{1d78a}', '\u{1d7a8}'),
+    ('\u{1d7aa}', '\u{1d7c2}'),
+    ('\u{1d7c4}', '\u{1d7cb}'),
+    ('\u{1d7ce}', '\u{1d7ff}'),
+    ('\u{1da00}', '\u{1da36}'),
+    ('\u{1da3b}', '\u{1da6c}'),
+    ('\u{1da75}', '\u{1da75}'),
+    ('\u{1da84}', '\u{1da84}'),
+    ('\u{1da9b}', '\u{1da9f}'),
+    ('\u{1daa1}', '\u{1daaf}'),
+    ('\u{1df00}', '\u{1df1e}'),
+    ('\u{1df25}', '\u{1df2a}'),
+    ('\u{1e000}', '\u{1e006}'),
+    ('\u{1e008}', '\u{1e018}'),
+    ('\u{1e01b}', '\u{1e021}'),
+    ('\u{1e023}', '\u{1e024}'),
+    ('\u{1e026}', '\u{1e02a}'),
+    ('\u{1e030}', '\u{1e06d}'),
+    ('\u{1e08f}', '\u{1e08f}'),
+    ('\u{1e100}', '\u{1e12c}'),
+    ('\u{1e130}', '\u{1e13d}'),
+    ('\u{1e140}', '\u{1e149}'),
+    ('\u{1e14e}', '\u{1e14e}'),
+    ('\u{1e290}', '\u{1e2ae}'),
+    ('\u{1e2c0}', '\u{1e2f9}'),
+    ('\u{1e4d0}', '\u{1e4f9}'),
+    ('\u{1e5d0}', '\u{1e5fa}'),
+    ('\u{1e6c0}', '\u{1e6de}'),
+    ('\u{1e6e0}', '\u{1e6f5}'),
+    ('\u{1e6fe}', '\u{1e6ff}'),
+    ('\u{1e7e0}', '\u{1e7e6}'),
+    ('\u{1e7e8}', '\u{1e7eb}'),
+    ('\u{1e7ed}', '\u{1e7ee}'),
+    ('\u{1e7f0}', '\u{1e7fe}'),
+    ('\u{1e800}', '\u{1e8c4}'),
+    ('\u{1e8d0}', '\u{1e8d6}'),
+    ('\u{1e900}', '\u{1e94b}'),
+    ('\u{1e950}', '\u{1e959}'),
+    ('\u{1ee00}', '\u{1ee03}'),
+    ('\u{1ee05}', '\u{1ee1f}'),
+    ('\u{1ee21}', '\u{1ee22}'),
+    ('\u{1ee24}', '\u{1ee24}'),
+    ('\u{1ee27}', '\u{1ee27}'),
+    ('\u{1ee29}', '\u{1ee32}'),
+    ('\u{1ee34}', '\u{1ee37}'),
+    ('\u{1ee39}', '\u{1ee39}'),
+    ('\u{1ee3b}', '\u{1ee3b}'),
+    ('\u{1ee42}', '\u{1ee42}'),
+    ('\u{1ee47}', '\u{1ee47}'),
+    ('\u{1ee49}', '\u{1ee49}'),
+    ('\u{1ee4b}', '\u{1ee4b}'),
+    ('\u{1ee4d}', '\u{1ee4f}'),
+    ('\u{1ee51}', '\u{1ee52}'),
+    ('\u{1ee54}', '\u{1ee54}'),
+    ('\u{1ee57}', '\u{1ee57}'),
+    ('\u{1ee59}', '\u{1ee59}'),
+    ('\u{1ee5b}', '\u{1ee5b}'),
+    ('\u{1ee5d}', '\u{1ee5d}'),
+    ('\u{1ee5f}', '\u{1ee5f}'),
+    ('\u{1ee61}', '\u{1ee62}'),
+    ('\u{1ee64}', '\u{1ee64}'),
+    ('\u{1ee67}', '\u{1ee6a}'),
+    ('\u{1ee6c}', '\u{1ee72}'),
+    ('\u{1ee74}', '\u{1ee77}'),
+    ('\u{1ee79}', '\u{1ee7c}'),
+    ('\u{1ee7e}', '\u{1ee7e}'),
+    ('\u{1ee80}', '\u{1ee89}'),
+    ('\u{1ee8b}', '\u{1ee9b}'),
+    ('\u{1eea1}', '\u{1eea3}'),
+    ('\u{1eea5}', '\u{1eea9}'),
+    ('\u{1eeab}', '\u{1eebb}'),
+    ('\u{1fbf0}', '\u{1fbf9}'),
+    ('\u{20000}', '\u{2a6df}'),
+    ('\u{2a700}', '\u{2b81d}'),
+    ('\u{2b820}', '\u{2cead}'),
+    ('\u{2ceb0}', '\u{2ebe0}'),
+    ('\u{2ebf0}', '\u{2ee5d}'),
+    ('\u{2f800}', '\u{2fa1d}'),
+    ('\u{30000}', '\u{3134a}'),
+    ('\u{31350}', '\u{33479}'),
+    ('\u{e0100}', '\u{e01ef}'),
+];
+
+// Normalization data of Unicode 14.0.0
+
+pub const CANONICAL_COMBINING_CLASS_TABLE: &'static [(char, char, u8)] = &[
+    ('\u{300}', '\u{314}', 230),
+    ('\u{315}', '\u{315}', 232),
+    ('\u{316}', '\u{319}', 220),
+    ('\u{31a}', '\u{31a}', 232),
+    ('\u{31b}', '\u{31b}', 216),
+    ('\u{31c}', '\u{320}', 220),
+    ('\u{321}', '\u{322}', 202),
+    ('\u{323}', '\u{326}', 220),
+    ('\u{327}', '\u{328}', 202),
+    ('\u{329}', '\u{333}', 220),
+    ('\u{334}', '\u{338}', 1),
+    ('\u{339}', '\u{33c}', 220),
+    ('\u{33d}', '\u{344}', 230),
+    ('\u{345}', '\u{345}', 240),
+    ('\u{346}', '\u{346}', 230),
+    ('\u{347}', '\u{349}', 220),
+    ('\u{34a}', '\u{34c}', 230),
+    ('\u{34d}', '\u{34e}', 220),
+    ('\u{350}', '\u{352}', 230),
+    ('\u{353}', '\u{356}', 220),
+    ('\u{357}', '\u{357}', 230),
+    ('\u{358}', '\u{358}', 232),
+    ('\u{359}', '\u{35a}', 220),
+    ('\u{35b}', '\u{35b}', 230),
+    ('\u{35c}', '\u{35c}', 233),
+    ('\u{35d}', '\u{35e}', 234),
+    ('\u{35f}', '\u{35f}', 233),
+    ('\u{360}', '\u{361}', 234),
+    ('\u{362}', '\u{362}', 233),
+    ('\u{363}', '\u{36f}', 230),
+    ('\u{483}', '\u{487}', 230),
+    ('\u{591}', '\u{591}', 220),
+    ('\u{592}', '\u{595}', 230),
+    ('\u{596}', '\u{596}', 220),
+    ('\u{597}', '\u{599}', 230),
+    ('\u{59a}', '\u{59a}', 222),
+    ('\u{59b}', '\u{59b}', 220),
+    ('\u{59c}', '\u{5a1}', 230),
+    ('\u{5a2}', '\u{5a7}', 220),
+    ('\u{5a8}', '\u{5a9}', 230),
+    ('\u{5aa}', '\u{5aa}', 220),
+    ('\u{5ab}', '\u{5ac}', 230),
+    ('\u{5ad}', '\u{5ad}', 222),
+    ('\u{5ae}', '\u{5ae}', 228),
+    ('\u{5af}', '\u{5af}', 230),
+    ('\u{5b0}', '\u{5b0}', 10),
+    ('\u{5b1}', '\u{5b1}', 11),
+    ('\u{5b2}', '\u{5b2}', 12),
+    ('\u{5b3}', '\u{5b3}', 13),
+    ('\u{5b4}', '\u{5b4}', 14),
+    ('\u{5b5}', '\u{5b5}', 15),
+    ('\u{5b6}', '\u{5b6}', 16),
+    ('\u{5b7}', '\u{5b7}', 17),
+    ('\u{5b8}', '\u{5b8}', 18),
+    ('\u{5b9}', '\u{5ba}', 19),
+    ('\u{5bb}', '\u{5bb}', 20),
+    ('\u{5bc}', '\u{5bc}', 21),
+    ('\u{5bd}', '\u{5bd}', 22),
+    ('\u{5bf}', '\u{5bf}', 23),
+    ('\u{5c1}', '\u{5c1}', 24),
+    ('\u{5c2}', '\u{5c2}', 25),
+    ('\u{5c4}', '\u{5c4}', 230),
+    ('\u{5c5}', '\u{5c5}', 220),
+    ('\u{5c7}', '\u{5c7}', 18),
+    ('\u{610}', '\u{617}', 230),
+    ('\u{618}', '\u{618}', 30),
+    ('\u{619}', '\u{619}', 31),
+    ('\u{61a}', '\u{61a}', 32),
+    ('\u{64b}', '\u{64b}', 27),
+    ('\u{64c}', '\u{64c}', 28),
+    ('\u{64d}', '\u{64d}', 29),
+    ('\u{64e}', '\u{64e}', 30),
+    ('\u{64f}', '\u{64f}', 31),
+    ('\u{650}', '\u{650}', 32),
+    ('\u{651}', '\u{651}', 33),
+    ('\u{652}', '\u{652}', 34),
+    ('\u{653}', '\u{654}', 230),
+    ('\u{655}', '\u{656}', 220),
+    ('\u{657}', '\u{65b}', 230),
+    ('\u{65c}', '\u{65c}', 220),
+    ('\u{65d}', '\u{65e}', 230),
+    ('\u{65f}', '\u{65f}', 220),
+    ('\u{670}', '\u{670}', 35),
+    ('\u{6d6}', '\u{6dc}', 230),
+    ('\u{6df}', '\u{6e2}', 230),
+    ('\u{6e3}', '\u{6e3}', 220),
+    ('\u{6e4}', '\u{6e4}', 230),
+    ('\u{6e7}', '\u{6e8}', 230),
+    ('\u{6ea}', '\u{6ea}', 220),
+    ('\u{6eb}', '\u{6ec}', 230),
+    ('\u{6ed}', '\u{6ed}', 220),
+    ('\u{711}', '\u{711}', 36),
+    ('\u{730}', '\u{730}', 230),
+    ('\u{731}', '\u{731}', 220),
+    ('\u{732}', '\u{733}', 230),
+    ('\u{734}', '\u{734}', 220),
+    ('\u{735}', '\u{736}', 230),
+    ('\u{737}', '\u{739}', 220),
+    ('\u{73a}', '\u{73a}', 230),
+    ('\u{73b}', '\u{73c}', 220),
+    ('\u{73d}', '\u{73d}', 230),
+    ('\u{73e}', '\u{73e}', 220),
+    ('\u{73f}', '\u{741}', 230),
+    ('\u{742}', '\u{742}', 220),
+    ('\u{743}', '\u{743}', 230),
+    ('\u{744}', '\u{744}', 220),
+    ('\u{745}', '\u{745}', 230),
+    ('\u{746}', '\u{746}', 220),
+    ('\u{747}', '\u{747}', 230),
+    ('\u{748}', '\u{748}', 220),
+    ('\u{749}', '\u{74a}', 230),
+    ('\u{7eb}', '\u{7f1}', 230),
+    ('\u{7f2}', '\u{7f2}', 220),
+    ('\u{7f3}', '\u{7f3}', 230),
+    ('\u{7fd}', '\u{7fd}', 220),
+    ('\u{816}', '\u{819}', 230),
+    ('\u{81b}', '\u{823}', 230),
+    ('\u{825}', '\u{827}', 230),
+    ('\u{829}', '\u{82d}', 230),
+    ('\u{859}', '\u{85b}', 220),
+    ('\u{898}', '\u{898}', 230),
+    ('\u{899}', '\u{89b}', 220),
+    ('\u{89c}', '\u{89f}', 230),
+    ('\u{8ca}', '\u{8ce}', 230),
+    ('\u{8cf}', '\u{8d3}', 220),
+    ('\u{8d4}', '\u{8e1}', 230),
+    ('\u{8e3}', '\u{8e3}', 220),
+    ('\u{8e4}', '\u{8e5}', 230),
+    ('\u{8e6}', '\u{8e6}', 220),
+    ('\u{8e7}', '\u{8e8}', 230),
+    ('\u{8e9}', '\u{8e9}', 220),
+    ('\u{8ea}', '\u{8ec}', 230),
+    ('\u{8ed}', '\u{8ef}', 220),
+    ('\u{8f0}', '\u{8f0}', 27),
+    ('\u{8f1}', '\u{8f1}', 28),
+    ('\u{8f2}', '\u{8f2}', 29),
+    ('\u{8f3}', '\u{8f5}', 230),
+    ('\u{8f6}', '\u{8f6}', 220),
+    ('\u{8f7}', '\u{8f8}', 230),
+    ('\u{8f9}', '\u{8fa}', 220),
+    ('\u{8fb}', '\u{8ff}', 230),
+    ('\u{93c}', '\u{93c}', 7),
+    ('\u{94d}', '\u{94d}', 9),
+    ('\u{951}', '\u{951}', 230),
+    ('\u{952}', '\u{952}', 220),
+    ('\u{953}', '\u{954}', 230),
+    ('\u{9bc}', '\u{9bc}', 7),
+    ('\u{9cd}', '\u{9cd}', 9),
+    ('\u{9fe}', '\u{9fe}', 230),
+    ('\u{a3c}', '\u{a3c}', 7),
+    ('\u{a4d}', '\u{a4d}', 9),
+    ('\u{abc}', '\u{abc}', 7),
+    ('\u{acd}', '\u{acd}', 9),
+    ('\u{b3c}', '\u{b3c}', 7),
+    ('\u{b4d}', '\u{b4d}', 9),
+    ('\u{bcd}', '\u{bcd}', 9),
+    ('\u{c3c}', '\u{c3c}', 7),
+    ('\u{c4d}', '\u{c4d}', 9),
+    ('\u{c55}', '\u{c55}', 84),
+    ('\u{c56}', '\u{c56}', 91),
+    ('\u{cbc}', '\u{cbc}', 7),
+    ('\u{ccd}', '\u{ccd}', 9),
+    ('\u{d3b}', '\u{d3c}', 9),
+    ('\u{d4d}', '\u{d4d}', 9),
+    ('\u{dca}', '\u{dca}', 9),
+    ('\u{e38}', '\u{e39}', 103),
+    ('\u{e3a}', '\u{e3a}', 9),
+    ('\u{e48}', '\u{e4b}', 107),
+    ('\u{eb8}', '\u{eb9}', 118),
+    ('\u{eba}', '\u{eba}', 9),
+    ('\u{ec8}', '\u{ecb}', 122),
+    ('\u{f18}', '\u{f19}', 220),
+    ('\u{f35}', '\u{f35}', 220),
+    ('\u{f37}', '\u{f37}', 220),
+    ('\u{f39}', '\u{f39}', 216),
+    ('\u{f71}', '\u{f71}', 129),
+    ('\u{f72}', '\u{f72}', 130),
+    ('\u{f74}', '\u{f74}', 132),
+    ('\u{f7a}', '\u{f7d}', 130),
+    ('\u{f80}', '\u{f80}', 130),
+    ('\u{f82}', '\u{f83}', 230),
+    ('\u{f84}', '\u{f84}', 9),
+    ('\u{f86}', '\u{f87}', 230),
+    ('\u{fc6}', '\u{fc6}', 220),
+    ('\u{1037}', '\u{1037}', 7),
+    ('\u{1039}', '\u{103a}', 9),
+    ('\u{108d}', '\u{108d}', 220),
+    ('\u{135d}', '\u{135f}', 230),
+    ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9),
+    ('\u{17d2}', '\u{17d2}', 9),
+    ('\u{17dd}', '\u{17dd}', 230),
+    ('\u{18a9}', '\u{18a9}', 228),
+    ('\u{1939}', '\u{1939}', 222),
+    ('\u{193a}', '\u{193a}', 230),
+    ('\u{193b}', '\u{193b}', 220),
+    ('\u{1a17}', '\u{1a17}', 230),
+    ('\u{1a18}', '\u{1a18}', 220),
+    ('\u{1a60}', '\u{1a60}', 9),
+    ('\u{1a75}', '\u{1a7c}', 230),
+    ('\u{1a7f}', '\u{1a7f}', 220),
+    ('\u{1ab0}', '\u{1ab4}', 230),
+    ('\u{1ab5}', '\u{1aba}', 220),
+    ('\u{1abb}', '\u{1abc}', 230),
+    ('\u{1abd}', '\u{1abd}', 220),
+    ('\u{1abf}', '\u{1ac0}', 220),
+    ('\u{1ac1}', '\u{1ac2}', 230),
+    ('\u{1ac3}', '\u{1ac4}', 220),
+    ('\u{1ac5}', '\u{1ac9}', 230),
+    ('\u{1aca}', '\u{1aca}', 220),
+    ('\u{1acb}', '\u{1ace}', 230),
+    ('\u{1b34}', '\u{1b34}', 7),
+    ('\u{1b44}', '\u{1b44}', 9),
+    ('\u{1b6b}', '\u{1b6b}', 230),
+    ('\u{1b6c}', '\u{1b6c}', 220),
+    ('\u{1b6d}', '\u{1b73}', 230),
+    ('\u{1baa}', '\u{1bab}', 9),
+    ('\u{1be6}', '\u{1be6}', 7),
+    ('\u{1bf2}', '\u{1bf3}', 9),
+    ('\u{1c37}', '\u{1c37}', 7),
+    ('\u{1cd0}', '\u{1cd2}', 230),
+    ('\u{1cd4}', '\u{1cd4}', 1),
+    ('\u{1cd5}', '\u{1cd9}', 220),
+    ('\u{1cda}', '\u{1cdb}', 230),
+    ('\u{1cdc}', '\u{1cdf}', 220),
+    ('\u{1ce0}', '\u{1ce0}', 230),
+    ('\u{1ce2}', '\u{1ce8}', 1),
+    ('\u{1ced}', '\u{1ced}', 220),
+    ('\u{1cf4}', '\u{1cf4}', 230),
+    ('\u{1cf8}', '\u{1cf9}', 230),
+    ('\u{1dc0}', '\u{1dc1}', 230),
+    ('\u{1dc2}', '\u{1dc2}', 220),
+    ('\u{1dc3}', '\u{1dc9}', 230),
+    ('\u{1dca}', '\u{1dca}', 220),
+    ('\u{1dcb}', '\u{1dcc}', 230),
+    ('\u{1dcd}', '\u{1dcd}', 234),
+    ('\u{1dce}', '\u{1dce}', 214),
+    ('\u{1dcf}', '\u{1dcf}', 220),
+    ('\u{1dd0}', '\u{1dd0}', 202),
+    ('\u{1dd1}', '\u{1df5}', 230),
+    ('\u{1df6}', '\u{1df6}', 232),
+    ('\u{1df7}', '\u{1df8}', 228),
+    ('\u{1df9}', '\u{1df9}', 220),
+    ('\u{1dfa}', '\u{1dfa}', 218),
+    ('\u{1dfb}', '\u{1dfb}', 230),
+    ('\u{1dfc}', '\u{1dfc}', 233),
+    ('\u{1dfd}', '\u{1dfd}', 220),
+    ('\u{1dfe}', '\u{1dfe}', 230),
+    ('\u{1dff}', '\u{1dff}', 220),
+    ('\u{20d0}', '\u{20d1}', 230),
+    ('\u{20d2}', '\u{20d3}', 1),
+    ('\u{20d4}', '\u{20d7}', 230),
+    ('\u{20d8}', '\u{20da}', 1),
+    ('\u{20db}', '\u{20dc}', 230),
+    ('\u{20e1}', '\u{20e1}', 230),
+    ('\u{20e5}', '\u{20e6}', 1),
+    ('\u{20e7}', '\u{20e7}', 230),
+    ('\u{20e8}', '\u{20e8}', 220),
+    ('\u{20e9}', '\u{20e9}', 230),
+    ('\u{20ea}', '\u{20eb}', 1),
+    ('\u{20ec}', '\u{20ef}', 220),
+    ('\u{20f0}', '\u{20f0}', 230),
+    ('\u{2cef}', '\u{2cf1}', 230),
+    ('\u{2d7f}', '\u{2d7f}', 9),
+    ('\u{2de0}', '\u{2dff}', 230),
+    ('\u{302a}', '\u{302a}', 218),
+    ('\u{302b}', '\u{302b}', 228),
+    ('\u{302c}', '\u{302c}', 232),
+    ('\u{302d}', '\u{302d}', 222),
+    ('\u{302e}', '\u{302f}', 224),
+    ('\u{3099}', '\u{309a}', 8),
+    ('\u{a66f}', '\u{a66f}', 230),
+    ('\u{a674}', '\u{a67d}', 230),
+    ('\u{a69e}', '\u{a69f}', 230),
+    ('\u{a6f0}', '\u{a6f1}', 230),
+    ('\u{a806}', '\u{a806}', 9),
+    ('\u{a82c}', '\u{a82c}', 9),
+    ('\u{a8c4}', '\u{a8c4}', 9),
+    ('\u{a8e0}', '\u{a8f1}', 230),
+    ('\u{a92b}', '\u{a92d}', 220),
+    ('\u{a953}', '\u{a953}', 9),
+    ('\u{a9b3}', '\u{a9b3}', 7),
+    ('\u{a9c0}', '\u{a9c0}', 9),
+    ('\u{aab0}', '\u{aab0}', 230),
+    ('\u{aab2}', '\u{aab3}', 230),
+    ('\u{aab4}', '\u{aab4}', 220),
+    ('\u{aab7}', '\u{aab8}', 230),
+    ('\u{aabe}', '\u{aabf}', 230),
+    ('\u{aac1}', '\u{aac1}', 230),
+    ('\u{aaf6}', '\u{aaf6}', 9),
+    ('\u{abed}', '\u{abed}', 9),
+    ('\u{fb1e}', '\u{fb1e}', 26),
+    ('\u{fe20}', '\u{fe26}', 230),
+    ('\u{fe27}', '\u{fe2d}', 220),
+    ('\u{fe2e}', '\u{fe2f}', 230),
+    ('\u{101fd}', '\u{101fd}', 220),
+    ('\u{102e0}', '\u{102e0}', 220),
+    ('\u{10376}', '\u{1037a}', 230),
+    ('\u{10a0d}', '\u{10a0d}', 220),
+    ('\u{10a0f}', '\u{10a0f}', 230),
+    ('\u{10a38}', '\u{10a38}', 230),
+    ('\u{10a39}', '\u{10a39}', 1),
+    ('\u{10a3a}', '\u{10a3a}', 220),
+    ('\u{10a3f}', '\u{10a3f}', 9),
+    ('\u{10ae5}', '\u{10ae5}', 230),
+    ('\u{10ae6}', '\u{10ae6}', 220),
+    ('\u{10d24}', '\u{10d27}', 230),
+    ('\u{10eab}', '\u{10eac}', 230),
+    ('\u{10f46}', '\u{10f47}', 220),
+    ('\u{10f48}', '\u{10f4a}', 230),
+    ('\u{10f4b}', '\u{10f4b}', 220),
+    ('\u{10f4c}', '\u{10f4c}', 230),
+    ('\u{10f4d}', '\u{10f50}', 220),
+    ('\u{10f82}', '\u{10f82}', 230),
+    ('\u{10f83}', '\u{10f83}', 220),
+    ('\u{10f84}', '\u{10f84}', 230),
+    ('\u{10f85}', '\u{10f85}', 220),
+    ('\u{11046}', '\u{11046}', 9),
+    ('\u{11070}', '\u{11070}', 9),
+    ('\u{1107f}', '\u{1107f}', 9),
+    ('\u{110b9}', '\u{110b9}', 9),
+    ('\u{110ba}', '\u{110ba}', 7),
+    ('\u{11100}', '\u{11102}', 230),
+    ('\u{11133}', '\u{11134}', 9),
+    ('\u{11173}', '\u{11173}', 7),
+    ('\u{111c0}', '\u{111c0}', 9),
+    ('\u{111ca}', '\u{111ca}', 7),
+    ('\u{11235}', '\u{11235}', 9),
+    ('\u{11236}', '\u{11236}', 7),
+    ('\u{112e9}', '\u{112e9}', 7),
+    ('\u{112ea}', '\u{112ea}', 9),
+    ('\u{1133b}', '\u{1133c}', 7),
+    ('\u{1134d}', '\u{1134d}', 9),
+    ('\u{11366}', '\u{1136c}', 230),
+    ('\u{11370}', '\u{11374}', 230),
+    ('\u{11442}', '\u{11442}', 9),
+    ('\u{11446}', '\u{11446}', 7),
+    ('\u{1145e}', '\u{1145e}', 230),
+    ('\u{114c2}', '\u{114c2}', 9),
+    ('\u{114c3}', '\u{114c3}', 7),
+    ('\u{115bf}', '\u{115bf}', 9),
+    ('\u{115c0}', '\u{115c0}', 7),
+    ('\u{1163f}', '\u{1163f}', 9),
+    ('\u{116b6}', '\u{116b6}', 9),
+    ('\u{116b7}', '\u{116b7}', 7),
+    ('\u{1172b}', '\u{1172b}', 9),
+    ('\u{11839}', '\u{11839}', 9),
+    ('\u{1183a}', '\u{1183a}', 7),
+    ('\u{1193d}', '\u{1193e}', 9),
+    ('\u{11943}', '\u{11943}', 7),
+    ('\u{119e0}', '\u{119e0}', 9),
+    ('\u{11a34}', '\u{11a34}', 9),
+    ('\u{11a47}', '\u{11a47}', 9),
+    ('\u{11a99}', '\u{11a99}', 9),
+    ('\u{11c3f}', '\u{11c3f}', 9),
+    ('\u{11d42}', '\u{11d42}', 7),
+    ('\u{11d44}', '\u{11d45}', 9),
+    ('\u{11d97}', '\u{11d97}', 9),
+    ('\u{16af0}', '\u{16af4}', 1),
+    ('\u{16b30}', '\u{16b36}', 230),
+    ('\u{16ff0}', '\u{16ff1}', 6),
+    ('\u{1bc9e}', '\u{1bc9e}', 1),
+    ('\u{1d165}', '\u{1d166}', 216),
+    ('\u{1d167}', '\u{1d169}', 1),
+    ('\u{1d16d}', '\u{1d16d}', 226),
+    ('\u{1d16e}', '\u{1d172}', 216),
+    ('\u{1d17b}', '\u{1d182}', 220),
+    ('\u{1d185}', '\u{1d189}', 230),
+    ('\u{1d18a}', '\u{1d18b}', 220),
+    ('\u{1d1aa}', '\u{1d1ad}', 230),
+    ('\u{1d242}', '\u{1d244}', 230),
+    ('\u{1e000}', '\u{1e006}', 230),
+    ('\u{1e008}', '\u{1e018}', 230),
+    ('\u{1e01b}', '\u{1e021}', 230),
+    ('\u{1e023}', '\u{1e024}', 230),
+    ('\u{1e026}', '\u{1e02a}', 230),
+    ('\u{1e130}', '\u{1e136}', 230),
+    ('\u{1e2ae}', '\u{1e2ae}', 230),
+    ('\u{1e2ec}', '\u{1e2ef}', 230),
+    ('\u{1e8d0}', '\u{1e8d6}', 220),
+    ('\u{1e944}', '\u{1e949}', 230),
+    ('\u{1e94a}', '\u{1e94a}', 7),
+];
+
+pub const CANONICAL_DECOMPOSITION_TABLE: &'static [(char, &'static [char])] = &[
+    ('\u{c0}', &['\u{41}', '\u{300}']),
+    ('\u{c1}', &['\u{41}', '\u{301}']),
+    ('\u{c2}', &['\u{41}', '\u{302}']),
+    ('\u{c3}', &['\u{41}', '\u{303}']),
+    ('\u{c4}', &['\u{41}', '\u{308}']),
+    ('\u{c5}', &['\u{41}', '\u{30a}']),
+    ('\u{c7}', &['\u{43}', '\u{327}']),
+    ('\u{c8}', &['\u{45}', '\u{300}']),
+    ('\u{c9}', &['\u{45}', '\u{301}']),
+    ('\u{ca}', &['\u{45}', '\u{302}']),
+    ('\u{cb}', &['\u{45}', '\u{308}']),
+    ('\u{cc}', &['\u{49}', '\u{300}']),
+    ('\u{cd}', &['\u{49}', '\u{301}']),
+    ('\u{ce}', &['\u{49}', '\u{302}']),
+    ('\u{cf}', &['\u{49}', '\u{308}']),
+    ('\u{d1}', &['\u{4e}', '\u{303}']),
+    ('\u{d2}', &['\u{4f}', '\u{300}']),
+    ('\u{d3}', &['\u{4f}', '\u{301}']),
+    ('\u{d4}', &['\u{4f}', '\u{302}']),
+    ('\u{d5}', &['\u{4f}', '\u{303}']),
+    ('\u{d6}', &['\u{4f}', '\u{308}']),
+    ('\u{d9}', &['\u{55}', '\u{300}']),
+    ('\u{da}', &['\u{55}', '\u{301}']),
+    ('\u{db}', &['\u{55}', '\u{302}']),
+    ('\u{dc}', &['\u{55}', '\u{308}']),
+    ('\u{dd}', &['\u{59}', '\u{301}']),
+    ('\u{e0}', &['\u{61}', '\u{300}']),
+    ('\u{e1}', &['\u{61}', '\u{301}']),
+    ('\u{e2}', &['\u{61}', '\u{302}']),
+    ('\u{e3}', &['\u{61}', '\u{303}']),
+    ('\u{e4}', &['\u{61}', '\u{308}']),
+    ('\u{e5}', &['\u{61}', '\u{30a}']),
+    ('\u{e7}', &['\u{63}', '\u{327}']),
+    ('\u{e8}', &['\u{65}', '\u{300}']),
+    ('\u{e9}', &['\u{65}', '\u{301}']),
+    ('\u{ea}', &['\u{65}', '\u{302}']),
+    ('\u{eb}', &['\u{65}', '\u{308}']),
+    ('\u{ec}', &['\u{69}', '\u{300}']),
+    ('\u{ed}', &['\u{69}', '\u{301}']),
+    ('\u{ee}', &['\u{69}', '\u{302}']),
+    ('\u{ef}', &['\u{69}', '\u{308}']),
+    ('\u{f1}', &['\u{6e}', '\u{303}']),
+    ('\u{f2}', &['\u{6f}', '\u{300}']),
+    ('\u{f3}', &['\u{6f}', '\u{301}']),
+    ('\u{f4}', &['\u{6f}', '\u{302}']),
+    ('\u{f5}', &['\u{6f}', '\u{303}']),
+    ('\u{f6}', &['\u{6f}', '\u{308}']),
+    ('\u{f9}', &['\u{75}', '\u{300}']),
+    ('\u{fa}', &['\u{75}', '\u{301}']),
+    ('\u{fb}', &['\u{75}', '\u{302}']),
+    ('\u{fc}', &['\u{75}', '\u{308}']),
+    ('\u{fd}', &['\u{79}', '\u{301}']),
+    ('\u{ff}', &['\u{79}', '\u{308}']),
+    ('\u{100}', &['\u{41}', '\u{304}']),
+    ('\u{101}', &['\u{61}', '\u{304}']),
+    ('\u{102}', &['\u{41}', '\u{306}']),
+    ('\u{103}', &['\u{61}', '\u{306}']),
+    ('\u{104}', &['\u{41}', '\u{328}']),
+    ('\u{105}', &['\u{61}', '\u{328}']),
+    ('\u{106}', &['\u{43}', '\u{301}']),
+    ('\u{107}', &['\u{63}', '\u{301}']),
+    ('\u{108}', &['\u{43}', '\u{302}']),
+    ('\u{109}', &['\u{63}', '\u{302}']),
+    ('\u{10a}', &['\u{43}', '\u{307}']),
+    ('\u{10b}', &['\u{63}', '\u{307}']),
+    ('\u{10c}', &['\u{43}', '\u{30c}']),
+    ('\u{10d}', &['\u{63}', '\u{30c}']),
+    ('\u{10e}', &['\u{44}', '\u{30c}']),
+    ('\u{10f}', &['\u{64}', '\u{30c}']),
+    ('\u{112}', &['\u{45}', '\u{304}']),
+    ('\u{113}', &['\u{65}', '\u{304}']),
+    ('\u{114}', &['\u{45}', '\u{306}']),
+    ('\u{115}', &['\u{65}', '\u{306}']),
+    ('\u{116}', &['\u{45}', '\u{307}']),
+    ('\u{117}', &['\u{65}', '\u{307}']),
+    ('\u{118}', &['\u{45}', '\u{328}']),
+    ('\u{119}', &['\u{65}', '\u{328}']),
+    ('\u{11a}', &['\u{45}', '\u{30c}']),
+    ('\u{11b}', &['\u{65}', '\u{30c}']),
+    ('\u{11c}', &['\u{47}', '\u{302}']),
+    ('\u{11d}', &['\u{67}', '\u{302}']),
+    ('\u{11e}', &['\u{47}', '\u{306}']),
+    ('\u{11f}', &['\u{67}', '\u{306}']),
+    ('\u{120}', &['\u{47}', '\u{307}']),
+    ('\u{121}', &['\u{67}', '\u{307}']),
+    ('\u{122}', &['\u{47}', '\u{327}']),
+    ('\u{123}', &['\u{67}', '\u{327}']),
+    ('\u{124}', &['\u{48}', '\u{302}']),
+    ('\u{125}', &['\u{68}', '\u{302}']),
+    ('\u{128}', &['\u{49}', '\u{303}']),
+    ('\u{129}', &['\u{69}', '\u{303}']),
+    ('\u{12a}', &['\u{49}', '\u{304}']),
+    ('\u{12b}', &['\u{69}', '\u{304}']),
+    ('\u{12c}', &['\u{49}', '\u{306}']),
+    ('\u{12d}', &['\u{69}', '\u{306}']),
+    ('\u{12e}', &['\u{49}', '\u{328}']),
+    ('\u{12f}', &['\u{69}', '\u{328}']),
+    ('\u{130}', &['\u{49}', '\u{307}']),
+    ('\u{134}', &['\u{4a}', '\u{302}']),
+    ('\u{135}', &['\u{6a}', '\u{302}']),
+    ('\u{136}', &['\u{4b}', '\u{327}']),
+    ('\u{137}', &['\u{6b}', '\u{327}']),
+    ('\u{139}', &['\u{4c}', '\u{301}']),
+    ('\u{13a}', &['\u{6c}', '\u{301}']),
+    ('\u{13b}', &['\u{4c}', '\u{327}']),
+    ('\u{13c}', &['\u{6c}', '\u{327}']),
+    ('\u{13d}', &['\u{4c}', '\u{30c}']),
+    ('\u{13e}', &['\u{6c}', '\u{30c}']),
+    ('\u{143}', &['\u{4e}', '\u{301}']),
+    ('\u{144}', &['\u{6e}', '\u{301}']),
+    ('\u{145}', &['\u{4e}', '\u{327}']),
+    ('\u{146}', &['\u{6e}', '\u{327}']),
+    ('\u{147}', &['\u{4e}', '\u{30c}']),
+    ('\u{148}', &['\u{6e}', '\u{30c}']),
+    ('\u{14c}', &['\u{4f}', '\u{304}']),
+    ('\u{14d}', &['\u{6f}', '\u{304}']),
+    ('\u{14e}', &['\u{4f}', '\u{306}']),
+    ('\u{14f}', &['\u{6f}', '\u{306}']),
+    ('\u{150}', &['\u{4f}', '\u{30b}']),
+    ('\u{151}', &['\u{6f}', '\u{30b}']),
+    ('\u{154}', &['\u{52}', '\u{301}']),
+    ('\u{155}', &['\u{72}', '\u{301}']),
+    ('\u{156}', &['\u{52}', '\u{327}']),
+    ('\u{157}', &['\u{72}', '\u{327}']),
+    ('\u{158}', &['\u{52}', '\u{30c}']),
+    ('\u{159}', &['\u{72}', '\u{30c}']),
+    ('\u{15a}', &['\u{53}', '\u{301}']),
+    ('\u{15b}', &['\u{73}', '\u{301}']),
+    ('\u{15c}', &['\u{53}', '\u{302}']),
+    ('\u{15d}', &['\u{73}', '\u{302}']),
+    ('\u{15e}', &['\u{53}', '\u{327}']),
+    ('\u{15f}', &['\u{73}', '\u{327}']),
+    ('\u{160}', &['\u{53}', '\u{30c}']),
+    ('\u{161}', &['\u{73}', '\u{30c}']),
+    ('\u{162}', &['\u{54}', '\u{327}']),
+    ('\u{163}', &['\u{74}', '\u{327}']),
+    ('\u{164}', &['\u{54}', '\u{30c}']),
+    ('\u{165}', &['\u{74}', '\u{30c}']),
+    ('\u{168}', &['\u{55}', '\u{303}']),
+    ('\u{169}', &['\u{75}', '\u{303}']),
+    ('\u{16a}', &['\u{55}', '\u{304}']),
+    ('\u{16b}', &['\u{75}', '\u{304}']),
+    ('\u{16c}', &['\u{55}', '\u{306}']),
+    ('\u{16d}', &['\u{75}', '\u{306}']),
+    ('\u{16e}', &['\u{55}', '\u{30a}']),
+    ('\u{16f}', &['\u{75}', '\u{30a}']),
+    ('\u{170}', &['\u{55}', '\u{30b}']),
+    ('\u{171}', &['\u{75}', '\u{30b}']),
+    ('\u{172}', &['\u{55}', '\u{328}']),
+    ('\u{173}', &['\u{75}', '\u{328}']),
+    ('\u{174}', &['\u{57}', '\u{302}']),
+    ('\u{175}', &['\u{77}', '\u{302}']),
+    ('\u{176}', &['\u{59}', '\u{302}']),
+    ('\u{177}', &['\u{79}', '\u{302}']),
+    ('\u{178}', &['\u{59}', '\u{308}']),
+    ('\u{179}', &['\u{5a}', '\u{301}']),
+    ('\u{17a}', &['\u{7a}', '\u{301}']),
+    ('\u{17b}', &['\u{5a}', '\u{307}']),
+    ('\u{17c}', &['\u{7a}', '\u{307}']),
+    ('\u{17d}', &['\u{5a}', '\u{30c}']),
+    ('\u{17e}', &['\u{7a}', '\u{30c}']),
+    ('\u{1a0}', &['\u{4f}', '\u{31b}']),
+    ('\u{1a1}', &['\u{6f}', '\u{31b}']),
+    ('\u{1af}', &['\u{55}', '\u{31b}']),
+    ('\u{1b0}', &['\u{75}', '\u{31b}']),
+    ('\u{1cd}', &['\u{41}', '\u{30c}']),
+    ('\u{1ce}', &['\u{61}', '\u{30c}']),
+    ('\u{1cf}', &['\u{49}', '\u{30c}']),
+    ('\u{1d0}', &['\u{69}', '\u{30c}']),
+    ('\u{1d1}', &['\u{4f}', '\u{30c}']),
+    ('\u{1d2}', &['\u{6f}', '\u{30c}']),
+    ('\u{1d3}', &['\u{55}', '\u{30c}']),
+    ('\u{1d4}', &['\u{75}', '\u{30c}']),
+    ('\u{1d5}', &['\u{55}', '\u{308}', '\u{304}']),
+    ('\u{1d6}', &['\u{75}', '\u{308}', '\u{304}']),
+    ('\u{1d7}', &['\u{55}', '\u{308}', '\u{301}']),
+    ('\u{1d8}', &['\u{75}', '\u{308}', '\u{301}']),
+    ('\u{1d9}', &['\u{55}', '\u{308}', '\u{30c}']),
+    ('\u{1da}', &['\u{75}', '\u{308}', '\u{30c}']),
+    ('\u{1db}', &['\u{55}', '\u{308}', '\u{300}']),
+    ('\u{1dc}', &['\u{75}', '\u{308}', '\u{300}']),
+    ('\u{1de}', &['\u{41}', '\u{308}', '\u{304}']),
+    ('\u{1df}', &['\u{61}', '\u{308}', '\u{304}']),
+    ('\u{1e0}', &['\u{41}', '\u{307}', '\u{304}']),
+    ('\u{1e1}', &['\u{61}', '\u{307}', '\u{304}']),
+    ('\u{1e2}', &['\u{c6}', '\u{304}']),
+    ('\u{1e3}', &['\u{e6}', '\u{304}']),
+    ('\u{1e6}', &['\u{47}', '\u{30c}']),
+    ('\u{1e7}', &['\u{67}', '\u{30c}']),
+    ('\u{1e8}', &['\u{4b}', '\u{30c}']),
+    ('\u{1e9}', &['\u{6b}', '\u{30c}']),
+    ('\u{1ea}', &['\u{4f}', '\u{328}']),
+    ('\u{1eb}', &['\u{6f}', '\u{328}']),
+    ('\u{1ec}', &['\u{4f}', '\u{328}', '\u{304}']),
+    ('\u{1ed}', &['\u{6f}', '\u{328}', '\u{304}']),
+    ('\u{1ee}', &['\u{1b7}', '\u{30c}']),
+    ('\u{1ef}', &['\u{292}', '\u{30c}']),
+    ('\u{1f0}', &['\u{6a}', '\u{30c}']),
+    ('\u{1f4}', &['\u{47}', '\u{301}']),
+    ('\u{1f5}', &['\u{67}', '\u{301}']),
+    ('\u{1f8}', &['\u{4e}', '\u{300}']),
+    ('\u{1f9}', &['\u{6e}', '\u{300}']),
+    ('\u{1fa}', &['\u{41}', '\u{30a}', '\u{301}']),
+    ('\u{1fb}', &['\u{61}', '\u{30a}', '\u{301}']),
+    ('\u{1fc}', &['\u{c6}', '\u{301}']),
+    ('\u{1fd}', &['\u{e6}', '\u{301}']),
+    ('\u{1fe}', &['\u{d8}', '\u{301}']),
+    ('\u{1ff}', &['\u{f8}', '\u{301}']),
+    ('\u{200}', &['\u{41}', '\u{30f}']),
+    ('\u{201}', &['\u{61}', '\u{30f}']),
+    ('\u{202}', &['\u{41}', '\u{311}']),
+    ('\u{203}', &['\u{61}', '\u{311}']),
+    ('\u{204}', &['\u{45}', '\u{30f}']),
+    ('\u{205}', &['\u{65}', '\u{30f}']),
+    ('\u{206}', &['\u{45}', '\u{311}']),
+    ('\u{207}', &['\u{65}', '\u{311}']),
+    ('\u{208}', &['\u{49}', '\u{30f}']),
+    ('\u{209}', &['\u{69}', '\u{30f}']),
+    ('\u{20a}', &['\u{49}', '\u{311}']),
+    ('\u{20b}', &['\u{69}', '\u{311}']),
+    ('\u{20c}', &['\u{4f}', '\u{30f}']),
+    ('\u{20d}', &['\u{6f}', '\u{30f}']),
+    ('\u{20e}', &['\u{4f}', '\u{311}']),
+    ('\u{20f}', &['\u{6f}', '\u{311}']),
+    ('\u{210}', &['\u{52}', '\u{30f}']),
+    ('\u{211}', &['\u{72}', '\u{30f}']),
+    ('\u{212}', &['\u{52}', '\u{311}']),
+    ('\u{213}', &['\u{72}', '\u{311}']),
+    ('\u{214}', &['\u{55}', '\u{30f}']),
+    ('\u{215}', &['\u{75}', '\u{30f}']),
+    ('\u{216}', &['\u{55}', '\u{311}']),
+    ('\u{217}', &['\u{75}', '\u{311}']),
+    ('\u{218}', &['\u{53}', '\u{326}']),
+    ('\u{219}', &['\u{73}', '\u{326}']),
+    ('\u{21a}', &['\u{54}', '\u{326}']),
+    ('\u{21b}', &['\u{74}', '\u{326}']),
+    ('\u{21e}', &['\u{48}', '\u{30c}']),
+    ('\u{21f}', &['\u{68}', '\u{30c}']),
+    ('\u{226}', &['\u{41}', '\u{307}']),
+    ('\u{227}', &['\u{61}', '\u{307}']),
+    ('\u{228}', &['\u{45}', '\u{327}']),
+    ('\u{229}', &['\u{65}', '\u{327}']),
+    ('\u{22a}', &['\u{4f}', '\u{308}', '\u{304}']),
+    ('\u{22b}', &['\u{6f}', '\u{308}', '\u{304}']),
+    ('\u{22c}', &['\u{4f}', '\u{303}', '\u{304}']),
+    ('\u{22d}', &['\u{6f}', '\u{303}', '\u{304}']),
+    ('\u{22e}', &['\u{4f}', '\u{307}']),
+    ('\u{22f}', &['\u{6f}', '\u{307}']),
+    ('\u{230}', &['\u{4f}', '\u{307}', '\u{304}']),
+    ('\u{231}', &['\u{6f}', '\u{307}', '\u{304}']),
+    ('\u{232}', &['\u{59}', '\u{304}']),
+    ('\u{233}', &['\u{79}', '\u{304}']),
+    ('\u{340}', &['\u{300}']),
+    ('\u{341}', &['\u{301}']),
+    ('\u{343}', &['\u{313}']),
+    ('\u{344}', &['\u{308}', '\u{301}']),
+    ('\u{374}', &['\u{2b9}']),
+    ('\u{37e}', &['\u{3b}']),
+    ('\u{385}', &['\u{a8}', '\u{301}']),
+    ('\u{386}', &['\u{391}', '\u{301}']),
+    ('\u{387}', &['\u{b7}']),
+    ('\u{388}', &['\u{395}', '\u{301}']),
+    ('\u{389}', &['\u{397}', '\u{301}']),
+    ('\u{38a}', &['\u{399}', '\u{301}']),
+    ('\u{38c}', &['\u{39f}', '\u{301}']),
+    ('\u{38e}', &['\u{3a5}', '\u{301}']),
+    ('\u{38f}', &['\u{3a9}', '\u{301}']),
+    ('\u{390}', &['\u{3b9}', '\u{308}', '\u{301}']),
+    ('\u{3aa}', &['\u{399}', '\u{308}']),
+    ('\u{3ab}', &['\u{3a5}', '\u{308}']),
+    ('\u{3ac}', &['\u{3b1}', '\u{301}']),
+    ('\u{3ad}', &['\u{3b5}', '\u{301}']),
+    ('\u{3ae}', &['\u{3b7}', '\u{301}']),
+    ('\u{3af}', &['\u{3b9}', '\u{301}']),
+    ('\u{3b0}', &['\u{3c5}', '\u{308}', '\u{301}']),
+    ('\u{3ca}', &['\u{3b9}', '\u{308}']),
+    ('\u{3cb}', &['\u{3c5}', '\u{308}']),
+    ('\u{3cc}', &['\u{3bf}', '\u{301}']),
+    ('\u{3cd}', &['\u{3c5}', '\u{301}']),
+    ('\u{3ce}', &['\u{3c9}', '\u{301}']),
+    ('\u{3d3}', &['\u{3d2}', '\u{301}']),
+    ('\u{3d4}', &['\u{3d2}', '\u{308}']),
+    ('\u{400}', &['\u{415}', '\u{300}']),
+    ('\u{401}', &['\u{415}', '\u{308}']),
+    ('\u{403}', &['\u{413}', '\u{301}']),
+    ('\u{407}', &['\u{406}', '\u{308}']),
+    ('\u{40c}', &['\u{41a}', '\u{301}']),
+    ('\u{40d}', &['\u{418}', '\u{300}']),
+    ('\u{40e}', &['\u{423}', '\u{306}']),
+    ('\u{419}', &['\u{418}', '\u{306}']),
+    ('\u{439}', &['\u{438}', '\u{306}']),
+    ('\u{450}', &['\u{435}', '\u{300}']),
+    ('\u{451}', &['\u{435}', '\u{308}']),
+    ('\u{453}', &['\u{433}', '\u{301}']),
+    ('\u{457}', &['\u{456}', '\u{308}']),
+    ('\u{45c}', &['\u{43a}', '\u{301}']),
+    ('\u{45d}', &['\u{438}', '\u{300}']),
+    ('\u{45e}', &['\u{443}', '\u{306}']),
+    ('\u{476}', &['\u{474}', '\u{30f}']),
+    ('\u{477}', &['\u{475}', '\u{30f}']),
+    ('\u{4c1}', &['\u{416}', '\u{306}']),
+    ('\u{4c2}', &['\u{436}', '\u{306}']),
+    ('\u{4d0}', &['\u{410}', '\u{306}']),
+    ('\u{4d1}', &['\u{430}', '\u{306}']),
+    ('\u{4d2}', &['\u{410}', '\u{308}']),
+    ('\u{4d3}', &['\u{430}', '\u{308}']),
+    ('\u{4d6}', &['\u{415}', '\u{306}']),
+    ('\u{4d7}', &['\u{435}', '\u{306}']),
+    ('\u{4da}', &['\u{4d8}', '\u{308}']),
+    ('\u{4db}', &['\u{4d9}', '\u{308}']),
+    ('\u{4dc}', &['\u{416}', '\u{308}']),
+    ('\u{4dd}', &['\u{436}', '\u{308}']),
+    ('\u{4de}', &['\u{417}', '\u{308}']),
+    ('\u{4df}', &['\u{437}', '\u{308}']),
+    ('\u{4e2}', &['\u{418}', '\u{304}']),
+    ('\u{4e3}', &['\u{438}', '\u{304}']),
+    ('\u{4e4}', &['\u{418}', '\u{308}']),
+    ('\u{4e5}', &['\u{438}', '\u{308}']),
+    ('\u{4e6}', &['\u{41e}', '\u{308}']),
+    ('\u{4e7}', &['\u{43e}', '\u{308}']),
+    ('\u{4ea}', &['\u{4e8}', '\u{308}']),
+    ('\u{4eb}', &['\u{4e9}', '\u{308}']),
+    ('\u{4ec}', &['\u{42d}', '\u{308}']),
+    ('\u{4ed}', &['\u{44d}', '\u{308}']),
+    ('\u{4ee}', &['\u{423}', '\u{304}']),
+    ('\u{4ef}', &['\u{443}', '\u{304}']),
+    ('\u{4f0}', &['\u{423}', '\u{308}']),
+    ('\u{4f1}', &['\u{443}', '\u{308}']),
+    ('\u{4f2}', &['\u{423}', '\u{30b}']),
+    ('\u{4f3}', &['\u{443}', '\u{30b}']),
+    ('\u{4f4}', &['\u{427}', '\u{308}']),
+    ('\u{4f5}', &['\u{447}', '\u{308}']),
+    ('\u{4f8}', &['\u{42b}', '\u{308}']),
+    ('\u{4f9}', &['\u{44b}', '\u{308}']),
+    ('\u{622}', &['\u{627}', '\u{653}']),
+    ('\u{623}', &['\u{627}', '\u{654}']),
+    ('\u{624}', &['\u{648}', '\u{654}']),
+    ('\u{625}', &['\u{627}', '\u{655}']),
+    ('\u{626}', &['\u{64a}', '\u{654}']),
+    ('\u{6c0}', &['\u{6d5}', '\u{654}']),
+    ('\u{6c2}', &['\u{6c1}', '\u{654}']),
+    ('\u{6d3}', &['\u{6d2}', '\u{654}']),
+    ('\u{929}', &['\u{928}', '\u{93c}']),
+    ('\u{931}', &['\u{930}', '\u{93c}']),
+    ('\u{934}', &['\u{933}', '\u{93c}']),
+    ('\u{958}', &['\u{915}', '\u{93c}']),
+    ('\u{959}', &['\u{916}', '\u{93c}']),
+    ('\u{95a}', &['\u{917}', '\u{93c}']),
+    ('\u{95b}', &['\u{91c}', '\u{93c}']),
+    ('\u{95c}', &['\u{921}', '\u{93c}']),
+    ('\u{95d}', &['\u{922}', '\u{93c}']),
+    ('\u{95e}', &['\u{92b}', '\u{93c}']),
+    ('\u{95f}', &['\u{92f}', '\u{93c}']),
+    ('\u{9cb}', &['\u{9c7}', '\u{9be}']),
+    ('\u{9cc}', &['\u{9c7}', '\u{9d7}']),
+    ('\u{9dc}', &['\u{9a1}', '\u{9bc}']),
+    ('\u{9dd}', &['\u{9a2}', '\u{9bc}']),
+    ('\u{9df}', &['\u{9af}', '\u{9bc}']),
+    ('\u{a33}', &['\u{a32}', '\u{a3c}']),
+    ('\u{a36}', &['\u{a38}', '\u{a3c}']),
+    ('\u{a59}', &['\u{a16}', '\u{a3c}']),
+    ('\u{a5a}', &['\u{a17}', '\u{a3c}']),
+    ('\u{a5b}', &['\u{a1c}', '\u{a3c}']),
+    ('\u{a5e}', &['\u{a2b}', '\u{a3c}']),
+    ('\u{b48}', &['\u{b47}', '\u{b56}']),
+    ('\u{b4b}', &['\u{b47}', '\u{b3e}']),
+    ('\u{b4c}', &['\u{b47}', '\u{b57}']),
+    ('\u{b5c}', &['\u{b21}', '\u{b3c}']),
+    ('\u{b5d}', &['\u{b22}', '\u{b3c}']),
+    ('\u{b94}', &['\u{b92}', '\u{bd7}']),
+    ('\u{bca}', &['\u{bc6}', '\u{bbe}']),
+    ('\u{bcb}', &['\u{bc7}', '\u{bbe}']),
+    ('\u{bcc}', &['\u{bc6}', '\u{bd7}']),
+    ('\u{c48}', &['\u{c46}', '\u{c56}']),
+    ('\u{cc0}', &['\u{cbf}', '\u{cd5}']),
+    ('\u{cc7}', &['\u{cc6}', '\u{cd5}']),
+    ('\u{cc8}', &['\u{cc6}', '\u{cd6}']),
+    ('\u{cca}', &['\u{cc6}', '\u{cc2}']),
+    ('\u{ccb}', &['\u{cc6}', '\u{cc2}', '\u{cd5}']),
+    ('\u{d4a}', &['\u{d46}', '\u{d3e}']),
+    ('\u{d4b}', &['\u{d47}', '\u{d3e}']),
+    ('\u{d4c}', &['\u{d46}', '\u{d57}']),
+    ('\u{dda}', &['\u{dd9}', '\u{dca}']),
+    ('\u{ddc}', &['\u{dd9}', '\u{dcf}']),
+    ('\u{ddd}', &['\u{dd9}', '\u{dcf}', '\u{dca}']),
+    ('\u{dde}', &['\u{dd9}', '\u{ddf}']),
+    ('\u{f43}', &['\u{f42}', '\u{fb7}']),
+    ('\u{f4d}', &['\u{f4c}', '\u{fb7}']),
+    ('\u{f52}', &['\u{f51}', '\u{fb7}']),
+    ('\u{f57}', &['\u{f56}', '\u{fb7}']),
+    ('\u{f5c}', &['\u{f5b}', '\u{fb7}']),
+    ('\u{f69}', &['\u{f40}', '\u{fb5}']),
+    ('\u{f73}', &['\u{f71}', '\u{f72}']),
+    ('\u{f75}', &['\u{f71}', '\u{f74}']),
+    ('\u{f76}', &['\u{fb2}', '\u{f80}']),
+    ('\u{f78}', &['\u{fb3}', '\u{f80}']),
+    ('\u{f81}', &['\u{f71}', '\u{f80}']),
+    ('\u{f93}', &['\u{f92}', '\u{fb7}']),
+    ('\u{f9d}', &['\u{f9c}', '\u{fb7}']),
+    ('\u{fa2}', &['\u{fa1}', '\u{fb7}']),
+    ('\u{fa7}', &['\u{fa6}', '\u{fb7}']),
+    ('\u{fac}', &['\u{fab}', '\u{fb7}']),
+    ('\u{fb9}', &['\u{f90}', '\u{fb5}']),
+    ('\u{1026}', &['\u{1025}', '\u{102e}']),
+    ('\u{1b06}', &['\u{1b05}', '\u{1b35}']),
+    ('\u{1b08}', &['\u{1b07}', '\u{1b35}']),
+    ('\u{1b0a}', &['\u{1b09}', '\u{1b35}']),
+    ('\u{1b0c}', &['\u{1b0b}', '\u{1b35}']),
+    ('\u{1b0e}', &['\u{1b0d}', '\u{1b35}']),
+    ('\u{1b12}', &['\u{1b11}', '\u{1b35}']),
+    ('\u{1b3b}', &['\u{1b3a}', '\u{1b35}']),
+    ('\u{1b3d}', &['\u{1b3c}', '\u{1b35}']),
+    ('\u{1b40}', &['\u{1b3e}', '\u{1b35}']),
+    ('\u{1b41}', &['\u{1b3f}', '\u{1b35}']),
+    ('\u{1b43}', &['\u{1b42}', '\u{1b35}']),
+    ('\u{1e00}', &['\u{41}', '\u{325}']),
+    ('\u{1e01}', &['\u{61}', '\u{325}']),
+    ('\u{1e02}', &['\u{42}', '\u{307}']),
+    ('\u{1e03}', &['\u{62}', '\u{307}']),
+    ('\u{1e04}', &['\u{42}', '\u{323}']),
+    ('\u{1e05}', &['\u{62}', '\u{323}']),
+    ('\u{1e06}', &['\u{42}', '\u{331}']),
+    ('\u{1e07}', &['\u{62}', '\u{331}']),
+    ('\u{1e08}', &['\u{43}', '\u{327}', '\u{301}']),
+    ('\u{1e09}', &['\u{63}', '\u{327}', '\u{301}']),
+    ('\u{1e0a}', &['\u{44}', '\u{307}']),
+    ('\u{1e0b}', &['\u{64}', '\u{307}']),
+    ('\u{1e0c}', &['\u{44}', '\u{323}']),
+    ('\u{1e0d}', &['\u{64}', '\u{323}']),
+    ('\u{1e0e}', &['\u{44}', '\u{331}']),
+    ('\u{1e0f}', &['\u{64}', '\u{331}']),
+    ('\u{1e10}', &['\u{44}', '\u{327}']),
+    ('\u{1e11}', &['\u{64}', '\u{327}']),
+    ('\u{1e12}', &['\u{44}', '\u{32d}']),
+    ('\u{1e13}', &['\u{64}', '\u{32d}']),
+    ('\u{1e14}', &['\u{45}', '\u{304}', '\u{300}']),
+    ('\u{1e15}', &['\u{65}', '\u{304}', '\u{300}']),
+    ('\u{1e16}', &['\u{45}', '\u{304}', '\u{301}']),
+    ('\u{1e17}', &['\u{65}', '\u{304}', '\u{301}']),
+    ('\u{1e18}', &['\u{45}', '\u{32d}']),
+    ('\u{1e19}', &['\u{65}', '\u{32d}']),
+    ('\u{1e1a}', &['\u{45}', '\u{330}']),
+    ('\u{1e1b}', &['\u{65}', '\u{330}']),
+    ('\u{1e1c}', &['\u{45}', '\u{327}', '\u{306}']),
+    ('\u{1e1d}', &['\u{65}', '\u{327}', '\u{306}']),
+    ('\u{1e1e}', &['\u{46}', '\u{307}']),
+    ('\u{1e1f}', &['\u{66}', '\u{307}']),
+    ('\u{1e20}', &['\u{47}', '\u{304}']),
+    ('\u{1e21}', &['\u{67}', '\u{304}']),
+    ('\u{1e22}', &['\u{48}', '\u{307}']),
+    ('\u{1e23}', &['\u{68}', '\u{307}']),
+    ('\u{1e24}', &['\u{48}', '\u{323}']),
+    ('\u{1e25}', &['\u{68}', '\u{323}']),
+    ('\u{1e26}', &['\u{48}', '\u{308}']),
+    ('\u{1e27}', &['\u{68}', '\u{308}']),
+    ('\u{1e28}', &['\u{48}', '\u{327}']),
+    ('\u{1e29}', &['\u{68}', '\u{327}']),
+    ('\u{1e2a}', &['\u{48}', '\u{32e}']),
+    ('\u{1e2b}', &['\u{68}', '\u{32e}']),
+    ('\u{1e2c}', &['\u{49}', '\u{330}']),
+    ('\u{1e2d}', &['\u{69}', '\u{330}']),
+    ('\u{1e2e}', &['\u{49}', '\u{308}', '\u{301}']),
+    ('\u{1e2f}', &['\u{69}', '\u{308}', '\u{301}']),
+    ('\u{1e30}', &['\u{4b}', '\u{301}']),
+    ('\u{1e31}', &['\u{6b}', '\u{301}']),
+    ('\u{1e32}', &['\u{4b}', '\u{323}']),
+    ('\u{1e33}', &['\u{6b}', '\u{323}']),
+    ('\u{1e34}', &['\u{4b}', '\u{331}']),
+    ('\u{1e35}', &['\u{6b}', '\u{331}']),
+    ('\u{1e36}', &['\u{4c}', '\u{323}']),
+    ('\u{1e37}', &['\u{6c}', '\u{323}']),
+    ('\u{1e38}', &['\u{4c}', '\u{323}', '\u{304}']),
+    ('\u{1e39}', &['\u{6c}', '\u{323}', '\u{304}']),
+    ('\u{1e3a}', &['\u{4c}', '\u{331}']),
+    ('\u{1e3b}', &['\u{6c}', '\u{331}']),
+    ('\u{1e3c}', &['\u{4c}', '\u{32d}']),
+    ('\u{1e3d}', &['\u{6c}', '\u{32d}']),
+    ('\u{1e3e}', &['\u{4d}', '\u{301}']),
+    ('\u{1e3f}', &['\u{6d}', '\u{301}']),
+    ('\u{1e40}', &['\u{4d}', '\u{307}']),
+    ('\u{1e41}', &['\u{6d}', '\u{307}']),
+    ('\u{1e42}', &['\u{4d}', '\u{323}']),
+    ('\u{1e43}', &['\u{6d}', '\u{323}']),
+    ('\u{1e44}', &['\u{4e}', '\u{307}']),
+    ('\u{1e45}', &['\u{6e}', '\u{307}']),
+    ('\u{1e46}', &['\u{4e}', '\u{323}']),
+    ('\u{1e47}', &['\u{6e}', '\u{323}']),
+    ('\u{1e48}', &['\u{4e}', '\u{331}']),
+    ('\u{1e49}', &['\u{6e}', '\u{331}']),
+    ('\u{1e4a}', &['\u{4e}', '\u{32d}']),
+    ('\u{1e4b}', &['\u{6e}', '\u{32d}']),
+    ('\u{1e4c}', &['\u{4f}', '\u{303}', '\u{301}']),
+    ('\u{1e4d}', &['\u{6f}', '\u{303}', '\u{301}']),
+    ('\u{1e4e}', &['\u{4f}', '\u{303}', '\u{308}']),
+    ('\u{1e4f}', &['\u{6f}', '\u{303}', '\u{308}']),
+    ('\u{1e50}', &['\u{4f}', '\u{304}', '\u{300}']),
+    ('\u{1e51}', &['\u{6f}', '\u{304}', '\u{300}']),
+    ('\u{1e52}', &['\u{4f}', '\u{304}', '\u{301}']),
+    ('\u{1e53}', &['\u{6f}', '\u{304}', '\u{301}']),
+    ('\u{1e54}', &['\u{50}', '\u{301}']),
+    ('\u{1e55}', &['\u{70}', '\u{301}']),
+    ('\u{1e56}', &['\u{50}', '\u{307}']),
+    ('\u{1e57}', &['\u{70}', '\u{307}']),
+    ('\u{1e58}', &['\u{52}', '\u{307}']),
+    ('\u{1e59}', &['\u{72}', '\u{307}']),
+    ('\u{1e5a}', &['\u{52}', '\u{323}']),
+    ('\u{1e5b}', &['\u{72}', '\u{323}']),
+    ('\u{1e5c}', &['\u{52}', '\u{323}', '\u{304}']),
+    ('\u{1e5d}', &['\u{72}', '\u{323}', '\u{304}']),
+    ('\u{1e5e}', &['\u{52}', '\u{331}']),
+    ('\u{1e5f}', &['\u{72}', '\u{331}']),
+    ('\u{1e60}', &['\u{53}', '\u{307}']),
+    ('\u{1e61}', &['\u{73}', '\u{307}']),
+    ('\u{1e62}', &['\u{53}', '\u{323}']),
+    ('\u{1e63}', &['\u{73}', '\u{323}']),
+    ('\u{1e64}', &['\u{53}', '\u{301}', '\u{307}']),
+    ('\u{1e65}', &['\u{73}', '\u{301}', '\u{307}']),
+    ('\u{1e66}', &['\u{53}', '\u{30c}', '\u{307}']),
+    ('\u{1e67}', &['\u{73}', '\u{30c}', '\u{307}']),
+    ('\u{1e68}', &['\u{53}', '\u{323}', '\u{307}']),
+    ('\u{1e69}', &['\u{73}', '\u{323}', '\u{307}']),
+    ('\u{1e6a}', &['\u{54}', '\u{307}']),
+    ('\u{1e6b}', &['\u{74}', '\u{307}']),
+    ('\u{1e6c}', &['\u{54}', '\u{323}']),
+    ('\u{1e6d}', &['\u{74}', '\u{323}']),
+    ('\u{1e6e}', &['\u{54}', '\u{331}']),
+    ('\u{1e6f}', &['\u{74}', '\u{331}']),
+    ('\u{1e70}', &['\u{54}', '\u{32d}']),
+    ('\u{1e71}', &['\u{74}', '\u{32d}']),
+    ('\u{1e72}', &['\u{55}', '\u{324}']),
+    ('\u{1e73}', &['\u{75}', '\u{324}']),
+    ('\u{1e74}', &['\u{55}', '\u{330}']),
+    ('\u{1e75}', &['\u{75}', '\u{330}']),
+    ('\u{1e76}', &['\u{55}', '\u{32d}']),
+    ('\u{1e77}', &['\u{75}', '\u{32d}']),
+    ('\u{1e78}', &['\u{55}', '\u{303}', '\u{301}']),
+    ('\u{1e79}', &['\u{75}', '\u{303}', '\u{301}']),
+    ('\u{1e7a}', &['\u{55}', '\u{304}', '\u{308}']),
+    ('\u{1e7b}', &['\u{75}', '\u{304}', '\u{308}']),
+    ('\u{1e7c}', &['\u{56}', '\u{303}']),
+    ('\u{1e7d}', &['\u{76}', '\u{303}']),
+    ('\u{1e7e}', &['\u{56}', '\u{323}']),
+    ('\u{1e7f}', &['\u{76}', '\u{323}']),
+    ('\u{1e80}', &['\u{57}', '\u{300}']),
+    ('\u{1e81}', &['\u{77}', '\u{300}']),
+    ('\u{1e82}', &['\u{57}', '\u{301}']),
+    ('\u{1e83}', &['\u{77}', '\u{301}']),
+    ('\u{1e84}', &['\u{57}', '\u{308}']),
+    ('\u{1e85}', &['\u{77}', '\u{308}']),
+    ('\u{1e86}', &['\u{57}', '\u{307}']),
+    ('\u{1e87}', &['\u{77}', '\u{307}']),
+    ('\u{1e88}', &['\u{57}', '\u{323}']),
+    ('\u{1e89}', &['\u{77}', '\u{323}']),
+    ('\u{1e8a}', &['\u{58}', '\u{307}']),
+    ('\u{1e8b}', &['\u{78}', '\u{307}']),
+    ('\u{1e8c}', &['\u{58}', '\u{308}']),
+    ('\u{1e8d}', &['\u{78}', '\u{308}']),
+    ('\u{1e8e}', &['\u{59}', '\u{307}']),
+    ('\u{1e8f}', &['\u{79}', '\u{307}']),
+    ('\u{1e90}', &['\u{5a}', '\u{302}']),
+    ('\u{1e91}', &['\u{7a}', '\u{302}']),
+    ('\u{1e92}', &['\u{5a}', '\u{323}']),
+    ('\u{1e93}', &['\u{7a}', '\u{323}']),
+    ('\u{1e94}', &['\u{5a}', '\u{331}']),
+    ('\u{1e95}', &['\u{7a}', '\u{331}']),
+    ('\u{1e96}', &['\u{68}', '\u{331}']),
+    ('\u{1e97}', &['\u{74}', '\u{308}']),
+    ('\u{1e98}', &['\u{77}', '\u{30a}']),
+    ('\u{1e99}', &['\u{79}', '\u{30a}']),
+    ('\u{1e9b}', &['\u{17f}', '\u{307}']),
+    ('\u{1ea0}', &['\u{41}', '\u{323}']),
+    ('\u{1ea1}', &['\u{61}', '\u{323}']),
+    ('\u{1ea2}', &['\u{41}', '\u{309}']),
+    ('\u{1ea3}', &['\u{61}', '\u{309}']),
+    ('\u{1ea4}', &['\u{41}', '\u{302}', '\u{301}']),
+    ('\u{1ea5}', &['\u{61}', '\u{302}', '\u{301}']),
+    ('\u{1ea6}', &['\u{41}', '\u{302}', '\u{300}']),
+    ('\u{1ea7}', &['\u{61}', '\u{302}', '\u{300}']),
+    ('\u{1ea8}', &['\u{41}', '\u{302}', '\u{309}']),
+    ('\u{1ea9}', &['\u{61}', '\u{302}', '\u{309}']),
+    ('\u{1eaa}', &['\u{41}', '\u{302}', '\u{303}']),
+    ('\u{1eab}', &['\u{61}', '\u{302}', '\u{303}']),
+    ('\u{1eac}', &['\u{41}', '\u{323}', '\u{302}']),
+    ('\u{1ead}', &['\u{61}', '\u{323}', '\u{302}']),
+    ('\u{1eae}', &['\u{41}', '\u{306}', '\u{301}']),
+    ('\u{1eaf}', &['\u{61}', '\u{306}', '\u{301}']),
+    ('\u{1eb0}', &['\u{41}', '\u{306}', '\u{300}']),
+    ('\u{1eb1}', &['\u{61}', '\u{306}', '\u{300}']),
+    ('\u{1eb2}', &['\u{41}', '\u{306}', '\u{309}']),
+    ('\u{1eb3}', &['\u{61}', '\u{306}', '\u{309}']),
+    ('\u{1eb4}', &['\u{41}', '\u{306}', '\u{303}']),
+    ('\u{1eb5}', &['\u{61}', '\u{306}', '\u{303}']),
+    ('\u{1eb6}', &['\u{41}', '\u{323}', '\u{306}']),
+    ('\u{1eb7}', &['\u{61}', '\u{323}', '\u{306}']),
+    ('\u{1eb8}', &['\u{45}', '\u{323}']),
+    ('\u{1eb9}', &['\u{65}', '\u{323}']),
+    ('\u{1eba}', &['\u{45}', '\u{309}']),
+    ('\u{1ebb}', &['\u{65}', '\u{309}']),
+    ('\u{1ebc}', &['\u{45}', '\u{303}']),
+    ('\u{1ebd}', &['\u{65}', '\u{303}']),
+    ('\u{1ebe}', &['\u{45}', '\u{302}', '\u{301}']),
+    ('\u{1ebf}', &['\u{65}', '\u{302}', '\u{301}']),
+    ('\u{1ec0}', &['\u{45}', '\u{302}', '\u{300}']),
+    ('\u{1ec1}', &['\u{65}', '\u{302}', '\u{300}']),
+    ('\u{1ec2}', &['\u{45}', '\u{302}', '\u{309}']),
+    ('\u{1ec3}', &['\u{65}', '\u{302}', '\u{309}']),
+    ('\u{1ec4}', &['\u{45}', '\u{302}', '\u{303}']),
+    ('\u{1ec5}', &['\u{65}', '\u{302}', '\u{303}']),
+    ('\u{1ec6}', &['\u{45}', '\u{323}', '\u{302}']),
+    ('\u{1ec7}', &['\u{65}', '\u{323}', '\u{302}']),
+    ('\u{1ec8}', &['\u{49}', '\u{309}']),
+    ('\u{1ec9}', &['\u{69}', '\u{309}']),
+    ('\u{1eca}', &['\u{49}', '\u{323}']),
+    ('\u{1ecb}', &['\u{69}', '\u{323}']),
+    ('\u{1ecc}', &['\u{4f}', '\u{323}']),
+    ('\u{1ecd}', &['\u{6f}', '\u{323}']),
+    ('\u{1ece}', &['\u{4f}', '\u{309}']),
+    ('\u{1ecf}', &['\u{6f}', '\u{309}']),
+    ('\u{1ed0}', &['\u{4f}', '\u{302}', '\u{301}']),
+    ('\u{1ed1}', &['\u{6f}', '\u{302}', '\u{301}']),
+    ('\u{1ed2}', &['\u{4f}', '\u{302}', '\u{300}']),
+    ('\u{1ed3}', &['\u{6f}', '\u{302}', '\u{300}']),
+    ('\u{1ed4}', &['\u{4f}', '\u{302}', '\u{309}']),
+    ('\u{1ed5}', &['\u{6f}', '\u{302}', '\u{309}']),
+    ('\u{1ed6}', &['\u{4f}', '\u{302}', '\u{303}']),
+    ('\u{1ed7}', &['\u{6f}', '\u{302}', '\u{303}']),
+    ('\u{1ed8}', &['\u{4f}', '\u{323}', '\u{302}']),
+    ('\u{1ed9}', &['\u{6f}', '\u{323}', '\u{302}']),
+    ('\u{1eda}', &['\u{4f}', '\u{31b}', '\u{301}']),
+    ('\u{1edb}', &['\u{6f}', '\u{31b}', '\u{301}']),
+    ('\u{1edc}', &['\u{4f}', '\u{31b}', '\u{300}']),
+    ('\u{1edd}', &['\u{6f}', '\u{31b}', '\u{300}']),
+    ('\u{1ede}', &['\u{4f}', '\u{31b}', '\u{309}']),
+    ('\u{1edf}', &['\u{6f}', '\u{31b}', '\u{309}']),
+    ('\u{1ee0}', &['\u{4f}', '\u{31b}', '\u{303}']),
+    ('\u{1ee1}', &['\u{6f}', '\u{31b}', '\u{303}']),
+    ('\u{1ee2}', &['\u{4f}', '\u{31b}', '\u{323}']),
+    ('\u{1ee3}', &['\u{6f}', '\u{31b}', '\u{323}']),
+    ('\u{1ee4}', &['\u{55}', '\u{323}']),
+    ('\u{1ee5}', &['\u{75}', '\u{323}']),
+    ('\u{1ee6}', &['\u{55}', '\u{309}']),
+    ('\u{1ee7}', &['\u{75}', '\u{309}']),
+    ('\u{1ee8}', &['\u{55}', '\u{31b}', '\u{301}']),
+    ('\u{1ee9}', &['\u{75}', '\u{31b}', '\u{301}']),
+    ('\u{1eea}', &['\u{55}', '\u{31b}', '\u{300}']),
+    ('\u{1eeb}', &['\u{75}', '\u{31b}', '\u{300}']),
+    ('\u{1eec}', &['\u{55}', '\u{31b}', '\u{309}']),
+    ('\u{1eed}', &['\u{75}', '\u{31b}', '\u{309}']),
+    ('\u{1eee}', &['\u{55}', '\u{31b}', '\u{303}']),
+    ('\u{1eef}', &['\u{75}', '\u{31b}', '\u{303}']),
+    ('\u{1ef0}', &['\u{55}', '\u{31b}', '\u{323}']),
+    ('\u{1ef1}', &['\u{75}', '\u{31b}', '\u{323}']),
+    ('\u{1ef2}', &['\u{59}', '\u{300}']),
+    ('\u{1ef3}', &['\u{79}', '\u{300}']),
+    ('\u{1ef4}', &['\u{59}', '\u{323}']),
+    ('\u{1ef5}', &['\u{79}', '\u{323}']),
+    ('\u{1ef6}', &['\u{59}', '\u{309}']),
+    ('\u{1ef7}', &['\u{79}', '\u{309}']),
+    ('\u{1ef8}', &['\u{59}', '\u{303}']),
+    ('\u{1ef9}', &['\u{79}', '\u{303}']),
+    ('\u{1f00}', &['\u{3b1}', '\u{313}']),
+    ('\u{1f01}', &['\u{3b1}', '\u{314}']),
+    ('\u{1f02}', &['\u{3b1}', '\u{313}', '\u{300}']),
+    ('\u{1f03}', &['\u{3b1}', '\u{314}', '\u{300}']),
+    ('\u{1f04}', &['\u{3b1}', '\u{313}', '\u{301}']),
+    ('\u{1f05}', &['\u{3b1}', '\u{314}', '\u{301}']),
+    ('\u{1f06}', &['\u{3b1}', '\u{313}', '\u{342}']),
+    ('\u{1f07}', &['\u{3b1}', '\u{314}', '\u{342}']),
+    ('\u{1f08}', &['\u{391}', '\u{313}']),
+    ('\u{1f09}', &['\u{391}', '\u{314}']),
+    ('\u{1f0a}', &['\u{391}', '\u{313}', '\u{300}']),
+    ('\u{1f0b}', &['\u{391}', '\u{314}', '\u{300}']),
+    ('\u{1f0c}', &['\u{391}', '\u{313}', '\u{301}']),
+    ('\u{1f0d}', &['\u{391}', '\u{314}', '\u{301}']),
+    ('\u{1f0e}', &['\u{391}', '\u{313}', '\u{342}']),
+    ('\u{1f0f}', &['\u{391}', '\u{314}', '\u{342}']),
+    ('\u{1f10}', &['\u{3b5}', '\u{313}']),
+    ('\u{1f11}', &['\u{3b5}', '\u{314}']),
+    ('\u{1f12}', &['\u{3b5}', '\u{313}', '\u{300}']),
+    ('\u{1f13}', &['\u{3b5}', '\u{314}', '\u{300}']),
+    ('\u{1f14}', &['\u{3b5}', '\u{313}', '\u{301}']),
+    ('\u{1f15}', &['\u{3b5}', '\u{314}', '\u{301}']),
+    ('\u{1f18}', &['\u{395}', '\u{313}']),
+    ('\u{1f19}', &['\u{395}', '\u{314}']),
+    ('\u{1f1a}', &['\u{395}', '\u{313}', '\u{300}']),
+    ('\u{1f1b}', &['\u{395}', '\u{314}', '\u{300}']),
+    ('\u{1f1c}', &['\u{395}', '\u{313}', '\u{301}']),
+    ('\u{1f1d}', &['\u{395}', '\u{314}', '\u{301}']),
+    ('\u{1f20}', &['\u{3b7}', '\u{313}']),
+    ('\u{1f21}', &['\u{3b7}', '\u{314}']),
+    ('\u{1f22}', &['\u{3b7}', '\u{313}', '\u{300}']),
+    ('\u{1f23}', &['\u{3b7}', '\u{314}', '\u{300}']),
+    ('\u{1f24}', &['\u{3b7}', '\u{313}', '\u{301}']),
+    ('\u{1f25}', &['\u{3b7}', '\u{314}', '\u{301}']),
+    ('\u{1f26}', &['\u{3b7}', '\u{313}', '\u{342}']),
+    ('\u{1f27}', &['\u{3b7}', '\u{314}', '\u{342}']),
+    ('\u{1f28}', &['\u{397}', '\u{313}']),
+    ('\u{1f29}', &['\u{397}', '\u{314}']),
+    ('\u{1f2a}', &['\u{397}', '\u{313}', '\u{300}']),
+    ('\u{1f2b}', &['\u{397}', '\u{314}', '\u{300}']),
+    ('\u{1f2c}', &['\u{397}', '\u{313}', '\u{301}']),
+    ('\u{1f2d}', &['\u{397}', '\u{314}', '\u{301}']),
+    ('\u{1f2e}', &['\u{397}', '\u{313}', '\u{342}']),
+    ('\u{1f2f}', &['\u{397}', '\u{314}', '\u{342}']),
+    ('\u{1f30}', &['\u{3b9}', '\u{313}']),
+    ('\u{1f31}', &['\u{3b9}', '\u{314}']),
+    ('\u{1f32}', &['\u{3b9}', '\u{313}', '\u{300}']),
+    ('\u{1f33}', &['\u{3b9}', '\u{314}', '\u{300}']),
+    ('\u{1f34}', &['\u{3b9}', '\u{313}', '\u{301}']),
+    ('\u{1f35}', &['\u{3b9}', '\u{314}', '\u{301}']),
+    ('\u{1f36}', &['\u{3b9}', '\u{313}', '\u{342}']),
+    ('\u{1f37}', &['\u{3b9}', '\u{314}', '\u{342}']),
+    ('\u{1f38}', &['\u{399}', '\u{313}']),
+    ('\u{1f39}', &['\u{399}', '\u{314}']),
+    ('\u{1f3a}', &['\u{399}', '\u{313}', '\u{300}']),
+    ('\u{1f3b}', &['\u{399}', '\u{314}', '\u{300}']),
+    ('\u{1f3c}', &['\u{399}', '\u{313}', '\u{301}']),
+    ('\u{1f3d}', &['\u{399}', '\u{314}', '\u{301}']),
+    ('\u{1f3e}', &['\u{399}', '\u{313}', '\u{342}']),
+    ('\u{1f3f}', &['\u{399}', '\u{314}', '\u{342}']),
+    ('\u{1f40}', &['\u{3bf}', '\u{313}']),
+    ('\u{1f41}', &['\u{3bf}', '\u{314}']),
+    ('\u{1f42}', &['\u{3bf}', '\u{313}', '\u{300}']),
+    ('\u{1f43}', &['\u{3bf}', '\u{314}', '\u{300}']),
+    ('\u{1f44}', &['\u{3bf}', '\u{313}', '\u{301}']),
+    ('\u{1f45}', &['\u{3bf}', '\u{314}', '\u{301}']),
+    ('\u{1f48}', &['\u{39f}', '\u{313}']),
+    ('\u{1f49}', &['\u{39f}', '\u{314}']),
+    ('\u{1f4a}', &['\u{39f}', '\u{313}', '\u{300}']),
+    ('\u{1f4b}', &['\u{39f}', '\u{314}', '\u{300}']),
+    ('\u{1f4c}', &['\u{39f}', '\u{313}', '\u{301}']),
+    ('\u{1f4d}', &['\u{39f}', '\u{314}', '\u{301}']),
+    ('\u{1f50}', &['\u{3c5}', '\u{313}']),
+    ('\u{1f51}', &['\u{3c5}', '\u{314}']),
+    ('\u{1f52}', &['\u{3c5}', '\u{313}', '\u{300}']),
+    ('\u{1f53}', &['\u{3c5}', '\u{314}', '\u{300}']),
+    ('\u{1f54}', &['\u{3c5}', '\u{313}', '\u{301}']),
+    ('\u{1f55}', &['\u{3c5}', '\u{314}', '\u{301}']),
+    ('\u{1f56}', &['\u{3c5}', '\u{313}', '\u{342}']),
+    ('\u{1f57}', &['\u{3c5}', '\u{314}', '\u{342}']),
+    ('\u{1f59}', &['\u{3a5}', '\u{314}']),
+    ('\u{1f5b}', &['\u{3a5}', '\u{314}', '\u{300}']),
+    ('\u{1f5d}', &['\u{3a5}', '\u{314}', '\u{301}']),
+    ('\u{1f5f}', &['\u{3a5}', '\u{314}', '\u{342}']),
+    ('\u{1f60}', &['\u{3c9}', '\u{313}']),
+    ('\u{1f61}', &['\u{3c9}', '\u{314}']),
+    ('\u{1f62}', &['\u{3c9}', '\u{313}', '\u{300}']),
+    ('\u{1f63}', &['\u{3c9}', '\u{314}', '\u{300}']),
+    ('\u{1f64}', &['\u{3c9}', '\u{313}', '\u{301}']),
+    ('\u{1f65}', &['\u{3c9}', '\u{314}', '\u{301}']),
+    ('\u{1f66}', &['\u{3c9}', '\u{313}', '\u{342}']),
+    ('\u{1f67}', &['\u{3c9}', '\u{314}', '\u{342}']),
+    ('\u{1f68}', &['\u{3a9}', '\u{313}']),
+    ('\u{1f69}', &['\u{3a9}', '\u{314}']),
+    ('\u{1f6a}', &['\u{3a9}', '\u{313}', '\u{300}']),
+    ('\u{1f6b}', &['\u{3a9}', '\u{314}', '\u{300}']),
+    ('\u{1f6c}', &['\u{3a9}', '\u{313}', '\u{301}']),
+    ('\u{1f6d}', &['\u{3a9}', '\u{314}', '\u{301}']),
+    ('\u{1f6e}', &['\u{3a9}', '\u{313}', '\u{342}']),
+    ('\u{1f6f}', &['\u{3a9}', '\u{314}', '\u{342}']),
+    ('\u{1f70}', &['\u{3b1}', '\u{300}']),
+    ('\u{1f71}', &['\u{3b1}', '\u{301}']),
+    ('\u{1f72}', &['\u{3b5}', '\u{300}']),
+    ('\u{1f73}', &['\u{3b5}', '\u{301}']),
+    ('\u{1f74}', &['\u{3b7}', '\u{300}']),
+    ('\u{1f75}', &['\u{3b7}', '\u{301}']),
+    ('\u{1f76}', &['\u{3b9}', '\u{300}']),
+    ('\u{1f77}', &['\u{3b9}', '\u{301}']),
+    ('\u{1f78}', &['\u{3bf}', '\u{300}']),
+    ('\u{1f79}', &['\u{3bf}', '\u{301}']),
+    ('\u{1f7a}', &['\u{3c5}', '\u{300}']),
+    ('\u{1f7b}', &['\u{3c5}', '\u{301}']),
+    ('\u{1f7c}', &['\u{3c9}', '\u{300}']),
+    ('\u{1f7d}', &['\u{3c9}', '\u{301}']),
+    ('\u{1f80}', &['\u{3b1}', '\u{313}', '\u{345}']),
+    ('\u{1f81}', &['\u{3b1}', '\u{314}', '\u{345}']),
+    ('\u{1f82}', &['\u{3b1}', '\u{313}', '\u{300}', '\u{345}']),
+    ('\u{1f83}', &['\u{3b1}', '\u{314}', '\u{300}', '\u{345}']),
+    ('\u{1f84}', &['\u{3b1}', '\u{313}', '\u{301}', '\u{345}']),
+    ('\u{1f85}', &['\u{3b1}', '\u{314}', '\u{301}', '\u{345}']),
+    ('\u{1f86}', &['\u{3b1}', '\u{313}', '\u{342}', '\u{345}']),
+    ('\u{1f87}', &['\u{3b1}', '\u{314}', '\u{342}', '\u{345}']),
+    ('\u{1f88}', &['\u{391}', '\u{313}', '\u{345}']),
+    ('\u{1f89}', &['\u{391}', '\u{314}', '\u{345}']),
+    ('\u{1f8a}', &['\u{391}', '\u{313}', '\u{300}', '\u{345}']),
+    ('\u{1f8b}', &['\u{391}', '\u{314}', '\u{300}', '\u{345}']),
+    ('\u{1f8c}', &['\u{391}', '\u{313}', '\u{301}', '\u{345}']),
+    ('\u{1f8d}', &['\u{391}', '\u{314}', '\u{301}', '\u{345}']),
+    ('\u{1f8e}', &['\u{391}', '\u{313}', '\u{342}', '\u{345}']),
+    ('\u{1f8f}', &['\u{391}', '\u{314}', '\u{342}', '\u{345}']),
+    ('\u{1f90}', &['\u{3b7}', '\u{313}', '\u{345}']),
+    ('\u{1f91}', &['\u{3b7}', '\u{314}', '\u{345}']),
+    ('\u{1f92}', &['\u{3b7}', '\u{313}', '\u{300}', '\u{345}']),
+    ('\u{1f93}', &['\u{3b7}', '\u{314}', '\u{300}', '\u{345}']),
+    ('\u{1f94}', &['\u{3b7}', '\u{313}', '\u{301}', '\u{345}']),
+    ('\u{1f95}', &['\u{3b7}', '\u{314}', '\u{301}', '\u{345}']),
+    ('\u{1f96}', &['\u{3b7}', '\u{313}', '\u{342}', '\u{345}']),
+    ('\u{1f97}', &['\u{3b7}', '\u{314}', '\u{342}', '\u{345}']),
+    ('\u{1f98}', &['\u{397}', '\u{313}', '\u{345}']),
+    ('\u{1f99}', &['\u{397}', '\u{314}', '\u{345}']),
+    ('\u{1f9a}', &['\u{397}', '\u{313}', '\u{300}', '\u{345}']),
+    ('\u{1f9b}', &['\u{397}', '\u{314}', '\u{300}', '\u{345}']),
+    ('\u{1f9c}', &['\u{397}', '\u{313}', '\u{301}', '\u{345}']),
+    ('\u{1f9d}', &['\u{397}', '\u{314}', '\u{301}', '\u{345}']),
+    ('\u{1f9e}', &['\u{397}', '\u{313}', '\u{342}', '\u{345}']),
+    ('\u{1f9f}', &['\u{397}', '\u{314}', '\u{342}', '\u{345}']),
+    ('\u{1fa0}', &['\u{3c9}', '\u{313}', '\u{345}']),
+    ('\u{1fa1}', &['\u{3c9}', '\u{314}', '\u{345}']),
+    ('\u{1fa2}', &['\u{3c9}', '\u{313}', '\u{300}', '\u{345}']),
+    ('\u{1fa3}', &['\u{3c9}', '\u{314}', '\u{300}', '\u{345}']),
+    ('\u{1fa4}', &['\u{3c9}', '\u{313}', '\u{301}', '\u{345}']),
+    ('\u{1fa5}', &['\u{3c9}', '\u{314}', '\u{301}', '\u{345}']),
+    ('\u{1fa6}', &['\u{3c9}', '\u{313}', '\u{342}', '\u{345}']),
+    ('\u{1fa7}', &['\u{3c9}', '\u{314}', '\u{342}', '\u{345}']),
+    ('\u{1fa8}', &['\u{3a9}', '\u{313}', '\u{345}']),
+    ('\u{1fa9}', &['\u{3a9}', '\u{314}', '\u{345}']),
+    ('\u{1faa}', &['\u{3a9}', '\u{313}', '\u{300}', '\u{345}']),
+    ('\u{1fab}', &['\u{3a9}', '\u{314}', '\u{300}', '\u{345}']),
+    ('\u{1fac}', &['\u{3a9}', '\u{313}', '\u{301}', '\u{345}']),
+    ('\u{1fad}', &['\u{3a9}', '\u{314}', '\u{301}', '\u{345}']),
+    ('\u{1fae}', &['\u{3a9}', '\u{313}', '\u{342}', '\u{345}']),
+    ('\u{1faf}', &['\u{3a9}', '\u{314}', '\u{342}', '\u{345}']),
+    ('\u{1fb0}', &['\u{3b1}', '\u{306}']),
+    ('\u{1fb1}', &['\u{3b1}', '\u{304}']),
+    ('\u{1fb2}', &['\u{3b1}', '\u{300}', '\u{345}']),
+    ('\u{1fb3}', &['\u{3b1}', '\u{345}']),
+    ('\u{1fb4}', &['\u{3b1}', '\u{301}', '\u{345}']),
+    ('\u{1fb6}', &['\u{3b1}', '\u{342}']),
+    ('\u{1fb7}', &['\u{3b1}', '\u{342}', '\u{345}']),
+    ('\u{1fb8}', &['\u{391}', '\u{306}']),
+    ('\u{1fb9}', &['\u{391}', '\u{304}']),
+    ('\u{1fba}', &['\u{391}', '\u{300}']),
+    ('\u{1fbb}', &['\u{391}', '\u{301}']),
+    ('\u{1fbc}', &['\u{391}', '\u{345}']),
+    ('\u{1fbe}', &['\u{3b9}']),
+    ('\u{1fc1}', &['\u{a8}', '\u{342}']),
+    ('\u{1fc2}', &['\u{3b7}', '\u{300}', '\u{345}']),
+    ('\u{1fc3}', &['\u{3b7}', '\u{345}']),
+    ('\u{1fc4}', &['\u{3b7}', '\u{301}', '\u{345}']),
+    ('\u{1fc6}', &['\u{3b7}', '\u{342}']),
+    ('\u{1fc7}', &['\u{3b7}', '\u{342}', '\u{345}']),
+    ('\u{1fc8}', &['\u{395}', '\u{300}']),
+    ('\u{1fc9}', &['\u{395}', '\u{301}']),
+    ('\u{1fca}', &['\u{397}', '\u{300}']),
+    ('\u{1fcb}', &['\u{397}', '\u{301}']),
+    ('\u{1fcc}', &['\u{397}', '\u{345}']),
+    ('\u{1fcd}', &['\u{1fbf}', '\u{300}']),
+    ('\u{1fce}', &['\u{1fbf}', '\u{301}']),
+    ('\u{1fcf}', &['\u{1fbf}', '\u{342}']),
+    ('\u{1fd0}', &['\u{3b9}', '\u{306}']),
+    ('\u{1fd1}', &['\u{3b9}', '\u{304}']),
+    ('\u{1fd2}', &['\u{3b9}', '\u{308}', '\u{300}']),
+    ('\u{1fd3}', &['\u{3b9}', '\u{308}', '\u{301}']),
+    ('\u{1fd6}', &['\u{3b9}', '\u{342}']),
+    ('\u{1fd7}', &['\u{3b9}', '\u{308}', '\u{342}']),
+    ('\u{1fd8}', &['\u{399}', '\u{306}']),
+    ('\u{1fd9}', &['\u{399}', '\u{304}']),
+    ('\u{1fda}', &['\u{399}', '\u{300}']),
+    ('\u{1fdb}', &['\u{399}', '\u{301}']),
+    ('\u{1fdd}', &['\u{1ffe}', '\u{300}']),
+    ('\u{1fde}', &['\u{1ffe}', '\u{301}']),
+    ('\u{1fdf}', &['\u{1ffe}', '\u{342}']),
+    ('\u{1fe0}', &['\u{3c5}', '\u{306}']),
+    ('\u{1fe1}', &['\u{3c5}', '\u{304}']),
+    ('\u{1fe2}', &['\u{3c5}', '\u{308}', '\u{300}']),
+    ('\u{1fe3}', &['\u{3c5}', '\u{308}', '\u{301}']),
+    ('\u{1fe4}', &['\u{3c1}', '\u{313}']),
+    ('\u{1fe5}', &['\u{3c1}', '\u{314}']),
+    ('\u{1fe6}', &['\u{3c5}', '\u{342}']),
+    ('\u{1fe7}', &['\u{3c5}', '\u{308}', '\u{342}']),
+    ('\u{1fe8}', &['\u{3a5}', '\u{306}']),
+    ('\u{1fe9}', &['\u{3a5}', '\u{304}']),
+    ('\u{1fea}', &['\u{3a5}', '\u{300}']),
+    ('\u{1feb}', &['\u{3a5}', '\u{301}']),
+    ('\u{1fec}', &['\u{3a1}', '\u{314}']),
+    ('\u{1fed}', &['\u{a8}', '\u{300}']),
+    ('\u{1fee}', &['\u{a8}', '\u{301}']),
+    ('\u{1fef}', &['\u{60}']),
+    ('\u{1ff2}', &['\u{3c9}', '\u{300}', '\u{345}']),
+    ('\u{1ff3}', &['\u{3c9}', '\u{345}']),
+    ('\u{1ff4}', &['\u{3c9}', '\u{301}', '\u{345}']),
+    ('\u{1ff6}', &['\u{3c9}', '\u{342}']),
+    ('\u{1ff7}', &['\u{3c9}', '\u{342}', '\u{345}']),
+    ('\u{1ff8}', &['\u{39f}', '\u{300}']),
+    ('\u{1ff9}', &['\u{39f}', '\u{301}']),
+    ('\u{1ffa}', &['\u{3a9}', '\u{300}']),
+    ('\u{1ffb}', &['\u{3a9}', '\u{301}']),
+    ('\u{1ffc}', &['\u{3a9}', '\u{345}']),
+    ('\u{1ffd}', &['\u{b4}']),
+    ('\u{2000}', &['\u{2002}']),
+    ('\u{2001}', &['\u{2003}']),
+    ('\u{2126}', &['\u{3a9}']),
+    ('\u{212a}', &['\u{4b}']),
+    ('\u{212b}', &['\u{41}', '\u{30a}']),
+    ('\u{219a}', &['\u{2190}', '\u{338}']),
+    ('\u{219b}', &['\u{2192}', '\u{338}']),
+    ('\u{21ae}', &['\u{2194}', '\u{338}']),
+    ('\u{21cd}', &['\u{21d0}', '\u{338}']),
+    ('\u{21ce}', &['\u{21d4}', '\u{338}']),
+    ('\u{21cf}', &['\u{21d2}', '\u{338}']),
+    ('\u{2204}', &['\u{2203}', '\u{338}']),
+    ('\u{2209}', &['\u{2208}', '\u{338}']),
+    ('\u{220c}', &['\u{220b}', '\u{338}']),
+    ('\u{2224}', &['\u{2223}', '\u{338}']),
+    ('\u{2226}', &['\u{2225}', '\u{338}']),
+    ('\u{2241}', &['\u{223c}', '\u{338}']),
+    ('\u{2244}', &['\u{2243}', '\u{338}']),
+    ('\u{2247}', &['\u{2245}', '\u{338}']),
+    ('\u{2249}', &['\u{2248}', '\u{338}']),
+    ('\u{2260}', &['\u{3d}', '\u{338}']),
+    ('\u{2262}', &['\u{2261}', '\u{338}']),
+    ('\u{226d}', &['\u{224d}', '\u{338}']),
+    ('\u{226e}', &['\u{3c}', '\u{338}']),
+    ('\u{226f}', &['\u{3e}', '\u{338}']),
+    ('\u{2270}', &['\u{2264}', '\u{338}']),
+    ('\u{2271}', &['\u{2265}', '\u{338}']),
+    ('\u{2274}', &['\u{2272}', '\u{338}']),
+    ('\u{2275}', &['\u{2273}', '\u{338}']),
+    ('\u{2278}', &['\u{2276}', '\u{338}']),
+    ('\u{2279}', &['\u{2277}', '\u{338}']),
+    ('\u{2280}', &['\u{227a}', '\u{338}']),
+    ('\u{2281}', &['\u{227b}', '\u{338}']),
+    ('\u{2284}', &['\u{2282}', '\u{338}']),
+    ('\u{2285}', &['\u{2283}', '\u{338}']),
+    ('\u{2288}', &['\u{2286}', '\u{338}']),
+    ('\u{2289}', &['\u{2287}', '\u{338}']),
+    ('\u{22ac}', &['\u{22a2}', '\u{338}']),
+    ('\u{22ad}', &['\u{22a8}', '\u{338}']),
+    ('\u{22ae}', &['\u{22a9}', '\u{338}']),
+    ('\u{22af}', &['\u{22ab}', '\u{338}']),
+    ('\u{22e0}', &['\u{227c}', '\u{338}']),
+    ('\u{22e1}', &['\u{227d}', '\u{338}']),
+    ('\u{22e2}', &['\u{2291}', '\u{338}']),
+    ('\u{22e3}', &['\u{2292}', '\u{338}']),
+    ('\u{22ea}', &['\u{22b2}', '\u{338}']),
+    ('\u{22eb}', &['\u{22b3}', '\u{338}']),
+    ('\u{22ec}', &['\u{22b4}', '\u{338}']),
+    ('\u{22ed}', &['\u{22b5}', '\u{338}']),
+    ('\u{2329}', &['\u{3008}']),
+    ('\u{232a}', &['\u{3009}']),
+    ('\u{2adc}', &['\u{2add}', '\u{338}']),
+    ('\u{304c}', &['\u{304b}', '\u{3099}']),
+    ('\u{304e}', &['\u{304d}', '\u{3099}']),
+    ('\u{3050}', &['\u{304f}', '\u{3099}']),
+    ('\u{3052}', &['\u{3051}', '\u{3099}']),
+    ('\u{3054}', &['\u{3053}', '\u{3099}']),
+    ('\u{3056}', &['\u{3055}', '\u{3099}']),
+    ('\u{3058}', &['\u{3057}', '\u{3099}']),
+    ('\u{305a}', &['\u{3059}', '\u{3099}']),
+    ('\u{305c}', &['\u{305b}', '\u{3099}']),
+    ('\u{305e}', &['\u{305d}', '\u{3099}']),
+    ('\u{3060}', &['\u{305f}', '\u{3099}']),
+    ('\u{3062}', &['\u{3061}', '\u{3099}']),
+    ('\u{3065}', &['\u{3064}', '\u{3099}']),
+    ('\u{3067}', &['\u{3066}', '\u{3099}']),
+    ('\u{3069}', &['\u{3068}', '\u{3099}']),
+    ('\u{3070}', &['\u{306f}', '\u{3099}']),
+    ('\u{3071}', &['\u{306f}', '\u{309a}']),
+    ('\u{3073}', &['\u{3072}', '\u{3099}']),
+    ('\u{3074}', &['\u{3072}', '\u{309a}']),
+    ('\u{3076}', &['\u{3075}', '\u{3099}']),
+    ('\u{3077}', &['\u{3075}', '\u{309a}']),
+    ('\u{3079}', &['\u{3078}', '\u{3099}']),
+    ('\u{307a}', &['\u{3078}', '\u{309a}']),
+    ('\u{307c}', &['\u{307b}', '\u{3099}']),
+    ('\u{307d}', &['\u{307b}', '\u{309a}']),
+    ('\u{3094}', &['\u{3046}', '\u{3099}']),
+    ('\u{309e}', &['\u{309d}', '\u{3099}']),
+    ('\u{30ac}', &['\u{30ab}', '\u{3099}']),
+    ('\u{30ae}', &['\u{30ad}', '\u{3099}']),
+    ('\u{30b0}', &['\u{30af}', '\u{3099}']),
+    ('\u{30b2}', &['\u{30b1}', '\u{3099}']),
+    ('\u{30b4}', &['\u{30b3}', '\u{3099}']),
+    ('\u{30b6}', &['\u{30b5}', '\u{3099}']),
+    ('\u{30b8}', &['\u{30b7}', '\u{3099}']),
+    ('\u{30ba}', &['\u{30b9}', '\u{3099}']),
+    ('\u{30bc}', &['\u{30bb}', '\u{3099}']),
+    ('\u{30be}', &['\u{30bd}', '\u{3099}']),
+    ('\u{30c0}', &['\u{30bf}', '\u{3099}']),
+    ('\u{30c2}', &['\u{30c1}', '\u{3099}']),
+    ('\u{30c5}', &['\u{30c4}', '\u{3099}']),
+    ('\u{30c7}', &['\u{30c6}', '\u{3099}']),
+    ('\u{30c9}', &['\u{30c8}', '\u{3099}']),
+    ('\u{30d0}', &['\u{30cf}', '\u{3099}']),
+    ('\u{30d1}', &['\u{30cf}', '\u{309a}']),
+    ('\u{30d3}', &['\u{30d2}', '\u{3099}']),
+    ('\u{30d4}', &['\u{30d2}', '\u{309a}']),
+    ('\u{30d6}', &['\u{30d5}', '\u{3099}']),
+    ('\u{30d7}', &['\u{30d5}', '\u{309a}']),
+    ('\u{30d9}', &['\u{30d8}', '\u{3099}']),
+    ('\u{30da}', &['\u{30d8}', '\u{309a}']),
+    ('\u{30dc}', &['\u{30db}', '\u{3099}']),
+    ('\u{30dd}', &['\u{30db}', '\u{309a}']),
+    ('\u{30f4}', &['\u{30a6}', '\u{3099}']),
+    ('\u{30f7}', &['\u{30ef}', '\u{3099}']),
+    ('\u{30f8}', &['\u{30f0}', '\u{3099}']),
+    ('\u{30f9}', &['\u{30f1}', '\u{3099}']),
+    ('\u{30fa}', &['\u{30f2}', '\u{3099}']),
+    ('\u{30fe}', &['\u{30fd}', '\u{3099}']),
+    ('\u{f900}', &['\u{8c48}']),
+    ('\u{f901}', &['\u{66f4}']),
+    ('\u{f902}', &['\u{8eca}']),
+    ('\u{f903}', &['\u{8cc8}']),
+    ('\u{f904}', &['\u{6ed1}']),
+    ('\u{f905}', &['\u{4e32}']),
+    ('\u{f906}', &['\u{53e5}']),
+    ('\u{f907}', &['\u{9f9c}']),
+    ('\u{f908}', &['\u{9f9c}']),
+    ('\u{f909}', &['\u{5951}']),
+    ('\u{f90a}', &['\u{91d1}']),
+    ('\u{f90b}', &['\u{5587}']),
+    ('\u{f90c}', &['\u{5948}']),
+    ('\u{f90d}', &['\u{61f6}']),
+    ('\u{f90e}', &['\u{7669}']),
+    ('\u{f90f}', &['\u{7f85}']),
+    ('\u{f910}', &['\u{863f}']),
+    ('\u{f911}', &['\u{87ba}']),
+    ('\u{f912}', &['\u{88f8}']),
+    ('\u{f913}', &['\u{908f}']),
+    ('\u{f914}', &['\u{6a02}']),
+    ('\u{f915}', &['\u{6d1b}']),
+    ('\u{f916}', &['\u{70d9}']),
+    ('\u{f917}', &['\u{73de}']),
+    ('\u{f918}', &['\u{843d}']),
+    ('\u{f919}', &['\u{916a}']),
+    ('\u{f91a}', &['\u{99f1}']),
+    ('\u{f91b}', &['\u{4e82}']),
+    ('\u{f91c}', &['\u{5375}']),
+    ('\u{f91d}', &['\u{6b04}']),
+    ('\u{f91e}', &['\u{721b}']),
+    ('\u{f91f}', &['\u{862d}']),
+    ('\u{f920}', &['\u{9e1e}']),
+    ('\u{f921}', &['\u{5d50}']),
+    ('\u{f922}', &['\u{6feb}']),
+    ('\u{f923}', &['\u{85cd}']),
+    ('\u{f924}', &['\u{8964}']),
+    ('\u{f925}', &['\u{62c9}']),
+    ('\u{f926}', &['\u{81d8}']),
+    ('\u{f927}', &['\u{881f}']),
+    ('\u{f928}', &['\u{5eca}']),
+    ('\u{f929}', &['\u{6717}']),
+    ('\u{f92a}', &['\u{6d6a}']),
+    ('\u{f92b}', &['\u{72fc}']),
+    ('\u{f92c}', &['\u{90ce}']),
+    ('\u{f92d}', &['\u{4f86}']),
+    ('\u{f92e}', &['\u{51b7}']),
+    ('\u{f92f}', &['\u{52de}']),
+    ('\u{f930}', &['\u{64c4}']),
+    ('\u{f931}', &['\u{6ad3}']),
+    ('\u{f932}', &['\u{7210}']),
+    ('\u{f933}', &['\u{76e7}']),
+    ('\u{f934}', &['\u{8001}']),
+    ('\u{f935}', &['\u{8606}']),
+    ('\u{f936}', &['\u{865c}']),
+    ('\u{f937}', &['\u{8def}']),
+    ('\u{f938}', &['\u{9732}']),
+    ('\u{f939}', &['\u{9b6f}']),
+    ('\u{f93a}', &['\u{9dfa}']),
+    ('\u{f93b}', &['\u{788c}']),
+    ('\u{f93c}', &['\u{797f}']),
+    ('\u{f93d}', &['\u{7da0}']),
+    ('\u{f93e}', &['\u{83c9}']),
+    ('\u{f93f}', &['\u{9304}']),
+    ('\u{f940}', &['\u{9e7f}']),
+    ('\u{f941}', &['\u{8ad6}']),
+    ('\u{f942}', &['\u{58df}']),
+    ('\u{f943}', &['\u{5f04}']),
+    ('\u{f944}', &['\u{7c60}']),
+    ('\u{f945}', &['\u{807e}']),
+    ('\u{f946}', &['\u{7262}']),
+    ('\u{f947}', &['\u{78ca}']),
+    ('\u{f948}', &['\u{8cc2}']),
+    ('\u{f949}', &['\u{96f7}']),
+    ('\u{f94a}', &['\u{58d8}']),
+    ('\u{f94b}', &['\u{5c62}']),
+    ('\u{f94c}', &['\u{6a13}']),
+    ('\u{f94d}', &['\u{6dda}']),
+    ('\u{f94e}', &['\u{6f0f}']),
+    ('\u{f94f}', &['\u{7d2f}']),
+    ('\u{f950}', &['\u{7e37}']),
+    ('\u{f951}', &['\u{964b}']),
+    ('\u{f952}', &['\u{52d2}']),
+    ('\u{f953}', &['\u{808b}']),
+    ('\u{f954}', &['\u{51dc}']),
+    ('\u{f955}', &['\u{51cc}']),
+    ('\u{f956}', &['\u{7a1c}']),
+    ('\u{f957}', &['\u{7dbe}']),
+    ('\u{f958}', &['\u{83f1}']),
+    ('\u{f959}', &['\u{9675}']),
+    ('\u{f95a}', &['\u{8b80}']),
+    ('\u{f95b}', &['\u{62cf}']),
+    ('\u{f95c}', &['\u{6a02}']),
+    ('\u{f95d}', &['\u{8afe}']),
+    ('\u{f95e}', &['\u{4e39}']),
+    ('\u{f95f}', &['\u{5be7}']),
+    ('\u{f960}', &['\u{6012}']),
+    ('\u{f961}', &['\u{7387}']),
+    ('\u{f962}', &['\u{7570}']),
+    ('\u{f963}', &['\u{5317}']),
+    ('\u{f964}', &['\u{78fb}']),
+    ('\u{f965}', &['\u{4fbf}']),
+    ('\u{f966}', &['\u{5fa9}']),
+    ('\u{f967}', &['\u{4e0d}']),
+    ('\u{f968}', &['\u{6ccc}']),
+    ('\u{f969}', &['\u{6578}']),
+    ('\u{f96a}', &['\u{7d22}']),
+    ('\u{f96b}', &['\u{53c3}']),
+    ('\u{f96c}', &['\u{585e}']),
+    ('\u{f96d}', &['\u{7701}']),
+    ('\u{f96e}', &['\u{8449}']),
+    ('\u{f96f}', &['\u{8aaa}']),
+    ('\u{f970}', &['\u{6bba}']),
+    ('\u{f971}', &['\u{8fb0}']),
+    ('\u{f972}', &['\u{6c88}']),
+    ('\u{f973}', &['\u{62fe}']),
+    ('\u{f974}', &['\u{82e5}']),
+    ('\u{f975}', &['\u{63a0}']),
+    ('\u{f976}', &['\u{7565}']),
+    ('\u{f977}', &['\u{4eae}']),
+    ('\u{f978}', &['\u{5169}']),
+    ('\u{f979}', &['\u{51c9}']),
+    ('\u{f97a}', &['\u{6881}']),
+    ('\u{f97b}', &['\u{7ce7}']),
+    ('\u{f97c}', &['\u{826f}']),
+    ('\u{f97d}', &['\u{8ad2}']),
+    ('\u{f97e}', &['\u{91cf}']),
+    ('\u{f97f}', &['\u{52f5}']),
+    ('\u{f980}', &['\u{5442}']),
+    ('\u{f981}', &['\u{5973}']),
+    ('\u{f982}', &['\u{5eec}']),
+    ('\u{f983}', &['\u{65c5}']),
+    ('\u{f984}', &['\u{6ffe}']),
+    ('\u{f985}', &['\u{792a}']),
+    ('\u{f986}', &['\u{95ad}']),
+    ('\u{f987}', &['\u{9a6a}']),
+    ('\u{f988}', &['\u{9e97}']),
+    ('\u{f989}', &['\u{9ece}']),
+    ('\u{f98a}', &['\u{529b}']),
+    ('\u{f98b}', &['\u{66c6}']),
+    ('\u{f98c}', &['\u{6b77}']),
+    ('\u{f98d}', &['\u{8f62}']),
+    ('\u{f98e}', &['\u{5e74}']),
+    ('\u{f98f}', &['\u{6190}']),
+    ('\u{f990}', &['\u{6200}']),
+    ('\u{f991}', &['\u{649a}']),
+    ('\u{f992}', &['\u{6f23}']),
+    ('\u{f993}', &['\u{7149}']),
+    ('\u{f994}', &['\u{7489}']),
+    ('\u{f995}', &['\u{79ca}']),
+    ('\u{f996}', &['\u{7df4}']),
+    ('\u{f997}', &['\u{806f}']),
+    ('\u{f998}', &['\u{8f26}']),
+    ('\u{f999}', &['\u{84ee}']),
+    ('\u{f99a}', &['\u{9023}']),
+    ('\u{f99b}', &['\u{934a}']),
+    ('\u{f99c}', &['\u{5217}']),
+    ('\u{f99d}', &['\u{52a3}']),
+    ('\u{f99e}', &['\u{54bd}']),
+    ('\u{f99f}', &['\u{70c8}']),
+    ('\u{f9a0}', &['\u{88c2}']),
+    ('\u{f9a1}', &['\u{8aaa}']),
+    ('\u{f9a2}', &['\u{5ec9}']),
+    ('\u{f9a3}', &['\u{5ff5}']),
+    ('\u{f9a4}', &['\u{637b}']),
+    ('\u{f9a5}', &['\u{6bae}']),
+    ('\u{f9a6}', &['\u{7c3e}']),
+    ('\u{f9a7}', &['\u{7375}']),
+    ('\u{f9a8}', &['\u{4ee4}']),
+    ('\u{f9a9}', &['\u{56f9}']),
+    ('\u{f9aa}', &['\u{5be7}']),
+    ('\u{f9ab}', &['\u{5dba}']),
+    ('\u{f9ac}', &['\u{601c}']),
+    ('\u{f9ad}', &['\u{73b2}']),
+    ('\u{f9ae}', &['\u{7469}']),
+    ('\u{f9af}', &['\u{7f9a}']),
+    ('\u{f9b0}', &['\u{8046}']),
+    ('\u{f9b1}', &['\u{9234}']),
+    ('\u{f9b2}', &['\u{96f6}']),
+    ('\u{f9b3}', &['\u{9748}']),
+    ('\u{f9b4}', &['\u{9818}']),
+    ('\u{f9b5}', &['\u{4f8b}']),
+    ('\u{f9b6}', &['\u{79ae}']),
+    ('\u{f9b7}', &['\u{91b4}']),
+    ('\u{f9b8}', &['\u{96b8}']),
+    ('\u{f9b9}', &['\u{60e1}']),
+    ('\u{f9ba}', &['\u{4e86}']),
+    ('\u{f9bb}', &['\u{50da}']),
+    ('\u{f9bc}', &['\u{5bee}']),
+    ('\u{f9bd}', &['\u{5c3f}']),
+    ('\u{f9be}', &['\u{6599}']),
+    ('\u{f9bf}', &['\u{6a02}']),
+    ('\u{f9c0}', &['\u{71ce}']),
+    ('\u{f9c1}', &['\u{7642}']),
+    ('\u{f9c2}', &['\u{84fc}']),
+    ('\u{f9c3}', &['\u{907c}']),
+    ('\u{f9c4}', &['\u{9f8d}']),
+    ('\u{f9c5}', &['\u{6688}']),
+    ('\u{f9c6}', &['\u{962e}']),
+    ('\u{f9c7}', &['\u{5289}']),
+    ('\u{f9c8}', &['\u{677b}']),
+    ('\u{f9c9}', &['\u{67f3}']),
+    ('\u{f9ca}', &['\u{6d41}']),
+    ('\u{f9cb}', &['\u{6e9c}']),
+    ('\u{f9cc}', &['\u{7409}']),
+    ('\u{f9cd}', &['\u{7559}']),
+    ('\u{f9ce}', &['\u{786b}']),
+    ('\u{f9cf}', &['\u{7d10}']),
+    ('\u{f9d0}', &['\u{985e}']),
+    ('\u{f9d1}', &['\u{516d}']),
+    ('\u{f9d2}', &['\u{622e}']),
+    ('\u{f9d3}', &['\u{9678}']),
+    ('\u{f9d4}', &['\u{502b}']),
+    ('\u{f9d5}', &['\u{5d19}']),
+    ('\u{f9d6}', &['\u{6dea}']),
+    ('\u{f9d7}', &['\u{8f2a}']),
+    ('\u{f9d8}', &['\u{5f8b}']),
+    ('\u{f9d9}', &['\u{6144}']),
+    ('\u{f9da}', &['\u{6817}']),
+    ('\u{f9db}', &['\u{7387}']),
+    ('\u{f9dc}', &['\u{9686}']),
+    ('\u{f9dd}', &['\u{5229}']),
+    ('\u{f9de}', &['\u{540f}']),
+    ('\u{f9df}', &['\u{5c65}']),
+    ('\u{f9e0}', &['\u{6613}']),
+    ('\u{f9e1}', &['\u{674e}']),
+    ('\u{f9e2}', &['\u{68a8}']),
+    ('\u{f9e3}', &['\u{6ce5}']),
+    ('\u{f9e4}', &['\u{7406}']),
+    ('\u{f9e5}', &['\u{75e2}']),
+    ('\u{f9e6}', &['\u{7f79}']),
+    ('\u{f9e7}', &['\u{88cf}']),
+    ('\u{f9e8}', &['\u{88e1}']),
+    ('\u{f9e9}', &['\u{91cc}']),
+    ('\u{f9ea}', &['\u{96e2}']),
+    ('\u{f9eb}', &['\u{533f}']),
+    ('\u{f9ec}', &['\u{6eba}']),
+    ('\u{f9ed}', &['\u{541d}']),
+    ('\u{f9ee}', &['\u{71d0}']),
+    ('\u{f9ef}', &['\u{7498}']),
+    ('\u{f9f0}', &['\u{85fa}']),
+    ('\u{f9f1}', &['\u{96a3}']),
+    ('\u{f9f2}', &['\u{9c57}']),
+    ('\u{f9f3}', &['\u{9e9f}']),
+    ('\u{f9f4}', &['\u{6797}']),
+    ('\u{f9f5}', &['\u{6dcb}']),
+    ('\u{f9f6}', &['\u{81e8}']),
+    ('\u{f9f7}', &['\u{7acb}']),
+    ('\u{f9f8}', &['\u{7b20}']),
+    ('\u{f9f9}', &['\u{7c92}']),
+    ('\u{f9fa}', &['\u{72c0}']),
+    ('\u{f9fb}', &['\u{7099}']),
+    ('\u{f9fc}', &['\u{8b58}']),
+    ('\u{f9fd}', &['\u{4ec0}']),
+    ('\u{f9fe}', &['\u{8336}']),
+    ('\u{f9ff}', &['\u{523a}']),
+    ('\u{fa00}', &['\u{5207}']),
+    ('\u{fa01}', &['\u{5ea6}']),
+    ('\u{fa02}', &['\u{62d3}']),
+    ('\u{fa03}', &['\u{7cd6}']),
+    ('\u{fa04}', &['\u{5b85}']),
+    ('\u{fa05}', &['\u{6d1e}']),
+    ('\u{fa06}', &['\u{66b4}']),
+    ('\u{fa07}', &['\u{8f3b}']),
+    ('\u{fa08}', &['\u{884c}']),
+    ('\u{fa09}', &['\u{964d}']),
+    ('\u{fa0a}', &['\u{898b}']),
+    ('\u{fa0b}', &['\u{5ed3}']),
+    ('\u{fa0c}', &['\u{5140}']),
+    ('\u{fa0d}', &['\u{55c0}']),
+    ('\u{fa10}', &['\u{585a}']),
+    ('\u{fa12}', &['\u{6674}']),
+    ('\u{fa15}', &['\u{51de}']),
+    ('\u{fa16}', &['\u{732a}']),
+    ('\u{fa17}', &['\u{76ca}']),
+    ('\u{fa18}', &['\u{793c}']),
+    ('\u{fa19}', &['\u{795e}']),
+    ('\u{fa1a}', &['\u{7965}']),
+    ('\u{fa1b}', &['\u{798f}']),
+    ('\u{fa1c}', &['\u{9756}']),
+    ('\u{fa1d}', &['\u{7cbe}']),
+    ('\u{fa1e}', &['\u{7fbd}']),
+    ('\u{fa20}', &['\u{8612}']),
+    ('\u{fa22}', &['\u{8af8}']),
+    ('\u{fa25}', &['\u{9038}']),
+    ('\u{fa26}', &['\u{90fd}']),
+    ('\u{fa2a}', &['\u{98ef}']),
+    ('\u{fa2b}', &['\u{98fc}']),
+    ('\u{fa2c}', &['\u{9928}']),
+    ('\u{fa2d}', &['\u{9db4}']),
+    ('\u{fa2e}', &['\u{90de}']),
+    ('\u{fa2f}', &['\u{96b7}']),
+    ('\u{fa30}', &['\u{4fae}']),
+    ('\u{fa31}', &['\u{50e7}']),
+    ('\u{fa32}', &['\u{514d}']),
+    ('\u{fa33}', &['\u{52c9}']),
+    ('\u{fa34}', &['\u{52e4}']),
+    ('\u{fa35}', &['\u{5351}']),
+    ('\u{fa36}', &['\u{559d}']),
+    ('\u{fa37}', &['\u{5606}']),
+    ('\u{fa38}', &['\u{5668}']),
+    ('\u{fa39}', &['\u{5840}']),
+    ('\u{fa3a}', &['\u{58a8}']),
+    ('\u{fa3b}', &['\u{5c64}']),
+    ('\u{fa3c}', &['\u{5c6e}']),
+    ('\u{fa3d}', &['\u{6094}']),
+    ('\u{fa3e}', &['\u{6168}']),
+    ('\u{fa3f}', &['\u{618e}']),
+    ('\u{fa40}', &['\u{61f2}']),
+    ('\u{fa41}', &['\u{654f}']),
+    ('\u{fa42}', &['\u{65e2}']),
+    ('\u{fa43}', &['\u{6691}']),
+    ('\u{fa44}', &['\u{6885}']),
+    ('\u{fa45}', &['\u{6d77}']),
+    ('\u{fa46}', &['\u{6e1a}']),
+    ('\u{fa47}', &['\u{6f22}']),
+    ('\u{fa48}', &['\u{716e}']),
+    ('\u{fa49}', &['\u{722b}']),
+    ('\u{fa4a}', &['\u{7422}']),
+    ('\u{fa4b}', &['\u{7891}']),
+    ('\u{fa4c}', &['\u{793e}']),
+    ('\u{fa4d}', &['\u{7949}']),
+    ('\u{fa4e}', &['\u{7948}']),
+    ('\u{fa4f}', &['\u{7950}']),
+    ('\u{fa50}', &['\u{7956}']),
+    ('\u{fa51}', &['\u{795d}']),
+    ('\u{fa52}', &['\u{798d}']),
+    ('\u{fa53}', &['\u{798e}']),
+    ('\u{fa54}', &['\u{7a40}']),
+    ('\u{fa55}', &['\u{7a81}']),
+    ('\u{fa56}', &['\u{7bc0}']),
+    ('\u{fa57}', &['\u{7df4}']),
+    ('\u{fa58}', &['\u{7e09}']),
+    ('\u{fa59}', &['\u{7e41}']),
+    ('\u{fa5a}', &['\u{7f72}']),
+    ('\u{fa5b}', &['\u{8005}']),
+    ('\u{fa5c}', &['\u{81ed}']),
+    ('\u{fa5d}', &['\u{8279}']),
+    ('\u{fa5e}', &['\u{8279}']),
+    ('\u{fa5f}', &['\u{8457}']),
+    ('\u{fa60}', &['\u{8910}']),
+    ('\u{fa61}', &['\u{8996}']),
+    ('\u{fa62}', &['\u{8b01}']),
+    ('\u{fa63}', &['\u{8b39}']),
+    ('\u{fa64}', &['\u{8cd3}']),
+    ('\u{fa65}', &['\u{8d08}']),
+    ('\u{fa66}', &['\u{8fb6}']),
+    ('\u{fa67}', &['\u{9038}']),
+    ('\u{fa68}', &['\u{96e3}']),
+    ('\u{fa69}', &['\u{97ff}']),
+    ('\u{fa6a}', &['\u{983b}']),
+    ('\u{fa6b}', &['\u{6075}']),
+    ('\u{fa6c}', &['\u{242ee}']),
+    ('\u{fa6d}', &['\u{8218}']),
+    ('\u{fa70}', &['\u{4e26}']),
+    ('\u{fa71}', &['\u{51b5}']),
+    ('\u{fa72}', &['\u{5168}']),
+    ('\u{fa73}', &['\u{4f80}']),
+    ('\u{fa74}', &['\u{5145}']),
+    ('\u{fa75}', &['\u{5180}']),
+    ('\u{fa76}', &['\u{52c7}']),
+    ('\u{fa77}', &['\u{52fa}']),
+    ('\u{fa78}', &['\u{559d}']),
+    ('\u{fa79}', &['\u{5555}']),
+    ('\u{fa7a}', &['\u{5599}']),
+    ('\u{fa7b}', &['\u{55e2}']),
+    ('\u{fa7c}', &['\u{585a}']),
+    ('\u{fa7d}', &['\u{58b3}']),
+    ('\u{fa7e}', &['\u{5944}']),
+    ('\u{fa7f}', &['\u{5954}']),
+    ('\u{fa80}', &['\u{5a62}']),
+    ('\u{fa81}', &['\u{5b28}']),
+    ('\u{fa82}', &['\u{5ed2}']),
+    ('\u{fa83}', &['\u{5ed9}']),
+    ('\u{fa84}', &['\u{5f69}']),
+    ('\u{fa85}', &['\u{5fad}']),
+    ('\u{fa86}', &['\u{60d8}']),
+    ('\u{fa87}', &['\u{614e}']),
+    ('\u{fa88}', &['\u{6108}']),
+    ('\u{fa89}', &['\u{618e}']),
+    ('\u{fa8a}', &['\u{6160}']),
+    ('\u{fa8b}', &['\u{61f2}']),
+    ('\u{fa8c}', &['\u{6234}']),
+    ('\u{fa8d}', &['\u{63c4}']),
+    ('\u{fa8e}', &['\u{641c}']),
+    ('\u{fa8f}', &['\u{6452}']),
+    ('\u{fa90}', &['\u{6556}']),
+    ('\u{fa91}', &['\u{6674}']),
+    ('\u{fa92}', &['\u{6717}']),
+    ('\u{fa93}', &['\u{671b}']),
+    ('\u{fa94}', &['\u{6756}']),
+    ('\u{fa95}', &['\u{6b79}']),
+    ('\u{fa96}', &['\u{6bba}']),
+    ('\u{fa97}', &['\u{6d41}']),
+    ('\u{fa98}', &['\u{6edb}']),
+    ('\u{fa99}', &['\u{6ecb}']),
+    ('\u{fa9a}', &['\u{6f22}']),
+    ('\u{fa9b}', &['\u{701e}']),
+    ('\u{fa9c}', &['\u{716e}']),
+    ('\u{fa9d}', &['\u{77a7}']),
+    ('\u{fa9e}', &['\u{7235}']),
+    ('\u{fa9f}', &['\u{72af}']),
+    ('\u{faa0}', &['\u{732a}']),
+    ('\u{faa1}', &['\u{7471}']),
+    ('\u{faa2}', &['\u{7506}']),
+    ('\u{faa3}', &['\u{753b}']),
+    ('\u{faa4}', &['\u{761d}']),
+    ('\u{faa5}', &['\u{761f}']),
+    ('\u{faa6}', &['\u{76ca}']),
+    ('\u{faa7}', &['\u{76db}']),
+    ('\u{faa8}', &['\u{76f4}']),
+    ('\u{faa9}', &['\u{774a}']),
+    ('\u{faaa}', &['\u{7740}']),
+    ('\u{faab}', &['\u{78cc}']),
+    ('\u{faac}', &['\u{7ab1}']),
+    ('\u{faad}', &['\u{7bc0}']),
+    ('\u{faae}', &['\u{7c7b}']),
+    ('\u{faaf}', &['\u{7d5b}']),
+    ('\u{fab0}', &['\u{7df4}']),
+    ('\u{fab1}', &['\u{7f3e}']),
+    ('\u{fab2}', &['\u{8005}']),
+    ('\u{fab3}', &['\u{8352}']),
+    ('\u{fab4}', &['\u{83ef}']),
+    ('\u{fab5}', &['\u{8779}']),
+    ('\u{fab6}', &['\u{8941}']),
+    ('\u{fab7}', &['\u{8986}']),
+    ('\u{fab8}', &['\u{8996}']),
+    ('\u{fab9}', &['\u{8abf}']),
+    ('\u{faba}', &['\u{8af8}']),
+    ('\u{fabb}', &['\u{8acb}']),
+    ('\u{fabc}', &['\u{8b01}']),
+    ('\u{fabd}', &['\u{8afe}']),
+    ('\u{fabe}', &['\u{8aed}']),
+    ('\u{fabf}', &['\u{8b39}']),
+    ('\u{fac0}', &['\u{8b8a}']),
+    ('\u{fac1}', &['\u{8d08}']),
+    ('\u{fac2}', &['\u{8f38}']),
+    ('\u{fac3}', &['\u{9072}']),
+    ('\u{fac4}', &['\u{9199}']),
+    ('\u{fac5}', &['\u{9276}']),
+    ('\u{fac6}', &['\u{967c}']),
+    ('\u{fac7}', &['\u{96e3}']),
+    ('\u{fac8}', &['\u{9756}']),
+    ('\u{fac9}', &['\u{97db}']),
+    ('\u{faca}', &['\u{97ff}']),
+    ('\u{facb}', &['\u{980b}']),
+    ('\u{facc}', &['\u{983b}']),
+    ('\u{facd}', &['\u{9b12}']),
+    ('\u{face}', &['\u{9f9c}']),
+    ('\u{facf}', &['\u{2284a}']),
+    ('\u{fad0}', &['\u{22844}']),
+    ('\u{fad1}', &['\u{233d5}']),
+    ('\u{fad2}', &['\u{3b9d}']),
+    ('\u{fad3}', &['\u{4018}']),
+    ('\u{fad4}', &['\u{4039}']),
+    ('\u{fad5}', &['\u{25249}']),
+    ('\u{fad6}', &['\u{25cd0}']),
+    ('\u{fad7}', &['\u{27ed3}']),
+    ('\u{fad8}', &['\u{9f43}']),
+    ('\u{fad9}', &['\u{9f8e}']),
+    ('\u{fb1d}', &['\u{5d9}', '\u{5b4}']),
+    ('\u{fb1f}', &['\u{5f2}', '\u{5b7}']),
+    ('\u{fb2a}', &['\u{5e9}', '\u{5c1}']),
+    ('\u{fb2b}', &['\u{5e9}', '\u{5c2}']),
+    ('\u{fb2c}', &['\u{5e9}', '\u{5bc}', '\u{5c1}']),
+    ('\u{fb2d}', &['\u{5e9}', '\u{5bc}', '\u{5c2}']),
+    ('\u{fb2e}', &['\u{5d0}', '\u{5b7}']),
+    ('\u{fb2f}', &['\u{5d0}', '\u{5b8}']),
+    ('\u{fb30}', &['\u{5d0}', '\u{5bc}']),
+    ('\u{fb31}', &['\u{5d1}', '\u{5bc}']),
+    ('\u{fb32}', &['\u{5d2}', '\u{5bc}']),
+    ('\u{fb33}', &['\u{5d3}', '\u{5bc}']),
+    ('\u{fb34}', &['\u{5d4}', '\u{5bc}']),
+    ('\u{fb35}', &['\u{5d5}', '\u{5bc}']),
+    ('\u{fb36}', &['\u{5d6}', '\u{5bc}']),
+    ('\u{fb38}', &['\u{5d8}', '\u{5bc}']),
+    ('\u{fb39}', &['\u{5d9}', '\u{5bc}']),
+    ('\u{fb3a}', &['\u{5da}', '\u{5bc}']),
+    ('\u{fb3b}', &['\u{5db}', '\u{5bc}']),
+    ('\u{fb3c}', &['\u{5dc}', '\u{5bc}']),
+    ('\u{fb3e}', &['\u{5de}', '\u{5bc}']),
+    ('\u{fb40}', &['\u{5e0}', '\u{5bc}']),
+    ('\u{fb41}', &['\u{5e1}', '\u{5bc}']),
+    ('\u{fb43}', &['\u{5e3}', '\u{5bc}']),
+    ('\u{fb44}', &['\u{5e4}', '\u{5bc}']),
+    ('\u{fb46}', &['\u{5e6}', '\u{5bc}']),
+    ('\u{fb47}', &['\u{5e7}', '\u{5bc}']),
+    ('\u{fb48}', &['\u{5e8}', '\u{5bc}']),
+    ('\u{fb49}', &['\u{5e9}', '\u{5bc}']),
+    ('\u{fb4a}', &['\u{5ea}', '\u{5bc}']),
+    ('\u{fb4b}', &['\u{5d5}', '\u{5b9}']),
+    ('\u{fb4c}', &['\u{5d1}', '\u{5bf}']),
+    ('\u{fb4d}', &['\u{5db}', '\u{5bf}']),
+    ('\u{fb4e}', &['\u{5e4}', '\u{5bf}']),
+    ('\u{1109a}', &['\u{11099}', '\u{110ba}']),
+    ('\u{1109c}', &['\u{1109b}', '\u{110ba}']),
+    ('\u{110ab}', &['\u{110a5}', '\u{110ba}']),
+    ('\u{1112e}', &['\u{11131}', '\u{11127}']),
+    ('\u{1112f}', &['\u{11132}', '\u{11127}']),
+    ('\u{1134b}', &['\u{11347}', '\u{1133e}']),
+    ('\u{1134c}', &['\u{11347}', '\u{11357}']),
+    ('\u{114bb}', &['\u{114b9}', '\u{114ba}']),
+    ('\u{114bc}', &['\u{114b9}', '\u{114b0}']),
+    ('\u{114be}', &['\u{114b9}', '\u{114bd}']),
+    ('\u{115ba}', &['\u{115b8}', '\u{115af}']),
+    ('\u{115bb}', &['\u{115b9}', '\u{115af}']),
+    ('\u{11938}', &['\u{11935}', '\u{11930}']),
+    ('\u{1d15e}', &['\u{1d157}', '\u{1d165}']),
+    ('\u{1d15f}', &['\u{1d158}', '\u{1d165}']),
+    ('\u{1d160}', &['\u{1d158}', '\u{1d165}', '\u{1d16e}']),
+    ('\u{1d161}', &['\u{1d158}', '\u{1d165}', '\u{1d16f}']),
+    ('\u{1d162}', &['\u{1d158}', '\u{1d165}', '\u{1d170}']),
+    ('\u{1d163}', &['\u{1d158}', '\u{1d165}', '\u{1d171}']),
+    ('\u{1d164}', &['\u{1d158}', '\u{1d165}', '\u{1d172}']),
+    ('\u{1d1bb}', &['\u{1d1b9}', '\u{1d165}']),
+    ('\u{1d1bc}', &['\u{1d1ba}', '\u{1d165}']),
+    ('\u{1d1bd}', &['\u{1d1b9}', '\u{1d165}', '\u{1d16e}']),
+    ('\u{1d1be}', &['\u{1d1ba}', '\u{1d165}', '\u{1d16e}']),
+    ('\u{1d1bf}', &['\u{1d1b9}', '\u{1d165}', '\u{1d16f}']),
+    ('\u{1d1c0}', &['\u{1d1ba}', '\u{1d165}', '\u{1d16f}']),
+    ('\u{2f800}', &['\u{4e3d}']),
+    ('\u{2f801}', &['\u{4e38}']),
+    ('\u{2f802}', &['\u{4e41}']),
+    ('\u{2f803}', &['\u{20122}']),
+    ('\u{2f804}', &['\u{4f60}']),
+    ('\u{2f805}', &['\u{4fae}']),
+    ('\u{2f806}', &['\u{4fbb}']),
+    ('\u{2f807}', &['\u{5002}']),
+    ('\u{2f808}', &['\u{507a}']),
+    ('\u{2f809}', &['\u{5099}']),
+    ('\u{2f80a}', &['\u{50e7}']),
+    ('\u{2f80b}', &['\u{50cf}']),
+    ('\u{2f80c}', &['\u{349e}']),
+    ('\u{2f80d}', &['\u{2063a}']),
+    ('\u{2f80e}', &['\u{514d}']),
+    ('\u{2f80f}', &['\u{5154}']),
+    ('\u{2f810}', &['\u{5164}']),
+    ('\u{2f811}', &['\u{5177}']),
+    ('\u{2f812}', &['\u{2051c}']),
+    ('\u{2f813}', &['\u{34b9}']),
+    ('\u{2f814}', &['\u{5167}']),
+    ('\u{2f815}', &['\u{518d}']),
+    ('\u{2f816}', &['\u{2054b}']),
+    ('\u{2f817}', &['\u{5197}']),
+    ('\u{2f818}', &['\u{51a4}']),
+    ('\u{2f819}', &['\u{4ecc}']),
+    ('\u{2f81a}', &['\u{51ac}']),
+    ('\u{2f81b}', &['\u{51b5}']),
+    ('\u{2f81c}', &['\u{291df}']),
+    ('\u{2f81d}', &['\u{51f5}']),
+    ('\u{2f81e}', &['\u{5203}']),
+    ('\u{2f81f}', &['\u{34df}']),
+    ('\u{2f820}', &['\u{523b}']),
+    ('\u{2f821}', &['\u{5246}']),
+    ('\u{2f822}', &['\u{5272}']),
+    ('\u{2f823}', &['\u{5277}']),
+    ('\u{2f824}', &['\u{3515}']),
+    ('\u{2f825}', &['\u{52c7}']),
+    ('\u{2f826}', &['\u{52c9}']),
+    ('\u{2f827}', &['\u{52e4}']),
+    ('\u{2f828}', &['\u{52fa}']),
+    ('\u{2f829}', &['\u{5305}']),
+    ('\u{2f82a}', &['\u{5306}']),
+    ('\u{2f82b}', &['\u{5317}']),
+    ('\u{2f82c}', &['\u{5349}']),
+    ('\u{2f82d}', &['\u{5351}']),
+    ('\u{2f82e}', &['\u{535a}']),
+    ('\u{2f82f}', &['\u{5373}']),
+    ('\u{2f830}', &['\u{537d}']),
+    ('\u{2f831}', &['\u{537f}']),
+    ('\u{2f832}', &['\u{537f}']),
+    ('\u{2f833}', &['\u{537f}']),
+    ('\u{2f834}', &['\u{20a2c}']),
+    ('\u{2f835}', &['\u{7070}']),
+    ('\u{2f836}', &['\u{53ca}']),
+    ('\u{2f837}', &['\u{53df}']),
+    ('\u{2f838}', &['\u{20b63}']),
+    ('\u{2f839}', &['\u{53eb}']),
+    ('\u{2f83a}', &['\u{53f1}']),
+    ('\u{2f83b}', &['\u{5406}']),
+    ('\u{2f83c}', &['\u{549e}']),
+    ('\u{2f83d}', &['\u{5438}']),
+    ('\u{2f83e}', &['\u{5448}']),
+    ('\u{2f83f}', &['\u{5468}']),
+    ('\u{2f840}', &['\u{54a2}']),
+    ('\u{2f841}', &['\u{54f6}']),
+    ('\u{2f842}', &['\u{5510}']),
+    ('\u{2f843}', &['\u{5553}']),
+    ('\u{2f844}', &['\u{5563}']),
+    ('\u{2f845}', &['\u{5584}']),
+    ('\u{2f846}', &['\u{5584}']),
+    ('\u{2f847}', &['\u{5599}']),
+    ('\u{2f848}', &['\u{55ab}']),
+    ('\u{2f849}', &['\u{55b3}']),
+    ('\u{2f84a}', &['\u{55c2}']),
+    ('\u{2f84b}', &['\u{5716}']),
+    ('\u{2f84c}', &['\u{5606}']),
+    ('\u{2f84d}', &['\u{5717}']),
+    ('\u{2f84e}', &['\u{5651}']),
+    ('\u{2f84f}', &['\u{5674}']),
+    ('\u{2f850}', &['\u{5207}']),
+    ('\u{2f851}', &['\u{58ee}']),
+    ('\u{2f852}', &['\u{57ce}']),
+    ('\u{2f853}', &['\u{57f4}']),
+    ('\u{2f854}', &['\u{580d}']),
+    ('\u{2f855}', &['\u{578b}']),
+    ('\u{2f856}', &['\u{5832}']),
+    ('\u{2f857}', &['\u{5831}']),
+    ('\u{2f858}', &['\u{58ac}']),
+    ('\u{2f859}', &['\u{214e4}']),
+    ('\u{2f85a}', &['\u{58f2}']),
+    ('\u{2f85b}', &['\u{58f7}']),
+    ('\u{2f85c}', &['\u{5906}']),
+    ('\u{2f85d}', &['\u{591a}']),
+    ('\u{2f85e}', &['\u{5922}']),
+    ('\u{2f85f}', &['\u{5962}']),
+    ('\u{2f860}', &['\u{216a8}']),
+    ('\u{2f861}', &['\u{216ea}']),
+    ('\u{2f862}', &['\u{59ec}']),
+    ('\u{2f863}', &['\u{5a1b}']),
+    ('\u{2f864}', &['\u{5a27}']),
+    ('\u{2f865}', &['\u{59d8}']),
+    ('\u{2f866}', &['\u{5a66}']),
+    ('\u{2f867}', &['\u{36ee}']),
+    ('\u{2f868}', &['\u{36fc}']),
+    ('\u{2f869}', &['\u{5b08}']),
+    ('\u{2f86a}', &['\u{5b3e}']),
+    ('\u{2f86b}', &['\u{5b3e}']),
+    ('\u{2f86c}', &['\u{219c8}']),
+    ('\u{2f86d}', &['\u{5bc3}']),
+    ('\u{2f86e}', &['\u{5bd8}']),
+    ('\u{2f86f}', &['\u{5be7}']),
+    ('\u{2f870}', &['\u{5bf3}']),
+    ('\u{2f871}', &['\u{21b18}']),
+    ('\u{2f872}', &['\u{5bff}']),
+    ('\u{2f873}', &['\u{5c06}']),
+    ('\u{2f874}', &['\u{5f53}']),
+    ('\u{2f875}', &['\u{5c22}']),
+    ('\u{2f876}', &['\u{3781}']),
+    ('\u{2f877}', &['\u{5c60}']),
+    ('\u{2f878}', &['\u{5c6e}']),
+    ('\u{2f879}', &['\u{5cc0}']),
+    ('\u{2f87a}', &['\u{5c8d}']),
+    ('\u{2f87b}', &['\u{21de4}']),
+    ('\u{2f87c}', &['\u{5d43}']),
+    ('\u{2f87d}', &['\u{21de6}']),
+    ('\u{2f87e}', &['\u{5d6e}']),
+    ('\u{2f87f}', &['\u{5d6b}']),
+    ('\u{2f880}', &['\u{5d7c}']),
+    ('\u{2f881}', &['\u{5de1}']),
+    ('\u{2f882}', &['\u{5de2}']),
+    ('\u{2f883}', &['\u{382f}']),
+    ('\u{2f884}', &['\u{5dfd}']),
+    ('\u{2f885}', &['\u{5e28}']),
+    ('\u{2f886}', &['\u{5e3d}']),
+    ('\u{2f887}', &['\u{5e69}']),
+    ('\u{2f888}', &['\u{3862}']),
+    ('\u{2f889}', &['\u{22183}']),
+    ('\u{2f88a}', &['\u{387c}']),
+    ('\u{2f88b}', &['\u{5eb0}']),
+    ('\u{2f88c}', &['\u{5eb3}']),
+    ('\u{2f88d}', &['\u{5eb6}']),
+    ('\u{2f88e}', &['\u{5eca}']),
+    ('\u{2f88f}', &['\u{2a392}']),
+    ('\u{2f890}', &['\u{5efe}']),
+    ('\u{2f891}', &['\u{22331}']),
+    ('\u{2f892}', &['\u{22331}']),
+    ('\u{2f893}', &['\u{8201}']),
+    ('\u{2f894}', &['\u{5f22}']),
+    ('\u{2f895}', &['\u{5f22}']),
+    ('\u{2f896}', &['\u{38c7}']),
+    ('\u{2f897}', &['\u{232b8}']),
+    ('\u{2f898}', &['\u{261da}']),
+    ('\u{2f899}', &['\u{5f62}']),
+    ('\u{2f89a}', &['\u{5f6b}']),
+    ('\u{2f89b}', &['\u{38e3}']),
+    ('\u{2f89c}', &['\u{5f9a}']),
+    ('\u{2f89d}', &['\u{5fcd}']),
+    ('\u{2f89e}', &['\u{5fd7}']),
+    ('\u{2f89f}', &['\u{5ff9}']),
+    ('\u{2f8a0}', &['\u{6081}']),
+    ('\u{2f8a1}', &['\u{393a}']),
+    ('\u{2f8a2}', &['\u{391c}']),
+    ('\u{2f8a3}', &['\u{6094}']),
+    ('\u{2f8a4}', &['\u{226d4}']),
+    ('\u{2f8a5}', &['\u{60c7}']),
+    ('\u{2f8a6}', &['\u{6148}']),
+    ('\u{2f8a7}', &['\u{614c}']),
+    ('\u{2f8a8}', &['\u{614e}']),
+    ('\u{2f8a9}', &['\u{614c}']),
+    ('\u{2f8aa}', &['\u{617a}']),
+    ('\u{2f8ab}', &['\u{618e}']),
+    ('\u{2f8ac}', &['\u{61b2}']),
+    ('\u{2f8ad}', &['\u{61a4}']),
+    ('\u{2f8ae}', &['\u{61af}']),
+    ('\u{2f8af}', &['\u{61de}']),
+    ('\u{2f8b0}', &['\u{61f2}']),
+    ('\u{2f8b1}', &['\u{61f6}']),
+    ('\u{2f8b2}', &['\u{6210}']),
+    ('\u{2f8b3}', &['\u{621b}']),
+    ('\u{2f8b4}', &['\u{625d}']),
+    ('\u{2f8b5}', &['\u{62b1}']),
+    ('\u{2f8b6}', &['\u{62d4}']),
+    ('\u{2f8b7}', &['\u{6350}']),
+    ('\u{2f8b8}', &['\u{22b0c}']),
+    ('\u{2f8b9}', &['\u{633d}']),
+    ('\u{2f8ba}', &['\u{62fc}']),
+    ('\u{2f8bb}', &['\u{6368}']),
+    ('\u{2f8bc}', &['\u{6383}']),
+    ('\u{2f8bd}', &['\u{63e4}']),
+    ('\u{2f8be}', &['\u{22bf1}']),
+    ('\u{2f8bf}', &['\u{6422}']),
+    ('\u{2f8c0}', &['\u{63c5}']),
+    ('\u{2f8c1}', &['\u{63a9}']),
+    ('\u{2f8c2}', &['\u{3a2e}']),
+    ('\u{2f8c3}', &['\u{6469}']),
+    ('\u{2f8c4}', &['\u{647e}']),
+    ('\u{2f8c5}', &['\u{649d}']),
+    ('\u{2f8c6}', &['\u{6477}']),
+    ('\u{2f8c7}', &['\u{3a6c}']),
+    ('\u{2f8c8}', &['\u{654f}']),
+    ('\u{2f8c9}', &['\u{656c}']),
+    ('\u{2f8ca}', &['\u{2300a}']),
+    ('\u{2f8cb}', &['\u{65e3}']),
+    ('\u{2f8cc}', &['\u{66f8}']),
+    ('\u{2f8cd}', &['\u{6649}']),
+    ('\u{2f8ce}', &['\u{3b19}']),
+    ('\u{2f8cf}', &['\u{6691}']),
+    ('\u{2f8d0}', &['\u{3b08}']),
+    ('\u{2f8d1}', &['\u{3ae4}']),
+    ('\u{2f8d2}', &['\u{5192}']),
+    ('\u{2f8d3}', &['\u{5195}']),
+    ('\u{2f8d4}', &['\u{6700}']),
+    ('\u{2f8d5}', &['\u{669c}']),
+    ('\u{2f8d6}', &['\u{80ad}']),
+    ('\u{2f8d7}', &['\u{43d9}']),
+    ('\u{2f8d8}', &['\u{6717}']),
+    ('\u{2f8d9}', &['\u{671b}']),
+    ('\u{2f8da}', &['\u{6721}']),
+    ('\u{2f8db}', &['\u{675e}']),
+    ('\u{2f8dc}', &['\u{6753}']),
+    ('\u{2f8dd}', &['\u{233c3}']),
+    ('\u{2f8de}', &['\u{3b49}']),
+    ('\u{2f8df}', &['\u{67fa}']),
+    ('\u{2f8e0}', &['\u{6785}']),
+    ('\u{2f8e1}', &['\u{6852}']),
+    ('\u{2f8e2}', &['\u{6885}']),
+    ('\u{2f8e3}', &['\u{2346d}']),
+    ('\u{2f8e4}', &['\u{688e}']),
+    ('\u{2f8e5}', &['\u{681f}']),
+    ('\u{2f8e6}', &['\u{6914}']),
+    ('\u{2f8e7}', &['\u{3b9d}']),
+    ('\u{2f8e8}', &['\u{6942}']),
+    ('\u{2f8e9}', &['\u{69a3}']),
+    ('\u{2f8ea}', &['\u{69ea}']),
+    ('\u{2f8eb}', &['\u{6aa8}']),
+    ('\u{2f8ec}', &['\u{236a3}']),
+    ('\u{2f8ed}', &['\u{6adb}']),
+    ('\u{2f8ee}', &['\u{3c18}']),
+    ('\u{2f8ef}', &['\u{6b21}']),
+    ('\u{2f8f0}', &['\u{238a7}']),
+    ('\u{2f8f1}', &['\u{6b54}']),
+    ('\u{2f8f2}', &['\u{3c4e}']),
+    ('\u{2f8f3}', &['\u{6b72}']),
+    ('\u{2f8f4}', &['\u{6b9f}']),
+    ('\u{2f8f5}', &['\u{6bba}']),
+    ('\u{2f8f6}', &['\u{6bbb}']),
+    ('\u{2f8f7}', &['\u{23a8d}']),
+    ('\u{2f8f8}', &['\u{21d0b}']),
+    ('\u{2f8f9}', &['\u{23afa}']),
+    ('\u{2f8fa}', &['\u{6c4e}']),
+    ('\u{2f8fb}', &['\u{23cbc}']),
+    ('\u{2f8fc}', &['\u{6cbf}']),
+    ('\u{2f8fd}', &['\u{6ccd}']),
+    ('\u{2f8fe}', &['\u{6c67}']),
+    ('\u{2f8ff}', &['\u{6d16}']),
+    ('\u{2f900}', &['\u{6d3e}']),
+    ('\u{2f901}', &['\u{6d77}']),
+    ('\u{2f902}', &['\u{6d41}']),
+    ('\u{2f903}', &['\u{6d69}']),
+    ('\u{2f904}', &['\u{6d78}']),
+    ('\u{2f905}', &['\u{6d85}']),
+    ('\u{2f906}', &['\u{23d1e}']),
+    ('\u{2f907}', &['\u{6d34}']),
+    ('\u{2f908}', &['\u{6e2f}']),
+    ('\u{2f909}', &['\u{6e6e}']),
+    ('\u{2f90a}', &['\u{3d33}']),
+    ('\u{2f90b}', &['\u{6ecb}']),
+    ('\u{2f90c}', &['\u{6ec7}']),
+    ('\u{2f90d}', &['\u{23ed1}']),
+    ('\u{2f90e}', &['\u{6df9}']),
+    ('\u{2f90f}', &['\u{6f6e}']),
+    ('\u{2f910}', &['\u{23f5e}']),
+    ('\u{2f911}', &['\u{23f8e}']),
+    ('\u{2f912}', &['\u{6fc6}']),
+    ('\u{2f913}', &['\u{7039}']),
+    ('\u{2f914}', &['\u{701e}']),
+    ('\u{2f915}', &['\u{701b}']),
+    ('\u{2f916}', &['\u{3d96}']),
+    ('\u{2f917}', &['\u{704a}']),
+    ('\u{2f918}', &['\u{707d}']),
+    ('\u{2f919}', &['\u{7077}']),
+    ('\u{2f91a}', &['\u{70ad}']),
+    ('\u{2f91b}', &['\u{20525}']),
+    ('\u{2f91c}', &['\u{7145}']),
+    ('\u{2f91d}', &['\u{24263}']),
+    ('\u{2f91e}', &['\u{719c}']),
+    ('\u{2f91f}', &['\u{243ab}']),
+    ('\u{2f920}', &['\u{7228}']),
+    ('\u{2f921}', &['\u{7235}']),
+    ('\u{2f922}', &['\u{7250}']),
+    ('\u{2f923}', &['\u{24608}']),
+    ('\u{2f924}', &['\u{7280}']),
+    ('\u{2f925}', &['\u{7295}']),
+    ('\u{2f926}', &['\u{24735}']),
+    ('\u{2f927}', &['\u{24814}']),
+    ('\u{2f928}', &['\u{737a}']),
+    ('\u{2f929}', &['\u{738b}']),
+    ('\u{2f92a}', &['\u{3eac}']),
+    ('\u{2f92b}', &['\u{73a5}']),
+    ('\u{2f92c}', &['\u{3eb8}']),
+    ('\u{2f92d}', &['\u{3eb8}']),
+    ('\u{2f92e}', &['\u{7447}']),
+    ('\u{2f92f}', &['\u{745c}']),
+    ('\u{2f930}', &['\u{7471}']),
+    ('\u{2f931}', &['\u{7485}']),
+    ('\u{2f932}', &['\u{74ca}']),
+    ('\u{2f933}', &['\u{3f1b}']),
+    ('\u{2f934}', &['\u{7524}']),
+    ('\u{2f935}', &['\u{24c36}']),
+    ('\u{2f936}', &['\u{753e}']),
+    ('\u{2f937}', &['\u{24c92}']),
+    ('\u{2f938}', &['\u{7570}']),
+    ('\u{2f939}', &['\u{2219f}']),
+    ('\u{2f93a}', &['\u{7610}']),
+    ('\u{2f93b}', &['\u{24fa1}']),
+    ('\u{2f93c}', &['\u{24fb8}']),
+    ('\u{2f93d}', &['\u{25044}']),
+    ('\u{2f93e}', &['\u{3ffc}']),
+    ('\u{2f93f}', &['\u{4008}']),
+    ('\u{2f940}', &['\u{76f4}']),
+    ('\u{2f941}', &['\u{250f3}']),
+    ('\u{2f942}', &['\u{250f2}']),
+    ('\u{2f943}', &['\u{25119}']),
+    ('\u{2f944}', &['\u{25133}']),
+    ('\u{2f945}', &['\u{771e}']),
+    ('\u{2f946}', &['\u{771f}']),
+    ('\u{2f947}', &['\u{771f}']),
+    ('\u{2f948}', &['\u{774a}']),
+    ('\u{2f949}', &['\u{4039}']),
+    ('\u{2f94a}', &['\u{778b}']),
+    ('\u{2f94b}', &['\u{4046}']),
+    ('\u{2f94c}', &['\u{4096}']),
+    ('\u{2f94d}', &['\u{2541d}']),
+    ('\u{2f94e}', &['\u{784e}']),
+    ('\u{2f94f}', &['\u{788c}']),
+    ('\u{2f950}', &['\u{78cc}']),
+    ('\u{2f951}', &['\u{40e3}']),
+    ('\u{2f952}', &['\u{25626}']),
+    ('\u{2f953}', &['\u{7956}']),
+    ('\u{2f954}', &['\u{2569a}']),
+    ('\u{2f955}', &['\u{256c5}']),
+    ('\u{2f956}', &['\u{798f}']),
+    ('\u{2f957}', &['\u{79eb}']),
+    ('\u{2f958}', &['\u{412f}']),
+    ('\u{2f959}', &['\u{7a40}']),
+    ('\u{2f95a}', &['\u{7a4a}']),
+    ('\u{2f95b}', &['\u{7a4f}']),
+    ('\u{2f95c}', &['\u{2597c}']),
+    ('\u{2f95d}', &['\u{25aa7}']),
+    ('\u{2f95e}', &['\u{25aa7}']),
+    ('\u{2f95f}', &['\u{7aee}']),
+    ('\u{2f960}', &['\u{4202}']),
+    ('\u{2f961}', &['\u{25bab}']),
+    ('\u{2f962}', &['\u{7bc6}']),
+    ('\u{2f963}', &['\u{7bc9}']),
+    ('\u{2f964}', &['\u{4227}']),
+    ('\u{2f965}', &['\u{25c80}']),
+    ('\u{2f966}', &['\u{7cd2}']),
+    ('\u{2f967}', &['\u{42a0}']),
+    ('\u{2f968}', &['\u{7ce8}']),
+    ('\u{2f969}', &['\u{7ce3}']),
+    ('\u{2f96a}', &['\u{7d00}']),
+    ('\u{2f96b}', &['\u{25f86}']),
+    ('\u{2f96c}', &['\u{7d63}']),
+    ('\u{2f96d}', &['\u{4301}']),
+    ('\u{2f96e}', &['\u{7dc7}']),
+    ('\u{2f96f}', &['\u{7e02}']),
+    ('\u{2f970}', &['\u{7e45}']),
+    ('\u{2f971}', &['\u{4334}']),
+    ('\u{2f972}', &['\u{26228}']),
+    ('\u{2f973}', &['\u{26247}']),
+    ('\u{2f974}', &['\u{4359}']),
+    ('\u{2f975}', &['\u{262d9}']),
+    ('\u{2f976}', &['\u{7f7a}']),
+    ('\u{2f977}', &['\u{2633e}']),
+    ('\u{2f978}', &['\u{7f95}']),
+    ('\u{2f979}', &['\u{7ffa}']),
+    ('\u{2f97a}', &['\u{8005}']),
+    ('\u{2f97b}', &['\u{264da}']),
+    ('\u{2f97c}', &['\u{26523}']),
+    ('\u{2f97d}', &['\u{8060}']),
+    ('\u{2f97e}', &['\u{265a8}']),
+    ('\u{2f97f}', &['\u{8070}']),
+    ('\u{2f980}', &['\u{2335f}']),
+    ('\u{2f981}', &['\u{43d5}']),
+    ('\u{2f982}', &['\u{80b2}']),
+    ('\u{2f983}', &['\u{8103}']),
+    ('\u{2f984}', &['\u{440b}']),
+    ('\u{2f985}', &['\u{813e}']),
+    ('\u{2f986}', &['\u{5ab5}']),
+    ('\u{2f987}', &['\u{267a7}']),
+    ('\u{2f988}', &['\u{267b5}']),
+    ('\u{2f989}', &['\u{23393}']),
+    ('\u{2f98a}', &['\u{2339c}']),
+    ('\u{2f98b}', &['\u{8201}']),
+    ('\u{2f98c}', &['\u{8204}']),
+    ('\u{2f98d}', &['\u{8f9e}']),
+    ('\u{2f98e}', &['\u{446b}']),
+    ('\u{2f98f}', &['\u{8291}']),
+    ('\u{2f990}', &['\u{828b}']),
+    ('\u{2f991}', &['\u{829d}']),
+    ('\u{2f992}', &['\u{52b3}']),
+    ('\u{2f993}', &['\u{82b1}']),
+    ('\u{2f994}', &['\u{82b3}']),
+    ('\u{2f995}', &['\u{82bd}']),
+    ('\u{2f996}', &['\u{82e6}']),
+    ('\u{2f997}', &['\u{26b3c}']),
+    ('\u{2f998}', &['\u{82e5}']),
+    ('\u{2f999}', &['\u{831d}']),
+    ('\u{2f99a}', &['\u{8363}']),
+    ('\u{2f99b}', &['\u{83ad}']),
+    ('\u{2f99c}', &['\u{8323}']),
+    ('\u{2f99d}', &['\u{83bd}']),
+    ('\u{2f99e}', &['\u{83e7}']),
+    ('\u{2f99f}', &['\u{8457}']),
+    ('\u{2f9a0}', &['\u{8353}']),
+    ('\u{2f9a1}', &['\u{83ca}']),
+    ('\u{2f9a2}', &['\u{83cc}']),
+    ('\u{2f9a3}', &['\u{83dc}']),
+    ('\u{2f9a4}', &['\u{26c36}']),
+    ('\u{2f9a5}', &['\u{26d6b}']),
+    ('\u{2f9a6}', &['\u{26cd5}']),
+    ('\u{2f9a7}', &['\u{452b}']),
+    ('\u{2f9a8}', &['\u{84f1}']),
+    ('\u{2f9a9}', &['\u{84f3}']),
+    ('\u{2f9aa}', &['\u{8516}']),
+    ('\u{2f9ab}', &['\u{273ca}']),
+    ('\u{2f9ac}', &['\u{8564}']),
+    ('\u{2f9ad}', &['\u{26f2c}']),
+    ('\u{2f9ae}', &['\u{455d}']),
+    ('\u{2f9af}', &['\u{4561}']),
+    ('\u{2f9b0}', &['\u{26fb1}']),
+    ('\u{2f9b1}', &['\u{270d2}']),
+    ('\u{2f9b2}', &['\u{456b}']),
+    ('\u{2f9b3}', &['\u{8650}']),
+    ('\u{2f9b4}', &['\u{865c}']),
+    ('\u{2f9b5}', &['\u{8667}']),
+    ('\u{2f9b6}', &['\u{8669}']),
+    ('\u{2f9b7}', &['\u{86a9}']),
+    ('\u{2f9b8}', &['\u{8688}']),
+    ('\u{2f9b9}', &['\u{870e}']),
+    ('\u{2f9ba}', &['\u{86e2}']),
+    ('\u{2f9bb}', &['\u{8779}']),
+    ('\u{2f9bc}', &['\u{8728}']),
+    ('\u{2f9bd}', &['\u{876b}']),
+    ('\u{2f9be}', &['\u{8786}']),
+    ('\u{2f9bf}', &['\u{45d7}']),
+    ('\u{2f9c0}', &['\u{87e1}']),
+    ('\u{2f9c1}', &['\u{8801}']),
+    ('\u{2f9c2}', &['\u{45f9}']),
+    ('\u{2f9c3}', &['\u{8860}']),
+    ('\u{2f9c4}', &['\u{8863}']),
+    ('\u{2f9c5}', &['\u{27667}']),
+    ('\u{2f9c6}', &['\u{88d7}']),
+    ('\u{2f9c7}', &['\u{88de}']),
+    ('\u{2f9c8}', &['\u{4635}']),
+    ('\u{2f9c9}', &['\u{88fa}']),
+    ('\u{2f9ca}', &['\u{34bb}']),
+    ('\u{2f9cb}', &['\u{278ae}']),
+    ('\u{2f9cc}', &['\u{27966}']),
+    ('\u{2f9cd}', &['\u{46be}']),
+    ('\u{2f9ce}', &['\u{46c7}']),
+    ('\u{2f9cf}', &['\u{8aa0}']),
+    ('\u{2f9d0}', &['\u{8aed}']),
+    ('\u{2f9d1}', &['\u{8b8a}']),
+    ('\u{2f9d2}', &['\u{8c55}']),
+    ('\u{2f9d3}', &['\u{27ca8}']),
+    ('\u{2f9d4}', &['\u{8cab}']),
+    ('\u{2f9d5}', &['\u{8cc1}']),
+    ('\u{2f9d6}', &['\u{8d1b}']),
+    ('\u{2f9d7}', &['\u{8d77}']),
+    ('\u{2f9d8}', &['\u{27f2f}']),
+    ('\u{2f9d9}', &['\u{20804}']),
+    ('\u{2f9da}', &['\u{8dcb}']),
+    ('\u{2f9db}', &['\u{8dbc}']),
+    ('\u{2f9dc}', &['\u{8df0}']),
+    ('\u{2f9dd}', &['\u{208de}']),
+    ('\u{2f9de}', &['\u{8ed4}']),
+    ('\u{2f9df}', &['\u{8f38}']),
+    ('\u{2f9e0}', &['\u{285d2}']),
+    ('\u{2f9e1}', &['\u{285ed}']),
+    ('\u{2f9e2}', &['\u{9094}']),
+    ('\u{2f9e3}', &['\u{90f1}']),
+    ('\u{2f9e4}', &['\u{9111}']),
+    ('\u{2f9e5}', &['\u{2872e}']),
+    ('\u{2f9e6}', &['\u{911b}']),
+    ('\u{2f9e7}', &['\u{9238}']),
+    ('\u{2f9e8}', &['\u{92d7}']),
+    ('\u{2f9e9}', &['\u{92d8}']),
+    ('\u{2f9ea}', &['\u{927c}']),
+    ('\u{2f9eb}', &['\u{93f9}']),
+    ('\u{2f9ec}', &['\u{9415}']),
+    ('\u{2f9ed}', &['\u{28bfa}']),
+    ('\u{2f9ee}', &['\u{958b}']),
+    ('\u{2f9ef}', &['\u{4995}']),
+    ('\u{2f9f0}', &['\u{95b7}']),
+    ('\u{2f9f1}', &['\u{28d77}']),
+    ('\u{2f9f2}', &['\u{49e6}']),
+    ('\u{2f9f3}', &['\u{96c3}']),
+    ('\u{2f9f4}', &['\u{5db2}']),
+    ('\u{2f9f5}', &['\u{9723}']),
+    ('\u{2f9f6}', &['\u{29145}']),
+    ('\u{2f9f7}', &['\u{2921a}']),
+    ('\u{2f9f8}', &['\u{4a6e}']),
+    ('\u{2f9f9}', &['\u{4a76}']),
+    ('\u{2f9fa}', &['\u{97e0}']),
+    ('\u{2f9fb}', &['\u{2940a}']),
+    ('\u{2f9fc}', &['\u{4ab2}']),
+    ('\u{2f9fd}', &['\u{29496}']),
+    ('\u{2f9fe}', &['\u{980b}']),
+    ('\u{2f9ff}', &['\u{980b}']),
+    ('\u{2fa00}', &['\u{9829}']),
+    ('\u{2fa01}', &['\u{295b6}']),
+    ('\u{2fa02}', &['\u{98e2}']),
+    ('\u{2fa03}', &['\u{4b33}']),
+    ('\u{2fa04}', &['\u{9929}']),
+    ('\u{2fa05}', &['\u{99a7}']),
+    ('\u{2fa06}', &['\u{99c2}']),
+    ('\u{2fa07}', &['\u{99fe}']),
+    ('\u{2fa08}', &['\u{4bce}']),
+    ('\u{2fa09}', &['\u{29b30}']),
+    ('\u{2fa0a}', &['\u{9b12}']),
+    ('\u{2fa0b}', &['\u{9c40}']),
+    ('\u{2fa0c}', &['\u{9cfd}']),
+    ('\u{2fa0d}', &['\u{4cce}']),
+    ('\u{2fa0e}', &['\u{4ced}']),
+    ('\u{2fa0f}', &['\u{9d67}']),
+    ('\u{2fa10}', &['\u{2a0ce}']),
+    ('\u{2fa11}', &['\u{4cf8}']),
+    ('\u{2fa12}', &['\u{2a105}']),
+    ('\u{2fa13}', &['\u{2a20e}']),
+    ('\u{2fa14}', &['\u{2a291}']),
+    ('\u{2fa15}', &['\u{9ebb}']),
+    ('\u{2fa16}', &['\u{4d56}']),
+    ('\u{2fa17}', &['\u{9ef9}']),
+    ('\u{2fa18}', &['\u{9efe}']),
+    ('\u{2fa19}', &['\u{9f05}']),
+    ('\u{2fa1a}', &['\u{9f0f}']),
+    ('\u{2fa1b}', &['\u{9f16}']),
+    ('\u{2fa1c}', &['\u{9f3b}']),
+    ('\u{2fa1d}', &['\u{2a600}']),
+];
+
+pub const COMPOSING_STARTER_PAIRS: &'static [(char, char)] = &[
+    ('\u{9c7}', '\u{9be}'),
+    ('\u{9c7}', '\u{9d7}'),
+    ('\u{b47}', '\u{b3e}'),
+    ('\u{b47}', '\u{b56}'),
+    ('\u{b47}', '\u{b57}'),
+    ('\u{b92}', '\u{bd7}'),
+    ('\u{bc6}', '\u{bbe}'),
+    ('\u{bc6}', '\u{bd7}'),
+    ('\u{bc7}', '\u{bbe}'),
+    ('\u{cbf}', '\u{cd5}'),
+    ('\u{cc6}', '\u{cc2}'),
+    ('\u{cc6}', '\u{cd5}'),
+    ('\u{cc6}', '\u{cd6}'),
+    ('\u{cca}', '\u{cd5}'),
+    ('\u{d46}', '\u{d3e}'),
+    ('\u{d46}', '\u{d57}'),
+    ('\u{d47}', '\u{d3e}'),
+    ('\u{dd9}', '\u{dcf}'),
+    ('\u{dd9}', '\u{ddf}'),
+    ('\u{1025}', '\u{102e}'),
+    ('\u{1b05}', '\u{1b35}'),
+    ('\u{1b07}', '\u{1b35}'),
+    ('\u{1b09}', '\u{1b35}'),
+    ('\u{1b0b}', '\u{1b35}'),
+    ('\u{1b0d}', '\u{1b35}'),
+    ('\u{1b11}', '\u{1b35}'),
+    ('\u{1b3a}', '\u{1b35}'),
+    ('\u{1b3c}', '\u{1b35}'),
+    ('\u{1b3e}', '\u{1b35}'),
+    ('\u{1b3f}', '\u{1b35}'),
+    ('\u{1b42}', '\u{1b35}'),
+    ('\u{11131}', '\u{11127}'),
+    ('\u{11132}', '\u{11127}'),
+    ('\u{11347}', '\u{1133e}'),
+    ('\u{11347}', '\u{11357}'),
+    ('\u{114b9}', '\u{114b0}'),
+    ('\u{114b9}', '\u{114ba}'),
+    ('\u{114b9}', '\u{114bd}'),
+    ('\u{115b8}', '\u{115af}'),
+    ('\u{115b9}', '\u{115af}'),
+    ('\u{11935}', '\u{11930}'),
+];
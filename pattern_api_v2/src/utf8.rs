@@ -1,5 +1,7 @@
+use core::ops::{Range, RangeInclusive};
+
 pub trait CharEq {
-    fn matches(&mut self, char) -> bool;
+    fn matches(&mut self, c: char) -> bool;
     fn only_ascii(&self) -> bool;
 }
 
@@ -31,6 +33,34 @@ impl<'a> CharEq for &'a [char] {
     }
 }
 
+impl CharEq for RangeInclusive<char> {
+    #[inline]
+    fn matches(&mut self, c: char) -> bool { self.contains(&c) }
+
+    #[inline]
+    fn only_ascii(&self) -> bool { self.is_empty() || (*self.end() as u32) < 128 }
+}
+
+impl CharEq for Range<char> {
+    #[inline]
+    fn matches(&mut self, c: char) -> bool { self.contains(&c) }
+
+    #[inline]
+    fn only_ascii(&self) -> bool { self.is_empty() || (self.end as u32) <= 128 }
+}
+
+impl<'a> CharEq for &'a [RangeInclusive<char>] {
+    #[inline]
+    fn matches(&mut self, c: char) -> bool {
+        self.iter().any(|r| r.contains(&c))
+    }
+
+    #[inline]
+    fn only_ascii(&self) -> bool {
+        self.iter().all(|r| r.only_ascii())
+    }
+}
+
 pub struct CharEqPattern<C: CharEq>(pub C);
 
 /// Mask of the value bits of a continuation byte
@@ -55,12 +85,96 @@ fn utf8_is_cont_byte(byte: u8) -> bool { (byte & !CONT_MASK) == TAG_CONT_U8 }
 
 #[inline]
 fn unwrap_or_0(opt: Option<u8>) -> u8 {
-    match opt {
-        Some(byte) => byte,
-        None => 0,
+    opt.unwrap_or(0)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Lenient decoding of possibly ill-formed UTF-8
+///////////////////////////////////////////////////////////////////////////////
+
+#[inline]
+pub fn utf8_width(b: u8) -> usize {
+    if b < 0x80 {
+        1
+    } else if b < 0xC2 {
+        0
+    } else if b < 0xE0 {
+        2
+    } else if b < 0xF0 {
+        3
+    } else if b < 0xF5 {
+        4
+    } else {
+        0
     }
 }
 
+/// Whether `bytes` start with a continuation byte, or end with a lead
+/// byte that is only followed by a part of its continuation bytes.
+pub fn cuts_sequence(bytes: &[u8]) -> bool {
+    let is_continuation = |b: u8| b & 0xC0 == 0x80;
+    match bytes.first() {
+        Some(&b) if is_continuation(b) => return true,
+        _ => (),
+    }
+    let tail = &bytes[bytes.len().saturating_sub(3)..];
+    match tail.iter().rposition(|&b| !is_continuation(b)) {
+        Some(lead) => utf8_width(tail[lead]) > tail.len() - lead,
+        None => false,
+    }
+}
+
+/// Decodes the char starting at `pos`, if there is a valid one.
+pub fn char_at(bytes: &[u8], pos: usize) -> Option<(char, usize)> {
+    let width = utf8_width(bytes[pos]);
+    if width == 0 || pos + width > bytes.len() {
+        return None;
+    }
+    match ::core::str::from_utf8(&bytes[pos..pos + width]) {
+        Ok(s) => s.chars().next().map(|c| (c, width)),
+        Err(_) => None,
+    }
+}
+
+/// Decodes the char ending at `pos`, if there is a valid one.
+pub fn char_before(bytes: &[u8], pos: usize) -> Option<char> {
+    for width in 1..5 {
+        if width > pos {
+            break;
+        }
+        match char_at(bytes, pos - width) {
+            Some((c, w)) if w == width => return Some(c),
+            _ => {}
+        }
+    }
+    None
+}
+
+pub fn is_inside_char(bytes: &[u8], pos: usize) -> bool {
+    (1..4).any(|back| {
+        back <= pos && match char_at(bytes, pos - back) {
+            Some((_, w)) => w > back,
+            None => false,
+        }
+    })
+}
+
+/// The end of the run of bytes at `pos` that are not part of a valid char.
+pub fn invalid_run_end(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() && char_at(bytes, pos).is_none() {
+        pos += 1;
+    }
+    pos
+}
+
+/// The start of the run of bytes before `pos` that are not part of a valid char.
+pub fn invalid_run_start(bytes: &[u8], mut pos: usize) -> usize {
+    while pos > 0 && char_before(bytes, pos).is_none() {
+        pos -= 1;
+    }
+    pos
+}
+
 /// Reads the next code point out of a byte iterator (assuming a
 /// UTF-8-like encoding).
 pub fn next_code_point<F>(mut next_byte: F) -> Option<char>
@@ -93,7 +207,7 @@ pub fn next_code_point<F>(mut next_byte: F) -> Option<char>
         }
     }
 
-    Some(unsafe { ::std::mem::transmute(ch) })
+    Some(unsafe { ::core::char::from_u32_unchecked(ch) })
 }
 
 /// Reads the last code point out of a byte iterator (assuming a
@@ -125,19 +239,19 @@ pub fn next_code_point_reverse<F>(mut next_byte_back: F) -> Option<char>
     }
     ch = utf8_acc_cont_byte(ch, w);
 
-    Some(unsafe { ::std::mem::transmute(ch) })
+    Some(unsafe { ::core::char::from_u32_unchecked(ch) })
 }
 
 pub fn byte_is_char_boundary(b: u8) -> bool {
-    b < 128 || b >= 192
+    !(128..192).contains(&b)
 }
 
 #[inline]
 pub unsafe fn ptr_range_starts_with_valid_utf8(start: *const u8, end: *const u8) -> bool {
     let len = end as usize - start as usize;
-    let len = ::std::cmp::min(len, 4);
-    let s: &[u8] = ::std::slice::from_raw_parts(start, len);
-    match ::std::str::from_utf8(s) {
+    let len = ::core::cmp::min(len, 4);
+    let s: &[u8] = ::core::slice::from_raw_parts(start, len);
+    match ::core::str::from_utf8(s) {
         Ok(_) => true,
         Err(e) if e.valid_up_to() > 0 => true,
         _ => false,
@@ -260,3 +374,17 @@ fn test_ptr_range_ends_with_valid_utf8() {
     assert!(check(b"\xbe1234"));
 }
 
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn test_char_range_only_ascii() {
+    assert!(('a'..='z').only_ascii());
+    assert!(('\0'..='\u{7f}').only_ascii());
+    assert!(!('\0'..='\u{80}').only_ascii());
+    assert!(('\0'..'\u{80}').only_ascii());
+    assert!(!('a'..'\u{e9}').only_ascii());
+    assert!(('\u{e9}'..'\u{e9}').only_ascii());
+    assert!(('z'..='a').only_ascii());
+    assert!((&['a'..='z', '0'..='9'][..]).only_ascii());
+    assert!(!(&['a'..='z', '\u{e0}'..='\u{ff}'][..]).only_ascii());
+}
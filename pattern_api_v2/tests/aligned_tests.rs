@@ -0,0 +1,149 @@
+extern crate pattern_api_v2;
+
+mod common;
+
+use pattern_api_v2::{Pattern, PatternError};
+use pattern_api_v2::aligned::Aligned;
+use pattern_api_v2::sets::ByteSet;
+use pattern_api_v2::slice::Elem;
+use pattern_api_v2::std_integration::IteratorConstructors as IC;
+
+use common::Lcg;
+
+#[test]
+fn byte_records() {
+    // 4 byte records, where "AB" also occurs across record boundaries
+    let h: &[u8] = b"ABxxxxABABxxxABBxxAB";
+    let p = Aligned(&b"AB"[..], 4);
+    assert_eq!(IC::match_indices(h, p).map(|m| m.0).collect::<Vec<_>>(), [0, 8]);
+    assert_eq!(IC::rmatch_indices(h, p).map(|m| m.0).collect::<Vec<_>>(), [8, 0]);
+    assert_eq!(IC::match_indices(h, &b"AB"[..]).count(), 5);
+    assert_eq!(IC::find(h, Aligned(&b"AB"[..], 2)), Some(0));
+    assert_eq!(IC::rfind(h, Aligned(&b"AB"[..], 2)), Some(18));
+    assert_eq!(IC::find(h, Aligned(&b"xA"[..], 4)), Some(12));
+    assert_eq!(IC::find(h, Aligned(&b"xA"[..], 8)), None);
+
+    // an unaligned match hides an overlapping aligned one
+    let h: &[u8] = b"xAAAAB";
+    assert_eq!(IC::match_indices(h, Aligned(&b"AA"[..], 2)).collect::<Vec<_>>(),
+               [(2, &b"AA"[..])]);
+    assert_eq!(IC::rmatch_indices(h, Aligned(&b"AA"[..], 2)).collect::<Vec<_>>(),
+               [(2, &b"AA"[..])]);
+    assert_eq!(IC::rmatch_indices(&b"AAAAx"[..], Aligned(&b"AA"[..], 2)).collect::<Vec<_>>(),
+               [(2, &b"AA"[..]), (0, &b"AA"[..])]);
+
+    let set: ByteSet = b"\r\n".iter().cloned().collect();
+    assert_eq!(IC::match_indices(&b"a\r\n\nb\r\r"[..], Aligned(set, 3)).map(|m| m.0)
+                   .collect::<Vec<_>>(),
+               [3, 6]);
+    assert_eq!(IC::rfind(&b"\nab\n"[..], Aligned(Elem(b'\n'), 2)), Some(0));
+}
+
+#[test]
+fn split_stays_record_aligned() {
+    // records of two bytes, separated by the record `;;`
+    let h: &[u8] = b"a;;;b;;;;;c;";
+    let p = Aligned(&b";;"[..], 2);
+    assert_eq!(IC::split(h, p).collect::<Vec<_>>(), [&b"a;"[..], b"b;", b"", b"c;"]);
+    assert_eq!(IC::rsplit(h, p).collect::<Vec<_>>(), [&b"c;"[..], b"", b"b;", b"a;"]);
+    assert_eq!(IC::rejects(h, p).collect::<Vec<_>>(), [&b"a;"[..], b"b;", b"c;"]);
+    assert_eq!(IC::split(h, &b";;"[..]).collect::<Vec<_>>(), [&b"a"[..], b";b", b"", b";c;"]);
+    for piece in IC::split(h, p) {
+        assert!(piece.len().is_multiple_of(2));
+    }
+    assert!(p.is_prefix_of(&b";;a"[..]) && !p.is_prefix_of(&b"a;;"[..]));
+    assert!(p.is_suffix_of(&b"ab;;"[..]) && !p.is_suffix_of(&b"a;;"[..]));
+    assert_eq!(p.matches_at(h, 2), Some(4));
+    assert_eq!(p.matches_at(h, 1), None);
+    assert_eq!(p.strip_prefix_of(&b";;ab"[..]), Some(&b"ab"[..]));
+}
+
+#[test]
+fn pixel_rows() {
+    // 3 pixel wide rows, looking for red pixels in the first column
+    const RED: u32 = 0xFF00_00FF;
+    let image: &[u32] = &[0, RED, 0,
+                          RED, 0, 0,
+                          0, 0, RED,
+                          RED, RED, 0];
+    assert_eq!(IC::match_indices(image, Aligned(Elem(RED), 3)).map(|m| m.0).collect::<Vec<_>>(),
+               [3, 9]);
+    assert_eq!(IC::rmatch_indices(image, Aligned(|p: &u32| *p == RED, 3)).map(|m| m.0)
+                   .collect::<Vec<_>>(),
+               [9, 3]);
+    assert_eq!(IC::find(image, Aligned(&[RED, RED][..], 3)), Some(9));
+    assert_eq!(IC::find(image, Aligned(&[RED, 0][..], 3)), Some(3));
+    assert_eq!(IC::split(image, Aligned(Elem(RED), 3)).map(|r| r.len()).collect::<Vec<_>>(),
+               [3, 5, 2]);
+
+    let mut rows = image.to_vec();
+    for row in IC::split_mut(&mut rows[..], Aligned(&[RED, 0, 0][..], 3)) {
+        for p in row {
+            *p = 1;
+        }
+    }
+    assert_eq!(rows, [1, 1, 1, RED, 0, 0, 1, 1, 1, 1, 1, 1]);
+}
+
+#[test]
+fn empty_needles_and_strides() {
+    let h: &[u8] = b"abcde";
+    assert_eq!(IC::match_indices(h, Aligned(&b""[..], 2)).map(|m| m.0).collect::<Vec<_>>(),
+               [0, 2, 4]);
+    assert_eq!(IC::rmatch_indices(h, Aligned(&b""[..], 2)).map(|m| m.0).collect::<Vec<_>>(),
+               [4, 2, 0]);
+    assert_eq!(IC::split(h, Aligned(&b""[..], 2)).collect::<Vec<_>>(),
+               [&b""[..], b"ab", b"cd", b"e"]);
+    assert_eq!(IC::match_indices(h, Aligned(&b"c"[..], 1)).map(|m| m.0).collect::<Vec<_>>(),
+               [2]);
+
+    assert_eq!(Pattern::<&[u8]>::validate(&Aligned(&b"a"[..], 0)), Err(PatternError::ZeroStride));
+    assert!(IC::try_find(h, Aligned(&b"a"[..], 0)).is_err());
+    assert_eq!(PatternError::ZeroStride.to_string(), "the stride of an aligned pattern is zero");
+}
+
+#[test]
+fn agrees_with_scanning() {
+    let mut rng = Lcg(11);
+    for round in 0..500 {
+        let len = rng.next(30);
+        let h: Vec<u8> = (0..len).map(|_| b"ab"[rng.next(2)]).collect();
+        let n = 1 + rng.next(3);
+        let needle: Vec<u8> = (0..n).map(|_| b"ab"[rng.next(2)]).collect();
+        let stride = 1 + rng.next(4);
+        let p = Aligned(&needle[..], stride);
+
+        let mut expected = vec![];
+        let mut pos = 0;
+        while pos + n <= h.len() {
+            if pos.is_multiple_of(stride) && h[pos..].starts_with(&needle) {
+                expected.push(pos);
+                pos += n;
+            } else {
+                pos += 1;
+            }
+        }
+        let found: Vec<usize> = IC::match_indices(&h[..], p).map(|m| m.0).collect();
+        assert_eq!(found, expected, "round {}: {:?} in {:?} / {}", round,
+                   String::from_utf8_lossy(&needle), String::from_utf8_lossy(&h), stride);
+
+        let mut expected = vec![];
+        let mut end = h.len();
+        while end >= n {
+            let start = end - n;
+            if start.is_multiple_of(stride) && h[start..end] == needle[..] {
+                expected.push(start);
+                end = start;
+            } else {
+                end -= 1;
+            }
+        }
+        let found: Vec<usize> = IC::rmatch_indices(&h[..], p).map(|m| m.0).collect();
+        assert_eq!(found, expected, "round {}: {:?} in {:?} / {}", round,
+                   String::from_utf8_lossy(&needle), String::from_utf8_lossy(&h), stride);
+
+        // the rejects and matches cover the haystack without overlapping
+        let rejects: usize = IC::rejects(&h[..], p).map(|r| r.len()).sum();
+        assert_eq!(rejects + n * IC::matches(&h[..], p).count(), h.len());
+    }
+}
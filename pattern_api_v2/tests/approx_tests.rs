@@ -0,0 +1,211 @@
+extern crate pattern_api_v2;
+
+mod common;
+
+use pattern_api_v2::Pattern;
+use pattern_api_v2::approx::{self, Mismatches, Edits};
+use pattern_api_v2::std_integration::IteratorConstructors as IC;
+
+use std::cmp::min;
+
+use common::Lcg;
+
+// Anchored edit distance of `needle` to `text`
+fn edit_distance(needle: &[u8], text: &[u8]) -> usize {
+    let mut col: Vec<usize> = (0..needle.len() + 1).collect();
+    for (t, &c) in text.iter().enumerate() {
+        let mut diag = col[0];
+        col[0] = t + 1;
+        for i in 1..needle.len() + 1 {
+            let v = min(diag + (needle[i - 1] != c) as usize, min(col[i], col[i - 1]) + 1);
+            diag = col[i];
+            col[i] = v;
+        }
+    }
+    col[needle.len()]
+}
+
+// The leftmost non-overlapping matches, with quadratically many
+// distance computations
+fn reference_edits(h: &[u8], needle: &[u8], k: usize) -> Vec<(usize, usize, usize)> {
+    let best_end = |front: usize, end: usize| {
+        (front..end + 1).map(|s| edit_distance(needle, &h[s..end])).min().unwrap()
+    };
+    let mut out = vec![];
+    let mut front = 0;
+    let mut skip_empty = false;
+    loop {
+        let mut found = None;
+        for end in front..h.len() + 1 {
+            if skip_empty && end == front {
+                continue;
+            }
+            let d = best_end(front, end);
+            match found {
+                Some((_, best)) if d >= best => break,
+                Some(_) => found = Some((end, d)),
+                None if d <= k => found = Some((end, d)),
+                None => {}
+            }
+        }
+        let (end, d) = match found {
+            Some(f) => f,
+            None => return out,
+        };
+        let start = (front..end + 1).find(|&s| edit_distance(needle, &h[s..end]) == d).unwrap();
+        let end = (end..h.len() + 1).rev()
+            .find(|&e| edit_distance(needle, &h[start..e]) == d).unwrap();
+        out.push((start, end, d));
+        if start == end {
+            if end == h.len() {
+                return out;
+            }
+            skip_empty = true;
+            front = end;
+        } else {
+            skip_empty = false;
+            front = end;
+        }
+    }
+}
+
+fn edits(h: &[u8], needle: &[u8], k: usize) -> Vec<(usize, usize, usize)> {
+    approx::match_indices(h, Edits { needle, max_edits: k })
+        .map(|(i, m, d)| (i, i + m.len(), d))
+        .collect()
+}
+
+fn dna(rng: &mut Lcg, len: usize) -> Vec<u8> {
+    (0..len).map(|_| b"ACGT"[rng.next(4)]).collect()
+}
+
+fn mutate(rng: &mut Lcg, s: &[u8], edits: usize) -> Vec<u8> {
+    let mut s = s.to_vec();
+    for _ in 0..edits {
+        if s.is_empty() {
+            s.push(b'A');
+        }
+        let i = rng.next(s.len());
+        match rng.next(3) {
+            0 => s[i] = b"ACGT"[rng.next(4)],
+            1 => { s.remove(i); }
+            _ => s.insert(i, b"ACGT"[rng.next(4)]),
+        }
+    }
+    s
+}
+
+#[test]
+fn hamming() {
+    let h = "ACGTTTTTACGAACGT";
+    let p = Mismatches { needle: "ACGA", max_mismatches: 1 };
+    assert_eq!(approx::match_indices(h, p).collect::<Vec<_>>(),
+               [(0, "ACGT", 1), (8, "ACGA", 0), (12, "ACGT", 1)]);
+    assert_eq!(approx::rmatch_indices(h, p).collect::<Vec<_>>(),
+               [(12, "ACGT", 1), (8, "ACGA", 0), (0, "ACGT", 1)]);
+    assert_eq!(IC::split(h, p).collect::<Vec<_>>(), ["", "TTTT", "", ""]);
+    assert_eq!(IC::find(h, Mismatches { needle: "TTTA", max_mismatches: 1 }), Some(3));
+    assert_eq!(IC::find(h, Mismatches { needle: "TTTA", max_mismatches: 0 }), Some(5));
+    assert_eq!(IC::find(h, Mismatches { needle: "GGGG", max_mismatches: 1 }), None);
+
+    let seq: &[u32] = &[1, 2, 3, 4, 5, 1, 9, 3];
+    let p = Mismatches { needle: &[1, 2, 3][..], max_mismatches: 1 };
+    assert_eq!(IC::match_indices(seq, p).collect::<Vec<_>>(),
+               [(0, &[1, 2, 3][..]), (5, &[1, 9, 3][..])]);
+
+    // chars are compared, and offsets are in bytes
+    let h = "na\u{ef}ve nai\u{308}ve naive";
+    assert_eq!(approx::match_indices(h, Mismatches { needle: "naive", max_mismatches: 1 })
+                   .collect::<Vec<_>>(),
+               [(0, "na\u{ef}ve", 1), (15, "naive", 0)]);
+}
+
+#[test]
+fn levenshtein() {
+    let h = "the quick brwn fox jumsp over the lazy dgo";
+    let matches = |needle, k| {
+        approx::match_indices(h, Edits { needle, max_edits: k }).collect::<Vec<_>>()
+    };
+    assert_eq!(matches("brown", 1), [(10, "brwn", 1)]);
+    assert_eq!(matches("jumps", 1), [(19, "jums", 1)]);
+    // a transposition is two edits, so "jums" is closer
+    assert_eq!(matches("jumps", 2), [(19, "jums", 1)]);
+    assert_eq!(matches("quick", 0), [(4, "quick", 0)]);
+    assert_eq!(matches("dog", 1), [(39, "dg", 1)]);
+    assert_eq!(matches("zebra", 2), []);
+
+    let p = Edits { needle: "the", max_edits: 1 };
+    assert_eq!(approx::rmatch_indices(h, p).collect::<Vec<_>>(),
+               [(30, "the", 0), (0, "the", 0)]);
+    assert!(p.is_prefix_of(h) && !p.is_suffix_of(h));
+    assert!(Edits { needle: "dog", max_edits: 2 }.is_suffix_of(h));
+    assert_eq!(IC::split(h, Edits { needle: "brown", max_edits: 1 }).collect::<Vec<_>>().join("brown"),
+               "the quick brown fox jumsp over the lazy dgo");
+
+    // chars are compared, so an accent is a single substitution
+    assert_eq!(approx::match_indices("un caf\u{e9} noir", Edits { needle: "cafe", max_edits: 1 })
+                   .collect::<Vec<_>>(),
+               [(3, "caf\u{e9}", 1)]);
+}
+
+#[test]
+fn levenshtein_agrees_with_reference() {
+    let mut rng = Lcg(7);
+    for round in 0..200 {
+        let (needle_len, head_len, mutations) = (1 + rng.next(8), rng.next(12), rng.next(3));
+        let needle = dna(&mut rng, needle_len);
+        let mut h = dna(&mut rng, head_len);
+        let copy = mutate(&mut rng, &needle, mutations);
+        h.extend_from_slice(&copy);
+        let tail_len = rng.next(12);
+        let tail = dna(&mut rng, tail_len);
+        h.extend_from_slice(&tail);
+        let k = rng.next(3);
+        assert_eq!(edits(&h, &needle, k), reference_edits(&h, &needle, k),
+                   "round {}: {:?} in {:?}, k = {}", round,
+                   String::from_utf8_lossy(&needle), String::from_utf8_lossy(&h), k);
+    }
+}
+
+#[test]
+fn long_needles() {
+    // needles longer than a machine word span several blocks
+    let mut rng = Lcg(42);
+    for &len in &[63, 64, 65, 130, 200] {
+        let needle = dna(&mut rng, len);
+        let copy = mutate(&mut rng, &needle, 4);
+        let mut h = dna(&mut rng, 300);
+        let at = h.len();
+        h.extend_from_slice(&copy);
+        let tail = dna(&mut rng, 300);
+        h.extend_from_slice(&tail);
+
+        let found = edits(&h, &needle, 8);
+        assert!(found.iter().any(|&(s, e, d)| s <= at + 4 && e + 4 >= at + copy.len() && d <= 4),
+                "{}: {:?}", len, found);
+        for &(s, e, d) in &found {
+            assert_eq!(edit_distance(&needle, &h[s..e]), d);
+        }
+        assert_eq!(edits(&h, &needle, 0), []);
+        assert_eq!(edits(&needle, &needle, 0), [(0, len, 0)]);
+    }
+}
+
+#[test]
+fn rejects_and_empty_needles() {
+    let h = "xxabcxxabdxx";
+    let p = Edits { needle: "abc", max_edits: 1 };
+    assert_eq!(IC::split(h, p).collect::<Vec<_>>(), ["xx", "xx", "xx"]);
+    assert_eq!(IC::rsplit(h, p).collect::<Vec<_>>(), ["xx", "xx", "xx"]);
+    assert_eq!(IC::rejects(h, p).collect::<Vec<_>>(), ["xx", "xx", "xx"]);
+
+    // an empty needle matches the empty string at each char boundary
+    let empty = Mismatches { needle: "", max_mismatches: 0 };
+    assert_eq!(IC::match_indices("a\u{e9}", empty).collect::<Vec<_>>(),
+               [(0, ""), (1, ""), (3, "")]);
+    assert_eq!(IC::rmatch_indices("a\u{e9}", empty).collect::<Vec<_>>(),
+               [(3, ""), (1, ""), (0, "")]);
+    assert_eq!(IC::match_indices("ab", Edits { needle: "", max_edits: 0 }).count(), 3);
+    assert_eq!(IC::split("ab", Edits { needle: "", max_edits: 0 }).collect::<Vec<_>>(),
+               ["", "a", "b", ""]);
+}
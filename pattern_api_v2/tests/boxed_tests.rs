@@ -0,0 +1,78 @@
+extern crate pattern_api_v2;
+
+use pattern_api_v2::{Pattern, PatternError, TryPattern};
+use pattern_api_v2::boxed::{BoxPattern, Forward, Reverse};
+use pattern_api_v2::std_integration::IteratorConstructors as IC;
+
+enum Setting<'a> {
+    Char(char),
+    Str(&'a str),
+    Whitespace,
+    Vowels,
+}
+
+fn separator<'a, 'h: 'a>(setting: &Setting<'a>) -> BoxPattern<'a, &'h str, Reverse> {
+    match *setting {
+        Setting::Char(c) => BoxPattern::new_double_ended(c).into_reverse(),
+        Setting::Str(s) => BoxPattern::new_reverse(s),
+        Setting::Whitespace => BoxPattern::new_reverse(char::is_whitespace),
+        Setting::Vowels => BoxPattern::new_reverse(&['a', 'e', 'i', 'o', 'u'][..]),
+    }
+}
+
+#[test]
+fn runtime_choice() {
+    let h = "one, two three";
+    let split = |s: Setting| IC::split(h, separator(&s)).collect::<Vec<_>>();
+    let rsplit = |s: Setting| IC::rsplit(h, separator(&s)).collect::<Vec<_>>();
+
+    assert_eq!(split(Setting::Char(',')), ["one", " two three"]);
+    assert_eq!(split(Setting::Str(", ")), ["one", "two three"]);
+    assert_eq!(split(Setting::Whitespace), ["one,", "two", "three"]);
+    assert_eq!(rsplit(Setting::Whitespace), ["three", "two", "one,"]);
+    assert_eq!(rsplit(Setting::Vowels), ["", "", " thr", ", tw", "n", ""]);
+
+    let needle = String::from("t");
+    assert_eq!(IC::rfind(h, separator(&Setting::Str(&needle))), Some(9));
+    assert_eq!(IC::match_indices(h, separator(&Setting::Str(&needle))).collect::<Vec<_>>(),
+               [(5, "t"), (9, "t")]);
+}
+
+#[test]
+fn capabilities() {
+    let h = "a1b22c";
+    let digits = BoxPattern::new_double_ended(|c: char| c.is_ascii_digit());
+    assert_eq!(IC::split(h, digits).rev().collect::<Vec<_>>(), ["c", "", "b", "a"]);
+
+    // patterns of all capabilities can be stored together
+    let patterns: Vec<BoxPattern<&str, Forward>> = vec![
+        BoxPattern::new("22"),
+        BoxPattern::new_reverse("b").into_forward(),
+        BoxPattern::new_double_ended('1').into_forward(),
+        BoxPattern::new(|c: char| c == 'c'),
+    ];
+    let found = patterns.into_iter().map(|p| IC::find(h, p)).collect::<Vec<_>>();
+    assert_eq!(found, [Some(3), Some(2), Some(1), Some(5)]);
+
+    let mut slices: Vec<BoxPattern<&[u8], Reverse>> = vec![
+        BoxPattern::new_reverse(&b"22"[..]),
+        BoxPattern::new_double_ended(|b: &u8| *b == b'a').into_reverse(),
+    ];
+    assert_eq!(IC::rfind(&b"a22a22"[..], slices.remove(0)), Some(4));
+    assert_eq!(IC::rfind(&b"a22a22"[..], slices.remove(0)), Some(3));
+}
+
+#[test]
+fn forwarded_methods() {
+    assert!(BoxPattern::new("ab").is_prefix_of("abc"));
+    assert!(BoxPattern::new_reverse("bc").is_suffix_of("abc"));
+    assert_eq!(BoxPattern::new("aa").matches_at("aaa", 1), Some(3));
+    assert_eq!(BoxPattern::new('x').matches_at("aaa", 1), None);
+
+    use pattern_api_v2::path::{PathComponents, Glob};
+    use std::path::Path;
+    let h = PathComponents(Path::new("src/lib.rs"));
+    assert_eq!(h.try_find(BoxPattern::new(Glob(""))).err(), Some(PatternError::EmptyNeedle));
+    assert_eq!(h.try_find(BoxPattern::new(Glob("*.rs"))), Ok(Some(4)));
+    assert!(BoxPattern::new(Glob("x")).try_into_searcher(h).is_ok());
+}
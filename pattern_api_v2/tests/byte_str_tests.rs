@@ -0,0 +1,126 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::{Pattern, PatternHaystack, Searcher, ReverseSearcher};
+use pattern_api_v2::byte_str::shared::PartialUnicode;
+use pattern_api_v2::std_integration::{ByteSliceExtension, IteratorConstructors};
+
+fn rejects<'a, P>(h: PartialUnicode<'a>, p: P) -> Vec<(usize, usize)>
+    where P: Pattern<PartialUnicode<'a>>,
+          P::Searcher: ReverseSearcher<PartialUnicode<'a>>
+{
+    let mut searcher = p.into_searcher(h);
+    let hs = searcher.haystack();
+    let mut v = vec![];
+    while let Some((a, b)) = searcher.next_reject() {
+        v.push((PartialUnicode::offset_from_front(hs, a),
+                PartialUnicode::offset_from_front(hs, b)));
+    }
+    v
+}
+
+fn rejects_back<'a, P>(h: PartialUnicode<'a>, p: P) -> Vec<(usize, usize)>
+    where P: Pattern<PartialUnicode<'a>>,
+          P::Searcher: ReverseSearcher<PartialUnicode<'a>>
+{
+    let mut searcher = p.into_searcher(h);
+    let hs = searcher.haystack();
+    let mut v = vec![];
+    while let Some((a, b)) = searcher.next_reject_back() {
+        v.push((PartialUnicode::offset_from_front(hs, a),
+                PartialUnicode::offset_from_front(hs, b)));
+    }
+    v.reverse();
+    v
+}
+
+#[test]
+fn text_patterns() {
+    let log = &b"\x00\x01GET /caf\xc3\xa9 \xff\xfe200\n"[..];
+
+    assert_eq!(log.for_unicode().matches(char::is_alphabetic).collect::<Vec<_>>(),
+               ["G", "E", "T", "c", "a", "f", "\u{e9}"]);
+    assert_eq!(log.for_unicode().rmatches(&['/', ' '][..]).collect::<Vec<_>>(),
+               [" ", "/", " "]);
+    assert_eq!(log.for_unicode().match_indices("caf\u{e9}").collect::<Vec<_>>(),
+               [(7, "caf\u{e9}")]);
+    assert_eq!(log.for_unicode().find('2'), Some(15));
+    assert_eq!(log.for_unicode().rfind(" "), Some(12));
+    assert!(log.for_unicode().contains("200"));
+    assert!(!log.for_unicode().starts_with("GET"));
+    assert!(log.for_unicode().ends_with('\n'));
+}
+
+#[test]
+fn no_matches_in_invalid_bytes() {
+    // the truncated `\xe2\x82` must not be matched as part of `€`
+    let h = &b"\xe2\x82 \xe2\x82\xac"[..];
+    assert_eq!(h.for_unicode().match_indices('\u{20ac}').collect::<Vec<_>>(),
+               [(3, "\u{20ac}")]);
+    assert_eq!(h.for_unicode().match_indices(|_| true).collect::<Vec<_>>(),
+               [(2, " "), (3, "\u{20ac}")]);
+    assert_eq!(h.for_unicode().rmatch_indices("").collect::<Vec<_>>(),
+               [(6, ""), (3, ""), (2, ""), (0, "")]);
+}
+
+#[test]
+fn skip_mode() {
+    let h = &b"ab\xffc\xc3\xa9\xff\xff"[..];
+
+    assert_eq!(rejects(h.for_unicode(), 'b'), [(0, 1), (3, 4), (4, 6)]);
+    assert_eq!(rejects_back(h.for_unicode(), 'b'), [(0, 1), (3, 4), (4, 6)]);
+    assert_eq!(rejects(h.for_unicode(), "b"), [(0, 1), (3, 4), (4, 6)]);
+    assert_eq!(rejects_back(h.for_unicode(), "b"), [(0, 1), (3, 4), (4, 6)]);
+
+    assert_eq!(h.for_unicode().trim_matches(char::is_alphabetic), "");
+}
+
+#[test]
+fn reject_mode() {
+    let h = &b"ab\xffc\xc3\xa9\xff\xff"[..];
+
+    assert_eq!(rejects(h.for_unicode().rejecting_invalid(), 'b'),
+               [(0, 1), (2, 3), (3, 4), (4, 6), (6, 8)]);
+    assert_eq!(rejects_back(h.for_unicode().rejecting_invalid(), 'b'),
+               [(0, 1), (2, 3), (3, 4), (4, 6), (6, 8)]);
+    assert_eq!(rejects(h.for_unicode().rejecting_invalid(), "b"),
+               [(0, 1), (2, 3), (3, 4), (4, 6), (6, 8)]);
+    assert_eq!(rejects_back(h.for_unicode().rejecting_invalid(), "b"),
+               [(0, 1), (2, 3), (3, 4), (4, 6), (6, 8)]);
+
+    // invalid bytes are only seen in the reject mode
+    assert!(b"\xffab"[..].for_unicode().starts_with('a'));
+    assert!(!b"\xffab"[..].for_unicode().rejecting_invalid().starts_with('a'));
+}
+
+searcher_test!(reject_mode_searcher,
+               "bc",
+               b"abc\xffbc\xe2\x82bc"[..].for_unicode().rejecting_invalid(),
+               forward: [
+                   Reject(0, 1),
+                   Match(1, 3),
+                   Reject(3, 4),
+                   Match(4, 6),
+                   Reject(6, 8),
+                   Match(8, 10),
+               ],
+               backward: [
+                   Reject(0, 1),
+                   Match(1, 3),
+                   Reject(3, 4),
+                   Match(4, 6),
+                   Reject(6, 8),
+                   Match(8, 10),
+               ]);
+
+#[test]
+fn mutable() {
+    let mut v = b"one\xfftwo\xfethree".to_vec();
+    for word in v.for_unicode_mut().matches_mut(char::is_alphabetic) {
+        word.make_ascii_uppercase();
+    }
+    assert_eq!(v, b"ONE\xffTWO\xfeTHREE");
+
+    assert_eq!(v.for_unicode_mut().lines().count(), 1);
+}
@@ -0,0 +1,131 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::Pattern;
+use pattern_api_v2::char_classes::*;
+use pattern_api_v2::sets::CharSet;
+use pattern_api_v2::std_integration::ByteSliceExtension;
+use pattern_api_v2::std_integration::IteratorConstructors as IC;
+
+#[test]
+fn general_categories() {
+    for c in "aZ\u{e9}\u{3b1}\u{5d0}\u{4e00}\u{1c5}".chars() {
+        assert!(LETTER.contains(c), "{:?}", c);
+    }
+    assert!(UPPERCASE_LETTER.contains('Q') && !UPPERCASE_LETTER.contains('q'));
+    assert!(TITLECASE_LETTER.contains('\u{1c5}') && CASED_LETTER.contains('\u{1c5}'));
+    assert!(!CASED_LETTER.contains('\u{4e00}'));
+    assert!(DECIMAL_NUMBER.contains('7') && DECIMAL_NUMBER.contains('\u{663}'));
+    assert!(!DECIMAL_NUMBER.contains('\u{bd}') && NUMBER.contains('\u{bd}'));
+    assert!(PUNCTUATION.contains('!') && PUNCTUATION.contains('\u{201c}'));
+    assert!(!PUNCTUATION.contains('+') && MATH_SYMBOL.contains('+'));
+    assert!(CURRENCY_SYMBOL.contains('\u{20ac}'));
+    assert!(SPACE_SEPARATOR.contains(' ') && SPACE_SEPARATOR.contains('\u{3000}'));
+    assert!(!SPACE_SEPARATOR.contains('\t') && CONTROL.contains('\t'));
+    assert!(NONSPACING_MARK.contains('\u{301}') && MARK.contains('\u{301}'));
+    assert!(PRIVATE_USE.contains('\u{e000}') && FORMAT.contains('\u{200d}'));
+    assert!(UNASSIGNED.contains('\u{378}') && OTHER.contains('\u{378}'));
+    assert!(UNASSIGNED.contains('\u{10FFFF}') && !UNASSIGNED.contains('a'));
+
+    assert_eq!(CharClass::general_category("Nd"), Some(DECIMAL_NUMBER));
+    assert_eq!(CharClass::general_category("Space_Separator"), Some(SPACE_SEPARATOR));
+    assert_eq!(CharClass::general_category("L"), Some(LETTER));
+    assert_eq!(CharClass::general_category("Xx"), None);
+}
+
+#[test]
+fn scripts_and_identifiers() {
+    assert!(LATIN.contains('a') && LATIN.contains('\u{e9}') && !LATIN.contains('\u{3b1}'));
+    assert!(GREEK.contains('\u{3b1}') && CYRILLIC.contains('\u{436}'));
+    assert!(HAN.contains('\u{4e2d}') && HIRAGANA.contains('\u{3042}'));
+    assert!(COMMON.contains('1') && !LATIN.contains('1'));
+    assert!(INHERITED.contains('\u{301}'));
+    assert_eq!(CharClass::script("Cyrillic"), Some(CYRILLIC));
+    assert_eq!(CharClass::script("Klingon"), None);
+
+    assert!(XID_START.contains('a') && XID_START.contains('\u{3b1}'));
+    assert!(!XID_START.contains('_') && !XID_START.contains('1'));
+    assert!(XID_CONTINUE.contains('_') && XID_CONTINUE.contains('1'));
+    assert!(!XID_CONTINUE.contains('-') && !XID_CONTINUE.contains(' '));
+}
+
+type CharMethod = fn(&char) -> bool;
+
+#[test]
+fn ascii_classes_agree_with_char_methods() {
+    let classes: &[(CharClass, CharMethod)] = &[
+        (ASCII_ALPHABETIC, char::is_ascii_alphabetic),
+        (ASCII_UPPERCASE, char::is_ascii_uppercase),
+        (ASCII_LOWERCASE, char::is_ascii_lowercase),
+        (ASCII_ALPHANUMERIC, char::is_ascii_alphanumeric),
+        (ASCII_DIGIT, char::is_ascii_digit),
+        (ASCII_HEXDIGIT, char::is_ascii_hexdigit),
+        (ASCII_PUNCTUATION, char::is_ascii_punctuation),
+        (ASCII_GRAPHIC, char::is_ascii_graphic),
+        (ASCII_WHITESPACE, char::is_ascii_whitespace),
+        (ASCII_CONTROL, char::is_ascii_control),
+    ];
+    for &(class, f) in classes {
+        assert!(class.is_ascii());
+        for c in (0..=255u8).map(char::from) {
+            assert_eq!(class.contains(c), f(&c), "{:?} {:?}", class, c);
+        }
+    }
+    assert!(!LETTER.is_ascii() && !XID_START.is_ascii());
+}
+
+#[test]
+fn patterns() {
+    let h = "let x_1 = \u{3b1}\u{3b2} + 42; // caf\u{e9}";
+    assert_eq!(IC::split(h, ASCII_WHITESPACE).collect::<Vec<_>>(),
+               ["let", "x_1", "=", "\u{3b1}\u{3b2}", "+", "42;", "//", "caf\u{e9}"]);
+    assert_eq!(IC::match_indices(h, DECIMAL_NUMBER).collect::<Vec<_>>(),
+               [(6, "1"), (17, "4"), (18, "2")]);
+    assert_eq!(IC::rfind(h, GREEK), Some(12));
+    assert_eq!(IC::trim_matches("  \u{3000}word\u{a0} ", SPACE_SEPARATOR), "word");
+    assert!(LETTER.is_prefix_of(h) && !LETTER.is_suffix_of("abc1"));
+
+    assert_eq!(IC::rejects(h, XID_CONTINUE).collect::<Vec<_>>(), [" ", " = ", " + ", "; // "]);
+
+    let o = os!(b"x\xce\xb1\xff1\xce\xb2y");
+    assert_eq!(IC::find(o, GREEK), Some(1));
+    assert_eq!(IC::rfind(o, GREEK), Some(5));
+    assert_eq!(IC::matches(o, ASCII_ALPHANUMERIC).count(), 3);
+    assert_eq!(IC::rfind(uos!(b"x\xce\xb1\xff1\xce\xb2y"), LETTER), Some(7));
+
+    let b = b"ab\xff\xce\xb1 9";
+    assert_eq!(IC::find(b.for_unicode(), GREEK), Some(3));
+    assert_eq!(IC::find(b.for_unicode(), ASCII_DIGIT), Some(6));
+}
+
+#[test]
+fn union_and_difference() {
+    let kebab = XID_CONTINUE.union(CharSet::from(&['-'][..]));
+    assert_eq!(IC::trim_matches("(my-ident_2)", &kebab), "(my-ident_2)");
+    assert_eq!(IC::rejects("(my-ident_2)", &kebab).collect::<Vec<_>>(), ["(", ")"]);
+
+    let letters_not_latin = LETTER.difference(LATIN);
+    assert!(letters_not_latin.contains('\u{3b1}') && letters_not_latin.contains('\u{4e2d}'));
+    assert!(!letters_not_latin.contains('a') && !letters_not_latin.contains('1'));
+    assert!(!letters_not_latin.is_ascii());
+
+    let word = ASCII_ALPHANUMERIC.union(CharSet::from(&['_'][..]));
+    assert!(word.is_ascii());
+    assert_eq!(word, ASCII_ALPHANUMERIC.union(CharSet::from(&['_'][..])));
+    assert_eq!(CharSet::from(ASCII_HEXDIGIT).difference(ASCII_DIGIT),
+               CharSet::from(&['a'..='f', 'A'..='F'][..]));
+
+    // conversions agree with the lookups, including across surrogates
+    let other = CharSet::from(OTHER);
+    let assigned = CharSet::from(&['\0'..='\u{10FFFF}'][..]).difference(UNASSIGNED);
+    for &c in &['\0', 'a', '\u{378}', '\u{d7ff}', '\u{e000}', '\u{fffe}', '\u{10FFFF}'] {
+        assert_eq!(other.contains(c), OTHER.contains(c), "{:?}", c);
+        assert_eq!(assigned.contains(c), !UNASSIGNED.contains(c), "{:?}", c);
+    }
+    let pieces = CharSet::from(&['a'..='z', '\u{100}'..='\u{2ff}', '\u{d000}'..='\u{10000}'][..])
+        .difference(CharSet::from(&['c'..='x', '\u{150}'..='\u{250}', '\u{d7ff}'..='\u{e000}'][..]));
+    assert_eq!(pieces, CharSet::from(&['a'..='b', 'y'..='z', '\u{100}'..='\u{14f}',
+                                       '\u{251}'..='\u{2ff}', '\u{d000}'..='\u{d7fe}',
+                                       '\u{e001}'..='\u{10000}'][..]));
+}
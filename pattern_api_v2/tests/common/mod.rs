@@ -0,0 +1,13 @@
+// Helpers shared by the integration tests
+
+/// A small deterministic random number generator, for tests that
+/// compare a search with a simple reference on many inputs.
+pub struct Lcg(pub u64);
+
+impl Lcg {
+    /// A number in `0..n`.
+    pub fn next(&mut self, n: usize) -> usize {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.0 >> 33) as usize % n
+    }
+}
@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::byte_str::InvalidUtf8;
+use pattern_api_v2::std_integration::{ByteSliceExtension, IteratorConstructors};
+
+#[test]
+fn ranges() {
+    let h = &b"a\xff\xfeb\xe2\x82\xacc\xe2\x82"[..];
+    assert_eq!(h.invalid_utf8_ranges().collect::<Vec<_>>(), [1..3, 8..10]);
+    assert_eq!(h.invalid_utf8_ranges().rev().collect::<Vec<_>>(), [8..10, 1..3]);
+    assert_eq!(b"caf\xc3\xa9"[..].invalid_utf8_ranges().count(), 0);
+    assert_eq!(b""[..].invalid_utf8_ranges().count(), 0);
+}
+
+#[test]
+fn bytes() {
+    let h = &b"\xffa\xfe\xfdb\xc3\xa9\xc3"[..];
+    assert_eq!(IteratorConstructors::split(h, InvalidUtf8).collect::<Vec<_>>(),
+               [&b""[..], b"a", b"b\xc3\xa9", b""]);
+    assert_eq!(IteratorConstructors::rsplit(h, InvalidUtf8).collect::<Vec<_>>(),
+               [&b""[..], b"b\xc3\xa9", b"a", b""]);
+    assert_eq!(IteratorConstructors::matches(h, InvalidUtf8).collect::<Vec<_>>(),
+               [&b"\xff"[..], b"\xfe\xfd", b"\xc3"]);
+    assert_eq!(IteratorConstructors::trim_matches(h, InvalidUtf8), b"a\xfe\xfdb\xc3\xa9");
+    assert_eq!(IteratorConstructors::trim_left_matches(h, InvalidUtf8), b"a\xfe\xfdb\xc3\xa9\xc3");
+    assert_eq!(IteratorConstructors::trim_matches(&b"\xff\xff"[..], InvalidUtf8), b"");
+}
+
+#[test]
+fn bytes_mut() {
+    let mut v = b"a\xffb\xfe".to_vec();
+    for invalid in IteratorConstructors::matches_mut(&mut v[..], InvalidUtf8) {
+        for b in invalid.iter_mut() {
+            *b = b'?';
+        }
+    }
+    assert_eq!(v, b"a?b?");
+}
+
+#[cfg(unix)]
+#[test]
+fn os_str() {
+    use std::ffi::{OsStr, OsString};
+    use std::os::unix::ffi::OsStringExt;
+    use pattern_api_v2::std_integration::OsStrExtension;
+
+    let name = OsString::from_vec(b"\xffreport\xfe.txt".to_vec());
+    assert_eq!(name.invalid_utf8_ranges().collect::<Vec<_>>(), [0..1, 7..8]);
+    assert_eq!(name.split(InvalidUtf8).collect::<Vec<_>>(),
+               [OsStr::new(""), OsStr::new("report"), OsStr::new(".txt")]);
+    assert_eq!(name.trim_matches(InvalidUtf8), os!(b"report\xfe.txt"));
+}
+
+searcher_test!(searcher,
+               InvalidUtf8,
+               &b"a\xff\xe2\x82\xac\xfe\xfe"[..],
+               double: [
+                   Reject(0, 1),
+                   Match(1, 2),
+                   Reject(2, 5),
+                   Match(5, 7),
+               ]);
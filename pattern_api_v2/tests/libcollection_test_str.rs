@@ -1,10 +1,14 @@
 #![no_implicit_prelude]
+// Some tests exercise the deprecated std methods and `&&str` patterns
+#![allow(deprecated, unused_imports)]
+#![allow(clippy::manual_pattern_char_comparison, clippy::needless_borrows_for_generic_args)]
 
 extern crate pattern_api_v2;
 use pattern_api_v2::std_integration::IteratorConstructors;
 use pattern_api_v2::Pattern;
 
 use std::vec::Vec;
+use std::{assert, assert_eq, vec};
 use std::option::Option::{self, Some, None};
 use std::string::String;
 use std::convert::From;
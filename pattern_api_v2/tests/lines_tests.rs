@@ -0,0 +1,138 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::std_integration::{IteratorConstructors, OsStrExtension};
+
+use pattern_api_v2_test_support::{s};
+
+// The methods are called through the trait to avoid
+// picking up the inherent methods of str
+
+fn lines(h: &str) -> Vec<&str> {
+    let fwd: Vec<_> = IteratorConstructors::lines(h).collect();
+    let mut back: Vec<_> = IteratorConstructors::lines(h).rev().collect();
+    back.reverse();
+    assert_eq!(fwd, back);
+    fwd
+}
+
+fn lines_any(h: &str) -> Vec<&str> {
+    let fwd: Vec<_> = IteratorConstructors::lines_any(h).collect();
+    let mut back: Vec<_> = IteratorConstructors::lines_any(h).rev().collect();
+    back.reverse();
+    assert_eq!(fwd, back);
+    fwd
+}
+
+#[test]
+fn lines_str() {
+    let empty: Vec<&str> = vec![];
+    assert_eq!(lines(""), empty);
+    assert_eq!(lines("\n"), [""]);
+    assert_eq!(lines("a"), ["a"]);
+    assert_eq!(lines("a\n"), ["a"]);
+    assert_eq!(lines("a\n\n"), ["a", ""]);
+    assert_eq!(lines("a\r\nb\nc"), ["a", "b", "c"]);
+    assert_eq!(lines("a\r\nb\r\n"), ["a", "b"]);
+    assert_eq!(lines("\r\n\r\n"), ["", ""]);
+    assert_eq!(lines("a\rb\r"), ["a\rb\r"]);
+    assert_eq!(lines("a\u{2028}b\n"), ["a\u{2028}b"]);
+    assert_eq!(lines("long line without a newline, longer than a word\r\n\
+                      and another one after it"),
+               ["long line without a newline, longer than a word",
+                "and another one after it"]);
+
+    // compare with the std implementation
+    let text = "\nMäry häd ä little lämb\r\n\r\nLittle lämb\n\r\n\n\nend";
+    assert_eq!(lines(text), text.lines().collect::<Vec<_>>());
+}
+
+#[test]
+fn lines_any_str() {
+    let empty: Vec<&str> = vec![];
+    assert_eq!(lines_any(""), empty);
+    assert_eq!(lines_any("a\r\nb\rc\nd"), ["a", "b", "c", "d"]);
+    assert_eq!(lines_any("a\r\r\n"), ["a", ""]);
+    assert_eq!(lines_any("a\x0Bb\x0Cc"), ["a", "b", "c"]);
+    assert_eq!(lines_any("a\u{85}b\u{2028}c\u{2029}"), ["a", "b", "c"]);
+    assert_eq!(lines_any("\u{2028}\u{2028}"), ["", ""]);
+    assert_eq!(lines_any("ä\u{2027}\u{2030}"), ["ä\u{2027}\u{2030}"]);
+}
+
+#[test]
+fn lines_mixed_ends() {
+    let mut it = IteratorConstructors::lines("a\nb\r\nc\nd\n");
+    assert_eq!(it.next(), Some("a"));
+    assert_eq!(it.next_back(), Some("d"));
+    assert_eq!(it.next_back(), Some("c"));
+    assert_eq!(it.next(), Some("b"));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    let mut it = IteratorConstructors::lines_any("a\r\nb");
+    assert_eq!(it.next_back(), Some("b"));
+    assert_eq!(it.next_back(), Some("a"));
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn line_indices_str() {
+    let v: Vec<_> = IteratorConstructors::line_indices("ab\r\n\ncd\n").collect();
+    assert_eq!(v, [(0, "ab"), (4, ""), (5, "cd")]);
+
+    let v: Vec<_> = IteratorConstructors::line_indices("ab\r\n\ncd\n").rev().collect();
+    assert_eq!(v, [(5, "cd"), (4, ""), (0, "ab")]);
+
+    let v: Vec<_> = IteratorConstructors::line_indices_any("a\u{2028}b\rc").collect();
+    assert_eq!(v, [(0, "a"), (4, "b"), (6, "c")]);
+}
+
+#[test]
+fn lines_mut_str() {
+    let mut string = s("a\r\nb\nc");
+    for line in IteratorConstructors::lines(&mut string[..]) {
+        line.make_ascii_uppercase();
+    }
+    assert_eq!(string, "A\r\nB\nC");
+}
+
+#[test]
+fn lines_slice() {
+    let v: Vec<_> = IteratorConstructors::lines(&b"a\xff\r\nb\n"[..]).collect();
+    assert_eq!(v, [&b"a\xff"[..], &b"b"[..]]);
+
+    let v: Vec<_> = IteratorConstructors::lines(&b"a\xff\r\nb\n"[..]).rev().collect();
+    assert_eq!(v, [&b"b"[..], &b"a\xff"[..]]);
+
+    let v: Vec<_> = IteratorConstructors::lines_any(&b"a\xc2\x85b"[..]).collect();
+    assert_eq!(v, [&b"a"[..], &b"b"[..]]);
+
+    let v: Vec<_> = IteratorConstructors::line_indices(&b"\nab"[..]).collect();
+    assert_eq!(v, [(0, &b""[..]), (1, &b"ab"[..])]);
+}
+
+#[test]
+fn lines_os_str() {
+    assert_eq!(os!(b"a\xff\r\nb\n").lines().collect::<Vec<_>>(),
+               [os!(b"a\xff"), os!("b")]);
+
+    assert_eq!(os!(b"a\r\nb\xff").lines().rev().collect::<Vec<_>>(),
+               [os!(b"b\xff"), os!("a")]);
+
+    assert_eq!(os!("a\u{2029}b").lines_any().collect::<Vec<_>>(),
+               [os!("a"), os!("b")]);
+
+    assert_eq!(os!(b"a\r\nb\xff").line_indices().collect::<Vec<_>>(),
+               [(0, os!("a")), (3, os!(b"b\xff"))]);
+}
+
+#[test]
+fn lines_partial_unicode() {
+    let haystack = os!(b"a\xff\r\nb\n");
+    let v: Vec<_> = haystack.for_unicode().lines().collect();
+    assert_eq!(v, [os!(b"a\xff"), os!("b")]);
+
+    assert_eq!(uos!(b"a\nb\xff").line_indices().rev().collect::<Vec<_>>(),
+               [(2, os!(b"b\xff")), (0, os!("a"))]);
+}
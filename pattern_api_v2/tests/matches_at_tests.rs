@@ -0,0 +1,163 @@
+extern crate pattern_api_v2;
+
+use pattern_api_v2::{Pattern, Searcher};
+use pattern_api_v2::slice::Elem;
+use pattern_api_v2::approx::{Edits, Mismatches};
+use pattern_api_v2::boxed::BoxPattern;
+use pattern_api_v2::byte_str::InvalidUtf8;
+use pattern_api_v2::byte_str::shared::PartialUnicode;
+use pattern_api_v2::normalization::Canonical;
+use pattern_api_v2::os_string::shared::PartialUnicode as UOsStr;
+use pattern_api_v2::path::PathComponents;
+use pattern_api_v2::segmentation::{Graphemes, WordBoundary};
+
+use std::ffi::OsStr;
+use std::path::Path;
+
+#[test]
+fn str_patterns() {
+    let h = "let x\u{e9} = 1;";
+    assert_eq!("let".matches_at(h, 0), Some(3));
+    assert_eq!("let".matches_at(h, 1), None);
+    assert_eq!('x'.matches_at(h, 4), Some(5));
+    assert_eq!('\u{e9}'.matches_at(h, 5), Some(7));
+    assert_eq!(char::is_alphabetic.matches_at(h, 6), None);
+    assert_eq!((&['=', ';'][..]).matches_at(h, 8), Some(9));
+    assert_eq!("".matches_at(h, 6), None);
+    assert_eq!("".matches_at(h, 7), Some(7));
+    assert_eq!("".matches_at(h, 12), Some(12));
+    assert_eq!(';'.matches_at(h, 12), None);
+    assert_eq!(";".matches_at(h, 13), None);
+}
+
+#[test]
+fn overlapping() {
+    // a forward search would only find the match at 0
+    assert_eq!("aa".matches_at("aaa", 1), Some(3));
+    assert_eq!("aa".matches_at(&mut String::from("aaa")[..], 1), Some(3));
+}
+
+#[test]
+fn tokenizer() {
+    let h = "ab+12";
+    let mut tokens = vec![];
+    let mut pos = 0;
+    while pos < h.len() {
+        let end = char::is_alphabetic.matches_at(h, pos)
+            .or_else(|| char::is_numeric.matches_at(h, pos))
+            .or_else(|| "+".matches_at(h, pos))
+            .unwrap();
+        tokens.push(&h[pos..end]);
+        pos = end;
+    }
+    assert_eq!(tokens, ["a", "b", "+", "1", "2"]);
+}
+
+#[test]
+fn slices() {
+    let h = &[1, 2, 3, 2][..];
+    assert_eq!(Elem(2).matches_at(h, 3), Some(4));
+    assert_eq!(Elem(2).matches_at(h, 4), None);
+    assert_eq!((|x: &i32| *x > 2).matches_at(h, 2), Some(3));
+    assert_eq!((&[2, 3][..]).matches_at(h, 1), Some(3));
+    assert_eq!((&[2, 3][..]).matches_at(h, 3), None);
+}
+
+#[test]
+fn os_str_and_bytes() {
+    let os = OsStr::new("k\u{e9}=v");
+    assert_eq!('='.matches_at(os, 3), Some(4));
+    assert_eq!('\u{e9}'.matches_at(os, 1), Some(3));
+    assert_eq!('\u{e9}'.matches_at(os, 2), None);
+    assert_eq!(OsStr::new("=v").matches_at(os, 3), Some(5));
+    assert_eq!("v".matches_at(UOsStr { os_str: os }, 4), Some(5));
+
+    let bytes = || PartialUnicode::new(b"\xffab\xc3\xa9");
+    assert_eq!('a'.matches_at(bytes(), 1), Some(2));
+    assert_eq!(char::is_alphabetic.matches_at(bytes(), 0), None);
+    assert_eq!('\u{e9}'.matches_at(bytes(), 3), Some(5));
+    assert_eq!('\u{e9}'.matches_at(bytes(), 4), None);
+    assert_eq!("b\u{e9}".matches_at(bytes(), 2), Some(5));
+    assert_eq!("".matches_at(bytes(), 4), None);
+}
+
+#[test]
+fn seekable_searchers() {
+    let h = Graphemes("e\u{301}x");
+    assert_eq!('x'.matches_at(h, 3), Some(4));
+    assert_eq!("e".matches_at(h, 0), None);
+    assert_eq!((|g: &str| g.len() > 1).matches_at(h, 0), Some(3));
+}
+
+#[test]
+fn seekable_searchers_overlapping() {
+    // each match overlaps the one a forward search finds at 0
+    assert_eq!("aa".matches_at(Graphemes("aaa"), 1), Some(3));
+    assert_eq!(Canonical("aa").matches_at("aaa", 1), Some(3));
+    assert_eq!(Canonical("a\u{e9}").matches_at("aae\u{301}", 1), Some(5));
+    assert_eq!(Edits { needle: "aba", max_edits: 0 }.matches_at("ababa", 2), Some(5));
+    assert_eq!(Mismatches { needle: "aa", max_mismatches: 0 }.matches_at("aaa", 1), Some(3));
+    assert_eq!(Mismatches { needle: &[1, 1][..], max_mismatches: 1 }
+                   .matches_at(&[1, 1, 2][..], 1), Some(3));
+    assert_eq!("a/a".matches_at(PathComponents(Path::new("a/a/a")), 2), Some(5));
+    assert_eq!(BoxPattern::new(Canonical("aa")).matches_at("aaa", 1), Some(3));
+    assert_eq!(BoxPattern::new("aa").matches_at(Graphemes("aaa"), 1), Some(3));
+}
+
+#[test]
+fn seekable_searchers_boundaries() {
+    assert_eq!(WordBoundary.matches_at("ab cd", 2), Some(2));
+    assert_eq!(WordBoundary.matches_at("ab cd", 1), None);
+    assert_eq!(WordBoundary.matches_at(Graphemes("ab cd"), 5), Some(5));
+    // not a segment boundary of the haystack
+    assert_eq!(Canonical("\u{301}").matches_at("e\u{301}", 1), None);
+    assert_eq!("a".matches_at(PathComponents(Path::new("ba/a")), 1), None);
+    assert_eq!("a".matches_at(PathComponents(Path::new("ba/a")), 3), Some(4));
+}
+
+#[test]
+fn invalid_utf8_runs() {
+    let h = &b"a\xff\xfeb\xc3\xa9"[..];
+    assert_eq!(InvalidUtf8.matches_at(h, 1), Some(3));
+    // inside of a run, or of a valid char
+    assert_eq!(InvalidUtf8.matches_at(h, 2), None);
+    assert_eq!(InvalidUtf8.matches_at(h, 5), None);
+    assert_eq!(InvalidUtf8.matches_at(h, 0), None);
+    assert_eq!(InvalidUtf8.matches_at(h, 6), None);
+}
+
+// A pattern from outside of the crate, whose searcher can not seek
+struct Digits;
+
+struct DigitsSearcher<'a>(<fn(char) -> bool as Pattern<&'a str>>::Searcher);
+
+unsafe impl<'a> Searcher<&'a str> for DigitsSearcher<'a> {
+    fn haystack(&self) -> (*const u8, *const u8) {
+        self.0.haystack()
+    }
+
+    fn next_match(&mut self) -> Option<(*const u8, *const u8)> {
+        self.0.next_match()
+    }
+
+    fn next_reject(&mut self) -> Option<(*const u8, *const u8)> {
+        self.0.next_reject()
+    }
+}
+
+impl<'a> Pattern<&'a str> for Digits {
+    type Searcher = DigitsSearcher<'a>;
+
+    fn into_searcher(self, haystack: &'a str) -> DigitsSearcher<'a> {
+        let is_digit: fn(char) -> bool = |c| c.is_ascii_digit();
+        DigitsSearcher(is_digit.into_searcher(haystack))
+    }
+}
+
+#[test]
+fn not_seekable() {
+    assert_eq!(Digits.matches_at("a12", 1), Some(2));
+    assert_eq!(Digits.matches_at("a12", 2), Some(3));
+    assert_eq!(Digits.matches_at("a12", 0), None);
+    assert_eq!(Digits.matches_at("a12", 3), None);
+}
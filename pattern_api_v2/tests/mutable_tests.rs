@@ -0,0 +1,134 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::slice::Elem;
+use pattern_api_v2::std_integration::IteratorConstructors;
+
+use pattern_api_v2_test_support::{s};
+
+#[test]
+fn split_mut_csv_fields() {
+    let mut line = s("id,name,city");
+    if let Some(field) = IteratorConstructors::split_mut(&mut line[..], ',').next() {
+        field.make_ascii_uppercase();
+    }
+    assert_eq!(line, "ID,name,city");
+
+    let mut line = s("id,name,city");
+    {
+        let mut fields = IteratorConstructors::split_mut(&mut line[..], ',');
+        let first = fields.next().unwrap();
+        let last = fields.next_back().unwrap();
+        let middle = fields.next_back().unwrap();
+        assert_eq!(fields.next(), None);
+        assert_eq!(fields.next_back(), None);
+
+        // all three are alive at the same time
+        first.make_ascii_uppercase();
+        middle.make_ascii_uppercase();
+        last.make_ascii_uppercase();
+        assert_eq!((&*first, &*middle, &*last), ("ID", "NAME", "CITY"));
+    }
+    assert_eq!(line, "ID,NAME,CITY");
+}
+
+#[test]
+fn split_mut_both_ends_disjoint() {
+    let mut v = [1, 0, 2, 0, 3, 0, 4];
+    {
+        let mut pieces = IteratorConstructors::split_mut(&mut v[..], Elem(0));
+        let mut collected = vec![];
+        while let Some(p) = pieces.next() {
+            collected.push(p);
+            match pieces.next_back() {
+                Some(p) => collected.push(p),
+                None => break,
+            }
+        }
+        assert_eq!(collected.len(), 4);
+        for piece in collected {
+            assert_eq!(piece.len(), 1);
+            piece[0] *= 10;
+        }
+    }
+    assert_eq!(v, [10, 0, 20, 0, 30, 0, 40]);
+}
+
+#[test]
+fn matches_mut() {
+    let mut string = s("a1b22c333");
+    {
+        let mut digits = IteratorConstructors::matches_mut(&mut string[..],
+                                                           |c: char| c.is_numeric());
+        let first = digits.next().unwrap();
+        let last = digits.next_back().unwrap();
+        unsafe {
+            first.as_bytes_mut()[0] = b'x';
+            last.as_bytes_mut()[0] = b'y';
+        }
+        assert_eq!(digits.count(), 4);
+    }
+    assert_eq!(string, "axb22c33y");
+
+    let mut v = [1, 2, 3, 2, 1];
+    for two in IteratorConstructors::matches_mut(&mut v[..], Elem(2)) {
+        two[0] = 0;
+    }
+    assert_eq!(v, [1, 0, 3, 0, 1]);
+
+    let haystack = mos!(b"ab\xbeab");
+    assert_eq!(IteratorConstructors::matches_mut(haystack, 'b').count(), 2);
+}
+
+#[test]
+fn trim_matches_mut() {
+    let mut string = s("  a b  ");
+    {
+        let trimmed = IteratorConstructors::trim_matches_mut(&mut string[..], ' ');
+        assert_eq!(trimmed, "a b");
+        trimmed.make_ascii_uppercase();
+    }
+    assert_eq!(string, "  A B  ");
+
+    let mut v = [0, 0, 1, 2, 0];
+    {
+        let trimmed = IteratorConstructors::trim_matches_mut(&mut v[..], Elem(0));
+        assert_eq!(trimmed, [1, 2]);
+        trimmed[1] = 3;
+    }
+    assert_eq!(v, [0, 0, 1, 3, 0]);
+}
+
+#[test]
+fn split_at_match_mut() {
+    let mut line = s("key=value");
+    {
+        let (key, sep, value) =
+            IteratorConstructors::split_at_match_mut(&mut line[..], "=").unwrap();
+        assert_eq!((&*key, &*sep, &*value), ("key", "=", "value"));
+        key.make_ascii_uppercase();
+        value.make_ascii_uppercase();
+    }
+    assert_eq!(line, "KEY=VALUE");
+
+    let mut line = s("key");
+    assert!(IteratorConstructors::split_at_match_mut(&mut line[..], "=").is_none());
+
+    let mut v = [1, 2, 3, 4, 5];
+    {
+        let (a, b, c) =
+            IteratorConstructors::split_at_match_mut(&mut v[..], &[2, 3][..]).unwrap();
+        a[0] = 0;
+        b[1] = 0;
+        c[1] = 0;
+    }
+    assert_eq!(v, [0, 2, 0, 4, 0]);
+
+    match IteratorConstructors::split_at_match_mut(mos!(b"a\xff=b"), "=") {
+        Some((a, b, c)) => {
+            assert_eq!((&*a, &*b, &*c), (os!(b"a\xff"), os!("="), os!("b")))
+        }
+        None => panic!(),
+    }
+}
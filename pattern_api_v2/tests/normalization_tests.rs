@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::{Pattern, Searcher, ReverseSearcher};
+use pattern_api_v2::normalization::{Canonical, CanonicalSearcher};
+use pattern_api_v2::std_integration::IteratorConstructors;
+
+fn matches<'a>(h: &'a str, n: &str) -> Vec<(usize, &'a str)> {
+    let fwd: Vec<_> = IteratorConstructors::match_indices(h, Canonical(n)).collect();
+    let mut back: Vec<_> = IteratorConstructors::rmatch_indices(h, Canonical(n)).collect();
+    back.reverse();
+    assert_eq!(fwd, back);
+    fwd
+}
+
+#[test]
+fn composed_and_decomposed() {
+    // the needle and haystack may be in any normalization form
+    assert_eq!(matches("caf\u{e9} cafe\u{301}", "caf\u{e9}"),
+               [(0, "caf\u{e9}"), (6, "cafe\u{301}")]);
+    assert_eq!(matches("caf\u{e9} cafe\u{301}", "cafe\u{301}"),
+               [(0, "caf\u{e9}"), (6, "cafe\u{301}")]);
+    assert_eq!(matches("\u{1e69}", "s\u{307}\u{323}"), [(0, "\u{1e69}")]);
+    assert_eq!(matches("s\u{307}\u{323}", "\u{1e63}\u{307}"), [(0, "s\u{307}\u{323}")]);
+
+    // hangul syllables and jamo
+    assert_eq!(matches("\u{ac01}\u{1100}\u{1161}\u{11a8}", "\u{ac01}"),
+               [(0, "\u{ac01}"), (3, "\u{1100}\u{1161}\u{11a8}")]);
+    assert!(matches("\u{ac01}", "\u{ac00}").is_empty());
+    assert!(matches("\u{1100}\u{1161}", "\u{1100}").is_empty());
+}
+
+#[test]
+fn no_partial_segments() {
+    // neither a base character nor a combining mark on its own
+    assert!(matches("caf\u{e9}", "cafe").is_empty());
+    assert!(matches("cafe\u{301}", "cafe").is_empty());
+    assert!(matches("e\u{301}", "\u{301}").is_empty());
+    // the combining marks need to be the same, not just a prefix
+    assert!(matches("e\u{323}\u{301}", "\u{e9}").is_empty());
+    assert_eq!(matches("e\u{301}\u{323}", "\u{1eb9}\u{301}"), [(0, "e\u{301}\u{323}")]);
+    assert_eq!(matches("ae\u{301}e", "e"), [(4, "e")]);
+}
+
+#[test]
+fn find_split_and_trim() {
+    let h = "r\u{e9}sum\u{e9}, re\u{301}sume\u{301}";
+    assert_eq!(IteratorConstructors::find(h, Canonical("sum\u{e9}")), Some(3));
+    assert_eq!(IteratorConstructors::rfind(h, Canonical("sum\u{e9}")), Some(14));
+    assert!(IteratorConstructors::starts_with(h, Canonical("re\u{301}")));
+    assert!(IteratorConstructors::ends_with(h, Canonical("\u{e9}")));
+    assert!(!IteratorConstructors::contains(h, Canonical("resume")));
+
+    let v: Vec<_> = IteratorConstructors::split("a\u{e9}be\u{301}c", Canonical("\u{e9}")).collect();
+    assert_eq!(v, ["a", "b", "c"]);
+    let v: Vec<_> = IteratorConstructors::rsplit("a\u{e9}be\u{301}c", Canonical("\u{e9}")).collect();
+    assert_eq!(v, ["c", "b", "a"]);
+
+    assert_eq!(IteratorConstructors::trim_left_matches("e\u{301}\u{e9}x", Canonical("\u{e9}")),
+               "x");
+    assert_eq!(IteratorConstructors::trim_right_matches("x\u{e9}e\u{301}", Canonical("\u{e9}")),
+               "x");
+
+    let v: Vec<_> = IteratorConstructors::match_indices("e\u{301}x", Canonical(""))
+                        .map(|t| t.0).collect();
+    assert_eq!(v, [0, 3, 4]);
+}
+
+#[test]
+fn empty_needle_from_both_ends() {
+    // the searcher is also one for `PartialUnicode`
+    fn next(h: &str, s: &mut CanonicalSearcher) -> Option<(usize, usize)> {
+        let m = Searcher::<&str>::next_match(s);
+        m.map(|(a, b)| (a as usize - h.as_ptr() as usize, b as usize - h.as_ptr() as usize))
+    }
+    fn next_back(h: &str, s: &mut CanonicalSearcher) -> Option<(usize, usize)> {
+        let m = ReverseSearcher::<&str>::next_match_back(s);
+        m.map(|(a, b)| (a as usize - h.as_ptr() as usize, b as usize - h.as_ptr() as usize))
+    }
+
+    // the last empty match is only returned once
+    let h = "";
+    let mut searcher = Canonical("").into_searcher(h);
+    assert_eq!(next(h, &mut searcher), Some((0, 0)));
+    assert_eq!(next_back(h, &mut searcher), None);
+    assert_eq!(next(h, &mut searcher), None);
+
+    let h = "e\u{301}x";
+    let mut searcher = Canonical("").into_searcher(h);
+    assert_eq!(next(h, &mut searcher), Some((0, 0)));
+    assert_eq!(next_back(h, &mut searcher), Some((4, 4)));
+    assert_eq!(next(h, &mut searcher), Some((3, 3)));
+    assert_eq!(next_back(h, &mut searcher), None);
+    assert_eq!(next(h, &mut searcher), None);
+}
+
+#[test]
+fn partial_unicode() {
+    assert_eq!(uos!(b"\xffcafe\xcc\x81\xff caf\xc3\xa9").match_indices(Canonical("caf\u{e9}"))
+                                                         .collect::<Vec<_>>(),
+               [(1, "cafe\u{301}"), (9, "caf\u{e9}")]);
+
+    assert_eq!(uos!(b"e\xcc\x81\xffe\xcc\x81").rfind(Canonical("\u{e9}")), Some(4));
+    // an invalid byte never takes part in a match
+    assert_eq!(uos!(b"e\xff\xcc\x81").find(Canonical("\u{e9}")), None);
+}
@@ -1,4 +1,5 @@
 #![no_implicit_prelude]
+#![allow(unused_imports, unused_macros)]
 
 #[macro_use]
 extern crate pattern_api_v2_test_support;
@@ -10,6 +11,7 @@ use pattern_api_v2::std_integration::IteratorConstructors;
 use pattern_api_v2::Pattern;
 
 use std::vec::Vec;
+use std::{assert, assert_eq, vec};
 use std::option::Option::{self, Some, None};
 use std::string::String;
 use std::convert::From;
@@ -173,3 +175,23 @@ fn osstr_ends_with_os() {
     assert!(full.ends_with_os(&end));
     assert!(full.ends_with_os(&full));
 }
+
+#[test]
+fn osstr_strip_path_prefix() {
+    let path = os!("/usr/lib/libfoo.so");
+
+    let rest = path.strip_prefix("/usr/").unwrap();
+    assert_eq!(rest, os!("lib/libfoo.so"));
+    assert_eq!(rest.strip_suffix(".so"), Some(os!("lib/libfoo")));
+    assert_eq!(rest.strip_suffix(".a"), None);
+    assert_eq!(path.strip_prefix(os!("/usr/local/")), None);
+
+    let (start, end) = split_char();
+    let mut full = start.to_owned();
+    full.push(&end);
+
+    assert_eq!(full.strip_prefix(&start), Some(&*end));
+    assert_eq!(full.strip_suffix(&end), Some(&*start));
+    assert_eq!(full.strip_prefix(&end), None);
+    assert_eq!(full.strip_suffix(&start), None);
+}
@@ -0,0 +1,76 @@
+extern crate pattern_api_v2;
+
+use pattern_api_v2::std_integration::{IteratorConstructors, OsStringExtension};
+
+use std::ffi::{OsStr, OsString};
+
+fn os(s: &str) -> OsString {
+    OsString::from(s)
+}
+
+#[test]
+fn replace() {
+    let mut s = os("a-b--c");
+    s.replace('-', OsStr::new("+"));
+    assert_eq!(s, os("a+b++c"));
+    s.replace("++", OsStr::new(""));
+    assert_eq!(s, os("a+bc"));
+    s.replace(OsStr::new("x"), OsStr::new("y"));
+    assert_eq!(s, os("a+bc"));
+}
+
+#[test]
+fn retain_matches() {
+    let mut s = os("r2-d2 and c-3po");
+    s.retain_matches(|c: char| c.is_ascii_digit());
+    assert_eq!(s, os("223"));
+}
+
+#[test]
+fn truncate_at_match() {
+    let mut s = os("report.tar.gz");
+    assert!(s.truncate_at_match('.'));
+    assert_eq!(s, os("report"));
+    assert!(!s.truncate_at_match('.'));
+    assert_eq!(s, os("report"));
+}
+
+#[test]
+fn insert_str_at_find() {
+    let mut s = os("report.txt");
+    let i = s.find(".txt").unwrap();
+    s.insert_str(i, OsStr::new("-final"));
+    assert_eq!(s, os("report-final.txt"));
+
+    s.replace_range(0..6, OsStr::new("caf\u{e9}"));
+    assert_eq!(s, os("caf\u{e9}-final.txt"));
+}
+
+#[test]
+#[should_panic(expected = "char boundaries")]
+fn insert_str_inside_char() {
+    let mut s = os("caf\u{e9}");
+    s.insert_str(4, OsStr::new("x"));
+}
+
+#[test]
+fn drain_matches() {
+    let mut s = os("a1b22c333");
+    let drained = s.drain_matches(|c: char| c.is_ascii_digit());
+    assert_eq!(drained, [os("1"), os("2"), os("2"), os("3"), os("3"), os("3")]);
+    assert_eq!(s, os("abc"));
+
+    let drained = s.drain_matches("bc");
+    assert_eq!(drained, [os("bc")]);
+    assert_eq!(s, os("a"));
+}
+
+#[cfg(unix)]
+#[test]
+fn invalid_unicode_is_kept() {
+    use std::os::unix::ffi::OsStringExt;
+
+    let mut s = OsString::from_vec(b"\xffa-b\xfe".to_vec());
+    s.replace('-', OsStr::new("--"));
+    assert_eq!(s.into_vec(), b"\xffa--b\xfe");
+}
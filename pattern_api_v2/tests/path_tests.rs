@@ -0,0 +1,102 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use std::ffi::OsStr;
+use std::path::Path;
+
+use pattern_api_v2::{PatternHaystack, Span};
+use pattern_api_v2::path::{PathComponents, Glob};
+use pattern_api_v2::std_integration::IteratorConstructors;
+
+fn p(s: &str) -> PathComponents<'_> {
+    PathComponents(Path::new(s))
+}
+
+#[test]
+fn whole_components() {
+    assert_eq!(p("/usr/libexec/lib").find("lib"), Some(13));
+    assert_eq!(p("/usr/lib/x").find(OsStr::new("lib")), Some(5));
+    assert_eq!(p("/usr/libexec").find("lib"), None);
+    assert_eq!(p("/usr/xlib").find("lib"), None);
+    assert_eq!(p("a/b/a/b").rfind("a/b"), Some(4));
+    assert_eq!(p("xa/a/a").find("a/a"), Some(3));
+    assert_eq!(p("a/b").find("a/"), None);
+    assert_eq!(p("a/b").find(""), None);
+    assert!(p("/usr/lib").contains(Path::new("usr/lib")));
+    assert_eq!(p("./a/./b").matches('.').count(), 2);
+    assert_eq!(p("a/.b/c.").find('.'), None);
+}
+
+#[test]
+fn split() {
+    assert_eq!(p("src/lib/mod.rs").split("lib").collect::<Vec<_>>(),
+               [Path::new("src"), Path::new("mod.rs")]);
+    assert_eq!(p("/usr/lib//x/").split("lib").collect::<Vec<_>>(),
+               [Path::new("/usr"), Path::new("x")]);
+    assert_eq!(p("/lib/a").split("lib").collect::<Vec<_>>(),
+               [Path::new("/"), Path::new("a")]);
+    assert_eq!(p("a/b/a").rsplit('a').map(|p| p.as_os_str()).collect::<Vec<_>>(),
+               ["", "b", ""]);
+}
+
+#[test]
+fn offsets() {
+    // the offsets are those of the returned paths
+    let h = p("src/lib/mod.rs");
+    assert_eq!(h.split_indices("lib").collect::<Vec<_>>(),
+               [(0, Path::new("src")), (8, Path::new("mod.rs"))]);
+    assert_eq!(h.rsplit_indices("lib").collect::<Vec<_>>(),
+               [(8, Path::new("mod.rs")), (0, Path::new("src"))]);
+    assert_eq!(h.reject_indices("lib").collect::<Vec<_>>(),
+               [(0, Path::new("src")), (8, Path::new("mod.rs"))]);
+    let h = p("/usr//lib/x/lib/");
+    assert_eq!(h.split_indices("lib").collect::<Vec<_>>(),
+               [(0, Path::new("/usr")), (10, Path::new("x")), (16, Path::new(""))]);
+    assert_eq!(h.match_indices("lib").collect::<Vec<_>>(),
+               [(6, Path::new("lib")), (12, Path::new("lib"))]);
+    let spans: Vec<_> = h.split_spans("lib").collect();
+    assert_eq!(spans, [Span::from(0..6), Span::from(10..12), Span::from(16..16)]);
+}
+
+#[test]
+fn spans() {
+    let h = p("src/lib/mod.rs");
+    let pieces: Vec<_> = h.split_spans("lib").map(|span| h.span_to_self(span).unwrap()).collect();
+    assert_eq!(pieces, [Path::new("src"), Path::new("mod.rs")]);
+    assert_eq!(h.span_to_self(Span::from(4..7)), Some(Path::new("lib")));
+    assert_eq!(h.span_to_self(Span::from(1..7)), None);
+}
+
+#[test]
+fn strip_prefix() {
+    assert_eq!(p("usr/lib/x").strip_prefix("usr/lib"), Some(Path::new("x")));
+    assert_eq!(p("usr/lib").strip_prefix("usr"), Some(Path::new("lib")));
+    assert_eq!(p("usr/lib").strip_prefix("us"), None);
+    assert_eq!(p("src/main.rs").strip_suffix(Glob("*.rs")), Some(Path::new("src")));
+    assert!(p("a/b.rs").ends_with(Glob("*.rs")));
+    assert!(!p("a.rs/b").ends_with(Glob("*.rs")));
+}
+
+#[test]
+fn glob() {
+    let h = p("src/a.rs/tests/b.rs/x");
+    assert_eq!(h.match_indices(Glob("*.rs")).collect::<Vec<_>>(),
+               [(4, Path::new("a.rs")), (15, Path::new("b.rs"))]);
+    assert_eq!(h.rmatch_indices(Glob("*.rs")).collect::<Vec<_>>(),
+               [(15, Path::new("b.rs")), (4, Path::new("a.rs"))]);
+    assert_eq!(h.find(Glob("t?st[a-z]")), Some(9));
+    assert_eq!(h.find(Glob("[!a-z]*")), None);
+    assert_eq!(h.split(Glob("te*s")).collect::<Vec<_>>(),
+               [Path::new("src/a.rs"), Path::new("b.rs/x")]);
+}
+
+searcher_test!(components_searcher,
+               "lib",
+               p("/lib/libexec/lib"),
+               double: [
+                   Reject(0, 1),
+                   Match(1, 5),
+                   Reject(5, 13),
+                   Match(13, 16),
+               ]);
@@ -0,0 +1,70 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::Pattern;
+use pattern_api_v2::std_integration::IteratorConstructors as IC;
+
+use std::ops::RangeInclusive;
+
+const IDENT: &[RangeInclusive<char>] = &['a'..='z', 'A'..='Z', '0'..='9', '_'..='_'];
+
+#[test]
+fn str_ranges() {
+    let h = "caf\u{e9} 42_x \u{1F600}Z";
+    assert_eq!(IC::find(h, '0'..='9'), Some(6));
+    assert_eq!(IC::rfind(h, 'a'..'x'), Some(2));
+    assert_eq!(IC::matches(h, 'a'..='z').collect::<String>(), "cafx");
+    assert_eq!(IC::matches(h, '\u{e0}'..='\u{ff}').collect::<Vec<_>>(), ["\u{e9}"]);
+    assert_eq!(IC::matches(h, '\u{1F600}'..='\u{10FFFF}').count(), 1);
+    assert_eq!(IC::split(h, IDENT).filter(|s| !s.is_empty()).collect::<Vec<_>>(),
+               ["\u{e9} ", " \u{1F600}"]);
+    assert_eq!(IC::rmatch_indices(h, IDENT).map(|(i, _)| i).collect::<Vec<_>>(),
+               [15, 9, 8, 7, 6, 2, 1, 0]);
+    assert_eq!(IC::trim_matches("__x_y__", '_'..='_'), "x_y");
+
+    assert!(('a'..='c').is_prefix_of("banana"));
+    assert!(!('a'..='c').is_prefix_of("\u{e9}"));
+    assert!(('x'..='z').is_suffix_of("xyz"));
+    assert_eq!(('0'..='9').matches_at("a1", 1), Some(2));
+    assert_eq!(('0'..='9').matches_at("a\u{e9}", 1), None);
+
+    let mut s = String::from("a1b2");
+    for d in IC::matches(&mut s[..], '0'..='9') {
+        d.make_ascii_uppercase();
+    }
+    assert_eq!(IC::find(&mut s[..], 'A'..='Z'), None);
+}
+
+#[test]
+fn os_str_and_bytes() {
+    use pattern_api_v2::std_integration::{OsStrExtension, ByteSliceExtension};
+
+    let h = os!(b"ab\xff12");
+    assert_eq!(IC::find(h, '0'..='9'), Some(3));
+    assert_eq!(IC::split(h, IDENT).collect::<Vec<_>>(), [os!(""), os!(""), os!(b"\xff"), os!(""), os!("")]);
+    assert_eq!(IC::find(h.for_unicode(), '1'..'3'), Some(3));
+
+    let bytes = &b"\xffx9"[..];
+    assert_eq!(IC::find(bytes.for_unicode(), 'a'..='z'), Some(1));
+}
+
+#[test]
+fn elem_ranges() {
+    let h = &[5, 1, 9, 12, 3, 40][..];
+    assert_eq!(IC::matches(h, 1..5).collect::<Vec<_>>(), [&[1][..], &[3]]);
+    assert_eq!(IC::rfind(h, 10..=12), Some(3));
+    assert_eq!(IC::split(h, &[1..=3, 9..=9][..]).collect::<Vec<_>>(),
+               [&[5][..], &[], &[12], &[40]]);
+    assert!((4..=5).is_prefix_of(h));
+    assert_eq!(IC::trim_matches(h, &[40..=50, 0..=5][..]), &[9, 12]);
+
+    let words = &["apple", "fig", "kiwi", "plum"][..];
+    assert_eq!(IC::find(words, "g".."p"), Some(2));
+
+    let mut v = vec![1u8, 200, 3];
+    for x in IC::matches(&mut v[..], 128..=255) {
+        x[0] = 0;
+    }
+    assert_eq!(v, [1, 0, 3]);
+}
@@ -0,0 +1,133 @@
+extern crate pattern_api_v2;
+
+use pattern_api_v2::iterators::Token;
+use pattern_api_v2::std_integration::IteratorConstructors as IC;
+
+use std::ffi::OsStr;
+
+const HAYSTACKS: &[&str] = &[
+    "", "a", "abc", "a  b c  ", "  a\u{e9}\u{1F4A9}  b", "aaaa", "abab ab ba",
+];
+
+fn non_empty_pieces<'a>(h: &'a str, pieces: Vec<&'a str>) -> Vec<(usize, &'a str)> {
+    pieces.into_iter()
+        .map(|p| (p.as_ptr() as usize - h.as_ptr() as usize, p))
+        .filter(|&(_, p)| !p.is_empty())
+        .collect()
+}
+
+#[test]
+fn rejects_are_gaps_between_matches() {
+    for &h in HAYSTACKS {
+        let expected = non_empty_pieces(h, IC::split(h, ' ').collect());
+        assert_eq!(IC::reject_indices(h, ' ').collect::<Vec<_>>(), expected);
+        let mut reversed = expected.clone();
+        reversed.reverse();
+        assert_eq!(IC::rreject_indices(h, ' ').collect::<Vec<_>>(), reversed);
+
+        for &needle in &["ab", "a", "aa", " ", "\u{e9}"] {
+            let expected = non_empty_pieces(h, IC::split(h, needle).collect());
+            let rejects = IC::rejects(h, needle).collect::<Vec<_>>();
+            assert_eq!(rejects, expected.iter().map(|x| x.1).collect::<Vec<_>>());
+            let mut rev = IC::rrejects(h, needle).collect::<Vec<_>>();
+            rev.reverse();
+            assert_eq!(rev, rejects);
+        }
+
+        assert_eq!(IC::rejects(h, char::is_whitespace).collect::<Vec<_>>(),
+                   h.split_whitespace().collect::<Vec<_>>());
+        assert_eq!(IC::rejects(h, "").collect::<Vec<_>>(),
+                   h.split("").filter(|s| !s.is_empty()).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn rejects_from_both_ends() {
+    let h = "xx a bb  ccc d  ee ";
+    let all = IC::rejects(h, ' ').collect::<Vec<_>>();
+    assert_eq!(all, ["xx", "a", "bb", "ccc", "d", "ee"]);
+
+    for first_back in 0..all.len() + 1 {
+        let mut iter = IC::rejects(h, ' ');
+        let mut front = iter.by_ref().take(first_back).collect::<Vec<_>>();
+        let mut back = vec![];
+        while let Some(x) = iter.next_back() {
+            back.push(x);
+            if let Some(x) = iter.next() {
+                front.push(x);
+            }
+        }
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, all);
+    }
+
+    // pieces made of several rejects of the searcher
+    let mut iter = IC::rejects("abc-def", '-');
+    assert_eq!((iter.next(), iter.next_back(), iter.next()), (Some("abc"), Some("def"), None));
+    let mut iter = IC::rejects("abcdef", |c: char| c == '-');
+    assert_eq!((iter.next_back(), iter.next()), (Some("abcdef"), None));
+}
+
+#[test]
+fn other_haystacks() {
+    let h = &[1, 0, 0, 2, 3, 0, 4][..];
+    assert_eq!(IC::rejects(h, |x: &i32| *x == 0).collect::<Vec<_>>(),
+               [&[1][..], &[2, 3], &[4]]);
+    assert_eq!(IC::rreject_indices(h, &[0, 0][..]).collect::<Vec<_>>(),
+               [(3, &[2, 3, 0, 4][..]), (0, &[1])]);
+
+    let h = OsStr::new("a::b::::c");
+    assert_eq!(IC::rejects(h, "::").collect::<Vec<_>>(),
+               [OsStr::new("a"), OsStr::new("b"), OsStr::new("c")]);
+
+    let mut bytes = *b"a1b22";
+    for piece in IC::rejects(&mut bytes[..], |b: &u8| b.is_ascii_digit()) {
+        piece.make_ascii_uppercase();
+    }
+    assert_eq!(&bytes, b"A1B22");
+}
+
+#[test]
+fn tokens() {
+    use Token::{Match, Reject};
+
+    assert_eq!(IC::tokens("let x = 10;", char::is_alphanumeric).collect::<Vec<_>>(),
+               [Match("l"), Match("e"), Match("t"), Reject(" "), Match("x"),
+                Reject(" = "), Match("1"), Match("0"), Reject(";")]);
+    assert_eq!(IC::tokens("fn fn  f", "fn").collect::<Vec<_>>(),
+               [Match("fn"), Reject(" "), Match("fn"), Reject("  f")]);
+    assert_eq!(IC::rtokens("fn fn  f", "fn").collect::<Vec<_>>(),
+               [Reject("  f"), Match("fn"), Reject(" "), Match("fn")]);
+    assert_eq!(IC::tokens("ab", "").collect::<Vec<_>>(),
+               [Match(""), Reject("a"), Match(""), Reject("b"), Match("")]);
+    assert_eq!(IC::tokens("", 'x').count(), 0);
+
+    for &h in HAYSTACKS {
+        for &needle in &["a", " ", "ab", ""] {
+            let joined = IC::tokens(h, needle).map(Token::into_inner).collect::<String>();
+            assert_eq!(joined, h);
+            let matches = IC::tokens(h, needle).filter(Token::is_match).count();
+            assert_eq!(matches, IC::matches(h, needle).count());
+        }
+
+        let forward = IC::tokens(h, ' ').collect::<Vec<_>>();
+        let mut backward = IC::tokens(h, ' ').rev().collect::<Vec<_>>();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        // alternating between both ends
+        let mut iter = IC::tokens(h, ' ');
+        let (mut front, mut back) = (vec![], vec![]);
+        while let Some(t) = iter.next() {
+            front.push(t);
+            match iter.next_back() {
+                Some(t) => back.push(t),
+                None => break,
+            }
+        }
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, forward);
+    }
+}
@@ -0,0 +1,105 @@
+extern crate pattern_api_v2;
+
+use pattern_api_v2::{Pattern, PatternHaystack, Searcher, ReverseSearcher, SeekableSearcher};
+use pattern_api_v2::slice::Elem;
+
+use std::ffi::OsStr;
+
+fn offsets<H: PatternHaystack>(hs: H::Haystack, m: Option<(H::Cursor, H::Cursor)>)
+    -> Option<(usize, usize)>
+{
+    m.map(|(a, b)| (H::offset_from_front(hs, a), H::offset_from_front(hs, b)))
+}
+
+fn find_from<H, P>(h: H, pat: P, caret: usize) -> Option<(usize, usize)>
+    where H: PatternHaystack,
+          P: Pattern<H>,
+          P::Searcher: SeekableSearcher<H>
+{
+    let mut searcher = pat.into_searcher(h);
+    assert!(searcher.seek_front(caret));
+    let m = searcher.next_match();
+    offsets::<H>(searcher.haystack(), m)
+}
+
+fn rfind_from<H, P>(h: H, pat: P, caret: usize) -> Option<(usize, usize)>
+    where H: PatternHaystack,
+          P: Pattern<H>,
+          P::Searcher: SeekableSearcher<H> + ReverseSearcher<H>
+{
+    let mut searcher = pat.into_searcher(h);
+    assert!(searcher.seek_back(caret));
+    let m = searcher.next_match_back();
+    offsets::<H>(searcher.haystack(), m)
+}
+
+#[test]
+fn str_patterns() {
+    let h = "abc abc \u{e9}abc";
+    assert_eq!(find_from(h, "abc", 1), Some((4, 7)));
+    assert_eq!(find_from(h, "abc", 4), Some((4, 7)));
+    assert_eq!(find_from(h, "abc", 13), None);
+    assert_eq!(rfind_from(h, "abc", 12), Some((4, 7)));
+    assert_eq!(find_from(h, 'c', 3), Some((6, 7)));
+    assert_eq!(rfind_from(h, 'a', 4), Some((0, 1)));
+    assert_eq!(find_from(h, char::is_whitespace, 4), Some((7, 8)));
+    assert_eq!(find_from(h, &['b', 'c'][..], 10), Some((11, 12)));
+    assert_eq!(find_from(h, "", 5), Some((5, 5)));
+    assert_eq!(rfind_from(h, "", 5), Some((5, 5)));
+}
+
+#[test]
+fn restart_resets_the_two_way_memory() {
+    let mut searcher = "aaa".into_searcher("aaaaaa");
+    let hs = searcher.haystack();
+    assert_eq!(offsets::<&str>(hs, searcher.next_match()), Some((0, 3)));
+    assert!(searcher.seek_front(1));
+    assert_eq!(offsets::<&str>(hs, searcher.next_match()), Some((1, 4)));
+    assert!(searcher.seek_front(0));
+    assert_eq!(offsets::<&str>(hs, searcher.next_match()), Some((0, 3)));
+    assert_eq!(offsets::<&str>(hs, searcher.next_match()), Some((3, 6)));
+    assert_eq!(offsets::<&str>(hs, searcher.next_match()), None);
+}
+
+#[test]
+fn invalid_offsets() {
+    let h = "a\u{e9}b";
+    let mut searcher = "b".into_searcher(h);
+    assert!(!searcher.seek_front(2));
+    assert!(!searcher.seek_front(5));
+    assert!(!searcher.seek_back(2));
+
+    let mut searcher = 'b'.into_searcher(h);
+    assert!(!searcher.seek_front(2));
+    assert!(searcher.seek_back(1));
+    // the front can not move past the back
+    assert!(!searcher.seek_front(3));
+    assert!(searcher.seek_front(1));
+    assert_eq!(offsets::<&str>(searcher.haystack(), searcher.next_match()), None);
+}
+
+#[test]
+fn slices() {
+    let h = &[1, 2, 3, 1, 2, 3][..];
+
+    let mut searcher = Elem(1).into_searcher(h);
+    assert!(searcher.seek_front(1));
+    assert_eq!(offsets::<&[i32]>(searcher.haystack(), searcher.next_match()), Some((3, 4)));
+    assert!(!searcher.seek_back(7));
+
+    let mut searcher = (|x: &i32| *x > 1).into_searcher(h);
+    assert!(searcher.seek_back(4));
+    assert_eq!(offsets::<&[i32]>(searcher.haystack(), searcher.next_match_back()), Some((2, 3)));
+
+    let mut searcher = (&[2, 3][..]).into_searcher(h);
+    assert!(searcher.seek_front(2));
+    assert_eq!(offsets::<&[i32]>(searcher.haystack(), searcher.next_match()), Some((4, 6)));
+}
+
+#[test]
+fn os_str() {
+    let h = OsStr::new("key=value=x");
+    assert_eq!(find_from(h, '=', 4), Some((9, 10)));
+    assert_eq!(rfind_from(h, OsStr::new("="), 9), Some((3, 4)));
+    assert_eq!(find_from(h, "value", 1), Some((4, 9)));
+}
@@ -0,0 +1,185 @@
+extern crate pattern_api_v2;
+
+use pattern_api_v2::{Pattern, Searcher, ReverseSearcher};
+use pattern_api_v2::segmentation::{Graphemes, WordBoundary};
+use pattern_api_v2::segmentation::{is_grapheme_boundary, is_word_boundary};
+use pattern_api_v2::std_integration::IteratorConstructors;
+
+fn clusters(s: &str) -> Vec<&str> {
+    let fwd: Vec<_> = Graphemes(s).matches(|_: &str| true).collect();
+    let mut back: Vec<_> = Graphemes(s).matches(|_: &str| true).rev().collect();
+    back.reverse();
+    assert_eq!(fwd, back);
+    fwd
+}
+
+#[test]
+fn grapheme_clusters() {
+    let empty: Vec<&str> = vec![];
+    assert_eq!(clusters(""), empty);
+    assert_eq!(clusters("abc"), ["a", "b", "c"]);
+    assert_eq!(clusters("e\u{301}x\r\n\n"), ["e\u{301}", "x", "\r\n", "\n"]);
+    // hangul syllable out of jamo
+    assert_eq!(clusters("\u{1100}\u{1161}\u{11a8}a"), ["\u{1100}\u{1161}\u{11a8}", "a"]);
+    // flags pair up from the start of the sequence
+    assert_eq!(clusters("\u{1f1e9}\u{1f1e9}\u{1f1ea}"),
+               ["\u{1f1e9}\u{1f1e9}", "\u{1f1ea}"]);
+    // emoji zwj sequence with skin tone modifier
+    assert_eq!(clusters("\u{1f468}\u{1f3fd}\u{200d}\u{1f469}!"),
+               ["\u{1f468}\u{1f3fd}\u{200d}\u{1f469}", "!"]);
+    assert_eq!(clusters("a\u{200d}\u{1f469}"), ["a\u{200d}", "\u{1f469}"]);
+    // prepend, spacing mark
+    assert_eq!(clusters("\u{600}1\u{903}"), ["\u{600}1\u{903}"]);
+    // devanagari conjunct
+    assert_eq!(clusters("\u{915}\u{94d}\u{937}\u{93f}"), ["\u{915}\u{94d}\u{937}\u{93f}"]);
+    assert_eq!(clusters("\u{915}\u{93f}\u{937}"), ["\u{915}\u{93f}", "\u{937}"]);
+
+    assert!(is_grapheme_boundary("e\u{301}", 0));
+    assert!(!is_grapheme_boundary("e\u{301}", 1));
+    assert!(!is_grapheme_boundary("e\u{301}", 2));
+    assert!(is_grapheme_boundary("e\u{301}", 3));
+}
+
+#[test]
+fn graphemes_str_pattern() {
+    let s = "e\u{301}xe";
+    assert_eq!(Graphemes(s).find("e"), Some(4));
+    assert_eq!(Graphemes(s).rfind("e"), Some(4));
+    assert_eq!(Graphemes(s).find("xe"), Some(3));
+    assert_eq!(Graphemes(s).find("e\u{301}"), Some(0));
+    assert_eq!(Graphemes(s).find("\u{301}x"), None);
+    assert!(!Graphemes(s).contains("\u{301}"));
+    assert!(!Graphemes(s).starts_with("e"));
+    assert!(Graphemes(s).ends_with("e"));
+
+    let flags = "\u{1f1e9}\u{1f1e9}\u{1f1ea}";
+    assert_eq!(Graphemes(flags).find("\u{1f1e9}\u{1f1ea}"), None);
+    assert_eq!(Graphemes(flags).rfind("\u{1f1e9}\u{1f1ea}"), None);
+    assert_eq!(Graphemes(flags).find("\u{1f1ea}"), Some(8));
+    assert_eq!(flags.find("\u{1f1e9}\u{1f1ea}"), Some(4));
+
+    let v: Vec<_> = Graphemes("a\u{301}ba\u{301}a").match_indices("a").collect();
+    assert_eq!(v, [(7, "a")]);
+    let v: Vec<_> = Graphemes("a\u{301}ba\u{301}a").rmatch_indices("a\u{301}").collect();
+    assert_eq!(v, [(4, "a\u{301}"), (0, "a\u{301}")]);
+
+    let v: Vec<_> = Graphemes("e\u{301}x").match_indices("").map(|t| t.0).collect();
+    assert_eq!(v, [0, 3, 4]);
+    let v: Vec<_> = Graphemes("e\u{301}x").rmatch_indices("").map(|t| t.0).collect();
+    assert_eq!(v, [4, 3, 0]);
+}
+
+#[test]
+fn graphemes_empty_needle_from_both_ends() {
+    // the last empty match is only returned once
+    let mut searcher = "".into_searcher(Graphemes(""));
+    assert_eq!(searcher.next_match(), Some((0, 0)));
+    assert_eq!(searcher.next_match_back(), None);
+    assert_eq!(searcher.next_match(), None);
+
+    let mut searcher = "".into_searcher(Graphemes("e\u{301}x"));
+    assert_eq!(searcher.next_match(), Some((0, 0)));
+    assert_eq!(searcher.next_match_back(), Some((4, 4)));
+    assert_eq!(searcher.next_match(), Some((3, 3)));
+    assert_eq!(searcher.next_match_back(), None);
+    assert_eq!(searcher.next_match(), None);
+}
+
+#[test]
+fn graphemes_split_and_trim() {
+    let v: Vec<_> = Graphemes("a\u{301},b,,c\u{20dd}").split(',').collect();
+    assert_eq!(v, ["a\u{301}", "b", "", "c\u{20dd}"]);
+    let v: Vec<_> = Graphemes("a\u{301},b,,c\u{20dd}").split(',').rev().collect();
+    assert_eq!(v, ["c\u{20dd}", "", "b", "a\u{301}"]);
+
+    // does not split off the combining mark
+    let v: Vec<_> = Graphemes("x,\u{301}y").split(',').collect();
+    assert_eq!(v, ["x,\u{301}y"]);
+
+    let v: Vec<_> = Graphemes("1e\u{301}2").split("e\u{301}").collect();
+    assert_eq!(v, ["1", "2"]);
+
+    assert_eq!(Graphemes("  e\u{301}  ").trim_matches(' '), "e\u{301}");
+    assert_eq!(Graphemes("e\u{301}e").trim_matches('e'), "e\u{301}");
+    assert_eq!(Graphemes("e\u{301}e").trim_left_matches("e"), "e\u{301}e");
+    let vowels: &[char] = &['a', 'e'];
+    assert_eq!(Graphemes("aa\u{308}e").trim_right_matches(vowels), "aa\u{308}");
+}
+
+fn words(s: &str) -> Vec<&str> {
+    let fwd: Vec<_> = IteratorConstructors::split(s, WordBoundary).collect();
+    let mut back: Vec<_> = IteratorConstructors::split(s, WordBoundary).rev().collect();
+    back.reverse();
+    assert_eq!(fwd, back);
+    fwd
+}
+
+#[test]
+fn word_boundaries() {
+    assert_eq!(words("The quick (\u{201c}brown\u{201d}) fox can\u{2019}t jump 32.3 feet, right?"),
+               ["", "The", " ", "quick", " ", "(", "\u{201c}", "brown", "\u{201d}", ")", " ",
+                "fox", " ", "can\u{2019}t", " ", "jump", " ", "32.3", " ", "feet", ",", " ",
+                "right", "?", ""]);
+    assert_eq!(words(""), ["", ""]);
+    assert_eq!(words("a_b c"), ["", "a_b", " ", "c", ""]);
+    assert_eq!(words("a  \r\n\n"), ["", "a", "  ", "\r\n", "\n", ""]);
+    assert_eq!(words("\u{5d0}\"\u{5d1} \u{5d2}'"), ["", "\u{5d0}\"\u{5d1}", " ", "\u{5d2}'", ""]);
+    assert_eq!(words("\u{30a2}\u{30a4}\u{1f1e9}\u{1f1ea}\u{1f1e9}"),
+               ["", "\u{30a2}\u{30a4}", "\u{1f1e9}\u{1f1ea}", "\u{1f1e9}", ""]);
+    assert_eq!(words("e\u{301}x\u{200e}y"), ["", "e\u{301}x\u{200e}y", ""]);
+
+    let v: Vec<_> = IteratorConstructors::match_indices("ab cd", WordBoundary)
+                        .map(|t| t.0).collect();
+    assert_eq!(v, [0, 2, 3, 5]);
+    assert_eq!(IteratorConstructors::rfind("ab cd", WordBoundary), Some(5));
+
+    let mut it = IteratorConstructors::match_indices("ab", WordBoundary);
+    assert_eq!(it.next(), Some((0, "")));
+    assert_eq!(it.next_back(), Some((2, "")));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
+
+#[test]
+fn word_boundaries_inside_clusters() {
+    // a prepended concatenation mark is a word of its own,
+    // but can not be split from the following cluster
+    assert!(is_word_boundary("\u{600} a", 2));
+    assert!(!is_grapheme_boundary("\u{600} a", 2));
+    assert_eq!(words("\u{600} a"), ["", "\u{600} ", "a", ""]);
+
+    let v: Vec<_> = Graphemes("a\u{301} b").split(WordBoundary).collect();
+    assert_eq!(v, ["", "a\u{301}", " ", "b", ""]);
+}
+
+// The pieces between the positions of `s` that are boundaries
+fn pieces<F: Fn(usize) -> bool>(s: &str, is_boundary: F) -> Vec<&str> {
+    let mut v = vec![];
+    let mut last = 0;
+    for i in 1..s.len() + 1 {
+        if is_boundary(i) {
+            v.push(&s[last..i]);
+            last = i;
+        }
+    }
+    v
+}
+
+#[test]
+fn regional_indicator_runs() {
+    // the flags pair up from the start of the run, also with chars
+    // that WB4 ignores in between
+    let s = "a\u{1f1e9}\u{1f1ea}\u{1f1e9}\u{200d}\u{1f1ea}\u{1f1e9}\u{301}\u{1f1ea}\u{1f1e9}b";
+    assert_eq!(clusters(s), pieces(s, |i| is_grapheme_boundary(s, i)));
+    let mut expected = vec![""];
+    expected.extend(pieces(s, |i| is_word_boundary(s, i) && is_grapheme_boundary(s, i)));
+    expected.push("");
+    assert_eq!(words(s), expected);
+
+    // long runs are not looked at again for every position
+    let flags = "\u{1f1e9}".repeat(20001);
+    assert_eq!(clusters(&flags).len(), 10001);
+    assert_eq!(words(&flags).len(), 10003);
+    assert_eq!(Graphemes(&flags).matches("\u{1f1e9}\u{1f1e9}").count(), 10000);
+    assert_eq!(Graphemes(&flags).rfind("\u{1f1e9}"), Some(80000));
+}
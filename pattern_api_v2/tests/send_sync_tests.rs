@@ -0,0 +1,123 @@
+extern crate pattern_api_v2;
+
+use pattern_api_v2::Pattern;
+use pattern_api_v2::iterators::{Split, RSplit, SplitN, SplitTerminator};
+use pattern_api_v2::iterators::{Matches, RMatches, MatchIndices, MatchSpans};
+use pattern_api_v2::iterators::{SplitIndices, SplitSpans};
+use pattern_api_v2::lines::{Lines, LineIndices};
+use pattern_api_v2::slice::Elem;
+use pattern_api_v2::{string, slice, byte_str, os_string, path, segmentation, normalization};
+use pattern_api_v2::fast_sequence_search::{Iter, OrdSeqSearcher};
+
+use std::ffi::OsStr;
+use std::path::Path;
+
+fn send<T: Send>() {}
+fn sync<T: Sync>() {}
+
+macro_rules! send_sync {
+    ($($t:ty),* $(,)*) => {
+        $(
+            send::<$t>();
+            sync::<$t>();
+        )*
+    }
+}
+
+type Searcher<H, P> = <P as Pattern<H>>::Searcher;
+
+type IsDigit = fn(char) -> bool;
+
+#[test]
+fn str_searchers() {
+    send_sync!(
+        string::shared::CharSearcher<'static>,
+        string::shared::CharSliceSearcher<'static, 'static>,
+        string::shared::CharPredicateSearcher<'static, IsDigit>,
+        string::shared::StrSearcher<'static, 'static>,
+        string::mutable::CharSearcher<'static>,
+        string::mutable::StrSearcher<'static, 'static>,
+        Iter<&'static str>,
+        OrdSeqSearcher<'static, &'static str>,
+        Searcher<segmentation::Graphemes<'static>, &'static str>,
+        Searcher<&'static str, segmentation::WordBoundary>,
+        Searcher<&'static str, normalization::Canonical<'static>>,
+    );
+}
+
+#[test]
+fn str_iterators() {
+    send_sync!(
+        Split<&'static str, char>,
+        RSplit<&'static str, &'static str>,
+        SplitN<&'static str, IsDigit>,
+        SplitTerminator<&'static str, &'static [char]>,
+        Matches<&'static str, &'static str>,
+        RMatches<&'static mut str, char>,
+        MatchIndices<&'static str, char>,
+        MatchSpans<&'static str, char>,
+        SplitIndices<&'static str, char>,
+        SplitSpans<&'static mut str, &'static str>,
+        Lines<&'static str>,
+        LineIndices<&'static [u8]>,
+    );
+}
+
+#[test]
+fn slice_searchers() {
+    send_sync!(
+        slice::shared::ElemSearcher<'static, u32>,
+        slice::shared::ElemPredicateSearcher<'static, u32, fn(&u32) -> bool>,
+        slice::shared::SliceSearcher<'static, 'static, u32>,
+        slice::mutable::ElemSearcher<'static, u32>,
+        slice::mutable::SliceSearcher<'static, 'static, u32>,
+        Split<&'static [u32], Elem<u32>>,
+        Matches<&'static mut [u32], &'static [u32]>,
+    );
+}
+
+#[test]
+fn bytes_and_os_str_searchers() {
+    send_sync!(
+        byte_str::shared::PartialUnicode<'static>,
+        byte_str::shared::CharSearcher<'static>,
+        byte_str::shared::StrSearcher<'static, 'static>,
+        byte_str::InvalidUtf8Ranges<'static>,
+        Searcher<&'static [u8], byte_str::InvalidUtf8>,
+        os_string::shared::PartialUnicode<'static>,
+        os_string::shared::CharSearcher<'static>,
+        os_string::shared::OsStrSearcher<'static, 'static>,
+        os_string::mutable::StrSearcher<'static, 'static>,
+        Split<&'static OsStr, char>,
+        MatchIndices<&'static OsStr, &'static OsStr>,
+        path::NeedleSearcher<'static, 'static>,
+        path::GlobSearcher<'static, 'static>,
+        Split<path::PathComponents<'static>, &'static Path>,
+    );
+}
+
+#[test]
+fn iterators_in_worker_threads() {
+    use pattern_api_v2::std_integration::IteratorConstructors;
+    use std::thread;
+
+    let text = String::from("a,b;c,d");
+    let mut words = IteratorConstructors::split(&text[..], ',');
+    let first = words.next();
+    let rest = thread::scope(|s| {
+        s.spawn(move || words.collect::<Vec<_>>()).join().unwrap()
+    });
+    assert_eq!(first, Some("a"));
+    assert_eq!(rest, ["b;c", "d"]);
+
+    let mut bytes = *b"x1y22z";
+    let counts = thread::scope(|s| {
+        let digits = IteratorConstructors::matches(&mut bytes[..], |b: &u8| b.is_ascii_digit());
+        let lines = IteratorConstructors::lines(&text[..]);
+        let a = s.spawn(move || digits.map(|d| d[0] = b'#').count());
+        let b = s.spawn(move || lines.count());
+        (a.join().unwrap(), b.join().unwrap())
+    });
+    assert_eq!(counts, (3, 1));
+    assert_eq!(&bytes, b"x#y##z");
+}
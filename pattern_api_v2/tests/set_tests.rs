@@ -0,0 +1,102 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::Pattern;
+use pattern_api_v2::sets::{CharSet, ByteSet};
+use pattern_api_v2::std_integration::IteratorConstructors as IC;
+
+#[test]
+fn char_set_contents() {
+    let mut set = CharSet::new();
+    assert!(set.is_empty() && set.is_ascii());
+    set.insert_range('a'..='f');
+    set.insert('\u{e9}');
+    set.insert_range('\u{100}'..='\u{17f}');
+    set.insert_range('\u{e0}'..='\u{ff}');
+    set.insert_range('\u{1F600}'..='\u{1F64F}');
+    assert!(!set.is_ascii());
+
+    for c in "abcdef\u{e0}\u{e9}\u{ff}\u{100}\u{17f}\u{1F600}\u{1F64F}".chars() {
+        assert!(set.contains(c), "{:?}", c);
+    }
+    for c in "gA\0\u{7f}\u{80}\u{df}\u{180}\u{1F5FF}\u{1F650}\u{10FFFF}".chars() {
+        assert!(!set.contains(c), "{:?}", c);
+    }
+
+    // overlapping and adjacent ranges are merged
+    let merged: CharSet = vec!['\u{200}'..='\u{2ff}', '\u{100}'..='\u{17f}',
+                               '\u{150}'..='\u{1ff}', '\u{e0}'..='\u{ff}'].into_iter().collect();
+    assert_eq!(merged, CharSet::from(&['\u{e0}'..='\u{2ff}'][..]));
+
+    let from_chars = CharSet::from(&['z', 'a', '\u{3b1}', '\u{3b2}'][..]);
+    assert_eq!(from_chars, CharSet::from(&['a'..='a', 'z'..='z', '\u{3b1}'..='\u{3b2}'][..]));
+    assert!(CharSet::from(&['\0'..='\u{10FFFF}'][..]).contains('\u{D7FF}'));
+}
+
+#[test]
+fn char_set_patterns_agree_with_char_slices() {
+    let needles: &[&[char]] = &[
+        &[' ', ',', ';'],
+        &['a', 'e', 'i', 'o', 'u', '\u{e9}'],
+        &['\u{1F600}', 'x', '\u{3b1}'],
+        &[],
+    ];
+    let haystacks = ["", "hello, world; caf\u{e9} \u{3b1}\u{1F600}x", "   ", "\u{e9}\u{e9}a"];
+
+    for &needle in needles {
+        let set = CharSet::from(needle);
+        for &h in &haystacks {
+            assert_eq!(IC::split(h, &set).collect::<Vec<_>>(),
+                       IC::split(h, needle).collect::<Vec<_>>());
+            assert_eq!(IC::rmatch_indices(h, &set).collect::<Vec<_>>(),
+                       IC::rmatch_indices(h, needle).collect::<Vec<_>>());
+            assert_eq!(IC::trim_matches(h, &set), IC::trim_matches(h, needle));
+            assert_eq!((&set).is_prefix_of(h), needle.is_prefix_of(h));
+            assert_eq!((&set).is_suffix_of(h), needle.is_suffix_of(h));
+        }
+    }
+}
+
+#[test]
+fn char_set_other_haystacks() {
+    use pattern_api_v2::std_integration::{OsStrExtension, ByteSliceExtension};
+
+    let set = CharSet::from(&['-', '\u{e9}'][..]);
+    let h = os!(b"a-\xff\xc3\xa9b");
+    assert_eq!(IC::split(h, &set).collect::<Vec<_>>(), [os!("a"), os!(b"\xff"), os!("b")]);
+    assert_eq!(IC::rfind(h.for_unicode(), &set), Some(3));
+    assert_eq!(IC::find(b"\xff\xc3\xa9"[..].for_unicode(), &set), Some(1));
+
+    let mut s = String::from("a-b-c");
+    for m in IC::matches(&mut s[..], &set) {
+        m.make_ascii_uppercase();
+    }
+    assert_eq!(IC::rsplit(&s[..], &set).collect::<Vec<_>>(), ["c", "b", "a"]);
+}
+
+#[test]
+fn byte_sets() {
+    let mut set = ByteSet::new();
+    assert!(set.is_empty());
+    set.insert(0);
+    set.insert(255);
+    set.insert_range(b'0'..=b'9');
+    assert!(set.contains(0) && set.contains(255) && set.contains(b'5'));
+    assert!(!set.contains(1) && !set.contains(254) && !set.contains(b'a'));
+    assert_eq!(set, [0, 255, b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9']
+                    .iter().cloned().collect());
+
+    let h = &b"\0ab12\xffc"[..];
+    assert_eq!(IC::split(h, set).collect::<Vec<_>>(), [&b""[..], b"ab", b"", b"", b"c"]);
+    assert_eq!(IC::rfind(h, set), Some(5));
+    assert_eq!(IC::trim_matches(h, ByteSet::from(&b"\0c"[..])), b"ab12\xff");
+    assert!(set.is_prefix_of(h));
+    assert_eq!(set.matches_at(h, 3), Some(4));
+
+    let mut v = *b"a1b2";
+    for m in IC::matches(&mut v[..], ByteSet::from(&b"12"[..])) {
+        m[0] = b'#';
+    }
+    assert_eq!(&v, b"a#b#");
+}
@@ -0,0 +1,137 @@
+extern crate pattern_api_v2;
+
+mod common;
+
+use pattern_api_v2::Pattern;
+use pattern_api_v2::signature::{Signature, SignatureError};
+use pattern_api_v2::std_integration::IteratorConstructors as IC;
+
+use common::Lcg;
+
+fn sig(s: &str) -> Signature {
+    s.parse().unwrap()
+}
+
+fn spans(h: &[u8], s: &Signature) -> Vec<(usize, usize)> {
+    IC::match_indices(h, s).map(|(i, m)| (i, i + m.len())).collect()
+}
+
+fn rspans(h: &[u8], s: &Signature) -> Vec<(usize, usize)> {
+    IC::rmatch_indices(h, s).map(|(i, m)| (i, i + m.len())).collect()
+}
+
+#[test]
+fn parsing() {
+    let s = sig("4D 5A ?? ?? 50 45");
+    assert_eq!((s.min_len(), s.max_len()), (6, 6));
+    assert_eq!(sig("4d\t5a\n? ??  50 45"), s);
+    let gaps = sig("E8 [4] 85 C0 [0-2] [1-3] 74");
+    assert_eq!((gaps.min_len(), gaps.max_len()), (9, 13));
+
+    assert_eq!(Signature::parse(""), Err(SignatureError::Empty));
+    assert_eq!(Signature::parse("  \n "), Err(SignatureError::Empty));
+    assert_eq!(Signature::parse("4D 5G"), Err(SignatureError::InvalidToken(3)));
+    assert_eq!(Signature::parse("4D 5A0"), Err(SignatureError::InvalidToken(3)));
+    assert_eq!(Signature::parse("4D ???"), Err(SignatureError::InvalidToken(3)));
+    assert_eq!(Signature::parse("4D [3-2] 5A"), Err(SignatureError::InvalidGap(3)));
+    assert_eq!(Signature::parse("4D [+3] 5A"), Err(SignatureError::InvalidGap(3)));
+    assert_eq!(Signature::parse("4D [x] 5A"), Err(SignatureError::InvalidGap(3)));
+    assert_eq!(Signature::parse("[2] 4D"), Err(SignatureError::GapAtEdge(0)));
+    assert_eq!(Signature::parse("4D [2] [1-3]"), Err(SignatureError::GapAtEdge(3)));
+    assert_eq!(SignatureError::InvalidToken(3).to_string(),
+               "invalid byte at offset 3 of the signature");
+}
+
+#[test]
+fn wildcards() {
+    let h: &[u8] = b"\0\0MZ\x90\0PE\0\0MZ\x90\0\0PE\0MZ\x01\x02PE";
+    let pe = sig("4D 5A ?? ?? 50 45");
+    assert_eq!(spans(h, &pe), [(2, 8), (18, 24)]);
+    assert_eq!(rspans(h, &pe), [(18, 24), (2, 8)]);
+    assert_eq!(IC::split(h, &pe).collect::<Vec<_>>(),
+               [&b"\0\0"[..], b"\0\0MZ\x90\0\0PE\0", b""]);
+    assert!(!pe.is_prefix_of(h) && pe.is_suffix_of(h));
+    assert_eq!(pe.matches_at(h, 18), Some(24));
+    assert_eq!(pe.matches_at(h, 10), None);
+
+    // nibble wildcards
+    let h: &[u8] = &[0x12, 0x48, 0x4F, 0x30, 0x0D, 0x4A, 0xFD];
+    assert_eq!(spans(h, &sig("4? ?D")), [(5, 7)]);
+    assert_eq!(spans(h, &sig("4?")), [(1, 2), (2, 3), (5, 6)]);
+    assert_eq!(spans(h, &sig("?0 ?D")), [(3, 5)]);
+
+    // without exact bytes, every offset is tried
+    assert_eq!(spans(b"abcd", &sig("?? ??")), [(0, 2), (2, 4)]);
+    assert_eq!(rspans(b"abcde", &sig("?? ??")), [(3, 5), (1, 3)]);
+    assert_eq!(IC::find(&b"abc"[..], &sig("?? ?? ?? ??")), None);
+}
+
+#[test]
+fn gaps() {
+    let call = sig("E8 [2-4] FF");
+    let h: &[u8] = &[0xE8, 0xFF, 0xE8, 0, 0, 0, 0, 0xFF, 0xE8, 0, 0xFF, 0xFF, 0xE8, 0, 0, 0xFF];
+    // the first E8 is too close to its FF, and gaps are lazy
+    assert_eq!(spans(h, &call), [(2, 8), (8, 12), (12, 16)]);
+    assert_eq!(rspans(h, &call), [(12, 16), (8, 12), (2, 8)]);
+    // backward, the gap is lazy from the end
+    assert_eq!(rspans(&[0xE8, 0, 0, 0xFF, 0xFF], &call), [(0, 5)]);
+    assert_eq!(spans(&[0xE8, 0, 0, 0xFF, 0xFF], &call), [(0, 4)]);
+
+    // the anchor is `50 45 00 00`, the variable prefix is checked around it
+    let sig2 = sig("4D 5A [1-3] 50 45 00 00");
+    let h: &[u8] = b"MZ.PE\0\0MZ...PE\0\0MZ....PE\0\0MZPE\0\0";
+    assert_eq!(spans(h, &sig2), [(0, 7), (7, 16)]);
+    assert_eq!(rspans(h, &sig2), [(7, 16), (0, 7)]);
+
+    // overlapping anchor occurrences are all considered
+    let s = sig("41 41 [1] 42");
+    assert_eq!(spans(b"AAAB", &s), [(0, 4)]);
+    assert_eq!(spans(b"AAAAB", &s), [(1, 5)]);
+    assert_eq!(rspans(b"AAAAB", &s), [(1, 5)]);
+}
+
+#[test]
+fn anchored_search_agrees_with_scanning() {
+    let mut rng = Lcg(3);
+    for round in 0..300 {
+        // a small alphabet, so that the signatures match often
+        let tokens = ["41", "42", "4?", "??", "[1]", "[0-2]", "[1-3]"];
+        let mut text = String::from("41");
+        for _ in 0..rng.next(6) {
+            text.push(' ');
+            text.push_str(tokens[rng.next(tokens.len())]);
+        }
+        text.push_str(if rng.next(2) == 0 { " 42" } else { " 41" });
+        let s = sig(&text);
+        let len = rng.next(40);
+        let h: Vec<u8> = (0..len).map(|_| b"ABC"[rng.next(3)]).collect();
+
+        let mut expected = vec![];
+        let mut pos = 0;
+        while pos < h.len() {
+            match s.match_at(&h, pos) {
+                Some(end) => {
+                    expected.push((pos, end));
+                    pos = end;
+                }
+                None => pos += 1,
+            }
+        }
+        assert_eq!(spans(&h, &s), expected,
+                   "round {}: {:?} in {:?}", round, text, String::from_utf8_lossy(&h));
+
+        let mut expected = vec![];
+        let mut pos = h.len();
+        while pos > 0 {
+            match s.match_ending_at(&h, pos) {
+                Some(start) => {
+                    expected.push((start, pos));
+                    pos = start;
+                }
+                None => pos -= 1,
+            }
+        }
+        assert_eq!(rspans(&h, &s), expected,
+                   "round {}: {:?} in {:?}", round, text, String::from_utf8_lossy(&h));
+    }
+}
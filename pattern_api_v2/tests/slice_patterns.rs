@@ -57,3 +57,40 @@ searcher_test!(elem_searcher_haystack, Elem(b'b'), &b"abbcbbd"[..], double: [
     Reject(6, 7),
 ]);
 
+
+// The byte fingerprint used to skip ahead for `&[u8]` is only valid
+// for `u8`, not for other element types of the same size.
+#[derive(Copy, Clone, Debug)]
+struct NoCase(u8);
+
+impl PartialEq for NoCase {
+    fn eq(&self, other: &NoCase) -> bool { self.cmp(other) == ::std::cmp::Ordering::Equal }
+}
+impl Eq for NoCase {}
+impl PartialOrd for NoCase {
+    fn partial_cmp(&self, other: &NoCase) -> Option<::std::cmp::Ordering> { Some(self.cmp(other)) }
+}
+impl Ord for NoCase {
+    fn cmp(&self, other: &NoCase) -> ::std::cmp::Ordering {
+        self.0.to_ascii_lowercase().cmp(&other.0.to_ascii_lowercase())
+    }
+}
+
+// Element types of other crates choose their skip optimization
+impl pattern_api_v2::fast_sequence_search::SliceElement for NoCase {
+    type FastSkipOptimization = pattern_api_v2::fast_sequence_search::NoOptimization;
+}
+
+#[test]
+fn slice_searcher_byte_sized_elements() {
+    use pattern_api_v2::std_integration::IteratorConstructors;
+
+    let haystack: Vec<_> = b"abcXYZWdefxyzw".iter().map(|&b| NoCase(b)).collect();
+    let needle: Vec<_> = b"xyzw".iter().map(|&b| NoCase(b)).collect();
+    assert_eq!(IteratorConstructors::find(&haystack[..], &needle[..]), Some(3));
+    assert_eq!(IteratorConstructors::rfind(&haystack[..], &needle[..]), Some(10));
+
+    assert_eq!(IteratorConstructors::find(&b"abcXYZWdefxyzw"[..], &b"xyzw"[..]), Some(10));
+    assert_eq!(IteratorConstructors::find(&[true, false, false][..], &[false, false][..]),
+               Some(1));
+}
@@ -0,0 +1,100 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::{PatternHaystack, Span};
+use pattern_api_v2::slice::Elem;
+use pattern_api_v2::iterators::{MatchSpans, RMatchSpans};
+use pattern_api_v2::iterators::{SplitIndices, RSplitIndices};
+use pattern_api_v2::iterators::{SplitSpans, RSplitSpans};
+use pattern_api_v2::os_string::shared::PartialUnicode as UOsStr;
+use pattern_api_v2::segmentation::Graphemes;
+use pattern_api_v2::std_integration::IteratorConstructors;
+
+use pattern_api_v2_test_support::{s};
+use std::ffi::{OsStr};
+
+fn sp(start: usize, end: usize) -> Span {
+    Span::from(start..end)
+}
+
+iterator_cross_test! {
+    forward-backward, MatchSpans::new, RMatchSpans::new, {
+        match_str, &str: &s("abbcbbd"), _: "bb",
+            [sp(1, 3), sp(4, 6)],
+            [sp(1, 3), sp(4, 6)]
+        match_os_str, &OsStr: os!("abbcbbd"), _: "bb",
+            [sp(1, 3), sp(4, 6)],
+            [sp(1, 3), sp(4, 6)]
+        match_uos_str, UOsStr: uos!(b"abbcbbd"), _: "bb",
+            [sp(1, 3), sp(4, 6)],
+            [sp(1, 3), sp(4, 6)]
+        match_i32, &[i32]: sl![1,-2,-2,3,-2,-2,4], &[_]: &[-2, -2],
+            [sp(1, 3), sp(4, 6)],
+            [sp(1, 3), sp(4, 6)]
+    }
+    double, MatchSpans::new, RMatchSpans::new, {
+        match_str_char, _: "a\u{e9}b\u{e9}", _: '\u{e9}',
+            [sp(1, 3), sp(4, 6)]
+        match_u8_elem, &[u8]: b"abcbd", _: Elem(b'b'),
+            [sp(1, 2), sp(3, 4)]
+    }
+    double, SplitSpans::new, RSplitSpans::new, {
+        split_str_char, _: ",a,,bc", _: ',',
+            [sp(0, 0), sp(1, 2), sp(3, 3), sp(4, 6)]
+        split_u8_elem, &[u8]: b"a b", _: Elem(b' '),
+            [sp(0, 1), sp(2, 3)]
+    }
+    double, SplitIndices::new, RSplitIndices::new, {
+        indices_str_char, _: ",a,,bc", _: ',',
+            [(0, ""), (1, "a"), (3, ""), (4, "bc")]
+        indices_os_char, &OsStr: os!(b"a\xff,b"), _: ',',
+            [(0, os!(b"a\xff")), (3, os!(b"b"))]
+    }
+}
+
+#[test]
+fn split_terminator_like() {
+    assert_eq!("a,b,".split_spans(',').collect::<Vec<_>>(),
+               [sp(0, 1), sp(2, 3), sp(4, 4)]);
+    assert_eq!("a,b,".rsplit_indices(',').collect::<Vec<_>>(),
+               [(4, ""), (2, "b"), (0, "a")]);
+}
+
+#[test]
+fn spans_round_trip() {
+    let text = "caf\u{e9} au lait, caf\u{e9} noir";
+    let spans: Vec<Span> = text.match_spans("caf\u{e9}").collect();
+    for &span in &spans {
+        assert_eq!(text.span_to_self(span), Some("caf\u{e9}"));
+        assert_eq!(&text[std::ops::Range::from(span)], "caf\u{e9}");
+        assert_eq!(span.len(), 5);
+    }
+
+    let mut owned = s("one two");
+    let spans: Vec<Span> = owned.as_str().split_spans(' ').collect();
+    for &span in &spans {
+        owned.as_mut_str().span_to_self(span).unwrap().make_ascii_uppercase();
+    }
+    assert_eq!(owned, "ONE TWO");
+}
+
+#[test]
+fn invalid_spans() {
+    assert_eq!("abc".span_to_self(sp(1, 4)), None);
+    assert_eq!("abc".span_to_self(sp(2, 1)), None);
+    assert_eq!("\u{e9}".span_to_self(sp(0, 1)), None);
+    assert_eq!("".span_to_self(Span::default()), Some(""));
+
+    assert_eq!((&[1, 2, 3][..]).span_to_self(Span::from(1..3)), Some(&[2, 3][..]));
+    assert_eq!((&[1, 2, 3][..]).span_to_self(sp(0, 4)), None);
+
+    assert_eq!(os!(b"a\xffb").span_to_self(sp(1, 2)), Some(os!(b"\xff")));
+    assert_eq!(uos!(b"a\xffb").span_to_self(sp(1, 2)), None);
+    assert_eq!(uos!(b"a\xffb").span_to_self(sp(2, 3)), Some("b"));
+
+    // the flag is a single grapheme
+    let flag = "\u{1F1E9}\u{1F1EA}";
+    assert_eq!(Graphemes(flag).span_to_self(sp(0, 4)), None);
+    assert_eq!(Graphemes(flag).span_to_self(sp(0, 8)), Some(flag));
+}
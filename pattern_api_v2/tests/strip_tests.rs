@@ -0,0 +1,141 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::normalization::Canonical;
+use pattern_api_v2::segmentation::{Graphemes, WordBoundary};
+use pattern_api_v2::slice::Elem;
+use pattern_api_v2::std_integration::IteratorConstructors;
+
+use pattern_api_v2_test_support::{s};
+
+// The methods are called through the trait to avoid
+// picking up the inherent methods of str and [T]
+
+#[test]
+#[allow(clippy::needless_borrows_for_generic_args)]
+fn strip_prefix_str() {
+    assert_eq!(IteratorConstructors::strip_prefix("abcab", "ab"), Some("cab"));
+    assert_eq!(IteratorConstructors::strip_prefix("abcab", "ba"), None);
+    assert_eq!(IteratorConstructors::strip_prefix("abcab", ""), Some("abcab"));
+    assert_eq!(IteratorConstructors::strip_prefix("", ""), Some(""));
+    assert_eq!(IteratorConstructors::strip_prefix("", "a"), None);
+    assert_eq!(IteratorConstructors::strip_prefix("ab", "abc"), None);
+    assert_eq!(IteratorConstructors::strip_prefix("├──", "├"), Some("──"));
+
+    assert_eq!(IteratorConstructors::strip_prefix("aab", 'a'), Some("ab"));
+    assert_eq!(IteratorConstructors::strip_prefix("baa", 'a'), None);
+    assert_eq!(IteratorConstructors::strip_prefix("├──", '├'), Some("──"));
+    assert_eq!(IteratorConstructors::strip_prefix("12a", |c: char| c.is_numeric()),
+               Some("2a"));
+    let chars: &[char] = &['x', 'a'];
+    assert_eq!(IteratorConstructors::strip_prefix("aab", chars), Some("ab"));
+
+    let pat = s("ab");
+    assert_eq!(IteratorConstructors::strip_prefix("abc", &pat), Some("c"));
+    assert_eq!(IteratorConstructors::strip_prefix("abc", &"ab"), Some("c"));
+}
+
+#[test]
+fn strip_suffix_str() {
+    assert_eq!(IteratorConstructors::strip_suffix("abcab", "ab"), Some("abc"));
+    assert_eq!(IteratorConstructors::strip_suffix("abcab", "ba"), None);
+    assert_eq!(IteratorConstructors::strip_suffix("abcab", ""), Some("abcab"));
+    assert_eq!(IteratorConstructors::strip_suffix("", ""), Some(""));
+    assert_eq!(IteratorConstructors::strip_suffix("", "a"), None);
+    assert_eq!(IteratorConstructors::strip_suffix("──├", "├"), Some("──"));
+
+    assert_eq!(IteratorConstructors::strip_suffix("baa", 'a'), Some("ba"));
+    assert_eq!(IteratorConstructors::strip_suffix("aab", 'a'), None);
+    assert_eq!(IteratorConstructors::strip_suffix("──├", '├'), Some("──"));
+    assert_eq!(IteratorConstructors::strip_suffix("a12", |c: char| c.is_numeric()),
+               Some("a1"));
+}
+
+#[test]
+fn strip_mut_str() {
+    let mut string = s("abcab");
+    {
+        let rest = IteratorConstructors::strip_prefix(&mut string[..], "ab").unwrap();
+        rest.make_ascii_uppercase();
+    }
+    assert_eq!(string, "abCAB");
+
+    let mut string = s("abcab");
+    {
+        let rest = IteratorConstructors::strip_suffix(&mut string[..], 'b').unwrap();
+        rest.make_ascii_uppercase();
+    }
+    assert_eq!(string, "ABCAb");
+}
+
+#[test]
+fn strip_slice() {
+    assert_eq!(IteratorConstructors::strip_prefix(&b"abcab"[..], &b"ab"[..]),
+               Some(&b"cab"[..]));
+    assert_eq!(IteratorConstructors::strip_prefix(&b"abcab"[..], &b"ba"[..]), None);
+    assert_eq!(IteratorConstructors::strip_suffix(&b"abcab"[..], &b"ab"[..]),
+               Some(&b"abc"[..]));
+    assert_eq!(IteratorConstructors::strip_suffix(&b"abcab"[..], &b"ba"[..]), None);
+
+    assert_eq!(IteratorConstructors::strip_prefix(&[1, 2, 3][..], Elem(1)),
+               Some(&[2, 3][..]));
+    assert_eq!(IteratorConstructors::strip_prefix(&[1, 2, 3][..], Elem(2)), None);
+    assert_eq!(IteratorConstructors::strip_suffix(&[1, 2, 3][..], |e: &i32| *e > 2),
+               Some(&[1, 2][..]));
+    assert_eq!(IteratorConstructors::strip_suffix(&[1, 2, 3][..], |e: &i32| *e < 2),
+               None);
+
+    let mut v = [1, 2, 3, 4];
+    {
+        let rest = IteratorConstructors::strip_prefix(&mut v[..], &[1, 2][..]).unwrap();
+        rest[0] = 0;
+    }
+    assert_eq!(v, [1, 2, 0, 4]);
+}
+
+#[test]
+fn strip_os_str() {
+    assert_eq!(os!("abcab").strip_prefix("ab"), Some(os!("cab")));
+    assert_eq!(os!("abcab").strip_prefix(os!("ab")), Some(os!("cab")));
+    assert_eq!(os!("abcab").strip_prefix('a'), Some(os!("bcab")));
+    assert_eq!(os!("abcab").strip_prefix("b"), None);
+    assert_eq!(os!(b"a\xffb").strip_prefix("a"), Some(os!(b"\xffb")));
+    assert_eq!(os!(b"\xffab").strip_prefix('a'), None);
+
+    assert_eq!(os!("abcab").strip_suffix("ab"), Some(os!("abc")));
+    assert_eq!(os!("abcab").strip_suffix(os!("ab")), Some(os!("abc")));
+    assert_eq!(os!("abcab").strip_suffix('b'), Some(os!("abca")));
+    assert_eq!(os!("abcab").strip_suffix("a"), None);
+    assert_eq!(os!(b"a\xffb").strip_suffix("b"), Some(os!(b"a\xff")));
+    assert_eq!(os!(b"ab\xff").strip_suffix('b'), None);
+}
+
+#[test]
+fn strip_default_impl() {
+    // a reject at the front ends the search
+    let mut calls = 0;
+    assert_eq!(IteratorConstructors::strip_prefix(Graphemes("xaaaa"),
+                                                  |g: &str| { calls += 1; g == "a" }),
+               None);
+    assert_eq!(calls, 1);
+    let mut calls = 0;
+    assert_eq!(IteratorConstructors::strip_suffix(Graphemes("aaaax"),
+                                                  |g: &str| { calls += 1; g == "a" }),
+               None);
+    assert_eq!(calls, 1);
+
+    assert_eq!(IteratorConstructors::strip_prefix(Graphemes("e\u{301}e"), "e"), None);
+    assert_eq!(IteratorConstructors::strip_suffix(Graphemes("e\u{301}e"), "e"),
+               Some("e\u{301}"));
+    assert_eq!(IteratorConstructors::strip_prefix("cafe\u{301}s", Canonical("caf\u{e9}")),
+               Some("s"));
+    assert_eq!(IteratorConstructors::strip_suffix("scaf\u{e9}", Canonical("cafe\u{301}")),
+               Some("s"));
+
+    // empty matches come before the rejects at the ends
+    assert_eq!(IteratorConstructors::strip_prefix("ab c", WordBoundary), Some("ab c"));
+    assert_eq!(IteratorConstructors::strip_suffix("ab c", WordBoundary), Some("ab c"));
+    assert_eq!(IteratorConstructors::strip_prefix("ab", Canonical("")), Some("ab"));
+    assert_eq!(IteratorConstructors::strip_suffix("ab", Canonical("")), Some("ab"));
+}
@@ -0,0 +1,108 @@
+// The WTF-8 behaviour of `OsStr` on Windows, checked on every platform
+// by selecting the `SurrogateMode` explicitly. `from_wide()` produces
+// WTF-8 everywhere, so the haystacks look like they would on Windows.
+
+extern crate pattern_api_v2;
+
+use pattern_api_v2::os_string::{OsStrNeedle, SurrogateMode};
+use pattern_api_v2::std_integration::{IteratorConstructors, OsStringExtension};
+
+use std::ffi::{OsStr, OsString};
+
+fn wide(v: &[u16]) -> OsString {
+    OsString::from_wide(v)
+}
+
+#[test]
+fn native_mode() {
+    if cfg!(windows) {
+        assert_eq!(SurrogateMode::native(), SurrogateMode::Wtf8);
+    } else {
+        assert_eq!(SurrogateMode::native(), SurrogateMode::Bytes);
+    }
+}
+
+#[test]
+fn wtf8_mode_finds_surrogate_pairs() {
+    // "a😺b😺"
+    let haystack = wide(&[0x61, 0xD83D, 0xDE3A, 0x62, 0xD83D, 0xDE3A]);
+    let cat = wide(&[0xD83D, 0xDE3A]);
+    let needle = OsStrNeedle(&cat, SurrogateMode::Wtf8);
+
+    let v: Vec<_> = IteratorConstructors::match_indices(&haystack[..], needle)
+                        .map(|t| t.0).collect();
+    assert_eq!(v, [1, 6]);
+    let v: Vec<_> = IteratorConstructors::split(&haystack[..], needle).collect();
+    assert_eq!(v, [OsStr::new("a"), OsStr::new("b"), OsStr::new("")]);
+}
+
+#[test]
+fn wtf8_mode_lone_surrogates_inside_needle() {
+    // a lone surrogate surrounded by other code points is fine
+    let haystack = wide(&[0x61, 0x62, 0xD800, 0x63]);
+    let needle = wide(&[0x62, 0xD800, 0x63]);
+    assert_eq!(IteratorConstructors::find(&haystack[..],
+                                          OsStrNeedle(&needle, SurrogateMode::Wtf8)),
+               Some(1));
+}
+
+#[test]
+#[should_panic(expected = "lone surrogate")]
+fn wtf8_mode_rejects_leading_low_surrogate() {
+    let haystack = wide(&[0xD83D, 0xDE3A]);
+    let needle = wide(&[0xDE3A]);
+    IteratorConstructors::find(&haystack[..], OsStrNeedle(&needle, SurrogateMode::Wtf8));
+}
+
+#[test]
+#[should_panic(expected = "lone surrogate")]
+fn wtf8_mode_rejects_trailing_high_surrogate() {
+    let haystack = wide(&[0x61, 0xD83D, 0xDE3A]);
+    let needle = wide(&[0x61, 0xD83D]);
+    IteratorConstructors::contains(&haystack[..], OsStrNeedle(&needle, SurrogateMode::Wtf8));
+}
+
+#[test]
+fn bytes_mode_only_finds_lone_encodings() {
+    let haystack = wide(&[0xD83D, 0xDE3A, 0x61, 0xDE3A]);
+    let needle = wide(&[0xDE3A]);
+    let v: Vec<_> = IteratorConstructors::match_indices(&haystack[..],
+                                                        OsStrNeedle(&needle, SurrogateMode::Bytes))
+                        .map(|t| t.0).collect();
+    assert_eq!(v, [5]);
+}
+
+#[test]
+fn edits_join_surrogate_pairs_in_wtf8_mode() {
+    // "\u{D83D}a\u{DE3A}"
+    let split_pair = || wide(&[0xD83D, 0x61, 0xDE3A]);
+    let cat = wide(&[0xD83D, 0xDE3A]);
+
+    let mut s = split_pair();
+    s.replace_with_mode('a', OsStr::new(""), SurrogateMode::Wtf8);
+    assert_eq!(s, cat);
+    let mut s = split_pair();
+    assert_eq!(s.drain_matches_with_mode('a', SurrogateMode::Wtf8), [OsString::from("a")]);
+    assert_eq!(s, cat);
+    let mut s = split_pair();
+    s.replace_range_with_mode(3..4, OsStr::new(""), SurrogateMode::Wtf8);
+    assert_eq!(s, cat);
+
+    if !cfg!(windows) {
+        let mut s = split_pair();
+        s.replace_with_mode('a', OsStr::new(""), SurrogateMode::Bytes);
+        assert!(s != cat);
+        assert_eq!(s.len(), 6);
+        // inside of an encoded surrogate, which is only invalid UTF-8 here
+        let mut s = split_pair();
+        s.insert_str_with_mode(1, OsStr::new("x"), SurrogateMode::Bytes);
+        assert_eq!(s.len(), 8);
+    }
+}
+
+#[test]
+#[should_panic(expected = "char boundaries")]
+fn wtf8_mode_edit_inside_surrogate() {
+    let mut s = wide(&[0xD83D, 0x61]);
+    s.insert_str_with_mode(1, OsStr::new("x"), SurrogateMode::Wtf8);
+}
@@ -0,0 +1,109 @@
+#[macro_use]
+extern crate pattern_api_v2_test_support;
+extern crate pattern_api_v2;
+
+use pattern_api_v2::{Pattern, PatternError, TryPattern, Searcher};
+use pattern_api_v2::os_string::{OsStrNeedle, SurrogateMode};
+use pattern_api_v2::path::{PathComponents, Glob};
+use pattern_api_v2::std_integration::{IteratorConstructors, OsStringExtension};
+
+use std::ffi::{OsStr, OsString};
+use std::path::Path;
+
+#[test]
+fn infallible_patterns() {
+    assert_eq!("a,b".try_find(','), Ok(Some(1)));
+    assert_eq!("a,b".try_find("x"), Ok(None));
+    assert_eq!("a,b".try_split(',').unwrap().collect::<Vec<_>>(), ["a", "b"]);
+    assert!('x'.try_into_searcher("x").unwrap().next_match().is_some());
+}
+
+#[test]
+fn lone_surrogates() {
+    let lead = OsString::from_wide(&[0x61, 0xD83D]);
+    let trail = OsString::from_wide(&[0xDE3A, 0x61]);
+    let inner = OsString::from_wide(&[0x61, 0xD83D, 0x61]);
+    let h = OsString::from_wide(&[0x61, 0xD83D, 0xDE3A, 0x61]);
+
+    fn needle(s: &OsString) -> OsStrNeedle<'_> {
+        OsStrNeedle(s.as_os_str(), SurrogateMode::Wtf8)
+    }
+    assert_eq!(h.as_os_str().try_find(needle(&lead)), Err(PatternError::LoneSurrogateAtEdge));
+    assert_eq!(h.as_os_str().try_find(needle(&trail)), Err(PatternError::LoneSurrogateAtEdge));
+    assert_eq!(h.as_os_str().try_find(needle(&inner)), Ok(None));
+    assert_eq!(h.as_os_str().try_split(needle(&lead)).err(),
+               Some(PatternError::LoneSurrogateAtEdge));
+
+    // searching byte by byte, half of the pair is found
+    let bytes = OsStrNeedle(lead.as_os_str(), SurrogateMode::Bytes);
+    assert!(h.as_os_str().try_find(bytes).is_ok());
+}
+
+#[test]
+fn utf8_boundaries() {
+    fn wtf8(s: &OsStr) -> OsStrNeedle<'_> {
+        OsStrNeedle(s, SurrogateMode::Wtf8)
+    }
+    let h = os!("caf\u{e9}");
+    assert_eq!(h.try_find(wtf8(os!(b"\xa9"))), Err(PatternError::InvalidUtf8Boundary));
+    assert_eq!(h.try_find(wtf8(os!(b"f\xc3"))), Err(PatternError::InvalidUtf8Boundary));
+    assert_eq!(h.try_find(wtf8(os!(b"\xe2\x82"))), Err(PatternError::InvalidUtf8Boundary));
+    assert_eq!(h.try_find(wtf8(os!("f\u{e9}"))), Ok(Some(2)));
+    assert_eq!(h.try_find(wtf8(os!(""))), Ok(Some(0)));
+}
+
+#[test]
+#[should_panic]
+fn utf8_boundaries_panic_like_try() {
+    let h = os!("caf\u{e9}");
+    IteratorConstructors::find(h, OsStrNeedle(os!(b"\xa9"), SurrogateMode::Wtf8));
+}
+
+#[cfg(unix)]
+#[test]
+fn native_needles_agree_with_find() {
+    // the bytes of an `OsStr` are arbitrary on Unix,
+    // so a needle can match a part of a char
+    let h = os!("caf\u{e9}");
+    for needle in &[os!(b"\xa9"), os!(b"f\xc3"), os!(b"\xe2\x82"), os!("f\u{e9}")] {
+        assert_eq!(h.try_find(*needle), Ok(IteratorConstructors::find(h, *needle)));
+    }
+    assert_eq!(h.try_find(os!(b"\xa9")), Ok(Some(4)));
+    assert_eq!(os!(b"a\xffb").try_find(os!(b"\xff")), Ok(Some(1)));
+}
+
+#[test]
+fn forwarded_needles() {
+    let h = OsString::from_wide(&[0x61, 0xD83D, 0xDE3A, 0x61]);
+    let lead = OsString::from_wide(&[0x61, 0xD83D]);
+    let direct = h.as_os_str().try_find(lead.as_os_str());
+    let by_ref: &&OsStr = &lead.as_os_str();
+    assert_eq!(h.as_os_str().try_find(&lead), direct);
+    assert_eq!(h.as_os_str().try_find(by_ref), direct);
+    assert_eq!(Pattern::<&OsStr>::validate(&by_ref),
+               Pattern::<&OsStr>::validate(&lead.as_os_str()));
+
+    let c: &&str = &"c";
+    assert_eq!(os!("abc").try_find(&OsString::from("b")), Ok(Some(1)));
+    assert_eq!(os!("abc").try_find(c), Ok(Some(2)));
+    assert_eq!("abc".try_find(&String::from("c")), Ok(Some(2)));
+}
+
+#[test]
+fn empty_needles() {
+    let h = PathComponents(Path::new("a/b"));
+    assert_eq!(h.try_find(""), Err(PatternError::EmptyNeedle));
+    assert_eq!(h.try_find(OsStr::new("")), Err(PatternError::EmptyNeedle));
+    assert_eq!(h.try_find(Path::new("")), Err(PatternError::EmptyNeedle));
+    assert_eq!(h.try_find(Glob("")), Err(PatternError::EmptyNeedle));
+    assert_eq!(h.try_find("b"), Ok(Some(2)));
+    assert!(Pattern::<PathComponents>::validate(&Glob("*")).is_ok());
+}
+
+#[test]
+fn error_messages() {
+    assert_eq!(PatternError::EmptyNeedle.to_string(),
+               "the pattern does not support empty needles");
+    let err: Box<dyn std::error::Error> = Box::new(PatternError::InvalidUtf8Boundary);
+    assert!(err.to_string().contains("UTF-8"));
+}
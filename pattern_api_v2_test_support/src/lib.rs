@@ -1,5 +1,3 @@
-#![feature(specialization)]
-
 extern crate pattern_api_v2 as pattern;
 
 use pattern::Pattern;
@@ -7,6 +5,7 @@ use pattern::{Searcher, ReverseSearcher};
 use pattern::PatternHaystack;
 use pattern::InverseMatchesAreValid;
 pub use pattern::os_string::shared::PartialUnicode as OsStrPartialUnicode;
+#[cfg(unix)]
 pub use pattern::os_string::mutable::PartialUnicode as MutOsStrPartialUnicode;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -345,7 +344,7 @@ macro_rules! iterator_cross_test {
                 let (h, p): ($hty, $pty) = ($h, $p);
 
                 let iterator = $new(h, p);
-                assert!(!iterator.is_double_ended(),
+                assert!(!(&$crate::SingleEndedCheck(&iterator)).is_double_ended(),
                         "Iterator is more than just single ended!");
                 let iterator_results = iterator.collect::<Vec<_>>();
                 assert_eq!(iterator_results, vs);
@@ -374,14 +373,14 @@ macro_rules! iterator_cross_test {
                 let (h, p): ($hty, $pty) = ($h, $p);
 
                 let iterator = $new(h, p);
-                assert!(!iterator.is_double_ended(),
+                assert!(!(&$crate::SingleEndedCheck(&iterator)).is_double_ended(),
                         "Iterator is more than just single ended!");
                 let iterator_results = iterator.collect::<Vec<_>>();
                 assert_eq!(iterator_results, vs);
 
                 let (h, p): ($hty, $pty) = ($h, $p);
                 let riterator = $rnew(h, p);
-                assert!(!riterator.is_double_ended(),
+                assert!(!(&$crate::SingleEndedCheck(&riterator)).is_double_ended(),
                         "Reverse Iterator is more than just single ended!");
                 let mut riterator_results = riterator.collect::<Vec<_>>();
                 riterator_results.reverse();
@@ -411,7 +410,7 @@ macro_rules! iterator_cross_test {
                 let (h, p): ($hty, $pty) = ($h, $p);
 
                 let iterator = $new(h, p);
-                assert!(iterator.is_double_ended(),
+                assert!((&$crate::SingleEndedCheck(&iterator)).is_double_ended(),
                         "Iterator is more than just single ended!");
                 let iterator_results = iterator.collect::<Vec<_>>();
                 assert_eq!(iterator_results, vs);
@@ -420,7 +419,7 @@ macro_rules! iterator_cross_test {
 
                 let (h, p): ($hty, $pty) = ($h, $p);
                 let riterator = $rnew(h, p);
-                assert!(riterator.is_double_ended(),
+                assert!((&$crate::SingleEndedCheck(&riterator)).is_double_ended(),
                         "Reverse Iterator is more than just single ended!");
                 let mut riterator_results = riterator.collect::<Vec<_>>();
                 riterator_results.reverse();
@@ -483,17 +482,25 @@ pub fn s(s: &str) -> String {
     String::from(s)
 }
 
+// `AssertSingleEnded` and `InverseMatchesAreValidIsImplemented` use
+// autoref-based dispatch: the method call resolves to the impl for `&T`
+// if `T` has the capability, and falls back to the impl for `&&T` otherwise.
+// This only works on concrete types, which is why they are only used
+// from within the test macros.
+
+pub struct SingleEndedCheck<'a, T: 'a>(pub &'a T);
+
 pub trait AssertSingleEnded {
     fn is_double_ended(&self) -> bool;
 }
 
-impl<T: Iterator> AssertSingleEnded for T {
-    default fn is_double_ended(&self) -> bool {
+impl<'a, 'b, T: Iterator> AssertSingleEnded for &'b SingleEndedCheck<'a, T> {
+    fn is_double_ended(&self) -> bool {
         false
     }
 }
 
-impl<T: DoubleEndedIterator> AssertSingleEnded for T {
+impl<'a, T: DoubleEndedIterator> AssertSingleEnded for SingleEndedCheck<'a, T> {
     fn is_double_ended(&self) -> bool {
         true
     }
@@ -541,18 +548,29 @@ macro_rules! msl {
     ($s:expr, $($ss:expr),*) => (&mut[$s, $($ss),*][..]);
 }
 
+pub struct InverseMatchesAreValidCheck<T>(pub ::std::marker::PhantomData<T>);
+
 pub trait InverseMatchesAreValidIsImplemented {
-    fn inverse_match_is_valid() -> bool;
+    fn inverse_match_is_valid(&self) -> bool;
 }
 
-impl<T> InverseMatchesAreValidIsImplemented for T {
-    default fn inverse_match_is_valid() -> bool {
+impl<'a, T> InverseMatchesAreValidIsImplemented for &'a InverseMatchesAreValidCheck<T> {
+    fn inverse_match_is_valid(&self) -> bool {
         false
     }
 }
 
-impl<T: InverseMatchesAreValid> InverseMatchesAreValidIsImplemented for T {
-    fn inverse_match_is_valid() -> bool {
+impl<T: InverseMatchesAreValid> InverseMatchesAreValidIsImplemented for InverseMatchesAreValidCheck<T> {
+    fn inverse_match_is_valid(&self) -> bool {
         true
     }
 }
+
+#[macro_export]
+macro_rules! inverse_match_is_valid {
+    ($t:ty) => {{
+        use $crate::InverseMatchesAreValidIsImplemented;
+        let check = $crate::InverseMatchesAreValidCheck::<$t>(::std::marker::PhantomData);
+        (&check).inverse_match_is_valid()
+    }}
+}